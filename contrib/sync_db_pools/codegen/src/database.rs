@@ -126,5 +126,9 @@ pub fn database_attr(attr: TokenStream, input: TokenStream) -> Result<TokenStrea
                 <#conn>::abort(__r)
             }
         }
+
+        impl #root::Database for #guard_type {
+            type Connection = #conn_type;
+        }
     }.into())
 }
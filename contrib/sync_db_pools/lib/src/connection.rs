@@ -1,9 +1,10 @@
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-use rocket::{Phase, Rocket, Ignite, Sentinel};
-use rocket::fairing::{AdHoc, Fairing};
+use rocket::{Phase, Rocket, Build, Ignite, Sentinel};
+use rocket::fairing::{self, Fairing, Info, Kind};
 use rocket::request::{Request, Outcome, FromRequest};
+use rocket::response::Response;
 use rocket::outcome::IntoOutcome;
 use rocket::http::Status;
 
@@ -11,6 +12,7 @@ use rocket::tokio::sync::{OwnedSemaphorePermit, Semaphore, Mutex};
 use rocket::tokio::time::timeout;
 
 use crate::{Config, Poolable, Error};
+use crate::transaction::Slot;
 
 /// Unstable internal details of generated code for the #[database] attribute.
 ///
@@ -68,29 +70,51 @@ macro_rules! dberr {
     });
 }
 
+/// The fairing returned by [`ConnectionPool::fairing()`]: initializes the pool
+/// on ignition and ends any [`Transaction`](crate::Transaction) on responses.
+struct PoolFairing<K, C> {
+    name: &'static str,
+    db: &'static str,
+    _marker: PhantomData<fn() -> (K, C)>,
+}
+
+#[rocket::async_trait]
+impl<K: 'static, C: Poolable> Fairing for PoolFairing<K, C> {
+    fn info(&self) -> Info {
+        Info { name: self.name, kind: Kind::Ignite | Kind::Response }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        let db = self.db;
+        run_blocking(move || {
+            let config = match Config::from(db, &rocket) {
+                Ok(config) => config,
+                Err(e) => dberr!("config", db, "{}", e, rocket),
+            };
+
+            let pool_size = config.pool_size;
+            match C::pool(db, &rocket) {
+                Ok(pool) => Ok(rocket.manage(ConnectionPool::<K, C> {
+                    config,
+                    pool: Some(pool),
+                    semaphore: Arc::new(Semaphore::new(pool_size as usize)),
+                    _marker: PhantomData,
+                })),
+                Err(Error::Config(e)) => dberr!("config", db, "{}", e, rocket),
+                Err(Error::Pool(e)) => dberr!("pool init", db, "{}", e, rocket),
+                Err(Error::Custom(e)) => dberr!("pool manager", db, "{:?}", e, rocket),
+            }
+        }).await
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        Slot::<K, C>::end(req, res).await
+    }
+}
+
 impl<K: 'static, C: Poolable> ConnectionPool<K, C> {
     pub fn fairing(fairing_name: &'static str, db: &'static str) -> impl Fairing {
-        AdHoc::try_on_ignite(fairing_name, move |rocket| async move {
-            run_blocking(move || {
-                let config = match Config::from(db, &rocket) {
-                    Ok(config) => config,
-                    Err(e) => dberr!("config", db, "{}", e, rocket),
-                };
-
-                let pool_size = config.pool_size;
-                match C::pool(db, &rocket) {
-                    Ok(pool) => Ok(rocket.manage(ConnectionPool::<K, C> {
-                        config,
-                        pool: Some(pool),
-                        semaphore: Arc::new(Semaphore::new(pool_size as usize)),
-                        _marker: PhantomData,
-                    })),
-                    Err(Error::Config(e)) => dberr!("config", db, "{}", e, rocket),
-                    Err(Error::Pool(e)) => dberr!("pool init", db, "{}", e, rocket),
-                    Err(Error::Custom(e)) => dberr!("pool manager", db, "{:?}", e, rocket),
-                }
-            }).await
-        })
+        PoolFairing::<K, C> { name: fairing_name, db, _marker: PhantomData }
     }
 
     async fn get(&self) -> Result<Connection<K, C>, ()> {
@@ -300,6 +300,40 @@
 //! # }
 //! ```
 //!
+//! ## Transactions
+//!
+//! For connections that implement [`Transactional`], a [`Transaction`] guard
+//! retrieves a connection with an open transaction instead. The transaction is
+//! committed if the handler's response has a `2xx` status and rolled back
+//! otherwise:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! # #[macro_use] extern crate rocket_sync_db_pools;
+//! #
+//! # #[cfg(feature = "diesel_sqlite_pool")]
+//! # mod test {
+//! # use rocket_sync_db_pools::diesel;
+//! # type Data = ();
+//! use rocket_sync_db_pools::Transaction;
+//!
+//! #[database("my_db")]
+//! struct MyDatabase(diesel::SqliteConnection);
+//!
+//! fn store_in_db(conn: &diesel::SqliteConnection) -> Result<Data, ()> {
+//!     // Do something with connection, return some data.
+//!     # Ok(())
+//! }
+//!
+//! #[post("/")]
+//! async fn my_handler(tx: Transaction<MyDatabase>) -> Result<Data, ()> {
+//!     // If this fails, the `Err` responder's status is `500`, and anything
+//!     // `store_in_db` did before failing is rolled back.
+//!     tx.run(|c| store_in_db(c)).await
+//! }
+//! # }
+//! ```
+//!
 //! # Database Support
 //!
 //! Built-in support is provided for many popular databases and drivers. Support
@@ -348,6 +382,8 @@
 //! [`FromRequest`]: rocket::request::FromRequest
 //! [request guards]: rocket::request::FromRequest
 //! [`Poolable`]: crate::Poolable
+//! [`Transactional`]: crate::Transactional
+//! [`Transaction`]: crate::Transaction
 
 #![doc(html_root_url = "https://api.rocket.rs/v0.5-rc/rocket_sync_db_pools")]
 #![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
@@ -379,6 +415,7 @@ mod poolable;
 mod config;
 mod error;
 mod connection;
+mod transaction;
 
 pub use self::poolable::{Poolable, PoolResult};
 pub use self::config::Config;
@@ -386,3 +423,4 @@ pub use self::error::Error;
 
 pub use rocket_sync_db_pools_codegen::*;
 pub use self::connection::*;
+pub use self::transaction::{Transaction, Transactional, Database};
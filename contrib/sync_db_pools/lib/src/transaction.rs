@@ -0,0 +1,283 @@
+use std::sync::{Arc, Mutex};
+
+use rocket::{Rocket, Ignite, Sentinel};
+use rocket::request::{Request, Outcome, FromRequest};
+use rocket::response::Response;
+use rocket::http::{Status, StatusClass};
+
+use crate::{Poolable, Connection};
+
+/// Unstable internal details of generated code for the #[database] attribute.
+///
+/// Ties a `#[database]` guard type to its connection type.
+#[doc(hidden)]
+pub trait Database: Send + Sync + 'static {
+    type Connection;
+}
+
+/// Trait implemented by [`Poolable`] connections that support transactions.
+///
+/// # Provided Implementations
+///
+/// Implementations of `Transactional` are provided for the following types:
+///
+///   * `diesel::MysqlConnection`
+///   * `diesel::PgConnection`
+///   * `diesel::SqliteConnection`
+///   * `postgres::Client`
+///   * `rusqlite::Connection`
+///
+/// # Implementation Guide
+///
+/// Each method is called on a thread from a threadpool, like the closure passed
+/// to `run()`. [`Transactional::begin()`] is called at most once before
+/// exactly one call to either [`Transactional::commit()`] or
+/// [`Transactional::rollback()`].
+pub trait Transactional: Poolable {
+    /// The error that beginning or ending a transaction may result in.
+    type TransactionError: std::fmt::Display;
+
+    /// Begins a transaction.
+    fn begin(&mut self) -> Result<(), Self::TransactionError>;
+
+    /// Commits the transaction begun with [`Transactional::begin()`].
+    fn commit(&mut self) -> Result<(), Self::TransactionError>;
+
+    /// Rolls back the transaction begun with [`Transactional::begin()`].
+    fn rollback(&mut self) -> Result<(), Self::TransactionError>;
+}
+
+macro_rules! impl_diesel_transactional {
+    ($($feature:literal => $conn:ty),*) => ($(
+        #[cfg(feature = $feature)]
+        impl Transactional for $conn {
+            type TransactionError = diesel::result::Error;
+
+            fn begin(&mut self) -> Result<(), Self::TransactionError> {
+                use diesel::connection::{Connection, TransactionManager};
+                self.transaction_manager().begin_transaction(self)
+            }
+
+            fn commit(&mut self) -> Result<(), Self::TransactionError> {
+                use diesel::connection::{Connection, TransactionManager};
+                self.transaction_manager().commit_transaction(self)
+            }
+
+            fn rollback(&mut self) -> Result<(), Self::TransactionError> {
+                use diesel::connection::{Connection, TransactionManager};
+                self.transaction_manager().rollback_transaction(self)
+            }
+        }
+    )*)
+}
+
+impl_diesel_transactional! {
+    "diesel_sqlite_pool" => diesel::SqliteConnection,
+    "diesel_postgres_pool" => diesel::PgConnection,
+    "diesel_mysql_pool" => diesel::MysqlConnection
+}
+
+#[cfg(feature = "postgres_pool")]
+impl Transactional for postgres::Client {
+    type TransactionError = postgres::Error;
+
+    fn begin(&mut self) -> Result<(), Self::TransactionError> {
+        self.batch_execute("BEGIN")
+    }
+
+    fn commit(&mut self) -> Result<(), Self::TransactionError> {
+        self.batch_execute("COMMIT")
+    }
+
+    fn rollback(&mut self) -> Result<(), Self::TransactionError> {
+        self.batch_execute("ROLLBACK")
+    }
+}
+
+#[cfg(feature = "sqlite_pool")]
+impl Transactional for rusqlite::Connection {
+    type TransactionError = rusqlite::Error;
+
+    fn begin(&mut self) -> Result<(), Self::TransactionError> {
+        self.execute_batch("BEGIN")
+    }
+
+    fn commit(&mut self) -> Result<(), Self::TransactionError> {
+        self.execute_batch("COMMIT")
+    }
+
+    fn rollback(&mut self) -> Result<(), Self::TransactionError> {
+        self.execute_batch("ROLLBACK")
+    }
+}
+
+/// A request guard for a connection with an open transaction.
+///
+/// A `Transaction<D>`, where `D` is a type decorated with `#[database]`,
+/// retrieves a connection from `D`'s pool and begins a transaction on it. The
+/// transaction ends automatically once the response for the request is known:
+///
+///   * If the response has a `2xx` status, the transaction is committed. If
+///     committing fails, the response is replaced with that of the `500
+///     Internal Server Error` catcher, as if the handler had failed.
+///   * Otherwise, the transaction is rolled back. This includes responses from
+///     catchers, for instance, because a guard following the `Transaction`
+///     failed, and requests whose handlers didn't complete.
+///
+/// All `Transaction<D>` guards for the same request share one connection and
+/// transaction. The connection type of `D` must implement [`Transactional`].
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # #[macro_use] extern crate rocket_sync_db_pools;
+/// # #[cfg(feature = "sqlite_pool")]
+/// # mod test {
+/// use rocket_sync_db_pools::{rusqlite, Transaction};
+///
+/// #[database("bank")]
+/// struct Bank(rusqlite::Connection);
+///
+/// #[post("/transfer/<from>/<to>/<amount>")]
+/// async fn transfer(tx: Transaction<Bank>, from: i64, to: i64, amount: i64) -> &'static str {
+///     // Both updates become visible together, and only if this handler's
+///     // response has a `2xx` status.
+///     tx.run(move |c| {
+///         let debit = "UPDATE accounts SET balance = balance - ?1 WHERE id = ?2";
+///         c.execute(debit, rusqlite::params![amount, from]).unwrap();
+///         let credit = "UPDATE accounts SET balance = balance + ?1 WHERE id = ?2";
+///         c.execute(credit, rusqlite::params![amount, to]).unwrap();
+///     }).await;
+///
+///     "transferred"
+/// }
+/// # }
+/// ```
+///
+/// As with `D`, retrieving a connection fails with `503 Service Unavailable` if
+/// connecting times out. If beginning the transaction fails, the guard fails
+/// with `500 Internal Server Error`.
+pub struct Transaction<D: Database> where D::Connection: Poolable {
+    connection: Arc<Connection<D, D::Connection>>,
+}
+
+impl<D: Database> Transaction<D> where D::Connection: Poolable {
+    /// Runs the provided closure on a thread from a threadpool. The closure
+    /// will be passed an `&mut` reference to the connection, in the
+    /// transaction. `.await`ing the return value of this function yields the
+    /// value returned by the closure.
+    #[inline]
+    pub async fn run<F, R>(&self, f: F) -> R
+        where F: FnOnce(&mut D::Connection) -> R + Send + 'static,
+              R: Send + 'static,
+    {
+        self.connection.run(f).await
+    }
+}
+
+/// The transaction, if any, begun for a request on a pool's connection.
+pub(crate) struct Slot<K: 'static, C: Poolable>(Mutex<Option<Pending<K, C>>>);
+
+/// A transaction that hasn't ended yet.
+struct Pending<K: 'static, C: Poolable> {
+    connection: Option<Arc<Connection<K, C>>>,
+    end: fn(&mut C, bool) -> Result<(), String>,
+}
+
+fn end<C: Transactional>(conn: &mut C, commit: bool) -> Result<(), String> {
+    let result = match commit {
+        // Don't return the connection to the pool mid-transaction.
+        true => match conn.commit() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let _ = conn.rollback();
+                Err(e)
+            }
+        },
+        false => conn.rollback(),
+    };
+
+    result.map_err(|e| e.to_string())
+}
+
+impl<K: 'static, C: Poolable> Slot<K, C> {
+    fn get<'r>(req: &'r Request<'_>) -> &'r Self {
+        req.local_cache(|| Slot(Mutex::new(None)))
+    }
+
+    /// Ends the request's transaction, if there is one, according to the
+    /// status of `response`.
+    pub(crate) async fn end<'r>(req: &'r Request<'_>, response: &mut Response<'r>) {
+        let pending = Slot::<K, C>::get(req).0.lock().expect("slot lock").take();
+        let (connection, end) = match pending {
+            Some(mut pending) => match pending.connection.take() {
+                Some(connection) => (connection, pending.end),
+                None => return,
+            },
+            None => return,
+        };
+
+        let commit = response.status().class() == StatusClass::Success;
+        if let Err(e) = connection.run(move |c| end(c, commit)).await {
+            match commit {
+                true => error_!("failed to commit database transaction: {}", e),
+                false => error_!("failed to roll back database transaction: {}", e),
+            }
+
+            if commit {
+                let status = Status::InternalServerError;
+                *response = req.rocket()._handle_error(status, req).await;
+            }
+        }
+    }
+}
+
+impl<K: 'static, C: Poolable> Drop for Pending<K, C> {
+    fn drop(&mut self) {
+        // The request ended without a response, so `end()` was never called.
+        if let Some(connection) = self.connection.take() {
+            let end = self.end;
+            rocket::tokio::spawn(async move {
+                if let Err(e) = connection.run(move |c| end(c, false)).await {
+                    error_!("failed to roll back database transaction: {}", e);
+                }
+            });
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl<'r, D: Database> FromRequest<'r> for Transaction<D>
+    where D::Connection: Transactional
+{
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, ()> {
+        let slot = Slot::<D, D::Connection>::get(request);
+        if let Some(ref pending) = *slot.0.lock().expect("slot lock") {
+            if let Some(ref connection) = pending.connection {
+                return Outcome::Success(Transaction { connection: connection.clone() });
+            }
+        }
+
+        let connection = match <Connection<D, D::Connection>>::from_request(request).await {
+            Outcome::Success(connection) => Arc::new(connection),
+            Outcome::Failure(e) => return Outcome::Failure(e),
+            Outcome::Forward(()) => return Outcome::Forward(()),
+        };
+
+        if let Err(e) = connection.run(|c| c.begin().map_err(|e| e.to_string())).await {
+            error_!("failed to begin database transaction: {}", e);
+            return Outcome::Failure((Status::InternalServerError, ()));
+        }
+
+        let pending = Pending { connection: Some(connection.clone()), end: end::<D::Connection> };
+        *slot.0.lock().expect("slot lock") = Some(pending);
+        Outcome::Success(Transaction { connection })
+    }
+}
+
+impl<D: Database> Sentinel for Transaction<D> where D::Connection: Poolable {
+    fn abort(rocket: &Rocket<Ignite>) -> bool {
+        <Connection<D, D::Connection>>::abort(rocket)
+    }
+}
@@ -118,3 +118,175 @@ mod sentinel_and_runtime_test {
         assert!(matches!(err.kind(), SentinelAborts(vec) if vec.len() == 1));
     }
 }
+
+mod transaction_tests {
+    use std::sync::{Arc, Mutex};
+
+    use rocket::{Rocket, Build};
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+    use r2d2::{ManageConnection, Pool};
+    use rocket_sync_db_pools::{database, Poolable, PoolResult, Transaction, Transactional};
+
+    #[derive(Clone, Default)]
+    struct Log(Arc<Mutex<Vec<&'static str>>>);
+
+    impl Log {
+        fn push(&self, event: &'static str) {
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn take(&self) -> Vec<&'static str> {
+            std::mem::take(&mut *self.0.lock().unwrap())
+        }
+    }
+
+    struct Manager(Log);
+
+    struct TestConnection {
+        log: Log,
+        fail_commit: bool,
+    }
+
+    impl ManageConnection for Manager {
+        type Connection = TestConnection;
+        type Error = std::convert::Infallible;
+
+        fn connect(&self) -> Result<Self::Connection, Self::Error> {
+            Ok(TestConnection { log: self.0.clone(), fail_commit: false })
+        }
+
+        fn is_valid(&self, _conn: &mut Self::Connection) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn has_broken(&self, _conn: &mut Self::Connection) -> bool {
+            false
+        }
+    }
+
+    impl Poolable for TestConnection {
+        type Manager = Manager;
+        type Error = ();
+
+        fn pool(_db_name: &str, rocket: &Rocket<Build>) -> PoolResult<Self> {
+            let log = rocket.state::<Log>().cloned().expect("managed log");
+            Ok(Pool::builder().max_size(2).build(Manager(log))?)
+        }
+    }
+
+    impl Transactional for TestConnection {
+        type TransactionError = &'static str;
+
+        fn begin(&mut self) -> Result<(), Self::TransactionError> {
+            self.log.push("begin");
+            Ok(())
+        }
+
+        fn commit(&mut self) -> Result<(), Self::TransactionError> {
+            if self.fail_commit {
+                return Err("commit failed");
+            }
+
+            self.log.push("commit");
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> Result<(), Self::TransactionError> {
+            self.fail_commit = false;
+            self.log.push("rollback");
+            Ok(())
+        }
+    }
+
+    #[database("test_db")]
+    struct TestDb(TestConnection);
+
+    #[rocket::get("/ok")]
+    async fn ok(tx: Transaction<TestDb>) -> &'static str {
+        tx.run(|c| c.log.push("query")).await;
+        "ok"
+    }
+
+    #[rocket::get("/err")]
+    async fn err(tx: Transaction<TestDb>) -> Result<&'static str, Status> {
+        tx.run(|c| c.log.push("query")).await;
+        Err(Status::Conflict)
+    }
+
+    #[rocket::get("/shared")]
+    async fn shared(a: Transaction<TestDb>, b: Transaction<TestDb>) -> &'static str {
+        a.run(|c| c.log.push("a")).await;
+        b.run(|c| c.log.push("b")).await;
+        "shared"
+    }
+
+    #[rocket::get("/commit_fails")]
+    async fn commit_fails(tx: Transaction<TestDb>) -> &'static str {
+        tx.run(|c| c.fail_commit = true).await;
+        "committed?"
+    }
+
+    #[rocket::get("/plain")]
+    async fn plain(db: TestDb) -> &'static str {
+        db.run(|c| c.log.push("query")).await;
+        "plain"
+    }
+
+    fn client() -> (Client, Log) {
+        use rocket::figment::{Figment, util::map};
+
+        let log = Log::default();
+        let config = Figment::from(rocket::Config::debug_default())
+            .merge(("databases", map!["test_db" => map!["url" => ""]]));
+
+        let rocket = rocket::custom(config)
+            .manage(log.clone())
+            .attach(TestDb::fairing())
+            .mount("/", rocket::routes![ok, err, shared, commit_fails, plain]);
+
+        (Client::tracked(rocket).expect("valid rocket"), log)
+    }
+
+    #[test]
+    fn commits_on_success() {
+        let (client, log) = client();
+        let response = client.get("/ok").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "ok");
+        assert_eq!(log.take(), &["begin", "query", "commit"]);
+    }
+
+    #[test]
+    fn rolls_back_on_failure() {
+        let (client, log) = client();
+        let response = client.get("/err").dispatch();
+        assert_eq!(response.status(), Status::Conflict);
+        assert_eq!(log.take(), &["begin", "query", "rollback"]);
+    }
+
+    #[test]
+    fn guards_share_a_transaction() {
+        let (client, log) = client();
+        let response = client.get("/shared").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(log.take(), &["begin", "a", "b", "commit"]);
+    }
+
+    #[test]
+    fn failed_commit_is_internal_error() {
+        let (client, log) = client();
+        let response = client.get("/commit_fails").dispatch();
+        assert_eq!(response.status(), Status::InternalServerError);
+        assert!(response.into_string().unwrap().contains("500"));
+        assert_eq!(log.take(), &["begin", "rollback"]);
+    }
+
+    #[test]
+    fn plain_connections_are_untouched() {
+        let (client, log) = client();
+        let response = client.get("/plain").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(log.take(), &["query"]);
+    }
+}
@@ -0,0 +1,83 @@
+//! WebSocket support for Rocket.
+//!
+//! This crate adds a [`WebSocket`] request guard which accepts WebSocket
+//! upgrade requests and negotiates a subprotocol, and a [`Channel`] responder
+//! which upgrades the connection and hands a [`DuplexStream`] of messages to a
+//! handler. It is built on [`tokio-tungstenite`].
+//!
+//! [`tokio-tungstenite`]: https://docs.rs/tokio-tungstenite/0.21
+//!
+//! # Usage
+//!
+//! Depend on the crate:
+//!
+//! ```toml
+//! [dependencies]
+//! rocket_ws = "0.1.0-rc.1"
+//! ```
+//!
+//! Then use the [`WebSocket`] guard in a `GET` route and return a
+//! [`Channel`] created via [`WebSocket::channel()`]:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::futures::{SinkExt, StreamExt};
+//! use rocket_ws::{WebSocket, Channel};
+//!
+//! #[get("/echo")]
+//! fn echo(ws: WebSocket) -> Channel<'static> {
+//!     ws.channel(|mut stream| Box::pin(async move {
+//!         while let Some(message) = stream.next().await {
+//!             stream.send(message?).await?;
+//!         }
+//!
+//!         Ok(())
+//!     }))
+//! }
+//! ```
+//!
+//! # Subprotocols
+//!
+//! Clients may offer one or more subprotocols in `Sec-WebSocket-Protocol`
+//! headers. The offered protocols are available via
+//! [`WebSocket::protocols()`]. A server selects one with
+//! [`WebSocket::select()`], which fails with a [`Rejection`] if the client
+//! offered none of the protocols the server supports. The selected protocol is
+//! sent in the `Sec-WebSocket-Protocol` header of the `101` response:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::futures::{SinkExt, StreamExt};
+//! use rocket_ws::{WebSocket, Channel, Message, Rejection};
+//!
+//! #[get("/chat")]
+//! fn chat(ws: WebSocket) -> Result<Channel<'static>, Rejection> {
+//!     let ws = ws.select(["chat.v2", "chat.v1"])?;
+//!     let version = ws.protocol().unwrap().to_string();
+//!     Ok(ws.channel(move |mut stream| Box::pin(async move {
+//!         stream.send(Message::Text(format!("speaking {}", version))).await
+//!     })))
+//! }
+//! ```
+
+#![doc(html_root_url = "https://api.rocket.rs/v0.5-rc/rocket_ws")]
+#![doc(html_favicon_url = "https://rocket.rs/images/favicon.ico")]
+#![doc(html_logo_url = "https://rocket.rs/images/logo-boxed.png")]
+
+mod websocket;
+
+pub use tokio_tungstenite::tungstenite;
+
+pub use self::websocket::{WebSocket, Channel, Rejection};
+
+/// A message sent or received over a WebSocket.
+pub use self::tungstenite::Message;
+
+/// Configuration for a WebSocket connection, set via [`WebSocket::config()`].
+pub use self::tungstenite::protocol::WebSocketConfig as Config;
+
+/// A stream and sink of [`Message`]s over an upgraded connection.
+pub type DuplexStream = tokio_tungstenite::WebSocketStream<rocket::data::IoStream>;
+
+/// The result of a WebSocket channel handler.
+pub type Result<T = ()> = std::result::Result<T, tungstenite::Error>;
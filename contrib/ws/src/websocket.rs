@@ -0,0 +1,261 @@
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+
+use rocket::data::{IoHandler, IoStream};
+use rocket::futures::future::BoxFuture;
+use rocket::http::{HeaderMap, Status};
+use rocket::request::{Request, FromRequest, Outcome};
+use rocket::response::{self, Responder, Response};
+
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::handshake::derive_accept_key;
+use tokio_tungstenite::tungstenite::protocol::Role;
+
+use crate::{Config, DuplexStream, Result};
+
+/// Request guard for WebSocket upgrade requests.
+///
+/// A `WebSocket` is the start of a WebSocket handshake. It exposes the
+/// subprotocols the client offers, allows selecting one, and is turned into a
+/// [`Channel`] responder, which completes the handshake, via
+/// [`WebSocket::channel()`].
+///
+/// # Outcomes
+///
+///   * **Success** if the request is a well-formed WebSocket upgrade request.
+///   * **Forward** if the request isn't a WebSocket upgrade request, that is,
+///     its `Connection` header doesn't contain `upgrade` or its `Upgrade`
+///     header doesn't contain `websocket`.
+///   * **Failure** with `400 Bad Request` if the request is missing a
+///     `Sec-WebSocket-Key` header or its `Sec-WebSocket-Version` isn't `13`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket_ws::{WebSocket, Channel, Message};
+/// use rocket::futures::SinkExt;
+///
+/// #[get("/hello")]
+/// fn hello(ws: WebSocket) -> Channel<'static> {
+///     ws.channel(|mut stream| Box::pin(async move {
+///         stream.send(Message::Text("Hello, world!".into())).await
+///     }))
+/// }
+/// ```
+#[derive(Debug)]
+pub struct WebSocket {
+    key: String,
+    offered: Vec<String>,
+    protocol: Option<String>,
+    config: Config,
+}
+
+impl WebSocket {
+    /// Returns the subprotocols offered by the client in the order they were
+    /// offered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket_ws::WebSocket;
+    ///
+    /// #[get("/offered")]
+    /// fn offered(ws: WebSocket) -> String {
+    ///     ws.protocols().collect::<Vec<_>>().join(", ")
+    /// }
+    /// ```
+    pub fn protocols(&self) -> impl Iterator<Item = &str> {
+        self.offered.iter().map(|protocol| protocol.as_str())
+    }
+
+    /// Returns the subprotocol selected via [`WebSocket::select()`], if any.
+    pub fn protocol(&self) -> Option<&str> {
+        self.protocol.as_deref()
+    }
+
+    /// Selects the first protocol in `supported`, in order of preference, that
+    /// the client offered. Protocols are compared case-sensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Rejection`] if the client offered none of the `supported`
+    /// protocols, including if it offered none at all. A `Rejection` responds
+    /// with `400 Bad Request`, so it can be returned directly from a handler.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket_ws::{WebSocket, Channel, Rejection};
+    ///
+    /// #[get("/graphql")]
+    /// fn graphql(ws: WebSocket) -> Result<Channel<'static>, Rejection> {
+    ///     let ws = ws.select(["graphql-transport-ws", "graphql-ws"])?;
+    ///     match ws.protocol() {
+    ///         Some("graphql-ws") => Ok(ws.channel(|stream| Box::pin(async move {
+    ///             /* legacy protocol */
+    /// #           let _ = stream;
+    /// #           Ok(())
+    ///         }))),
+    ///         _ => Ok(ws.channel(|stream| Box::pin(async move {
+    ///             /* current protocol */
+    /// #           let _ = stream;
+    /// #           Ok(())
+    ///         }))),
+    ///     }
+    /// }
+    /// ```
+    pub fn select<I, S>(mut self, supported: I) -> std::result::Result<Self, Rejection>
+        where I: IntoIterator<Item = S>, S: AsRef<str>
+    {
+        let selected = supported.into_iter()
+            .find(|protocol| self.offered.iter().any(|o| o == protocol.as_ref()));
+
+        match selected {
+            Some(protocol) => {
+                self.protocol = Some(protocol.as_ref().to_string());
+                Ok(self)
+            }
+            None => Err(Rejection { offered: self.offered }),
+        }
+    }
+
+    /// Sets the configuration of the connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket_ws::{WebSocket, Channel, Config};
+    ///
+    /// #[get("/small")]
+    /// fn small(ws: WebSocket) -> Channel<'static> {
+    ///     let config = Config { max_message_size: Some(4096), ..Default::default() };
+    ///     ws.config(config).channel(|stream| Box::pin(async move {
+    ///         /* .. */
+    /// #       let _ = stream;
+    /// #       Ok(())
+    ///     }))
+    /// }
+    /// ```
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Creates a [`Channel`] which completes the handshake and calls `handler`
+    /// with a [`DuplexStream`] to the client once the connection has been
+    /// upgraded. The connection is closed when `handler`'s future completes.
+    pub fn channel<'r, F>(self, handler: F) -> Channel<'r>
+        where F: FnOnce(DuplexStream) -> BoxFuture<'r, Result<()>> + Send + 'r
+    {
+        Channel { ws: self, handler: Box::new(handler) }
+    }
+}
+
+/// A [`Responder`] which upgrades the connection to a WebSocket.
+///
+/// A `Channel` is created via [`WebSocket::channel()`]. It responds with
+/// `101 Switching Protocols`, the `Sec-WebSocket-Accept` header, and, if one
+/// was selected, the `Sec-WebSocket-Protocol` header, then calls its handler.
+/// If the connection isn't upgraded, as when the response is dispatched by a
+/// local client, the response is instead a `426 Upgrade Required`.
+pub struct Channel<'r> {
+    ws: WebSocket,
+    handler: Box<dyn FnOnce(DuplexStream) -> BoxFuture<'r, Result<()>> + Send + 'r>,
+}
+
+/// The error returned by [`WebSocket::select()`] when the client offered none
+/// of the supported subprotocols.
+///
+/// Responds with `400 Bad Request`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    offered: Vec<String>,
+}
+
+impl Rejection {
+    /// Returns the subprotocols the client offered, if any.
+    pub fn offered(&self) -> impl Iterator<Item = &str> {
+        self.offered.iter().map(|protocol| protocol.as_str())
+    }
+}
+
+fn tokens<'h>(headers: &'h HeaderMap<'_>, name: &str) -> impl Iterator<Item = &'h str> {
+    headers.get(name)
+        .flat_map(|value| value.split(','))
+        .map(|token| token.trim())
+        .filter(|token| !token.is_empty())
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for WebSocket {
+    type Error = &'static str;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let headers = req.headers();
+        let is_upgrade = tokens(headers, "Connection").any(|t| t.eq_ignore_ascii_case("upgrade"));
+        let is_ws = tokens(headers, "Upgrade").any(|t| t.eq_ignore_ascii_case("websocket"));
+        if !is_upgrade || !is_ws {
+            return Outcome::Forward(());
+        }
+
+        let key = match headers.get_one("Sec-WebSocket-Key") {
+            Some(key) => key.trim().to_string(),
+            None => return Outcome::Failure((Status::BadRequest, "missing websocket key")),
+        };
+
+        if headers.get_one("Sec-WebSocket-Version").map(|v| v.trim()) != Some("13") {
+            return Outcome::Failure((Status::BadRequest, "unsupported websocket version"));
+        }
+
+        let offered = tokens(headers, "Sec-WebSocket-Protocol").map(String::from).collect();
+        Outcome::Success(WebSocket { key, offered, protocol: None, config: Config::default() })
+    }
+}
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for Channel<'o> {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = Response::build();
+        response.status(Status::UpgradeRequired)
+            .raw_header("Upgrade", "websocket")
+            .raw_header("Sec-WebSocket-Accept", derive_accept_key(self.ws.key.as_bytes()));
+
+        if let Some(protocol) = self.ws.protocol.clone() {
+            response.raw_header("Sec-WebSocket-Protocol", protocol);
+        }
+
+        response.upgrade("websocket", self).ok()
+    }
+}
+
+#[rocket::async_trait]
+impl IoHandler for Channel<'_> {
+    async fn io(self: Pin<Box<Self>>, io: IoStream) -> io::Result<()> {
+        let channel = Pin::into_inner(self);
+        let config = Some(channel.ws.config);
+        let stream = WebSocketStream::from_raw_socket(io, Role::Server, config).await;
+        (channel.handler)(stream).await.map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+}
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for Rejection {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+        Err(Status::BadRequest)
+    }
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.offered.is_empty() {
+            true => write!(f, "no websocket subprotocol was offered"),
+            false => write!(f, "no offered websocket subprotocol is supported: {}",
+                self.offered.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for Rejection { }
@@ -0,0 +1,140 @@
+#[macro_use] extern crate rocket;
+
+use rocket::{Config, Rocket, Build};
+use rocket::fairing::AdHoc;
+use rocket::futures::{SinkExt, StreamExt, channel::oneshot};
+use rocket::tokio::{self, net::TcpStream};
+use rocket_ws::{WebSocket, Channel, Message, Rejection};
+
+#[get("/chat")]
+fn chat(ws: WebSocket) -> Result<Channel<'static>, Rejection> {
+    let ws = ws.select(["chat.v2", "chat.v1"])?;
+    let protocol = ws.protocol().unwrap().to_string();
+    Ok(ws.channel(move |mut stream| Box::pin(async move {
+        while let Some(message) = stream.next().await {
+            let reply = format!("{}: {}", protocol, message?.into_text()?);
+            stream.send(Message::Text(reply)).await?;
+        }
+
+        Ok(())
+    })))
+}
+
+#[get("/offered")]
+fn offered(ws: WebSocket) -> String {
+    ws.protocols().collect::<Vec<_>>().join(", ")
+}
+
+#[get("/offered", rank = 2)]
+fn not_websocket() -> &'static str {
+    "not a websocket"
+}
+
+fn rocket() -> Rocket<Build> {
+    let config = Config { port: 0, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![chat, offered, not_websocket])
+}
+
+mod websocket_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+    use rocket_ws::tungstenite::client::IntoClientRequest;
+
+    fn handshake(protocols: &str) -> Vec<Header<'static>> {
+        let mut headers = vec![
+            Header::new("Connection", "Upgrade"),
+            Header::new("Upgrade", "websocket"),
+            Header::new("Sec-WebSocket-Version", "13"),
+            Header::new("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ=="),
+        ];
+
+        if !protocols.is_empty() {
+            headers.push(Header::new("Sec-WebSocket-Protocol", protocols.to_string()));
+        }
+
+        headers
+    }
+
+    #[rocket::async_test]
+    async fn selected_protocol_is_negotiated() {
+        let (tx, rx) = oneshot::channel();
+        let rocket = rocket().attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+            tx.send(rocket.config().port).unwrap();
+        })));
+
+        tokio::spawn(rocket.launch());
+        let port = rx.await.unwrap();
+        let stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+
+        let mut request = format!("ws://127.0.0.1:{}/chat", port).into_client_request().unwrap();
+        let offered = "chat.v1, chat.v2".parse().unwrap();
+        request.headers_mut().insert("Sec-WebSocket-Protocol", offered);
+
+        let (mut ws, response) = tokio_tungstenite::client_async(request, stream).await.unwrap();
+        assert_eq!(response.status(), 101);
+        assert_eq!(response.headers()["Sec-WebSocket-Protocol"], "chat.v2");
+
+        ws.send(Message::Text("hi".into())).await.unwrap();
+        let reply = ws.next().await.unwrap().unwrap();
+        assert_eq!(reply, Message::Text("chat.v2: hi".into()));
+    }
+
+    #[test]
+    fn handshake_response_headers() {
+        let client = Client::debug(rocket()).unwrap();
+        let mut request = client.get("/chat");
+        for header in handshake("chat.v0, chat.v1") {
+            request.add_header(header);
+        }
+
+        let response = request.dispatch();
+        assert_eq!(response.status(), Status::UpgradeRequired);
+        assert_eq!(response.headers().get_one("Sec-WebSocket-Protocol"), Some("chat.v1"));
+        assert_eq!(response.headers().get_one("Sec-WebSocket-Accept"),
+            Some("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+    }
+
+    #[test]
+    fn unsupported_protocols_are_rejected() {
+        let client = Client::debug(rocket()).unwrap();
+        for protocols in &["", "chat.v3", "CHAT.V2"] {
+            let mut request = client.get("/chat");
+            for header in handshake(protocols) {
+                request.add_header(header);
+            }
+
+            assert_eq!(request.dispatch().status(), Status::BadRequest, "{:?}", protocols);
+        }
+    }
+
+    #[test]
+    fn offered_protocols_are_exposed() {
+        let client = Client::debug(rocket()).unwrap();
+        let mut request = client.get("/offered")
+            .header(Header::new("Sec-WebSocket-Protocol", "mqtt"));
+
+        for header in handshake("wamp.2.json, , soap") {
+            request.add_header(header);
+        }
+
+        let response = request.dispatch();
+        assert_eq!(response.into_string().unwrap(), "mqtt, wamp.2.json, soap");
+    }
+
+    #[test]
+    fn invalid_handshakes() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/offered").dispatch();
+        assert_eq!(response.into_string().unwrap(), "not a websocket");
+
+        let response = client.get("/offered")
+            .header(Header::new("Connection", "upgrade"))
+            .header(Header::new("Upgrade", "websocket"))
+            .header(Header::new("Sec-WebSocket-Version", "8"))
+            .header(Header::new("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ=="))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
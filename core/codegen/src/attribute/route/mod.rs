@@ -1,5 +1,7 @@
 mod parse;
 
+pub use self::parse::RouteName;
+
 use std::hash::Hash;
 
 use devise::{Spanned, SpanWrapped, Result, FromMeta, Diagnostic};
@@ -37,7 +39,7 @@ fn query_decls(route: &Route) -> Option<TokenStream> {
     }
 
     define_spanned_export!(Span::call_site() =>
-        __req, __data, _log, _form, Outcome, _Ok, _Err, _Some, _None
+        __req, __data, _log, _form, Outcome, Status, _Vec, _Ok, _Err, _Some, _None
     );
 
     // Record all of the static parameters for later filtering.
@@ -50,6 +52,14 @@ fn query_decls(route: &Route) -> Option<TokenStream> {
         .split2();
 
     // Now record all of the dynamic parameters.
+    let plus_as_space = route.query_guards()
+        .map(|guard| {
+            let ty = &guard.ty;
+            define_spanned_export!(ty.span() => FromForm);
+            quote_spanned!(ty.span() => <#ty as #FromForm>::PLUS_AS_SPACE)
+        })
+        .collect::<Vec<_>>();
+
     let (name, matcher, ident, init_expr, push_expr, finalize_expr) = route.query_guards()
         .map(|guard| {
             let (name, ty) = (&guard.name, &guard.ty);
@@ -84,11 +94,24 @@ fn query_decls(route: &Route) -> Option<TokenStream> {
             match (_raw, _key) {
                 // Skip static parameters so <param..> doesn't see them.
                 #(((#raw_name, #raw_value), _) => { /* skip */ },)*
-                #((_, #matcher) => #push_expr,)*
+                #((_, #matcher) if #plus_as_space => #push_expr,)*
                 _ => { /* in case we have no trailing, ignore all else */ },
             }
         }
 
+        // Guards that treat `+` literally see the query decoded per RFC 3986.
+        if false #(|| !#plus_as_space)* {
+            for _f in #__req.rfc3986_query_fields() {
+                let _raw = (_f.name.source().as_str(), _f.value);
+                let _key = _f.name.key_lossy().as_str();
+                match (_raw, _key) {
+                    #(((#raw_name, #raw_value), _) => { /* skip */ },)*
+                    #((_, #matcher) if !#plus_as_space => #push_expr,)*
+                    _ => { /* in case we have no trailing, ignore all else */ },
+                }
+            }
+        }
+
         #(
             let #ident = match #finalize_expr {
                 #_Ok(_v) => #_Some(_v),
@@ -101,7 +124,23 @@ fn query_decls(route: &Route) -> Option<TokenStream> {
 
         if !__e.is_empty() {
             #_log::warn_!("query string failed to match declared route");
-            for _err in __e { #_log::warn_!("{}", _err); }
+            let mut _missing = #_Vec::new();
+            for _err in __e {
+                match (&_err.kind, &_err.name) {
+                    (#_form::ErrorKind::Required, #_Some(_name)) => {
+                        #_log::warn_!("missing required query parameter `{}`", _name);
+                        _missing.push(_name.to_string());
+                    }
+                    _ => #_log::warn_!("{}", _err),
+                }
+            }
+
+            // Missing `#[field(required)]` fields fail instead of forwarding.
+            if !_missing.is_empty() {
+                #__req.set_missing_query_fields(_missing);
+                return #Outcome::Failure(#Status::BadRequest);
+            }
+
             return #Outcome::Forward(#__data);
         }
 
@@ -138,10 +177,12 @@ fn param_guard_decl(guard: &Guard) -> TokenStream {
     );
 
     // Returned when a dynamic parameter fails to parse.
+    let trailing = guard.trailing;
     let parse_error = quote!({
         #_log::warn_!("`{}: {}` param guard parsed forwarding with error {:?}",
             #name, stringify!(#ty), __error);
 
+        #__req.set_param_error(#name, #i, #trailing, &__error);
         #Outcome::Forward(#__data)
     });
 
@@ -209,6 +250,23 @@ fn internal_uri_macro_decl(route: &Route) -> TokenStream {
     });
 
     let route_uri = route.attr.uri.to_string();
+    let uri_args = quote!((#(#uri_args),*));
+
+    // A named route's macro is exported, path-independently, at the crate root.
+    // Two routes with the same name in one crate thus fail to compile.
+    let named_macro = route.attr.name.as_ref().map(|name| {
+        let name_macro = RouteName::macro_ident(name.0.as_str(), name.0.span());
+        quote_spanned! { Span::call_site() =>
+            #[doc(hidden)]
+            #[macro_export]
+            /// Rocket generated URI macro for a named route.
+            macro_rules! #name_macro {
+                ($($token:tt)*) => {{
+                    rocket::rocket_internal_uri!(#route_uri, #uri_args, $($token)*)
+                }};
+            }
+        }
+    });
 
     quote_spanned! { Span::call_site() =>
         #[doc(hidden)]
@@ -216,12 +274,14 @@ fn internal_uri_macro_decl(route: &Route) -> TokenStream {
         /// Rocket generated URI macro.
         macro_rules! #inner_macro_name {
             ($($token:tt)*) => {{
-                rocket::rocket_internal_uri!(#route_uri, (#(#uri_args),*), $($token)*)
+                rocket::rocket_internal_uri!(#route_uri, #uri_args, $($token)*)
             }};
         }
 
         #[doc(hidden)]
         pub use #inner_macro_name as #macro_name;
+
+        #named_macro
     }
 }
 
@@ -238,10 +298,26 @@ fn responder_outcome_expr(route: &Route) -> TokenStream {
     let _await = route.handler.sig.asyncness
         .map(|a| quote_spanned!(a.span().into() => .await));
 
-    define_spanned_export!(ret_span => __req, _route);
+    define_spanned_export!(ret_span => __req, _route, Outcome);
+    let content_type = match route.attr.content_type {
+        Some(ref content_type) => content_type,
+        None => return quote_spanned! { ret_span =>
+            let ___responder = #user_handler_fn_name(#(#parameter_names),*) #_await;
+            #_route::Outcome::from(#__req, ___responder)
+        }
+    };
+
+    // Set the route's `content_type` unless the responder set one.
     quote_spanned! { ret_span =>
         let ___responder = #user_handler_fn_name(#(#parameter_names),*) #_await;
-        #_route::Outcome::from(#__req, ___responder)
+        let mut ___outcome = #_route::Outcome::from(#__req, ___responder);
+        if let #Outcome::Success(ref mut ___response) = ___outcome {
+            if ___response.content_type().is_none() {
+                ___response.set_header(#content_type);
+            }
+        }
+
+        ___outcome
     }
 }
 
@@ -311,6 +387,10 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
     // Gather info about the function.
     let (vis, handler_fn) = (&route.handler.vis, &route.handler);
     let handler_fn_name = &handler_fn.sig.ident;
+    let route_name = match route.attr.name {
+        Some(ref name) => name.0.as_str().to_string(),
+        None => handler_fn_name.to_string(),
+    };
     let internal_uri_macro = internal_uri_macro_decl(&route);
     let responder_outcome = responder_outcome_expr(&route);
 
@@ -318,6 +398,13 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
     let uri = route.attr.uri.to_string();
     let rank = Optional(route.attr.rank);
     let format = Optional(route.attr.format.as_ref());
+    let strict_format = matches!(route.attr.strict_format, Some(ref s) if s.value);
+    let content_type = Optional(route.attr.content_type.as_ref());
+    let max_concurrency = Optional(route.attr.max_concurrency.as_ref().map(|m| m.value));
+    let cache = Optional(route.attr.cache.as_ref().map(|ttl| {
+        let secs = ttl.secs;
+        quote_spanned!(ttl.span => #_Duration::from_secs(#secs))
+    }));
 
     Ok(quote! {
         #handler_fn
@@ -341,17 +428,25 @@ fn codegen_route(route: Route) -> Result<TokenStream> {
                         #query_guards
                         #data_guard
 
+                        if let #_Some(__response) = #__req.cached_response() {
+                            return #Outcome::Success(__response);
+                        }
+
                         #responder_outcome
                     })
                 }
 
                 #_route::StaticInfo {
-                    name: stringify!(#handler_fn_name),
+                    name: #route_name,
                     method: #method,
                     uri: #uri,
                     handler: monomorphized_function,
                     format: #format,
+                    strict_format: #strict_format,
+                    content_type: #content_type,
                     rank: #rank,
+                    max_concurrency: #max_concurrency,
+                    cache: #cache,
                     sentinels: #sentinels,
                 }
             }
@@ -403,7 +498,12 @@ fn incomplete_route(
         uri: method_attribute.uri,
         data: method_attribute.data,
         format: method_attribute.format,
+        strict_format: method_attribute.strict_format,
         rank: method_attribute.rank,
+        max_concurrency: method_attribute.max_concurrency,
+        content_type: method_attribute.content_type,
+        name: method_attribute.name,
+        cache: method_attribute.cache,
     };
 
     codegen_route(Route::from(attribute, function)?)
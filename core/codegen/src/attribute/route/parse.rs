@@ -4,7 +4,7 @@ use indexmap::{IndexSet, IndexMap};
 use proc_macro2::Span;
 
 use crate::proc_macro_ext::Diagnostics;
-use crate::http_codegen::{Method, MediaType};
+use crate::http_codegen::{Method, MediaType, ContentType};
 use crate::attribute::param::{Parameter, Dynamic, Guard};
 use crate::syn_ext::FnArgExt;
 use crate::name::Name;
@@ -46,7 +46,12 @@ pub struct Attribute {
     pub uri: RouteUri,
     pub data: Option<SpanWrapped<Dynamic>>,
     pub format: Option<MediaType>,
+    pub strict_format: Option<SpanWrapped<bool>>,
     pub rank: Option<isize>,
+    pub max_concurrency: Option<SpanWrapped<usize>>,
+    pub content_type: Option<ContentType>,
+    pub name: Option<RouteName>,
+    pub cache: Option<CacheTtl>,
 }
 
 /// The parsed `#[method(..)]` (e.g, `get`, `put`, etc.) attribute.
@@ -56,7 +61,12 @@ pub struct MethodAttribute {
     pub uri: RouteUri,
     pub data: Option<SpanWrapped<Dynamic>>,
     pub format: Option<MediaType>,
+    pub strict_format: Option<SpanWrapped<bool>>,
     pub rank: Option<isize>,
+    pub max_concurrency: Option<SpanWrapped<usize>>,
+    pub content_type: Option<ContentType>,
+    pub name: Option<RouteName>,
+    pub cache: Option<CacheTtl>,
 }
 
 #[derive(Debug)]
@@ -101,6 +111,83 @@ impl FromMeta for RouteUri {
     }
 }
 
+/// The explicit name of a route: `get_x` in `#[get("/x", name = "get_x")]`.
+#[derive(Debug)]
+pub struct RouteName(pub Name);
+
+impl RouteName {
+    /// Checks that `name` is a valid route name: a non-empty string of ASCII
+    /// alphanumerics and `_` that doesn't begin with a digit.
+    pub fn validate(name: &str) -> std::result::Result<(), &'static str> {
+        if name.is_empty() {
+            Err("route names cannot be empty")
+        } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+            Err("route names cannot begin with a digit")
+        } else if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            Err("route names may only contain ASCII alphanumerics and `_`")
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The identifier of the crate-level macro that `uri!` invokes for a route
+    /// named `name`.
+    pub fn macro_ident(name: &str, span: Span) -> syn::Ident {
+        syn::Ident::new(&format!("{}{}", crate::URI_NAME_PREFIX, name), span)
+    }
+}
+
+impl FromMeta for RouteName {
+    fn from_meta(meta: &devise::MetaItem) -> Result<Self> {
+        let string = crate::proc_macro_ext::StringLit::from_meta(meta)?;
+        RouteName::validate(&string)
+            .map_err(|e| string.span().error(e)
+                .help("route names are written like identifiers: `name = \"get_user\"`"))?;
+
+        let span = string.span();
+        Ok(RouteName(Name::new(string.0, span)))
+    }
+}
+
+/// The time-to-live of a route's cache: `60s` in `#[get("/", cache = "60s")]`.
+#[derive(Debug)]
+pub struct CacheTtl {
+    pub secs: u64,
+    pub span: Span,
+}
+
+impl CacheTtl {
+    /// Parses a time-to-live of the form `INTEGER ('s' | 'm' | 'h')`.
+    pub fn parse(ttl: &str) -> std::result::Result<u64, &'static str> {
+        let unit_start = ttl.find(|c: char| !c.is_ascii_digit()).unwrap_or(ttl.len());
+        let (num, unit) = ttl.split_at(unit_start);
+        let multiplier = match unit {
+            "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "" => return Err("cache time-to-live is missing a unit"),
+            _ => return Err("unknown cache time-to-live unit"),
+        };
+
+        match num.parse::<u64>().ok().and_then(|n| n.checked_mul(multiplier)) {
+            Some(0) => Err("cache time-to-live must be at least 1 second"),
+            Some(secs) => Ok(secs),
+            None => Err("invalid cache time-to-live"),
+        }
+    }
+}
+
+impl FromMeta for CacheTtl {
+    fn from_meta(meta: &devise::MetaItem) -> Result<Self> {
+        let string = crate::proc_macro_ext::StringLit::from_meta(meta)?;
+        let secs = CacheTtl::parse(&string)
+            .map_err(|e| string.span().error(e)
+                .help("write the time-to-live in seconds, minutes, or hours: `cache = \"60s\"`"))?;
+
+        Ok(CacheTtl { secs, span: string.span() })
+    }
+}
+
 impl Route {
     pub fn upgrade_param(param: Parameter, args: &Arguments) -> Result<Parameter> {
         if !param.dynamic().is_some() {
@@ -136,6 +223,22 @@ impl Route {
             }
         }
 
+        // Emit an error if `strict_format` was supplied without a `format`.
+        if let Some(ref strict) = attr.strict_format {
+            if strict.value && attr.format.is_none() {
+                diags.push(strict.full_span.error("`strict_format` requires a `format`")
+                    .help("add a `format` parameter: `format = \"json\"`"));
+            }
+        }
+
+        // Emit an error if `max_concurrency` would reject every request.
+        if let Some(ref max) = attr.max_concurrency {
+            if max.value == 0 {
+                diags.push(max.full_span.error("`max_concurrency` must be at least 1")
+                    .help("remove `max_concurrency` to allow unlimited concurrency"));
+            }
+        }
+
         // Check the validity of function arguments.
         let span = handler.sig.paren_token.span;
         let mut arguments = Arguments { map: ArgumentMap::new(), span };
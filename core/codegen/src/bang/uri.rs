@@ -10,7 +10,7 @@ use crate::syn_ext::IdentExt;
 use crate::bang::uri_parsing::*;
 use crate::attribute::param::Parameter;
 use crate::exports::*;
-use crate::URI_MACRO_PREFIX;
+use crate::{URI_MACRO_PREFIX, URI_NAME_PREFIX};
 
 macro_rules! p {
     (@go $num:expr, $singular:expr, $plural:expr) => (
@@ -31,7 +31,8 @@ pub fn _uri_macro(input: TokenStream) -> Result<TokenStream> {
     let input2: TokenStream = input.clone().into();
     match syn::parse2::<UriMacro>(input)? {
         UriMacro::Routed(ref mut mac) => {
-            prefix_last_segment(&mut mac.route.path, URI_MACRO_PREFIX);
+            let prefix = if mac.route.named { URI_NAME_PREFIX } else { URI_MACRO_PREFIX };
+            prefix_last_segment(&mut mac.route.path, prefix);
             let path = &mac.route.path;
             Ok(quote!(#path!(#input2)))
         },
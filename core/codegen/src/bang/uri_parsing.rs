@@ -14,6 +14,7 @@ use crate::http::ext::IntoOwned;
 use crate::proc_macro_ext::StringLit;
 use crate::attribute::param::{Parameter, Dynamic};
 use crate::name::Name;
+use crate::attribute::route::RouteName;
 
 // TODO(diag): Use 'Diagnostic' in place of syn::Error.
 
@@ -53,6 +54,8 @@ pub enum UriExpr {
 pub struct RouteInvocation {
     pub path: Path,
     pub args: Args,
+    /// Whether the route was referenced by its name: `name = "path"`.
+    pub named: bool,
 }
 
 /// See `UriMacro` for what each field represents.
@@ -183,9 +186,37 @@ impl Parse for Args {
     }
 }
 
+impl RouteInvocation {
+    /// Parses `name = "path"`, the reference to a named route, into the path
+    /// of the route's name: `path` is `name` or `crate::name`.
+    fn parse_name(input: ParseStream<'_>) -> syn::Result<Path> {
+        input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let string = input.parse::<StringLit>()?;
+        let (prefix, name) = match string.rfind("::") {
+            Some(i) => (&string[..i + 2], &string[i + 2..]),
+            None => ("", &string[..]),
+        };
+
+        if let Err(e) = RouteName::validate(name) {
+            return err(string.span(), e);
+        }
+
+        let mut path = syn::parse_str::<Path>(&format!("{}{}", prefix, name))
+            .map_err(|_| syn::Error::new(string.span(), "invalid path in route name"))?;
+
+        path.segments.iter_mut().for_each(|s| s.ident.set_span(string.span()));
+        Ok(path)
+    }
+}
+
 impl Parse for RouteInvocation {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
-        let path = input.parse()?;
+        let named = input.peek(Ident) && input.peek2(Token![=]) && input.peek3(LitStr)
+            && matches!(input.fork().parse::<Ident>(), Ok(ident) if ident == "name");
+
+        let path = if named { RouteInvocation::parse_name(input)? } else { input.parse()? };
+
         let args = if input.peek(syn::token::Paren) {
             let args;
             syn::parenthesized!(args in input);
@@ -194,7 +225,7 @@ impl Parse for RouteInvocation {
             Args::Unnamed(Punctuated::new())
         };
 
-        Ok(RouteInvocation { path, args })
+        Ok(RouteInvocation { path, args, named })
     }
 }
 
@@ -19,12 +19,132 @@ pub struct FieldAttr {
     pub validate: Option<SpanWrapped<syn::Expr>>,
     pub default: Option<syn::Expr>,
     pub default_with: Option<syn::Expr>,
+    pub required: Option<bool>,
+    pub source: Option<FieldSource>,
 }
 
 impl FieldAttr {
     const NAME: &'static str = "field";
 }
 
+#[derive(Default, FromMeta)]
+pub struct ItemAttr {
+    pub rename_all: Option<Case>,
+}
+
+impl ItemAttr {
+    pub const NAME: &'static str = "form";
+}
+
+/// The part of the request, in `#[field(source = ..)]`, a field is read from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldSource {
+    Query,
+    Data,
+}
+
+impl FromMeta for FieldSource {
+    fn from_meta(meta: &MetaItem) -> Result<Self> {
+        let path = meta.path()?;
+        match path.get_ident() {
+            Some(i) if i == "query" => Ok(FieldSource::Query),
+            Some(i) if i == "data" => Ok(FieldSource::Data),
+            _ => Err(meta.value_span()
+                .error("unknown field source")
+                .help("expected one of `query` or `data`")),
+        }
+    }
+}
+
+impl ToTokens for FieldSource {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        define_spanned_export!(Span::call_site() => _form);
+        tokens.extend(match self {
+            FieldSource::Query => quote!(#_form::Source::Query),
+            FieldSource::Data => quote!(#_form::Source::Data),
+        })
+    }
+}
+
+/// A `rename_all` strategy, named as in serde.
+#[derive(Debug, Clone, Copy)]
+pub enum Case {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl Case {
+    const ALL: &'static [(&'static str, Case)] = &[
+        ("lowercase", Case::Lower),
+        ("UPPERCASE", Case::Upper),
+        ("PascalCase", Case::Pascal),
+        ("camelCase", Case::Camel),
+        ("snake_case", Case::Snake),
+        ("SCREAMING_SNAKE_CASE", Case::ScreamingSnake),
+        ("kebab-case", Case::Kebab),
+        ("SCREAMING-KEBAB-CASE", Case::ScreamingKebab),
+    ];
+
+    /// Converts `field`, a `snake_case` field name, to `self`.
+    pub fn apply(self, field: &str) -> String {
+        match self {
+            Case::Lower | Case::Snake => field.to_owned(),
+            Case::Upper | Case::ScreamingSnake => field.to_ascii_uppercase(),
+            Case::Kebab => field.replace('_', "-"),
+            Case::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+            Case::Pascal => {
+                let mut pascal = String::with_capacity(field.len());
+                let mut capitalize = true;
+                for c in field.chars() {
+                    if c == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(c.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(c);
+                    }
+                }
+
+                pascal
+            }
+            Case::Camel => {
+                let pascal = Case::Pascal.apply(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+        }
+    }
+}
+
+impl FromMeta for Case {
+    fn from_meta(meta: &MetaItem) -> Result<Self> {
+        let name = Name::from_meta(meta)?;
+        Case::ALL.iter()
+            .find(|(case, _)| name == **case)
+            .map(|(_, case)| *case)
+            .ok_or_else(|| {
+                let cases = Case::ALL.iter()
+                    .map(|(case, _)| format!("{:?}", case))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                meta.value_span()
+                    .error("unknown `rename_all` case")
+                    .help(format!("expected one of {}", cases))
+            })
+    }
+}
+
 pub(crate) trait FieldExt {
     fn ident(&self) -> &syn::Ident;
     fn field_names(&self) -> Result<Vec<FieldName>>;
@@ -159,7 +279,15 @@ impl FieldExt for Field<'_> {
 
         if attr_names.is_empty() {
             let ident_name = Name::from(self.ident());
-            return Ok(vec![FieldName::Cased(ident_name)]);
+            let rename_all = ItemAttr::one_from_attrs(ItemAttr::NAME, self.parent.attrs())?
+                .and_then(|attr| attr.rename_all);
+
+            let name = match rename_all {
+                Some(case) => Name::new(case.apply(ident_name.as_str()), ident_name.span()),
+                None => ident_name,
+            };
+
+            return Ok(vec![FieldName::Cased(name)]);
         }
 
         Ok(attr_names)
@@ -387,6 +515,42 @@ pub fn default<'v>(field: Field<'v>) -> Result<Option<TokenStream>> {
     }
 }
 
+/// Returns the `source` of the field, if it has one.
+pub fn source(field: Field<'_>) -> Result<Option<FieldSource>> {
+    let attrs = FieldAttr::from_attrs(FieldAttr::NAME, &field.attrs)?;
+    let mut sources = attrs.iter().filter_map(|a| a.source);
+    let source = sources.next();
+    if sources.next().is_some() {
+        return Err(field.span()
+            .error("duplicate field source")
+            .help("at most one `source` is allowed"));
+    }
+
+    Ok(source)
+}
+
+/// Returns `true` if the field is marked `required`. Required fields are
+/// missing when absent, so they can't have a default.
+pub fn required(field: Field<'_>) -> Result<bool> {
+    let attrs = FieldAttr::from_attrs(FieldAttr::NAME, &field.attrs)?;
+    let required = attrs.iter().any(|a| a.required == Some(true));
+    if required {
+        if let Some(e) = attrs.iter().filter_map(|a| a.default.as_ref()).next() {
+            return Err(e.span()
+                .error("`required` fields cannot have a default")
+                .help("remove `default` or `required`"));
+        }
+
+        if let Some(e) = attrs.iter().filter_map(|a| a.default_with.as_ref()).next() {
+            return Err(e.span()
+                .error("`required` fields cannot have a default")
+                .help("remove `default_with` or `required`"));
+        }
+    }
+
+    Ok(required)
+}
+
 pub fn first_duplicate<K: Spanned, V: PartialEq + Spanned>(
     keys: impl Iterator<Item = K> + Clone,
     values: impl Fn(&K) -> Result<Vec<V>>,
@@ -4,8 +4,9 @@ use proc_macro2::TokenStream;
 use crate::exports::*;
 use crate::derive::form_field::{*, FieldName::*};
 
+// source: Expr of type `Option<Source>`, the source of the field `__f`.
 // F: fn(field_ty: Ty, field_context: Expr)
-fn fields_map<F>(fields: Fields<'_>, map_f: F) -> Result<TokenStream>
+fn fields_map<F>(fields: Fields<'_>, source: TokenStream, map_f: F) -> Result<TokenStream>
     where F: Fn(&syn::Type, &syn::Expr) -> TokenStream
 {
     let mut matchers = vec![];
@@ -19,9 +20,16 @@ fn fields_map<F>(fields: Fields<'_>, map_f: F) -> Result<TokenStream>
         let field_names = field.field_names()?;
         let field_context = syn::parse2(field_context).expect("valid expr");
         let push = map_f(&ty, &field_context);
+        // A field with a `source` only takes fields from that source, or with
+        // an unknown source. Others fall through as if the name didn't match.
+        let admits = match crate::derive::form_field::source(field)? {
+            Some(s) => quote!(#_Option::map_or(#source, true, |__s| __s == #s)),
+            None => quote!(true),
+        };
+
         let field_matchers = field_names.iter().map(|f| match f {
-            Cased(name) => quote!(#name => { #push }),
-            Uncased(name) => quote!(__n if __n.as_uncased() == #name => { #push }),
+            Cased(name) => quote!(#name if #admits => { #push }),
+            Uncased(name) => quote!(__n if __n.as_uncased() == #name && #admits => { #push }),
         });
 
         matchers.extend(field_matchers);
@@ -61,9 +69,14 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
         .replace_generic(0, 0)
         .type_bound(quote!(#_form::FromForm<'__f> + '__f))
         .validator(ValidatorBuild::new()
-            .input_validate(|_, i| match i.generics().lifetimes().enumerate().last() {
-                Some((i, lt)) if i >= 1 => Err(lt.span().error("only one lifetime is supported")),
-                _ => Ok(())
+            .input_validate(|_, i| {
+                if let Some((n, lt)) = i.generics().lifetimes().enumerate().last() {
+                    if n >= 1 {
+                        return Err(lt.span().error("only one lifetime is supported"));
+                    }
+                }
+
+                ItemAttr::one_from_attrs(ItemAttr::NAME, i.attrs()).map(|_| ())
             })
             .fields_validate(|_, fields| {
                 if fields.is_empty() {
@@ -152,9 +165,11 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
                     #output
                 }
             })
-            .try_fields_map(|_, f| fields_map(f, |ty, ctxt| quote_spanned!(ty.span() => {
-                <#ty as #_form::FromForm<'__f>>::push_value(#ctxt, __f.shift());
-            })))
+            .try_fields_map(|_, f| fields_map(f, quote!(__f.source()), |ty, ctxt| {
+                quote_spanned!(ty.span() => {
+                    <#ty as #_form::FromForm<'__f>>::push_value(#ctxt, __f.shift());
+                })
+            }))
         )
         .inner_mapper(MapperBuild::new()
             .try_input_map(|mapper, input| {
@@ -171,10 +186,13 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
             })
             // Without the `let _fut`, we get a wild lifetime error. It don't
             // make no sense, Rust async/await, it don't make no sense.
-            .try_fields_map(|_, f| fields_map(f, |ty, ctxt| quote_spanned!(ty.span() => {
-                let _fut = <#ty as #_form::FromForm<'__f>>::push_data(#ctxt, __f.shift());
-                _fut.await;
-            })))
+            .try_fields_map(|_, f| {
+                let source = quote!(#_Some(#_form::Source::Data));
+                fields_map(f, source, |ty, ctxt| quote_spanned!(ty.span() => {
+                    let _fut = <#ty as #_form::FromForm<'__f>>::push_data(#ctxt, __f.shift());
+                    _fut.await;
+                }))
+            })
         )
         .inner_mapper(MapperBuild::new()
             .with_output(|_, output| quote! {
@@ -235,13 +253,18 @@ pub fn derive_from_form(input: proc_macro::TokenStream) -> TokenStream {
                         <#ty as #_form::FromForm<'__f>>::default(__opts)
                     }));
 
+                let missing = match required(f)? {
+                    true => quote!(#_Err(#_form::ErrorKind::Required.into())),
+                    false => quote!(#default.ok_or_else(|| #_form::ErrorKind::Missing.into())),
+                };
+
                 let _err = _Err;
                 Ok(quote_spanned! { ty.span() => {
                     let __name = #name_view;
                     let __opts = __c.__opts;
                     __c.#ident
                         .map_or_else(
-                            || #default.ok_or_else(|| #_form::ErrorKind::Missing.into()),
+                            || #missing,
                             <#ty as #_form::FromForm<'__f>>::finalize
                         )
                         .and_then(|#ident| {
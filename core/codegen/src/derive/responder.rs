@@ -48,9 +48,17 @@ pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
                 true => Err(i.generics().span().error("only one lifetime is supported")),
                 false => Ok(())
             })
-            .fields_validate(|_, fields| match fields.is_empty() {
-                true => return Err(fields.span().error("need at least one field")),
-                false => Ok(())
+            .fields_validate(|_, fields| {
+                if !fields.is_empty() {
+                    return Ok(());
+                }
+
+                // Fieldless items respond with only their status.
+                match ItemAttr::one_from_attrs("response", fields.parent.attrs())? {
+                    Some(attr) if attr.status.is_some() => Ok(()),
+                    _ => Err(fields.span().error("need at least one field")
+                        .help("fieldless structures and variants require a `status`")),
+                }
             })
         )
         .inner_mapper(MapperBuild::new()
@@ -74,7 +82,7 @@ pub fn derive_responder(input: proc_macro::TokenStream) -> TokenStream {
                             #accessor, __req
                         )?;
                     }
-                }).expect("have at least one field");
+                }).unwrap_or_else(|| quote!(let mut __res = #Response::new();));
 
                 let mut headers = vec![];
                 for field in fields.iter().skip(1) {
@@ -72,6 +72,7 @@ use crate::http::Method;
 use proc_macro::TokenStream;
 
 static URI_MACRO_PREFIX: &str = "rocket_uri_macro_";
+static URI_NAME_PREFIX: &str = "rocket_uri_name_";
 static ROCKET_IDENT_PREFIX: &str = "__rocket_";
 
 macro_rules! emit {
@@ -156,7 +157,12 @@ macro_rules! route_attribute {
         ///
         /// parameter := 'rank' '=' INTEGER
         ///            | 'format' '=' '"' MEDIA_TYPE '"'
+        ///            | 'strict_format' ('=' BOOL)?
+        ///            | 'max_concurrency' '=' INTEGER
+        ///            | 'content_type' '=' '"' MEDIA_TYPE '"'
         ///            | 'data' '=' '"' SINGLE_PARAM '"'
+        ///            | 'name' '=' '"' NAME '"'
+        ///            | 'cache' '=' '"' TTL '"'
         ///
         /// SINGLE_PARAM := '<' IDENT '>'
         /// TRAILING_PARAM := '<' IDENT '..>'
@@ -165,9 +171,22 @@ macro_rules! route_attribute {
         /// MEDIA_TYPE := valid HTTP media type or known shorthand
         ///
         /// INTEGER := unsigned integer, as defined by Rust
+        /// BOOL := `true` or `false`
         /// IDENT := valid identifier, as defined by Rust
+        /// NAME := ASCII alphanumerics and `_`, not beginning with a digit
+        /// TTL := INTEGER ('s' | 'm' | 'h'), at least 1 second
         /// ```
         ///
+        /// `strict_format` may only be used alongside `format`. See
+        /// [`Route::strict_format`] for its meaning.
+        /// `max_concurrency` must be at least `1`. See [`Route::concurrency`]
+        /// for its meaning. `content_type` sets the `Content-Type` of
+        /// successful responses whose responder set none. See
+        /// [`Route::content_type`] for its meaning. `name`
+        /// gives the route a stable name by which [`uri!`] can refer to it. See
+        /// [Named Routes](macro.uri.html#named-routes). `cache` stores successful
+        /// responses for the time-to-live. See [`Route::cache`] for its meaning.
+        ///
         /// The generic route attribute is defined as:
         ///
         /// ```text
@@ -223,6 +242,10 @@ macro_rules! route_attribute {
         /// [`FromData`]: ../rocket/data/trait.FromData.html
         /// [`FromRequest`]: ../rocket/request/trait.FromRequest.html
         /// [`Route`]: ../rocket/struct.Route.html
+        /// [`Route::strict_format`]: ../rocket/struct.Route.html#structfield.strict_format
+        /// [`Route::content_type`]: ../rocket/struct.Route.html#structfield.content_type
+        /// [`Route::concurrency`]: ../rocket/struct.Route.html#structfield.concurrency
+        /// [`Route::cache`]: ../rocket/struct.Route.html#structfield.cache
         /// [`Responder`]: ../rocket/response/trait.Responder.html
         ///
         /// # Semantics
@@ -258,12 +281,13 @@ macro_rules! route_attribute {
         ///   2. A static structure used by [`routes!`] to generate a [`Route`].
         ///
         ///      The static structure (and resulting [`Route`]) is populated
-        ///      with the name (the function's name), path, query, rank, and
-        ///      format from the route attribute. The handler is set to the
-        ///      generated handler.
+        ///      with the name (`name` or the function's name), path, query, rank,
+        ///      format, and format strictness from the route attribute. The
+        ///      handler is set to the generated handler.
         ///
         ///   3. A macro used by [`uri!`] to type-check and generate an
-        ///      [`Origin`].
+        ///      [`Origin`]. A named route's macro is additionally exported at
+        ///      the crate root.
         ///
         /// [`Handler`]: ../rocket/route/trait.Handler.html
         /// [`routes!`]: macro.routes.html
@@ -577,7 +601,7 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 /// The derive accepts one field attribute: `field`, with the following syntax:
 ///
 /// ```text
-/// field := name? default? validate*
+/// field := name? (default | required)? source? validate*
 ///
 /// name := 'name' '=' name_val ','?
 /// name_val :=  '"' FIELD_NAME '"'
@@ -586,6 +610,10 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 /// default := 'default' '=' EXPR ','?
 ///          | 'default_with' '=' EXPR ','?
 ///
+/// required := 'required' ','?
+///
+/// source := 'source' '=' ('query' | 'data') ','?
+///
 /// validate := 'validate' '=' EXPR ','?
 ///
 /// FIELD_NAME := valid field name, according to the HTML5 spec
@@ -593,7 +621,7 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 /// ```
 ///
 /// The attribute can be applied any number of times on a field as long as at
-/// most _one_ of `default` or `default_with` is present per field:
+/// most _one_ of `default`, `default_with`, or `required` is present per field:
 ///
 /// ```rust
 /// # #[macro_use] extern crate rocket;
@@ -655,9 +683,100 @@ pub fn derive_from_form_field(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// **`required`**
+///
+/// Marks the field as required: if it is missing in the incoming form, the
+/// field fails with an [`ErrorKind::Required`] error, even if the field's type
+/// has a default. When such an error occurs while parsing a route's query, the
+/// route fails with a `400 Bad Request` instead of forwarding. The names of the
+/// missing fields are available to catchers via
+/// [`Request::missing_query_fields()`].
+///
+/// [`Request::missing_query_fields()`]: ../rocket/struct.Request.html#method.missing_query_fields
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(FromForm)]
+/// struct Search<'r> {
+///     // `/search` and `/search?page=2` fail with a `400`.
+///     #[field(required)]
+///     q: &'r str,
+///     page: Option<usize>,
+/// }
+///
+/// #[get("/search?<search..>")]
+/// fn search(search: Search<'_>) { /* .. */ }
+/// ```
+///
+/// **`source = query | data`**
+///
+/// Limits the field to form fields submitted in the request's query string
+/// (`query`) or in its body (`data`). Form fields with the field's name that
+/// were submitted elsewhere are treated as if their name didn't match: they're
+/// ignored when parsing leniently and are errors when parsing strictly. Form
+/// fields with no known source, such as those parsed via [`Form::parse()`],
+/// match regardless. At most one `source` may be given per field. A `source` is
+/// chiefly useful with the [`Merged`] data guard, which parses a form from both
+/// the query string and the body:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::Merged;
+///
+/// #[derive(FromForm)]
+/// struct Edit<'r> {
+///     // Only ever read from the query string, as in `/edit?version=3`.
+///     #[field(source = query)]
+///     version: usize,
+///     // Only ever read from the body.
+///     #[field(source = data)]
+///     text: &'r str,
+/// }
+///
+/// #[post("/edit", data = "<edit>")]
+/// fn edit(edit: Merged<Edit<'_>>) { /* .. */ }
+/// ```
+///
+/// The derive additionally accepts one container attribute: `form`, with the
+/// following syntax:
+///
+/// ```text
+/// form := 'rename_all' '=' '"' CASE '"'
+///
+/// CASE := "lowercase" | "UPPERCASE" | "PascalCase" | "camelCase"
+///       | "snake_case" | "SCREAMING_SNAKE_CASE" | "kebab-case"
+///       | "SCREAMING-KEBAB-CASE"
+/// ```
+///
+/// **`rename_all`**
+///
+/// Converts the name of every field without a `name` attribute, assumed to be
+/// in `snake_case`, to the given case. The cases and conversions are the same
+/// as those of serde's `rename_all`, so a structure that derives both
+/// `FromForm` and `Deserialize` can use the same strategy for both. Fields with
+/// a `name` attribute match only their explicit names. The [`UriDisplayQuery`]
+/// derive honors the attribute as well.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(FromForm)]
+/// #[form(rename_all = "camelCase")]
+/// struct Signup {
+///     // Matches `firstName`.
+///     first_name: String,
+///     // Matches `email_address`.
+///     #[field(name = "email_address")]
+///     email: String,
+/// }
+/// ```
+///
 /// [`FromForm`]: rocket::form::FromForm
 /// [`form::Errors`]: rocket::form::Errors
-#[proc_macro_derive(FromForm, attributes(field))]
+/// [`ErrorKind::Required`]: rocket::form::error::ErrorKind::Required
+/// [`Form::parse()`]: rocket::form::Form::parse()
+/// [`Merged`]: rocket::form::Merged
+/// [`UriDisplayQuery`]: derive.UriDisplayQuery.html
+#[proc_macro_derive(FromForm, attributes(form, field))]
 pub fn derive_from_form(input: TokenStream) -> TokenStream {
     emit!(derive::from_form::derive_from_form(input))
 }
@@ -788,6 +907,38 @@ pub fn derive_from_form(input: TokenStream) -> TokenStream {
 /// shorthands. The [`Response`] produced from the generated implementation will
 /// have its content-type overridden to this value.
 ///
+/// A structure or enum variant without fields may also derive `Responder` as
+/// long as it has a `status`. Its [`Response`] has that status and no body.
+/// This makes the derive suitable for an application's error type, which
+/// handlers can return to propagate errors with `?`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(Responder)]
+/// enum ApiError {
+///     #[response(status = 404)]
+///     NotFound,
+///     #[response(status = 400, content_type = "json")]
+///     Invalid(String),
+/// }
+///
+/// fn find(id: usize) -> Result<&'static str, ApiError> {
+///     match id {
+///         0 => Ok("Rocket"),
+///         _ => Err(ApiError::NotFound),
+///     }
+/// }
+///
+/// #[get("/<id>")]
+/// fn user(id: usize) -> Result<&'static str, ApiError> {
+///     let name = find(id)?;
+///     match name.len() {
+///         0 => Err(ApiError::Invalid(r#"{ "error": "empty name" }"#.into())),
+///         _ => Ok(name),
+///     }
+/// }
+/// ```
+///
 /// [`Responder`]: ../rocket/response/trait.Responder.html
 /// [`Response`]: ../rocket/struct.Response.html
 /// [`Response::set_header()`]: ../rocket/response/struct.Response.html#method.set_header
@@ -939,10 +1090,28 @@ pub fn derive_responder(input: TokenStream) -> TokenStream {
 /// the example above, the variant `Kind::String` will render with a value of
 /// `str`.
 ///
+/// Finally, the `form` container attribute of the [`FromForm`] derive may be
+/// applied to a structure to convert the names of fields without a `name`
+/// attribute; a structure deriving both renders the names `FromForm` parses:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[derive(FromForm, UriDisplayQuery)]
+/// #[form(rename_all = "kebab-case")]
+/// struct Query {
+///     page_size: usize,
+/// }
+///
+/// let uri = uri!(items(Query { page_size: 10 }));
+/// # #[get("/items?<q..>")] fn items(q: Query) {}
+/// assert_eq!(uri.to_string(), "/items?page-size=10");
+/// ```
+///
+/// [`FromForm`]: derive.FromForm.html
 /// [`UriDisplay<Query>`]: ../rocket/http/uri/fmt/trait.UriDisplay.html
 /// [`Formatter::write_named_value()`]: ../rocket/http/uri/fmt/struct.Formatter.html#method.write_named_value
 /// [`Formatter::write_value()`]: ../rocket/http/uri/fmt/struct.Formatter.html#method.write_value
-#[proc_macro_derive(UriDisplayQuery, attributes(field))]
+#[proc_macro_derive(UriDisplayQuery, attributes(form, field))]
 pub fn derive_uri_display_query(input: TokenStream) -> TokenStream {
     emit!(derive::uri_display::derive_uri_display_query(input))
 }
@@ -964,8 +1133,37 @@ pub fn derive_uri_display_query(input: TokenStream) -> TokenStream {
 /// The field's type is required to implement [`UriDisplay<Path>`].
 ///
 /// The derive generates an implementation of the [`UriDisplay<Path>`] trait.
-/// The implementation calls [`Formatter::write_value()`] for the field.
+/// The implementation calls [`Formatter::write_value()`] for the field,
+/// delegating to the inner type. The derive also generates the identity
+/// [`FromUriParam`] implementations for the type and references to it, so a
+/// newtype can be used directly wherever its inner type could in [`uri!`]:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::FromParam;
 ///
+/// #[derive(UriDisplayPath)]
+/// struct ProductId(u64);
+///
+/// impl<'a> FromParam<'a> for ProductId {
+///     type Error = std::num::ParseIntError;
+///
+///     fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+///         param.parse().map(ProductId)
+///     }
+/// }
+///
+/// #[get("/product/<id>")]
+/// fn product(id: ProductId) { /* .. */ }
+///
+/// assert_eq!(uri!(product(id = ProductId(5))).to_string(), "/product/5");
+/// ```
+///
+/// To rename fields of a struct displayed in a query, use the `field`
+/// attribute of the [`UriDisplayQuery`](derive.UriDisplayQuery.html) derive.
+///
+/// [`uri!`]: macro.uri.html
+/// [`FromUriParam`]: ../rocket/http/uri/fmt/trait.FromUriParam.html
 /// [`UriDisplay<Path>`]: ../rocket/http/uri/fmt/trait.UriDisplay.html
 /// [`Formatter::write_value()`]: ../rocket/http/uri/fmt/struct.Formatter.html#method.write_value
 #[proc_macro_derive(UriDisplayPath)]
@@ -1286,6 +1484,41 @@ pub fn catchers(input: TokenStream) -> TokenStream {
 /// assert_eq!(bob.to_string(), "/person/Bob?woo#bam");
 /// ```
 ///
+/// ## Named Routes
+///
+/// A route with a `name`, as in `#[get("/x", name = "get_x")]`, can be
+/// referenced by that name with `name = "get_x"` in place of the route's path.
+/// Arguments, if any, follow the name: `uri!(name = "get_x"(v1, v2))`.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// mod people {
+///     #[get("/person/<name>?<age>", name = "person")]
+///     pub fn get(name: &str, age: Option<u8>) { }
+/// }
+///
+/// let bob = uri!(name = "person"("Bob", Some(28)));
+/// assert_eq!(bob.to_string(), "/person/Bob?age=28");
+///
+/// let bob = uri!("/api", name = "person"(name = "Bob", age = _));
+/// assert_eq!(bob.to_string(), "/api/person/Bob");
+/// ```
+///
+/// The `name` attribute parameter exports a `uri!` support macro under the
+/// route's name at the root of the crate defining the route, irrespective of
+/// the handler's module. As a result:
+///
+///   * Route names must be unique within a crate. Declaring two routes with
+///     the same name in one crate is a compile-time error.
+///
+///   * In _other_ crates, the name is qualified by the defining crate's name:
+///     `uri!(name = "my_crate::get_x")`. If the defining crate is imported
+///     with `#[macro_use] extern crate my_crate`, the unqualified name works,
+///     too. Names are resolved, and their arguments checked, at compile-time.
+///
+///   * In the defining crate itself, names can only be referenced from the
+///     crate root module. Elsewhere, use the handler's path.
+///
 /// ## Grammar
 ///
 /// The grammar for this variant of the `uri!` macro is:
@@ -1297,7 +1530,7 @@ pub fn catchers(input: TokenStream) -> TokenStream {
 /// prefix := STRING | expr                     ; `Origin` or `Absolute`
 /// suffix := STRING | expr                     ; `Reference` or `Absolute`
 ///
-/// route := PATH '(' (named | unnamed) ')'
+/// route := (PATH | 'name' '=' NAME) ('(' (named | unnamed) ')')?
 ///
 /// named := IDENT = expr (',' named)? ','?
 /// unnamed := expr (',' unnamed)? ','?
@@ -1308,6 +1541,8 @@ pub fn catchers(input: TokenStream) -> TokenStream {
 /// IDENT := a valid Rust identifier (examples: `name`, `age`)
 /// STRING := an uncooked string literal, as defined by Rust (example: `"hi"`)
 /// PATH := a path, as defined by Rust (examples: `route`, `my_mod::route`)
+/// NAME := a string literal route name, optionally prefixed with a crate path
+///         (examples: `"get_x"`, `"my_crate::get_x"`)
 /// ```
 ///
 /// ## Dynamic Semantics
@@ -1361,6 +1596,14 @@ pub fn catchers(input: TokenStream) -> TokenStream {
 /// impl<P: Part, 'a> FromUriParam<P, &'a str> for String { .. }
 /// ```
 ///
+/// ### Raw Values
+///
+/// A string or path value wrapped in [`Raw`] is written with minimal
+/// percent-encoding: reserved characters like `/`, `&`, and `=`, as well as
+/// existing percent-encoded sequences, are written as-is. A raw value thus
+/// need not form a single path segment or query value. This can change which
+/// route a URI matches, so `Raw` must never be used with untrusted input.
+///
 /// ### Ignorables
 ///
 /// Query parameters can be ignored using `_` in place of an expression. The
@@ -1374,9 +1617,11 @@ pub fn catchers(input: TokenStream) -> TokenStream {
 /// [`Authority`]: ../rocket/http/uri/struct.Authority.html
 /// [`Absolute`]: ../rocket/http/uri/struct.Absolute.html
 /// [`Reference`]: ../rocket/http/uri/struct.Reference.html
+/// [`uri!`]: macro.uri.html
 /// [`FromUriParam`]: ../rocket/http/uri/fmt/trait.FromUriParam.html
 /// [`UriDisplay`]: ../rocket/http/uri/fmt/trait.UriDisplay.html
 /// [`Ignorable`]: ../rocket/http/uri/fmt/trait.Ignorable.html
+/// [`Raw`]: ../rocket/http/uri/fmt/struct.Raw.html
 #[proc_macro]
 pub fn uri(input: TokenStream) -> TokenStream {
     emit!(bang::uri_macro(input))
@@ -842,3 +842,146 @@ fn test_lazy_default() {
         missing3: 42
     }));
 }
+
+#[test]
+fn test_rename_all() {
+    #[derive(Debug, PartialEq, FromForm)]
+    #[form(rename_all = "camelCase")]
+    struct Camel {
+        first_name: String,
+        #[field(name = "the_age")]
+        age_in_years: usize,
+        r#type: bool,
+    }
+
+    let form: Camel = strict("firstName=Bob&the_age=10&type=yes").unwrap();
+    assert_eq!(form, Camel { first_name: "Bob".into(), age_in_years: 10, r#type: true });
+    assert!(strict::<Camel>("first_name=Bob&the_age=10&type=yes").is_err());
+    assert!(strict::<Camel>("firstName=Bob&ageInYears=10&type=yes").is_err());
+
+    macro_rules! assert_case {
+        ($case:literal, $name:literal) => ({
+            #[derive(Debug, PartialEq, FromForm)]
+            #[form(rename_all = $case)]
+            struct Cased {
+                many_word_name: usize,
+            }
+
+            let form: Cased = strict(concat!($name, "=5")).unwrap();
+            assert_eq!(form, Cased { many_word_name: 5 });
+        })
+    }
+
+    assert_case!("lowercase", "many_word_name");
+    assert_case!("UPPERCASE", "MANY_WORD_NAME");
+    assert_case!("PascalCase", "ManyWordName");
+    assert_case!("camelCase", "manyWordName");
+    assert_case!("snake_case", "many_word_name");
+    assert_case!("SCREAMING_SNAKE_CASE", "MANY_WORD_NAME");
+    assert_case!("kebab-case", "many-word-name");
+    assert_case!("SCREAMING-KEBAB-CASE", "MANY-WORD-NAME");
+}
+
+#[test]
+fn test_required() {
+    use rocket::form::error::ErrorKind;
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Search {
+        #[field(required)]
+        q: String,
+        #[field(required)]
+        page: Option<usize>,
+        #[field(default = 10)]
+        limit: usize,
+    }
+
+    let form: Search = lenient("q=rocket&page=2").unwrap();
+    assert_eq!(form, Search { q: "rocket".into(), page: Some(2), limit: 10 });
+
+    let errors = lenient::<Search>("limit=10").unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|e| e.kind == ErrorKind::Required));
+    assert!(errors.iter().any(|e| e.is_for_exactly("q")));
+    assert!(errors.iter().any(|e| e.is_for_exactly("page")));
+
+    // Invalid values are ordinary errors.
+    let errors = lenient::<Search>("q=rocket&page=2&limit=ten").unwrap_err();
+    assert!(matches!(errors[0].kind, ErrorKind::Int(_)));
+}
+
+#[test]
+fn test_field_source() {
+    use rocket::form::{ValueField, Source};
+    use rocket::form::error::ErrorKind;
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Sourced<'r> {
+        #[field(source = query)]
+        page: usize,
+        #[field(source = data)]
+        #[field(name = uncased("TEXT"))]
+        text: &'r str,
+        tags: Vec<&'r str>,
+    }
+
+    type Fields<'r> = [(&'r str, Option<Source>)];
+
+    fn parse<'r, T: FromForm<'r>>(fields: &Fields<'r>) -> form::Result<'r, T> {
+        Form::<T>::parse_iter(fields.iter().map(|&(field, source)| match source {
+            Some(source) => ValueField::parse(field).with_source(source),
+            None => ValueField::parse(field),
+        }))
+    }
+
+    let (q, d) = (Some(Source::Query), Some(Source::Data));
+
+    // Fields without a source match any field.
+    let form: Sourced<'_> = lenient("page=2&text=hi&tags=a").unwrap();
+    assert_eq!(form, Sourced { page: 2, text: "hi", tags: vec!["a"] });
+
+    let fields = [("page=2", q), ("text=hi", d), ("tags=a", d), ("tags=b", q)];
+    let form: Sourced<'_> = parse(&fields).unwrap();
+    assert_eq!(form, Sourced { page: 2, text: "hi", tags: vec!["a", "b"] });
+
+    // Fields from the other source are ignored...
+    let form: Sourced<'_> = parse(&[
+        ("page=7", d), ("page=2", q), ("text=no", q), ("text=hi", d)
+    ]).unwrap();
+    assert_eq!(form, Sourced { page: 2, text: "hi", tags: vec![] });
+
+    let errors = parse::<Sourced<'_>>(&[("page=2", d), ("TeXt=hi", q)]).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().all(|e| e.kind == ErrorKind::Missing));
+
+    // ...unless parsing is strict, where they're unexpected.
+    let fields = [("page=2", q), ("text=hi", d), ("text=no", q)];
+    let errors = parse::<Strict<Sourced<'_>>>(&fields).unwrap_err();
+    assert!(errors.iter().any(|e| e.kind == ErrorKind::Unexpected && e.is_for_exactly("text")));
+}
+
+#[test]
+fn test_maybe() {
+    use rocket::form::Maybe;
+
+    #[derive(Debug, PartialEq, FromForm)]
+    struct Patch<'r> {
+        name: Maybe<&'r str>,
+        bio: Maybe<&'r str>,
+        age: Maybe<u8>,
+    }
+
+    let patch: Patch<'_> = strict("name=Bob&bio=").unwrap();
+    assert_eq!(patch, Patch { name: Maybe::Present("Bob"), bio: Maybe::Empty, age: Maybe::Absent });
+
+    let patch: Patch<'_> = strict("").unwrap();
+    assert_eq!(patch, Patch { name: Maybe::Absent, bio: Maybe::Absent, age: Maybe::Absent });
+
+    let patch: Patch<'_> = lenient("age=&age=7&bio").unwrap();
+    assert_eq!(patch, Patch { name: Maybe::Absent, bio: Maybe::Empty, age: Maybe::Present(7) });
+
+    // Unlike `Option`, a `Maybe` doesn't mask parse errors.
+    let errors = strict::<Patch<'_>>("age=old").unwrap_err();
+    assert!(errors.iter().any(|e| e.is_for_exactly("age")));
+    assert!(lenient::<Patch<'_>>("age=old").is_err());
+}
@@ -146,3 +146,46 @@ async fn generic_responder() {
     assert_eq!(r.content_type().unwrap(), ContentType::Text);
     assert_eq!(r.body_mut().to_string().await.unwrap(), "beep beep");
 }
+
+#[derive(Responder)]
+enum ApiError {
+    #[response(status = 404)]
+    NotFound,
+    #[response(status = 400, content_type = "json")]
+    BadRequest(String),
+    #[response(status = 401)]
+    Unauthorized(&'static str, #[response(ignore)] u32),
+}
+
+#[derive(Responder)]
+#[response(status = 204)]
+struct NoContent;
+
+#[rocket::async_test]
+async fn fieldless_responder() {
+    let client = Client::debug_with(vec![]).await.expect("valid rocket");
+    let local_req = client.get("/");
+    let req = local_req.inner();
+
+    let mut r = ApiError::NotFound.respond_to(req).expect("response okay");
+    assert_eq!(r.status(), Status::NotFound);
+    assert_eq!(r.content_type(), None);
+    assert_eq!(r.body_mut().to_string().await.unwrap(), "");
+
+    let mut r = ApiError::BadRequest(r#"{"error":"bad"}"#.into())
+        .respond_to(req)
+        .expect("response okay");
+
+    assert_eq!(r.status(), Status::BadRequest);
+    assert_eq!(r.content_type(), Some(ContentType::JSON));
+    assert_eq!(r.body_mut().to_string().await.unwrap(), r#"{"error":"bad"}"#);
+
+    let mut r = ApiError::Unauthorized("no token", 7).respond_to(req).expect("response okay");
+    assert_eq!(r.status(), Status::Unauthorized);
+    assert_eq!(r.content_type(), Some(ContentType::Plain));
+    assert_eq!(r.body_mut().to_string().await.unwrap(), "no token");
+
+    let r = NoContent.respond_to(req).expect("response okay");
+    assert_eq!(r.status(), Status::NoContent);
+    assert!(r.body().is_none());
+}
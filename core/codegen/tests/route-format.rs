@@ -115,3 +115,118 @@ fn test_custom_formats() {
     let response = client.post("/").header(ContentType::HTML).dispatch();
     assert_eq!(response.status(), Status::NotFound);
 }
+
+// Test strict formats.
+
+#[post("/strict", format = "json", strict_format)]
+fn strict_json() -> &'static str { "strict_json" }
+
+#[post("/strict", format = "msgpack", strict_format = true)]
+fn strict_msgpack() -> &'static str { "strict_msgpack" }
+
+#[put("/strict", format = "json", strict_format = false)]
+fn lax_json() -> &'static str { "lax_json" }
+
+#[get("/strict", format = "json", strict_format)]
+fn strict_get_json() -> &'static str { "strict_get_json" }
+
+#[post("/strict", format = "plain")]
+fn lax_plain() -> &'static str { "lax_plain" }
+
+#[catch(406)]
+fn not_acceptable(req: &rocket::Request<'_>) -> String {
+    req.expected_formats().iter().map(|f| f.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[test]
+fn test_strict_formats() {
+    let rocket = rocket::build()
+        .mount("/", routes![strict_json, strict_msgpack, lax_json, strict_get_json,
+               lax_plain])
+        .register("/", catchers![not_acceptable]);
+
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.post("/strict").header(ContentType::JSON).dispatch();
+    assert_eq!(response.into_string().unwrap(), "strict_json");
+
+    let response = client.post("/strict").header(ContentType::MsgPack).dispatch();
+    assert_eq!(response.into_string().unwrap(), "strict_msgpack");
+
+    let response = client.post("/strict").header(ContentType::HTML).dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+    let accept = response.headers().get_one("Accept");
+    assert_eq!(accept, Some("application/json, application/msgpack"));
+
+    let response = client.post("/strict").dispatch();
+    assert_eq!(response.status(), Status::UnsupportedMediaType);
+
+    let response = client.put("/strict").header(ContentType::HTML).dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+
+    let response = client.get("/strict").header(Accept::JSON).dispatch();
+    assert_eq!(response.into_string().unwrap(), "strict_get_json");
+
+    let response = client.get("/strict").header(Accept::HTML).dispatch();
+    assert_eq!(response.status(), Status::NotAcceptable);
+    assert!(!response.headers().contains("Accept"));
+    assert_eq!(response.into_string().unwrap(), "application/json");
+
+    // Any other route that matches is preferred to failing.
+    let response = client.post("/strict").header(ContentType::Plain).dispatch();
+    assert_eq!(response.into_string().unwrap(), "lax_plain");
+}
+
+#[get("/img", content_type = "image/png")]
+fn png() -> Vec<u8> { vec![0x89, b'P', b'N', b'G'] }
+
+#[get("/html", content_type = "html")]
+fn html() -> &'static [u8] { b"<p>hi</p>" }
+
+#[get("/json", content_type = "image/png")]
+fn json_anyway() -> (ContentType, &'static str) { (ContentType::JSON, "{}") }
+
+#[get("/text", content_type = "image/png")]
+fn text() -> &'static str { "hi" }
+
+#[get("/async", content_type = "application/x-custom; v=1")]
+async fn custom() -> Vec<u8> { vec![1, 2, 3] }
+
+#[get("/bin", content_type = "image/png")]
+fn explicit_binary() -> (ContentType, Vec<u8>) { (ContentType::Binary, vec![0]) }
+
+#[get("/fail", content_type = "image/png")]
+fn fail() -> Status { Status::NotFound }
+
+#[test]
+fn test_route_content_types() {
+    let rocket = rocket::build()
+        .mount("/", routes![png, html, json_anyway, text, custom, explicit_binary, fail]);
+
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/img").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::PNG));
+    assert_eq!(response.into_bytes().unwrap(), b"\x89PNG");
+
+    let response = client.get("/html").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+
+    // A content-type set by the responder takes precedence.
+    let response = client.get("/json").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::JSON));
+
+    let response = client.get("/text").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Plain));
+
+    let response = client.get("/bin").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Binary));
+
+    let response = client.get("/async").dispatch();
+    let expected = ContentType::with_params("application", "x-custom", ("v", "1"));
+    assert_eq!(response.content_type(), Some(expected));
+
+    let response = client.get("/fail").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.content_type(), Some(ContentType::HTML));
+}
@@ -537,3 +537,150 @@ fn test_simple_ignored() {
         uri!(ignore_with_q3(&mut 5, "boo", "hi b", "ho")) => "/hi/5/foo/boo?hi=hi%20b&hey=ho",
     }
 }
+
+#[derive(UriDisplayPath, UriDisplayQuery)]
+struct ProductId(u64);
+
+impl<'v> rocket::form::FromFormField<'v> for ProductId {
+    fn from_value(field: rocket::form::ValueField<'v>) -> rocket::form::Result<'v, Self> {
+        u64::from_value(field).map(ProductId)
+    }
+}
+
+impl<'a> rocket::request::FromParam<'a> for ProductId {
+    type Error = std::num::ParseIntError;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        param.parse().map(ProductId)
+    }
+}
+
+#[derive(FromForm, UriDisplayQuery)]
+struct ProductQuery {
+    #[field(name = "product-id")]
+    id: ProductId,
+    #[field(name = "in-stock")]
+    in_stock: bool,
+}
+
+#[test]
+fn test_newtype_uri_parameters() {
+    #[get("/product/<id>?<related>")]
+    fn product(id: ProductId, related: Option<ProductId>) { }
+
+    #[get("/search?<query..>")]
+    fn search(query: ProductQuery) { }
+
+    let mut id = ProductId(7);
+    assert_uri_eq! {
+        uri!(product(id = ProductId(5), related = Some(ProductId(6)))) => "/product/5?related=6",
+        uri!(product(&ProductId(5), _)) => "/product/5",
+        uri!(product(&mut id, Some(&ProductId(8)))) => "/product/7?related=8",
+        uri!(search(ProductQuery { id: ProductId(1), in_stock: true }))
+            => "/search?product-id=1&in-stock=true",
+    }
+}
+
+#[derive(FromForm, UriDisplayQuery)]
+struct Filter<'r> {
+    color: Option<&'r str>,
+    sizes: Vec<u8>,
+    price: Option<Range>,
+}
+
+#[derive(FromForm, UriDisplayQuery)]
+struct Range {
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+#[get("/list?<page>&<tags>")]
+fn list(page: Option<usize>, tags: Vec<String>) { }
+
+#[get("/shop?sort&<filter..>")]
+fn sorted_shop(filter: Filter<'_>) { }
+
+#[get("/shop?<filter..>")]
+fn shop(filter: Filter<'_>) -> String {
+    let price = filter.price.map(|p| (p.min, p.max));
+    format!("{:?} {:?} {:?}", filter.color, filter.sizes, price)
+}
+
+#[test]
+fn test_omitted_query_parameters() {
+    let tags = vec!["a b".to_string(), "c".to_string()];
+    assert_uri_eq! {
+        uri!(list(None as Option<usize>, Vec::<String>::new())) => "/list",
+        uri!(list(_, _)) => "/list",
+        uri!(list(Some(2), Vec::<String>::new())) => "/list?page=2",
+        uri!(list(None as Option<usize>, vec!["x"])) => "/list?tags=x",
+        uri!(list(page = _, tags = &tags)) => "/list?tags=a%20b&tags=c",
+        uri!(list(Some(3), &tags[..1])) => "/list?page=3&tags=a%20b",
+    }
+
+    let empty = Filter { color: None, sizes: vec![], price: None };
+    let any_price = Some(Range { min: None, max: None });
+    let unbounded = Filter { color: None, sizes: vec![], price: any_price };
+    let bounded = Filter {
+        color: Some("red"),
+        sizes: vec![1, 2],
+        price: Some(Range { min: None, max: Some(10) }),
+    };
+
+    assert_uri_eq! {
+        uri!(sorted_shop(&empty)) => "/shop?sort",
+        uri!(sorted_shop(&unbounded)) => "/shop?sort",
+        uri!(sorted_shop(&bounded)) => "/shop?sort&color=red&sizes=1&sizes=2&price.max=10",
+    }
+}
+
+#[test]
+fn test_omitted_query_parameters_round_trip() {
+    use rocket::local::blocking::Client;
+
+    let client = Client::debug_with(routes![shop]).unwrap();
+    let price = Some(Range { min: Some(1), max: None });
+    let filter = Filter { color: None, sizes: vec![3, 4], price };
+    let response = client.get(uri!(shop(&filter))).dispatch();
+    assert_eq!(response.into_string().unwrap(), "None [3, 4] Some((Some(1), None))");
+}
+
+#[test]
+fn test_raw_parameters() {
+    use rocket::http::uri::fmt::Raw;
+
+    assert_uri_eq! {
+        uri!(simple2(1, Raw("a/b"))) => "/1/a/b",
+        uri!(simple2(1, Raw("a b/c?d#e"))) => "/1/a%20b/c%3Fd%23e",
+        uri!(simple2(1, Raw(String::from("a%2Fb%zz%")))) => "/1/a%2Fb%25zz%25",
+        uri!(simple4(1, Raw("x&y=z"))) => "/?id=1&name=x&y=z",
+        uri!(simple4(1, Raw("x y?/#"))) => "/?id=1&name=x%20y?/%23",
+        uri!(segments(Raw("a/../b"))) => "/a/a/../b",
+    }
+}
+
+mod named_routes {
+    #[get("/users/<id>?<tab>", name = "user_profile")]
+    pub fn profile(id: usize, tab: Option<&str>) { }
+
+    #[get("/users", name = "users")]
+    pub fn users() { }
+}
+
+#[test]
+fn test_named_routes() {
+    assert_uri_eq! {
+        uri!(name = "users") => "/users",
+        uri!(name = "user_profile"(5, Some("bio"))) => "/users/5?tab=bio",
+        uri!(name = "user_profile"(id = 5, tab = _)) => "/users/5",
+        uri!("/api", name = "user_profile"(id = 5, tab = _), "#top") => "/api/users/5#top",
+        uri!(named_routes::profile(5, _)) => "/users/5",
+        uri!(named_routes::users) => "/users",
+    }
+
+    let names: Vec<_> = routes![named_routes::profile, named_routes::users, index].into_iter()
+        .map(|route| route.name.unwrap())
+        .collect();
+
+    assert_eq!(names, ["user_profile", "users", "index"]);
+}
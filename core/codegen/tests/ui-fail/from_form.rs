@@ -201,4 +201,10 @@ struct Default5 {
     no_conversion_from_with: String,
 }
 
+#[derive(FromForm)]
+#[form(rename_all = "Title Case")]
+struct UnknownCase {
+    field: usize,
+}
+
 fn main() { }
@@ -107,4 +107,34 @@ fn f3() {}
 #[route(120, "/")]
 fn f4() {}
 
+// Check that `content_type` is validated properly.
+
+#[get("/", content_type = "")]
+fn g0() {}
+
+#[get("/", content_type = "image/")]
+fn g1() {}
+
+// Check that `name` is validated properly.
+
+#[get("/", name = "")]
+fn h0() {}
+
+#[get("/", name = "1st")]
+fn h1() {}
+
+#[get("/", name = "get-user")]
+fn h2() {}
+
+// Check that `cache` is validated properly.
+
+#[get("/", cache = "60")]
+fn i0() {}
+
+#[get("/", cache = "1d")]
+fn i1() {}
+
+#[get("/", cache = "0s")]
+fn i2() {}
+
 fn main() {}
@@ -0,0 +1,364 @@
+use std::fmt;
+use std::borrow::Cow;
+
+use crate::{Header, Status};
+
+/// A challenge in a `WWW-Authenticate` header.
+///
+/// A `401 Unauthorized` response must include a `WWW-Authenticate` header with
+/// at least one challenge indicating how the client can authenticate. Each
+/// challenge names an authentication scheme, such as `Basic` or `Bearer`,
+/// followed by parameters like the `realm` the credentials apply to, all as
+/// described in [RFC 7235 § 4.1]. A `Challenge` is built from its scheme and
+/// parameters and serialized with properly quoted and escaped values:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{Challenge, BearerError};
+///
+/// let basic = Challenge::basic("admin panel");
+/// assert_eq!(basic.to_string(), r#"Basic realm="admin panel", charset="UTF-8""#);
+///
+/// let bearer = Challenge::bearer()
+///     .realm("api")
+///     .error(BearerError::InsufficientScope)
+///     .scope(["read", "write"]);
+///
+/// assert_eq!(bearer.to_string(),
+///     r#"Bearer realm="api", error="insufficient_scope", scope="read write""#);
+/// ```
+///
+/// # Header
+///
+/// `Challenge` implements `Into<Header>` and can thus be used in any context
+/// where an `Into<Header>` is expected. To send several challenges, one for
+/// each supported scheme, use the [`Responder`] implementation for
+/// `(Vec<Challenge>, R)`, which adds one header per challenge:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{Header, Challenge};
+///
+/// let header: Header = Challenge::bearer().realm("api").into();
+/// assert_eq!(header.name(), "WWW-Authenticate");
+/// assert_eq!(header.value(), r#"Bearer realm="api""#);
+/// ```
+///
+/// [RFC 7235 § 4.1]: https://tools.ietf.org/html/rfc7235#section-4.1
+/// [`Responder`]: rocket::response::Responder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    scheme: Cow<'static, str>,
+    params: Vec<(Cow<'static, str>, String)>,
+}
+
+/// An error code in a `Bearer` challenge as defined in [RFC 6750 § 3.1].
+///
+/// Each error code corresponds to the status the response should have, as
+/// returned by [`BearerError::status()`].
+///
+/// [RFC 6750 § 3.1]: https://tools.ietf.org/html/rfc6750#section-3.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BearerError {
+    /// `invalid_request`: the request is missing a parameter or is otherwise
+    /// malformed.
+    InvalidRequest,
+    /// `invalid_token`: the access token is expired, revoked, malformed, or
+    /// invalid for other reasons.
+    InvalidToken,
+    /// `insufficient_scope`: the access token doesn't grant the privileges
+    /// the request requires.
+    InsufficientScope,
+}
+
+impl BearerError {
+    /// Returns the error code as it appears in a challenge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::BearerError;
+    ///
+    /// assert_eq!(BearerError::InvalidToken.as_str(), "invalid_token");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BearerError::InvalidRequest => "invalid_request",
+            BearerError::InvalidToken => "invalid_token",
+            BearerError::InsufficientScope => "insufficient_scope",
+        }
+    }
+
+    /// Returns the status a response with this error should have: `400 Bad
+    /// Request` for `invalid_request`, `401 Unauthorized` for
+    /// `invalid_token`, and `403 Forbidden` for `insufficient_scope`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{BearerError, Status};
+    ///
+    /// assert_eq!(BearerError::InvalidToken.status(), Status::Unauthorized);
+    /// assert_eq!(BearerError::InsufficientScope.status(), Status::Forbidden);
+    /// ```
+    pub fn status(&self) -> Status {
+        match self {
+            BearerError::InvalidRequest => Status::BadRequest,
+            BearerError::InvalidToken => Status::Unauthorized,
+            BearerError::InsufficientScope => Status::Forbidden,
+        }
+    }
+}
+
+impl fmt::Display for BearerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Challenge {
+    /// Returns a challenge for the authentication scheme `scheme` without any
+    /// parameters. `scheme` must be a valid HTTP token.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::new("Negotiate");
+    /// assert_eq!(challenge.to_string(), "Negotiate");
+    /// ```
+    pub fn new<S: Into<Cow<'static, str>>>(scheme: S) -> Challenge {
+        Challenge { scheme: scheme.into(), params: vec![] }
+    }
+
+    /// Returns a `Basic` challenge, as in [RFC 7617], for the realm `realm`.
+    /// The challenge indicates that credentials are to be encoded as UTF-8.
+    ///
+    /// [RFC 7617]: https://tools.ietf.org/html/rfc7617
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::basic("admin panel");
+    /// assert_eq!(challenge.to_string(), r#"Basic realm="admin panel", charset="UTF-8""#);
+    /// ```
+    pub fn basic<R: Into<String>>(realm: R) -> Challenge {
+        Challenge::new("Basic").realm(realm).param("charset", "UTF-8")
+    }
+
+    /// Returns a `Bearer` challenge, as in [RFC 6750], without any parameters.
+    ///
+    /// [RFC 6750]: https://tools.ietf.org/html/rfc6750
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Challenge, BearerError};
+    ///
+    /// let challenge = Challenge::bearer()
+    ///     .error(BearerError::InvalidToken)
+    ///     .error_description("The access token expired");
+    ///
+    /// assert_eq!(challenge.to_string(),
+    ///     r#"Bearer error="invalid_token", error_description="The access token expired""#);
+    /// ```
+    pub fn bearer() -> Challenge {
+        Challenge::new("Bearer")
+    }
+
+    /// Sets the `realm` parameter to `realm`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::bearer().realm("example");
+    /// assert_eq!(challenge.get("realm"), Some("example"));
+    /// ```
+    pub fn realm<R: Into<String>>(self, realm: R) -> Challenge {
+        self.param("realm", realm)
+    }
+
+    /// Sets the `error` parameter to the code of `error`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Challenge, BearerError};
+    ///
+    /// let challenge = Challenge::bearer().error(BearerError::InvalidRequest);
+    /// assert_eq!(challenge.get("error"), Some("invalid_request"));
+    /// ```
+    pub fn error(self, error: BearerError) -> Challenge {
+        self.param("error", error.as_str())
+    }
+
+    /// Sets the `error_description` parameter, a human-readable explanation
+    /// of the error, to `description`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Challenge, BearerError};
+    ///
+    /// let challenge = Challenge::bearer()
+    ///     .error(BearerError::InvalidToken)
+    ///     .error_description("token revoked");
+    ///
+    /// assert_eq!(challenge.get("error_description"), Some("token revoked"));
+    /// ```
+    pub fn error_description<D: Into<String>>(self, description: D) -> Challenge {
+        self.param("error_description", description)
+    }
+
+    /// Sets the `scope` parameter to the space-separated list of `scopes`,
+    /// typically the scopes required to access the resource.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::bearer().scope(["profile", "email"]);
+    /// assert_eq!(challenge.get("scope"), Some("profile email"));
+    /// ```
+    pub fn scope<I, S>(self, scopes: I) -> Challenge
+        where I: IntoIterator<Item = S>, S: AsRef<str>
+    {
+        let scopes: Vec<S> = scopes.into_iter().collect();
+        let scopes: Vec<&str> = scopes.iter().map(|s| s.as_ref()).collect();
+        self.param("scope", scopes.join(" "))
+    }
+
+    /// Sets the parameter `name` to `value`, replacing any existing value for
+    /// a parameter with the same name, compared case-insensitively. `name`
+    /// must be a valid HTTP token. The value is quoted when serialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::new("Digest")
+    ///     .realm("files")
+    ///     .param("nonce", "abc")
+    ///     .param("Nonce", "xyz");
+    ///
+    /// assert_eq!(challenge.to_string(), r#"Digest realm="files", Nonce="xyz""#);
+    /// ```
+    pub fn param<N, V>(mut self, name: N, value: V) -> Challenge
+        where N: Into<Cow<'static, str>>, V: Into<String>
+    {
+        let (name, value) = (name.into(), value.into());
+        match self.params.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(&name)) {
+            Some(param) => *param = (name, value),
+            None => self.params.push((name, value)),
+        }
+
+        self
+    }
+
+    /// Returns the authentication scheme of the challenge.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// assert_eq!(Challenge::basic("admin").scheme(), "Basic");
+    /// ```
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    /// Returns the value of the parameter `name`, compared case-insensitively,
+    /// if it is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Challenge;
+    ///
+    /// let challenge = Challenge::basic("admin");
+    /// assert_eq!(challenge.get("Realm"), Some("admin"));
+    /// assert_eq!(challenge.get("scope"), None);
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl fmt::Display for Challenge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.scheme)?;
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            f.write_str(if i == 0 { " " } else { ", " })?;
+            write!(f, "{}=\"", name)?;
+            for c in value.chars() {
+                if c == '"' || c == '\\' {
+                    f.write_str("\\")?;
+                }
+
+                write!(f, "{}", c)?;
+            }
+
+            f.write_str("\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a new `Header` with name `WWW-Authenticate` and the value set to
+/// the HTTP rendering of this `Challenge`.
+impl From<Challenge> for Header<'static> {
+    fn from(challenge: Challenge) -> Self {
+        Header::new("WWW-Authenticate", challenge.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Challenge, BearerError};
+
+    #[test]
+    fn test_challenge_escapes_values() {
+        let challenge = Challenge::basic(r#"the "admin" \ panel"#);
+        assert_eq!(challenge.to_string(),
+            r#"Basic realm="the \"admin\" \\ panel", charset="UTF-8""#);
+
+        let challenge = Challenge::new("Custom").param("a", "").param("b", "é");
+        assert_eq!(challenge.to_string(), r#"Custom a="", b="é""#);
+    }
+
+    #[test]
+    fn test_bearer_challenge_params() {
+        let challenge = Challenge::bearer()
+            .error(BearerError::InvalidRequest)
+            .realm("api")
+            .error(BearerError::InvalidToken)
+            .scope(Vec::<String>::new());
+
+        assert_eq!(challenge.to_string(), r#"Bearer error="invalid_token", realm="api", scope="""#);
+        assert_eq!(challenge.scheme(), "Bearer");
+        assert_eq!(challenge.get("ERROR"), Some("invalid_token"));
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::header::Header;
+
+/// Representation of an HTTP `Content-Range` header value in `bytes`.
+///
+/// A `Content-Range` identifies where in a complete representation the
+/// enclosed body belongs. The range is _inclusive_: `start` is the offset of
+/// the first byte and `end` is the offset of the last byte. The total length of
+/// the complete representation may be unknown, serialized as `*`.
+///
+/// Clients performing chunked or resumable uploads send a `Content-Range` with
+/// each `PUT` request. Servers send it with `206 Partial Content` responses.
+///
+/// # Header
+///
+/// `ContentRange` implements `Into<Header>` and can thus be used in any context
+/// where an `Into<Header>` is expected:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{Header, ContentRange};
+///
+/// let header: Header = ContentRange::new(0, 499, Some(1234)).unwrap().into();
+/// assert_eq!(header.value(), "bytes 0-499/1234");
+///
+/// let header: Header = ContentRange::new(500, 999, None).unwrap().into();
+/// assert_eq!(header.value(), "bytes 500-999/*");
+/// ```
+///
+/// # Parsing
+///
+/// A `ContentRange` can be parsed from a header value of the form
+/// `bytes start-end/total` or `bytes start-end/*` via [`FromStr`]. Ranges that
+/// are invalid, where `start > end` or `end >= total`, are rejected:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::ContentRange;
+///
+/// let range: ContentRange = "bytes 0-499/1234".parse().unwrap();
+/// assert_eq!((range.start(), range.end(), range.total()), (0, 499, Some(1234)));
+///
+/// let range: ContentRange = "bytes 500-999/*".parse().unwrap();
+/// assert_eq!(range.total(), None);
+///
+/// assert!("bytes 10-5/100".parse::<ContentRange>().is_err());
+/// assert!("bytes 0-100/100".parse::<ContentRange>().is_err());
+/// assert!("bytes */100".parse::<ContentRange>().is_err());
+/// assert!("items 0-5/10".parse::<ContentRange>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentRange {
+    start: u64,
+    end: u64,
+    total: Option<u64>,
+}
+
+impl ContentRange {
+    /// Returns a new `ContentRange` for the inclusive range `start` to `end`
+    /// of a representation of length `total`, if it is known. Returns `None`
+    /// if `start > end` or if `total` is known and `end >= total`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::ContentRange;
+    ///
+    /// assert!(ContentRange::new(0, 9, Some(10)).is_some());
+    /// assert!(ContentRange::new(0, 9, None).is_some());
+    ///
+    /// assert!(ContentRange::new(9, 0, None).is_none());
+    /// assert!(ContentRange::new(0, 10, Some(10)).is_none());
+    /// ```
+    pub fn new(start: u64, end: u64, total: Option<u64>) -> Option<ContentRange> {
+        if start > end || matches!(total, Some(total) if end >= total) {
+            return None;
+        }
+
+        Some(ContentRange { start, end, total })
+    }
+
+    /// Returns the offset of the first byte in the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::ContentRange;
+    ///
+    /// let range = ContentRange::new(100, 199, None).unwrap();
+    /// assert_eq!(range.start(), 100);
+    /// ```
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the offset of the last byte in the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::ContentRange;
+    ///
+    /// let range = ContentRange::new(100, 199, None).unwrap();
+    /// assert_eq!(range.end(), 199);
+    /// ```
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Returns the length of the complete representation, if it is known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::ContentRange;
+    ///
+    /// let range = ContentRange::new(100, 199, Some(1000)).unwrap();
+    /// assert_eq!(range.total(), Some(1000));
+    ///
+    /// let range = ContentRange::new(100, 199, None).unwrap();
+    /// assert_eq!(range.total(), None);
+    /// ```
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
+    /// Returns `true` if the range ends at the last byte of the complete
+    /// representation. Returns `false` if it doesn't or if the total length is
+    /// unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::ContentRange;
+    ///
+    /// assert!(ContentRange::new(900, 999, Some(1000)).unwrap().is_last());
+    /// assert!(!ContentRange::new(0, 99, Some(1000)).unwrap().is_last());
+    /// assert!(!ContentRange::new(900, 999, None).unwrap().is_last());
+    /// ```
+    pub fn is_last(&self) -> bool {
+        self.total == Some(self.end + 1)
+    }
+}
+
+impl FromStr for ContentRange {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<ContentRange, String> {
+        fn parse_pos(pos: &str) -> Option<u64> {
+            match !pos.is_empty() && pos.bytes().all(|b| b.is_ascii_digit()) {
+                true => pos.parse().ok(),
+                false => None,
+            }
+        }
+
+        fn split(string: &str, delim: char) -> Option<(&str, &str)> {
+            let i = string.find(delim)?;
+            Some((&string[..i], &string[(i + 1)..]))
+        }
+
+        let invalid = || format!("invalid Content-Range value: {:?}", raw);
+        let range = raw.trim().strip_prefix("bytes ").ok_or_else(invalid)?;
+        let (range, total) = match split(range, '/') {
+            Some((range, "*")) => (range, None),
+            Some((range, total)) => (range, Some(parse_pos(total).ok_or_else(invalid)?)),
+            None => return Err(invalid()),
+        };
+
+        let (start, end) = split(range, '-').ok_or_else(invalid)?;
+        let start = parse_pos(start).ok_or_else(invalid)?;
+        let end = parse_pos(end).ok_or_else(invalid)?;
+        ContentRange::new(start, end, total)
+            .ok_or_else(|| format!("invalid Content-Range range: {:?}", raw))
+    }
+}
+
+impl fmt::Display for ContentRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bytes {}-{}/", self.start, self.end)?;
+        match self.total {
+            Some(total) => write!(f, "{}", total),
+            None => f.write_str("*"),
+        }
+    }
+}
+
+/// Creates a new `Header` with name `Content-Range` and the value set to the
+/// HTTP rendering of this `ContentRange`.
+impl From<ContentRange> for Header<'static> {
+    fn from(range: ContentRange) -> Self {
+        Header::new("Content-Range", range.to_string())
+    }
+}
@@ -0,0 +1,640 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::ext::IntoCollection;
+use crate::header::Header;
+use crate::uri::Reference;
+
+/// A `Content-Security-Policy` (CSP) header value.
+///
+/// A `Csp` is an ordered set of _directives_, each of which restricts where a
+/// kind of resource may be loaded from to a list of [`CspSource`]s. Policies
+/// are built via a method per directive, each of which replaces any previous
+/// value of the directive, or via [`Csp::directive()`] for any other
+/// directive:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{Csp, CspSource};
+///
+/// let csp = Csp::new()
+///     .default_src(CspSource::This)
+///     .script_src([CspSource::This, CspSource::host("https://cdn.rocket.rs")])
+///     .style_src([CspSource::This, CspSource::UnsafeInline])
+///     .object_src(CspSource::None)
+///     .report_uri(uri!("/csp-reports"));
+///
+/// assert_eq!(csp.to_string(), "default-src 'self'; \
+///     script-src 'self' https://cdn.rocket.rs; \
+///     style-src 'self' 'unsafe-inline'; \
+///     object-src 'none'; \
+///     report-uri /csp-reports");
+/// ```
+///
+/// # Header
+///
+/// `Csp` implements `Into<Header>`, rendering a `Content-Security-Policy`
+/// header. To set the header on a single response, return `(Csp, R)` for any
+/// responder `R`. To set it on all responses, enable the policy in
+/// [`Shield`](../shield/struct.Shield.html).
+///
+/// # Nonces
+///
+/// A strict policy allows only those inline scripts and styles that carry a
+/// nonce that is unique to each response. [`CspSource::RequestNonce`] stands
+/// in for the nonce of the request being responded to. When the policy is
+/// rendered by `Shield` or by the `(Csp, R)` responder, it is replaced by the
+/// request's [`CspNonce`](../shield/struct.CspNonce.html), which handlers
+/// retrieve as a request guard and pass to templates. Elsewhere, it can be
+/// replaced via [`Csp::with_nonce()`]; a policy rendered without a nonce omits
+/// the source.
+///
+/// # Parsing
+///
+/// A `Csp` can be parsed from a header value via [`FromStr`]. As required by
+/// the CSP specification, only the first occurrence of a directive counts:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{Csp, CspSource};
+///
+/// let csp: Csp = "script-src 'self' 'nonce-a1b2'; img-src *; script-src *".parse().unwrap();
+/// assert_eq!(csp.get("script-src").unwrap(), &[CspSource::This, CspSource::nonce("a1b2")]);
+/// assert_eq!(csp.get("IMG-SRC").unwrap(), &[CspSource::Any]);
+/// assert!(csp.get("style-src").is_none());
+///
+/// assert!("script-src 'unknown'".parse::<Csp>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Csp {
+    directives: Vec<(Cow<'static, str>, Vec<CspSource>)>,
+}
+
+/// A source in the source list of a [`Csp`] directive.
+///
+/// Sources with values are most easily constructed via [`CspSource::host()`],
+/// [`CspSource::scheme()`], and [`CspSource::nonce()`], which check that the
+/// value is valid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CspSource {
+    /// `'none'`: no source. Must be the only source in its list.
+    None,
+    /// `'self'`: the origin the document was served from.
+    This,
+    /// `*`: any URL, except those with a `data:`, `blob:`, or `filesystem:`
+    /// scheme.
+    Any,
+    /// `'unsafe-inline'`: inline scripts, styles, and event handlers.
+    UnsafeInline,
+    /// `'unsafe-eval'`: dynamic code evaluation, such as `eval()`.
+    UnsafeEval,
+    /// `'unsafe-hashes'`: event handlers matching a hash source.
+    UnsafeHashes,
+    /// `'strict-dynamic'`: scripts loaded by already allowed scripts.
+    StrictDynamic,
+    /// `'report-sample'`: include a sample of the violating code in reports.
+    ReportSample,
+    /// `'nonce-<value>'`: inline elements carrying the nonce `value`.
+    Nonce(Cow<'static, str>),
+    /// The per-request nonce, rendered as `'nonce-<value>'` with the nonce of
+    /// the request being responded to.
+    RequestNonce,
+    /// `'sha256-<value>'`: inline elements whose SHA-256 digest is the
+    /// base64-encoded `value`.
+    Sha256(Cow<'static, str>),
+    /// `'sha384-<value>'`: inline elements whose SHA-384 digest is the
+    /// base64-encoded `value`.
+    Sha384(Cow<'static, str>),
+    /// `'sha512-<value>'`: inline elements whose SHA-512 digest is the
+    /// base64-encoded `value`.
+    Sha512(Cow<'static, str>),
+    /// `<scheme>:`: any URL with the scheme, such as `https` or `data`.
+    Scheme(Cow<'static, str>),
+    /// A host or URL, such as `https://cdn.rocket.rs` or `*.rocket.rs`, or,
+    /// in directives that don't take sources, such as `report-uri`, a literal
+    /// value.
+    Host(Cow<'static, str>),
+}
+
+/// Whether `value` may appear unquoted in a policy.
+fn is_valid_value(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|b| {
+        b.is_ascii_graphic() && !matches!(b, b';' | b',' | b'\'')
+    })
+}
+
+/// Whether `value` is valid base64, or base64url, as used by nonces and hashes.
+fn is_valid_base64(value: &str) -> bool {
+    let data = value.trim_end_matches('=');
+    !data.is_empty()
+        && value.len() - data.len() <= 2
+        && data.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'/' | b'-' | b'_'))
+}
+
+/// Whether `name` is a valid directive name.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Whether `scheme` is a valid URI scheme.
+fn is_valid_scheme(scheme: &str) -> bool {
+    let mut bytes = scheme.bytes();
+    bytes.next().map(|b| b.is_ascii_alphabetic()).unwrap_or(false)
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+impl CspSource {
+    /// Returns a host or URL source for `host`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `host` is empty or contains whitespace, control characters,
+    /// `;`, `,`, or `'`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::CspSource;
+    ///
+    /// let source = CspSource::host("https://*.rocket.rs:443");
+    /// ```
+    pub fn host<H: Into<Cow<'static, str>>>(host: H) -> CspSource {
+        let host = host.into();
+        assert!(is_valid_value(&host), "invalid CSP host source: {:?}", host);
+        CspSource::Host(host)
+    }
+
+    /// Returns a scheme source for `scheme`, with or without its trailing `:`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scheme` is not a valid URI scheme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::CspSource;
+    ///
+    /// assert_eq!(CspSource::scheme("data:"), CspSource::scheme("data"));
+    /// ```
+    pub fn scheme<S: Into<Cow<'static, str>>>(scheme: S) -> CspSource {
+        let scheme = match scheme.into() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.strip_suffix(':').unwrap_or(s)),
+            Cow::Owned(s) => Cow::Owned(s.strip_suffix(':').unwrap_or(&s).to_string()),
+        };
+
+        assert!(is_valid_scheme(&scheme), "invalid CSP scheme source: {:?}", scheme);
+        CspSource::Scheme(scheme)
+    }
+
+    /// Returns a nonce source for the nonce `nonce`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nonce` is not valid base64 or base64url.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().script_src(CspSource::nonce("cm9ja2V0"));
+    /// assert_eq!(csp.to_string(), "script-src 'nonce-cm9ja2V0'");
+    /// ```
+    pub fn nonce<N: Into<Cow<'static, str>>>(nonce: N) -> CspSource {
+        let nonce = nonce.into();
+        assert!(is_valid_base64(&nonce), "invalid CSP nonce: {:?}", nonce);
+        CspSource::Nonce(nonce)
+    }
+
+    /// Returns `true` if `self` is valid, that is, if its value, if any, would
+    /// be accepted by the checked constructors.
+    fn is_valid(&self) -> bool {
+        use CspSource::*;
+
+        match self {
+            Nonce(v) | Sha256(v) | Sha384(v) | Sha512(v) => is_valid_base64(v),
+            Scheme(v) => is_valid_scheme(v),
+            Host(v) => is_valid_value(v),
+            _ => true,
+        }
+    }
+
+    fn render(&self, f: &mut fmt::Formatter<'_>, nonce: Option<&str>) -> fmt::Result {
+        use CspSource::*;
+
+        match self {
+            None => f.write_str("'none'"),
+            This => f.write_str("'self'"),
+            Any => f.write_str("*"),
+            UnsafeInline => f.write_str("'unsafe-inline'"),
+            UnsafeEval => f.write_str("'unsafe-eval'"),
+            UnsafeHashes => f.write_str("'unsafe-hashes'"),
+            StrictDynamic => f.write_str("'strict-dynamic'"),
+            ReportSample => f.write_str("'report-sample'"),
+            Nonce(v) => write!(f, "'nonce-{}'", v),
+            RequestNonce => write!(f, "'nonce-{}'", nonce.unwrap_or_default()),
+            Sha256(v) => write!(f, "'sha256-{}'", v),
+            Sha384(v) => write!(f, "'sha384-{}'", v),
+            Sha512(v) => write!(f, "'sha512-{}'", v),
+            Scheme(v) => write!(f, "{}:", v),
+            Host(v) => f.write_str(v),
+        }
+    }
+}
+
+impl FromStr for CspSource {
+    type Err = String;
+
+    /// Parses a single source, such as `'self'` or `https://rocket.rs`.
+    fn from_str(s: &str) -> Result<CspSource, String> {
+        use CspSource::*;
+
+        let error = || format!("invalid CSP source: {:?}", s);
+        let source = match s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+            Some(keyword) => {
+                let lower = keyword.to_ascii_lowercase();
+                let value = |prefix: &str| Cow::Owned(keyword[prefix.len()..].to_string());
+                match lower.as_str() {
+                    "none" => None,
+                    "self" => This,
+                    "unsafe-inline" => UnsafeInline,
+                    "unsafe-eval" => UnsafeEval,
+                    "unsafe-hashes" => UnsafeHashes,
+                    "strict-dynamic" => StrictDynamic,
+                    "report-sample" => ReportSample,
+                    k if k.starts_with("nonce-") => Nonce(value("nonce-")),
+                    k if k.starts_with("sha256-") => Sha256(value("sha256-")),
+                    k if k.starts_with("sha384-") => Sha384(value("sha384-")),
+                    k if k.starts_with("sha512-") => Sha512(value("sha512-")),
+                    _ => return Err(error()),
+                }
+            }
+            Option::None if s == "*" => Any,
+            Option::None => match s.strip_suffix(':') {
+                Some(scheme) if is_valid_scheme(scheme) => Scheme(scheme.to_string().into()),
+                _ => Host(s.to_string().into()),
+            }
+        };
+
+        match source.is_valid() {
+            true => Ok(source),
+            false => Err(error()),
+        }
+    }
+}
+
+macro_rules! directives {
+    ($($(#[$attr:meta])* $method:ident => $name:literal),* $(,)?) => ($(
+        $(#[$attr])*
+        ///
+        /// `sources` may be a single [`CspSource`], an array or slice of them,
+        /// or a vector. The value replaces any previous value of the
+        /// directive.
+        ///
+        /// # Panics
+        ///
+        /// Panics if a source is invalid. See [`CspSource`] for details.
+        pub fn $method<S: IntoCollection<CspSource>>(self, sources: S) -> Csp {
+            self.directive($name, sources)
+        }
+    )*)
+}
+
+impl Csp {
+    /// Returns an empty policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Csp;
+    ///
+    /// let csp = Csp::new();
+    /// assert!(csp.is_empty());
+    /// ```
+    pub fn new() -> Csp {
+        Csp::default()
+    }
+
+    directives! {
+        /// Sets the `default-src` directive, the fallback for every other
+        /// fetch directive.
+        default_src => "default-src",
+        /// Sets the `script-src` directive, where scripts may be loaded from.
+        script_src => "script-src",
+        /// Sets the `style-src` directive, where stylesheets may be loaded
+        /// from.
+        style_src => "style-src",
+        /// Sets the `img-src` directive, where images may be loaded from.
+        img_src => "img-src",
+        /// Sets the `font-src` directive, where fonts may be loaded from.
+        font_src => "font-src",
+        /// Sets the `connect-src` directive, which URLs scripts may connect to.
+        connect_src => "connect-src",
+        /// Sets the `media-src` directive, where audio and video may be
+        /// loaded from.
+        media_src => "media-src",
+        /// Sets the `object-src` directive, where plugins may be loaded from.
+        object_src => "object-src",
+        /// Sets the `frame-src` directive, where frames may be loaded from.
+        frame_src => "frame-src",
+        /// Sets the `worker-src` directive, where workers may be loaded from.
+        worker_src => "worker-src",
+        /// Sets the `base-uri` directive, which URLs a `<base>` may use.
+        base_uri => "base-uri",
+        /// Sets the `form-action` directive, which URLs forms may submit to.
+        form_action => "form-action",
+        /// Sets the `frame-ancestors` directive, which pages may embed this
+        /// one.
+        frame_ancestors => "frame-ancestors",
+    }
+
+    /// Sets the directive `name` to `sources`, replacing any previous value of
+    /// the directive. Directive names are compared case-insensitively.
+    ///
+    /// `sources` may be a single [`CspSource`], an array or slice of them, or
+    /// a vector. Directives without a value, such as
+    /// `upgrade-insecure-requests`, take an empty vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid directive name, that is, if it is empty
+    /// or contains a character other than an ASCII alphanumeric or `-`, or if
+    /// a source is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new()
+    ///     .directive("manifest-src", CspSource::This)
+    ///     .directive("block-all-mixed-content", vec![]);
+    ///
+    /// assert_eq!(csp.to_string(), "manifest-src 'self'; block-all-mixed-content");
+    /// ```
+    pub fn directive<N, S>(mut self, name: N, sources: S) -> Csp
+        where N: Into<Cow<'static, str>>, S: IntoCollection<CspSource>
+    {
+        let name = name.into();
+        assert!(is_valid_name(&name), "invalid CSP directive name: {:?}", name);
+
+        let sources = sources.mapped_vec(|source| source);
+        if let Some(invalid) = sources.iter().find(|s| !s.is_valid()) {
+            panic!("invalid CSP source: {:?}", invalid);
+        }
+
+        match self.directives.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(&name)) {
+            Some(directive) => directive.1 = sources,
+            None => self.directives.push((name, sources)),
+        }
+
+        self
+    }
+
+    /// Sets the `report-uri` directive, where violation reports are sent to.
+    ///
+    /// The characters `;`, `,`, and `'`, which are valid in a URI but delimit
+    /// values in a policy, are percent-encoded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Csp;
+    ///
+    /// let csp = Csp::new().report_uri(uri!("https://rocket.rs/csp"));
+    /// assert_eq!(csp.to_string(), "report-uri https://rocket.rs/csp");
+    ///
+    /// let csp = Csp::new().report_uri(uri!("https://rocket.rs/csp;v=1?a,b='c'"));
+    /// assert_eq!(csp.to_string(), "report-uri https://rocket.rs/csp%3Bv=1?a%2Cb=%27c%27");
+    /// ```
+    pub fn report_uri<U: Into<Reference<'static>>>(self, uri: U) -> Csp {
+        let uri = uri.into().to_string()
+            .replace(';', "%3B")
+            .replace(',', "%2C")
+            .replace('\'', "%27");
+
+        self.directive("report-uri", CspSource::host(uri))
+    }
+
+    /// Sets the `report-to` directive, the name of the reporting endpoint
+    /// group violation reports are sent to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group` is not a valid value. See [`CspSource::host()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Csp;
+    ///
+    /// let csp = Csp::new().report_to("csp-endpoint");
+    /// assert_eq!(csp.to_string(), "report-to csp-endpoint");
+    /// ```
+    pub fn report_to<G: Into<Cow<'static, str>>>(self, group: G) -> Csp {
+        self.directive("report-to", CspSource::host(group))
+    }
+
+    /// Sets the `upgrade-insecure-requests` directive, which instructs
+    /// browsers to fetch `http:` URLs via `https:`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Csp;
+    ///
+    /// let csp = Csp::new().upgrade_insecure_requests();
+    /// assert_eq!(csp.to_string(), "upgrade-insecure-requests");
+    /// ```
+    pub fn upgrade_insecure_requests(self) -> Csp {
+        self.directive("upgrade-insecure-requests", vec![])
+    }
+
+    /// Returns the sources of the directive `name`, if it is set. Directive
+    /// names are compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().img_src([CspSource::This, CspSource::scheme("data")]);
+    /// assert_eq!(csp.get("img-src").unwrap(), &[CspSource::This, CspSource::scheme("data")]);
+    /// assert!(csp.get("font-src").is_none());
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&[CspSource]> {
+        self.directives.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, sources)| &sources[..])
+    }
+
+    /// Returns an iterator over the directives, in order, and their sources.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().default_src(CspSource::None).img_src(CspSource::This);
+    /// let names: Vec<_> = csp.iter().map(|(name, _)| name).collect();
+    /// assert_eq!(names, ["default-src", "img-src"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[CspSource])> {
+        self.directives.iter().map(|(k, v)| (k.as_ref(), &v[..]))
+    }
+
+    /// Returns `true` if the policy has no directives.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// assert!(Csp::new().is_empty());
+    /// assert!(!Csp::new().default_src(CspSource::This).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Returns `true` if a directive contains [`CspSource::RequestNonce`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().script_src(CspSource::This);
+    /// assert!(!csp.uses_request_nonce());
+    ///
+    /// let csp = csp.style_src(CspSource::RequestNonce);
+    /// assert!(csp.uses_request_nonce());
+    /// ```
+    pub fn uses_request_nonce(&self) -> bool {
+        self.directives.iter()
+            .flat_map(|(_, sources)| sources)
+            .any(|source| *source == CspSource::RequestNonce)
+    }
+
+    /// Returns the policy rendered with every [`CspSource::RequestNonce`] as
+    /// a nonce source for `nonce`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nonce` is not valid base64 or base64url.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().script_src([CspSource::This, CspSource::RequestNonce]);
+    /// assert_eq!(csp.to_string(), "script-src 'self'");
+    /// assert_eq!(csp.with_nonce("cm9ja2V0"), "script-src 'self' 'nonce-cm9ja2V0'");
+    /// ```
+    pub fn with_nonce(&self, nonce: &str) -> String {
+        assert!(is_valid_base64(nonce), "invalid CSP nonce: {:?}", nonce);
+        Rendered(self, Some(nonce)).to_string()
+    }
+
+    /// Returns the `Content-Security-Policy` header for the policy rendered
+    /// as by [`Csp::with_nonce()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{Csp, CspSource};
+    ///
+    /// let csp = Csp::new().style_src(CspSource::RequestNonce);
+    /// let header = csp.header_with_nonce("cm9ja2V0");
+    /// assert_eq!(header.name(), "Content-Security-Policy");
+    /// assert_eq!(header.value(), "style-src 'nonce-cm9ja2V0'");
+    /// ```
+    pub fn header_with_nonce(&self, nonce: &str) -> Header<'static> {
+        Header::new("Content-Security-Policy", self.with_nonce(nonce))
+    }
+}
+
+/// A policy rendered with an optional request nonce.
+struct Rendered<'a>(&'a Csp, Option<&'a str>);
+
+impl fmt::Display for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rendered(csp, nonce) = *self;
+        for (i, (name, sources)) in csp.directives.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+
+            f.write_str(name)?;
+            for source in sources {
+                if *source == CspSource::RequestNonce && nonce.is_none() {
+                    continue;
+                }
+
+                f.write_str(" ")?;
+                source.render(f, nonce)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Csp {
+    /// Renders the policy, omitting every [`CspSource::RequestNonce`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Rendered(self, None).fmt(f)
+    }
+}
+
+impl FromStr for Csp {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Csp, String> {
+        let mut csp = Csp::new();
+        for directive in raw.split(';') {
+            let mut tokens = directive.split_ascii_whitespace();
+            let name = match tokens.next() {
+                Some(name) if is_valid_name(name) => name,
+                Some(name) => return Err(format!("invalid CSP directive name: {:?}", name)),
+                None => continue,
+            };
+
+            let sources = tokens.map(|t| t.parse()).collect::<Result<Vec<_>, _>>()?;
+            if csp.get(name).is_none() {
+                csp.directives.push((name.to_string().into(), sources));
+            }
+        }
+
+        Ok(csp)
+    }
+}
+
+impl From<&Csp> for Header<'static> {
+    fn from(csp: &Csp) -> Self {
+        Header::new("Content-Security-Policy", csp.to_string())
+    }
+}
+
+impl From<Csp> for Header<'static> {
+    fn from(csp: Csp) -> Self {
+        Header::from(&csp)
+    }
+}
@@ -122,6 +122,51 @@ impl<'h> Header<'h> {
         allow_empty || i > 0
     }
 
+    /// Returns `true` if `name` is a valid header name.
+    ///
+    /// This implements a simple (i.e, correct but not particularly performant)
+    /// header "field-name" checker as defined in RFC 7230:
+    ///
+    ///   field-name     = token
+    ///   token          = 1*tchar
+    ///   tchar          = "!" / "#" / "$" / "%" / "&" / "'" / "*" / "+" / "-"
+    ///                  / "." / "^" / "_" / "`" / "|" / "~" / DIGIT / ALPHA
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Header;
+    ///
+    /// assert!(!Header::is_valid_name(""));
+    /// assert!(!Header::is_valid_name("X App"));
+    /// assert!(!Header::is_valid_name("X-App:"));
+    /// assert!(!Header::is_valid_name("X-App\n"));
+    ///
+    /// assert!(Header::is_valid_name("X-App-Version"));
+    /// assert!(Header::is_valid_name("x_app.version"));
+    /// ```
+    #[doc(hidden)]
+    pub const fn is_valid_name(name: &str) -> bool {
+        const fn is_tchar(b: &u8) -> bool {
+            b.is_ascii_alphanumeric() || matches!(*b,
+                b'!' | b'#' | b'$' | b'%' | b'&' | b'\'' | b'*' | b'+' | b'-' |
+                b'.' | b'^' | b'_' | b'`' | b'|' | b'~')
+        }
+
+        let mut i = 0;
+        let bytes = name.as_bytes();
+        while i < bytes.len() {
+            if !is_tchar(&bytes[i]) {
+                return false;
+            }
+
+            i += 1;
+        }
+
+        i > 0
+    }
+
     /// Returns the name of this header.
     ///
     /// # Example
@@ -550,6 +595,17 @@ impl<'h> HeaderMap<'h> {
         self.headers.swap_remove(UncasedStr::new(name));
     }
 
+    /// Removes and returns all of the values of the header named `name`, in
+    /// FIFO order, preserving the order of the remaining headers.
+    pub(crate) fn take(&mut self, name: &str) -> Vec<Header<'h>> {
+        match self.headers.shift_remove_entry(UncasedStr::new(name)) {
+            Some((name, values)) => values.into_iter()
+                .map(|value| Header { name: name.clone(), value })
+                .collect(),
+            None => vec![],
+        }
+    }
+
     /// Removes all of the headers stored in this map and returns a vector
     /// containing them. Header names are returned in no specific order, but all
     /// values for a given header name are grouped together, and values are in
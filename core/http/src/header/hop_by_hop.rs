@@ -0,0 +1,280 @@
+use crate::header::HeaderMap;
+
+/// The hop-by-hop headers that are removed whether or not they're listed in
+/// `Connection`: those of RFC 7230 §6.1 and RFC 2616 §13.5.1 as well as the
+/// non-standard but common `Proxy-Connection`.
+const HOP_BY_HOP: &[&str] = &[
+    "Connection", "Keep-Alive", "Proxy-Connection", "Proxy-Authenticate",
+    "Proxy-Authorization", "TE", "Trailer", "Transfer-Encoding", "Upgrade",
+];
+
+/// The connection-specific headers removed from a request or response so that
+/// it can be forwarded by a proxy.
+///
+/// As per [RFC 7230 §6.1], a proxy must not forward headers that only apply
+/// to the connection they were received on. These are the standard hop-by-hop
+/// headers, `Connection`, `Keep-Alive`, `Proxy-Connection`,
+/// `Proxy-Authenticate`, `Proxy-Authorization`, `TE`, `Trailer`,
+/// `Transfer-Encoding`, and `Upgrade`, as well as any header named as an
+/// option in `Connection`. [`HopByHop::strip_request()`] and
+/// [`HopByHop::strip_response()`] remove all such headers from a
+/// [`HeaderMap`], leaving only headers that can be forwarded, and return them
+/// as a `HopByHop`. The removed headers remain available via
+/// [`HopByHop::headers()`], while the methods on `HopByHop` interpret those
+/// that typically inform how the proxy itself handles the message.
+///
+/// [RFC 7230 §6.1]: https://tools.ietf.org/html/rfc7230#section-6.1
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{HeaderMap, HopByHop};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.add_raw("Host", "rocket.rs");
+/// headers.add_raw("Connection", "keep-alive, X-Hop");
+/// headers.add_raw("Keep-Alive", "timeout=5");
+/// headers.add_raw("X-Hop", "only for this connection");
+/// headers.add_raw("TE", "trailers");
+///
+/// let hop_by_hop = HopByHop::strip_request(&mut headers);
+/// assert_eq!(headers.len(), 1);
+/// assert_eq!(headers.get_one("Host"), Some("rocket.rs"));
+///
+/// assert!(hop_by_hop.accepts_trailers());
+/// assert!(hop_by_hop.headers().contains("X-Hop"));
+/// assert_eq!(hop_by_hop.connection_options().collect::<Vec<_>>(), ["keep-alive", "X-Hop"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HopByHop<'h> {
+    headers: HeaderMap<'h>,
+}
+
+impl<'h> HopByHop<'h> {
+    /// Removes every hop-by-hop header from the request headers `headers`,
+    /// leaving only those that can be forwarded to an upstream server, and
+    /// returns the removed headers.
+    ///
+    /// In addition to the hop-by-hop headers, the `Expect` header is removed.
+    /// A proxy fulfills a `100-continue` expectation on its own: by the time
+    /// a handler has the request body to forward, the client has already been
+    /// asked to send it. Whether continuation was expected is reported by
+    /// [`HopByHop::expects_continue()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Content-Type", "text/plain");
+    /// headers.add_raw("Expect", "100-continue");
+    /// headers.add_raw("Transfer-Encoding", "chunked");
+    ///
+    /// let hop_by_hop = HopByHop::strip_request(&mut headers);
+    /// assert_eq!(headers.len(), 1);
+    /// assert!(hop_by_hop.expects_continue());
+    /// ```
+    pub fn strip_request(headers: &mut HeaderMap<'h>) -> HopByHop<'h> {
+        let mut hop_by_hop = HopByHop::strip_response(headers);
+        hop_by_hop.take(headers, "Expect");
+        hop_by_hop
+    }
+
+    /// Removes every hop-by-hop header from the response headers `headers`,
+    /// leaving only those that can be forwarded to the client, and returns the
+    /// removed headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Content-Type", "text/plain");
+    /// headers.add_raw("Connection", "close");
+    /// headers.add_raw("Proxy-Authenticate", "Basic realm=\"upstream\"");
+    ///
+    /// let hop_by_hop = HopByHop::strip_response(&mut headers);
+    /// assert_eq!(headers.len(), 1);
+    /// assert!(hop_by_hop.is_close());
+    /// ```
+    pub fn strip_response(headers: &mut HeaderMap<'h>) -> HopByHop<'h> {
+        let options: Vec<String> = headers.get("Connection")
+            .flat_map(|value| value.split(','))
+            .map(|option| option.trim().to_string())
+            .filter(|option| !option.is_empty())
+            .collect();
+
+        let mut hop_by_hop = HopByHop::default();
+        for name in HOP_BY_HOP.iter().copied().chain(options.iter().map(|s| s.as_str())) {
+            hop_by_hop.take(headers, name);
+        }
+
+        hop_by_hop
+    }
+
+    /// Moves all headers named `name` from `headers` into `self`.
+    fn take(&mut self, headers: &mut HeaderMap<'h>, name: &str) {
+        for header in headers.take(name) {
+            self.headers.add(header);
+        }
+    }
+
+    /// Returns the headers that were removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Upgrade", "websocket");
+    ///
+    /// let hop_by_hop = HopByHop::strip_request(&mut headers);
+    /// assert_eq!(hop_by_hop.headers().get_one("Upgrade"), Some("websocket"));
+    /// ```
+    pub fn headers(&self) -> &HeaderMap<'h> {
+        &self.headers
+    }
+
+    /// Returns an iterator over the connection options in the removed
+    /// `Connection` headers, in the order in which they appeared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Connection", "Upgrade");
+    /// headers.add_raw("Connection", "X-Trace, ");
+    ///
+    /// let hop_by_hop = HopByHop::strip_request(&mut headers);
+    /// let options: Vec<_> = hop_by_hop.connection_options().collect();
+    /// assert_eq!(options, ["Upgrade", "X-Trace"]);
+    /// ```
+    pub fn connection_options(&self) -> impl Iterator<Item = &str> {
+        self.headers.get("Connection")
+            .flat_map(|value| value.split(','))
+            .map(|option| option.trim())
+            .filter(|option| !option.is_empty())
+    }
+
+    /// Returns `true` if the `Connection` header has the `close` option, that
+    /// is, if the sender will close the connection after this message.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Connection", "Close");
+    ///
+    /// let hop_by_hop = HopByHop::strip_response(&mut headers);
+    /// assert!(hop_by_hop.is_close());
+    /// ```
+    pub fn is_close(&self) -> bool {
+        self.connection_options().any(|option| option.eq_ignore_ascii_case("close"))
+    }
+
+    /// Returns `true` if a removed `TE` header lists `trailers`, indicating
+    /// that the client is willing to accept trailer fields in a chunked
+    /// response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("TE", "gzip;q=0.5, trailers");
+    ///
+    /// let hop_by_hop = HopByHop::strip_request(&mut headers);
+    /// assert!(hop_by_hop.accepts_trailers());
+    /// ```
+    pub fn accepts_trailers(&self) -> bool {
+        self.headers.get("TE")
+            .flat_map(|value| value.split(','))
+            .filter_map(|coding| coding.split(';').next())
+            .any(|coding| coding.trim().eq_ignore_ascii_case("trailers"))
+    }
+
+    /// Returns `true` if a removed `Expect` header is `100-continue`. Only
+    /// [`HopByHop::strip_request()`] removes `Expect`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::{HeaderMap, HopByHop};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.add_raw("Expect", "100-Continue");
+    ///
+    /// let hop_by_hop = HopByHop::strip_request(&mut headers);
+    /// assert!(hop_by_hop.expects_continue());
+    /// ```
+    pub fn expects_continue(&self) -> bool {
+        self.headers.get("Expect").any(|value| value.trim().eq_ignore_ascii_case("100-continue"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HopByHop;
+    use crate::header::HeaderMap;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap<'static> {
+        let mut map = HeaderMap::new();
+        pairs.iter().for_each(|(name, value)| map.add_raw(*name, *value));
+        map
+    }
+
+    #[test]
+    fn test_strips_hop_by_hop_and_connection_options() {
+        let mut map = headers(&[
+            ("Host", "rocket.rs"),
+            ("connection", "Keep-Alive, x-foo"),
+            ("Connection", "X-Bar"),
+            ("KEEP-ALIVE", "timeout=5"),
+            ("X-Foo", "1"),
+            ("x-bar", "2"),
+            ("X-Baz", "3"),
+            ("Transfer-Encoding", "chunked"),
+            ("Proxy-Authorization", "Basic Zm9vOmJhcg=="),
+            ("Expect", "100-continue"),
+        ]);
+
+        let mut response = map.clone();
+        let hop_by_hop = HopByHop::strip_request(&mut map);
+        let names: Vec<_> = map.iter().map(|h| h.name.to_string()).collect();
+        assert_eq!(names, ["Host", "X-Baz"]);
+        assert_eq!(hop_by_hop.headers().len(), 8);
+        assert_eq!(hop_by_hop.headers().get("Connection").count(), 2);
+        assert!(!hop_by_hop.is_close());
+
+        let hop_by_hop = HopByHop::strip_response(&mut response);
+        assert_eq!(response.get_one("Expect"), Some("100-continue"));
+        assert!(!hop_by_hop.expects_continue());
+    }
+
+    #[test]
+    fn test_forwardable_headers_are_untouched() {
+        let mut map = headers(&[("Content-Type", "text/plain"), ("Authorization", "x")]);
+        let original = map.clone();
+        let hop_by_hop = HopByHop::strip_response(&mut map);
+        assert_eq!(map, original);
+        assert!(hop_by_hop.headers().is_empty());
+        assert!(!hop_by_hop.accepts_trailers());
+        assert_eq!(hop_by_hop.connection_options().count(), 0);
+    }
+}
@@ -8,6 +8,7 @@ macro_rules! known_media_types {
         Text (is_text): "plain text", "text", "plain" ; "charset" => "utf-8",
         JSON (is_json): "JSON", "application", "json",
         MsgPack (is_msgpack): "MsgPack", "application", "msgpack",
+        CBOR (is_cbor): "CBOR", "application", "cbor",
         Form (is_form): "forms", "application", "x-www-form-urlencoded",
         JavaScript (is_javascript): "JavaScript", "application", "javascript",
         CSS (is_css): "CSS", "text", "css" ; "charset" => "utf-8",
@@ -57,6 +58,7 @@ macro_rules! known_extensions {
         "js" => JavaScript,
         "css" => CSS,
         "json" => JSON,
+        "cbor" => CBOR,
         "png" => PNG,
         "gif" => GIF,
         "bmp" => BMP,
@@ -104,6 +106,7 @@ macro_rules! known_shorthands {
         "text" => Text,
         "json" => JSON,
         "msgpack" => MsgPack,
+        "cbor" => CBOR,
         "form" => Form,
         "js" => JavaScript,
         "css" => CSS,
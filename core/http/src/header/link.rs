@@ -0,0 +1,580 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::iter::FromIterator;
+use std::str::FromStr;
+
+use crate::header::Header;
+use crate::parse::checkers::{is_whitespace, is_valid_token};
+use crate::uri::Reference;
+
+/// A single web link, as found in an HTTP `Link` header.
+///
+/// A `Link` is a URI reference, the _target_, along with a set of parameters,
+/// most importantly `rel`, the relation type, which identifies how the target
+/// relates to the current resource. `Link`s are typically collected into a
+/// [`LinkSet`], which renders as a single `Link` header.
+///
+/// # Construction
+///
+/// The target is any type that converts into a [`Reference`], including the
+/// URIs generated by Rocket's type-safe `uri!` macro:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::Link;
+///
+/// #[get("/items?<page>")]
+/// fn items(page: usize) { /* .. */ }
+///
+/// let next = Link::new(uri!(items(page = 3)), "next").param("title", "Page 3");
+/// assert_eq!(next.to_string(), r#"</items?page=3>; rel="next"; title="Page 3""#);
+/// assert_eq!(next.rel(), Some("next"));
+/// assert_eq!(next.get_param("title"), Some("Page 3"));
+/// ```
+///
+/// Parameter values are always rendered as quoted strings, with any `"` or `\`
+/// escaped. Parameter names must be valid HTTP tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Link {
+    uri: Reference<'static>,
+    params: Vec<(Cow<'static, str>, Option<Cow<'static, str>>)>,
+}
+
+/// A set of web links, rendered as a single HTTP `Link` header.
+///
+/// A `LinkSet` is most often used to advertise pagination links, such as the
+/// `next` and `prev` pages of a collection.
+///
+/// # Header
+///
+/// `LinkSet` implements `Into<Header>`, joining all of its links, in order,
+/// into a single comma-separated header value. It can be built from a sequence
+/// of `(uri, rel)` pairs or from [`Link`]s:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{Header, Link, LinkSet};
+///
+/// let links: LinkSet = vec![(uri!("/items?page=3"), "next"), (uri!("/items?page=1"), "prev")]
+///     .into_iter()
+///     .collect();
+///
+/// let header: Header = links.into();
+/// assert_eq!(header.value(), r#"</items?page=3>; rel="next", </items?page=1>; rel="prev""#);
+///
+/// let links = LinkSet::new()
+///     .link(Link::new(uri!("https://rocket.rs/guide"), "help").param("hreflang", "en"));
+///
+/// let header: Header = links.into();
+/// assert_eq!(header.value(), r#"<https://rocket.rs/guide>; rel="help"; hreflang="en""#);
+/// ```
+///
+/// A `LinkSet` can be returned alongside any responder as `(LinkSet, R)` to set
+/// the `Link` header of the response.
+///
+/// # Parsing
+///
+/// A `LinkSet` can be parsed from a `Link` header value via [`FromStr`]. Values
+/// may be quoted strings or tokens, and commas and semicolons within targets
+/// and quoted strings are handled as expected:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::LinkSet;
+///
+/// let links: LinkSet = r#"</a,b>; rel="next last"; title="x, \"y\"", </c>; rel=prev"#
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(links.len(), 2);
+///
+/// let next = links.find("next").unwrap();
+/// assert_eq!(next.uri().to_string(), "/a,b");
+/// assert!(next.has_rel("last"));
+/// assert_eq!(next.get_param("title"), Some(r#"x, "y""#));
+///
+/// assert_eq!(links.find("prev").unwrap().uri().to_string(), "/c");
+/// assert!(links.find("first").is_none());
+///
+/// assert!("/a; rel=next".parse::<LinkSet>().is_err());
+/// assert!(r#"</a>; rel="next"#.parse::<LinkSet>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LinkSet(Vec<Link>);
+
+impl Link {
+    /// Returns a new `Link` to `uri` with the relation type `rel`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/items?page=2"), "next");
+    /// assert_eq!(link.to_string(), r#"</items?page=2>; rel="next""#);
+    /// ```
+    pub fn new<U, R>(uri: U, rel: R) -> Link
+        where U: Into<Reference<'static>>, R: Into<Cow<'static, str>>
+    {
+        Link { uri: uri.into(), params: vec![] }.param("rel", rel)
+    }
+
+    /// Sets the parameter `name` to `value`, replacing any existing value, and
+    /// returns `self`. Parameter names are compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/"), "start").param("title", "Home").param("TITLE", "Index");
+    /// assert_eq!(link.to_string(), r#"</>; rel="start"; title="Index""#);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid HTTP token as defined in [RFC 7230 §
+    /// 3.2.6], for instance, if it is empty or contains whitespace, `=`, `;`,
+    /// or `"`.
+    ///
+    /// [RFC 7230 § 3.2.6]: https://tools.ietf.org/html/rfc7230#section-3.2.6
+    ///
+    /// ```rust,should_panic
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/"), "start").param("title=x; rel", "evil");
+    /// ```
+    pub fn param<N, V>(mut self, name: N, value: V) -> Link
+        where N: Into<Cow<'static, str>>, V: Into<Cow<'static, str>>
+    {
+        let (name, value) = (name.into(), Some(value.into()));
+        if name.is_empty() || !name.chars().all(|c| is_valid_token(&c)) {
+            panic!("invalid Link parameter name: {:?}", name);
+        }
+
+        match self.params.iter_mut().find(|(k, _)| k.eq_ignore_ascii_case(&name)) {
+            Some(param) => param.1 = value,
+            None => self.params.push((name, value)),
+        }
+
+        self
+    }
+
+    /// Returns the target URI reference of the link.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/items?page=2"), "next");
+    /// assert_eq!(link.uri().path(), "/items");
+    /// ```
+    pub fn uri(&self) -> &Reference<'static> {
+        &self.uri
+    }
+
+    /// Returns the value of the `rel` parameter, if there is one. The value
+    /// may contain several space-separated relation types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/items?page=9"), "next last");
+    /// assert_eq!(link.rel(), Some("next last"));
+    /// ```
+    pub fn rel(&self) -> Option<&str> {
+        self.get_param("rel")
+    }
+
+    /// Returns `true` if `rel` is one of the link's relation types. Relation
+    /// types are compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/items?page=9"), "next last");
+    /// assert!(link.has_rel("next"));
+    /// assert!(link.has_rel("LAST"));
+    /// assert!(!link.has_rel("prev"));
+    /// ```
+    pub fn has_rel(&self, rel: &str) -> bool {
+        self.rel().into_iter()
+            .flat_map(|rels| rels.split_ascii_whitespace())
+            .any(|r| r.eq_ignore_ascii_case(rel))
+    }
+
+    /// Returns the value of the parameter `name`, if it is present and has
+    /// one. Parameter names are compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/"), "alternate").param("hreflang", "de");
+    /// assert_eq!(link.get_param("HrefLang"), Some("de"));
+    /// assert_eq!(link.get_param("type"), None);
+    /// ```
+    pub fn get_param(&self, name: &str) -> Option<&str> {
+        self.params.iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .and_then(|(_, v)| v.as_deref())
+    }
+
+    /// Returns an iterator over the link's parameters, in order, including
+    /// `rel`. Parameters without a value yield `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Link;
+    ///
+    /// let link = Link::new(uri!("/"), "index").param("title", "Home");
+    /// let params: Vec<_> = link.params().collect();
+    /// assert_eq!(params, vec![("rel", Some("index")), ("title", Some("Home"))]);
+    /// ```
+    pub fn params(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.params.iter().map(|(k, v)| (k.as_ref(), v.as_deref()))
+    }
+}
+
+impl LinkSet {
+    /// Returns an empty `LinkSet`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::LinkSet;
+    ///
+    /// let links = LinkSet::new();
+    /// assert!(links.is_empty());
+    /// ```
+    pub fn new() -> LinkSet {
+        LinkSet(vec![])
+    }
+
+    /// Appends `link` to the set and returns `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Link, LinkSet};
+    ///
+    /// let links = LinkSet::new()
+    ///     .link(Link::new(uri!("/items?page=2"), "next"))
+    ///     .link(Link::new(uri!("/items?page=9"), "last"));
+    ///
+    /// assert_eq!(links.len(), 2);
+    /// ```
+    pub fn link(mut self, link: Link) -> LinkSet {
+        self.push(link);
+        self
+    }
+
+    /// Appends `link` to the set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Link, LinkSet};
+    ///
+    /// let mut links = LinkSet::new();
+    /// links.push(Link::new(uri!("/items?page=2"), "next"));
+    /// assert_eq!(links.len(), 1);
+    /// ```
+    pub fn push(&mut self, link: Link) {
+        self.0.push(link);
+    }
+
+    /// Returns the first link in the set with the relation type `rel`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Link, LinkSet};
+    ///
+    /// let links = LinkSet::new().link(Link::new(uri!("/items?page=2"), "next"));
+    /// assert_eq!(links.find("next").unwrap().uri().to_string(), "/items?page=2");
+    /// assert!(links.find("prev").is_none());
+    /// ```
+    pub fn find(&self, rel: &str) -> Option<&Link> {
+        self.0.iter().find(|link| link.has_rel(rel))
+    }
+
+    /// Returns an iterator over the links in the set, in order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Link, LinkSet};
+    ///
+    /// let links = LinkSet::new().link(Link::new(uri!("/items?page=2"), "next"));
+    /// assert_eq!(links.iter().next().unwrap().rel(), Some("next"));
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Link> {
+        self.0.iter()
+    }
+
+    /// Returns the number of links in the set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::LinkSet;
+    ///
+    /// let links: LinkSet = "</a>; rel=next, </b>; rel=prev".parse().unwrap();
+    /// assert_eq!(links.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set contains no links.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Link, LinkSet};
+    ///
+    /// assert!(LinkSet::new().is_empty());
+    /// assert!(!LinkSet::new().link(Link::new(uri!("/"), "index")).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Link> for LinkSet {
+    fn from(link: Link) -> Self {
+        LinkSet(vec![link])
+    }
+}
+
+impl From<Vec<Link>> for LinkSet {
+    fn from(links: Vec<Link>) -> Self {
+        LinkSet(links)
+    }
+}
+
+impl FromIterator<Link> for LinkSet {
+    fn from_iter<I: IntoIterator<Item = Link>>(iter: I) -> Self {
+        LinkSet(iter.into_iter().collect())
+    }
+}
+
+impl<U, R> FromIterator<(U, R)> for LinkSet
+    where U: Into<Reference<'static>>, R: Into<Cow<'static, str>>
+{
+    fn from_iter<I: IntoIterator<Item = (U, R)>>(iter: I) -> Self {
+        iter.into_iter().map(|(uri, rel)| Link::new(uri, rel)).collect()
+    }
+}
+
+impl Extend<Link> for LinkSet {
+    fn extend<I: IntoIterator<Item = Link>>(&mut self, iter: I) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for LinkSet {
+    type Item = Link;
+    type IntoIter = std::vec::IntoIter<Link>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.uri)?;
+        for (name, value) in &self.params {
+            write!(f, "; {}", name)?;
+            if let Some(value) = value {
+                f.write_str("=\"")?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+
+                    write!(f, "{}", c)?;
+                }
+
+                f.write_str("\"")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for LinkSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, link) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+
+            link.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A minimal cursor over a `Link` header value.
+struct Parser<'a> {
+    raw: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> String {
+        format!("invalid Link value: {:?}", self.raw)
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start_matches(|c| is_whitespace(&c));
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => { self.rest = rest; true }
+            None => false,
+        }
+    }
+
+    fn token(&mut self) -> Result<&'a str, String> {
+        self.skip_whitespace();
+        let len = self.rest.find(|c| !is_valid_token(&c)).unwrap_or(self.rest.len());
+        if len == 0 {
+            return Err(self.error());
+        }
+
+        let (token, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Ok(token)
+    }
+
+    fn quoted(&mut self) -> Result<String, String> {
+        let mut value = String::new();
+        let mut chars = self.rest.char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[(i + 1)..];
+                    return Ok(value);
+                }
+                '\\' => value.push(chars.next().ok_or_else(|| self.error())?.1),
+                c => value.push(c),
+            }
+        }
+
+        Err(self.error())
+    }
+
+    fn link(&mut self) -> Result<Link, String> {
+        if !self.eat('<') {
+            return Err(self.error());
+        }
+
+        let end = self.rest.find('>').ok_or_else(|| self.error())?;
+        let uri = Reference::parse_owned(self.rest[..end].to_string())
+            .map_err(|e| format!("invalid Link target: {}", e))?;
+
+        self.rest = &self.rest[(end + 1)..];
+        let mut link = Link { uri, params: vec![] };
+        while self.eat(';') {
+            let name = self.token()?.to_string();
+            let value = match self.eat('=') {
+                true => {
+                    self.skip_whitespace();
+                    match self.rest.starts_with('"') {
+                        true => Some(self.quoted()?),
+                        false => Some(self.token()?.to_string()),
+                    }
+                }
+                false => None,
+            };
+
+            // Per RFC 8288, only the first occurrence of a parameter counts.
+            if link.params.iter().all(|(k, _)| !k.eq_ignore_ascii_case(&name)) {
+                link.params.push((name.into(), value.map(Cow::from)));
+            }
+        }
+
+        Ok(link)
+    }
+}
+
+impl FromStr for Link {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Link, String> {
+        let mut links = raw.parse::<LinkSet>()?.0;
+        match links.len() {
+            1 => Ok(links.remove(0)),
+            _ => Err(format!("expected exactly one link: {:?}", raw)),
+        }
+    }
+}
+
+impl FromStr for LinkSet {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<LinkSet, String> {
+        let mut parser = Parser { raw, rest: raw };
+        let mut links = LinkSet::new();
+        loop {
+            // Empty list elements are allowed and ignored.
+            while parser.eat(',') {}
+            parser.skip_whitespace();
+            if parser.rest.is_empty() {
+                return Ok(links);
+            }
+
+            links.push(parser.link()?);
+            parser.skip_whitespace();
+            if !parser.rest.is_empty() && !parser.eat(',') {
+                return Err(parser.error());
+            }
+        }
+    }
+}
+
+/// Creates a new `Header` with name `Link` and the value set to the HTTP
+/// rendering of this `Link`.
+impl From<Link> for Header<'static> {
+    fn from(link: Link) -> Self {
+        Header::new("Link", link.to_string())
+    }
+}
+
+/// Creates a new `Header` with name `Link` and the value set to the HTTP
+/// rendering of all links in this `LinkSet`, joined by commas.
+impl From<LinkSet> for Header<'static> {
+    fn from(links: LinkSet) -> Self {
+        Header::new("Link", links.to_string())
+    }
+}
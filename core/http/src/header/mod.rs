@@ -4,10 +4,22 @@ mod media_type;
 mod content_type;
 mod accept;
 mod header;
+mod retry_after;
+mod content_range;
+mod link;
+mod csp;
+mod challenge;
+mod hop_by_hop;
 
 pub use self::content_type::ContentType;
 pub use self::accept::{Accept, QMediaType};
 pub use self::media_type::MediaType;
 pub use self::header::{Header, HeaderMap};
+pub use self::retry_after::RetryAfter;
+pub use self::content_range::ContentRange;
+pub use self::link::{Link, LinkSet};
+pub use self::csp::{Csp, CspSource};
+pub use self::challenge::{Challenge, BearerError};
+pub use self::hop_by_hop::HopByHop;
 
 pub(crate) use self::media_type::Source;
@@ -0,0 +1,202 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+use crate::header::Header;
+
+/// The preferred HTTP-date format, IMF-fixdate, as in RFC 7231 § 7.1.1.1.
+const IMF_FIXDATE: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// The obsolete RFC 850 format, after expanding its two-digit year.
+const RFC_850: &str = "%A, %d-%b-%Y %H:%M:%S GMT";
+
+/// The obsolete ANSI C `asctime()` format, after zero-padding its day.
+const ASCTIME: &str = "%a %b %d %H:%M:%S %Y";
+
+/// Parses an HTTP-date in any of the three formats recipients must accept.
+fn parse_http_date(raw: &str) -> Option<OffsetDateTime> {
+    if let Ok(date) = PrimitiveDateTime::parse(raw, IMF_FIXDATE) {
+        return Some(date.assume_utc());
+    }
+
+    // RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`. A two-digit year is taken to
+    // be in the 1900s if it's 70 or later and in the 2000s otherwise.
+    let mut parts = raw.splitn(2, ", ");
+    if let (Some(weekday), Some(rest)) = (parts.next(), parts.next()) {
+        let yy = rest.get(7..9).and_then(|yy| yy.parse::<u16>().ok());
+        if let (Some(yy), Some(" ")) = (yy, rest.get(9..10)) {
+            let century = if yy >= 70 { "19" } else { "20" };
+            let date = format!("{}, {}{}{}", weekday, &rest[..7], century, &rest[7..]);
+            if let Ok(date) = PrimitiveDateTime::parse(date, RFC_850) {
+                return Some(date.assume_utc());
+            }
+        }
+    }
+
+    // asctime(): `Sun Nov  6 08:49:37 1994`, where the day is space-padded.
+    let parts: Vec<&str> = raw.split_whitespace().collect();
+    if let [weekday, month, day, time, year] = parts[..] {
+        let date = format!("{} {} {:0>2} {} {}", weekday, month, day, time, year);
+        if let Ok(date) = PrimitiveDateTime::parse(date, ASCTIME) {
+            return Some(date.assume_utc());
+        }
+    }
+
+    None
+}
+
+/// Representation of an HTTP `Retry-After` header value.
+///
+/// A `Retry-After` indicates how long a client ought to wait before making a
+/// follow-up request. It is most commonly sent with `503 Service Unavailable`
+/// and `429 Too Many Requests` responses, or with a `3XX` redirect. The value
+/// is either a non-negative number of seconds, [`RetryAfter::Delay`], or an
+/// absolute point in time, [`RetryAfter::Date`], serialized as an HTTP-date.
+///
+/// # Header
+///
+/// `RetryAfter` implements `Into<Header>` and can thus be used in any context
+/// where an `Into<Header>` is expected:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use std::time::Duration;
+/// use rocket::http::RetryAfter;
+/// use rocket::response::Response;
+///
+/// let response = Response::build()
+///     .header(RetryAfter::Delay(Duration::from_secs(120)))
+///     .finalize();
+///
+/// assert_eq!(response.headers().get_one("Retry-After"), Some("120"));
+/// ```
+///
+/// Delays are serialized in whole seconds; any fractional part is truncated.
+/// Dates are converted to UTC and serialized as an IMF-fixdate:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::{Header, RetryAfter};
+/// # use time::{date, time};
+///
+/// let date = date!(2021-06-09).with_time(time!(23:59:59)).assume_utc();
+/// let header: Header = RetryAfter::Date(date).into();
+/// assert_eq!(header.value(), "Wed, 09 Jun 2021 23:59:59 GMT");
+/// ```
+///
+/// # Parsing
+///
+/// A `RetryAfter` can be parsed from a header value via [`FromStr`]. The
+/// obsolete HTTP-date formats are accepted as well:
+///
+/// ```rust
+/// # extern crate rocket;
+/// use std::time::Duration;
+/// use rocket::http::RetryAfter;
+///
+/// let delay: RetryAfter = "30".parse().unwrap();
+/// assert_eq!(delay, RetryAfter::Delay(Duration::from_secs(30)));
+///
+/// let date: RetryAfter = "Wed, 09 Jun 2021 23:59:59 GMT".parse().unwrap();
+/// let rfc850: RetryAfter = "Wednesday, 09-Jun-21 23:59:59 GMT".parse().unwrap();
+/// let asctime: RetryAfter = "Wed Jun  9 23:59:59 2021".parse().unwrap();
+/// assert!(matches!(date, RetryAfter::Date(_)));
+/// assert_eq!(date, rfc850);
+/// assert_eq!(date, asctime);
+///
+/// assert!("-10".parse::<RetryAfter>().is_err());
+/// assert!("tomorrow".parse::<RetryAfter>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RetryAfter {
+    /// A delay, in seconds, after which to retry.
+    Delay(Duration),
+    /// A point in time after which to retry.
+    Date(OffsetDateTime),
+}
+
+impl RetryAfter {
+    /// Returns the remaining time to wait, as of `now`, before retrying.
+    ///
+    /// For a `Delay`, this is the delay itself. For a `Date`, this is the time
+    /// between `now` and the date or zero if the date is not after `now`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use std::time::Duration;
+    /// use rocket::http::RetryAfter;
+    /// # use time::OffsetDateTime;
+    ///
+    /// let now = OffsetDateTime::now_utc();
+    /// let delay = RetryAfter::Delay(Duration::from_secs(10));
+    /// assert_eq!(delay.delay_from(now), Duration::from_secs(10));
+    ///
+    /// let date = RetryAfter::Date(now + Duration::from_secs(60));
+    /// assert_eq!(date.delay_from(now), Duration::from_secs(60));
+    ///
+    /// let past = RetryAfter::Date(now - Duration::from_secs(60));
+    /// assert_eq!(past.delay_from(now), Duration::from_secs(0));
+    /// ```
+    pub fn delay_from(&self, now: OffsetDateTime) -> Duration {
+        match *self {
+            RetryAfter::Delay(delay) => delay,
+            RetryAfter::Date(date) => {
+                let secs = (date - now).whole_seconds();
+                Duration::from_secs(secs.max(0) as u64)
+            }
+        }
+    }
+}
+
+impl From<Duration> for RetryAfter {
+    fn from(delay: Duration) -> Self {
+        RetryAfter::Delay(delay)
+    }
+}
+
+impl From<OffsetDateTime> for RetryAfter {
+    fn from(date: OffsetDateTime) -> Self {
+        RetryAfter::Date(date)
+    }
+}
+
+impl FromStr for RetryAfter {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<RetryAfter, String> {
+        let raw = raw.trim();
+        if !raw.is_empty() && raw.bytes().all(|b| b.is_ascii_digit()) {
+            return raw.parse()
+                .map(|secs| RetryAfter::Delay(Duration::from_secs(secs)))
+                .map_err(|e| format!("invalid delay-seconds: {}", e));
+        }
+
+        parse_http_date(raw)
+            .map(RetryAfter::Date)
+            .ok_or_else(|| format!("invalid Retry-After value: {:?}", raw))
+    }
+}
+
+impl fmt::Display for RetryAfter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RetryAfter::Delay(delay) => write!(f, "{}", delay.as_secs()),
+            RetryAfter::Date(date) => {
+                let date = date.to_offset(UtcOffset::UTC);
+                write!(f, "{}", date.format(IMF_FIXDATE))
+            }
+        }
+    }
+}
+
+/// Creates a new `Header` with name `Retry-After` and the value set to the
+/// HTTP rendering of this `RetryAfter`.
+impl From<RetryAfter> for Header<'static> {
+    fn from(retry_after: RetryAfter) -> Self {
+        Header::new("Retry-After", retry_after.to_string())
+    }
+}
@@ -24,6 +24,7 @@ mod docify;
 #[macro_use]
 mod header;
 mod method;
+mod version;
 mod status;
 mod raw_str;
 mod parse;
@@ -47,11 +48,12 @@ pub mod private {
 
     pub use crate::parse::Indexed;
     pub use smallvec::{SmallVec, Array};
-    pub use crate::listener::{Incoming, Listener, Connection, bind_tcp};
+    pub use crate::listener::{Incoming, Listener, Connection, TlsInfo, bind_tcp};
     pub use cookie;
 }
 
 pub use crate::method::Method;
+pub use crate::version::Version;
 pub use crate::status::{Status, StatusClass};
 pub use crate::raw_str::{RawStr, RawStrBuf};
 pub use crate::header::*;
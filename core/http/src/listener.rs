@@ -34,6 +34,46 @@ pub trait Listener {
 pub trait Connection: AsyncRead + AsyncWrite {
     /// The remote address, i.e. the client's socket address.
     fn remote_addr(&self) -> Option<SocketAddr>;
+
+    /// The local address, i.e. the server's socket address.
+    fn local_addr(&self) -> Option<SocketAddr> {
+        None
+    }
+
+    /// Information about the connection's TLS session, if it has one.
+    fn tls_info(&self) -> Option<TlsInfo> {
+        None
+    }
+}
+
+/// Information about the TLS session of a connection.
+///
+/// A `TlsInfo` is only available for connections that were established over
+/// TLS, which requires Rocket's `tls` feature to be enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TlsInfo {
+    pub(crate) version: Option<&'static str>,
+    pub(crate) alpn_protocol: Option<Vec<u8>>,
+    pub(crate) server_name: Option<String>,
+}
+
+impl TlsInfo {
+    /// The negotiated TLS protocol version, such as `TLSv1.3`, if it is known.
+    pub fn version(&self) -> Option<&str> {
+        self.version
+    }
+
+    /// The protocol negotiated via ALPN, such as `h2` or `http/1.1`, if one
+    /// was negotiated.
+    pub fn alpn_protocol(&self) -> Option<&[u8]> {
+        self.alpn_protocol.as_deref()
+    }
+
+    /// The server name the client requested via the SNI extension during the
+    /// handshake, if it sent one.
+    pub fn server_name(&self) -> Option<&str> {
+        self.server_name.as_deref()
+    }
 }
 
 pin_project_lite::pin_project! {
@@ -187,4 +227,8 @@ impl Connection for TcpStream {
     fn remote_addr(&self) -> Option<SocketAddr> {
         self.peer_addr().ok()
     }
+
+    fn local_addr(&self) -> Option<SocketAddr> {
+        TcpStream::local_addr(self).ok()
+    }
 }
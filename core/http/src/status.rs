@@ -340,6 +340,44 @@ impl std::hash::Hash for Status {
     }
 }
 
+macro_rules! status_class_check_fn {
+    ($func:ident, $type:expr, $variant:ident) => (
+        /// Returns `true` if the class of `self` is
+        #[doc=$type]
+        /// Returns `false` otherwise.
+        #[inline(always)]
+        pub const fn $func(self) -> bool {
+            matches!(self.class(), StatusClass::$variant)
+        }
+    )
+}
+
+/// Predicates on the [class](Status::class()) of a `Status`.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate rocket;
+/// use rocket::http::Status;
+///
+/// assert!(Status::Continue.is_informational());
+/// assert!(Status::Created.is_success());
+/// assert!(Status::Found.is_redirection());
+/// assert!(Status::NotFound.is_client_error());
+/// assert!(Status::BadGateway.is_server_error());
+///
+/// assert!(!Status::NotFound.is_success());
+/// assert!(Status::new(299).is_success());
+/// assert!(!Status::new(600).is_server_error());
+/// ```
+impl Status {
+    status_class_check_fn!(is_informational, "`Informational` (1XX).", Informational);
+    status_class_check_fn!(is_success, "`Success` (2XX).", Success);
+    status_class_check_fn!(is_redirection, "`Redirection` (3XX).", Redirection);
+    status_class_check_fn!(is_client_error, "`ClientError` (4XX).", ClientError);
+    status_class_check_fn!(is_server_error, "`ServerError` (5XX).", ServerError);
+}
+
 impl PartialEq for Status {
     fn eq(&self, other: &Self) -> bool {
         self.code.eq(&other.code)
@@ -359,3 +397,64 @@ impl Ord for Status {
         self.code.cmp(&other.code)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Status, StatusClass::{self, *}};
+
+    #[test]
+    fn known_codes_map_to_classes() {
+        let expected: &[(u16, StatusClass)] = &[
+            (100, Informational), (101, Informational), (102, Informational),
+            (200, Success), (201, Success), (202, Success), (203, Success),
+            (204, Success), (205, Success), (206, Success), (207, Success),
+            (208, Success), (226, Success),
+            (300, Redirection), (301, Redirection), (302, Redirection),
+            (303, Redirection), (304, Redirection), (305, Redirection),
+            (307, Redirection), (308, Redirection),
+            (400, ClientError), (401, ClientError), (402, ClientError),
+            (403, ClientError), (404, ClientError), (405, ClientError),
+            (406, ClientError), (407, ClientError), (408, ClientError),
+            (409, ClientError), (410, ClientError), (411, ClientError),
+            (412, ClientError), (413, ClientError), (414, ClientError),
+            (415, ClientError), (416, ClientError), (417, ClientError),
+            (418, ClientError), (421, ClientError), (422, ClientError),
+            (423, ClientError), (424, ClientError), (426, ClientError),
+            (428, ClientError), (429, ClientError), (431, ClientError),
+            (451, ClientError),
+            (500, ServerError), (501, ServerError), (502, ServerError),
+            (503, ServerError), (504, ServerError), (505, ServerError),
+            (506, ServerError), (507, ServerError), (508, ServerError),
+            (510, ServerError), (511, ServerError),
+        ];
+
+        let known = (0..1000).filter_map(Status::from_code).collect::<Vec<_>>();
+        assert_eq!(known.len(), expected.len());
+        for (status, &(code, class)) in known.iter().zip(expected) {
+            assert_eq!(status.code, code);
+            assert_eq!(status.class(), class, "class of {}", status);
+            assert_eq!(status.is_informational(), class.is_informational());
+            assert_eq!(status.is_success(), class.is_success());
+            assert_eq!(status.is_redirection(), class.is_redirection());
+            assert_eq!(status.is_client_error(), class.is_client_error());
+            assert_eq!(status.is_server_error(), class.is_server_error());
+        }
+    }
+
+    #[test]
+    fn unknown_codes_map_to_classes() {
+        assert_eq!(Status::new(199).class(), Informational);
+        assert_eq!(Status::new(299).class(), Success);
+        assert_eq!(Status::new(399).class(), Redirection);
+        assert_eq!(Status::new(499).class(), ClientError);
+        assert_eq!(Status::new(599).class(), ServerError);
+
+        for &code in &[0, 99, 600, 999, u16::MAX] {
+            let status = Status::new(code);
+            assert_eq!(status.class(), Unknown);
+            assert!(!status.is_informational() && !status.is_success());
+            assert!(!status.is_redirection() && !status.is_client_error());
+            assert!(!status.is_server_error());
+        }
+    }
+}
@@ -2,16 +2,17 @@ use std::io;
 use std::future::Future;
 use std::net::SocketAddr;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
 
 use rustls::internal::pemfile;
-use rustls::{Certificate, PrivateKey, ServerConfig};
+use rustls::{Certificate, PrivateKey, ProducesTickets, ServerConfig};
 use tokio::net::{TcpListener, TcpStream};
 use tokio_rustls::{TlsAcceptor, Accept, server::TlsStream};
 use tokio_rustls::rustls;
 
-use crate::listener::{Connection, Listener};
+use crate::listener::{Connection, Listener, TlsInfo};
 
 fn load_certs(reader: &mut dyn io::BufRead) -> io::Result<Vec<Certificate>> {
     pemfile::certs(reader)
@@ -46,6 +47,82 @@ fn load_private_key(reader: &mut dyn io::BufRead) -> io::Result<PrivateKey> {
         .map(|_| key)
 }
 
+/// The lifetime of tickets produced by `rustls::Ticketer`.
+const DEFAULT_TICKET_LIFETIME: u32 = 12 * 60 * 60;
+
+/// A session ticketer whose tickets are accepted for at most `lifetime`
+/// seconds.
+///
+/// Tickets are encrypted by the current key, which is replaced every
+/// `lifetime / 2` seconds. The replaced key is kept to decrypt tickets for
+/// another `lifetime / 2` seconds, then erased. Keys are produced by
+/// `rustls::Ticketer`, which rotates its own keys no later than every 6 hours.
+struct RollingTicketer {
+    lifetime: u32,
+    period: Duration,
+    keys: Mutex<TicketKeys>,
+}
+
+struct TicketKeys {
+    current: Arc<dyn ProducesTickets>,
+    previous: Option<Arc<dyn ProducesTickets>>,
+    next_roll: Instant,
+}
+
+impl RollingTicketer {
+    fn new(lifetime: u32) -> Self {
+        let period = Duration::from_secs(u64::from(lifetime / 2 + lifetime % 2));
+        let keys = TicketKeys {
+            current: rustls::Ticketer::new(),
+            previous: None,
+            next_roll: Instant::now() + period,
+        };
+
+        RollingTicketer { lifetime, period, keys: Mutex::new(keys) }
+    }
+
+    /// Rolls the keys as of `now` and returns the current and previous keys.
+    fn roll(
+        &self,
+        now: Instant
+    ) -> (Arc<dyn ProducesTickets>, Option<Arc<dyn ProducesTickets>>) {
+        let mut keys = self.keys.lock().expect("ticket keys lock");
+        if now >= keys.next_roll {
+            // Keys are rolled on a fixed schedule, even if there's no ticket
+            // to encrypt or decrypt when a period ends.
+            let periods = 1 + (now - keys.next_roll).as_secs() / self.period.as_secs();
+            let current = std::mem::replace(&mut keys.current, rustls::Ticketer::new());
+            keys.previous = match periods {
+                1 => Some(current),
+                _ => None,
+            };
+
+            keys.next_roll += self.period * periods as u32;
+        }
+
+        (keys.current.clone(), keys.previous.clone())
+    }
+}
+
+impl ProducesTickets for RollingTicketer {
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn get_lifetime(&self) -> u32 {
+        self.lifetime
+    }
+
+    fn encrypt(&self, plain: &[u8]) -> Option<Vec<u8>> {
+        self.roll(Instant::now()).0.encrypt(plain)
+    }
+
+    fn decrypt(&self, cipher: &[u8]) -> Option<Vec<u8>> {
+        let (current, previous) = self.roll(Instant::now());
+        current.decrypt(cipher).or_else(|| previous?.decrypt(cipher))
+    }
+}
+
 pub struct TlsListener {
     listener: TcpListener,
     acceptor: TlsAcceptor,
@@ -94,10 +171,13 @@ impl Listener for TlsListener {
     }
 }
 
+/// Binds a TLS listener to `address`. Session tickets are issued if
+/// `ticket_lifetime` is `Some` and nonzero, with lifetimes of at most 12 hours.
 pub async fn bind_tls<C: io::BufRead + Send, K: io::BufRead + Send>(
     address: SocketAddr,
     mut cert_chain: C,
     mut private_key: K,
+    ticket_lifetime: Option<u32>,
 ) -> io::Result<TlsListener> {
     let cert_chain = load_certs(&mut cert_chain).map_err(|e| {
         let msg = format!("malformed TLS certificate chain: {}", e);
@@ -115,7 +195,14 @@ pub async fn bind_tls<C: io::BufRead + Send, K: io::BufRead + Send>(
     let mut tls_config = ServerConfig::new(client_auth);
     let cache = rustls::ServerSessionMemoryCache::new(1024);
     tls_config.set_persistence(cache);
-    tls_config.ticketer = rustls::Ticketer::new();
+    match ticket_lifetime {
+        Some(0) | None => {},
+        Some(lifetime) if lifetime >= DEFAULT_TICKET_LIFETIME => {
+            tls_config.ticketer = rustls::Ticketer::new();
+        }
+        Some(lifetime) => tls_config.ticketer = Arc::new(RollingTicketer::new(lifetime)),
+    }
+
     tls_config.set_single_cert(cert_chain, key).expect("invalid key");
     tls_config.set_protocols(&[b"h2".to_vec(), b"http/1.1".to_vec()]);
 
@@ -129,6 +216,29 @@ impl Connection for TlsStream<TcpStream> {
     fn remote_addr(&self) -> Option<SocketAddr> {
         self.get_ref().0.remote_addr()
     }
+
+    fn local_addr(&self) -> Option<SocketAddr> {
+        Connection::local_addr(self.get_ref().0)
+    }
+
+    fn tls_info(&self) -> Option<TlsInfo> {
+        use rustls::{Session, ProtocolVersion::*};
+
+        let session = self.get_ref().1;
+        let version = session.get_protocol_version().and_then(|version| match version {
+            SSLv2 => Some("SSLv2"),
+            SSLv3 => Some("SSLv3"),
+            TLSv1_0 => Some("TLSv1.0"),
+            TLSv1_1 => Some("TLSv1.1"),
+            TLSv1_2 => Some("TLSv1.2"),
+            TLSv1_3 => Some("TLSv1.3"),
+            _ => None,
+        });
+
+        let alpn_protocol = session.get_alpn_protocol().map(|p| p.to_vec());
+        let server_name = session.get_sni_hostname().map(|name| name.to_string());
+        Some(TlsInfo { version, alpn_protocol, server_name })
+    }
 }
 
 #[cfg(test)]
@@ -158,6 +268,30 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn rolled_ticket_keys_expire() {
+        let ticketer = RollingTicketer::new(61);
+        assert_eq!(ticketer.get_lifetime(), 61);
+        assert_eq!(ticketer.period, Duration::from_secs(31));
+
+        let start = Instant::now();
+        let ticket = ticketer.roll(start).0.encrypt(b"session").unwrap();
+        let decrypt = |now| {
+            let (current, previous) = ticketer.roll(now);
+            current.decrypt(&ticket).or_else(|| previous?.decrypt(&ticket))
+        };
+
+        assert_eq!(decrypt(start).unwrap(), b"session");
+        assert_eq!(decrypt(start + Duration::from_secs(40)).unwrap(), b"session");
+        assert!(decrypt(start + Duration::from_secs(80)).is_none());
+
+        // Keys are erased on schedule even when they aren't used.
+        let ticket = ticketer.roll(start + Duration::from_secs(92)).0.encrypt(b"x").unwrap();
+        let (current, previous) = ticketer.roll(start + Duration::from_secs(150));
+        assert!(previous.is_none());
+        assert!(current.decrypt(&ticket).is_none());
+    }
+
     #[test]
     fn verify_load_certs_of_different_types() -> io::Result<()> {
         let rsa_sha256_cert = tls_example_key!("rsa_sha256_cert.pem");
@@ -84,3 +84,39 @@ impl EncodeSet for DEFAULT_ENCODE_SET {
 pub fn percent_encode<S: EncodeSet + Default>(string: &RawStr) -> Cow<'_, str> {
     utf8_percent_encode(string.as_str(), &S::SET).into()
 }
+
+#[derive(Clone, Copy)]
+#[allow(non_camel_case_types)]
+pub struct RAW_ENCODE_SET<P: Part>(PhantomData<P>);
+
+impl EncodeSet for RAW_ENCODE_SET<Path> {
+    const SET: AsciiSet = <UNSAFE_ENCODE_SET<Path>>::SET.remove(b'%');
+}
+
+impl EncodeSet for RAW_ENCODE_SET<Query> {
+    const SET: AsciiSet = <UNSAFE_ENCODE_SET<Query>>::SET.remove(b'%');
+}
+
+/// Percent-encodes the characters in `S`, which mustn't contain `%`, leaving
+/// percent-encoded sequences as they are but encoding any other `%`.
+pub fn percent_encode_raw<S: EncodeSet>(string: &str) -> Cow<'_, str> {
+    let bytes = string.as_bytes();
+    let is_escape = |i: usize| bytes.len() > i + 2
+        && bytes[i + 1].is_ascii_hexdigit()
+        && bytes[i + 2].is_ascii_hexdigit();
+
+    let invalid_percent = (0..bytes.len()).any(|i| bytes[i] == b'%' && !is_escape(i));
+    if !invalid_percent {
+        return utf8_percent_encode(string, &S::SET).into();
+    }
+
+    let (mut encoded, mut last) = (String::with_capacity(string.len() + 2), 0);
+    for (i, _) in string.match_indices('%') {
+        encoded.extend(utf8_percent_encode(&string[last..i], &S::SET));
+        encoded.push_str(if is_escape(i) { "%" } else { "%25" });
+        last = i + 1;
+    }
+
+    encoded.extend(utf8_percent_encode(&string[last..], &S::SET));
+    encoded.into()
+}
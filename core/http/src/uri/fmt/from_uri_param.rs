@@ -89,6 +89,8 @@ use crate::uri::fmt::{self, Part};
 ///
 ///   * `Option<T>` to `Result<T, E>` (for any `E`)
 ///   * `Result<T, E>` to `Option<T>` (for any `E`)
+///   * `Vec<A>`, `&Vec<A>`, and `&[A]` to `Vec<T>` where `A`, or `&A`,
+///     converts to `T`
 ///
 /// See [Foreign Impls](#foreign-impls) for all provided implementations.
 ///
@@ -380,3 +382,30 @@ impl<A, E, T: FromUriParam<fmt::Query, A>> FromUriParam<fmt::Query, Result<A, E>
         param.map(|a| T::from_uri_param(a))
     }
 }
+
+impl<A, T: FromUriParam<fmt::Query, A>> FromUriParam<fmt::Query, Vec<A>> for Vec<T> {
+    type Target = Vec<T::Target>;
+
+    #[inline]
+    fn from_uri_param(param: Vec<A>) -> Self::Target {
+        param.into_iter().map(T::from_uri_param).collect()
+    }
+}
+
+impl<'a, A, T: FromUriParam<fmt::Query, &'a A>> FromUriParam<fmt::Query, &'a Vec<A>> for Vec<T> {
+    type Target = Vec<T::Target>;
+
+    #[inline]
+    fn from_uri_param(param: &'a Vec<A>) -> Self::Target {
+        param.iter().map(T::from_uri_param).collect()
+    }
+}
+
+impl<'a, A, T: FromUriParam<fmt::Query, &'a A>> FromUriParam<fmt::Query, &'a [A]> for Vec<T> {
+    type Target = Vec<T::Target>;
+
+    #[inline]
+    fn from_uri_param(param: &'a [A]) -> Self::Target {
+        param.iter().map(T::from_uri_param).collect()
+    }
+}
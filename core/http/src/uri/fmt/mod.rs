@@ -5,10 +5,12 @@ mod formatter;
 mod from_uri_param;
 mod encoding;
 mod part;
+mod raw;
 
 pub use self::formatter::*;
 pub use self::uri_display::*;
 pub use self::from_uri_param::*;
 pub use self::part::*;
+pub use self::raw::*;
 
 pub(crate) use self::encoding::*;
@@ -0,0 +1,83 @@
+use std::fmt;
+use std::borrow::Cow;
+use std::path::{Path as StdPath, PathBuf};
+
+use crate::uri::fmt::{Path, Query, Formatter, UriDisplay, FromUriParam};
+use crate::uri::fmt::{RAW_ENCODE_SET, percent_encode_raw};
+
+/// A marker for a [`uri!`] parameter whose value should be written with
+/// minimal percent-encoding.
+///
+/// By default, a string parameter to [`uri!`] is percent-encoded so that it
+/// forms exactly one path segment or query value: reserved characters such as
+/// `/` in the path or `&` and `=` in the query are encoded. Wrapping the value
+/// in `Raw` suppresses this, encoding only the characters that cannot appear in
+/// that part of a URI at all, such as spaces, non-ASCII characters, `#`, and,
+/// in the path, `?`. Existing percent-encoded sequences, like `%2F`, are left
+/// as is; any other `%` is encoded. The result is always a valid URI.
+///
+/// A `Raw` value can be used in place of any string or path parameter: one of
+/// type `String`, `&str`, `Cow<str>`, or, in the path, `PathBuf` or `&Path`.
+///
+/// # Risks
+///
+/// The value is no longer confined to the segment or value it is written to.
+/// A `Raw` path parameter containing `/` spans several segments and may
+/// contain `..` segments, and a `Raw` query value containing `&` or `=`
+/// introduces additional query fields. The generated URI may thus route to a
+/// different route than the one named in `uri!` or carry fields the route
+/// doesn't expect. Never wrap untrusted input in `Raw`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::uri::fmt::Raw;
+///
+/// #[get("/proxy/<path>?<q>")]
+/// fn proxy(path: &str, q: &str) { /* .. */ }
+///
+/// let uri = uri!(proxy("a/b c", "x&y"));
+/// assert_eq!(uri.to_string(), "/proxy/a%2Fb%20c?q=x%26y");
+///
+/// let uri = uri!(proxy(Raw("a/b c%2F"), Raw("x&y")));
+/// assert_eq!(uri.to_string(), "/proxy/a/b%20c%2F?q=x&y");
+/// ```
+///
+/// [`uri!`]: rocket::uri
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Raw<T>(pub T);
+
+impl<T: AsRef<str>> UriDisplay<Path> for Raw<T> {
+    fn fmt(&self, f: &mut Formatter<'_, Path>) -> fmt::Result {
+        f.write_raw(percent_encode_raw::<RAW_ENCODE_SET<Path>>(self.0.as_ref()))
+    }
+}
+
+impl<T: AsRef<str>> UriDisplay<Query> for Raw<T> {
+    fn fmt(&self, f: &mut Formatter<'_, Query>) -> fmt::Result {
+        f.write_raw(percent_encode_raw::<RAW_ENCODE_SET<Query>>(self.0.as_ref()))
+    }
+}
+
+macro_rules! impl_raw_conversion {
+    ($([$P:ty] ($($l:tt)*) $B:ty),*) => ($(
+        impl<$($l)* T: AsRef<str>> FromUriParam<$P, Raw<T>> for $B {
+            type Target = Raw<T>;
+
+            #[inline(always)]
+            fn from_uri_param(param: Raw<T>) -> Raw<T> { param }
+        }
+    )*)
+}
+
+impl_raw_conversion! {
+    [Path] () String,
+    [Path] ('a,) &'a str,
+    [Path] ('a,) Cow<'a, str>,
+    [Query] () String,
+    [Query] ('a,) &'a str,
+    [Query] ('a,) Cow<'a, str>,
+    [Path] () PathBuf,
+    [Path] ('a,) &'a StdPath
+}
@@ -165,6 +165,16 @@ use crate::uri::fmt::{Part, Path, Query, Formatter};
 ///     If the `Result` is `Ok`, uses the implementation of `UriDisplay` for
 ///     `T`. Otherwise, nothing is rendered.
 ///
+///   * **`Vec<T>`, `[T]`** _where_ **`T: UriDisplay<Query>`**
+///
+///     Renders each element, using the implementation of `UriDisplay` for `T`,
+///     as a separate value of the same name: `tags=a&tags=b`. This is the form
+///     a `Vec` of scalars is parsed from. An empty collection renders nothing.
+///
+/// When a value renders nothing, as a `None` or an empty `Vec` does, the named
+/// parameter is omitted from the query entirely. If no parameter renders
+/// anything, `uri!` omits the query.
+///
 /// [`FromUriParam`]: crate::uri::fmt::FromUriParam
 ///
 /// # Deriving
@@ -409,6 +419,21 @@ impl<T: UriDisplay<Query>, E> UriDisplay<Query> for Result<T, E> {
     }
 }
 
+/// Writes each element as a separate value.
+impl<T: UriDisplay<Query>> UriDisplay<Query> for [T] {
+    fn fmt(&self, f: &mut Formatter<'_, Query>) -> fmt::Result {
+        self.iter().try_for_each(|v| f.write_value(v))
+    }
+}
+
+/// Defers to the `UriDisplay<Query>` implementation for `[T]`.
+impl<T: UriDisplay<Query>> UriDisplay<Query> for Vec<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_, Query>) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
 #[cfg(feature = "uuid")] impl_with_display!(uuid_::Uuid);
 #[cfg(feature = "uuid")] crate::impl_from_uri_param_identity!(uuid_::Uuid);
 
@@ -437,7 +462,8 @@ impl<T: UriDisplay<Query>, E> UriDisplay<Query> for Result<T, E> {
 ///
 /// # Implementations
 ///
-/// Only `Option<T>` and `Result<T, E>` implement this trait. You may implement
+/// Only `Option<T>`, `Result<T, E>`, and `Vec<T>`, which is parsed as empty when
+/// its field is missing, implement this trait. You may implement
 /// this trait for your own ignorable types as well:
 ///
 /// ```rust
@@ -451,6 +477,7 @@ pub trait Ignorable<P: Part> { }
 
 impl<T> Ignorable<Query> for Option<T> { }
 impl<T, E> Ignorable<Query> for Result<T, E> { }
+impl<T> Ignorable<Query> for Vec<T> { }
 
 #[doc(hidden)]
 pub fn assert_ignorable<P: Part, T: Ignorable<P>>() {  }
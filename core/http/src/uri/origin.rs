@@ -13,6 +13,10 @@ use crate::{RawStr, RawStrBuf};
 /// They are also the _simplest_ type of URIs, made up of only a path and an
 /// optional query.
 ///
+/// Despite the shared name, an origin URI is unrelated to the `Origin` request
+/// header used by CORS, which names a scheme, host, and port. That header is
+/// parsed by the `rocket::request::OriginHeader` request guard.
+///
 /// # Structure
 ///
 /// The following diagram illustrates the syntactic structure of an origin URI:
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use self::Version::*;
+
+/// Representation of HTTP protocol versions.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Version {
+    /// The `HTTP/0.9` variant.
+    Http09,
+    /// The `HTTP/1.0` variant.
+    Http10,
+    /// The `HTTP/1.1` variant.
+    Http11,
+    /// The `HTTP/2` variant.
+    Http2,
+    /// The `HTTP/3` variant.
+    Http3,
+}
+
+impl Version {
+    /// WARNING: This is unstable! Do not use this method outside of Rocket!
+    #[doc(hidden)]
+    pub fn from_hyp(version: http::Version) -> Option<Version> {
+        match version {
+            http::Version::HTTP_09 => Some(Http09),
+            http::Version::HTTP_10 => Some(Http10),
+            http::Version::HTTP_11 => Some(Http11),
+            http::Version::HTTP_2 => Some(Http2),
+            http::Version::HTTP_3 => Some(Http3),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if messages in this version of HTTP are framed in a
+    /// binary, multiplexed stream, as in `HTTP/2` and `HTTP/3`, and `false` if
+    /// they are transferred as text, as in `HTTP/1.x`. Mechanisms specific to
+    /// the textual protocol, such as chunked transfer encoding and connection
+    /// headers, do not apply to multiplexed versions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Version;
+    ///
+    /// assert!(!Version::Http11.is_multiplexed());
+    /// assert!(Version::Http2.is_multiplexed());
+    /// ```
+    #[inline]
+    pub fn is_multiplexed(self) -> bool {
+        match self {
+            Http2 | Http3 => true,
+            Http09 | Http10 | Http11 => false,
+        }
+    }
+
+    /// Returns the string representation of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::http::Version;
+    ///
+    /// assert_eq!(Version::Http11.as_str(), "HTTP/1.1");
+    /// assert_eq!(Version::Http2.as_str(), "HTTP/2");
+    /// ```
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Http09 => "HTTP/0.9",
+            Http10 => "HTTP/1.0",
+            Http11 => "HTTP/1.1",
+            Http2 => "HTTP/2",
+            Http3 => "HTTP/3",
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
@@ -3,7 +3,7 @@ use std::io::Cursor;
 
 use crate::response::Response;
 use crate::request::Request;
-use crate::http::{Status, ContentType, uri};
+use crate::http::{Status, ContentType, RawStr, uri};
 use crate::catcher::{Handler, BoxFuture};
 
 use yansi::Paint;
@@ -337,7 +337,10 @@ macro_rules! default_handler_fn {
             req: &'r Request<'_>
         ) -> Response<'r> {
             let preferred = req.accept().map(|a| a.preferred());
-            let (mime, text) = if preferred.map_or(false, |a| a.is_json()) {
+            let missing = req.missing_query_fields();
+            let (mime, text) = if status.code == 400 && !missing.is_empty() {
+                missing_fields_body(preferred.map_or(false, |a| a.is_json()), missing)
+            } else if preferred.map_or(false, |a| a.is_json()) {
                 let json: Cow<'_, str> = match status.code {
                     $($code => json_error_template!($code, $reason, $description).into(),)*
                     code => format!(json_error_fmt_template!("{}", "Unknown Error",
@@ -366,6 +369,25 @@ macro_rules! default_handler_fn {
     )
 }
 
+/// The body of the default `400` response for a request that is missing the
+/// `#[field(required)]` query fields `fields`.
+fn missing_fields_body(json: bool, fields: &[String]) -> (ContentType, Cow<'static, str>) {
+    let plural = if fields.len() == 1 { "" } else { "s" };
+    let description = |escape: fn(&str) -> String| format!(
+        "The request is missing the required query parameter{} {}.", plural,
+        fields.iter().map(|f| format!("`{}`", escape(f))).collect::<Vec<_>>().join(", "));
+
+    if json {
+        let escape = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let json = format!(json_error_fmt_template!(400, "Bad Request", "{}"), description(escape));
+        (ContentType::JSON, json.into())
+    } else {
+        let escape = |s: &str| RawStr::new(s).html_escape().into_owned();
+        let html = format!(html_error_template!(400, "Bad Request", "{}"), description(escape));
+        (ContentType::HTML, html.into())
+    }
+}
+
 default_handler_fn! {
     400, "Bad Request", "The request could not be understood by the server due \
         to malformed syntax.",
@@ -2,6 +2,8 @@
 
 mod catcher;
 mod handler;
+pub(crate) mod panic;
 
 pub use catcher::*;
 pub use handler::*;
+pub use panic::Panic;
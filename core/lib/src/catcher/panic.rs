@@ -0,0 +1,75 @@
+use std::any::Any;
+
+use crate::Request;
+
+/// Details of a panic in a route handler, available to catchers.
+///
+/// When a route's handler panics, Rocket catches the panic, logs it along with
+/// the request and the route's name, and responds via the `500` catcher. While
+/// that catcher runs, [`Panic::of()`] returns the details of the panic.
+///
+/// The panic's message is only retained when the
+/// [`panic_messages`](crate::Config::panic_messages) configuration parameter
+/// is enabled, as it is by default in debug builds. Messages are likely to
+/// contain internal details which should not be revealed in production.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::Request;
+/// use rocket::catcher::Panic;
+///
+/// #[catch(500)]
+/// fn internal_error(req: &Request<'_>) -> String {
+///     match Panic::of(req).and_then(|panic| panic.message()) {
+///         Some(message) => format!("The handler panicked: {}", message),
+///         None => "Something went wrong.".into(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Panic {
+    message: Option<String>,
+}
+
+impl Panic {
+    pub(crate) fn new(payload: &(dyn Any + Send), keep_message: bool) -> Self {
+        let message = match keep_message {
+            true => message(payload).map(|message| message.to_string()),
+            false => None,
+        };
+
+        Panic { message }
+    }
+
+    /// Returns the details of the panic in `req`'s route handler, if there was
+    /// one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::catcher::Panic;
+    ///
+    /// # let client = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = client.get("/");
+    /// # let req = request.inner();
+    /// assert!(Panic::of(req).is_none());
+    /// ```
+    pub fn of<'r>(req: &'r Request<'_>) -> Option<&'r Panic> {
+        req.state.cache.try_get()
+    }
+
+    /// Returns the panic's message if it was retained and the panic's payload
+    /// was a string, as it is for panics via `panic!()`, `unwrap()`, and
+    /// `expect()`.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+/// Returns the message of a panic with `payload`, if it is a string.
+pub(crate) fn message(payload: &(dyn Any + Send)) -> Option<&str> {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(|s| s.as_str()))
+}
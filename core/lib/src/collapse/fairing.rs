@@ -0,0 +1,258 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{Request, Response, Data};
+use crate::data::{ByteUnit, ToByteUnit};
+use crate::fairing::{Fairing, Info, Kind};
+use crate::http::Method;
+use crate::response::{CapturedResponse, CaptureError};
+
+/// The request headers carrying credentials.
+const CREDENTIAL_HEADERS: &[&str] = &["Cookie", "Authorization"];
+
+/// A [`Fairing`] that lets only one of several concurrent, identical `GET` or
+/// `HEAD` requests run its handler, sharing its response with the others.
+///
+/// See the [module documentation](crate::collapse) for details.
+pub struct Collapse {
+    in_flight: Arc<Mutex<HashMap<Key, Flight>>>,
+    vary: Vec<Cow<'static, str>>,
+    timeout: Duration,
+    body_limit: ByteUnit,
+}
+
+/// Identifies identical requests.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    method: Method,
+    tls: bool,
+    host: Option<String>,
+    uri: String,
+    headers: Vec<Vec<String>>,
+}
+
+/// A leader's response: `None` until the leader shares one.
+type Flight = watch::Receiver<Option<CapturedResponse>>;
+
+/// The in-flight leader for a key. Dropping a leader that didn't complete, as
+/// happens when its request is abandoned, lets its followers proceed.
+struct Leader {
+    key: Key,
+    in_flight: Arc<Mutex<HashMap<Key, Flight>>>,
+    sender: Mutex<Option<watch::Sender<Option<CapturedResponse>>>>,
+}
+
+/// The leader of the requests identical to a request, if the request leads.
+struct Leading(Option<Leader>);
+
+impl Collapse {
+    /// Returns a `Collapse` fairing with the default settings: responses vary
+    /// by `Accept`, followers wait for up to 10 seconds, and response bodies
+    /// of up to 1MiB are shared.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::collapse::Collapse;
+    ///
+    /// let rocket = rocket::build().attach(Collapse::new());
+    /// ```
+    pub fn new() -> Self {
+        Collapse {
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+            vary: vec!["Accept".into()],
+            timeout: Duration::from_secs(10),
+            body_limit: 1.mebibytes(),
+        }
+    }
+
+    /// Adds `header` to the request headers responses vary by. Only requests
+    /// with equal values for every such header are collapsed.
+    ///
+    /// Requests with a `Cookie` or `Authorization` header are only collapsed
+    /// if responses vary by that header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::collapse::Collapse;
+    ///
+    /// let collapse = Collapse::new()
+    ///     .vary("Accept-Language")
+    ///     .vary("Authorization");
+    /// ```
+    pub fn vary<H: Into<Cow<'static, str>>>(mut self, header: H) -> Self {
+        self.vary.push(header.into());
+        self
+    }
+
+    /// Sets how long a follower waits for the leader's response before it is
+    /// handled as usual. Defaults to 10 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::collapse::Collapse;
+    ///
+    /// let collapse = Collapse::new().timeout(Duration::from_secs(2));
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the size of the largest response body that is shared. Responses
+    /// with larger bodies, or bodies of unknown size, are not shared.
+    /// Defaults to 1MiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::collapse::Collapse;
+    ///
+    /// let collapse = Collapse::new().body_limit(64.kibibytes());
+    /// ```
+    pub fn body_limit(mut self, limit: ByteUnit) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// Returns the key of `req` if it may be collapsed.
+    fn key_of(&self, req: &Request<'_>) -> Option<Key> {
+        if !matches!(req.method(), Method::Get | Method::Head) {
+            return None;
+        }
+
+        let varies = |name: &str| self.vary.iter().any(|h| h.eq_ignore_ascii_case(name));
+        let credentialed = CREDENTIAL_HEADERS.iter()
+            .any(|name| req.headers().contains(*name) && !varies(name));
+
+        if credentialed {
+            return None;
+        }
+
+        let headers = self.vary.iter()
+            .map(|name| req.headers().get(name).map(|v| v.to_string()).collect())
+            .collect();
+
+        // `uri()` is only the origin: distinguish virtual hosts and schemes.
+        let host = req.raw_host().map(|host| host.to_ascii_lowercase());
+        let tls = req.tls().is_some();
+        Some(Key { method: req.method(), tls, host, uri: req.uri().to_string(), headers })
+    }
+
+    /// Waits for the leader's response in `flight` for at most `timeout`.
+    async fn wait(mut flight: Flight, timeout: Duration) -> Option<CapturedResponse> {
+        let shared = async move {
+            loop {
+                let value = flight.borrow().clone();
+                if value.is_some() || flight.changed().await.is_err() {
+                    return value.or_else(|| flight.borrow().clone());
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, shared).await.ok().flatten()
+    }
+
+    /// Buffers `res` to share it, if it is shareable.
+    async fn share(&self, res: &mut Response<'_>) -> Option<CapturedResponse> {
+        if res.headers().contains("Set-Cookie") {
+            return None;
+        }
+
+        match CapturedResponse::capture(res, self.body_limit).await {
+            Ok(shared) => Some(shared),
+            Err(e @ CaptureError::Io(_)) => {
+                error_!("Failed to share response: {}.", e);
+                None
+            }
+            Err(_) => None,
+        }
+    }
+}
+
+impl Default for Collapse {
+    fn default() -> Self {
+        Collapse::new()
+    }
+}
+
+impl Leader {
+    /// Shares `shared`, if any, with the followers and retires the leader.
+    fn complete(&self, shared: Option<CapturedResponse>) {
+        let mut in_flight = self.in_flight.lock();
+        if let Some(sender) = self.sender.lock().take() {
+            if let Some(shared) = shared {
+                let _ = sender.send(Some(shared));
+            }
+
+            in_flight.remove(&self.key);
+        }
+    }
+}
+
+impl Drop for Leader {
+    fn drop(&mut self) {
+        if self.sender.get_mut().take().is_some() {
+            self.in_flight.lock().remove(&self.key);
+        }
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Collapse {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request Collapsing",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        let key = match self.key_of(req) {
+            Some(key) => key,
+            None => return,
+        };
+
+        let flight = {
+            let mut in_flight = self.in_flight.lock();
+            match in_flight.get(&key) {
+                Some(flight) => flight.clone(),
+                None => {
+                    let (sender, flight) = watch::channel(None);
+                    in_flight.insert(key.clone(), flight);
+                    let leader = Leader {
+                        key,
+                        in_flight: self.in_flight.clone(),
+                        sender: Mutex::new(Some(sender)),
+                    };
+
+                    req.local_cache(|| Leading(Some(leader)));
+                    return;
+                }
+            }
+        };
+
+        match Collapse::wait(flight, self.timeout).await {
+            Some(shared) => {
+                info_!("Collapsing request into an identical in-flight request.");
+                req.halt_with(shared.to_response());
+            }
+            None => warn_!("Identical in-flight request didn't share a response."),
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Leading(Some(leader)) = req.local_cache(|| Leading(None)) {
+            leader.complete(self.share(res).await);
+        }
+    }
+}
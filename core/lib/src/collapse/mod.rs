@@ -0,0 +1,69 @@
+//! Collapsing of concurrent, identical requests.
+//!
+//! When an expensive, cacheable resource is requested by many clients at once,
+//! for instance, just after a cache was emptied, every request runs the same
+//! handler to produce the same response. The [`Collapse`] fairing lets only the
+//! first of several concurrent, identical requests run its handler. The others
+//! wait for its response and receive a copy of it:
+//!
+//!   * Only `GET` and `HEAD` requests are collapsed. Requests are identical
+//!     when their method, scheme, `Host`, URI, and the values of the headers
+//!     the response [varies](Collapse::vary()) by, by default only `Accept`,
+//!     are equal.
+//!   * The first request, the _leader_, is handled as usual. Identical
+//!     requests received while the leader is in flight, the _followers_, wait
+//!     for the leader's response without their handler being invoked.
+//!   * A follower that waits longer than the [timeout](Collapse::timeout()),
+//!     by default 10 seconds, stops waiting and is handled as usual, as is
+//!     every follower if the leader's response can't be shared.
+//!
+//! # Shareable Responses
+//!
+//! A response is copied to followers by buffering it in memory. The leader's
+//! response is thus only shared if:
+//!
+//!   * Its body has a known size no larger than the
+//!     [body limit](Collapse::body_limit()), by default 1MiB. Streamed bodies
+//!     are never shared.
+//!   * It doesn't set cookies: a `Set-Cookie` header is specific to a client.
+//!
+//! Because responses are shared between clients, requests that carry
+//! credentials, that is, a `Cookie` or `Authorization` header, are never
+//! collapsed unless the response [varies](Collapse::vary()) by that header.
+//!
+//! # Usage
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use std::time::Duration;
+//!
+//! use rocket::collapse::Collapse;
+//!
+//! #[get("/report")]
+//! async fn report() -> String {
+//!     /* compute an expensive report... */
+//!     "report".into()
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     let collapse = Collapse::new()
+//!         .vary("Accept-Language")
+//!         .timeout(Duration::from_secs(5));
+//!
+//!     rocket::build()
+//!         .mount("/", routes![report])
+//!         .attach(collapse)
+//! }
+//! ```
+//!
+//! # Caveats
+//!
+//! A follower receives the leader's response by being halted via
+//! [`Request::halt_with()`](crate::Request::halt_with()), so request fairings
+//! attached after `Collapse` don't run for followers. Response fairings run on
+//! the shared response as usual.
+
+mod fairing;
+
+pub use self::fairing::Collapse;
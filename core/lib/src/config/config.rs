@@ -7,7 +7,7 @@ use figment::value::{Map, Dict};
 use serde::{Deserialize, Serialize};
 use yansi::Paint;
 
-use crate::config::{TlsConfig, LogLevel, Shutdown, Ident};
+use crate::config::{TlsConfig, LogLevel, LogFormat, Shutdown, Ident, DefaultHeaders};
 use crate::request::{self, Request, FromRequest};
 use crate::data::Limits;
 
@@ -70,6 +70,10 @@ pub struct Config {
     pub workers: usize,
     /// Keep-alive timeout in seconds; disabled when `0`. **(default: `5`)**
     pub keep_alive: u32,
+    /// Seconds to wait for the next chunk of a request body before failing the
+    /// read with an I/O error of kind `TimedOut`, which built-in data guards
+    /// turn into a `408`; disabled when `0`. **(default: `0`)**
+    pub body_read_timeout: u32,
     /// Streaming read size limits. **(default: [`Limits::default()`])**
     pub limits: Limits,
     /// The TLS configuration, if any. **(default: `None`)**
@@ -77,6 +81,27 @@ pub struct Config {
     /// How, if at all, to identify the server via the `Server` header.
     /// **(default: `"Rocket"`)**
     pub ident: Ident,
+    /// Headers to set on every response that doesn't already set them.
+    /// **(default: [`DefaultHeaders::new()`], no headers)**
+    pub default_headers: DefaultHeaders,
+    /// Hosts the [`Host`](crate::request::Host) request guard accepts; all
+    /// hosts when empty. **(default: `[]`)**
+    pub allowed_hosts: Vec<String>,
+    /// Whether to retry requests no route matches with static path segments
+    /// compared case-insensitively, redirecting with a `308` to the matching
+    /// route's casing. **(default: `false`)**
+    #[serde(deserialize_with = "figment::util::bool_from_str_or_int")]
+    pub case_insensitive_fallback: bool,
+    /// Whether to respond to requests no route matches with a `405` and an
+    /// `Allow` header when routes with other methods match their path.
+    /// **(default: `false`)**
+    #[serde(deserialize_with = "figment::util::bool_from_str_or_int")]
+    pub method_not_allowed: bool,
+    /// Whether the messages of panics in route handlers are made available to
+    /// catchers via [`Panic`](crate::catcher::Panic). **(default: `true` in
+    /// debug, `false` in release)**
+    #[serde(deserialize_with = "figment::util::bool_from_str_or_int")]
+    pub panic_messages: bool,
     /// The secret key for signing and encrypting. **(default: `0`)**
     ///
     /// **Note:** This field _always_ serializes as a 256-bit array of `0`s to
@@ -90,6 +115,9 @@ pub struct Config {
     pub temp_dir: PathBuf,
     /// Max level to log. **(default: _debug_ `normal` / _release_ `critical`)**
     pub log_level: LogLevel,
+    /// How to log launch information, like mounted routes. **(default:
+    /// `lines`)**
+    pub log_format: LogFormat,
     /// Graceful shutdown configuration. **(default: [`Shutdown::default()`])**
     pub shutdown: Shutdown,
     /// Whether to use colors and emoji when logging. **(default: `true`)**
@@ -162,13 +190,20 @@ impl Config {
             port: 8000,
             workers: num_cpus::get(),
             keep_alive: 5,
+            body_read_timeout: 0,
             limits: Limits::default(),
             tls: None,
             ident: Ident::default(),
+            default_headers: DefaultHeaders::new(),
+            allowed_hosts: vec![],
+            case_insensitive_fallback: false,
+            method_not_allowed: false,
+            panic_messages: true,
             #[cfg(feature = "secrets")]
             secret_key: SecretKey::zero(),
             temp_dir: std::env::temp_dir(),
             log_level: LogLevel::Normal,
+            log_format: LogFormat::Lines,
             shutdown: Shutdown::default(),
             cli_colors: true,
             __non_exhaustive: (),
@@ -194,6 +229,7 @@ impl Config {
         Config {
             profile: Self::RELEASE_PROFILE,
             log_level: LogLevel::Critical,
+            panic_messages: false,
             ..Config::debug_default()
         }
     }
@@ -312,6 +348,17 @@ impl Config {
         launch_info_!("port: {}", Paint::default(&self.port).bold());
         launch_info_!("workers: {}", Paint::default(self.workers).bold());
         launch_info_!("ident: {}", Paint::default(&self.ident).bold());
+        launch_info_!("default headers: {}", Paint::default(&self.default_headers).bold());
+        let hosts = match self.allowed_hosts.is_empty() {
+            true => "any".to_string(),
+            false => self.allowed_hosts.join(", "),
+        };
+
+        launch_info_!("allowed hosts: {}", Paint::default(hosts).bold());
+        launch_info_!("case-insensitive fallback: {}",
+            Paint::default(&self.case_insensitive_fallback).bold());
+        launch_info_!("method not allowed: {}", Paint::default(&self.method_not_allowed).bold());
+        launch_info_!("panic messages: {}", Paint::default(&self.panic_messages).bold());
 
         let ka = self.keep_alive;
         if ka > 0 {
@@ -320,6 +367,14 @@ impl Config {
             launch_info_!("keep-alive: {}", Paint::default("disabled").bold());
         }
 
+        let timeout = self.body_read_timeout;
+        if timeout > 0 {
+            let timeout = format!("{}s", timeout);
+            launch_info_!("body read timeout: {}", Paint::default(timeout).bold());
+        } else {
+            launch_info_!("body read timeout: {}", Paint::default("disabled").bold());
+        }
+
         launch_info_!("limits: {}", Paint::default(&self.limits).bold());
         match self.tls_enabled() {
             true => launch_info_!("tls: {}", Paint::default("enabled").bold()),
@@ -337,6 +392,7 @@ impl Config {
 
         launch_info_!("temp dir: {}", Paint::default(&self.temp_dir.display()).bold());
         launch_info_!("log level: {}", Paint::default(self.log_level).bold());
+        launch_info_!("log format: {}", Paint::default(self.log_format).bold());
         launch_info_!("cli colors: {}", Paint::default(&self.cli_colors).bold());
         launch_info_!("shutdown: {}", Paint::default(&self.shutdown).bold());
 
@@ -404,6 +460,30 @@ impl Config {
     /// The stringy parameter name for setting/extracting [`Config::keep_alive`].
     pub const KEEP_ALIVE: &'static str = "keep_alive";
 
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::body_read_timeout`].
+    pub const BODY_READ_TIMEOUT: &'static str = "body_read_timeout";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::default_headers`].
+    pub const DEFAULT_HEADERS: &'static str = "default_headers";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::allowed_hosts`].
+    pub const ALLOWED_HOSTS: &'static str = "allowed_hosts";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::case_insensitive_fallback`].
+    pub const CASE_INSENSITIVE_FALLBACK: &'static str = "case_insensitive_fallback";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::method_not_allowed`].
+    pub const METHOD_NOT_ALLOWED: &'static str = "method_not_allowed";
+
+    /// The stringy parameter name for setting/extracting
+    /// [`Config::panic_messages`].
+    pub const PANIC_MESSAGES: &'static str = "panic_messages";
+
     /// The stringy parameter name for setting/extracting [`Config::limits`].
     pub const LIMITS: &'static str = "limits";
 
@@ -419,6 +499,9 @@ impl Config {
     /// The stringy parameter name for setting/extracting [`Config::log_level`].
     pub const LOG_LEVEL: &'static str = "log_level";
 
+    /// The stringy parameter name for setting/extracting [`Config::log_format`].
+    pub const LOG_FORMAT: &'static str = "log_format";
+
     /// The stringy parameter name for setting/extracting [`Config::shutdown`].
     pub const SHUTDOWN: &'static str = "shutdown";
 }
@@ -458,6 +541,15 @@ impl<'r> FromRequest<'r> for &'r Config {
     }
 }
 
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Figment {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        request::Outcome::Success(req.rocket().figment())
+    }
+}
+
 #[doc(hidden)]
 pub fn pretty_print_error(error: figment::Error) {
     use figment::error::{Kind, OneOf};
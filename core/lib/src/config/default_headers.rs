@@ -0,0 +1,229 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde::de::{self, Deserializer};
+use serde::ser::{Serializer, SerializeMap};
+
+use crate::http::Header;
+
+/// Headers to set on every response that doesn't already set them.
+///
+/// When configured as [`Config::default_headers`](crate::Config), Rocket adds
+/// every configured header to every response, including error responses, that
+/// doesn't contain a header of the same name. A header name may be configured
+/// with multiple values, in which case all of the values are added. Headers
+/// are added before response fairings run, so fairings observe, and may modify
+/// or remove, the defaults.
+///
+/// # Deserialization
+///
+/// `DefaultHeaders` deserializes from a dictionary mapping header names to
+/// either a single string value or an array of string values. Header names
+/// must be valid tokens and values must be valid header values, that is,
+/// non-empty and without line breaks.
+///
+/// # Example
+///
+/// As with all Rocket configuration options, when using the default
+/// [`Config::figment()`](crate::Config::figment()), `DefaultHeaders` can be
+/// configured via a `Rocket.toml` file. By default, no headers are set.
+///
+/// ```rust
+/// # use rocket::figment::{Figment, providers::{Format, Toml}};
+/// use rocket::config::{Config, DefaultHeaders};
+///
+/// // If these are the contents of `Rocket.toml`...
+/// # let toml = Toml::string(r#"
+/// [default.default_headers]
+/// X-App-Version = "1.2.3"
+/// Link = ["</style.css>; rel=preload", "</app.js>; rel=preload"]
+/// # "#).nested();
+///
+/// // The config parses as follows:
+/// # let config = Config::from(Figment::from(Config::debug_default()).merge(toml));
+/// assert_eq!(config.default_headers, DefaultHeaders::new()
+///     .header("Link", "</style.css>; rel=preload")
+///     .header("Link", "</app.js>; rel=preload")
+///     .header("X-App-Version", "1.2.3"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DefaultHeaders {
+    headers: Vec<(String, Vec<String>)>,
+}
+
+impl DefaultHeaders {
+    /// Returns a new `DefaultHeaders` without any headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::DefaultHeaders;
+    ///
+    /// let headers = DefaultHeaders::new();
+    /// assert!(headers.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        DefaultHeaders::default()
+    }
+
+    /// Adds a header with `name` and `value` to `self`. If a header with the
+    /// same name, compared case-insensitively, already exists, `value` is
+    /// added as an additional value.
+    ///
+    /// Invalid header names or values are not checked for; responses with
+    /// such headers will fail to be written out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::DefaultHeaders;
+    ///
+    /// let headers = DefaultHeaders::new()
+    ///     .header("X-App-Version", "1.2.3")
+    ///     .header("X-Served-By", "a")
+    ///     .header("x-served-by", "b");
+    ///
+    /// let served_by: Vec<_> = headers.iter()
+    ///     .filter(|h| h.name() == "X-Served-By")
+    ///     .map(|h| h.value().to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(served_by, ["a", "b"]);
+    /// ```
+    pub fn header<N, V>(mut self, name: N, value: V) -> Self
+        where N: Into<String>, V: Into<String>
+    {
+        let name = name.into();
+        match self.headers.iter_mut().find(|(n, _)| n.eq_ignore_ascii_case(&name)) {
+            Some((_, values)) => values.push(value.into()),
+            None => self.headers.push((name, vec![value.into()])),
+        }
+
+        self
+    }
+
+    /// Returns `true` if `self` contains no headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::DefaultHeaders;
+    ///
+    /// assert!(DefaultHeaders::new().is_empty());
+    /// assert!(!DefaultHeaders::new().header("X-App", "1").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    /// Returns an iterator over all of the headers in `self`, with all values
+    /// of a given name adjacent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::DefaultHeaders;
+    ///
+    /// let headers = DefaultHeaders::new()
+    ///     .header("X-A", "1")
+    ///     .header("X-B", "2")
+    ///     .header("X-A", "3");
+    ///
+    /// let headers: Vec<_> = headers.iter().map(|h| h.to_string()).collect();
+    /// assert_eq!(headers, ["X-A: 1", "X-A: 3", "X-B: 2"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Header<'_>> {
+        self.headers.iter().flat_map(|(name, values)| {
+            values.iter().map(move |value| Header::new(name.as_str(), value.as_str()))
+        })
+    }
+
+    /// Adds the headers in `self` to `response` for every name that
+    /// `response` doesn't contain.
+    pub(crate) fn apply_to(&self, response: &mut crate::Response<'_>) {
+        for (name, values) in &self.headers {
+            if response.headers().contains(name.as_str()) {
+                continue;
+            }
+
+            for value in values {
+                response.adjoin_header(Header::new(name.clone(), value.clone()));
+            }
+        }
+    }
+}
+
+impl Serialize for DefaultHeaders {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        let mut map = ser.serialize_map(Some(self.headers.len()))?;
+        for (name, values) in &self.headers {
+            map.serialize_entry(name, values)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DefaultHeaders {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Values {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        struct Visitor;
+
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DefaultHeaders;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a map of header names to a value or array of values")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut headers = DefaultHeaders::new();
+                while let Some((name, values)) = map.next_entry::<String, Values>()? {
+                    if !Header::is_valid_name(&name) {
+                        let unexpected = de::Unexpected::Str(&name);
+                        return Err(de::Error::invalid_value(unexpected, &"a header name"));
+                    }
+
+                    let values = match values {
+                        Values::One(value) => vec![value],
+                        Values::Many(values) => values,
+                    };
+
+                    for value in values {
+                        if !Header::is_valid_value(&value, false) {
+                            let unexpected = de::Unexpected::Str(&value);
+                            return Err(de::Error::invalid_value(unexpected, &"a header value"));
+                        }
+
+                        headers = headers.header(name.clone(), value);
+                    }
+                }
+
+                Ok(headers)
+            }
+        }
+
+        de.deserialize_map(Visitor)
+    }
+}
+
+impl fmt::Display for DefaultHeaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return "none".fmt(f);
+        }
+
+        for (i, (name, values)) in self.headers.iter().enumerate() {
+            if i != 0 { f.write_str(", ")?; }
+            write!(f, "{} = {:?}", name, values)?;
+        }
+
+        Ok(())
+    }
+}
@@ -13,7 +13,8 @@ use crate::http::Header;
 ///
 /// * `string`
 ///
-///   The string must be a valid `Ident`. See [`Ident::try_new()`] for details.
+///   If non-empty, the string must be a valid `Ident`. See [`Ident::try_new()`]
+///   for details. If empty, the value is [`Ident::none()`].
 ///
 /// * `boolean`
 ///
@@ -70,6 +71,10 @@ use crate::http::Header;
 /// let config = rocket::Config::from(figment);
 /// assert_eq!(config.ident, Ident::none());
 ///
+/// let figment = rocket::Config::figment().merge(("ident", ""));
+/// let config = rocket::Config::from(figment);
+/// assert_eq!(config.ident, Ident::none());
+///
 /// let figment = rocket::Config::figment().merge(("ident", "Fancy/1.0"));
 /// let config = rocket::Config::from(figment);
 /// assert_eq!(config.ident, Ident::try_new("Fancy/1.0").unwrap());
@@ -208,6 +213,10 @@ impl<'de> Deserialize<'de> for Ident {
             }
 
             fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                if v.is_empty() {
+                    return Ok(Ident::none());
+                }
+
                 Ident::try_new(v)
                     .map_err(|s| E::invalid_value(de::Unexpected::Str(&s), &self))
             }
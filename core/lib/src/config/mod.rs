@@ -6,9 +6,9 @@
 //!
 //! ## Extracting Configuration Parameters
 //!
-//! Rocket exposes the active [`Figment`] via [`Rocket::figment()`] and
-//! [`Rocket::figment()`]. Any value that implements [`Deserialize`] can be
-//! extracted from the figment:
+//! Rocket exposes the active [`Figment`] via [`Rocket::figment()`] and, in
+//! handlers, via the `&Figment` [request guard](crate::request::FromRequest).
+//! Any value that implements [`Deserialize`] can be extracted from the figment:
 //!
 //! ```rust
 //! use rocket::fairing::AdHoc;
@@ -27,7 +27,6 @@
 //!
 //! [`Figment`]: figment::Figment
 //! [`Rocket::figment()`]: crate::Rocket::figment()
-//! [`Rocket::figment()`]: crate::Rocket::figment()
 //! [`Deserialize`]: serde::Deserialize
 //!
 //! ## Workers
@@ -116,6 +115,7 @@ mod ident;
 mod config;
 mod tls;
 mod shutdown;
+mod default_headers;
 
 #[cfg(feature = "secrets")]
 mod secret_key;
@@ -123,10 +123,11 @@ mod secret_key;
 #[doc(hidden)] pub use config::pretty_print_error;
 
 pub use config::Config;
-pub use crate::log::LogLevel;
+pub use crate::log::{LogLevel, LogFormat};
 pub use shutdown::Shutdown;
 pub use tls::TlsConfig;
 pub use ident::Ident;
+pub use default_headers::DefaultHeaders;
 
 #[cfg(feature = "secrets")]
 #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
@@ -142,8 +143,8 @@ mod tests {
     use figment::{Figment, Profile};
     use pretty_assertions::assert_eq;
 
-    use crate::config::{Config, TlsConfig, Shutdown, Ident};
-    use crate::log::LogLevel;
+    use crate::config::{Config, TlsConfig, Shutdown, Ident, DefaultHeaders};
+    use crate::log::{LogLevel, LogFormat};
     use crate::data::{Limits, ToByteUnit};
 
     #[test]
@@ -200,6 +201,7 @@ mod tests {
                 workers = 20
                 keep_alive = 10
                 log_level = "off"
+                log_format = "table"
                 cli_colors = 0
             "#)?;
 
@@ -211,6 +213,7 @@ mod tests {
                 ident: ident!("Something Cool"),
                 keep_alive: 10,
                 log_level: LogLevel::Off,
+                log_format: LogFormat::Table,
                 cli_colors: false,
                 ..Config::default()
             });
@@ -279,6 +282,22 @@ mod tests {
                 ..Config::default()
             });
 
+            jail.create_file("Rocket.toml", r#"
+                [global.tls]
+                certs = "/ssl/cert.pem"
+                key = "/ssl/key.pem"
+                session_tickets = false
+                ticket_lifetime = 600
+            "#)?;
+
+            let config = Config::from(Config::figment());
+            assert_eq!(config, Config {
+                tls: Some(TlsConfig::from_paths("/ssl/cert.pem", "/ssl/key.pem")
+                    .with_session_tickets(false)
+                    .with_ticket_lifetime(600)),
+                ..Config::default()
+            });
+
             jail.set_env("ROCKET_CONFIG", "Other.toml");
             jail.create_file("Other.toml", r#"
                 [default]
@@ -392,6 +411,14 @@ mod tests {
                 ..Config::default()
             });
 
+            jail.set_env("ROCKET_IDENT", "");
+            let config = Config::from(Config::figment().join(&prev_figment));
+            assert_eq!(config.ident, Ident::none());
+
+            jail.set_env("ROCKET_IDENT", "Fancy/1.0");
+            let config = Config::from(Config::figment().join(&prev_figment));
+            assert_eq!(config.ident, Ident::try_new("Fancy/1.0").unwrap());
+
             Ok(())
         });
     }
@@ -434,6 +461,64 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_default_headers() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file("Rocket.toml", r#"
+                [default.default_headers]
+                X-App-Version = "1.2.3"
+                X-Served-By = ["a", "b"]
+            "#)?;
+
+            let config = Config::from(Config::figment());
+            assert_eq!(config, Config {
+                default_headers: DefaultHeaders::new()
+                    .header("X-App-Version", "1.2.3")
+                    .header("X-Served-By", "a")
+                    .header("X-Served-By", "b"),
+                ..Config::default()
+            });
+
+            jail.create_file("Rocket.toml", r#"
+                [default.default_headers]
+                "X App" = "1.2.3"
+            "#)?;
+
+            assert!(Config::try_from(Config::figment()).is_err());
+
+            jail.create_file("Rocket.toml", r#"
+                [default.default_headers]
+                X-App = ["\nbad"]
+            "#)?;
+
+            assert!(Config::try_from(Config::figment()).is_err());
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn test_allowed_hosts() {
+        figment::Jail::expect_with(|jail| {
+            jail.create_file("Rocket.toml", r#"
+                [default]
+                allowed_hosts = ["example.com", "*.example.com:8000"]
+            "#)?;
+
+            let config = Config::from(Config::figment());
+            assert_eq!(config, Config {
+                allowed_hosts: vec!["example.com".into(), "*.example.com:8000".into()],
+                ..Config::default()
+            });
+
+            jail.set_env("ROCKET_ALLOWED_HOSTS", r#"["localhost"]"#);
+            let config = Config::from(Config::figment());
+            assert_eq!(config.allowed_hosts, vec!["localhost".to_string()]);
+
+            Ok(())
+        });
+    }
+
     #[test]
     #[cfg(feature = "secrets")]
     #[should_panic]
@@ -105,6 +105,16 @@ impl fmt::Display for Sig {
 /// proceed nominally. Rocket waits at most `mercy` seconds for connections to
 /// shutdown before forcefully terminating all connections.
 ///
+/// # Deadline
+///
+/// Requests can also be cut short _before_ the grace period elapses. If
+/// `deadline` is set, requests whose handlers are still running `deadline`
+/// seconds after shutdown was triggered are cancelled and answered with a
+/// `503 Service Unavailable` that closes the connection, as are any requests
+/// received thereafter. Responses whose bodies are already being written are
+/// unaffected. For the `503` to be written, `deadline` should be less than
+/// `grace`.
+///
 /// # Runaway I/O
 ///
 /// If tasks are _still_ executing after both periods _and_ a Rocket configured
@@ -146,6 +156,7 @@ impl fmt::Display for Sig {
 /// signals = ["term", "hup"]
 /// grace = 10
 /// mercy = 5
+/// deadline = 8
 /// # force = false
 /// # "#).nested();
 ///
@@ -154,6 +165,7 @@ impl fmt::Display for Sig {
 /// assert_eq!(config.shutdown.ctrlc, false);
 /// assert_eq!(config.shutdown.grace, 10);
 /// assert_eq!(config.shutdown.mercy, 5);
+/// assert_eq!(config.shutdown.deadline, Some(8));
 /// # assert_eq!(config.shutdown.force, false);
 ///
 /// # #[cfg(unix)] {
@@ -227,6 +239,13 @@ pub struct Shutdown {
     ///
     /// **default: `3`**
     pub mercy: u32,
+    /// The deadline: number of seconds after shutdown is triggered after
+    /// which requests still being handled are answered with a `503` instead.
+    /// See [Deadline](#deadline).
+    ///
+    /// **default: `None`**
+    #[serde(default)]
+    pub deadline: Option<u32>,
     /// Whether to force termination of a process that refuses to cooperatively
     /// shutdown.
     ///
@@ -270,6 +289,10 @@ impl fmt::Display for Shutdown {
         }
 
         write!(f, "grace = {}s, mercy = {}s", self.grace, self.mercy)?;
+        if let Some(deadline) = self.deadline {
+            write!(f, ", deadline = {}s", deadline)?;
+        }
+
         Ok(())
     }
 }
@@ -282,6 +305,7 @@ impl Default for Shutdown {
             signals: { let mut set = HashSet::new(); set.insert(Sig::Term); set },
             grace: 2,
             mercy: 3,
+            deadline: None,
             force: true,
             __non_exhaustive: (),
         }
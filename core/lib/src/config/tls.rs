@@ -1,7 +1,8 @@
 use figment::value::magic::{Either, RelativePathBuf};
 use serde::{Deserialize, Serialize};
 
-/// TLS configuration: a certificate chain and a private key.
+/// TLS configuration: a certificate chain, a private key, and session
+/// resumption settings.
 ///
 /// Both `certs` and `key` can be configured as a path or as raw bytes. `certs`
 /// must be a DER-encoded X.509 TLS certificate chain, while `key` must be a
@@ -24,6 +25,48 @@ use serde::{Deserialize, Serialize};
 ///
 /// When a path is configured in a file source, such as `Rocket.toml`, relative
 /// paths are interpreted as being relative to the source file's directory.
+///
+/// # Session Resumption
+///
+/// A client that has previously connected can _resume_ its TLS session,
+/// skipping the certificate exchange and saving a round trip on TLS 1.2.
+/// Sessions are always resumed from a server-side cache of recent sessions.
+/// When `session_tickets` is enabled, as it is by default, sessions can also
+/// be resumed via _tickets_: encrypted session state that the client stores
+/// and presents on reconnect. Tickets are the only means of resumption for
+/// TLS 1.3. The keys that encrypt tickets are generated at launch, so tickets
+/// issued before a restart are not accepted after it.
+///
+/// `ticket_lifetime` is the number of seconds, at most 12 hours, for which a
+/// ticket can be used to resume a session. Shorter lifetimes rotate the keys
+/// that encrypt tickets more often, limiting how much past traffic a leak of
+/// those keys would expose. A lifetime of `0` disables tickets.
+///
+/// ```rust
+/// use rocket::Config;
+///
+/// let figment = Config::figment()
+///     .merge(("tls.certs", "/ssl/certs.pem"))
+///     .merge(("tls.key", "/ssl/key.pem"))
+///     .merge(("tls.ticket_lifetime", 60 * 60));
+///
+/// let config = Config::from(figment);
+/// let tls_config = config.tls.as_ref().unwrap();
+/// assert!(tls_config.session_tickets());
+/// assert_eq!(tls_config.ticket_lifetime(), 3600);
+/// ```
+///
+/// ## 0-RTT
+///
+/// Rocket's TLS implementation does not accept TLS 1.3 early data, also known
+/// as 0-RTT, as the underlying TLS library does not support it for servers.
+/// Every request is thus received after the handshake completes and can't be
+/// replayed by an attacker the way early data can. Applications served behind
+/// a TLS-terminating proxy that accepts early data should check for the
+/// `Early-Data: 1` header of [RFC 8470] and respond with `425 Too Early` to
+/// requests that are unsafe to replay.
+///
+/// [RFC 8470]: https://tools.ietf.org/html/rfc8470
 #[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
 pub struct TlsConfig {
     /// Path or raw bytes for the DER-encoded X.509 TLS certificate chain.
@@ -31,6 +74,20 @@ pub struct TlsConfig {
     /// Path or raw bytes to DER-encoded ASN.1 key in either PKCS#8 or PKCS#1
     /// format.
     pub(crate) key: Either<RelativePathBuf, Vec<u8>>,
+    /// Whether to issue session tickets. Defaults to `true`.
+    #[serde(default = "default_session_tickets")]
+    pub(crate) session_tickets: bool,
+    /// The lifetime of session tickets in seconds. Defaults to 12 hours.
+    #[serde(default = "default_ticket_lifetime")]
+    pub(crate) ticket_lifetime: u32,
+}
+
+fn default_session_tickets() -> bool {
+    true
+}
+
+fn default_ticket_lifetime() -> u32 {
+    12 * 60 * 60
 }
 
 impl TlsConfig {
@@ -50,7 +107,9 @@ impl TlsConfig {
     {
         TlsConfig {
             certs: Either::Left(certs.as_ref().to_path_buf().into()),
-            key: Either::Left(key.as_ref().to_path_buf().into())
+            key: Either::Left(key.as_ref().to_path_buf().into()),
+            session_tickets: default_session_tickets(),
+            ticket_lifetime: default_ticket_lifetime(),
         }
     }
 
@@ -70,8 +129,10 @@ impl TlsConfig {
     /// ```
     pub fn from_bytes(certs: &[u8], key: &[u8]) -> Self {
         TlsConfig {
-            certs: Either::Right(certs.to_vec().into()),
-            key: Either::Right(key.to_vec().into())
+            certs: Either::Right(certs.to_vec()),
+            key: Either::Right(key.to_vec()),
+            session_tickets: default_session_tickets(),
+            ticket_lifetime: default_ticket_lifetime(),
         }
     }
 
@@ -121,6 +182,76 @@ impl TlsConfig {
             Either::Right(bytes) => either::Either::Right(&bytes),
         }
     }
+
+    /// Sets whether session tickets are issued. See [Session
+    /// Resumption](#session-resumption) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::TlsConfig;
+    ///
+    /// let tls_config = TlsConfig::from_paths("/ssl/certs.pem", "/ssl/key.pem")
+    ///     .with_session_tickets(false);
+    ///
+    /// assert!(!tls_config.session_tickets());
+    /// ```
+    pub fn with_session_tickets(mut self, enabled: bool) -> Self {
+        self.session_tickets = enabled;
+        self
+    }
+
+    /// Sets the lifetime of session tickets in `seconds`. Lifetimes longer
+    /// than 12 hours are capped. A lifetime of `0` disables tickets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::TlsConfig;
+    ///
+    /// let tls_config = TlsConfig::from_paths("/ssl/certs.pem", "/ssl/key.pem")
+    ///     .with_ticket_lifetime(60 * 60);
+    ///
+    /// assert_eq!(tls_config.ticket_lifetime(), 3600);
+    /// ```
+    pub fn with_ticket_lifetime(mut self, seconds: u32) -> Self {
+        self.ticket_lifetime = seconds;
+        self
+    }
+
+    /// Returns whether session tickets are issued: `true` if
+    /// `session_tickets` is enabled and `ticket_lifetime` is nonzero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::TlsConfig;
+    ///
+    /// let tls_config = TlsConfig::from_paths("/ssl/certs.pem", "/ssl/key.pem");
+    /// assert!(tls_config.session_tickets());
+    /// assert!(!tls_config.clone().with_session_tickets(false).session_tickets());
+    /// assert!(!tls_config.with_ticket_lifetime(0).session_tickets());
+    /// ```
+    pub fn session_tickets(&self) -> bool {
+        self.session_tickets && self.ticket_lifetime > 0
+    }
+
+    /// Returns the lifetime of session tickets in seconds, capped to 12 hours.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::config::TlsConfig;
+    ///
+    /// let tls_config = TlsConfig::from_paths("/ssl/certs.pem", "/ssl/key.pem");
+    /// assert_eq!(tls_config.ticket_lifetime(), 12 * 60 * 60);
+    ///
+    /// let tls_config = tls_config.with_ticket_lifetime(u32::MAX);
+    /// assert_eq!(tls_config.ticket_lifetime(), 12 * 60 * 60);
+    /// ```
+    pub fn ticket_lifetime(&self) -> u32 {
+        self.ticket_lifetime.min(default_ticket_lifetime())
+    }
 }
 
 #[cfg(feature = "tls")]
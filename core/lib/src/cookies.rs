@@ -1,12 +1,14 @@
 use std::fmt;
+use std::collections::HashSet;
 
 use parking_lot::Mutex;
 
 use crate::Config;
+use crate::http::Header;
 use crate::http::private::cookie;
 
 #[doc(inline)]
-pub use self::cookie::{Cookie, SameSite, Iter};
+pub use self::cookie::{Cookie, CookieBuilder, Expiration, SameSite, Iter};
 
 /// Collection of one or more HTTP cookies.
 ///
@@ -139,6 +141,21 @@ pub use self::cookie::{Cookie, SameSite, Iter};
 /// collection via the [`get_private()`], [`add_private()`], and
 /// [`remove_private()`] methods.
 ///
+/// # Partitioned Cookies
+///
+/// A _partitioned_ cookie, one with the `Partitioned` attribute, is stored by
+/// browsers separately for each top-level site it is set in, as proposed by
+/// [CHIPS]. Browsers that block third-party cookies generally accept cookies
+/// set in a third-party context, such as an embedded frame, only if they are
+/// partitioned. Since [`Cookie`] has no `Partitioned` attribute, partitioned
+/// cookies are added with [`add_partitioned()`] and
+/// [`add_private_partitioned()`] instead; they are retrieved and removed just
+/// like other cookies.
+///
+/// [CHIPS]: https://developer.mozilla.org/en-US/docs/Web/Privacy/Partitioned_cookies
+/// [`add_partitioned()`]: #method.add_partitioned
+/// [`add_private_partitioned()`]: #method.add_private_partitioned
+///
 /// ## Encryption Key
 ///
 /// To encrypt private cookies, Rocket uses the 256-bit key specified in the
@@ -170,16 +187,18 @@ impl<'a> Clone for CookieJar<'a> {
     }
 }
 
+/// A pending change: the cookie and whether it is private and, for additions,
+/// whether it is partitioned.
 #[derive(Clone)]
 enum Op {
-    Add(Cookie<'static>, bool),
+    Add(Cookie<'static>, bool, bool),
     Remove(Cookie<'static>, bool),
 }
 
 impl Op {
     fn cookie(&self) -> &Cookie<'static> {
         match self {
-            Op::Add(c, _) | Op::Remove(c, _) => c
+            Op::Add(c, ..) | Op::Remove(c, _) => c
         }
     }
 }
@@ -261,7 +280,7 @@ impl<'a> CookieJar<'a> {
         let ops = self.ops.lock();
         for op in ops.iter().rev().filter(|op| op.cookie().name() == name) {
             match op {
-                Op::Add(c, _) => return Some(c.clone()),
+                Op::Add(c, ..) => return Some(c.clone()),
                 Op::Remove(_, _) => return None,
             }
         }
@@ -321,7 +340,38 @@ impl<'a> CookieJar<'a> {
     /// ```
     pub fn add(&self, mut cookie: Cookie<'static>) {
         Self::set_defaults(&mut cookie);
-        self.ops.lock().push(Op::Add(cookie, false));
+        self.ops.lock().push(Op::Add(cookie, false, false));
+    }
+
+    /// Adds `cookie` to the collection as a _partitioned_ cookie: the
+    /// `Set-Cookie` header for `cookie` includes the `Partitioned` attribute.
+    ///
+    /// Browsers reject partitioned cookies that aren't also `Secure`, so the
+    /// `Secure` attribute is always set, overriding any value set on `cookie`.
+    /// Otherwise, the same defaults as for [`add()`](#method.add) are set. Note
+    /// that `SameSite=Strict` and `SameSite=Lax` prevent a cookie from being
+    /// sent in a third-party context; partitioned cookies are typically set
+    /// with `SameSite=None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Cookie, SameSite, CookieJar};
+    ///
+    /// #[get("/embed")]
+    /// fn embed(jar: &CookieJar<'_>) {
+    ///     let cookie = Cookie::build("session", "abc123")
+    ///         .same_site(SameSite::None);
+    ///
+    ///     // Set-Cookie: session=abc123; SameSite=None; Secure; Path=/; Partitioned
+    ///     jar.add_partitioned(cookie.finish());
+    /// }
+    /// ```
+    pub fn add_partitioned(&self, mut cookie: Cookie<'static>) {
+        Self::set_defaults(&mut cookie);
+        cookie.set_secure(true);
+        self.ops.lock().push(Op::Add(cookie, false, true));
     }
 
     /// Adds `cookie` to the collection. The cookie's value is encrypted with
@@ -356,7 +406,33 @@ impl<'a> CookieJar<'a> {
     #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
     pub fn add_private(&self, mut cookie: Cookie<'static>) {
         Self::set_private_defaults(&mut cookie);
-        self.ops.lock().push(Op::Add(cookie, true));
+        self.ops.lock().push(Op::Add(cookie, true, false));
+    }
+
+    /// Adds `cookie` to the collection as a private, _partitioned_ cookie. The
+    /// cookie's value is encrypted as with
+    /// [`add_private()`](#method.add_private), and its `Set-Cookie` header
+    /// includes the `Partitioned` and `Secure` attributes as with
+    /// [`add_partitioned()`](#method.add_partitioned).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::{Cookie, SameSite, CookieJar};
+    ///
+    /// #[get("/embed")]
+    /// fn embed(jar: &CookieJar<'_>) {
+    ///     let cookie = Cookie::build("user_id", "42").same_site(SameSite::None);
+    ///     jar.add_private_partitioned(cookie.finish());
+    /// }
+    /// ```
+    #[cfg(feature = "secrets")]
+    #[cfg_attr(nightly, doc(cfg(feature = "secrets")))]
+    pub fn add_private_partitioned(&self, mut cookie: Cookie<'static>) {
+        Self::set_private_defaults(&mut cookie);
+        cookie.set_secure(true);
+        self.ops.lock().push(Op::Add(cookie, true, true));
     }
 
     /// Removes `cookie` from this collection and generates a "removal" cookies
@@ -439,26 +515,39 @@ impl<'a> CookieJar<'a> {
         self.jar.iter()
     }
 
+    /// Returns `true` if cookies have been added or removed.
+    pub(crate) fn has_delta(&self) -> bool {
+        !self.ops.lock().is_empty()
+    }
+
     /// Removes all delta cookies.
     #[inline(always)]
     pub(crate) fn reset_delta(&self) {
         self.ops.lock().clear();
     }
 
+    /// Removes all delta cookies, returning a `Set-Cookie` header for each.
+    ///
     /// TODO: This could be faster by just returning the cookies directly via
     /// an ordered hash-set of sorts.
-    pub(crate) fn take_delta_jar(&self) -> cookie::CookieJar {
+    pub(crate) fn take_delta(&self) -> Vec<Header<'static>> {
         let ops = std::mem::replace(&mut *self.ops.lock(), Vec::new());
         let mut jar = cookie::CookieJar::new();
+        let mut partitioned = HashSet::new();
 
         for op in ops {
             match op {
-                Op::Add(c, false) => jar.add(c),
+                Op::Add(c, false, is_partitioned) => {
+                    Self::track_partitioned(&mut partitioned, &c, is_partitioned);
+                    jar.add(c);
+                }
                 #[cfg(feature = "secrets")]
-                Op::Add(c, true) => {
+                Op::Add(c, true, is_partitioned) => {
+                    Self::track_partitioned(&mut partitioned, &c, is_partitioned);
                     jar.private_mut(&self.config.secret_key.key).add(c);
                 }
                 Op::Remove(mut c, _) => {
+                    partitioned.remove(c.name());
                     if self.jar.get(c.name()).is_some() {
                         c.make_removal();
                         jar.add(c);
@@ -471,7 +560,19 @@ impl<'a> CookieJar<'a> {
             }
         }
 
-        jar
+        jar.delta()
+            .map(|cookie| match partitioned.contains(cookie.name()) {
+                true => Header::new("Set-Cookie", format!("{}; Partitioned", cookie.encoded())),
+                false => Header::from(cookie),
+            })
+            .collect()
+    }
+
+    fn track_partitioned(set: &mut HashSet<String>, cookie: &Cookie<'_>, partitioned: bool) {
+        match partitioned {
+            true => set.insert(cookie.name().to_string()),
+            false => set.remove(cookie.name()),
+        };
     }
 
     /// Adds an original `cookie` to this collection.
@@ -550,3 +651,168 @@ impl fmt::Debug for CookieJar<'_> {
     }
 
 }
+
+/// The longest lifetime, 400 days, that browsers honor for a cookie, per
+/// [RFC 6265bis](https://httpwg.org/http-extensions/draft-ietf-httpbis-rfc6265bis.html#section-5.6.1).
+const MAX_LIFETIME_SECS: u64 = 400 * 24 * 60 * 60;
+
+/// Duration-based expiration helpers for [`CookieBuilder`].
+///
+/// Setting a cookie's lifetime with [`CookieBuilder`] alone requires a
+/// [`time::Duration`] for `Max-Age` and an [`Expiration`] or
+/// `time::OffsetDateTime` for `Expires`. The methods of this trait take a
+/// standard library [`Duration`](std::time::Duration) or a number of seconds
+/// instead and set _both_ attributes: `Max-Age` for modern clients and the
+/// equivalent `Expires`, computed from the current time, for older clients
+/// which ignore `Max-Age`.
+///
+/// Lifetimes longer than 400 days are capped at 400 days, the longest that
+/// browsers honor.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::time::Duration;
+///
+/// use rocket::http::{Cookie, CookieJar, CookieExpiry};
+///
+/// #[post("/login")]
+/// fn login(jar: &CookieJar<'_>) {
+///     // Remembered for an hour: `Max-Age=3600; Expires=<in one hour>`.
+///     jar.add(Cookie::build("remember", "yes").max_age_secs(3600).finish());
+///
+///     // Remembered until the browser is closed: no `Max-Age` or `Expires`.
+///     jar.add(Cookie::build("session", "abc").session().finish());
+/// }
+///
+/// #[post("/logout")]
+/// fn logout(jar: &CookieJar<'_>) {
+///     jar.add(Cookie::build("remember", "").expired().finish());
+/// }
+/// ```
+pub trait CookieExpiry: Sized {
+    /// Sets the cookie to expire after `duration`, rounded down to whole
+    /// seconds, by setting both `Max-Age` and `Expires`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::http::{Cookie, CookieExpiry};
+    ///
+    /// let cookie = Cookie::build("name", "value")
+    ///     .expires_in(Duration::from_secs(90))
+    ///     .finish();
+    ///
+    /// assert_eq!(cookie.max_age().unwrap().whole_seconds(), 90);
+    /// assert!(cookie.expires_datetime().is_some());
+    /// ```
+    fn expires_in(self, duration: std::time::Duration) -> Self;
+
+    /// Sets the cookie to expire after `secs` seconds, by setting both
+    /// `Max-Age` and `Expires`. Equivalent to
+    /// `expires_in(Duration::from_secs(secs))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{Cookie, CookieExpiry};
+    ///
+    /// let cookie = Cookie::build("name", "value").max_age_secs(3600).finish();
+    /// assert_eq!(cookie.max_age().unwrap().whole_seconds(), 3600);
+    ///
+    /// // Lifetimes are capped at 400 days.
+    /// let cookie = Cookie::build("name", "value").max_age_secs(u64::MAX).finish();
+    /// assert_eq!(cookie.max_age().unwrap().whole_days(), 400);
+    /// ```
+    fn max_age_secs(self, secs: u64) -> Self {
+        self.expires_in(std::time::Duration::from_secs(secs))
+    }
+
+    /// Marks the cookie as a session cookie, one which clients discard when
+    /// the session ends, usually when the browser is closed, by setting its
+    /// expiration to [`Expiration::Session`].
+    ///
+    /// A built cookie without `Max-Age` or `Expires` is already a session
+    /// cookie, but [`CookieJar::add_private()`] gives private cookies without
+    /// an expiration a one week lifetime. An explicit session expiration
+    /// prevents this. Any `Max-Age` set earlier, which clients would honor
+    /// over the session expiration, is removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{Cookie, CookieExpiry, Expiration};
+    ///
+    /// let cookie = Cookie::build("name", "value").session().finish();
+    /// assert_eq!(cookie.expires(), Some(Expiration::Session));
+    /// assert!(cookie.max_age().is_none());
+    ///
+    /// let cookie = Cookie::build("name", "value").max_age_secs(60).session().finish();
+    /// assert_eq!(cookie.expires(), Some(Expiration::Session));
+    /// assert!(cookie.max_age().is_none());
+    /// ```
+    fn session(self) -> Self;
+
+    /// Sets the cookie to expire immediately, by setting `Max-Age` to `0` and
+    /// `Expires` to a date in the past, so that clients delete any cookie
+    /// with the same name, path, and domain.
+    ///
+    /// [`CookieJar::remove()`] does this for cookies in a jar. This method is
+    /// useful when a removal cookie is constructed directly, such as for a
+    /// response built without a `CookieJar`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{Cookie, CookieExpiry};
+    ///
+    /// let cookie = Cookie::build("name", "").expired().finish();
+    /// assert_eq!(cookie.max_age().unwrap().whole_seconds(), 0);
+    /// assert!(cookie.expires_datetime().unwrap() < time::OffsetDateTime::now_utc());
+    /// ```
+    fn expired(self) -> Self;
+}
+
+impl CookieExpiry for CookieBuilder<'_> {
+    fn expires_in(self, duration: std::time::Duration) -> Self {
+        let secs = duration.as_secs().min(MAX_LIFETIME_SECS) as i64;
+        let lifetime = time::Duration::seconds(secs);
+        self.max_age(lifetime).expires(time::OffsetDateTime::now_utc() + lifetime)
+    }
+
+    fn session(self) -> Self {
+        // A `CookieBuilder` can't unset `Max-Age`, so rebuild without it.
+        let cookie = self.finish();
+        let mut builder = Cookie::build(cookie.name().to_string(), cookie.value().to_string())
+            .expires(Expiration::Session);
+
+        if let Some(path) = cookie.path() {
+            builder = builder.path(path.to_string());
+        }
+
+        if let Some(domain) = cookie.domain() {
+            builder = builder.domain(domain.to_string());
+        }
+
+        if let Some(secure) = cookie.secure() {
+            builder = builder.secure(secure);
+        }
+
+        if let Some(http_only) = cookie.http_only() {
+            builder = builder.http_only(http_only);
+        }
+
+        if let Some(same_site) = cookie.same_site() {
+            builder = builder.same_site(same_site);
+        }
+
+        builder
+    }
+
+    fn expired(self) -> Self {
+        let past = time::OffsetDateTime::now_utc() - time::Duration::days(365);
+        self.max_age(time::Duration::zero()).expires(past)
+    }
+}
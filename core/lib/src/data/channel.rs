@@ -0,0 +1,236 @@
+use std::io;
+use std::pin::Pin;
+use std::future::Future;
+
+use futures::stream::StreamExt;
+use tokio::sync::mpsc;
+use tokio_util::io::ReaderStream;
+
+use crate::request::Request;
+use crate::data::{Data, FromData, Outcome, Limits, ByteUnit, Bytes, N};
+
+/// The future that reads a request body into a [`ChannelBody`]'s channel.
+type Pump<'r> = Pin<Box<dyn Future<Output = io::Result<N>> + Send + 'r>>;
+
+/// A data guard that delivers the request body as chunks over a bounded
+/// channel.
+///
+/// A `ChannelBody` reads the request body into a bounded
+/// [`mpsc`](tokio::sync::mpsc) channel of [`Bytes`], allowing a handler to
+/// process chunks as the client uploads them without buffering the entire body.
+/// When the channel is full, the body is not read until the receiver makes
+/// room, applying back-pressure to the client.
+///
+/// The body is read up to a limit: that of the `channel` limit, if one is set,
+/// or [`Limits::CHANNEL`] otherwise. On I/O error, client disconnect, or once
+/// the limit is reached, the channel is closed. Whether the body was read to
+/// completion can be checked with [`ChannelBody::finish()`].
+///
+/// # Usage
+///
+/// Because the body is borrowed from the request, it is read by the same task
+/// that runs the handler. The simplest way to drive the read is to call
+/// [`ChannelBody::recv()`], which reads from the body as needed:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::io;
+/// use rocket::data::ChannelBody;
+///
+/// #[post("/count", data = "<body>")]
+/// async fn count(mut body: ChannelBody<'_>) -> io::Result<String> {
+///     let mut newlines = 0;
+///     while let Some(chunk) = body.recv().await {
+///         newlines += chunk.iter().filter(|&&b| b == b'\n').count();
+///     }
+///
+///     let n = body.finish().await?;
+///     Ok(format!("{} newlines in {} bytes", newlines, n.written))
+/// }
+/// ```
+///
+/// To hand the raw [`mpsc::Receiver`] to other code, split the guard with
+/// [`ChannelBody::into_parts()`]. The returned future reads the body into the
+/// channel and must be polled concurrently with the receiver:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::data::{ChannelBody, Bytes};
+/// use rocket::tokio::sync::mpsc::Receiver;
+/// use rocket::tokio::join;
+///
+/// async fn process(mut rx: Receiver<Bytes>) -> usize {
+///     let mut len = 0;
+///     while let Some(chunk) = rx.recv().await {
+///         len += chunk.len();
+///     }
+///
+///     len
+/// }
+///
+/// #[post("/pipeline", data = "<body>")]
+/// async fn pipeline(body: ChannelBody<'_>) -> String {
+///     let (rx, pump) = body.into_parts();
+///     let (n, len) = join!(pump, process(rx));
+///     format!("{:?} {}", n.map(|n| n.complete), len)
+/// }
+/// ```
+pub struct ChannelBody<'r> {
+    rx: mpsc::Receiver<Bytes>,
+    pump: Option<Pump<'r>>,
+    result: Option<io::Result<N>>,
+}
+
+impl<'r> ChannelBody<'r> {
+    /// The default capacity, in chunks, of the channel.
+    pub const CAPACITY: usize = 16;
+
+    /// Creates a new `ChannelBody` that reads up to `limit` bytes of `data`
+    /// into a channel of `capacity` chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Data, ChannelBody, ToByteUnit};
+    ///
+    /// async fn f(data: Data<'_>) {
+    ///     let body = ChannelBody::new(data, 64.mebibytes(), 4);
+    /// }
+    /// ```
+    pub fn new(data: Data<'r>, limit: ByteUnit, capacity: usize) -> Self {
+        let (tx, rx) = mpsc::channel(capacity);
+        let pump = async move {
+            let mut stream = data.open(limit);
+            let mut written = 0;
+            let mut chunks = ReaderStream::new(&mut stream);
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk?;
+                let len = chunk.len() as u64;
+                if tx.send(chunk).await.is_err() {
+                    return Ok(N { written, complete: false });
+                }
+
+                written += len;
+            }
+
+            drop(tx);
+            drop(chunks);
+            let complete = !stream.limit_exceeded().await?;
+            Ok(N { written, complete })
+        };
+
+        ChannelBody { rx, pump: Some(Box::pin(pump)), result: None }
+    }
+
+    /// Receives the next chunk of the body, reading from the body as needed.
+    /// Returns `None` once the channel is closed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ChannelBody;
+    ///
+    /// async fn total(mut body: ChannelBody<'_>) -> usize {
+    ///     let mut len = 0;
+    ///     while let Some(chunk) = body.recv().await {
+    ///         len += chunk.len();
+    ///     }
+    ///
+    ///     len
+    /// }
+    /// ```
+    pub async fn recv(&mut self) -> Option<Bytes> {
+        loop {
+            let pump = match self.pump {
+                Some(ref mut pump) => pump,
+                None => return self.rx.recv().await,
+            };
+
+            tokio::select! {
+                biased;
+                chunk = self.rx.recv() => return chunk,
+                result = pump => {
+                    self.result = Some(result);
+                    self.pump = None;
+                }
+            }
+        }
+    }
+
+    /// Closes the channel, stopping any further reads from the body, and
+    /// returns the result of reading the body. Chunks that have been read but
+    /// not received are discarded.
+    ///
+    /// On success, returns an [`N`] with the number of bytes sent into the
+    /// channel. The body was read in its entirety iff [`N::complete`] is
+    /// `true`: it is `false` if the limit was exceeded or the channel was
+    /// closed before the body was exhausted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    /// use rocket::data::ChannelBody;
+    ///
+    /// async fn first(mut body: ChannelBody<'_>) -> io::Result<bool> {
+    ///     let _chunk = body.recv().await;
+    ///     let n = body.finish().await?;
+    ///     Ok(n.complete)
+    /// }
+    /// ```
+    pub async fn finish(mut self) -> io::Result<N> {
+        self.rx.close();
+        match self.pump {
+            Some(pump) => pump.await,
+            None => self.result.expect("finished pump has result"),
+        }
+    }
+
+    /// Splits `self` into the channel's receiver and a future that reads the
+    /// body into the channel, resolving to the result of the read as in
+    /// [`ChannelBody::finish()`]. The future must be polled for the receiver
+    /// to make progress.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ChannelBody;
+    ///
+    /// async fn total(body: ChannelBody<'_>) -> usize {
+    ///     let (mut rx, pump) = body.into_parts();
+    ///     let consumer = async move {
+    ///         let mut len = 0;
+    ///         while let Some(chunk) = rx.recv().await {
+    ///             len += chunk.len();
+    ///         }
+    ///
+    ///         len
+    ///     };
+    ///
+    ///     rocket::tokio::join!(pump, consumer).1
+    /// }
+    /// ```
+    pub fn into_parts(self) -> (mpsc::Receiver<Bytes>, Pump<'r>) {
+        let pump = match (self.pump, self.result) {
+            (Some(pump), _) => pump,
+            (None, Some(result)) => Box::pin(async move { result }),
+            (None, None) => unreachable!("pump or result always present"),
+        };
+
+        (self.rx, pump)
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromData<'r> for ChannelBody<'r> {
+    type Error = std::convert::Infallible;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let limit = req.limits().get("channel").unwrap_or(Limits::CHANNEL);
+        Outcome::Success(ChannelBody::new(data, limit, Self::CAPACITY))
+    }
+}
@@ -0,0 +1,165 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{Stream, StreamExt};
+use futures::ready;
+
+use crate::data::{StreamReader, Bytes, Capped, N};
+
+/// Raw data stream of a request body as a stream of chunks.
+///
+/// This stream can only be obtained by calling
+/// [`Data::chunks()`](crate::data::Data::chunks()) with a data limit. Unlike
+/// [`DataStream`](crate::data::DataStream), which flattens the body into a
+/// byte stream, a `DataChunks` yields the body in the pieces it was received
+/// in, allowing framed protocols to observe chunk boundaries.
+///
+/// # Boundaries
+///
+/// For a request with a `Transfer-Encoding: chunked` body, every item is a
+/// single transfer-encoding chunk or a part of one: chunks are never merged,
+/// but a large chunk may be yielded in several consecutive parts as it is
+/// received. Empty chunks are not yielded. For any other body, including those
+/// of [local](crate::local) requests, the body behaves as a single logical
+/// stream and no particular boundaries should be assumed.
+///
+/// Any data that was read via [`Data::peek()`](crate::data::Data::peek()) is
+/// yielded first as a single chunk.
+///
+/// # Limit
+///
+/// At most `limit` bytes are yielded; the chunk that crosses the limit is
+/// truncated, and the stream ends thereafter. Whether the entire body was
+/// yielded can be checked via [`DataChunks::n()`] once the stream has ended.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::data::{Data, ToByteUnit};
+/// use rocket::futures::StreamExt;
+///
+/// async fn chunk_sizes(data: Data<'_>) -> std::io::Result<Vec<usize>> {
+///     let mut sizes = vec![];
+///     let mut chunks = data.chunks(1.mebibytes());
+///     while let Some(chunk) = chunks.next().await {
+///         sizes.push(chunk?.len());
+///     }
+///
+///     Ok(sizes)
+/// }
+/// ```
+pub struct DataChunks<'r> {
+    buffer: Option<Bytes>,
+    stream: StreamReader<'r>,
+    remaining: u64,
+    n: N,
+    done: bool,
+}
+
+impl<'r> DataChunks<'r> {
+    pub(crate) fn new(buffer: Vec<u8>, stream: StreamReader<'r>, limit: u64) -> Self {
+        let buffer = Some(Bytes::from(buffer)).filter(|b| !b.is_empty());
+        let n = N { written: 0, complete: false };
+        DataChunks { buffer, stream, remaining: limit, n, done: false }
+    }
+
+    /// Returns the number of bytes yielded so far and whether the stream has
+    /// ended with the entire body yielded. `N::complete` is `false` until the
+    /// stream has ended and remains `false` if the limit was exceeded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Data, ToByteUnit};
+    /// use rocket::futures::StreamExt;
+    ///
+    /// async fn count(data: Data<'_>) -> std::io::Result<u64> {
+    ///     let mut chunks = data.chunks(1.mebibytes());
+    ///     let mut count = 0;
+    ///     while let Some(chunk) = chunks.next().await {
+    ///         chunk?;
+    ///         count += 1;
+    ///     }
+    ///
+    ///     assert!(chunks.n().complete);
+    ///     Ok(count)
+    /// }
+    /// ```
+    pub fn n(&self) -> N {
+        self.n
+    }
+
+    /// Collects all of the chunks in the stream into a `Vec`, preserving their
+    /// boundaries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// async fn frames(data: Data<'_>) -> std::io::Result<usize> {
+    ///     let chunks = data.chunks(1.mebibytes()).into_vec().await?;
+    ///     if !chunks.is_complete() {
+    ///         println!("there are bytes remaining in the body");
+    ///     }
+    ///
+    ///     Ok(chunks.len())
+    /// }
+    /// ```
+    pub async fn into_vec(mut self) -> io::Result<Capped<Vec<Bytes>>> {
+        let mut chunks = vec![];
+        while let Some(chunk) = self.next().await {
+            chunks.push(chunk?);
+        }
+
+        Ok(Capped::new(chunks, self.n))
+    }
+}
+
+impl Stream for DataChunks<'_> {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            let mut chunk = match self.buffer.take() {
+                Some(buffer) => buffer,
+                None => match ready!(Pin::new(&mut self.stream).poll_next(cx)) {
+                    Some(Ok(chunk)) => chunk,
+                    Some(Err(e)) => {
+                        self.done = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                    None => {
+                        self.done = true;
+                        self.n.complete = true;
+                        return Poll::Ready(None);
+                    }
+                }
+            };
+
+            if chunk.is_empty() {
+                continue;
+            }
+
+            // The limit was reached exactly and there's more: it's exceeded.
+            if self.remaining == 0 {
+                self.done = true;
+                return Poll::Ready(None);
+            }
+
+            if chunk.len() as u64 > self.remaining {
+                chunk.truncate(self.remaining as usize);
+                self.done = true;
+            }
+
+            self.remaining -= chunk.len() as u64;
+            self.n.written += chunk.len() as u64;
+            return Poll::Ready(Some(Ok(chunk)));
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::tokio::io::AsyncReadExt;
 use crate::data::data_stream::DataStream;
+use crate::data::chunks::DataChunks;
 use crate::data::{ByteUnit, StreamReader};
 
 /// The number of bytes to read into the "peek" buffer.
@@ -27,7 +28,8 @@ pub const PEEK_BYTES: usize = 512;
 /// # Reading Data
 ///
 /// Data may be read from a `Data` object by calling either the
-/// [`open()`](Data::open()) or [`peek()`](Data::peek()) methods.
+/// [`open()`](Data::open()), [`chunks()`](Data::chunks()), or
+/// [`peek()`](Data::peek()) methods.
 ///
 /// The `open` method consumes the `Data` object and returns the raw data
 /// stream. The `Data` object is consumed for safety reasons: consuming the
@@ -43,13 +45,15 @@ pub struct Data<'r> {
     stream: StreamReader<'r>,
 }
 
+// `&Data` must be `Send` so that fairings can hold one across an `.await`.
+const _: fn() = || {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<Data<'static>>();
+};
+
 impl<'r> Data<'r> {
     /// Create a `Data` from a recognized `stream`.
     pub(crate) fn from<S: Into<StreamReader<'r>>>(stream: S) -> Data<'r> {
-        // TODO.async: This used to also set the read timeout to 5 seconds.
-        // Such a short read timeout is likely no longer necessary, but some
-        // kind of idle timeout should be implemented.
-
         let stream = stream.into();
         let buffer = Vec::with_capacity(PEEK_BYTES / 8);
         Data { buffer, stream, is_complete: false }
@@ -86,6 +90,28 @@ impl<'r> Data<'r> {
         DataStream::new(self.buffer, self.stream, limit.into())
     }
 
+    /// Returns the raw data stream as a stream of chunks, limited to `limit`
+    /// bytes.
+    ///
+    /// Like [`Data::open()`], the stream contains all of the data in the body
+    /// of the request, including that in the `peek` buffer. Unlike the stream
+    /// returned by `open()`, the body is yielded in the chunks it was received
+    /// in, preserving the boundaries of `Transfer-Encoding: chunked` bodies.
+    /// See [`DataChunks`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::{Data, ToByteUnit};
+    ///
+    /// fn handler(data: Data<'_>) {
+    ///     let chunks = data.chunks(2.mebibytes());
+    /// }
+    /// ```
+    pub fn chunks(self, limit: ByteUnit) -> DataChunks<'r> {
+        DataChunks::new(self.buffer, self.stream, limit.into())
+    }
+
     /// Retrieve at most `num` bytes from the `peek` buffer without consuming
     /// `self`.
     ///
@@ -147,7 +173,12 @@ impl<'r> Data<'r> {
     /// }
     /// ```
     pub async fn peek(&mut self, num: usize) -> &[u8] {
-        let num = std::cmp::min(PEEK_BYTES, num);
+        self.buffer_up_to(std::cmp::min(PEEK_BYTES, num)).await
+    }
+
+    /// Like [`Data::peek()`], but without limiting `num` to `PEEK_BYTES`. The
+    /// buffered data remains part of the body when the data is opened.
+    pub(crate) async fn buffer_up_to(&mut self, num: usize) -> &[u8] {
         let mut len = self.buffer.len();
         if len >= num {
             return &self.buffer[..num];
@@ -167,6 +198,33 @@ impl<'r> Data<'r> {
         &self.buffer[..std::cmp::min(len, num)]
     }
 
+    /// Returns a `Data` with the same contents as `self` which calls `f` with
+    /// each chunk of the body as it is read and with `None` when the body ends.
+    #[cfg(feature = "digest")]
+    pub(crate) fn inspect<F>(self, mut f: F) -> Data<'r>
+        where F: FnMut(Option<&[u8]>) + Send + Sync + 'r
+    {
+        use std::task::Poll;
+        use futures::stream::{self, StreamExt};
+        use crate::data::data_stream::BoxedStream;
+
+        let Data { buffer, stream: body, .. } = self;
+        let buffered = (!buffer.is_empty()).then(|| Ok(buffer.into()));
+        let mut chunks = stream::iter(buffered).chain(body);
+        let stream = stream::poll_fn(move |cx| {
+            let next = futures::ready!(chunks.poll_next_unpin(cx));
+            match next {
+                Some(Ok(ref chunk)) => f(Some(chunk)),
+                Some(Err(_)) => {},
+                None => f(None),
+            }
+
+            Poll::Ready(next)
+        });
+
+        Data::from(Box::pin(stream) as BoxedStream<'r>)
+    }
+
     /// Returns true if the `peek` buffer contains all of the data in the body
     /// of the request. Returns `false` if it does not or if it is not known if
     /// it does.
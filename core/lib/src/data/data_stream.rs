@@ -2,13 +2,16 @@ use std::pin::Pin;
 use std::task::{Context, Poll};
 use std::path::Path;
 use std::io::{self, Cursor};
+use std::future::Future;
+use std::time::Duration;
 
 use tokio::fs::File;
+use tokio::time::{Instant, Sleep};
 use tokio::io::{AsyncRead, AsyncWrite, AsyncReadExt, ReadBuf, Take};
 use futures::stream::Stream;
 use futures::ready;
 
-use crate::http::hyper;
+use crate::http::{hyper, Status};
 use crate::ext::{PollExt, Chain};
 use crate::data::{Capped, N};
 
@@ -47,6 +50,17 @@ pub struct DataStream<'r> {
 pub struct StreamReader<'r> {
     state: State,
     inner: StreamKind<'r>,
+    timeout: Option<ReadTimeout>,
+}
+
+/// An idle timeout on reads from the inner stream of a `StreamReader`.
+struct ReadTimeout {
+    /// How long to wait for the next chunk.
+    period: Duration,
+    /// Fires `period` after the inner stream first returned `Pending`.
+    sleep: Pin<Box<Sleep>>,
+    /// Whether `sleep` has been reset since the last chunk arrived.
+    armed: bool,
 }
 
 /// The current state of `StreamReader` `AsyncRead` adapter.
@@ -60,9 +74,16 @@ enum State {
 enum StreamKind<'r> {
     Empty,
     Body(&'r mut hyper::Body),
-    Multipart(multer::Field<'r>)
+    Multipart(multer::Field<'r>),
+    #[cfg(feature = "digest")]
+    Boxed(BoxedStream<'r>),
 }
 
+/// A type-erased stream of body chunks. It must be `Sync` so that `Data` is.
+#[cfg(feature = "digest")]
+pub(crate) type BoxedStream<'r> =
+    Pin<Box<dyn Stream<Item = io::Result<hyper::Bytes>> + Send + Sync + 'r>>;
+
 impl<'r> DataStream<'r> {
     pub(crate) fn new(buf: Vec<u8>, stream: StreamReader<'r>, limit: u64) -> Self {
         let chain = Chain::new(Cursor::new(buf), stream).take(limit);
@@ -70,7 +91,7 @@ impl<'r> DataStream<'r> {
     }
 
     /// Whether a previous read exhausted the set limit _and then some_.
-    async fn limit_exceeded(&mut self) -> io::Result<bool> {
+    pub(crate) async fn limit_exceeded(&mut self) -> io::Result<bool> {
         #[cold]
         async fn _limit_exceeded(stream: &mut DataStream<'_>) -> io::Result<bool> {
             stream.chain.set_limit(1);
@@ -229,19 +250,69 @@ impl<'r> DataStream<'r> {
 
 impl StreamReader<'_> {
     pub fn empty() -> Self {
-        Self { inner: StreamKind::Empty, state: State::Done }
+        Self { inner: StreamKind::Empty, state: State::Done, timeout: None }
+    }
+
+    /// Fails reads with `TimedOut` if no chunk arrives within `period` of a
+    /// read starting to wait for one.
+    pub(crate) fn with_timeout(mut self, period: Duration) -> Self {
+        let sleep = Box::pin(tokio::time::sleep(period));
+        self.timeout = Some(ReadTimeout { period, sleep, armed: false });
+        self
+    }
+
+    /// Polls the inner stream, enforcing the read timeout, if any.
+    fn poll_inner(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<hyper::Bytes>>> {
+        let next = Pin::new(&mut self.inner).poll_next(cx);
+        let timeout = match self.timeout {
+            Some(ref mut timeout) => timeout,
+            None => return next,
+        };
+
+        if next.is_ready() {
+            timeout.armed = false;
+            return next;
+        }
+
+        if !timeout.armed {
+            let deadline = Instant::now() + timeout.period;
+            timeout.sleep.as_mut().reset(deadline);
+            timeout.armed = true;
+        }
+
+        ready!(timeout.sleep.as_mut().poll(cx));
+        let msg = "timed out waiting for request body data";
+        Poll::Ready(Some(Err(io::Error::new(io::ErrorKind::TimedOut, msg))))
+    }
+}
+
+/// Returns the status to fail a data guard with if reading the body failed
+/// with `error`: `408 Request Timeout` if the read timed out, as it does once
+/// [`Config::body_read_timeout`](crate::Config::body_read_timeout) elapses,
+/// and `400 Bad Request` otherwise.
+pub(crate) fn io_error_status(error: &io::Error) -> Status {
+    match error.kind() {
+        io::ErrorKind::TimedOut => Status::RequestTimeout,
+        _ => Status::BadRequest,
     }
 }
 
 impl<'r> From<&'r mut hyper::Body> for StreamReader<'r> {
     fn from(body: &'r mut hyper::Body) -> Self {
-        Self { inner: StreamKind::Body(body), state: State::Pending }
+        Self { inner: StreamKind::Body(body), state: State::Pending, timeout: None }
     }
 }
 
 impl<'r> From<multer::Field<'r>> for StreamReader<'r> {
     fn from(field: multer::Field<'r>) -> Self {
-        Self { inner: StreamKind::Multipart(field), state: State::Pending }
+        Self { inner: StreamKind::Multipart(field), state: State::Pending, timeout: None }
+    }
+}
+
+#[cfg(feature = "digest")]
+impl<'r> From<BoxedStream<'r>> for StreamReader<'r> {
+    fn from(stream: BoxedStream<'r>) -> Self {
+        Self { inner: StreamKind::Boxed(stream), state: State::Pending, timeout: None }
     }
 }
 
@@ -268,6 +339,8 @@ impl Stream for StreamKind<'_> {
                 .map_err_ext(|e| io::Error::new(io::ErrorKind::Other, e)),
             StreamKind::Multipart(mp) => Pin::new(mp).poll_next(cx)
                 .map_err_ext(|e| io::Error::new(io::ErrorKind::Other, e)),
+            #[cfg(feature = "digest")]
+            StreamKind::Boxed(stream) => stream.as_mut().poll_next(cx),
             StreamKind::Empty => Poll::Ready(None),
         }
     }
@@ -276,11 +349,44 @@ impl Stream for StreamKind<'_> {
         match self {
             StreamKind::Body(body) => body.size_hint(),
             StreamKind::Multipart(mp) => mp.size_hint(),
+            #[cfg(feature = "digest")]
+            StreamKind::Boxed(stream) => stream.size_hint(),
             StreamKind::Empty => (0, Some(0)),
         }
     }
 }
 
+impl Stream for StreamReader<'_> {
+    type Item = io::Result<hyper::Bytes>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        // Yield whatever remains of a partially read chunk first.
+        match std::mem::replace(&mut self.state, State::Pending) {
+            State::Partial(cursor) => {
+                let (pos, bytes) = (cursor.position() as usize, cursor.into_inner());
+                if pos < bytes.len() {
+                    return Poll::Ready(Some(Ok(bytes.slice(pos..))));
+                }
+            }
+            State::Done => {
+                self.state = State::Done;
+                return Poll::Ready(None);
+            }
+            State::Pending => {}
+        }
+
+        let next = ready!(self.poll_inner(cx));
+        if next.is_none() {
+            self.state = State::Done;
+        }
+
+        Poll::Ready(next)
+    }
+}
+
 impl AsyncRead for StreamReader<'_> {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -290,7 +396,7 @@ impl AsyncRead for StreamReader<'_> {
         loop {
             self.state = match self.state {
                 State::Pending => {
-                    match ready!(Pin::new(&mut self.inner).poll_next(cx)) {
+                    match ready!(self.poll_inner(cx)) {
                         Some(Err(e)) => return Poll::Ready(Err(e)),
                         Some(Ok(bytes)) => State::Partial(Cursor::new(bytes)),
                         None => State::Done,
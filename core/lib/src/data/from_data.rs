@@ -1,6 +1,6 @@
 use crate::http::{RawStr, Status};
 use crate::request::{Request, local_cache};
-use crate::data::{Data, Limits};
+use crate::data::{Data, Limits, io_error_status};
 use crate::outcome::{self, IntoOutcome, try_outcome, Outcome::*};
 
 /// Type alias for the `Outcome` of [`FromData`].
@@ -202,7 +202,10 @@ impl<'r> FromData<'r> for Capped<String> {
 
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
         let limit = req.limits().get("string").unwrap_or(Limits::STRING);
-        data.open(limit).into_string().await.into_outcome(Status::BadRequest)
+        match data.open(limit).into_string().await {
+            Ok(value) => Success(value),
+            Err(e) => Failure((io_error_status(&e), e)),
+        }
     }
 }
 
@@ -265,7 +268,10 @@ impl<'r> FromData<'r> for Capped<Vec<u8>> {
 
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
         let limit = req.limits().get("bytes").unwrap_or(Limits::BYTES);
-        data.open(limit).into_bytes().await.into_outcome(Status::BadRequest)
+        match data.open(limit).into_bytes().await {
+            Ok(value) => Success(value),
+            Err(e) => Failure((io_error_status(&e), e)),
+        }
     }
 }
 
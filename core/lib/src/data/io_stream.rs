@@ -0,0 +1,123 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+use crate::http::hyper;
+
+/// A bidirectional stream to the client over an upgraded connection.
+///
+/// An `IoStream` is passed to an [`IoHandler`] once a connection has been
+/// upgraded to a different protocol. It implements [`AsyncRead`] and
+/// [`AsyncWrite`]; bytes read and written are exchanged with the client
+/// directly, with no framing from HTTP. See [`Response::set_upgrade()`] for
+/// details on how and when connections are upgraded.
+///
+/// [`Response::set_upgrade()`]: crate::Response::set_upgrade()
+pub struct IoStream {
+    kind: IoStreamKind,
+}
+
+enum IoStreamKind {
+    Upgraded(hyper::Upgraded),
+}
+
+/// An upgraded connection handler.
+///
+/// An `IoHandler` is registered with a response via
+/// [`Response::set_upgrade()`]. If the connection is upgraded, the handler is
+/// invoked with an [`IoStream`] to the client once the `101 Switching
+/// Protocols` response has been sent. It is not invoked otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+/// use std::pin::Pin;
+///
+/// use rocket::data::{IoHandler, IoStream};
+/// use rocket::tokio::io::{self as tio, AsyncWriteExt};
+///
+/// struct Echo;
+///
+/// #[rocket::async_trait]
+/// impl IoHandler for Echo {
+///     async fn io(self: Pin<Box<Self>>, io: IoStream) -> io::Result<()> {
+///         let (mut reader, mut writer) = tio::split(io);
+///         tio::copy(&mut reader, &mut writer).await?;
+///         writer.shutdown().await
+///     }
+/// }
+/// ```
+///
+/// [`Response::set_upgrade()`]: crate::Response::set_upgrade()
+#[crate::async_trait]
+pub trait IoHandler: Send {
+    /// Handles the upgraded connection `io` to the client.
+    async fn io(self: Pin<Box<Self>>, io: IoStream) -> io::Result<()>;
+}
+
+impl IoStream {
+    pub(crate) fn upgraded(io: hyper::Upgraded) -> Self {
+        IoStream { kind: IoStreamKind::Upgraded(io) }
+    }
+}
+
+impl AsyncRead for IoStream {
+    #[inline(always)]
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.get_mut().kind {
+            IoStreamKind::Upgraded(ref mut io) => Pin::new(io).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for IoStream {
+    #[inline(always)]
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut().kind {
+            IoStreamKind::Upgraded(ref mut io) => Pin::new(io).poll_write(cx, buf),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut().kind {
+            IoStreamKind::Upgraded(ref mut io) => Pin::new(io).poll_flush(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut().kind {
+            IoStreamKind::Upgraded(ref mut io) => Pin::new(io).poll_shutdown(cx),
+        }
+    }
+
+    #[inline(always)]
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut().kind {
+            IoStreamKind::Upgraded(ref mut io) => Pin::new(io).poll_write_vectored(cx, bufs),
+        }
+    }
+
+    #[inline(always)]
+    fn is_write_vectored(&self) -> bool {
+        match self.kind {
+            IoStreamKind::Upgraded(ref io) => io.is_write_vectored(),
+        }
+    }
+}
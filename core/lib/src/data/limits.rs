@@ -53,20 +53,34 @@ use crate::http::uncased::Uncased;
 ///
 /// The following table details recognized built-in limits used by Rocket.
 ///
-/// | Limit Name        | Default | Type         | Description                           |
-/// |-------------------|---------|--------------|---------------------------------------|
-/// | `form`            | 32KiB   | [`Form`]     | entire non-data-based form            |
-/// | `data-form`       | 2MiB    | [`Form`]     | entire data-based form                |
-/// | `file`            | 1MiB    | [`TempFile`] | [`TempFile`] data guard or form field |
-/// | `file/$ext`       | _N/A_   | [`TempFile`] | file form field with extension `$ext` |
-/// | `string`          | 8KiB    | [`String`]   | data guard or data form field         |
-/// | `bytes`           | 8KiB    | [`Vec<u8>`]  | data guard                            |
-/// | `json`            | 1MiB    | [`Json`]     | JSON data and form payloads           |
-/// | `msgpack`         | 1MiB    | [`MsgPack`]  | MessagePack data and form payloads    |
+/// | Limit Name      | Default | Type            | Description                           |
+/// |-----------------|---------|-----------------|---------------------------------------|
+/// | `form`          | 32KiB   | [`Form`]        | entire non-data-based form            |
+/// | `data-form`     | 2MiB    | [`Form`]        | entire data-based form                |
+/// | `file`          | 1MiB    | [`TempFile`]    | [`TempFile`] data guard or form field |
+/// | `file/$ext`     | _N/A_   | [`TempFile`]    | file form field with extension `$ext` |
+/// | `spill`         | 0B      | [`TempFile`]    | size under which files stay in memory |
+/// | `spill/$ext`    | _N/A_   | [`TempFile`]    | as `spill` for extension `$ext`       |
+/// | `string`        | 8KiB    | [`String`]      | data guard or data form field         |
+/// | `bytes`         | 8KiB    | [`Vec<u8>`]     | data guard                            |
+/// | `channel`       | 1MiB    | [`ChannelBody`] | streamed data guard                   |
+/// | `json`          | 1MiB    | [`Json`]        | JSON data and form payloads           |
+/// | `msgpack`       | 1MiB    | [`MsgPack`]     | MessagePack data and form payloads    |
+/// | `cbor`          | 1MiB    | [`Cbor`]        | CBOR data and form payloads           |
+/// | `xml`           | 1MiB    | [`Xml`]         | XML data and form payloads            |
+/// | `uri`           | 8KiB    | _request_       | entire request target, in bytes       |
+/// | `path_segments` | 256     | _request_       | number of path segments, not bytes    |
+///
+/// The `uri` and `path_segments` limits are enforced on incoming requests as
+/// they are parsed, before any fairings or routing. A request exceeding either
+/// is answered with `414 URI Too Long`.
 ///
 /// [`TempFile`]: crate::fs::TempFile
+/// [`ChannelBody`]: crate::data::ChannelBody
 /// [`Json`]: crate::serde::json::Json
 /// [`MsgPack`]: crate::serde::msgpack::MsgPack
+/// [`Cbor`]: crate::serde::cbor::Cbor
+/// [`Xml`]: crate::serde::xml::Xml
 ///
 /// # Usage
 ///
@@ -132,10 +146,16 @@ impl Default for Limits {
             .limit("form", Limits::FORM)
             .limit("data-form", Limits::DATA_FORM)
             .limit("file", Limits::FILE)
+            .limit("spill", Limits::SPILL)
             .limit("string", Limits::STRING)
             .limit("bytes", Limits::BYTES)
+            .limit("channel", Limits::CHANNEL)
             .limit("json", Limits::JSON)
             .limit("msgpack", Limits::MESSAGE_PACK)
+            .limit("cbor", Limits::CBOR)
+            .limit("xml", Limits::XML)
+            .limit("uri", Limits::URI)
+            .limit("path_segments", Limits::PATH_SEGMENTS)
     }
 }
 
@@ -149,18 +169,39 @@ impl Limits {
     /// Default limit for temporary files.
     pub const FILE: ByteUnit = ByteUnit::Mebibyte(1);
 
+    /// Default threshold below which temporary files are kept in memory. The
+    /// default of `0` writes every temporary file to disk.
+    pub const SPILL: ByteUnit = ByteUnit::Byte(0);
+
     /// Default limit for strings.
     pub const STRING: ByteUnit = ByteUnit::Kibibyte(8);
 
     /// Default limit for bytes.
     pub const BYTES: ByteUnit = ByteUnit::Kibibyte(8);
 
+    /// Default limit for streamed [`ChannelBody`](crate::data::ChannelBody)
+    /// data.
+    pub const CHANNEL: ByteUnit = ByteUnit::Mebibyte(1);
+
     /// Default limit for JSON payloads.
     pub const JSON: ByteUnit = ByteUnit::Mebibyte(1);
 
     /// Default limit for MessagePack payloads.
     pub const MESSAGE_PACK: ByteUnit = ByteUnit::Mebibyte(1);
 
+    /// Default limit for CBOR payloads.
+    pub const CBOR: ByteUnit = ByteUnit::Mebibyte(1);
+
+    /// Default limit for XML payloads.
+    pub const XML: ByteUnit = ByteUnit::Mebibyte(1);
+
+    /// Default limit for the length of a request's URI.
+    pub const URI: ByteUnit = ByteUnit::Kibibyte(8);
+
+    /// Default limit for the number of segments in a request's path. Unlike
+    /// other limits, this is a count, not a size in bytes.
+    pub const PATH_SEGMENTS: ByteUnit = ByteUnit::Byte(256);
+
     /// Construct a new `Limits` structure with no limits set.
     ///
     /// # Example
@@ -2,16 +2,28 @@
 
 #[macro_use]
 mod capped;
+mod channel;
+mod chunks;
 mod data;
 mod data_stream;
 mod from_data;
+mod io_stream;
 mod limits;
+#[cfg(feature = "digest")]
+mod verified;
 
 pub use self::data::Data;
 pub use self::data_stream::DataStream;
+pub use self::chunks::DataChunks;
 pub use self::from_data::{FromData, Outcome};
 pub use self::limits::Limits;
 pub use self::capped::{N, Capped};
+pub use self::channel::ChannelBody;
+pub use self::io_stream::{IoHandler, IoStream};
 pub use ubyte::{ByteUnit, ToByteUnit};
+pub use bytes::Bytes;
 
-pub(crate) use self::data_stream::StreamReader;
+#[cfg(feature = "digest")]
+pub use self::verified::{Verified, DigestAlgorithm, DigestError};
+
+pub(crate) use self::data_stream::{StreamReader, io_error_status};
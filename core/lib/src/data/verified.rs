@@ -0,0 +1,301 @@
+use std::fmt;
+use std::sync::Arc;
+use std::ops::{Deref, DerefMut};
+
+use base64::Engine;
+use md5::Md5;
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+
+use crate::request::Request;
+use crate::data::{Data, FromData, Outcome};
+use crate::http::{Status, HeaderMap, uncased::UncasedStr};
+use crate::outcome::Outcome::*;
+
+/// A data guard that verifies the request body against a declared digest.
+///
+/// `Verified<T>` wraps any data guard `T`. As `T` reads the body, the digests
+/// declared by the request are computed over each chunk as it passes through;
+/// the body is never buffered beyond what `T` itself buffers. Once `T`
+/// succeeds, the computed digests are compared against the declared ones, and
+/// the guard fails with a `400 Bad Request` if any differ. Digests are declared
+/// by any of the following headers:
+///
+///   * `Digest`, as defined in [RFC 3230], with a `sha-256` or `md5` value:
+///
+///     `Digest: sha-256=X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=`
+///
+///   * `Content-MD5`, as defined in [RFC 1864]:
+///
+///     `Content-MD5: Q2hlY2sgSW50ZWdyaXR5IQ==`
+///
+/// Algorithm names are matched case-insensitively and values are base64
+/// encoded. Values for other algorithms are ignored. When several supported
+/// digests are declared, all of them must match.
+///
+/// [RFC 3230]: https://tools.ietf.org/html/rfc3230#section-4.3.2
+/// [RFC 1864]: https://tools.ietf.org/html/rfc1864
+///
+/// # Outcomes
+///
+///   * **Failure**(400, [`DigestError::Missing`]) if the request declares no
+///     supported digest.
+///   * **Failure**(400, [`DigestError::Malformed`]) if a supported digest is
+///     not validly encoded.
+///   * **Failure**(400, [`DigestError::Incomplete`]) if `T` succeeds without
+///     reading the entire body, so that no digest can be computed.
+///   * **Failure**(400, [`DigestError::Mismatch`]) if a computed digest
+///     differs from the declared one.
+///   * **Failure**(_, [`DigestError::Guard`]) if `T` fails. The status is that
+///     of `T`'s failure.
+///   * **Forward** if `T` forwards.
+///   * **Success** if `T` succeeds and every declared digest matches.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::data::Verified;
+///
+/// #[post("/upload", data = "<upload>")]
+/// fn upload(upload: Verified<Vec<u8>>) -> String {
+///     format!("received {} intact bytes", upload.len())
+/// }
+/// ```
+#[cfg_attr(nightly, doc(cfg(feature = "digest")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Verified<T>(pub T);
+
+/// A digest algorithm supported by [`Verified`].
+#[cfg_attr(nightly, doc(cfg(feature = "digest")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DigestAlgorithm {
+    /// SHA-256, declared as `sha-256` in a `Digest` header.
+    Sha256,
+    /// MD5, declared as `md5` in a `Digest` header or via `Content-MD5`.
+    Md5,
+}
+
+/// An error returned by the [`Verified`] data guard.
+#[cfg_attr(nightly, doc(cfg(feature = "digest")))]
+#[derive(Debug)]
+pub enum DigestError<E> {
+    /// The request did not declare a digest with a supported algorithm.
+    Missing,
+    /// A declared digest was malformed. Contains the name of the header.
+    Malformed(&'static str),
+    /// The inner guard did not read the entire body.
+    Incomplete,
+    /// The computed digest for the algorithm differs from the declared one.
+    Mismatch(DigestAlgorithm),
+    /// The inner data guard failed.
+    Guard(E),
+}
+
+impl<T> Verified<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::Verified;
+    ///
+    /// let verified = Verified(vec![1, 2, 3]);
+    /// assert_eq!(verified.into_inner(), vec![1, 2, 3]);
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Verified<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Verified<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl DigestAlgorithm {
+    /// Returns the name of the algorithm as used in a `Digest` header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::DigestAlgorithm;
+    ///
+    /// assert_eq!(DigestAlgorithm::Sha256.as_str(), "sha-256");
+    /// assert_eq!(DigestAlgorithm::Md5.as_str(), "md5");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DigestAlgorithm::Sha256 => "sha-256",
+            DigestAlgorithm::Md5 => "md5",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        let name = UncasedStr::new(name.trim());
+        if name == "sha-256" {
+            Some(DigestAlgorithm::Sha256)
+        } else if name == "md5" {
+            Some(DigestAlgorithm::Md5)
+        } else {
+            None
+        }
+    }
+
+    fn output_len(&self) -> usize {
+        match self {
+            DigestAlgorithm::Sha256 => 32,
+            DigestAlgorithm::Md5 => 16,
+        }
+    }
+}
+
+impl fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// A digest being computed alongside the one it's expected to equal.
+enum Hasher {
+    Sha256(Sha256),
+    Md5(Md5),
+}
+
+struct Check {
+    expected: Vec<u8>,
+    algorithm: DigestAlgorithm,
+    hasher: Hasher,
+}
+
+/// The state shared between the body stream and the guard.
+struct Checks {
+    checks: Vec<Check>,
+    done: bool,
+}
+
+impl Check {
+    fn new(algorithm: DigestAlgorithm, expected: Vec<u8>) -> Self {
+        let hasher = match algorithm {
+            DigestAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            DigestAlgorithm::Md5 => Hasher::Md5(Md5::new()),
+        };
+
+        Check { expected, algorithm, hasher }
+    }
+
+    fn update(&mut self, chunk: &[u8]) {
+        match self.hasher {
+            Hasher::Sha256(ref mut h) => h.update(chunk),
+            Hasher::Md5(ref mut h) => h.update(chunk),
+        }
+    }
+
+    fn matches(self) -> bool {
+        match self.hasher {
+            Hasher::Sha256(h) => h.finalize()[..] == self.expected[..],
+            Hasher::Md5(h) => h.finalize()[..] == self.expected[..],
+        }
+    }
+}
+
+impl Checks {
+    fn from_headers<E>(headers: &HeaderMap<'_>) -> Result<Self, DigestError<E>> {
+        fn decode<E>(value: &str, alg: DigestAlgorithm, header: &'static str)
+            -> Result<Check, DigestError<E>>
+        {
+            base64::engine::general_purpose::STANDARD.decode(value.trim()).ok()
+                .filter(|digest| digest.len() == alg.output_len())
+                .map(|digest| Check::new(alg, digest))
+                .ok_or(DigestError::Malformed(header))
+        }
+
+        let mut checks = vec![];
+        for value in headers.get("Digest").flat_map(|v| v.split(',')) {
+            let mut parts = value.splitn(2, '=');
+            let (name, digest) = (parts.next().unwrap_or(""), parts.next());
+            if let Some(alg) = DigestAlgorithm::from_name(name) {
+                let digest = digest.ok_or(DigestError::Malformed("Digest"))?;
+                checks.push(decode(digest, alg, "Digest")?);
+            }
+        }
+
+        for value in headers.get("Content-MD5") {
+            checks.push(decode(value, DigestAlgorithm::Md5, "Content-MD5")?);
+        }
+
+        if checks.is_empty() {
+            return Err(DigestError::Missing);
+        }
+
+        Ok(Checks { checks, done: false })
+    }
+
+    fn verify<E>(self) -> Result<(), DigestError<E>> {
+        if !self.done {
+            return Err(DigestError::Incomplete);
+        }
+
+        for check in self.checks {
+            let algorithm = check.algorithm;
+            if !check.matches() {
+                return Err(DigestError::Mismatch(algorithm));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: FromData<'r>> FromData<'r> for Verified<T> {
+    type Error = DigestError<T::Error>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let checks = match Checks::from_headers(req.headers()) {
+            Ok(checks) => Arc::new(Mutex::new(checks)),
+            Err(e) => return Failure((Status::BadRequest, e)),
+        };
+
+        let state = checks.clone();
+        let data = data.inspect(move |chunk| {
+            let mut state = state.lock();
+            match chunk {
+                Some(chunk) => state.checks.iter_mut().for_each(|c| c.update(chunk)),
+                None => state.done = true,
+            }
+        });
+
+        let value = match T::from_data(req, data).await {
+            Success(value) => value,
+            Failure((status, e)) => return Failure((status, DigestError::Guard(e))),
+            Forward(data) => return Forward(data),
+        };
+
+        // The body stream, and with it the other reference, is gone by now.
+        let checks = match Arc::try_unwrap(checks) {
+            Ok(checks) => checks.into_inner(),
+            Err(_) => return Failure((Status::BadRequest, DigestError::Incomplete)),
+        };
+
+        match checks.verify() {
+            Ok(()) => Success(Verified(value)),
+            Err(e) => {
+                warn_!("Request body failed digest verification: {:?}", e);
+                Failure((Status::BadRequest, e))
+            }
+        }
+    }
+}
@@ -299,6 +299,14 @@ impl<F: Future, C: Connection> Connection for CancellableIo<F, C> {
     fn remote_addr(&self) -> Option<std::net::SocketAddr> {
         self.io.remote_addr()
     }
+
+    fn local_addr(&self) -> Option<std::net::SocketAddr> {
+        self.io.local_addr()
+    }
+
+    fn tls_info(&self) -> Option<crate::http::private::TlsInfo> {
+        self.io.tls_info()
+    }
 }
 
 pin_project! {
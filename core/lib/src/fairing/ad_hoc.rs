@@ -1,6 +1,9 @@
 use std::sync::Mutex;
+use std::time::Duration;
 
 use futures::future::{Future, BoxFuture, FutureExt};
+use tokio::time::{sleep, Instant};
+use figment::Figment;
 
 use crate::{Rocket, Request, Response, Data, Build, Orbit};
 use crate::fairing::{Fairing, Kind, Info, Result};
@@ -14,7 +17,10 @@ use crate::fairing::{Fairing, Kind, Info, Result};
 ///
 /// Use [`AdHoc::on_ignite`], [`AdHoc::on_liftoff`], [`AdHoc::on_request()`], or
 /// [`AdHoc::on_response()`] to create an `AdHoc` structure from a function or
-/// closure. Then, simply attach the structure to the `Rocket` instance.
+/// closure. Then, simply attach the structure to the `Rocket` instance. To
+/// manage state derived from configuration at ignition, use
+/// [`AdHoc::try_manage()`]. To observe a graceful shutdown as it drains, use
+/// [`AdHoc::on_drain()`].
 ///
 /// # Example
 ///
@@ -38,6 +44,33 @@ use crate::fairing::{Fairing, Kind, Info, Result};
 pub struct AdHoc {
     name: &'static str,
     kind: AdHocKind,
+    priority: i32,
+}
+
+/// The progress of a graceful shutdown, as reported to
+/// [`AdHoc::on_drain()`].
+#[derive(Debug, Clone, Copy)]
+pub struct Drain {
+    in_flight: usize,
+    connections: usize,
+    elapsed: Duration,
+}
+
+impl Drain {
+    /// The number of requests still being handled or responded to.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+
+    /// The number of connections still open.
+    pub fn connections(&self) -> usize {
+        self.connections
+    }
+
+    /// The time elapsed since shutdown was triggered.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
 }
 
 struct Once<F: ?Sized>(Mutex<Option<Box<F>>>);
@@ -110,7 +143,71 @@ impl AdHoc {
         where F: FnOnce(Rocket<Build>) -> Fut + Send + 'static,
               Fut: Future<Output = Result> + Send + 'static,
     {
-        AdHoc { name, kind: AdHocKind::Ignite(Once::new(Box::new(|r| f(r).boxed()))) }
+        let kind = AdHocKind::Ignite(Once::new(Box::new(|r| f(r).boxed())));
+        AdHoc { name, kind, priority: 0 }
+    }
+
+    /// Constructs an `AdHoc` ignite fairing named `name` that manages the
+    /// value returned by `f`. The function `f` is called during the
+    /// [`Rocket::ignite()`] phase with the configured [`Figment`]. If it
+    /// returns `Ok`, the value is managed as if by [`Rocket::manage()`]. If it
+    /// returns `Err`, the error is logged and ignition, thus launch, is
+    /// aborted.
+    ///
+    /// This is useful to initialize resources, like database pools or HTTP
+    /// clients, from configuration values that may not be final until ignition,
+    /// as they may be set by other ignite fairings.
+    ///
+    /// # Ordering
+    ///
+    /// Like all ignite fairings, `f` is called in `attach()` order relative to
+    /// other ignite fairings. The `Figment` passed to `f` reflects the changes
+    /// made, for instance via [`Rocket::configure()`], by ignite fairings that
+    /// were attached before it but not by those attached after it. Similarly,
+    /// the managed value is visible to ignite fairings attached afterwards via
+    /// [`Rocket::state()`]. Fairings attached by an ignite fairing run after
+    /// all fairings that were attached before ignition began.
+    ///
+    /// # Panics
+    ///
+    /// Like [`Rocket::manage()`], the fairing panics if state of type `T` is
+    /// already being managed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::launch;
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// struct Client {
+    ///     url: String,
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .attach(AdHoc::try_manage("API Client", |figment| async move {
+    ///             let url: String = figment.extract_inner("api_url")?;
+    ///             Ok::<_, rocket::figment::Error>(Client { url })
+    ///         }))
+    /// }
+    /// ```
+    pub fn try_manage<T, E, F, Fut>(name: &'static str, f: F) -> AdHoc
+        where T: Send + Sync + 'static,
+              E: std::fmt::Display,
+              F: FnOnce(Figment) -> Fut + Send + 'static,
+              Fut: Future<Output = std::result::Result<T, E>> + Send + 'static,
+    {
+        AdHoc::try_on_ignite(name, move |rocket| async move {
+            match f(rocket.figment().clone()).await {
+                Ok(value) => Ok(rocket.manage(value)),
+                Err(e) => {
+                    error!("Failed to initialize managed state in {:?}.", name);
+                    error_!("{}", e);
+                    Err(rocket)
+                }
+            }
+        })
     }
 
     /// Constructs an `AdHoc` liftoff fairing named `name`. The function `f`
@@ -129,7 +226,7 @@ impl AdHoc {
     pub fn on_liftoff<F: Send + Sync + 'static>(name: &'static str, f: F) -> AdHoc
         where F: for<'a> FnOnce(&'a Rocket<Orbit>) -> BoxFuture<'a, ()>
     {
-        AdHoc { name, kind: AdHocKind::Liftoff(Once::new(Box::new(f))) }
+        AdHoc { name, kind: AdHocKind::Liftoff(Once::new(Box::new(f))), priority: 0 }
     }
 
     /// Constructs an `AdHoc` request fairing named `name`. The function `f`
@@ -152,7 +249,7 @@ impl AdHoc {
     pub fn on_request<F: Send + Sync + 'static>(name: &'static str, f: F) -> AdHoc
         where F: for<'a> Fn(&'a mut Request<'_>, &'a Data<'_>) -> BoxFuture<'a, ()>
     {
-        AdHoc { name, kind: AdHocKind::Request(Box::new(f)) }
+        AdHoc { name, kind: AdHocKind::Request(Box::new(f)), priority: 0 }
     }
 
     // FIXME(rustc): We'd like to allow passing `async fn` to these methods...
@@ -178,7 +275,76 @@ impl AdHoc {
     pub fn on_response<F: Send + Sync + 'static>(name: &'static str, f: F) -> AdHoc
         where F: for<'b, 'r> Fn(&'r Request<'_>, &'b mut Response<'r>) -> BoxFuture<'b, ()>
     {
-        AdHoc { name, kind: AdHocKind::Response(Box::new(f)) }
+        AdHoc { name, kind: AdHocKind::Response(Box::new(f)), priority: 0 }
+    }
+
+    /// Constructs an `AdHoc` liftoff fairing named `name` that reports the
+    /// progress of a graceful shutdown. Once shutdown is triggered, `f` is
+    /// called immediately and then every `period` with a [`Drain`]
+    /// describing the connections and requests still being served, until
+    /// none remain or the server stops.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use rocket::fairing::AdHoc;
+    ///
+    /// let fairing = AdHoc::on_drain("Drain Logger", Duration::from_secs(1), |drain| {
+    ///     println!("{} request(s) on {} connection(s) remain after {:?}",
+    ///         drain.in_flight(), drain.connections(), drain.elapsed());
+    /// });
+    /// ```
+    pub fn on_drain<F>(name: &'static str, period: Duration, f: F) -> AdHoc
+        where F: Fn(&Drain) + Send + Sync + 'static
+    {
+        assert!(period > Duration::from_secs(0), "drain period must be non-zero");
+        AdHoc::on_liftoff(name, move |rocket| Box::pin(async move {
+            let shutdown = rocket.shutdown();
+            tokio::spawn(async move {
+                shutdown.clone().await;
+                let start = Instant::now();
+                loop {
+                    let drain = Drain {
+                        in_flight: shutdown.in_flight(),
+                        connections: shutdown.connections(),
+                        elapsed: start.elapsed(),
+                    };
+
+                    f(&drain);
+                    if drain.connections == 0 && drain.in_flight == 0 {
+                        break;
+                    }
+
+                    sleep(period).await;
+                }
+            });
+        }))
+    }
+
+    /// Sets the [priority](Fairing::priority()) of the fairing to `priority`.
+    /// The default is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::AdHoc;
+    /// use rocket::http::Status;
+    ///
+    /// // Runs before request fairings with lower priorities, even if attached
+    /// // after them.
+    /// let fairing = AdHoc::on_request("Maintenance", |req, _| Box::pin(async move {
+    ///     req.halt(Status::ServiceUnavailable);
+    /// })).with_priority(100);
+    /// ```
+    pub fn with_priority(mut self, priority: i32) -> AdHoc {
+        self.priority = priority;
+        self
     }
 
     /// Constructs an `AdHoc` launch fairing that extracts a configuration of
@@ -234,6 +400,10 @@ impl Fairing for AdHoc {
         Info { name: self.name, kind }
     }
 
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+
     async fn on_ignite(&self, rocket: Rocket<Build>) -> Result {
         match self.kind {
             AdHocKind::Ignite(ref f) => (f.take())(rocket).await,
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 use crate::{Rocket, Request, Response, Data, Build, Orbit};
 use crate::fairing::{Fairing, Info, Kind};
@@ -48,6 +48,12 @@ impl Fairings {
             .chain(self.response.iter())
     }
 
+    /// Each active fairing exactly once, in the order they were attached.
+    pub fn unique_active(&self) -> impl Iterator<Item = &dyn Fairing> {
+        let active_fairings = self.active().collect::<BTreeSet<_>>();
+        iter!(self, active_fairings.into_iter()).map(|v| v.1)
+    }
+
     pub fn add(&mut self, fairing: Box<dyn Fairing>) {
         let this = &fairing;
         let this_info = this.info();
@@ -72,7 +78,7 @@ impl Fairings {
 
             // Finds `k` in `from` and removes it if it's there.
             let remove = |k: usize, from: &mut Vec<usize>| {
-                if let Ok(j) = from.binary_search(&k) {
+                if let Some(j) = from.iter().position(|&i| i == k) {
                     from.remove(j);
                 }
             };
@@ -94,11 +100,25 @@ impl Fairings {
         }
 
         let index = self.all_fairings.len();
+        let priority = fairing.priority();
         self.all_fairings.push(fairing);
-        if this_info.kind.is(Kind::Ignite) { self.ignite.push(index); }
-        if this_info.kind.is(Kind::Liftoff) { self.liftoff.push(index); }
-        if this_info.kind.is(Kind::Request) { self.request.push(index); }
-        if this_info.kind.is(Kind::Response) { self.response.push(index); }
+
+        // Inserts `index` after all fairings in `into[start..]` with a priority
+        // at least `priority`, keeping ties in attach order.
+        let all_fairings = &self.all_fairings;
+        let insert = |into: &mut Vec<usize>, start: usize| {
+            let offset = into[start..].iter()
+                .position(|&i| all_fairings[i].priority() < priority)
+                .unwrap_or(into.len() - start);
+
+            into.insert(start + offset, index);
+        };
+
+        // Ignite fairings that have already run keep their place.
+        if this_info.kind.is(Kind::Ignite) { insert(&mut self.ignite, self.num_ignited); }
+        if this_info.kind.is(Kind::Liftoff) { insert(&mut self.liftoff, 0); }
+        if this_info.kind.is(Kind::Request) { insert(&mut self.request, 0); }
+        if this_info.kind.is(Kind::Response) { insert(&mut self.response, 0); }
     }
 
     pub fn append(&mut self, others: &mut Fairings) {
@@ -142,7 +162,10 @@ impl Fairings {
     #[inline(always)]
     pub async fn handle_request(&self, req: &mut Request<'_>, data: &mut Data<'_>) {
         for fairing in iter!(self.request) {
-            fairing.on_request(req, data).await
+            fairing.on_request(req, data).await;
+            if req.halted().is_some() || req.request_fairings_skipped() {
+                break;
+            }
         }
     }
 
@@ -161,11 +184,11 @@ impl Fairings {
     }
 
     pub fn pretty_print(&self) {
-        let active_fairings = self.active().collect::<HashSet<_>>();
-        if !active_fairings.is_empty() {
+        let mut active_fairings = self.unique_active().peekable();
+        if active_fairings.peek().is_some() {
             launch_info!("{}{}:", Paint::emoji("📡 "), Paint::magenta("Fairings"));
 
-            for (_, fairing) in iter!(self, active_fairings.into_iter()) {
+            for fairing in active_fairings {
                 launch_info_!("{} ({})", Paint::default(fairing.info().name).bold(),
                 Paint::blue(fairing.info().kind).bold());
             }
@@ -45,6 +45,32 @@
 //! attached may be significant. It is thus important to communicate specific
 //! fairing functionality clearly.
 //!
+//! When attach order alone isn't enough, as when a fairing must run before
+//! fairings attached by a library, a fairing can declare a
+//! [priority](Fairing::priority()), set for [`AdHoc`] fairings via
+//! [`AdHoc::with_priority()`]. Fairings with higher priorities run before those
+//! with lower priorities; fairings with equal priorities, including the default
+//! of `0`, run in attach order. Priorities apply to every kind of callback
+//! alike. Ignite fairings attached by another ignite fairing run after all
+//! fairings attached before ignition, ordered by priority among themselves.
+//!
+//! ```rust
+//! use rocket::fairing::AdHoc;
+//!
+//! let rocket = rocket::build()
+//!     .attach(AdHoc::on_request("Rate Limiter", |_, _| Box::pin(async move {
+//!         /* runs second */
+//!     })))
+//!     .attach(AdHoc::on_request("Authenticator", |_, _| Box::pin(async move {
+//!         /* runs first */
+//!     })).with_priority(10));
+//! ```
+//!
+//! A request fairing can also end the chain early for a given request: calling
+//! [`Request::skip_request_fairings()`] skips the request fairings that would
+//! otherwise run after it while still routing the request as usual.
+//! [`Request::halt()`] additionally skips routing.
+//!
 //! Furthermore, a `Fairing` should take care to act locally so that the actions
 //! of other `Fairings` are not jeopardized. For instance, unless it is made
 //! abundantly clear, a fairing should not rewrite every request.
@@ -56,9 +82,11 @@ use crate::{Rocket, Request, Response, Data, Build, Orbit};
 mod fairings;
 mod ad_hoc;
 mod info_kind;
+mod schedule;
 
 pub(crate) use self::fairings::Fairings;
-pub use self::ad_hoc::AdHoc;
+pub use self::ad_hoc::{AdHoc, Drain};
+pub use self::schedule::Schedule;
 pub use self::info_kind::{Info, Kind};
 
 /// A type alias for the return `Result` type of [`Fairing::on_ignite()`].
@@ -126,6 +154,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///     ignite fairing returns `Err`, launch will be aborted. All ignite
 ///     fairings are executed even if one or more signal a failure.
 ///
+///     Because configuration is only extracted after all ignite callbacks have
+///     run, an ignite callback is the right place to derive managed state from
+///     [`Rocket::figment()`]; see [`AdHoc::try_manage()`]. A callback observes
+///     the configuration and managed state as left by the callbacks before it.
+///
 ///   * **Liftoff (`on_liftoff`)**
 ///
 ///     A liftoff callback, represented by the [`Fairing::on_liftoff()`] method,
@@ -142,11 +175,19 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///     fields. At this point, Rocket has parsed the incoming HTTP request into
 ///     [`Request`] and [`Data`] structures but has not routed the request. A
 ///     request callback can modify the request at will and [`Data::peek()`]
-///     into the incoming data. It may not, however, abort or respond directly
-///     to the request; these issues are better handled via [request guards] or
-///     via response callbacks. Any modifications to a request are persisted and
+///     into the incoming data. Any modifications to a request are persisted and
 ///     can potentially alter how a request is routed.
 ///
+///     A request callback may also halt the request with an error status via
+///     [`Request::halt()`]; this is useful for application-wide gates such as
+///     a maintenance mode or an IP allowlist. Request callbacks run in
+///     `attach()` order, and once a request is halted, no further request
+///     callbacks are run and the request is not routed. Instead, the
+///     [catcher](crate::Catcher) for the status generates the response, which
+///     is then passed to response callbacks as usual. Rejections that depend
+///     on the route, such as authentication for some routes but not others,
+///     are better handled via [request guards].
+///
 ///   * **Response (`on_response`)**
 ///
 ///     A response callback, represented by the [`Fairing::on_response()`]
@@ -162,6 +203,11 @@ pub type Result<T = Rocket<Build>, E = Rocket<Build>> = std::result::Result<T, E
 ///     request. Additionally, Rocket will automatically strip the body for
 ///     `HEAD` requests _after_ response fairings have run.
 ///
+///     Cookies added to or removed from the request's
+///     [`CookieJar`](crate::http::CookieJar) by a response callback are sent
+///     alongside those changed by the handler, each in its own `Set-Cookie`
+///     header.
+///
 /// # Singletons
 ///
 /// In general, any number of instances of a given fairing type can be attached
@@ -423,6 +469,39 @@ pub trait Fairing: Send + Sync + Any + 'static {
     /// ```
     fn info(&self) -> Info;
 
+    /// Returns the priority of this fairing.
+    ///
+    /// The callbacks of fairings with a higher priority are run before those
+    /// of fairings with a lower priority, regardless of the order in which the
+    /// fairings were attached. Fairings with equal priorities run in `attach()`
+    /// order. See [ordering](crate::fairing#ordering) for details.
+    ///
+    /// ## Default Implementation
+    ///
+    /// The default implementation of this method returns `0`.
+    ///
+    /// # Example
+    ///
+    /// A fairing that authenticates requests before fairings of the default
+    /// priority, such as a rate limiter, see them:
+    ///
+    /// ```rust
+    /// use rocket::fairing::{Fairing, Info, Kind};
+    ///
+    /// struct Authenticator;
+    ///
+    /// impl Fairing for Authenticator {
+    ///     fn info(&self) -> Info {
+    ///         Info { name: "Authenticator", kind: Kind::Request }
+    ///     }
+    ///
+    ///     fn priority(&self) -> i32 {
+    ///         10
+    ///     }
+    /// }
+    /// ```
+    fn priority(&self) -> i32 { 0 }
+
     /// The ignite callback. Returns `Ok` if ignition should proceed and `Err`
     /// if ignition and launch should be aborted.
     ///
@@ -452,7 +531,9 @@ pub trait Fairing: Send + Sync + Any + 'static {
     /// This method is called when a new request is received if `Kind::Request`
     /// is in the `kind` field of the `Info` structure for this fairing. The
     /// `&mut Request` parameter is the incoming request, and the `&Data`
-    /// parameter is the incoming data in the request.
+    /// parameter is the incoming data in the request. The request can be
+    /// halted, skipping routing and any remaining request fairings, via
+    /// [`Request::halt()`].
     ///
     /// ## Default Implementation
     ///
@@ -479,6 +560,11 @@ impl<T: Fairing> Fairing for std::sync::Arc<T> {
         (self as &T).info()
     }
 
+    #[inline]
+    fn priority(&self) -> i32 {
+        (self as &T).priority()
+    }
+
     #[inline]
     async fn on_ignite(&self, rocket: Rocket<Build>) -> Result {
         (self as &T).on_ignite(rocket).await
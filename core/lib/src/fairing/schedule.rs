@@ -0,0 +1,191 @@
+use std::any::type_name;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use futures::future::{Future, BoxFuture};
+use tokio::time::{sleep_until, Instant};
+
+use crate::{Rocket, Orbit, Shutdown};
+use crate::fairing::{Fairing, Info, Kind};
+
+/// A job that, given the launched instance, returns the future that runs it.
+type Job = Box<dyn FnOnce(&Rocket<Orbit>) -> Option<BoxFuture<'static, ()>> + Send>;
+
+/// A fairing that runs recurring jobs on Rocket's runtime.
+///
+/// A `Schedule` is a [liftoff](crate::fairing::Fairing#liftoff) fairing that
+/// spawns each of its jobs as a task once Rocket has launched. Every job runs
+/// at a fixed period until [graceful shutdown](crate::Shutdown) is initiated,
+/// at which point the job, including any run in progress, is cancelled.
+///
+/// Runs of the same job never overlap: the next run is started only after the
+/// previous one has completed. If a run takes longer than the period, the next
+/// run starts as soon as the previous completes. The first run of each job
+/// occurs immediately after liftoff.
+///
+/// # Example
+///
+/// The following registers a job that runs every ten seconds and a job that
+/// uses the managed `Cache` state every minute:
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// use rocket::fairing::Schedule;
+/// use rocket::tokio::sync::RwLock;
+///
+/// #[derive(Default)]
+/// struct Cache(RwLock<Vec<String>>);
+///
+/// async fn cleanup() {
+///     /* remove stale files */
+/// }
+///
+/// let schedule = Schedule::new()
+///     .every(Duration::from_secs(10), cleanup)
+///     .every_with(Duration::from_secs(60), |cache: Arc<Cache>| async move {
+///         cache.0.write().await.clear();
+///     });
+///
+/// let rocket = rocket::build()
+///     .manage(Arc::new(Cache::default()))
+///     .attach(schedule);
+/// ```
+#[derive(Default)]
+pub struct Schedule {
+    jobs: Mutex<Vec<Job>>,
+}
+
+impl Schedule {
+    /// Creates a new `Schedule` without any jobs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::Schedule;
+    ///
+    /// let schedule = Schedule::new();
+    /// ```
+    pub fn new() -> Schedule {
+        Schedule::default()
+    }
+
+    /// Registers a job that calls `job` and awaits the returned future every
+    /// `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::fairing::Schedule;
+    ///
+    /// let schedule = Schedule::new().every(Duration::from_secs(5), || async {
+    ///     println!("Another five seconds have passed.");
+    /// });
+    /// ```
+    pub fn every<F, Fut>(self, period: Duration, job: F) -> Schedule
+        where F: Fn() -> Fut + Send + Sync + 'static,
+              Fut: Future<Output = ()> + Send + 'static,
+    {
+        assert!(period > Duration::from_secs(0), "schedule period must be non-zero");
+        self.push(Box::new(move |rocket| Some(run(period, rocket.shutdown(), job))))
+    }
+
+    /// Registers a job that calls `job` with a clone of the managed state of
+    /// type `T` and awaits the returned future every `period`. For state that
+    /// is not `Clone`, manage an `Arc<T>` instead.
+    ///
+    /// The state is retrieved at liftoff. If no state of type `T` is managed,
+    /// an error is logged and the job is not run.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::time::Duration;
+    ///
+    /// use rocket::fairing::Schedule;
+    ///
+    /// struct Ticks(AtomicUsize);
+    ///
+    /// let schedule = Schedule::new()
+    ///     .every_with(Duration::from_secs(1), |ticks: Arc<Ticks>| async move {
+    ///         ticks.0.fetch_add(1, Ordering::Relaxed);
+    ///     });
+    ///
+    /// let rocket = rocket::build()
+    ///     .manage(Arc::new(Ticks(AtomicUsize::new(0))))
+    ///     .attach(schedule);
+    /// ```
+    pub fn every_with<T, F, Fut>(self, period: Duration, job: F) -> Schedule
+        where T: Clone + Send + Sync + 'static,
+              F: Fn(T) -> Fut + Send + Sync + 'static,
+              Fut: Future<Output = ()> + Send + 'static,
+    {
+        assert!(period > Duration::from_secs(0), "schedule period must be non-zero");
+        self.push(Box::new(move |rocket| {
+            let state = match rocket.state::<T>() {
+                Some(state) => state.clone(),
+                None => {
+                    error_!("Scheduled job requires unmanaged state `{}`.", type_name::<T>());
+                    return None;
+                }
+            };
+
+            Some(run(period, rocket.shutdown(), move || job(state.clone())))
+        }))
+    }
+
+    fn push(self, job: Job) -> Schedule {
+        self.jobs.lock().expect("Schedule::jobs lock").push(job);
+        self
+    }
+}
+
+/// Returns a future that runs `job` every `period` until `shutdown` resolves.
+fn run<F, Fut>(period: Duration, shutdown: Shutdown, job: F) -> BoxFuture<'static, ()>
+    where F: Fn() -> Fut + Send + Sync + 'static,
+          Fut: Future<Output = ()> + Send + 'static,
+{
+    Box::pin(async move {
+        let runs = async move {
+            let mut next = Instant::now();
+            loop {
+                sleep_until(next).await;
+                job().await;
+                next = std::cmp::max(next + period, Instant::now());
+            }
+        };
+
+        tokio::select! {
+            _ = runs => {},
+            _ = shutdown => {},
+        }
+    })
+}
+
+#[crate::async_trait]
+impl Fairing for Schedule {
+    fn info(&self) -> Info {
+        Info { name: "Schedule", kind: Kind::Liftoff }
+    }
+
+    async fn on_liftoff(&self, rocket: &Rocket<Orbit>) {
+        let jobs = std::mem::take(&mut *self.jobs.lock().expect("Schedule::jobs lock"));
+        for job in jobs {
+            if let Some(future) = job(rocket) {
+                tokio::spawn(future);
+            }
+        }
+    }
+}
@@ -365,6 +365,8 @@ impl<'f> From<Errors<'f>> for Context<'f> {
 impl<'v, T: FromForm<'v>> FromForm<'v> for Contextual<'v, T> {
     type Context = (<T as FromForm<'v>>::Context, Context<'v>);
 
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
     fn init(opts: Options) -> Self::Context {
         (T::init(opts), Context::default())
     }
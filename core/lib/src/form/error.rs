@@ -160,7 +160,13 @@ pub struct Error<'v> {
 ///   * [`AddrParseError`] => [`ErrorKind::Addr`]
 ///   * [`io::Error`] => [`ErrorKind::Io`]
 ///   * `Box<dyn std::error::Error + Send` => [`ErrorKind::Custom`]
+///
+/// ## Matching
+///
+/// New kinds of errors may be added without a breaking release, so matches on
+/// an `ErrorKind` outside of Rocket must include a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ErrorKind<'v> {
     /// The value's length, in bytes, was outside the range `[min, max]`.
     InvalidLength {
@@ -187,6 +193,9 @@ pub enum ErrorKind<'v> {
     Duplicate,
     /// An entity was expected but was not received.
     Missing,
+    /// An entity marked as required, via `#[field(required)]`, was not
+    /// received.
+    Required,
     /// An unexpected entity was received.
     Unexpected,
     /// An unknown entity was received.
@@ -684,7 +693,10 @@ impl<'v> Error<'v> {
     ///    - `Multpart(FieldSizeExceeded | StreamSizeExceeded)`
     ///  * **`InternalServerError`** if the error kind is:
     ///    - `Unknown`
+    ///  * **`RequestTimeout`** if the error kind is:
+    ///    - `Io` with an error kind of `TimedOut`
     ///  * **`BadRequest`** if the error kind is:
+    ///    - `Required`
     ///    - `Io` with an `entity` of `Form`
     ///  * **`UnprocessableEntity`** otherwise
     ///
@@ -703,6 +715,9 @@ impl<'v> Error<'v> {
     ///  let error = Error::from(ErrorKind::Unknown);
     ///  assert_eq!(error.status(), Status::InternalServerError);
     ///
+    ///  let error = Error::from(ErrorKind::Required);
+    ///  assert_eq!(error.status(), Status::BadRequest);
+    ///
     ///  // default entity for `io::Error` is `Form`.
     ///  let error = Error::from(std::io::Error::last_os_error());
     ///  assert_eq!(error.status(), Status::BadRequest);
@@ -719,6 +734,8 @@ impl<'v> Error<'v> {
             | Multipart(FieldSizeExceeded { .. })
             | Multipart(StreamSizeExceeded { .. }) => Status::PayloadTooLarge,
             Unknown => Status::InternalServerError,
+            Required => Status::BadRequest,
+            Io(ref e) if e.kind() == io::ErrorKind::TimedOut => Status::RequestTimeout,
             Io(_) | _ if self.entity == Entity::Form => Status::BadRequest,
             _ => Status::UnprocessableEntity
         }
@@ -843,6 +860,7 @@ impl fmt::Display for ErrorKind<'_> {
             ErrorKind::Validation(msg) => msg.fmt(f)?,
             ErrorKind::Duplicate => "duplicate".fmt(f)?,
             ErrorKind::Missing => "missing".fmt(f)?,
+            ErrorKind::Required => "missing required value".fmt(f)?,
             ErrorKind::Unexpected => "unexpected".fmt(f)?,
             ErrorKind::Unknown => "unknown internal error".fmt(f)?,
             ErrorKind::Custom(e) => e.fmt(f)?,
@@ -871,6 +889,7 @@ impl crate::http::ext::IntoOwned for ErrorKind<'_> {
             Validation(s) => Validation(s.into_owned().into()),
             Duplicate => Duplicate,
             Missing => Missing,
+            Required => Required,
             Unexpected => Unexpected,
             Unknown => Unknown,
             Custom(e) => Custom(e),
@@ -902,6 +921,7 @@ impl<'a, 'b> PartialEq<ErrorKind<'b>> for ErrorKind<'a> {
             (Validation(a), Validation(b)) => a == b,
             (Duplicate, Duplicate) => true,
             (Missing, Missing) => true,
+            (Required, Required) => true,
             (Unexpected, Unexpected) => true,
             (Custom(_), Custom(_)) => true,
             (Multipart(a), Multipart(b)) => a == b,
@@ -997,7 +1017,8 @@ impl fmt::Display for Entity {
 impl Entity {
     /// The default entity for an [`Error`] created for `ErrorKind`.
     ///
-    ///  * **[`Field`]** if `Duplicate`, `Missing`, `Unexpected`, or `Unknown`
+    ///  * **[`Field`]** if `Duplicate`, `Missing`, `Required`, `Unexpected`,
+    ///    or `Unknown`
     ///  * **[`Form`]** if `Multipart` or `Io`
     ///  * **[`Value`]** otherwise
     ///
@@ -1019,6 +1040,7 @@ impl Entity {
 
             | ErrorKind::Duplicate
             | ErrorKind::Missing
+            | ErrorKind::Required
             | ErrorKind::Unknown
             | ErrorKind::Unexpected => Entity::Field,
 
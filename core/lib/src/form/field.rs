@@ -14,6 +14,27 @@ pub struct ValueField<'r> {
     pub name: NameView<'r>,
     /// The (decoded) value of the form field.
     pub value: &'r str,
+    source: Option<Source>,
+}
+
+/// The part of a request in which a form field was submitted.
+///
+/// Fields parsed from the query string by a query guard have a source of
+/// [`Source::Query`], while fields parsed from the body by a [`Form`] have a
+/// source of [`Source::Data`]. A [`Merged`] form draws from both. A field in a
+/// derived [`FromForm`] type can be limited to fields from one source with
+/// `#[field(source = query)]` or `#[field(source = data)]`; see the
+/// [`FromForm` derive](macro@crate::form::FromForm) documentation for details.
+///
+/// [`Form`]: crate::form::Form
+/// [`Merged`]: crate::form::Merged
+/// [`FromForm`]: crate::form::FromForm
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    /// The request's query string.
+    Query,
+    /// The request's body.
+    Data,
 }
 
 /// A multipart form field with an underlying data stream.
@@ -88,6 +109,40 @@ impl<'v> ValueField<'v> {
         ValueField::from(("", value))
     }
 
+    /// Returns `self` with its source set to `source`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::{ValueField, Source};
+    ///
+    /// let field = ValueField::parse("page=2").with_source(Source::Query);
+    /// assert_eq!(field.source(), Some(Source::Query));
+    /// ```
+    pub fn with_source(mut self, source: Source) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Returns where the field was submitted, if known. Fields parsed from
+    /// strings, such as those created with [`ValueField::parse()`], have no
+    /// source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::{ValueField, Source};
+    ///
+    /// let field = ValueField::parse("page=2");
+    /// assert_eq!(field.source(), None);
+    ///
+    /// let field = field.with_source(Source::Data);
+    /// assert_eq!(field.source(), Some(Source::Data));
+    /// ```
+    pub fn source(&self) -> Option<Source> {
+        self.source
+    }
+
     /// Shift the `name` of `self` and return `self` with the shfited `name`.
     ///
     /// See [`NameView::shift()`] for the details on name "shifting".
@@ -216,7 +271,7 @@ impl<'v> DataField<'v, '_> {
 
 impl<'a> From<(&'a str, &'a str)> for ValueField<'a> {
     fn from((name, value): (&'a str, &'a str)) -> Self {
-        ValueField { name: NameView::new(name), value }
+        ValueField { name: NameView::new(name), value, source: None }
     }
 }
 
@@ -24,6 +24,13 @@ use crate::form::prelude::*;
 /// "a" and "c", the form _will_ parse as `Form<T>`. To parse strictly, use the
 /// [`Strict`](crate::form::Strict) form guard.
 ///
+/// # Query Strings
+///
+/// A `Form<T>` parses only the request body: fields in the query string are
+/// never pushed into `T`, even when their names match. Query strings are parsed
+/// by query guards instead. Should a single `T` be parsed from both, use the
+/// [`Merged`](crate::form::Merged) data guard.
+///
 /// # Usage
 ///
 /// This type can be used with any type that implements the `FromForm` trait.
@@ -246,7 +253,7 @@ impl<T: for<'a> FromForm<'a> + 'static> Form<T> {
     pub fn parse_encoded(string: &RawStr) -> Result<'static, T> {
         let buffer = Buffer::new();
         let mut ctxt = T::init(Options::Lenient);
-        for field in RawStrParser::new(&buffer, string) {
+        for field in RawStrParser::new(&buffer, string, T::PLUS_AS_SPACE) {
             T::push_value(&mut ctxt, field)
         }
 
@@ -302,13 +309,13 @@ impl<'r, T: FromForm<'r>> FromData<'r> for Form<T> {
     async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
         use either::Either;
 
-        let mut parser = try_outcome!(Parser::new(req, data).await);
+        let mut parser = try_outcome!(Parser::new(req, data, T::PLUS_AS_SPACE).await);
         let mut context = T::init(Options::Lenient);
         while let Some(field) = parser.next().await {
             match field {
                 Ok(Either::Left(value)) => T::push_value(&mut context, value),
                 Ok(Either::Right(data)) => T::push_data(&mut context, data).await,
-                Err(e) => T::push_error(&mut context, e),
+                Err(e) => T::push_error(&mut context, *e),
             }
         }
 
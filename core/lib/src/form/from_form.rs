@@ -123,6 +123,11 @@ use crate::http::uncased::AsUncased;
 /// | [`time::Date`]     | _inherit_   | **no default**    | No     | Yes    | `%F` (`YYYY-MM-DD`). HTML "date" input.            |
 /// | [`time::DateTime`] | _inherit_   | **no default**    | No     | Yes    | `%FT%R` or `%FT%T` (`YYYY-MM-DDTHH:MM[:SS]`)       |
 /// | [`time::Time`]     | _inherit_   | **no default**    | No     | Yes    | `%R` or `%T` (`HH:MM[:SS]`)                        |
+/// | [`OffsetDateTime`] | _inherit_   | **no default**    | No     | Yes    | RFC 3339, e.g. `2021-06-13T12:30:00+02:00`         |
+/// | [`NaiveDate`]      | _inherit_   | **no default**    | No     | Yes    | As `time::Date`. Requires `chrono` feature.        |
+/// | [`NaiveTime`]      | _inherit_   | **no default**    | No     | Yes    | As `time::Time`. Requires `chrono` feature.        |
+/// | [`NaiveDateTime`]  | _inherit_   | **no default**    | No     | Yes    | As `time::DateTime`. Requires `chrono` feature.    |
+/// | [`DateTime<Utc>`]  | _inherit_   | **no default**    | No     | Yes    | As `OffsetDateTime`. Requires `chrono` feature.    |
 ///
 /// [`Result<T>`]: crate::form::Result
 /// [`Strict<T>`]: crate::form::Strict
@@ -132,6 +137,12 @@ use crate::http::uncased::AsUncased;
 /// [`TempFile`]: crate::fs::TempFile
 /// [`Capped<C>`]: crate::data::Capped
 /// [`time::DateTime`]: time::PrimitiveDateTime
+/// [`Validation`]: crate::form::error::ErrorKind::Validation
+/// [`OffsetDateTime`]: time::OffsetDateTime
+/// [`NaiveDate`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
+/// [`NaiveTime`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveTime.html
+/// [`NaiveDateTime`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDateTime.html
+/// [`DateTime<Utc>`]: https://docs.rs/chrono/0.4/chrono/struct.DateTime.html
 /// [`IpAddr`]: std::net::IpAddr
 /// [`Ipv4Addr`]: std::net::Ipv4Addr
 /// [`Ipv6Addr`]: std::net::Ipv6Addr
@@ -169,7 +180,11 @@ use crate::http::uncased::AsUncased;
 ///     created for the offending form field.
 ///
 ///     Errors are collected as they occur. Finalization finalizes all pairs and
-///     returns errors, if any, or the map.
+///     returns errors, if any, or the map. Errors in a key or value are named
+///     after the map entry, as in `map[index]`, so every invalid key is
+///     reported separately, even when the key was parsed from the index.
+///     Values may themselves be structures, so `map[index].field=value` pushes
+///     `field=value` to the `V` for `index`.
 ///
 ///   * **`bool`**
 ///
@@ -189,6 +204,23 @@ use crate::http::uncased::AsUncased;
 ///     This is the `"time"` HTML input type without support for the millisecond
 ///     variant.
 ///
+///   * **[`OffsetDateTime`]**
+///
+///     Parses a date and time with a UTC offset in RFC 3339 format, for
+///     example, `2021-06-13T12:30:00Z` or `2021-06-13T12:30:00.5-07:00`. The
+///     HTML `"datetime-local"` input type has no offset; parse it as a
+///     [`time::DateTime`] instead.
+///
+///   * **[`NaiveDate`], [`NaiveTime`], [`NaiveDateTime`], [`DateTime<Utc>`]**
+///
+///     Available with the `chrono` feature and parsed exactly as
+///     [`time::Date`], [`time::Time`], [`time::DateTime`], and
+///     [`OffsetDateTime`], respectively. A `DateTime<Utc>` accepts any offset
+///     and is converted to UTC.
+///
+///     All date and time types fail to parse with a [`Validation`] error
+///     naming the expected format.
+///
 /// # Push Parsing
 ///
 /// `FromForm` describes a push-based parser for Rocket's [field wire format].
@@ -523,6 +555,18 @@ pub trait FromForm<'r>: Send + Sized {
     /// The form guard's parsing context.
     type Context: Send;
 
+    /// Whether a `+` in a URL-encoded field name or value decodes as a space.
+    ///
+    /// When `true`, the default, fields are decoded with
+    /// `application/x-www-form-urlencoded` semantics. When `false`, fields are
+    /// decoded with strict RFC 3986 semantics, where `+` is literal. Only the
+    /// value for the outermost form guard, that is, the `T` in a `Form<T>` or
+    /// the type of a query parameter, is considered. See [`Rfc3986`] for
+    /// details.
+    ///
+    /// [`Rfc3986`]: crate::form::Rfc3986
+    const PLUS_AS_SPACE: bool = true;
+
     /// Initializes and returns the parsing context for `Self`.
     fn init(opts: Options) -> Self::Context;
 
@@ -671,7 +715,15 @@ impl<'v, K, V> MapContext<'v, K, V>
                 let is_new_key = !self.key_map.contains_key(key);
                 let (key_ctxt, val_ctxt) = self.ctxt(key, name);
                 if is_new_key {
-                    K::push_value(key_ctxt, ValueField::from_value(key));
+                    // The key is the index itself, so name it after the field.
+                    let mut key_name = NameView::new(name.as_name());
+                    while !key_name.exhausted() {
+                        key_name.shift();
+                    }
+
+                    let mut key_field = ValueField::from_value(key);
+                    key_field.name = key_name;
+                    K::push_value(key_ctxt, key_field);
                 }
 
                 return Some(Either::Right(val_ctxt));
@@ -718,28 +770,27 @@ impl<'v, K, V> MapContext<'v, K, V>
     }
 
     fn finalize<T: std::iter::FromIterator<(K, V)>>(self) -> Result<'v, T> {
-        let (keys, values, key_map) = (self.keys, self.values, self.key_map);
-        let errors = std::cell::RefCell::new(self.errors);
-
-        let keys = keys.into_iter()
-            .zip(key_map.values().map(|(_, name)| name))
-            .filter_map(|(ctxt, name)| match K::finalize(ctxt) {
-                Ok(value) => Some(value),
-                Err(e) => { errors.borrow_mut().extend(e.with_name(*name)); None },
-            });
-
-        let values = values.into_iter()
-            .zip(key_map.values().map(|(_, name)| name))
-            .filter_map(|(ctxt, name)| match V::finalize(ctxt) {
-                Ok(value) => Some(value),
-                Err(e) => { errors.borrow_mut().extend(e.with_name(*name)); None },
-            });
-
-        let map: T = keys.zip(values).collect();
-        let no_errors = errors.borrow().is_empty();
-        match no_errors {
+        let mut errors = self.errors;
+        let map: T = self.keys.into_iter()
+            .zip(self.values)
+            .zip(self.key_map.values())
+            .filter_map(|((key, value), (_, name))| {
+                match (K::finalize(key), V::finalize(value)) {
+                    (Ok(key), Ok(value)) => return Some((key, value)),
+                    (Err(e), Ok(_)) | (Ok(_), Err(e)) => errors.extend(e.with_name(*name)),
+                    (Err(key_errors), Err(value_errors)) => {
+                        errors.extend(key_errors.with_name(*name));
+                        errors.extend(value_errors.with_name(*name));
+                    }
+                }
+
+                None
+            })
+            .collect();
+
+        match errors.is_empty() {
             true => Ok(map),
-            false => Err(errors.into_inner())
+            false => Err(errors)
         }
     }
 }
@@ -794,6 +845,8 @@ impl<'v, K, V> FromForm<'v> for BTreeMap<K, V>
 impl<'v, T: FromForm<'v>> FromForm<'v> for Option<T> {
     type Context = <T as FromForm<'v>>::Context;
 
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
     fn init(opts: Options) -> Self::Context {
         T::init(Options { strict: true, ..opts })
     }
@@ -815,6 +868,8 @@ impl<'v, T: FromForm<'v>> FromForm<'v> for Option<T> {
 impl<'v, T: FromForm<'v>> FromForm<'v> for Result<'v, T> {
     type Context = <T as FromForm<'v>>::Context;
 
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
     fn init(opts: Options) -> Self::Context {
         T::init(opts)
     }
@@ -5,7 +5,7 @@ use std::num::{
     NonZeroUsize, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128,
 };
 
-use time::{Date, Time, PrimitiveDateTime};
+use time::{Date, Time, PrimitiveDateTime, OffsetDateTime, Format};
 
 use crate::data::Capped;
 use crate::http::uncased::AsUncased;
@@ -356,31 +356,83 @@ impl_with_parse!(
     Ipv4Addr, IpAddr, Ipv6Addr, SocketAddrV4, SocketAddrV6, SocketAddr
 );
 
+/// Returns the error for a value that failed to parse as a `what` in the
+/// `expected` format(s).
+fn invalid_datetime<'v>(what: &str, expected: &str) -> Errors<'v> {
+    Error::validation(format!("invalid {}: expected {}", what, expected)).into()
+}
+
 impl<'v> FromFormField<'v> for Date {
     fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
-        let date = Self::parse(field.value, "%F")
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send>)?;
-
-        Ok(date)
+        Self::parse(field.value, "%F")
+            .map_err(|_| invalid_datetime("date", "`YYYY-MM-DD`"))
     }
 }
 
 impl<'v> FromFormField<'v> for Time {
     fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
-        let time = Self::parse(field.value, "%T")
+        Self::parse(field.value, "%T")
             .or_else(|_| Self::parse(field.value, "%R"))
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send>)?;
-
-        Ok(time)
+            .map_err(|_| invalid_datetime("time", "`HH:MM` or `HH:MM:SS`"))
     }
 }
 
 impl<'v> FromFormField<'v> for PrimitiveDateTime {
     fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
-        let dt = Self::parse(field.value, "%FT%T")
+        Self::parse(field.value, "%FT%T")
             .or_else(|_| Self::parse(field.value, "%FT%R"))
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Send>)?;
+            .map_err(|_| {
+                let expected = "`YYYY-MM-DDTHH:MM` or `YYYY-MM-DDTHH:MM:SS`";
+                invalid_datetime("date and time", expected)
+            })
+    }
+}
+
+impl<'v> FromFormField<'v> for OffsetDateTime {
+    fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+        Self::parse(field.value, Format::Rfc3339)
+            .map_err(|_| invalid_datetime("date and time", "RFC 3339"))
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    use chrono_::{NaiveDate, NaiveTime, NaiveDateTime, DateTime, Utc};
 
-        Ok(dt)
+    use crate::form::prelude::*;
+    use super::invalid_datetime;
+
+    impl<'v> FromFormField<'v> for NaiveDate {
+        fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+            Self::parse_from_str(field.value, "%F")
+                .map_err(|_| invalid_datetime("date", "`YYYY-MM-DD`"))
+        }
+    }
+
+    impl<'v> FromFormField<'v> for NaiveTime {
+        fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+            Self::parse_from_str(field.value, "%T")
+                .or_else(|_| Self::parse_from_str(field.value, "%R"))
+                .map_err(|_| invalid_datetime("time", "`HH:MM` or `HH:MM:SS`"))
+        }
+    }
+
+    impl<'v> FromFormField<'v> for NaiveDateTime {
+        fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+            Self::parse_from_str(field.value, "%FT%T")
+                .or_else(|_| Self::parse_from_str(field.value, "%FT%R"))
+                .map_err(|_| {
+                    let expected = "`YYYY-MM-DDTHH:MM` or `YYYY-MM-DDTHH:MM:SS`";
+                    invalid_datetime("date and time", expected)
+                })
+        }
+    }
+
+    impl<'v> FromFormField<'v> for DateTime<Utc> {
+        fn from_value(field: ValueField<'v>) -> Result<'v, Self> {
+            DateTime::parse_from_rfc3339(field.value)
+                .map(|datetime| datetime.with_timezone(&Utc))
+                .map_err(|_| invalid_datetime("date and time", "RFC 3339"))
+        }
     }
 }
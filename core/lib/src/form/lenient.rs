@@ -77,6 +77,8 @@ impl<T> Lenient<T> {
 impl<'v, T: FromForm<'v>> FromForm<'v> for Lenient<T> {
     type Context = T::Context;
 
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
     #[inline(always)]
     fn init(opts: Options) -> Self::Context {
         T::init(Options { strict: false, ..opts })
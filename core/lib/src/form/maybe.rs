@@ -0,0 +1,221 @@
+use crate::form::prelude::*;
+
+/// A form guard that distinguishes a missing field from an empty one.
+///
+/// An HTML form submits an empty text input as `field=` but omits a field that
+/// isn't part of the form at all. An `Option<T>` conflates the two, and often
+/// a failure to parse, as `None`. A `Maybe<T>` instead parses as one of three
+/// states:
+///
+///   * [`Maybe::Absent`] if no field was submitted,
+///   * [`Maybe::Empty`] if every submitted field was empty (`field=` or
+///     `field`), and
+///   * [`Maybe::Present(T)`] otherwise, with `T` parsed from the non-empty
+///     fields.
+///
+/// This makes `Maybe` suited to `PATCH`-style forms, where an absent field
+/// leaves a value unchanged while an empty one clears it.
+///
+/// # Usage
+///
+/// `Maybe<T>` implements [`FromForm`] as long as `T` implements `FromForm`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::{Form, Maybe};
+///
+/// #[derive(FromForm)]
+/// struct Profile<'r> {
+///     name: Maybe<&'r str>,
+///     bio: Maybe<&'r str>,
+///     age: Maybe<u8>,
+/// }
+///
+/// #[patch("/profile", data = "<profile>")]
+/// fn update(profile: Form<Profile<'_>>) {
+///     match profile.bio {
+///         Maybe::Absent => { /* leave the bio as it is */ },
+///         Maybe::Empty => { /* clear the bio */ },
+///         Maybe::Present(bio) => { /* set the bio to `bio` */ },
+///     }
+/// }
+/// ```
+///
+/// With the form above, `name=Bob&bio=` parses `name` as `Present("Bob")`,
+/// `bio` as `Empty`, and `age` as `Absent`.
+///
+/// # Strictness
+///
+/// Unlike other form guards, a `Maybe<T>` never fails because a field is
+/// missing or empty, in [strict](crate::form::Strict) and
+/// [lenient](crate::form::Lenient) modes alike: such fields are what
+/// `Absent` and `Empty` represent. Empty fields are never pushed to `T`.
+///
+/// The non-empty fields of a `Present` value are parsed by `T` in the
+/// surrounding mode, so `Maybe<T>` within a `Form<Strict<_>>` parses `T`
+/// strictly, while `Maybe<Strict<T>>` or `Maybe<Lenient<T>>` opt into a mode
+/// for `T` alone. If they fail to parse, so does the `Maybe<T>`; the errors
+/// are never masked as they are by `Option<T>`.
+///
+/// [`Maybe::Present(T)`]: Maybe::Present
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Maybe<T> {
+    /// No field was submitted.
+    Absent,
+    /// Only empty fields were submitted.
+    Empty,
+    /// A value was submitted.
+    Present(T),
+}
+
+impl<T> Maybe<T> {
+    /// Returns `true` if `self` is `Absent`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// assert!(Maybe::<u8>::Absent.is_absent());
+    /// assert!(!Maybe::<u8>::Empty.is_absent());
+    /// ```
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Maybe::Absent)
+    }
+
+    /// Returns `true` if `self` is `Empty`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// assert!(Maybe::<u8>::Empty.is_empty());
+    /// assert!(!Maybe::Present(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Maybe::Empty)
+    }
+
+    /// Returns `true` if `self` is `Present`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// assert!(Maybe::Present(1).is_present());
+    /// assert!(!Maybe::<u8>::Absent.is_present());
+    /// ```
+    pub fn is_present(&self) -> bool {
+        matches!(self, Maybe::Present(_))
+    }
+
+    /// Converts from `&Maybe<T>` to `Maybe<&T>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// let name = Maybe::Present(String::from("Bob"));
+    /// assert_eq!(name.as_ref(), Maybe::Present(&String::from("Bob")));
+    /// ```
+    pub fn as_ref(&self) -> Maybe<&T> {
+        match self {
+            Maybe::Absent => Maybe::Absent,
+            Maybe::Empty => Maybe::Empty,
+            Maybe::Present(value) => Maybe::Present(value),
+        }
+    }
+
+    /// Returns the value if `self` is `Present` and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// assert_eq!(Maybe::Present(1).present(), Some(1));
+    /// assert_eq!(Maybe::<u8>::Empty.present(), None);
+    /// ```
+    pub fn present(self) -> Option<T> {
+        match self {
+            Maybe::Present(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Converts `self` into the `Option<Option<T>>` conventionally used for
+    /// updates: `None` if `Absent`, `Some(None)` if `Empty`, and
+    /// `Some(Some(value))` if `Present(value)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::Maybe;
+    ///
+    /// assert_eq!(Maybe::<u8>::Absent.into_option(), None);
+    /// assert_eq!(Maybe::<u8>::Empty.into_option(), Some(None));
+    /// assert_eq!(Maybe::Present(1).into_option(), Some(Some(1)));
+    /// ```
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Maybe::Absent => None,
+            Maybe::Empty => Some(None),
+            Maybe::Present(value) => Some(Some(value)),
+        }
+    }
+}
+
+impl<T> From<Maybe<T>> for Option<Option<T>> {
+    fn from(maybe: Maybe<T>) -> Self {
+        maybe.into_option()
+    }
+}
+
+#[doc(hidden)]
+pub struct MaybeContext<'v, T: FromForm<'v>> {
+    inner: T::Context,
+    /// Whether any field was pushed.
+    submitted: bool,
+    /// Whether a non-empty field was pushed to `inner`.
+    present: bool,
+}
+
+#[crate::async_trait]
+impl<'v, T: FromForm<'v>> FromForm<'v> for Maybe<T> {
+    type Context = MaybeContext<'v, T>;
+
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
+    fn init(opts: Options) -> Self::Context {
+        MaybeContext { inner: T::init(opts), submitted: false, present: false }
+    }
+
+    fn push_value(ctxt: &mut Self::Context, field: ValueField<'v>) {
+        ctxt.submitted = true;
+        if field.value.is_empty() && field.name.key().is_none() {
+            return;
+        }
+
+        ctxt.present = true;
+        T::push_value(&mut ctxt.inner, field)
+    }
+
+    async fn push_data(ctxt: &mut Self::Context, field: DataField<'v, '_>) {
+        ctxt.submitted = true;
+        ctxt.present = true;
+        T::push_data(&mut ctxt.inner, field).await
+    }
+
+    fn finalize(ctxt: Self::Context) -> Result<'v, Self> {
+        if ctxt.present {
+            T::finalize(ctxt.inner).map(Maybe::Present)
+        } else if ctxt.submitted {
+            Ok(Maybe::Empty)
+        } else {
+            Ok(Maybe::Absent)
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::ops::{Deref, DerefMut};
+
+use either::Either;
+
+use crate::Request;
+use crate::outcome::try_outcome;
+use crate::data::{Data, FromData, Outcome};
+use crate::form::parser::Parser;
+use crate::form::prelude::*;
+
+/// A data guard for [`FromForm`] types drawing from both the query string and
+/// the body.
+///
+/// Like [`Form`], this type implements the [`FromData`] trait and parses a
+/// form from the request body. Unlike `Form`, which considers only the body, a
+/// `Merged<T>` additionally pushes every field in the request's query string
+/// into the same `T`. The body is parsed first, followed by the query string.
+/// As a result, when a field is submitted in both, a single-valued field such
+/// as a `String` takes its value from the body while a collection such as a
+/// `Vec<T>` collects the values from the body followed by those from the query.
+///
+/// As with `Form`, a `Merged<T>` forwards when the request doesn't contain form
+/// data, and it is subject to the same [data limits](Form#data-limits).
+///
+/// # Sources
+///
+/// Fields of a derived `FromForm` type can be limited to one part of the
+/// request with the `source` field attribute. The field `page` below is only
+/// ever read from the query string and the field `body` only from the request
+/// body. A field with a `source` ignores fields with the same name submitted
+/// in the other part of the request. The field `tags` has no `source` and so
+/// collects values from both:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::Merged;
+///
+/// #[derive(FromForm)]
+/// struct Comment<'r> {
+///     #[field(source = query)]
+///     page: usize,
+///     #[field(source = data)]
+///     body: &'r str,
+///     tags: Vec<&'r str>,
+/// }
+///
+/// #[post("/comment", data = "<comment>")]
+/// fn comment(comment: Merged<Comment<'_>>) -> String {
+///     format!("{} on page {}, tagged {:?}", comment.body, comment.page, comment.tags)
+/// }
+/// ```
+///
+/// The `source` attribute applies equally to `Form` and to query guards: a
+/// `#[field(source = data)]` field is never filled by a query guard, for
+/// instance, and a `#[field(source = query)]` field never by a `Form`.
+///
+/// # Strictness
+///
+/// Like `Form`, `Merged` is lenient. To parse strictly, use
+/// `Merged<Strict<T>>`. Note that in strict mode, _every_ field in the query
+/// string must be expected by `T`, including those meant for other query
+/// guards in the same route, and fields submitted in a part of the request
+/// other than their `source` are errors.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Merged<T>(T);
+
+impl<T> Merged<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::form::Merged;
+    ///
+    /// #[derive(FromForm)]
+    /// struct MyForm {
+    ///     field: String,
+    /// }
+    ///
+    /// #[post("/submit", data = "<form>")]
+    /// fn submit(form: Merged<MyForm>) -> String {
+    ///     form.into_inner().field
+    /// }
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Merged<T> {
+    #[inline]
+    fn from(val: T) -> Merged<T> {
+        Merged(val)
+    }
+}
+
+impl<T> Deref for Merged<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Merged<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: FromForm<'r>> FromData<'r> for Merged<T> {
+    type Error = Errors<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let mut parser = try_outcome!(Parser::new(req, data, T::PLUS_AS_SPACE).await);
+        let mut context = T::init(Options::Lenient);
+        while let Some(field) = parser.next().await {
+            match field {
+                Ok(Either::Left(value)) => T::push_value(&mut context, value),
+                Ok(Either::Right(data)) => T::push_data(&mut context, data).await,
+                Err(e) => T::push_error(&mut context, *e),
+            }
+        }
+
+        let query = match T::PLUS_AS_SPACE {
+            true => Either::Left(req.query_fields()),
+            false => Either::Right(req.rfc3986_query_fields()),
+        };
+
+        query.for_each(|field| T::push_value(&mut context, field));
+        match T::finalize(context) {
+            Ok(value) => Outcome::Success(Merged(value)),
+            Err(e) => Outcome::Failure((e.status(), e)),
+        }
+    }
+}
@@ -364,10 +364,14 @@ mod options;
 mod from_form;
 mod from_form_field;
 mod form;
+mod merged;
 mod context;
 mod strict;
 mod lenient;
-mod parser;
+mod maybe;
+mod rfc3986;
+mod raw_query;
+pub(crate) mod parser;
 pub mod validate;
 pub mod name;
 pub mod error;
@@ -389,9 +393,13 @@ pub use options::*;
 pub use from_form_field::*;
 pub use from_form::*;
 pub use form::*;
+pub use merged::*;
 pub use context::*;
 pub use strict::*;
 pub use lenient::*;
+pub use maybe::*;
+pub use rfc3986::*;
+pub use raw_query::*;
 
 #[doc(hidden)]
 pub mod prelude {
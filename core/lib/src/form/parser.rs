@@ -13,6 +13,9 @@ type Result<'r, T> = std::result::Result<T, Error<'r>>;
 
 type Field<'r, 'i> = Either<ValueField<'r>, DataField<'r, 'i>>;
 
+/// The result of parsing one field. The large error is boxed.
+type FieldResult<'r, 'i> = std::result::Result<Field<'r, 'i>, Box<Error<'r>>>;
+
 pub struct Buffer {
     strings: UnsafeCell<Vec<String>>,
     mutex: RawMutex,
@@ -28,6 +31,7 @@ pub struct MultipartParser<'r, 'i> {
 pub struct RawStrParser<'r> {
     buffer: &'r Buffer,
     source: &'r RawStr,
+    plus_as_space: bool,
 }
 
 pub enum Parser<'r, 'i> {
@@ -38,10 +42,11 @@ pub enum Parser<'r, 'i> {
 impl<'r, 'i> Parser<'r, 'i> {
     pub async fn new(
         req: &'r Request<'i>,
-        data: Data<'r>
+        data: Data<'r>,
+        plus_as_space: bool,
     ) -> Outcome<'r, Parser<'r, 'i>, Errors<'r>> {
         let parser = match req.content_type() {
-            Some(c) if c.is_form() => Self::from_form(req, data).await,
+            Some(c) if c.is_form() => Self::from_form(req, data, plus_as_space).await,
             Some(c) if c.is_form_data() => Self::from_multipart(req, data).await,
             _ => return Outcome::Forward(data),
         };
@@ -52,7 +57,11 @@ impl<'r, 'i> Parser<'r, 'i> {
         }
     }
 
-    async fn from_form(req: &'r Request<'i>, data: Data<'r>) -> Result<'r, Parser<'r, 'i>> {
+    async fn from_form(
+        req: &'r Request<'i>,
+        data: Data<'r>,
+        plus_as_space: bool,
+    ) -> Result<'r, Parser<'r, 'i>> {
         let limit = req.limits().get("form").unwrap_or(Limits::FORM);
         let string = data.open(limit).into_string().await?;
         if !string.is_complete() {
@@ -62,6 +71,7 @@ impl<'r, 'i> Parser<'r, 'i> {
         Ok(Parser::RawStr(RawStrParser {
             buffer: local_cache!(req, Buffer::new()),
             source: RawStr::new(local_cache!(req, string.into_inner())),
+            plus_as_space,
         }))
     }
 
@@ -83,17 +93,19 @@ impl<'r, 'i> Parser<'r, 'i> {
         }))
     }
 
-    pub async fn next(&mut self) -> Option<Result<'r, Field<'r, 'i>>> {
+    pub async fn next(&mut self) -> Option<FieldResult<'r, 'i>> {
         match self {
             Parser::Multipart(ref mut p) => p.next().await,
-            Parser::RawStr(ref mut p) => p.next().map(|f| Ok(Either::Left(f)))
+            Parser::RawStr(ref mut p) => p.next().map(|f| {
+                Ok(Either::Left(f.with_source(Source::Data)))
+            })
         }
     }
 }
 
 impl<'r> RawStrParser<'r> {
-    pub fn new(buffer: &'r Buffer, source: &'r RawStr) -> Self {
-        RawStrParser { buffer, source }
+    pub fn new(buffer: &'r Buffer, source: &'r RawStr, plus_as_space: bool) -> Self {
+        RawStrParser { buffer, source, plus_as_space }
     }
 }
 
@@ -117,7 +129,12 @@ impl<'r> Iterator for RawStrParser<'r> {
         };
 
         trace_!("url-encoded field: {:?}", (name, value));
-        let name_val = match (name.url_decode_lossy(), value.url_decode_lossy()) {
+        let decode = |raw: &'r RawStr| match self.plus_as_space {
+            true => raw.url_decode_lossy(),
+            false => raw.percent_decode_lossy(),
+        };
+
+        let name_val = match (decode(name), decode(value)) {
             (Borrowed(name), Borrowed(val)) => (name, val),
             (Borrowed(name), Owned(v)) => (name, self.buffer.push_one(v)),
             (Owned(name), Borrowed(val)) => (self.buffer.push_one(name), val),
@@ -139,22 +156,31 @@ mod raw_str_parse_tests {
     #[test]
     fn test_skips_empty() {
         let buffer = super::Buffer::new();
-        let fields: Vec<_> = super::RawStrParser::new(&buffer, "a&b=c&&&c".into()).collect();
+        let fields: Vec<_> = super::RawStrParser::new(&buffer, "a&b=c&&&c".into(), true).collect();
         assert_eq!(fields, &[Field::parse("a"), Field::parse("b=c"), Field::parse("c")]);
     }
 
     #[test]
     fn test_decodes() {
         let buffer = super::Buffer::new();
-        let fields: Vec<_> = super::RawStrParser::new(&buffer, "a+b=c%20d&%26".into()).collect();
+        let source = "a+b=c%20d&%26".into();
+        let fields: Vec<_> = super::RawStrParser::new(&buffer, source, true).collect();
         assert_eq!(fields, &[Field::parse("a b=c d"), Field::parse("&")]);
     }
+
+    #[test]
+    fn test_decodes_plus_literally() {
+        let buffer = super::Buffer::new();
+        let source = "a+b=c+d%20e&%2B".into();
+        let fields: Vec<_> = super::RawStrParser::new(&buffer, source, false).collect();
+        assert_eq!(fields, &[Field::parse("a+b=c+d e"), Field::parse("+")]);
+    }
 }
 
 impl<'r, 'i> MultipartParser<'r, 'i> {
     /// Returns `None` when there are no further fields. Otherwise tries to
     /// parse the next multipart form field and returns the result.
-    async fn next(&mut self) -> Option<Result<'r, Field<'r, 'i>>> {
+    async fn next(&mut self) -> Option<FieldResult<'r, 'i>> {
         if self.done {
             return None;
         }
@@ -164,7 +190,7 @@ impl<'r, 'i> MultipartParser<'r, 'i> {
             Ok(None) => return None,
             Err(e) => {
                 self.done = true;
-                return Some(Err(e.into()));
+                return Some(Err(Box::new(e.into())));
             }
         };
 
@@ -197,11 +223,11 @@ impl<'r, 'i> MultipartParser<'r, 'i> {
 
             match field.text().await {
                 Ok(text) => buf.push_str(&text),
-                Err(e) => return Some(Err(e.into())),
+                Err(e) => return Some(Err(Box::new(e.into()))),
             };
 
             let name_val = self.buffer.push_split(buf, len);
-            Either::Left(ValueField::from(name_val))
+            Either::Left(ValueField::from(name_val).with_source(Source::Data))
         };
 
         Some(Ok(field))
@@ -0,0 +1,184 @@
+use std::convert::Infallible;
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the query string as an ordered list of key/value pairs.
+///
+/// A `RawQuery` binds to the entire query of a request without requiring a
+/// [`FromForm`](crate::form::FromForm) type to parse it into. Each field of
+/// the query is a `(key, value)` pair, percent-decoded exactly as it would be
+/// for a query guard, in the order the fields appear in the query. Duplicate
+/// keys are preserved. A request without a query has an empty `RawQuery`.
+///
+/// This makes `RawQuery` suitable for handlers that accept arbitrary query
+/// parameters, such as generic search or filter endpoints.
+///
+/// # Outcomes
+///
+///   * **Success** always.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::RawQuery;
+///
+/// #[get("/search")]
+/// fn search(query: RawQuery<'_>) -> String {
+///     let tags: Vec<&str> = query.get_all("tag").collect();
+///     let sort = query.get("sort").unwrap_or("relevance");
+///     format!("tags: {:?}, sorted by {}", tags, sort)
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RawQuery<'r> {
+    fields: Vec<(&'r str, &'r str)>,
+}
+
+impl<'r> RawQuery<'r> {
+    /// Returns the value of the first field with key `key`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/?a=apple&z=zebra&a=aardvark");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request's query is `a=apple&z=zebra&a=aardvark`.
+    /// assert_eq!(query.get("a"), Some("apple"));
+    /// assert_eq!(query.get("z"), Some("zebra"));
+    /// assert_eq!(query.get("b"), None);
+    /// # });
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&'r str> {
+        self.get_all(key).next()
+    }
+
+    /// Returns an iterator over the values of every field with key `key`, in
+    /// the order they appear in the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/?a=apple&z=zebra&a=aardvark");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request's query is `a=apple&z=zebra&a=aardvark`.
+    /// let a: Vec<&str> = query.get_all("a").collect();
+    /// assert_eq!(a, ["apple", "aardvark"]);
+    /// assert_eq!(query.get_all("b").count(), 0);
+    /// # });
+    /// ```
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'r str> + 'a {
+        self.fields.iter()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, v)| *v)
+    }
+
+    /// Returns `true` if there is a field with key `key`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/?verbose&z=zebra");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request's query is `verbose&z=zebra`.
+    /// assert!(query.contains("verbose"));
+    /// assert!(!query.contains("a"));
+    /// # });
+    /// ```
+    pub fn contains(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns an iterator over all `(key, value)` pairs in the order they
+    /// appear in the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/?a=apple&z=zebra+fish&a=aardvark");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request's query is `a=apple&z=zebra+fish&a=aardvark`.
+    /// let fields: Vec<_> = query.iter().collect();
+    /// assert_eq!(fields, [("a", "apple"), ("z", "zebra fish"), ("a", "aardvark")]);
+    /// # });
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'r str, &'r str)> + '_ {
+        self.fields.iter().copied()
+    }
+
+    /// Returns the number of fields in the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/?a=apple&a=aardvark");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request's query is `a=apple&a=aardvark`.
+    /// assert_eq!(query.len(), 2);
+    /// # });
+    /// ```
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns `true` if the query has no fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::form::RawQuery;
+    ///
+    /// # rocket::async_test(async {
+    /// # let c = rocket::local::asynchronous::Client::debug_with(vec![]).await.unwrap();
+    /// # let req = c.get("/");
+    /// # let query: RawQuery<'_> = req.guard().await.unwrap();
+    /// // The request has no query.
+    /// assert!(query.is_empty());
+    /// # });
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl<'r> IntoIterator for RawQuery<'r> {
+    type Item = (&'r str, &'r str);
+    type IntoIter = std::vec::IntoIter<(&'r str, &'r str)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RawQuery<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let fields = req.query_fields()
+            .map(|field| (field.name.source().as_str(), field.value))
+            .collect();
+
+        Outcome::Success(RawQuery { fields })
+    }
+}
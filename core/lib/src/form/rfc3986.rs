@@ -0,0 +1,146 @@
+use std::ops::{Deref, DerefMut};
+
+use crate::form::prelude::*;
+use crate::http::uri::fmt::{Query, FromUriParam};
+
+/// A form guard for parsing URL-encoded forms with strict RFC 3986 decoding.
+///
+/// This type implements the [`FromForm`] trait and thus can be used as a
+/// generic parameter to the [`Form`] data guard, `Form<Rfc3986<T>>`, or as the
+/// type of a query parameter, where `T` implements `FromForm`. Unlike using `T`
+/// directly, a `+` in a field name or value is _not_ decoded as a space.
+///
+/// # Decoding
+///
+/// By default, URL-encoded forms, both in request bodies and in query strings,
+/// are decoded with `application/x-www-form-urlencoded` semantics: `+` decodes
+/// as a space, and percent-encoded sequences, including `%20` and `%2B`, as
+/// the characters they encode. This matches the behavior of HTML forms and
+/// most clients, which encode spaces as `+`.
+///
+/// Some clients instead percent-encode per [RFC 3986] alone, where `+` is a
+/// literal character, sending `c++` for the search term `c++`. `Rfc3986<T>`
+/// decodes fields received from such clients correctly; spaces must then be
+/// sent as `%20`. Regardless of the decoding, a literal `+` encoded as `%2B`
+/// parses as `+`, so clients that always percent-encode `+` are interpreted
+/// identically by both.
+///
+/// [RFC 3986]: https://tools.ietf.org/html/rfc3986#section-2.1
+///
+/// # Usage
+///
+/// `Rfc3986<T>` implements [`FromForm`] as long as `T` implements `FromForm`.
+/// Decoding occurs before fields are pushed to form guards, so only the
+/// outermost form guard can select it: that is, the `T` of a `Form<T>` or the
+/// type of a query parameter. `Form<Strict<Rfc3986<T>>>` and
+/// `Option<Rfc3986<T>>`, for instance, decode strictly, while a struct field of
+/// type `Rfc3986<T>` has no effect. Multipart forms are not URL-encoded and
+/// are unaffected.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::form::{Form, Rfc3986};
+///
+/// #[derive(FromForm)]
+/// struct Search<'r> {
+///     term: &'r str,
+/// }
+///
+/// // For `/search?q=c++`, `q` is `"c++"`.
+/// #[get("/search?<q>")]
+/// fn search(q: Rfc3986<&str>) -> String {
+///     q.into_inner().to_string()
+/// }
+///
+/// // For a body of `term=c++`, `term` is `"c++"`.
+/// #[post("/search", data = "<form>")]
+/// fn post_search(form: Form<Rfc3986<Search<'_>>>) -> String {
+///     form.term.to_string()
+/// }
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rfc3986<T>(T);
+
+impl<T> Rfc3986<T> {
+    /// Consumes `self` and returns the inner value.
+    ///
+    /// Note that since `Rfc3986` implements [`Deref`] and [`DerefMut`] with
+    /// target `T`, reading and writing an inner value can be accomplished
+    /// transparently.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::form::Rfc3986;
+    ///
+    /// #[get("/?<tags>")]
+    /// fn index(tags: Rfc3986<Vec<String>>) -> String {
+    ///     // We can read or mutate a value transparently:
+    ///     let count = tags.len();
+    ///
+    ///     // To gain ownership, however, use `into_inner()`:
+    ///     tags.into_inner().join(",")
+    /// }
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[crate::async_trait]
+impl<'v, T: FromForm<'v>> FromForm<'v> for Rfc3986<T> {
+    type Context = T::Context;
+
+    const PLUS_AS_SPACE: bool = false;
+
+    #[inline(always)]
+    fn init(opts: Options) -> Self::Context {
+        T::init(opts)
+    }
+
+    #[inline(always)]
+    fn push_value(ctxt: &mut Self::Context, field: ValueField<'v>) {
+        T::push_value(ctxt, field)
+    }
+
+    #[inline(always)]
+    async fn push_data(ctxt: &mut Self::Context, field: DataField<'v, '_>) {
+        T::push_data(ctxt, field).await
+    }
+
+    #[inline(always)]
+    fn finalize(this: Self::Context) -> Result<'v, Self> {
+        T::finalize(this).map(Self)
+    }
+}
+
+impl<T> Deref for Rfc3986<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Rfc3986<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> From<T> for Rfc3986<T> {
+    #[inline]
+    fn from(val: T) -> Rfc3986<T> {
+        Rfc3986(val)
+    }
+}
+
+impl<'f, A, T: FromUriParam<Query, A> + FromForm<'f>> FromUriParam<Query, A> for Rfc3986<T> {
+    type Target = T::Target;
+
+    #[inline(always)]
+    fn from_uri_param(param: A) -> Self::Target {
+        T::from_uri_param(param)
+    }
+}
@@ -92,6 +92,8 @@ impl<T> Strict<T> {
 impl<'v, T: FromForm<'v>> FromForm<'v> for Strict<T> {
     type Context = T::Context;
 
+    const PLUS_AS_SPACE: bool = T::PLUS_AS_SPACE;
+
     #[inline(always)]
     fn init(opts: Options) -> Self::Context {
         T::init(Options { strict: true, ..opts })
@@ -64,7 +64,7 @@ macro_rules! assert_parse_fails {
 
 #[test]
 fn time() {
-    use time::{date, time, Date, Time, PrimitiveDateTime as DateTime};
+    use time::{date, time, offset, Date, Time, PrimitiveDateTime as DateTime, OffsetDateTime};
 
     assert_values_parse_eq! {
         &["=2010-10-20"] => Date = date!(2010-10-20),
@@ -74,6 +74,56 @@ fn time() {
         &["=20:20:52"] => Time = time!(20:20:52),
         &["=06:08"] => Time = time!(06:08),
     }
+
+    let (date, time) = (date!(2021-06-13), time!(12:30));
+    assert_values_parse_eq! {
+        &["=2021-06-13T12:30:00Z"] => OffsetDateTime = DateTime::new(date, time).assume_utc(),
+        &["=2021-06-13T14:30:00+02:00"] => OffsetDateTime = DateTime::new(date, time).assume_utc(),
+        &["=2021-06-13T12:30:00.5-07:00"] => OffsetDateTime
+            = DateTime::new(date, time!(12:30:00.5)).assume_offset(offset!(-7)),
+    }
+
+    assert_parses_fail! {
+        &["=2021-13-01"] => Date,
+        &["=2021-06-13T12"] => DateTime,
+        &["=2021-06-13T12:30:00"] => OffsetDateTime,
+        &["=25:00"] => Time,
+    }
+
+    let error = parse::<Date>(&["=06/13/2021"]).unwrap_err();
+    assert_eq!(error[0].to_string(), "invalid date: expected `YYYY-MM-DD`");
+
+    let error = parse::<OffsetDateTime>(&["=2021-06-13T12:30"]).unwrap_err();
+    assert_eq!(error[0].to_string(), "invalid date and time: expected RFC 3339");
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono() {
+    use chrono_::{NaiveDate, NaiveTime, NaiveDateTime, DateTime, TimeZone, Utc};
+
+    let date = NaiveDate::from_ymd_opt(2021, 6, 13).unwrap();
+    let time = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+    let utc = Utc.from_utc_datetime(&date.and_time(time(12, 30, 0)));
+    assert_values_parse_eq! {
+        &["=2021-06-13"] => NaiveDate = date,
+        &["=12:30"] => NaiveTime = time(12, 30, 0),
+        &["=12:30:15"] => NaiveTime = time(12, 30, 15),
+        &["=2021-06-13T12:30"] => NaiveDateTime = date.and_time(time(12, 30, 0)),
+        &["=2021-06-13T12:30:15"] => NaiveDateTime = date.and_time(time(12, 30, 15)),
+        &["=2021-06-13T12:30:00Z"] => DateTime<Utc> = utc,
+        &["=2021-06-13T14:30:00+02:00"] => DateTime<Utc> = utc,
+    }
+
+    assert_parses_fail! {
+        &["=2021-13-01"] => NaiveDate,
+        &["=25:00"] => NaiveTime,
+        &["=2021-06-13"] => NaiveDateTime,
+        &["=2021-06-13T12:30"] => DateTime<Utc>,
+    }
+
+    let error = parse::<NaiveDate>(&["=06/13/2021"]).unwrap_err();
+    assert_eq!(error[0].to_string(), "invalid date: expected `YYYY-MM-DD`");
 }
 
 #[test]
@@ -119,6 +169,27 @@ fn defaults() {
     }
 }
 
+#[test]
+fn maybe() {
+    assert_values_parse_eq! {
+        &[] => Maybe<u8> = Maybe::Absent,
+        &[] => Maybe<Strict<bool>> = Maybe::Absent,
+        &["="] => Maybe<u8> = Maybe::Empty,
+        &["=", "="] => Maybe<Strict<u8>> = Maybe::Empty,
+        &[""] => Maybe<&str> = Maybe::Empty,
+
+        &["=5"] => Maybe<u8> = Maybe::Present(5),
+        &["=hi"] => Maybe<&str> = Maybe::Present("hi"),
+        &["=", "=4"] => Maybe<Vec<u8>> = Maybe::Present(std::vec![4]),
+        &["=yes"] => Maybe<Strict<bool>> = Maybe::Present(true.into()),
+    }
+
+    assert_parses_fail! {
+        &["=x"] => Maybe<u8>,
+        &["=", "=300"] => Maybe<Vec<u8>>,
+    }
+}
+
 #[test]
 fn potpourri() {
     assert_values_parse_eq! {
@@ -170,3 +241,30 @@ fn potpourri() {
             ],
     }
 }
+
+
+#[test]
+fn map_key_errors() {
+    assert_values_parse_eq! {
+        &["[1]=yes", "[2]=off", "[k:a]=3", "[v:a]=on"] => HashMap<usize, bool>
+            = map![1usize => true, 2usize => false, 3usize => true],
+    }
+
+    let errors = parse::<HashMap<usize, bool>>(&[
+        "[1]=yes", "[x]=on", "[2]=maybe", "[k:a]=-1", "[v:a]=no", "[3]=on"
+    ]).unwrap_err();
+
+    let names: Vec<_> = errors.iter()
+        .map(|e| (e.name.as_ref().unwrap().to_string(), e.value.as_deref().unwrap()))
+        .collect();
+
+    assert_eq!(names, &[
+        ("[x]".to_string(), "x"),
+        ("[2]".to_string(), "maybe"),
+        ("[k:a]".to_string(), "-1"),
+    ]);
+
+    let errors = parse::<Vec<HashMap<u8, u8>>>(&["[0][1]=1", "[0][300]=2"]).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].name.as_ref().unwrap(), "[0][300]");
+}
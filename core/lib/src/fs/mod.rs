@@ -2,11 +2,14 @@
 
 mod server;
 mod named_file;
+mod open_file;
 mod temp_file;
 mod file_name;
+mod range;
 
 pub use server::*;
 pub use named_file::*;
+pub use open_file::*;
 pub use temp_file::*;
 pub use file_name::*;
 pub use server::relative;
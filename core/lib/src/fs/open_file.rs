@@ -0,0 +1,146 @@
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use tokio::fs::File;
+use tokio::io::AsyncSeekExt;
+
+use crate::request::Request;
+use crate::response::{self, Responder, Response};
+use crate::http::ContentType;
+
+/// A [`Responder`] that streams an already open file.
+///
+/// Unlike [`NamedFile`], an `OpenFile` doesn't require a path to the file: it
+/// wraps a [`File`] that was opened elsewhere or that refers to a file
+/// descriptor with no path at all. An optional name can be provided via
+/// [`OpenFile::named()`] to infer the Content-Type of the response.
+///
+/// The file is streamed from its current position, so a file that was
+/// already read from or seeked into is only sent from that point on. When the
+/// file is a regular file, the response has a known size, the length of the
+/// file less its current position, and thus a `Content-Length`. Otherwise, as
+/// for a pipe or a FIFO, the file is streamed with chunked encoding.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::fs::OpenFile;
+///
+/// #[get("/report")]
+/// async fn report() -> std::io::Result<OpenFile> {
+///     let file = open_report().await?;
+///     Ok(OpenFile::new(file).await?.named("report.csv"))
+/// }
+///
+/// # async fn open_report() -> std::io::Result<rocket::tokio::fs::File> {
+/// #     rocket::tokio::fs::File::open("report.csv").await
+/// # }
+/// ```
+///
+/// [`NamedFile`]: crate::fs::NamedFile
+#[derive(Debug)]
+pub struct OpenFile {
+    file: File,
+    name: Option<PathBuf>,
+    size: Option<u64>,
+}
+
+impl OpenFile {
+    /// Wraps the open file `file`, which may be a [`std::fs::File`] or a
+    /// [`tokio::fs::File`](File), reading its length from its metadata and its
+    /// current position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the file's metadata or, for a regular file,
+    /// its position fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fs::OpenFile;
+    ///
+    /// # async fn f() -> std::io::Result<()> {
+    /// let file = std::fs::File::open("index.html")?;
+    /// let file = OpenFile::new(file).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn new<F: Into<File>>(file: F) -> io::Result<OpenFile> {
+        let mut file = file.into();
+        let metadata = file.metadata().await?;
+        let size = match metadata.is_file() {
+            true => Some(metadata.len().saturating_sub(file.seek(SeekFrom::Current(0)).await?)),
+            false => None,
+        };
+
+        Ok(OpenFile { file, name: None, size })
+    }
+
+    /// Sets the name used to infer the Content-Type of the response from its
+    /// extension. The name need not refer to an existing file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fs::OpenFile;
+    ///
+    /// # async fn f() -> std::io::Result<()> {
+    /// let file = std::fs::File::open("index.html")?;
+    /// let file = OpenFile::new(file).await?.named("index.html");
+    /// assert_eq!(file.name().unwrap().as_os_str(), "index.html");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn named<P: AsRef<Path>>(mut self, name: P) -> Self {
+        self.name = Some(name.as_ref().to_path_buf());
+        self
+    }
+
+    /// Retrieve the name set via [`OpenFile::named()`], if any.
+    #[inline(always)]
+    pub fn name(&self) -> Option<&Path> {
+        self.name.as_deref()
+    }
+
+    /// Retrieve the number of bytes that remain to be sent from the file's
+    /// current position if it is a regular file.
+    #[inline(always)]
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    /// Retrieve the underlying `File`.
+    #[inline(always)]
+    pub fn file(&self) -> &File {
+        &self.file
+    }
+
+    /// Take the underlying `File`.
+    #[inline(always)]
+    pub fn take_file(self) -> File {
+        self.file
+    }
+}
+
+/// Streams the file to the client with a `Content-Length` if its size is
+/// known and with chunked encoding otherwise. Sets or overrides the
+/// Content-Type in the response according to the extension of the file's
+/// name, if it has one and the extension is recognized.
+impl<'r> Responder<'r, 'static> for OpenFile {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        let mut response = Response::new();
+        match self.size {
+            Some(size) => response.set_sized_body(size as usize, self.file),
+            None => response.set_streamed_body(self.file),
+        }
+
+        let ext = self.name.as_ref().and_then(|name| name.extension());
+        if let Some(ct) = ext.and_then(|ext| ContentType::from_extension(&ext.to_string_lossy())) {
+            response.set_header(ct);
+        }
+
+        Ok(response)
+    }
+}
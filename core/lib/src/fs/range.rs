@@ -0,0 +1,83 @@
+use std::io::{self, SeekFrom};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::fs::File;
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
+/// A window `start..end` of a file that reads and seeks as if it were the
+/// entire file. The file must be positioned at `start` on construction.
+pub(crate) struct FileSlice {
+    file: File,
+    start: u64,
+    end: u64,
+    pos: u64,
+}
+
+impl FileSlice {
+    /// Returns the slice of `file` from `start` to `end`, inclusive.
+    pub(crate) fn new(file: File, start: u64, end: u64) -> Self {
+        FileSlice { file, start, end: end + 1, pos: start }
+    }
+
+    pub(crate) fn len(&self) -> u64 {
+        self.end - self.start
+    }
+}
+
+impl AsyncRead for FileSlice {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>
+    ) -> Poll<io::Result<()>> {
+        let me = self.get_mut();
+        let remaining = me.end.saturating_sub(me.pos);
+        let n = std::cmp::min(remaining, buf.remaining() as u64) as usize;
+        if n == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        let mut window = ReadBuf::new(buf.initialize_unfilled_to(n));
+        futures::ready!(Pin::new(&mut me.file).poll_read(cx, &mut window))?;
+        let read = window.filled().len();
+        buf.advance(read);
+        me.pos += read as u64;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for FileSlice {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        let me = self.get_mut();
+        let target = match position {
+            SeekFrom::Start(n) => me.start.checked_add(n),
+            SeekFrom::End(n) => offset(me.end, n),
+            SeekFrom::Current(n) => offset(me.pos, n),
+        };
+
+        match target {
+            Some(target) if target >= me.start => {
+                Pin::new(&mut me.file).start_seek(SeekFrom::Start(target))
+            }
+            _ => {
+                let e = "invalid seek to a negative or overflowing position";
+                Err(io::Error::new(io::ErrorKind::InvalidInput, e))
+            }
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        let me = self.get_mut();
+        let pos = futures::ready!(Pin::new(&mut me.file).poll_complete(cx))?;
+        me.pos = pos;
+        Poll::Ready(Ok(pos.saturating_sub(me.start)))
+    }
+}
+
+fn offset(base: u64, n: i64) -> Option<u64> {
+    match n >= 0 {
+        true => base.checked_add(n as u64),
+        false => base.checked_sub(n.unsigned_abs()),
+    }
+}
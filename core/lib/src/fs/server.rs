@@ -1,10 +1,17 @@
+use std::io::SeekFrom;
 use std::path::{PathBuf, Path};
+use std::time::{Duration, SystemTime};
 
-use crate::{Request, Data};
-use crate::http::{Method, uri::Segments, ext::IntoOwned};
+use time::OffsetDateTime;
+
+use crate::{Request, Data, Response};
+use crate::http::{Method, Status, ContentType, ContentRange, Header};
+use crate::http::{uri::Segments, ext::IntoOwned};
 use crate::route::{Route, Handler, Outcome};
-use crate::response::Redirect;
+use crate::response::{self, Redirect, Responder};
 use crate::fs::NamedFile;
+use crate::fs::range::FileSlice;
+use crate::request::RangeHeader;
 
 /// Custom handler for serving static files.
 ///
@@ -209,15 +216,377 @@ impl Handler for FileServer {
                     return Outcome::forward(data);
                 }
 
-                let index = NamedFile::open(p.join("index.html")).await.ok();
-                Outcome::from_or_forward(req, data, index)
+                let index = self.open(req, p.join("index.html")).await;
+                respond_or_forward(req, data, index)
             },
-            Some(p) => Outcome::from_or_forward(req, data, NamedFile::open(p).await.ok()),
+            Some(p) => respond_or_forward(req, data, self.open(req, p).await),
             None => Outcome::forward(data),
         }
     }
 }
 
+/// Responds with `served`, failing with `406 Not Acceptable` if no acceptable
+/// encoding of it is available, or forwards if there is nothing to serve.
+fn respond_or_forward<'r>(
+    req: &'r Request<'_>,
+    data: Data<'r>,
+    served: Option<Served>,
+) -> Outcome<'r> {
+    match served {
+        Some(served) => Outcome::from(req, served),
+        None => Outcome::forward(data),
+    }
+}
+
+impl FileServer {
+    /// Opens the file at `path` or, if [`Options::Precompressed`] is enabled,
+    /// a precompressed variant the client accepts, if there is one. If
+    /// [`Options::Ranges`] is enabled and the client requested a range, the
+    /// range is resolved against the opened file, subject to any `If-Range`.
+    async fn open(&self, req: &Request<'_>, path: PathBuf) -> Option<Served> {
+        let mut range = match self.options.contains(Options::Ranges) {
+            true => requested_range(req),
+            false => None,
+        };
+
+        let if_range = req.headers().get_one("If-Range");
+        if !self.options.contains(Options::Precompressed) {
+            let mut served = Served::new(NamedFile::open(&path).await.ok()?, false);
+            served.ranges = self.options.contains(Options::Ranges);
+            served.resolve(range, if_range).await;
+            return Some(served);
+        }
+
+        // The `Content-Range` of a precompressed variant refers to compressed
+        // bytes. Unless asked to, serve ranges only from the original file,
+        // and ignore the range if the client doesn't accept the original.
+        let mut codings = acceptable_codings(req.headers().get("Accept-Encoding"));
+        if range.is_some() && !self.options.contains(Options::CompressedRanges) {
+            match codings.contains(&Coding::Identity) {
+                true => codings = vec![Coding::Identity],
+                false => range = None,
+            }
+        }
+
+        let mut served = Self::open_variant(path, &codings).await?;
+        served.ranges = self.options.contains(Options::Ranges);
+        served.resolve(range, if_range).await;
+        Some(served)
+    }
+
+    /// Opens the first of `codings` available for the file at `path`: either
+    /// a precompressed variant of the file or the file itself. If none is
+    /// available, the file is opened but marked as not acceptable.
+    async fn open_variant(path: PathBuf, codings: &[Coding]) -> Option<Served> {
+        let file = NamedFile::open(&path).await.ok()?;
+        for coding in codings {
+            let (encoding, ext) = match *coding {
+                Coding::Variant(encoding, ext) => (encoding, ext),
+                Coding::Identity => return Some(Served::new(file, true)),
+            };
+
+            let mut variant = path.clone().into_os_string();
+            variant.push(ext);
+            if let Ok(variant) = NamedFile::open(variant).await {
+                let content_type = path.extension()
+                    .and_then(|ext| ContentType::from_extension(&ext.to_string_lossy()));
+
+                let mut served = Served::new(variant, true);
+                served.encoding = Some((encoding, content_type));
+                return Some(served);
+            }
+        }
+
+        let mut served = Served::new(file, true);
+        served.acceptable = false;
+        Some(served)
+    }
+}
+
+/// Returns the single byte range requested by `req`, if any. A `Range` is
+/// ignored if it is malformed or requests multiple ranges. Any `If-Range` is
+/// evaluated once the file to serve is known, in [`Served::resolve()`].
+fn requested_range<'r>(req: &'r Request<'_>) -> Option<RangeHeader<'r>> {
+    RangeHeader::parse(req.headers().get_one("Range")?)
+        .filter(|range| range.is_bytes() && range.ranges().len() == 1)
+}
+
+/// A content coding `FileServer` can respond with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Coding {
+    /// A precompressed variant: its encoding and the extension of its file.
+    Variant(&'static str, &'static str),
+    /// The original file, without a content coding.
+    Identity,
+}
+
+/// The codings `FileServer` can serve, in order of preference.
+const CODINGS: &[Coding] = &[
+    Coding::Variant("br", ".br"),
+    Coding::Variant("gzip", ".gz"),
+    Coding::Identity,
+];
+
+/// Parses a quality value: a number between `0` and `1`.
+fn quality(value: &str) -> Option<f32> {
+    value.trim().parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q))
+}
+
+/// Returns the members of `CODINGS` acceptable according to the
+/// `Accept-Encoding` header values `accept`, negotiated per RFC 7231 §5.3.4,
+/// in descending order of quality and then of preference.
+///
+/// A coding is qualified by its own entry or, absent one, by the `*` entry.
+/// `Identity` is acceptable unless excluded by `identity;q=0` or, absent an
+/// `identity` entry, by `*;q=0`; when qualified by neither, it is the least
+/// preferred coding. Entries with a malformed quality are ignored. Without
+/// any entries, only `Identity` is acceptable.
+fn acceptable_codings<'a, I>(accept: I) -> Vec<Coding>
+    where I: Iterator<Item = &'a str>
+{
+    let mut qualities: Vec<Option<f32>> = vec![None; CODINGS.len()];
+    let mut wildcard = None;
+    for entry in accept.flat_map(|value| value.split(',')) {
+        let mut params = entry.split(';').map(|s| s.trim());
+        let name = params.next().unwrap_or("");
+        let q = params
+            .map(|param| param.splitn(2, '=').map(|s| s.trim()))
+            .find_map(|mut kv| match kv.next() {
+                Some(k) if k.eq_ignore_ascii_case("q") => Some(kv.next().and_then(quality)),
+                _ => None,
+            });
+
+        let q = match q {
+            Some(Some(q)) => q,
+            Some(None) => continue,
+            None => 1.0,
+        };
+
+        let coding = CODINGS.iter().position(|coding| match coding {
+            Coding::Variant(encoding, _) => name.eq_ignore_ascii_case(encoding),
+            Coding::Identity => name.eq_ignore_ascii_case("identity"),
+        });
+
+        match coding {
+            Some(i) => qualities[i] = Some(q),
+            None if name == "*" => wildcard = Some(q),
+            None => {}
+        }
+    }
+
+    let mut accepted: Vec<(Coding, f32)> = CODINGS.iter()
+        .zip(qualities)
+        .filter_map(|(&coding, q)| match (coding, q.or(wildcard)) {
+            (_, Some(q)) => Some((coding, q)),
+            (Coding::Identity, None) => Some((coding, f32::MIN_POSITIVE)),
+            (Coding::Variant(..), None) => None,
+        })
+        .filter(|&(_, q)| q > 0.0)
+        .collect();
+
+    accepted.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    accepted.into_iter().map(|(coding, _)| coding).collect()
+}
+
+/// A file served by [`FileServer`].
+struct Served {
+    /// The file to respond with.
+    file: NamedFile,
+    /// If `file` is a precompressed variant, its encoding and the content type
+    /// of the original file, if it is known.
+    encoding: Option<(&'static str, Option<ContentType>)>,
+    /// Whether the response varies on `Accept-Encoding`.
+    vary: bool,
+    /// Whether to advertise support for range requests.
+    ranges: bool,
+    /// The validators of `file`, sent when `ranges` is set.
+    validators: Option<Validators>,
+    /// The part of `file` to respond with, if a range was requested.
+    partial: Option<Partial>,
+    /// Whether the client accepts any coding that is available. If not, the
+    /// request fails with `406 Not Acceptable`.
+    acceptable: bool,
+}
+
+/// The validators of a served file, against which an `If-Range` is evaluated.
+struct Validators {
+    /// A strong entity tag derived from the file's modification time and
+    /// length.
+    etag: String,
+    /// The file's modification time as an IMF-fixdate.
+    last_modified: String,
+    /// Whether `last_modified` is a strong validator: the file was last
+    /// modified at least a second ago, so it can't change again unnoticed
+    /// within the same second.
+    strong_date: bool,
+}
+
+/// The outcome of resolving a requested range against a file.
+enum Partial {
+    /// The range is served. The file is positioned at its start.
+    Satisfiable(ContentRange),
+    /// The range is unsatisfiable for a file of the given length.
+    Unsatisfiable(u64),
+}
+
+impl Served {
+    fn new(file: NamedFile, vary: bool) -> Self {
+        Served {
+            file,
+            encoding: None,
+            vary,
+            ranges: false,
+            validators: None,
+            partial: None,
+            acceptable: true,
+        }
+    }
+
+    /// If `self.ranges` is set, computes the validators of `self.file` and
+    /// resolves `range`, if any, against it unless `self` isn't acceptable.
+    /// As in RFC 7233 § 3.2, `range` is ignored if `if_range` is set and
+    /// doesn't match a strong validator of the file.
+    async fn resolve(&mut self, range: Option<RangeHeader<'_>>, if_range: Option<&str>) {
+        if !self.ranges {
+            return;
+        }
+
+        let metadata = match self.file.metadata().await {
+            Ok(metadata) => metadata,
+            Err(_) => return,
+        };
+
+        self.validators = Validators::new(&metadata);
+        let range = match range {
+            Some(range) => range,
+            None => return,
+        };
+
+        if let Some(if_range) = if_range {
+            match self.validators {
+                Some(ref validators) if validators.matches(if_range) => {},
+                _ => return,
+            }
+        }
+
+        if self.acceptable {
+            self.partial = Some(Partial::new(&mut self.file, &range, metadata.len()).await);
+        }
+    }
+}
+
+impl Validators {
+    /// The preferred HTTP-date format, IMF-fixdate, as in RFC 7231 § 7.1.1.1.
+    const IMF_FIXDATE: &'static str = "%a, %d %b %Y %H:%M:%S GMT";
+
+    /// Returns the validators of a file with metadata `metadata`, or `None` if
+    /// its modification time is unavailable.
+    fn new(metadata: &std::fs::Metadata) -> Option<Validators> {
+        let modified = metadata.modified().ok()?;
+        let secs = modified.duration_since(SystemTime::UNIX_EPOCH).ok()?.as_secs();
+        let date = OffsetDateTime::from_unix_timestamp(secs as i64);
+        let age = SystemTime::now().duration_since(modified);
+        let strong_date = matches!(age, Ok(age) if age >= Duration::from_secs(1));
+
+        Some(Validators {
+            etag: format!("\"{:x}-{:x}\"", secs, metadata.len()),
+            last_modified: date.format(Self::IMF_FIXDATE),
+            strong_date,
+        })
+    }
+
+    /// Whether the `If-Range` value `if_range` matches these validators. An
+    /// entity tag matches only if it is strong and identical to `self.etag`;
+    /// an HTTP-date only if it is identical to a strong `self.last_modified`.
+    fn matches(&self, if_range: &str) -> bool {
+        let if_range = if_range.trim();
+        match if_range.starts_with('"') || if_range.starts_with("W/") {
+            true => if_range == self.etag,
+            false => self.strong_date && if_range == self.last_modified,
+        }
+    }
+}
+
+impl Partial {
+    /// Resolves `range` against `file` of length `len`, seeking `file` to the
+    /// start of the range if it is satisfiable. A range that fails to seek is
+    /// treated as unsatisfiable.
+    async fn new(file: &mut NamedFile, range: &RangeHeader<'_>, len: u64) -> Partial {
+        use tokio::io::AsyncSeekExt;
+
+        let content_range = range.normalize(len).first()
+            .and_then(|range| ContentRange::new(*range.start(), *range.end(), Some(len)));
+
+        match content_range {
+            Some(range) => match file.seek(SeekFrom::Start(range.start())).await {
+                Ok(_) => Partial::Satisfiable(range),
+                Err(_) => Partial::Unsatisfiable(len),
+            },
+            None => Partial::Unsatisfiable(len),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for Served {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        if !self.acceptable {
+            return Err(Status::NotAcceptable);
+        }
+
+        let mut response = match self.partial {
+            None => self.file.respond_to(req)?,
+            Some(Partial::Satisfiable(range)) => {
+                let content_type = self.file.path().extension()
+                    .and_then(|ext| ContentType::from_extension(&ext.to_string_lossy()));
+
+                let slice = FileSlice::new(self.file.take_file(), range.start(), range.end());
+                let mut response = Response::build()
+                    .status(Status::PartialContent)
+                    .header(range)
+                    .sized_body(slice.len() as usize, slice)
+                    .finalize();
+
+                if let Some(content_type) = content_type {
+                    response.set_header(content_type);
+                }
+
+                response
+            }
+            Some(Partial::Unsatisfiable(len)) => {
+                return Response::build()
+                    .status(Status::RangeNotSatisfiable)
+                    .raw_header("Content-Range", format!("bytes */{}", len))
+                    .raw_header("Accept-Ranges", "bytes")
+                    .ok();
+            }
+        };
+
+        if let Some((encoding, content_type)) = self.encoding {
+            response.remove_header("Content-Type");
+            if let Some(content_type) = content_type {
+                response.set_header(content_type);
+            }
+
+            response.set_header(Header::new("Content-Encoding", encoding));
+        }
+
+        if self.vary {
+            response.adjoin_header(Header::new("Vary", "Accept-Encoding"));
+        }
+
+        if self.ranges {
+            response.set_raw_header("Accept-Ranges", "bytes");
+        }
+
+        if let Some(validators) = self.validators {
+            response.set_header(Header::new("ETag", validators.etag));
+            response.set_header(Header::new("Last-Modified", validators.last_modified));
+        }
+
+        Ok(response)
+    }
+}
+
 /// A bitset representing configurable options for [`FileServer`].
 ///
 /// The valid options are:
@@ -227,6 +596,12 @@ impl Handler for FileServer {
 ///   * [`Options::Index`] - Render `index.html` pages for directory requests.
 ///   * [`Options::NormalizeDirs`] - Redirect directories without a trailing
 ///     slash to ones with a trailing slash.
+///   * [`Options::Precompressed`] - Serve precompressed `.br` and `.gz`
+///     variants of files to clients that accept them.
+///   * [`Options::Ranges`] - Serve single byte ranges of files to clients that
+///     request them.
+///   * [`Options::CompressedRanges`] - Serve byte ranges of precompressed
+///     variants, implies `Options::Ranges`.
 ///
 /// `Options` structures can be `or`d together to select two or more options.
 /// For instance, to request that both dot files and index pages be returned,
@@ -293,6 +668,100 @@ impl Options {
     /// that would be served is a directory.
     pub const NormalizeDirs: Options = Options(0b0100);
 
+    /// Serve precompressed variants of files to clients that accept them.
+    ///
+    /// When enabled, [`FileServer`] looks for a sibling of a requested file
+    /// with a `.br` (brotli) or `.gz` (gzip) extension appended, such as
+    /// `app.js.br` for `app.js`. Encodings, including `identity`, the original
+    /// file, are negotiated according to the quality values in the request's
+    /// `Accept-Encoding` header: the most acceptable of the variants that exist
+    /// and the original file is served. A variant is served with the
+    /// corresponding `Content-Encoding` and the `Content-Type` of the original
+    /// file. In any case, the response includes a `Vary: Accept-Encoding`
+    /// header.
+    ///
+    /// An encoding without a quality value of its own takes that of `*`, if
+    /// present. Unless forbidden with `identity;q=0` or, absent an `identity`
+    /// entry, with `*;q=0`, the original file is always acceptable, but it is
+    /// the least preferred encoding if not listed. If the original file is
+    /// forbidden and no acceptable variant exists, the request fails with `406
+    /// Not Acceptable`, handled by the `406` catcher.
+    ///
+    /// Variants are only considered for files that exist uncompressed. Between
+    /// equally acceptable encodings, brotli is preferred, followed by gzip,
+    /// followed by the original file.
+    ///
+    /// **Disabled by default.**
+    ///
+    /// # Example
+    ///
+    /// Given the following directory structure...
+    ///
+    /// ```text
+    /// static/
+    /// ├── app.js
+    /// ├── app.js.br
+    /// └── app.js.gz
+    /// ```
+    ///
+    /// ...with `FileServer::new("static", Options::Index | Options::Precompressed)`,
+    /// a request to `/app.js` with `Accept-Encoding: gzip, br` is served
+    /// `static/app.js.br` with `Content-Encoding: br` and `Content-Type:
+    /// application/javascript`, while a request without an `Accept-Encoding`
+    /// header is served `static/app.js`.
+    pub const Precompressed: Options = Options(0b1000);
+
+    /// Serve byte ranges of files to clients that request them.
+    ///
+    /// When enabled, [`FileServer`] responds to a request with a `Range`
+    /// header for a single byte range, such as `bytes=0-1023`, `bytes=1024-`,
+    /// or `bytes=-512`, with a `206 Partial Content` response containing only
+    /// the requested bytes and a `Content-Range` header. Requests for a range
+    /// that begins past the end of the file are answered with `416 Range Not
+    /// Satisfiable`. All responses include an `Accept-Ranges: bytes` header.
+    ///
+    /// A `Range` header that is malformed or that requests multiple ranges is
+    /// ignored, and the entire file is served.
+    ///
+    /// So that clients can resume interrupted downloads safely, responses also
+    /// include `ETag` and `Last-Modified` headers derived from the served
+    /// file's modification time and length. A `Range` accompanied by an
+    /// `If-Range` header is honored only if the `If-Range` is identical to
+    /// the `ETag` or, if the file was modified at least a second earlier, to
+    /// the `Last-Modified`; otherwise, the entire file is served, as required
+    /// by RFC 7233 § 3.2. Weak entity tags never match.
+    ///
+    /// If [`Options::Precompressed`] is also enabled, range requests are served
+    /// from the original file, never from a precompressed variant, as ranges
+    /// of compressed bytes are useless to clients that decode the response as
+    /// it arrives and are easily mistaken for ranges of the original. The
+    /// response still includes a `Vary: Accept-Encoding` header. To serve
+    /// ranges of variants instead, use [`Options::CompressedRanges`].
+    ///
+    /// **Disabled by default.**
+    ///
+    /// # Example
+    ///
+    /// With `FileServer::new("static", Options::Ranges)`, a request to
+    /// `/video.mp4` with `Range: bytes=1000-1999` is answered with the bytes
+    /// at offsets 1000 through 1999 of `static/video.mp4` and a `Content-Range:
+    /// bytes 1000-1999/{len}` header, where `{len}` is the length of the file.
+    pub const Ranges: Options = Options(0b1_0000);
+
+    /// Serve byte ranges of precompressed variants of files.
+    ///
+    /// Like [`Options::Ranges`], which this option implies, but if
+    /// [`Options::Precompressed`] is also enabled, a range request is served
+    /// from the precompressed variant that would be served without a `Range`
+    /// header. The range and `Content-Range` then refer to the compressed
+    /// bytes, as specified by HTTP for a response with a `Content-Encoding`.
+    /// Use this option only if clients are known to handle this correctly,
+    /// for instance, by resuming interrupted downloads of the compressed
+    /// representation before decoding it.
+    ///
+    /// **Disabled by default.**
+    pub const CompressedRanges: Options = Options(0b11_0000);
+
     /// Returns `true` if `self` is a superset of `other`. In other words,
     /// returns `true` if all of the options in `other` are also in `self`.
     ///
@@ -5,11 +5,11 @@ use crate::Request;
 use crate::http::{ContentType, Status};
 use crate::data::{self, FromData, Data, Capped, N, Limits};
 use crate::form::{FromFormField, ValueField, DataField, error::Errors};
-use crate::outcome::IntoOutcome;
+use crate::outcome::Outcome;
 use crate::fs::FileName;
 
 use tokio::fs::{self, File};
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tempfile::{NamedTempFile, TempPath};
 use either::Either;
 
@@ -43,18 +43,33 @@ use either::Either;
 /// `TempFile` is configured via the following [`config`](crate::config)
 /// parameters:
 ///
-/// | Name               | Default             | Description                             |
-/// |--------------------|---------------------|-----------------------------------------|
-/// | `temp_dir`         | [`env::temp_dir()`] | Directory files are temporarily stored. |
-/// | `limits.file`      | 1MiB                | Default limit for all file extensions.  |
-/// | `limits.file/$ext` | _N/A_               | Limit for files with extension `$ext`.  |
+/// | Name                | Default             | Description                             |
+/// |---------------------|---------------------|-----------------------------------------|
+/// | `temp_dir`          | [`env::temp_dir()`] | Directory files are temporarily stored. |
+/// | `limits.file`       | 1MiB                | Default limit for all file extensions.  |
+/// | `limits.file/$ext`  | _N/A_               | Limit for files with extension `$ext`.  |
+/// | `limits.spill`      | 0B                  | Size under which files stay in memory.  |
+/// | `limits.spill/$ext` | _N/A_               | Spill threshold for extension `$ext`.   |
 ///
 /// [`env::temp_dir()`]: std::env::temp_dir()
 ///
 /// When used as a form guard, the extension `$ext` is identified by the form
 /// field's `Content-Type` ([`ContentType::extension()`]). When used as a data
 /// guard, the extension is identified by the Content-Type of the request, if
-/// any. If there is no Content-Type, the limit `file` is used.
+/// any. If there is no Content-Type, the limits `file` and `spill` are used.
+///
+/// # Spilling
+///
+/// Files smaller than the spill threshold are kept in memory and are only
+/// written to disk if and when they are persisted or copied. Files as large as
+/// or larger than the threshold are streamed into a file in `temp_dir`. Until
+/// it is persisted, a file kept in memory has no [`path()`](TempFile::path()).
+///
+/// The spill threshold only decides _where_ data is stored: the `file` limit
+/// applies regardless and bounds how much data is read at all. As such, a
+/// threshold larger than the `file` limit keeps every file in memory. The
+/// default threshold of `0` writes every file to disk. To keep files under 64
+/// KiB in memory, for instance, set `limits.spill` to `64KiB`.
 ///
 /// # Cappable
 ///
@@ -110,6 +125,12 @@ pub enum TempFile<'v> {
     #[doc(hidden)]
     Buffered {
         content: &'v str,
+    },
+    #[doc(hidden)]
+    Memory {
+        file_name: Option<&'v FileName>,
+        content_type: Option<ContentType>,
+        content: Vec<u8>,
     }
 }
 
@@ -188,16 +209,7 @@ impl<'v> TempFile<'v> {
                     }
                 }
             }
-            TempFile::Buffered { content } => {
-                let mut file = File::create(&new_path).await?;
-                file.write_all(content.as_bytes()).await?;
-                *self = TempFile::File {
-                    file_name: None,
-                    content_type: None,
-                    path: Either::Right(new_path),
-                    len: content.len() as u64
-                };
-            }
+            _ => self.write_out(&new_path).await?,
         }
 
         Ok(())
@@ -256,17 +268,7 @@ impl<'v> TempFile<'v> {
 
                 tokio::fs::copy(&either, path).await?;
             }
-            TempFile::Buffered { content } => {
-                let path = path.as_ref();
-                let mut file = File::create(path).await?;
-                file.write_all(content.as_bytes()).await?;
-                *self = TempFile::File {
-                    file_name: None,
-                    content_type: None,
-                    path: Either::Right(path.to_path_buf()),
-                    len: content.len() as u64
-                };
-            }
+            _ => self.write_out(path.as_ref()).await?,
         }
 
         Ok(())
@@ -304,8 +306,11 @@ impl<'v> TempFile<'v> {
         where P: AsRef<Path>
     {
         let dest = path.as_ref();
-        self.copy_to(dest).await?;
+        if !matches!(self, TempFile::File { .. }) {
+            return self.write_out(dest).await;
+        }
 
+        self.copy_to(dest).await?;
         if let TempFile::File { path, .. } = self {
             fs::remove_file(&path).await?;
             *path = Either::Right(dest.to_path_buf());
@@ -314,6 +319,29 @@ impl<'v> TempFile<'v> {
         Ok(())
     }
 
+    /// Writes the contents of an in-memory `self` to a new file at `path` and
+    /// updates `self` to refer to it. Does nothing if `self` is a file.
+    async fn write_out(&mut self, path: &Path) -> io::Result<()> {
+        let (content, file_name, content_type) = match self {
+            TempFile::File { .. } => return Ok(()),
+            TempFile::Buffered { content } => (content.as_bytes(), None, None),
+            TempFile::Memory { content, file_name, content_type } => {
+                (&content[..], *file_name, content_type.take())
+            }
+        };
+
+        let mut file = File::create(path).await?;
+        file.write_all(content).await?;
+        *self = TempFile::File {
+            file_name,
+            content_type,
+            path: Either::Right(path.to_path_buf()),
+            len: content.len() as u64,
+        };
+
+        Ok(())
+    }
+
     /// Returns the size, in bytes, of the file.
     ///
     /// This method does not perform any system calls.
@@ -331,6 +359,7 @@ impl<'v> TempFile<'v> {
         match self {
             TempFile::File { len, .. } => *len,
             TempFile::Buffered { content } => content.len() as u64,
+            TempFile::Memory { content, .. } => content.len() as u64,
         }
     }
 
@@ -361,7 +390,36 @@ impl<'v> TempFile<'v> {
         match self {
             TempFile::File { path: Either::Left(p), .. } => Some(p.as_ref()),
             TempFile::File { path: Either::Right(p), .. } => Some(p.as_path()),
-            TempFile::Buffered { .. } => None,
+            TempFile::Buffered { .. } | TempFile::Memory { .. } => None,
+        }
+    }
+
+    /// Returns the contents of the file if they are kept in memory.
+    ///
+    /// Files smaller than the spill threshold, set by the `spill` limits, are
+    /// never written to disk; for these, this method returns `Some`. Once a
+    /// file is persisted with [`TempFile::persist_to()`], or if it was written
+    /// to disk when received, this method returns `None`.
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::fs::TempFile;
+    ///
+    /// #[post("/", data = "<file>")]
+    /// fn handle(file: TempFile<'_>) -> String {
+    ///     match file.bytes() {
+    ///         Some(bytes) => format!("{} bytes in memory", bytes.len()),
+    ///         None => format!("{} bytes on disk", file.len()),
+    ///     }
+    /// }
+    /// # let file = TempFile::Buffered { content: "hi".into() };
+    /// # assert_eq!(handle(file), "2 bytes in memory");
+    /// ```
+    pub fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            TempFile::Buffered { content } => Some(content.as_bytes()),
+            TempFile::Memory { content, .. } => Some(content),
+            TempFile::File { .. } => None,
         }
     }
 
@@ -413,6 +471,7 @@ impl<'v> TempFile<'v> {
     pub fn raw_name(&self) -> Option<&FileName> {
         match *self {
             TempFile::File { file_name, .. } => file_name,
+            TempFile::Memory { file_name, .. } => file_name,
             TempFile::Buffered { .. } => None
         }
     }
@@ -435,6 +494,7 @@ impl<'v> TempFile<'v> {
     pub fn content_type(&self) -> Option<&ContentType> {
         match self {
             TempFile::File { content_type, .. } => content_type.as_ref(),
+            TempFile::Memory { content_type, .. } => content_type.as_ref(),
             TempFile::Buffered { .. } => None
         }
     }
@@ -445,12 +505,26 @@ impl<'v> TempFile<'v> {
         file_name: Option<&'a FileName>,
         content_type: Option<ContentType>,
     ) -> io::Result<Capped<TempFile<'a>>> {
-        let limit = content_type.as_ref()
-            .and_then(|ct| ct.extension())
+        let ext = content_type.as_ref().and_then(|ct| ct.extension());
+        let limit = ext
             .and_then(|ext| req.limits().find(&["file", ext.as_str()]))
             .or_else(|| req.limits().get("file"))
             .unwrap_or(Limits::FILE);
 
+        let threshold = ext
+            .and_then(|ext| req.limits().find(&["spill", ext.as_str()]))
+            .or_else(|| req.limits().get("spill"))
+            .unwrap_or(Limits::SPILL);
+
+        // Read up to the threshold. If the stream ends sooner, keep it all.
+        let mut stream = data.open(limit);
+        let mut content = vec![];
+        (&mut stream).take(threshold.as_u64()).read_to_end(&mut content).await?;
+        if (content.len() as u64) < threshold.as_u64() {
+            let n = N { written: content.len() as u64, complete: !stream.limit_exceeded().await? };
+            return Ok(Capped::new(TempFile::Memory { file_name, content_type, content }, n));
+        }
+
         let temp_dir = req.rocket().config().temp_dir.clone();
         let file = tokio::task::spawn_blocking(move || {
             NamedTempFile::new_in(temp_dir)
@@ -459,8 +533,10 @@ impl<'v> TempFile<'v> {
         })??;
 
         let (file, temp_path) = file.into_parts();
-        let mut file = File::from_std(file);
-        let n = data.open(limit).stream_to(tokio::io::BufWriter::new(&mut file)).await?;
+        let mut file = tokio::io::BufWriter::new(File::from_std(file));
+        file.write_all(&content).await?;
+        let mut n = stream.stream_to(file).await?;
+        n.written += content.len() as u64;
         let temp_file = TempFile::File {
             content_type, file_name,
             path: Either::Left(temp_path),
@@ -500,8 +576,10 @@ impl<'r> FromData<'r> for Capped<TempFile<'_>> {
             info_!("Did you mean to use `{}` instead?", form);
         }
 
-        TempFile::from(req, data, None, req.content_type().cloned()).await
-            .into_outcome(Status::BadRequest)
+        match TempFile::from(req, data, None, req.content_type().cloned()).await {
+            Ok(file) => Outcome::Success(file),
+            Err(e) => Outcome::Failure((data::io_error_status(&e), e)),
+        }
     }
 }
 
@@ -0,0 +1,312 @@
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sha2::{Digest, Sha256};
+
+use crate::{Rocket, Request, Response, Data, Build};
+use crate::data::{ByteUnit, ToByteUnit};
+use crate::fairing::{self, Fairing, Info, Kind};
+use crate::http::{Header, Method, Status};
+use crate::response::{CapturedResponse, CaptureError};
+use crate::idempotency::{Store, MemoryStore, Reservation, Token, Fingerprint};
+
+/// The name of the request header carrying the idempotency key.
+const KEY_HEADER: &str = "Idempotency-Key";
+
+/// The name of the response header marking a replayed response.
+const REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// The maximum length, in bytes, of an idempotency key.
+const MAX_KEY_LEN: usize = 255;
+
+/// A [`Fairing`] that records responses to requests with an `Idempotency-Key`
+/// in a [`Store`] and replays them to retries.
+///
+/// See the [module documentation](crate::idempotency) for details.
+pub struct Idempotency {
+    store: Arc<dyn Store>,
+    managed: Option<MemoryStore>,
+    ttl: Duration,
+    in_flight_timeout: Duration,
+    fingerprint: bool,
+    body_limit: ByteUnit,
+    response_limit: ByteUnit,
+    scope_keys: bool,
+}
+
+/// The key a request reserved and the reservation's token, if any.
+struct Reserved(Option<(String, Token)>);
+
+impl Idempotency {
+    /// Returns an `Idempotency` fairing that keeps keys in `store`.
+    ///
+    /// Unlike [`Idempotency::default()`], nothing is placed in managed state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::{Idempotency, MemoryStore};
+    ///
+    /// let rocket = rocket::build().attach(Idempotency::new(MemoryStore::new()));
+    /// ```
+    pub fn new<S: Store>(store: S) -> Self {
+        Idempotency {
+            store: Arc::new(store),
+            managed: None,
+            ttl: Duration::from_secs(24 * 60 * 60),
+            in_flight_timeout: Duration::from_secs(60),
+            fingerprint: false,
+            body_limit: 1.mebibytes(),
+            response_limit: 1.mebibytes(),
+            scope_keys: true,
+        }
+    }
+
+    /// Sets how long a recorded response is replayed for. Defaults to 24
+    /// hours.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default().ttl(Duration::from_secs(60 * 60));
+    /// ```
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how long a key stays reserved while its first request is being
+    /// handled. Once the timeout elapses, the key is freed even if the request
+    /// is still being handled. Defaults to 60 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default().in_flight_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn in_flight_timeout(mut self, timeout: Duration) -> Self {
+        self.in_flight_timeout = timeout;
+        self
+    }
+
+    /// Sets whether to fingerprint requests by their method, URI, and body,
+    /// failing requests that reuse a key with a different fingerprint with a
+    /// **422 Unprocessable Entity**. Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default().fingerprint(true);
+    /// ```
+    pub fn fingerprint(mut self, enabled: bool) -> Self {
+        self.fingerprint = enabled;
+        self
+    }
+
+    /// Sets the size of the largest body that is fingerprinted. Requests with
+    /// a key and a larger body fail with **413 Payload Too Large** when
+    /// fingerprinting is enabled. Defaults to 1MiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default()
+    ///     .fingerprint(true)
+    ///     .body_limit(64.kibibytes());
+    /// ```
+    pub fn body_limit(mut self, limit: ByteUnit) -> Self {
+        self.body_limit = limit;
+        self
+    }
+
+    /// Sets the size of the largest response body that is recorded. Responses
+    /// with a larger body are sent as usual, but not recorded, and their key is
+    /// freed. Defaults to 1MiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default().response_limit(64.kibibytes());
+    /// ```
+    pub fn response_limit(mut self, limit: ByteUnit) -> Self {
+        self.response_limit = limit;
+        self
+    }
+
+    /// Sets whether keys are scoped to the method and path of a request, so
+    /// that the same key sent to different routes refers to different
+    /// requests. Scoped keys are passed to the [`Store`] as `{method} {path}
+    /// {key}`, for example, `POST /payments 8e03978e`. Defaults to `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let idempotency = Idempotency::default().scope_keys(false);
+    /// ```
+    pub fn scope_keys(mut self, enabled: bool) -> Self {
+        self.scope_keys = enabled;
+        self
+    }
+
+    /// Fingerprints `req` and its body, if the body fits in the body limit.
+    async fn fingerprint_of(
+        &self,
+        req: &Request<'_>,
+        data: &mut Data<'_>
+    ) -> Option<Fingerprint> {
+        let limit = usize::try_from(self.body_limit.as_u64()).unwrap_or(usize::MAX);
+        let body = data.buffer_up_to(limit.saturating_add(1)).await;
+        if body.len() > limit {
+            return None;
+        }
+
+        // Length-prefix every part so that distinct requests can't collide by
+        // shifting bytes from one part to another.
+        let uri = req.uri().to_string();
+        let mut hasher = Sha256::new();
+        for part in &[req.method().as_str().as_bytes(), uri.as_bytes(), body] {
+            hasher.update((part.len() as u64).to_be_bytes());
+            hasher.update(part);
+        }
+
+        let mut digest = [0; 32];
+        digest.copy_from_slice(&hasher.finalize());
+        Some(Fingerprint::from_bytes(digest))
+    }
+
+    /// Records the response `res` to the request that reserved `key` with
+    /// `token`, or frees `key` if the response is a server error or its body
+    /// is unsized, exceeds the response limit, or can't be read.
+    async fn record(&self, key: &str, token: Token, res: &mut Response<'_>) {
+        if res.status().class().is_server_error() {
+            return self.store.release(key, token).await;
+        }
+
+        match CapturedResponse::capture(res, self.response_limit).await {
+            Ok(captured) => self.store.complete(key, token, captured, self.ttl).await,
+            Err(e) => {
+                match e {
+                    CaptureError::Io(_) => error_!("Failed to record response: {}.", e),
+                    _ => warn_!("Not recording response for `{}`: {}.", KEY_HEADER, e),
+                }
+
+                self.store.release(key, token).await
+            }
+        }
+    }
+}
+
+impl Default for Idempotency {
+    /// Returns an `Idempotency` fairing that keeps keys in a new
+    /// [`MemoryStore`], which is placed in managed state at ignition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Idempotency;
+    ///
+    /// let rocket = rocket::build().attach(Idempotency::default());
+    /// ```
+    fn default() -> Self {
+        let store = MemoryStore::new();
+        Idempotency { managed: Some(store.clone()), ..Idempotency::new(store) }
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Idempotency {
+    fn info(&self) -> Info {
+        Info {
+            name: "Idempotency",
+            kind: Kind::Ignite | Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        match self.managed {
+            Some(ref store) if rocket.state::<MemoryStore>().is_none() => {
+                Ok(rocket.manage(store.clone()))
+            }
+            Some(_) => {
+                error_!("`Idempotency` failed to manage its store.");
+                info_!("A `MemoryStore` is already being managed.");
+                Err(rocket)
+            }
+            None => Ok(rocket),
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, data: &mut Data<'_>) {
+        use Method::*;
+
+        if matches!(req.method(), Get | Head | Options | Trace) {
+            return;
+        }
+
+        let key = match req.headers().get_one(KEY_HEADER) {
+            Some(key) if !key.is_empty() && key.len() <= MAX_KEY_LEN => match self.scope_keys {
+                true => format!("{} {} {}", req.method(), req.uri().path(), key),
+                false => key.to_string(),
+            },
+            Some(_) => {
+                warn_!("Request has an invalid `{}`.", KEY_HEADER);
+                return req.halt(Status::BadRequest);
+            }
+            None => return,
+        };
+
+        let fingerprint = match self.fingerprint {
+            true => match self.fingerprint_of(req, data).await {
+                Some(fingerprint) => Some(fingerprint),
+                None => {
+                    warn_!("Request body is too large to fingerprint for `{}`.", KEY_HEADER);
+                    return req.halt(Status::PayloadTooLarge);
+                }
+            },
+            false => None,
+        };
+
+        match self.store.reserve(&key, fingerprint, self.in_flight_timeout).await {
+            Reservation::Reserved(token) => {
+                req.local_cache(|| Reserved(Some((key, token))));
+            }
+            Reservation::InFlight => {
+                warn_!("A request with the same `{}` is in flight.", KEY_HEADER);
+                req.halt(Status::Conflict);
+            }
+            Reservation::Mismatch => {
+                warn_!("`{}` was used with a different request.", KEY_HEADER);
+                req.halt(Status::UnprocessableEntity);
+            }
+            Reservation::Completed(response) => {
+                info_!("Replaying recorded response for `{}`.", KEY_HEADER);
+                let mut replay = response.to_response();
+                replay.set_header(Header::new(REPLAYED_HEADER, "true"));
+                req.halt_with(replay);
+            }
+        }
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        if let Reserved(Some((key, token))) = req.local_cache(|| Reserved(None)) {
+            self.record(key, *token, res).await;
+        }
+    }
+}
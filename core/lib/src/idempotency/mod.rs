@@ -0,0 +1,102 @@
+//! Replay of responses to retried requests via `Idempotency-Key`.
+//!
+//! Clients retrying a non-idempotent request, such as a `POST` that initiates
+//! a payment, can't know whether an earlier attempt that timed out was
+//! processed. By sending the same `Idempotency-Key` header with every attempt,
+//! they can ask the server to process the request at most once. The
+//! [`Idempotency`] fairing implements the server side of this contract:
+//!
+//!   * The first request with a given key is handled as usual. Its response,
+//!     status, headers, and body, is recorded in a [`Store`] for a configurable
+//!     time-to-live (TTL), by default 24 hours.
+//!   * A later request with the same key receives the recorded response
+//!     without its handler being invoked. Replayed responses carry an
+//!     `Idempotent-Replayed: true` header.
+//!   * A request with a key whose first request is still being handled fails
+//!     with **409 Conflict**; the client should retry later. If the first
+//!     request doesn't complete within the in-flight timeout, by default 60
+//!     seconds, for instance, because the client went away, the key is freed.
+//!   * Responses with a `5XX` status are not recorded, and their key is freed,
+//!     so that a retry is processed anew.
+//!
+//! Only requests with an unsafe method, that is, any method but `GET`, `HEAD`,
+//! `OPTIONS`, and `TRACE`, are considered. Keys must be between 1 and 255
+//! bytes long; requests with other keys fail with **400 Bad Request**.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `idempotency` feature is enabled.
+//! Enable it in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rocket]
+//! version = "0.5.0-rc.1"
+//! features = ["idempotency"]
+//! ```
+//!
+//! # Scopes and Fingerprints
+//!
+//! Keys are chosen by clients. By default, a key is [scoped](Idempotency::scope_keys())
+//! to the method and path of its request, so the same key sent to two routes
+//! refers to two requests. Within a scope, however, keys are global: two
+//! clients that send the same key share a response. To guard against misuse,
+//! the fairing can additionally [fingerprint](Idempotency::fingerprint()) each
+//! request by the SHA-256 digest of its method, URI, and body. A request that reuses a key
+//! with a different fingerprint fails with **422 Unprocessable Entity**.
+//! Fingerprinting buffers request bodies in memory up to a
+//! [limit](Idempotency::body_limit()); larger requests with a key fail with
+//! **413 Payload Too Large**.
+//!
+//! # Stores
+//!
+//! A [`Store`] tracks keys, their fingerprints, and recorded responses. The
+//! default store, [`MemoryStore`], keeps entries in memory and is thus only
+//! suitable for applications running as a single instance. Stores shared
+//! across instances, for example, one backed by Redis, can be plugged in via
+//! [`Idempotency::new()`].
+//!
+//! # Usage
+//!
+//! Attach [`Idempotency::default()`] to record into a [`MemoryStore`], which is
+//! also placed in managed state:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use std::time::Duration;
+//!
+//! use rocket::idempotency::Idempotency;
+//!
+//! #[post("/payments", data = "<amount>")]
+//! fn pay(amount: &str) -> String {
+//!     /* charge the card... */
+//!     format!("charged {}", amount)
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     let idempotency = Idempotency::default()
+//!         .ttl(Duration::from_secs(60 * 60))
+//!         .fingerprint(true);
+//!
+//!     rocket::build()
+//!         .mount("/", routes![pay])
+//!         .attach(idempotency)
+//! }
+//! ```
+//!
+//! # Caveats
+//!
+//! A replayed response is produced by halting the request via
+//! [`Request::halt_with()`](crate::Request::halt_with()), so request fairings
+//! attached after `Idempotency` don't run for replayed requests. Response
+//! fairings run on the replayed response as usual. Response bodies are
+//! buffered in memory in order to be recorded.
+//! Responses with unsized bodies, such as streams, or bodies larger than the
+//! [response limit](Idempotency::response_limit()) are thus never recorded:
+//! like server errors, their key is freed.
+
+mod fairing;
+mod store;
+
+pub use self::fairing::Idempotency;
+pub use self::store::{Store, MemoryStore, Reservation, Token, Fingerprint};
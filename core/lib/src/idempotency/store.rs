@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::response::CapturedResponse;
+
+/// The SHA-256 digest of a request's method, URI, and body.
+///
+/// Fingerprints are stable across Rust releases and platforms, so stores may
+/// persist them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint([u8; 32]);
+
+impl Fingerprint {
+    /// Returns the fingerprint with digest `bytes`. Stores that persist
+    /// fingerprints elsewhere use this to reconstruct them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Fingerprint;
+    ///
+    /// let fingerprint = Fingerprint::from_bytes([7; 32]);
+    /// assert_eq!(fingerprint.as_bytes(), &[7; 32]);
+    /// ```
+    pub fn from_bytes(bytes: [u8; 32]) -> Self {
+        Fingerprint(bytes)
+    }
+
+    /// The SHA-256 digest.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+/// Identifies one reservation of an idempotency key.
+///
+/// A [`Store`] issues a new token with every successful
+/// [`Store::reserve()`]. [`Store::complete()`] and [`Store::release()`] only
+/// act on a key while it is reserved with the same token, so a request that
+/// outlives its reservation can't affect a newer request's reservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token(u64);
+
+impl Token {
+    /// Returns a new, random token.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Token;
+    ///
+    /// assert_ne!(Token::random(), Token::random());
+    /// ```
+    pub fn random() -> Self {
+        Token(rand::random())
+    }
+
+    /// Returns the token with id `id`. Stores that persist tokens elsewhere
+    /// use this to reconstruct them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::Token;
+    ///
+    /// assert_eq!(Token::from_u64(7).as_u64(), 7);
+    /// ```
+    pub fn from_u64(id: u64) -> Self {
+        Token(id)
+    }
+
+    /// The id of the token.
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The result of [reserving](Store::reserve()) an idempotency key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reservation {
+    /// The key was unknown and is now reserved for the request with the
+    /// given token.
+    Reserved(Token),
+    /// The key is reserved by a request that is still being handled.
+    InFlight,
+    /// The key was used by a request with a different fingerprint.
+    Mismatch,
+    /// A response was recorded for the key.
+    Completed(CapturedResponse),
+}
+
+/// Storage for idempotency keys and the responses recorded for them.
+///
+/// A key moves through three states: unknown, reserved while its first
+/// request is handled, and completed once a response is recorded. A reserved
+/// key that is neither completed nor released within the timeout passed to
+/// [`Store::reserve()`] must become unknown again. A completed key must become
+/// unknown once the TTL passed to [`Store::complete()`] elapses.
+///
+/// Implementations must make [`Store::reserve()`] atomic: of any number of
+/// concurrent reservations of an unknown key, exactly one may succeed. Every
+/// successful reservation must be issued a new [`Token`], and
+/// [`Store::complete()`] and [`Store::release()`] must atomically check that
+/// the key is still reserved with the token they are passed, doing nothing
+/// otherwise.
+#[crate::async_trait]
+pub trait Store: Send + Sync + 'static {
+    /// Reserves `key` for a request with fingerprint `fingerprint` if the key
+    /// is unknown, holding the reservation for at most `timeout`, and returns
+    /// a new token for the reservation. Otherwise, reports the state of the
+    /// key. A known key whose fingerprint differs from `fingerprint` is
+    /// reported as a [`Reservation::Mismatch`].
+    async fn reserve(
+        &self,
+        key: &str,
+        fingerprint: Option<Fingerprint>,
+        timeout: Duration
+    ) -> Reservation;
+
+    /// Records `response` for `key`, retaining it for `ttl`, if `key` is
+    /// still reserved with `token`. Otherwise, does nothing.
+    async fn complete(
+        &self,
+        key: &str,
+        token: Token,
+        response: CapturedResponse,
+        ttl: Duration
+    );
+
+    /// Releases the reservation of `key`, making it unknown, if `key` is still
+    /// reserved with `token`. Otherwise, does nothing.
+    async fn release(&self, key: &str, token: Token);
+}
+
+/// An in-memory [`Store`].
+///
+/// Clones of a `MemoryStore` share the same entries. Expired entries are
+/// removed lazily, at most once a second, when a key is reserved.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStore(Arc<Mutex<Entries>>);
+
+#[derive(Debug, Default)]
+struct Entries {
+    map: HashMap<String, Entry>,
+    last_sweep: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    fingerprint: Option<Fingerprint>,
+    expires: Instant,
+    state: State,
+}
+
+#[derive(Debug)]
+enum State {
+    Reserved(Token),
+    Completed(CapturedResponse),
+}
+
+impl MemoryStore {
+    /// Returns a new, empty `MemoryStore`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::{Idempotency, MemoryStore};
+    ///
+    /// let store = MemoryStore::new();
+    /// let rocket = rocket::build().attach(Idempotency::new(store.clone()));
+    /// ```
+    pub fn new() -> Self {
+        MemoryStore::default()
+    }
+
+    /// Removes `key`, along with any response recorded for it. Returns `true`
+    /// if the key was known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::idempotency::MemoryStore;
+    ///
+    /// let store = MemoryStore::new();
+    /// assert!(!store.remove("a3f1c"));
+    /// ```
+    pub fn remove(&self, key: &str) -> bool {
+        self.0.lock().map.remove(key).is_some()
+    }
+}
+
+impl Entries {
+    /// Returns the live entry for `key` if it is reserved with `token`.
+    fn reserved(&mut self, key: &str, token: Token) -> Option<&mut Entry> {
+        let now = Instant::now();
+        self.map.get_mut(key)
+            .filter(|entry| entry.expires > now)
+            .filter(|entry| matches!(entry.state, State::Reserved(t) if t == token))
+    }
+
+    fn sweep(&mut self, now: Instant) {
+        let due = self.last_sweep
+            .map(|last| now.duration_since(last) >= Duration::from_secs(1))
+            .unwrap_or(true);
+
+        if due {
+            self.map.retain(|_, entry| entry.expires > now);
+            self.last_sweep = Some(now);
+        }
+    }
+}
+
+#[crate::async_trait]
+impl Store for MemoryStore {
+    async fn reserve(
+        &self,
+        key: &str,
+        fingerprint: Option<Fingerprint>,
+        timeout: Duration
+    ) -> Reservation {
+        let now = Instant::now();
+        let mut entries = self.0.lock();
+        entries.sweep(now);
+        match entries.map.get(key) {
+            Some(entry) if entry.expires > now => match entry.state {
+                _ if entry.fingerprint != fingerprint => Reservation::Mismatch,
+                State::Completed(ref response) => Reservation::Completed(response.clone()),
+                State::Reserved(_) => Reservation::InFlight,
+            },
+            _ => {
+                let token = Token::random();
+                let state = State::Reserved(token);
+                let entry = Entry { fingerprint, expires: now + timeout, state };
+                entries.map.insert(key.to_string(), entry);
+                Reservation::Reserved(token)
+            }
+        }
+    }
+
+    async fn complete(
+        &self,
+        key: &str,
+        token: Token,
+        response: CapturedResponse,
+        ttl: Duration
+    ) {
+        if let Some(entry) = self.0.lock().reserved(key, token) {
+            entry.expires = Instant::now() + ttl;
+            entry.state = State::Completed(response);
+        }
+    }
+
+    async fn release(&self, key: &str, token: Token) {
+        let mut entries = self.0.lock();
+        if entries.reserved(key, token).is_some() {
+            entries.map.remove(key);
+        }
+    }
+}
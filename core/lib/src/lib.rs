@@ -59,13 +59,18 @@
 //! To avoid compiling unused dependencies, Rocket gates certain features, all
 //! of which are disabled by default:
 //!
-//! | Feature   | Description                                             |
-//! |-----------|---------------------------------------------------------|
-//! | `secrets` | Support for authenticated, encrypted [private cookies]. |
-//! | `tls`     | Support for [TLS] encrypted connections.                |
-//! | `json`    | Support for [JSON (de)serialization].                   |
-//! | `msgpack` | Support for [MessagePack (de)serialization].            |
-//! | `uuid`    | Support for [UUID value parsing and (de)serialization]. |
+//! | Feature       | Description                                               |
+//! |---------------|-----------------------------------------------------------|
+//! | `secrets`     | Support for authenticated, encrypted [private cookies].   |
+//! | `tls`         | Support for [TLS] encrypted connections.                  |
+//! | `json`        | Support for [JSON (de)serialization].                     |
+//! | `msgpack`     | Support for [MessagePack (de)serialization].              |
+//! | `cbor`        | Support for [CBOR (de)serialization].                     |
+//! | `xml`         | Support for [XML (de)serialization].                      |
+//! | `uuid`        | Support for [UUID value parsing and (de)serialization].   |
+//! | `chrono`      | Support for [`chrono` date and time form fields].         |
+//! | `digest`      | Support for [verifying request body digests].             |
+//! | `idempotency` | Support for [replaying responses by `Idempotency-Key`].   |
 //!
 //! Features can be selectively enabled in `Cargo.toml`:
 //!
@@ -76,7 +81,12 @@
 //!
 //! [JSON (de)serialization]: crate::serde::json
 //! [MessagePack (de)serialization]: crate::serde::msgpack
+//! [CBOR (de)serialization]: crate::serde::cbor
+//! [XML (de)serialization]: crate::serde::xml
 //! [UUID value parsing and (de)serialization]: crate::serde::uuid
+//! [`chrono` date and time form fields]: crate::form::FromForm#provided-implementations
+//! [verifying request body digests]: crate::data::Verified
+//! [replaying responses by `Idempotency-Key`]: crate::idempotency
 //! [private cookies]: https://rocket.rs/v0.5-rc/guide/requests/#private-cookies
 //! [TLS]: https://rocket.rs/v0.5-rc/guide/configuration/#tls
 //!
@@ -121,8 +131,19 @@ pub mod catcher;
 pub mod route;
 pub mod serde;
 pub mod shield;
+pub mod metrics;
+pub mod collapse;
+pub mod tee;
 pub mod fs;
 
+#[cfg(feature = "tls")]
+#[cfg_attr(nightly, doc(cfg(feature = "tls")))]
+pub mod tls;
+
+#[cfg(feature = "idempotency")]
+#[cfg_attr(nightly, doc(cfg(feature = "idempotency")))]
+pub mod idempotency;
+
 // Reexport of HTTP everything.
 pub mod http {
     //! Types that map to concepts in HTTP.
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::convert::TryInto;
+use std::sync::Arc;
 
 use parking_lot::RwLock;
 
@@ -48,9 +49,10 @@ use crate::http::{Method, uri::Origin, private::cookie};
 /// # });
 /// ```
 pub struct Client {
-    rocket: Rocket<Orbit>,
+    rocket: Arc<Rocket<Orbit>>,
     cookies: RwLock<cookie::CookieJar>,
     pub(in super) tracked: bool,
+    pub(in super) max_redirects: usize,
 }
 
 impl Client {
@@ -58,9 +60,15 @@ impl Client {
         rocket: Rocket<P>,
         tracked: bool
     ) -> Result<Client, Error> {
-        let rocket = rocket.local_launch().await?;
+        let rocket = Arc::new(rocket.local_launch().await?);
         let cookies = RwLock::new(cookie::CookieJar::new());
-        Ok(Client { rocket, tracked, cookies })
+        Ok(Client { rocket, tracked, cookies, max_redirects: 0 })
+    }
+
+    #[inline(always)]
+    pub(crate) fn _follow_redirects(mut self, max: usize) -> Self {
+        self.max_redirects = max;
+        self
     }
 
     // WARNING: This is unstable! Do not use this method outside of Rocket!
@@ -102,6 +110,11 @@ impl Client {
         LocalRequest::new(self, method, uri)
     }
 
+    #[cfg(feature = "raw_requests")]
+    pub(crate) async fn _send_raw(&self, bytes: &[u8]) -> crate::local::RawResponse {
+        crate::local::RawResponse::new(self.rocket.clone().serve_raw(bytes).await)
+    }
+
     // Generates the public API methods, which call the private methods above.
     pub_client_impl!("use rocket::local::asynchronous::Client;" @async await);
 }
@@ -2,8 +2,9 @@ use std::fmt;
 use std::convert::TryInto;
 
 use crate::{Request, Data};
-use crate::http::{Status, Method};
+use crate::http::{Status, Method, Header};
 use crate::http::uri::Origin;
+use crate::http::ext::IntoOwned;
 
 use super::{Client, LocalResponse};
 
@@ -45,11 +46,12 @@ impl<'c> LocalRequest<'c> {
     {
         // Try to parse `uri` into an `Origin`, storing whether it's good.
         let uri_str = uri.to_string();
-        let try_origin = uri.try_into().map_err(|_| Origin::path_only(uri_str));
+        let try_origin = uri.try_into().map_err(|_| Origin::path_only(uri_str.clone()));
 
         // Create a request. We'll handle bad URIs later, in `_dispatch`.
         let origin = try_origin.clone().unwrap_or_else(|bad| bad);
         let mut request = Request::new(client.rocket(), method, origin);
+        request.set_raw_uri(uri_str);
 
         // Add any cookies we know about.
         if client.tracked {
@@ -75,8 +77,67 @@ impl<'c> LocalRequest<'c> {
         &mut self.data
     }
 
-    // Performs the actual dispatch.
-    async fn _dispatch(mut self) -> LocalResponse<'c> {
+    // Performs the actual dispatch, following redirects if the client has
+    // been configured to do so.
+    async fn _dispatch(self) -> LocalResponse<'c> {
+        let mut request = self;
+        let mut remaining = request.client.max_redirects;
+        loop {
+            let original = match remaining {
+                0 => None,
+                _ => Some(request.clone()),
+            };
+
+            let response = request._dispatch_one().await;
+            match original.and_then(|original| original.redirected(&response)) {
+                Some(next) => {
+                    request = next;
+                    remaining -= 1;
+                }
+                None => return response,
+            }
+        }
+    }
+
+    // Returns the request to dispatch to follow `response`, the response to
+    // `self`, if it is a redirect to an origin URI.
+    fn redirected(self, response: &LocalResponse<'_>) -> Option<LocalRequest<'c>> {
+        let status = response.status();
+        if !matches!(status.code, 301 | 302 | 303 | 307 | 308) {
+            return None;
+        }
+
+        let location = response.headers().get_one("Location")?;
+        let uri = Origin::parse(location).ok()?.into_owned();
+        let preserve = matches!(status.code, 307 | 308);
+        let method = match self.inner().method() {
+            _ if preserve => self.inner().method(),
+            Method::Head => Method::Head,
+            _ => Method::Get,
+        };
+
+        let mut next = LocalRequest::new(self.client, method, uri);
+        for header in self.inner().headers().iter() {
+            let content = header.name == "Content-Type" || header.name == "Content-Length";
+            if preserve || !content {
+                let (name, value) = (header.name.to_string(), header.value.to_string());
+                next.request.add_header(Header::new(name, value));
+            }
+        }
+
+        if let Some(remote) = self.inner().remote() {
+            next.request.set_remote(remote);
+        }
+
+        if preserve {
+            next.data = self.data;
+        }
+
+        Some(next)
+    }
+
+    // Dispatches `self` exactly once.
+    async fn _dispatch_one(mut self) -> LocalResponse<'c> {
         // First, revalidate the URI, returning an error response (generated
         // from an error catcher) immediately if it's invalid. If it's valid,
         // then `request` already contains a correct URI.
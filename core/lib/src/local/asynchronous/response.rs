@@ -98,6 +98,12 @@ impl<'c> LocalResponse<'c> {
     }
 }
 
+impl Drop for LocalResponse<'_> {
+    fn drop(&mut self) {
+        self._request.state.disconnect.finish(false);
+    }
+}
+
 impl LocalResponse<'_> {
     pub(crate) fn _response(&self) -> &Response<'_> {
         &self.response
@@ -129,7 +135,21 @@ impl LocalResponse<'_> {
         self.blocking_read(|r| rmp_serde::from_read(r)).await?.ok()
     }
 
-    #[cfg(any(feature = "json", feature = "msgpack"))]
+    #[cfg(feature = "cbor")]
+    async fn _into_cbor<T>(self) -> Option<T>
+        where T: Send + 'static + serde::de::DeserializeOwned
+    {
+        self.blocking_read(|r| ciborium::de::from_reader(r)).await?.ok()
+    }
+
+    #[cfg(feature = "xml")]
+    async fn _into_xml<T>(self) -> Option<T>
+        where T: Send + 'static + serde::de::DeserializeOwned
+    {
+        self.blocking_read(|r| crate::serde::xml::from_reader(io::BufReader::new(r))).await?.ok()
+    }
+
+    #[cfg(any(feature = "json", feature = "msgpack", feature = "cbor", feature = "xml"))]
     async fn blocking_read<T, F>(mut self, f: F) -> Option<T>
         where T: Send + 'static,
               F: FnOnce(&mut dyn io::Read) -> T + Send + 'static
@@ -179,6 +199,8 @@ impl LocalResponse<'_> {
             }
         }
 
+        // Close the channel so the reader sees EOF once it drains the buffer.
+        drop(tx);
         reader.await.ok()
     }
 
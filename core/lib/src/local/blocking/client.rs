@@ -67,6 +67,12 @@ impl Client {
         self.runtime.borrow_mut().block_on(fut)
     }
 
+    #[inline(always)]
+    fn _follow_redirects(mut self, max: usize) -> Self {
+        self.inner = self.inner.take().map(|inner| inner._follow_redirects(max));
+        self
+    }
+
     #[inline(always)]
     fn _rocket(&self) -> &Rocket<Orbit> {
         self.inner()._rocket()
@@ -86,6 +92,12 @@ impl Client {
         LocalRequest::new(self, method, uri)
     }
 
+    #[cfg(feature = "raw_requests")]
+    #[inline(always)]
+    fn _send_raw(&self, bytes: &[u8]) -> crate::local::RawResponse {
+        self.block_on(self.inner()._send_raw(bytes))
+    }
+
     // Generates the public API methods, which call the private methods above.
     pub_client_impl!("use rocket::local::blocking::Client;");
 }
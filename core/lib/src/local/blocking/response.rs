@@ -85,6 +85,20 @@ impl LocalResponse<'_> {
         rmp_serde::from_read(self).ok()
     }
 
+    #[cfg(feature = "cbor")]
+    fn _into_cbor<T>(self) -> Option<T>
+        where T: Send + 'static + serde::de::DeserializeOwned
+    {
+        ciborium::de::from_reader(self).ok()
+    }
+
+    #[cfg(feature = "xml")]
+    fn _into_xml<T>(self) -> Option<T>
+        where T: Send + 'static + serde::de::DeserializeOwned
+    {
+        crate::serde::xml::from_reader(io::BufReader::new(self)).ok()
+    }
+
     // Generates the public API methods, which call the private methods above.
     pub_response_impl!("# use rocket::local::blocking::Client;\n\
         use rocket::local::blocking::LocalResponse;");
@@ -95,6 +95,40 @@ macro_rules! pub_client_impl {
         Self::_new(rocket, false) $(.$suffix)?
     }
 
+    /// Configures `self` to follow up to `max` redirects when dispatching
+    /// requests. A `max` of `0`, the default, disables following redirects.
+    ///
+    /// When a dispatched request results in a `301`, `302`, `303`, `307`, or
+    /// `308` response with a `Location` header containing an origin URI, the
+    /// `Client` dispatches a new request to that URI and returns its response
+    /// instead, repeating up to `max` times. The response to the last request
+    /// is returned even if it is itself a redirect.
+    ///
+    ///   * For `307` and `308` responses, the new request preserves the
+    ///     original request's method, headers, remote address, and body.
+    ///   * For `301`, `302`, and `303` responses, the new request is a `GET`
+    ///     request, or a `HEAD` request if the original was, without the
+    ///     original's body or its `Content-Type` and `Content-Length` headers.
+    ///
+    /// Cookies are carried across redirects via the client's cookie jar. As
+    /// such, a [tracked](Client::tracked()) client sends cookies set by a
+    /// redirecting response with the new request, while cookies set directly
+    /// on the original request are not propagated.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    #[doc = $import]
+    ///
+    /// fn follow_redirects(client: Client) -> Client {
+    ///     client.follow_redirects(5)
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn follow_redirects(self, max: usize) -> Self {
+        self._follow_redirects(max)
+    }
+
     #[doc(hidden)]
     pub $($prefix)? fn debug_with(routes: Vec<crate::Route>) -> Result<Self, Error> {
         let rocket = crate::custom(crate::Config::debug_default());
@@ -200,6 +234,36 @@ macro_rules! pub_client_impl {
         self._req(method, uri)
     }
 
+    /// Sends the raw bytes `bytes` to the server as if they had been received
+    /// over a network connection and returns the raw bytes of the response.
+    ///
+    /// Unlike requests created via [`Client::req()`], the bytes are parsed by
+    /// the same HTTP/1 parser used by a launched server, so they may contain
+    /// anything, including malformed request lines and headers, unusual
+    /// methods, or several pipelined requests. This makes `send_raw()` useful
+    /// for testing how the server treats requests a typed `LocalRequest`
+    /// cannot express. The connection is closed once all of `bytes` has been
+    /// read and every response has been written.
+    ///
+    /// Cookie tracking does not apply to raw requests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $import]
+    ///
+    /// # Client::_test(|client, _, _| {
+    /// let client: &Client = client;
+    /// let response = client.send_raw("GET / HTTP/1.1\r\nHost\r\n\r\n");
+    /// # });
+    /// ```
+    #[cfg(feature = "raw_requests")]
+    #[cfg_attr(nightly, doc(cfg(feature = "raw_requests")))]
+    #[inline(always)]
+    pub $($prefix)? fn send_raw<B: AsRef<[u8]>>(&self, bytes: B) -> crate::local::RawResponse {
+        self._send_raw(bytes.as_ref()) $(.$suffix)?
+    }
+
     #[cfg(test)]
     #[allow(dead_code)]
     fn _ensure_impls_exist() {
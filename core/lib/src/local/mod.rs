@@ -112,6 +112,24 @@
 //! `untracked()` constructor to create a `Client` that _will not_ track
 //! cookies.
 //!
+//! **Following Redirects**
+//!
+//! By default, a `Client` returns redirect responses as they are. A `Client`
+//! configured with [`follow_redirects()`] ([`async` `follow_redirects()`])
+//! instead dispatches a new request to the redirect's `Location`, up to a
+//! maximum number of times, and returns the response to the final request.
+//! With cookie tracking enabled, cookies set by redirecting responses are sent
+//! with the new request, as is typical of multi-step flows like logins.
+//!
+//! **Raw Requests**
+//!
+//! With the `raw_requests` feature enabled, requests that can't be expressed
+//! with a `LocalRequest`, such as those with malformed headers, can be sent as
+//! raw bytes via [`send_raw()`] ([`async` `send_raw()`]). The bytes are parsed
+//! by the server's HTTP/1 parser, and the raw bytes of the response are
+//! returned as a [`RawResponse`]. As a testing facility, this is not meant to
+//! be enabled in production builds.
+//!
 //! [`Client`]: blocking::Client
 //! [`async` `Client`]: asynchronous::Client
 //! [`LocalRequest`]: blocking::LocalRequest
@@ -121,6 +139,10 @@
 //! [`untracked()`]: blocking::Client::untracked()
 //! [`async` `tracked()`]: asynchronous::Client::tracked()
 //! [`async` `untracked()`]: asynchronous::Client::untracked()
+//! [`follow_redirects()`]: blocking::Client::follow_redirects()
+//! [`async` `follow_redirects()`]: asynchronous::Client::follow_redirects()
+//! [`send_raw()`]: blocking::Client::send_raw()
+//! [`async` `send_raw()`]: asynchronous::Client::send_raw()
 //!
 //! [`get()`]: blocking::Client::get()
 //! [`put()`]: blocking::Client::put()
@@ -182,6 +204,12 @@
 #[macro_use] mod client;
 #[macro_use] mod request;
 #[macro_use] mod response;
+#[cfg(feature = "raw_requests")] mod raw_response;
+#[cfg(feature = "raw_requests")] pub(crate) mod raw_io;
 
 pub mod asynchronous;
 pub mod blocking;
+
+#[cfg(feature = "raw_requests")]
+#[cfg_attr(nightly, doc(cfg(feature = "raw_requests")))]
+pub use self::raw_response::RawResponse;
@@ -0,0 +1,125 @@
+use std::io::{self, Cursor};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll};
+
+use futures::task::AtomicWaker;
+use parking_lot::Mutex;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::sync::oneshot;
+
+/// The in-memory connection a raw request is served over.
+///
+/// Reads yield the raw request bytes. Once they're exhausted, reads wait until
+/// no request is in flight and then report EOF, as a client that waits for its
+/// responses before closing the connection would. Writes are appended to the
+/// shared output.
+pub(crate) struct RawIo {
+    input: Cursor<Vec<u8>>,
+    output: Arc<Mutex<Vec<u8>>>,
+    in_flight: Arc<InFlight>,
+    _closed: oneshot::Sender<()>,
+}
+
+/// The server-independent half of a [`RawIo`]: tracks the requests in flight
+/// and collects the output.
+pub(crate) struct RawConnection {
+    in_flight: Arc<InFlight>,
+    output: Arc<Mutex<Vec<u8>>>,
+    closed: oneshot::Receiver<()>,
+}
+
+/// The number of requests on a [`RawIo`] whose response is incomplete.
+#[derive(Default)]
+pub(crate) struct InFlight {
+    count: AtomicUsize,
+    reader: AtomicWaker,
+}
+
+/// Marks a request as in flight until dropped.
+pub(crate) struct InFlightGuard(Arc<InFlight>);
+
+impl RawIo {
+    /// Returns a connection that reads `input` and its other half.
+    pub fn new(input: &[u8]) -> (Self, RawConnection) {
+        let (tx, rx) = oneshot::channel();
+        let in_flight = Arc::new(InFlight::default());
+        let output = Arc::new(Mutex::new(vec![]));
+        let io = RawIo {
+            input: Cursor::new(input.to_vec()),
+            output: output.clone(),
+            in_flight: in_flight.clone(),
+            _closed: tx,
+        };
+
+        (io, RawConnection { in_flight, output, closed: rx })
+    }
+}
+
+impl RawConnection {
+    /// The requests in flight on the connection.
+    pub fn in_flight(&self) -> Arc<InFlight> {
+        self.in_flight.clone()
+    }
+
+    /// Waits for the [`RawIo`] to be dropped, then returns everything that
+    /// was written to it.
+    pub async fn output(self) -> Vec<u8> {
+        let _ = self.closed.await;
+        std::mem::take(&mut *self.output.lock())
+    }
+}
+
+impl InFlight {
+    /// Marks a request as in flight until the returned guard is dropped.
+    pub fn start(self: &Arc<Self>) -> InFlightGuard {
+        self.count.fetch_add(1, Ordering::AcqRel);
+        InFlightGuard(self.clone())
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.0.reader.wake();
+        }
+    }
+}
+
+impl AsyncRead for RawIo {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        if self.input.position() < self.input.get_ref().len() as u64 {
+            return Pin::new(&mut self.input).poll_read(cx, buf);
+        }
+
+        self.in_flight.reader.register(cx.waker());
+        match self.in_flight.count.load(Ordering::Acquire) {
+            0 => Poll::Ready(Ok(())),
+            _ => Poll::Pending,
+        }
+    }
+}
+
+impl AsyncWrite for RawIo {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.output.lock().extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
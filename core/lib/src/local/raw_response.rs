@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::http::Status;
+
+/// The raw bytes written in response to a raw request.
+///
+/// A `RawResponse` is returned by `Client::send_raw()`, available on both the
+/// [`blocking`](crate::local::blocking::Client::send_raw()) and
+/// [`asynchronous`](crate::local::asynchronous::Client::send_raw()) clients.
+/// It contains everything the server wrote to the connection, including the
+/// status line, headers, and body of every response, as they were written.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::local::blocking::Client;
+/// use rocket::http::Status;
+///
+/// let client = Client::tracked(rocket::build()).unwrap();
+/// let response = client.send_raw("GET / HTTP/1.1\r\nHost: example.com\r\n\r\n");
+/// assert_eq!(response.status(), Some(Status::NotFound));
+/// assert!(response.as_bytes().starts_with(b"HTTP/1.1 404 Not Found\r\n"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct RawResponse {
+    bytes: Vec<u8>,
+}
+
+impl RawResponse {
+    pub(crate) fn new(bytes: Vec<u8>) -> Self {
+        RawResponse { bytes }
+    }
+
+    /// Returns the status of the first response, if the server wrote a
+    /// well-formed status line.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::local::blocking::Client;
+    /// use rocket::http::Status;
+    ///
+    /// let client = Client::tracked(rocket::build()).unwrap();
+    /// let response = client.send_raw("GET / HTTP/1.1\r\nHost: \u{7f}\r\n\r\n");
+    /// assert_eq!(response.status(), Some(Status::BadRequest));
+    ///
+    /// let response = client.send_raw("");
+    /// assert_eq!(response.status(), None);
+    /// ```
+    pub fn status(&self) -> Option<Status> {
+        let line = self.bytes.split(|&b| b == b'\r' || b == b'\n').next()?;
+        let line = std::str::from_utf8(line).ok()?;
+        let mut parts = line.splitn(3, ' ');
+        if !parts.next()?.starts_with("HTTP/") {
+            return None;
+        }
+
+        parts.next()?.parse().ok().and_then(Status::from_code)
+    }
+
+    /// Returns the raw bytes that were written in response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::local::blocking::Client;
+    ///
+    /// let client = Client::tracked(rocket::build()).unwrap();
+    /// let response = client.send_raw("GET / HTTP/1.1\r\nHost: example.com\r\n\r\n");
+    /// let bytes: &[u8] = response.as_bytes();
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes `self` and returns the raw bytes that were written in
+    /// response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::local::blocking::Client;
+    ///
+    /// let client = Client::tracked(rocket::build()).unwrap();
+    /// let response = client.send_raw("GET / HTTP/1.1\r\nHost: example.com\r\n\r\n");
+    /// let bytes: Vec<u8> = response.into_bytes();
+    /// ```
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl fmt::Debug for RawResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RawResponse").field(&String::from_utf8_lossy(&self.bytes)).finish()
+    }
+}
@@ -285,6 +285,74 @@ macro_rules! pub_request_impl {
         self.header(crate::http::ContentType::MsgPack).body(msgpack)
     }
 
+    /// Sets the body to `value` serialized as CBOR with `Content-Type`
+    /// [`ContentType::CBOR`](crate::http::ContentType::CBOR).
+    ///
+    /// If `value` fails to serialize, the body is set to empty. The
+    /// `Content-Type` header is _always_ set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    #[doc = $import]
+    /// use rocket::serde::Serialize;
+    /// use rocket::http::ContentType;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Task {
+    ///     id: usize,
+    ///     complete: bool,
+    /// }
+    ///
+    /// # Client::_test(|_, request, _| {
+    /// let task = Task { id: 10, complete: false };
+    ///
+    /// let request: LocalRequest = request;
+    /// let req = request.cbor(&task);
+    /// assert_eq!(req.content_type(), Some(&ContentType::CBOR));
+    /// # });
+    /// ```
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(nightly, doc(cfg(feature = "cbor")))]
+    pub fn cbor<T: crate::serde::Serialize>(self, value: &T) -> Self {
+        let cbor = crate::serde::cbor::to_vec(value).unwrap_or_default();
+        self.header(crate::http::ContentType::CBOR).body(cbor)
+    }
+
+    /// Sets the body to `value` serialized as XML with `Content-Type`
+    /// `application/xml`.
+    ///
+    /// If `value` fails to serialize, the body is set to empty. The
+    /// `Content-Type` header is _always_ set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    #[doc = $import]
+    /// use rocket::serde::Serialize;
+    /// use rocket::http::ContentType;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Task {
+    ///     id: usize,
+    ///     complete: bool,
+    /// }
+    ///
+    /// # Client::_test(|_, request, _| {
+    /// let task = Task { id: 10, complete: false };
+    ///
+    /// let request: LocalRequest = request;
+    /// let req = request.xml(&task);
+    /// assert_eq!(req.content_type(), Some(&ContentType::new("application", "xml")));
+    /// # });
+    /// ```
+    #[cfg(feature = "xml")]
+    #[cfg_attr(nightly, doc(cfg(feature = "xml")))]
+    pub fn xml<T: crate::serde::Serialize>(self, value: &T) -> Self {
+        let xml = crate::serde::xml::to_string(value).unwrap_or_default();
+        self.header(crate::http::ContentType::new("application", "xml")).body(xml)
+    }
+
     /// Set the body (data) of the request without consuming `self`.
     ///
     /// # Examples
@@ -37,6 +37,76 @@ macro_rules! pub_response_impl {
     getter_method!($doc_prelude, "HTTP headers",
         headers -> &crate::http::HeaderMap<'_>);
 
+    /// Returns `true` if the response has a header named `name`, compared
+    /// case-insensitively, with the value `value`, compared exactly.
+    ///
+    /// To check whether the header is present regardless of its value, use
+    /// [`HeaderMap::contains()`](crate::http::HeaderMap::contains()) on
+    /// [`headers()`](Self::headers()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    ///
+    /// fn test(response: LocalResponse<'_>) {
+    ///     assert!(response.headers_contains("X-Frame-Options", "SAMEORIGIN"));
+    /// }
+    /// ```
+    pub fn headers_contains(&self, name: &str, value: &str) -> bool {
+        self.headers().get(name).any(|v| v == value)
+    }
+
+    /// Asserts that the response has every header in `expected`, each given as
+    /// a `(name, value)` pair and checked as in
+    /// [`headers_contains()`](Self::headers_contains()). Headers not in
+    /// `expected` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any header is missing or has a different value. The message
+    /// lists every failed expectation alongside all of the response's headers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    ///
+    /// fn test(response: LocalResponse<'_>) {
+    ///     response.assert_headers(&[
+    ///         ("X-Content-Type-Options", "nosniff"),
+    ///         ("X-Frame-Options", "SAMEORIGIN"),
+    ///     ]);
+    /// }
+    /// ```
+    pub fn assert_headers(&self, expected: &[(&str, &str)]) {
+        use std::fmt::Write;
+
+        let mut failures = String::new();
+        for &(name, value) in expected {
+            if self.headers_contains(name, value) {
+                continue;
+            }
+
+            let actual: Vec<_> = self.headers().get(name).collect();
+            let _ = if actual.is_empty() {
+                writeln!(failures, "  {}: expected {:?}, header missing", name, value)
+            } else {
+                writeln!(failures, "  {}: expected {:?}, found {:?}", name, value, actual)
+            };
+        }
+
+        if !failures.is_empty() {
+            let mut headers = String::new();
+            for header in self.headers().iter() {
+                let _ = writeln!(headers, "  {}", header);
+            }
+
+            panic!("response ({}) header mismatch\n{}response headers:\n{}",
+                self.status(), failures, headers);
+        }
+    }
+
     /// Return a cookie jar containing the HTTP cookies in the response.
     ///
     /// # Example
@@ -144,6 +214,66 @@ macro_rules! pub_response_impl {
         self._into_json() $(.$suffix)?
     }
 
+    /// Consumes `self` and deserializes its body as an untyped JSON
+    /// [`Value`](crate::serde::json::Value).
+    ///
+    /// If deserialization fails or the body is unset in the response, returns
+    /// `None`. Otherwise, returns `Some`. Two `Value`s compare equal when they
+    /// are structurally equal: the order of keys in objects is disregarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    ///
+    /// # Client::_test(|_, _, response| {
+    /// let response: LocalResponse = response;
+    /// let value = response.into_json_value();
+    /// # });
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(nightly, doc(cfg(feature = "json")))]
+    pub $($prefix)? fn into_json_value(self) -> Option<crate::serde::json::Value> {
+        self.into_json() $(.$suffix)?
+    }
+
+    /// Consumes `self` and asserts that its body is JSON structurally equal to
+    /// `expected`, disregarding the order of keys in objects.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the body is unset, is not valid JSON, or is not equal to the
+    /// serialization of `expected`. The message contains both the expected and
+    /// actual values, pretty-printed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    /// use rocket::serde::json::json;
+    ///
+    /// fn test(response: LocalResponse<'_>) {
+    ///     response.assert_json_eq(json!({ "id": 1, "tags": ["a", "b"] }));
+    /// }
+    /// ```
+    #[cfg(feature = "json")]
+    #[cfg_attr(nightly, doc(cfg(feature = "json")))]
+    pub $($prefix)? fn assert_json_eq<T: serde::Serialize>(self, expected: T) {
+        let expected = match serde_json::to_value(expected) {
+            Ok(value) => value,
+            Err(e) => panic!("expected value failed to serialize as JSON: {}", e),
+        };
+
+        let status = self.status();
+        let actual = match self.into_json_value() $(.$suffix)? {
+            Some(value) => value,
+            None => panic!("response ({}) body is not JSON; expected:\n{:#}", status, expected),
+        };
+
+        assert!(actual == expected, "response ({}) JSON mismatch\nexpected:\n{:#}\nactual:\n{:#}",
+            status, expected, actual);
+    }
+
     /// Consumes `self` and deserializes its body as MessagePack without
     /// buffering in memory.
     ///
@@ -180,6 +310,78 @@ macro_rules! pub_response_impl {
         self._into_msgpack() $(.$suffix)?
     }
 
+    /// Consumes `self` and deserializes its body as CBOR without buffering in
+    /// memory.
+    ///
+    /// If deserialization fails or the body is unset in the response, returns
+    /// `None`. Otherwise, returns `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    /// use rocket::serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Task {
+    ///     id: usize,
+    ///     complete: bool,
+    ///     text: String,
+    /// }
+    ///
+    /// # Client::_test(|_, _, response| {
+    /// let response: LocalResponse = response;
+    /// let task = response.into_cbor::<Task>();
+    /// # });
+    /// ```
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(nightly, doc(cfg(feature = "cbor")))]
+    pub $($prefix)? fn into_cbor<T>(self) -> Option<T>
+        where T: Send + serde::de::DeserializeOwned + 'static
+    {
+        if self._response().body().is_none() {
+            return None;
+        }
+
+        self._into_cbor() $(.$suffix)?
+    }
+
+    /// Consumes `self` and deserializes its body as XML without buffering in
+    /// memory.
+    ///
+    /// If deserialization fails or the body is unset in the response, returns
+    /// `None`. Otherwise, returns `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    #[doc = $doc_prelude]
+    /// use rocket::serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Task {
+    ///     id: usize,
+    ///     complete: bool,
+    ///     text: String,
+    /// }
+    ///
+    /// # Client::_test(|_, _, response| {
+    /// let response: LocalResponse = response;
+    /// let task = response.into_xml::<Task>();
+    /// # });
+    /// ```
+    #[cfg(feature = "xml")]
+    #[cfg_attr(nightly, doc(cfg(feature = "xml")))]
+    pub $($prefix)? fn into_xml<T>(self) -> Option<T>
+        where T: Send + serde::de::DeserializeOwned + 'static
+    {
+        if self._response().body().is_none() {
+            return None;
+        }
+
+        self._into_xml() $(.$suffix)?
+    }
+
     #[cfg(test)]
     #[allow(dead_code)]
     fn _ensure_impls_exist() {
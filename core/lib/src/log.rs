@@ -51,6 +51,16 @@ pub enum LogLevel {
     Off,
 }
 
+/// Defines how launch information, such as the mounted routes, is logged.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum LogFormat {
+    /// Logs one route per line: `"lines"`.
+    Lines,
+    /// Logs routes as an aligned table of their method, path, rank, and
+    /// handler name: `"table"`.
+    Table,
+}
+
 pub trait PaintExt {
     fn emoji(item: &str) -> Paint<&str>;
 }
@@ -215,6 +225,51 @@ impl<'de> Deserialize<'de> for LogLevel {
     }
 }
 
+impl LogFormat {
+    fn as_str(&self) -> &str {
+        match self {
+            LogFormat::Lines => "lines",
+            LogFormat::Table => "table",
+        }
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let format = match &*s.to_ascii_lowercase() {
+            "lines" => LogFormat::Lines,
+            "table" => LogFormat::Table,
+            _ => return Err("a log format (lines, table)")
+        };
+
+        Ok(format)
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for LogFormat {
+    fn serialize<S: Serializer>(&self, ser: S) -> Result<S::Ok, S::Error> {
+        ser.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LogFormat {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(de)?;
+        LogFormat::from_str(&string).map_err(|_| de::Error::invalid_value(
+            de::Unexpected::Str(&string),
+            &figment::error::OneOf( &["lines", "table"])
+        ))
+    }
+}
+
 impl PaintExt for Paint<&str> {
     /// Paint::masked(), but hidden on Windows due to broken output. See #1122.
     fn emoji(_item: &str) -> Paint<&str> {
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+use std::time::Duration;
+use std::collections::{HashMap, BTreeMap};
+
+use parking_lot::Mutex;
+use serde::Serialize;
+
+use crate::metrics::{Recorder, Sample};
+
+/// A [`Recorder`] that keeps an in-memory latency histogram per route.
+///
+/// Samples are keyed by [`Sample::name()`]. Each histogram uses logarithmic
+/// buckets with 16 linear sub-buckets each, so reported percentiles are within
+/// roughly 3% of the exact value while memory use per route stays constant.
+///
+/// `Histograms` is cheap to clone: clones share the same histograms.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::metrics::Histograms;
+///
+/// #[get("/latency")]
+/// fn latency(histograms: &State<Histograms>) -> String {
+///     match histograms.percentile("index", 0.99) {
+///         Some(p99) => format!("index p99: {:?}", p99),
+///         None => "no requests to index yet".into(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Histograms {
+    inner: Arc<Mutex<HashMap<String, Histogram>>>,
+}
+
+/// A summary of the latencies recorded for a single route.
+///
+/// All durations are in microseconds. A `Summary` implements `Serialize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct Summary {
+    /// The number of requests recorded.
+    pub count: u64,
+    /// The smallest latency recorded.
+    pub min_us: u64,
+    /// The largest latency recorded.
+    pub max_us: u64,
+    /// The mean latency.
+    pub mean_us: u64,
+    /// The median latency.
+    pub p50_us: u64,
+    /// The 90th percentile latency.
+    pub p90_us: u64,
+    /// The 99th percentile latency.
+    pub p99_us: u64,
+}
+
+impl Histograms {
+    /// Returns a new, empty set of histograms.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::Histograms;
+    ///
+    /// let histograms = Histograms::new();
+    /// assert!(histograms.summaries().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Histograms::default()
+    }
+
+    /// Returns a summary of the latencies recorded under `name`, or `None` if
+    /// none have been recorded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::Histograms;
+    ///
+    /// let histograms = Histograms::new();
+    /// assert!(histograms.summary("index").is_none());
+    /// ```
+    pub fn summary(&self, name: &str) -> Option<Summary> {
+        self.inner.lock().get(name).map(Histogram::summary)
+    }
+
+    /// Returns a summary of the latencies recorded under every name, ordered
+    /// by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::Histograms;
+    ///
+    /// let histograms = Histograms::new();
+    /// for (name, summary) in histograms.summaries() {
+    ///     println!("{}: {} requests, p99 {}us", name, summary.count, summary.p99_us);
+    /// }
+    /// ```
+    pub fn summaries(&self) -> BTreeMap<String, Summary> {
+        self.inner.lock()
+            .iter()
+            .map(|(name, histogram)| (name.clone(), histogram.summary()))
+            .collect()
+    }
+
+    /// Returns the `q`-th quantile of the latencies recorded under `name`,
+    /// where `q` is between `0.0` and `1.0`, or `None` if none have been
+    /// recorded. Out-of-range values of `q` are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::Histograms;
+    ///
+    /// let histograms = Histograms::new();
+    /// assert!(histograms.percentile("index", 0.5).is_none());
+    /// ```
+    pub fn percentile(&self, name: &str, q: f64) -> Option<Duration> {
+        self.inner.lock()
+            .get(name)
+            .map(|histogram| Duration::from_micros(histogram.quantile(q)))
+    }
+}
+
+impl Recorder for Histograms {
+    fn record(&self, sample: &Sample<'_>) {
+        let micros = sample.duration.as_micros().min(u64::MAX as u128) as u64;
+        let mut histograms = self.inner.lock();
+        match histograms.get_mut(sample.name()) {
+            Some(histogram) => histogram.record(micros),
+            None => {
+                let mut histogram = Histogram::new();
+                histogram.record(micros);
+                histograms.insert(sample.name().to_string(), histogram);
+            }
+        }
+    }
+}
+
+/// The number of linear sub-buckets per power of two, as a power of two.
+const SUB_BITS: u32 = 4;
+const SUB_BUCKETS: usize = 1 << SUB_BITS;
+
+/// Values below `SUB_BUCKETS` get a bucket each; every power of two above gets
+/// `SUB_BUCKETS` buckets.
+const BUCKETS: usize = SUB_BUCKETS + (64 - SUB_BITS as usize) * SUB_BUCKETS;
+
+/// A log-linear histogram of microsecond values.
+#[derive(Debug, Clone)]
+struct Histogram {
+    count: u64,
+    sum: u128,
+    min: u64,
+    max: u64,
+    buckets: Vec<u64>,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Histogram { count: 0, sum: 0, min: u64::MAX, max: 0, buckets: vec![0; BUCKETS] }
+    }
+
+    fn bucket(value: u64) -> usize {
+        if value < SUB_BUCKETS as u64 {
+            return value as usize;
+        }
+
+        let exp = 63 - value.leading_zeros();
+        let sub = (value >> (exp - SUB_BITS)) as usize & (SUB_BUCKETS - 1);
+        SUB_BUCKETS + (exp - SUB_BITS) as usize * SUB_BUCKETS + sub
+    }
+
+    /// The value at the middle of bucket `index`.
+    fn midpoint(index: usize) -> u64 {
+        if index < SUB_BUCKETS {
+            return index as u64;
+        }
+
+        let shift = ((index - SUB_BUCKETS) / SUB_BUCKETS) as u32;
+        let sub = ((index - SUB_BUCKETS) % SUB_BUCKETS) as u64;
+        let low = (SUB_BUCKETS as u64 + sub) << shift;
+        low.saturating_add((1 << shift) / 2)
+    }
+
+    fn record(&mut self, value: u64) {
+        self.count += 1;
+        self.sum += value as u128;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buckets[Histogram::bucket(value)] += 1;
+    }
+
+    fn quantile(&self, q: f64) -> u64 {
+        let q = if q.is_nan() { 0.0 } else { q.clamp(0.0, 1.0) };
+        let rank = ((q * self.count as f64).ceil() as u64).max(1);
+        if rank >= self.count {
+            return self.max;
+        }
+
+        let mut seen = 0;
+        for (index, &n) in self.buckets.iter().enumerate() {
+            seen += n;
+            if seen >= rank {
+                return Histogram::midpoint(index).max(self.min).min(self.max);
+            }
+        }
+
+        self.max
+    }
+
+    fn summary(&self) -> Summary {
+        Summary {
+            count: self.count,
+            min_us: self.min,
+            max_us: self.max,
+            mean_us: (self.sum / self.count.max(1) as u128) as u64,
+            p50_us: self.quantile(0.50),
+            p90_us: self.quantile(0.90),
+            p99_us: self.quantile(0.99),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Histogram;
+
+    #[test]
+    fn buckets_are_monotonic_and_in_range() {
+        let mut last = 0;
+        for value in (0..20_000).chain(vec![u64::MAX / 2, u64::MAX]) {
+            let bucket = Histogram::bucket(value);
+            assert!(bucket >= last, "bucket({}) = {} < {}", value, bucket, last);
+            assert!(bucket < super::BUCKETS);
+            last = bucket;
+        }
+    }
+
+    #[test]
+    fn quantiles_are_approximately_exact() {
+        let mut histogram = Histogram::new();
+        for value in 1..=10_000 {
+            histogram.record(value);
+        }
+
+        for &(q, exact) in &[(0.5, 5_000.0), (0.9, 9_000.0), (0.99, 9_900.0)] {
+            let approx = histogram.quantile(q) as f64;
+            assert!((approx - exact).abs() / exact < 0.04, "q{}: {} vs {}", q, approx, exact);
+        }
+
+        assert_eq!(histogram.quantile(0.0), 1);
+        assert_eq!(histogram.quantile(1.0), 10_000);
+        assert_eq!(histogram.summary().mean_us, 5_000);
+    }
+}
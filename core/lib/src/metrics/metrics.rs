@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::{Rocket, Request, Response, Data, Route, Build};
+use crate::fairing::{self, Fairing, Info, Kind};
+use crate::http::{Method, Status};
+use crate::metrics::Histograms;
+
+/// A recorder of request timing [`Sample`]s.
+///
+/// A `Recorder` is handed a [`Sample`] by the [`Metrics`] fairing for every
+/// request Rocket responds to. Recording happens while responding, so
+/// implementations should be quick to return. See the [module
+/// documentation](crate::metrics) for an example.
+pub trait Recorder: Send + Sync + 'static {
+    /// Records the sample `sample`.
+    fn record(&self, sample: &Sample<'_>);
+}
+
+/// A timing of a single request, as reported to a [`Recorder`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct Sample<'r> {
+    /// The route that handled the request, if any.
+    pub route: Option<&'r Route>,
+    /// The method of the request.
+    pub method: Method,
+    /// The status of the response.
+    pub status: Status,
+    /// The time elapsed from when the request callback of [`Metrics`] ran
+    /// until its response callback ran.
+    pub duration: Duration,
+}
+
+impl Sample<'_> {
+    /// The key of the sample: the name of the route that handled the request.
+    ///
+    /// If the request was not handled by a route, for instance, because no
+    /// route matched, the key is `<unmatched>`. If the route that handled the
+    /// request has no name, the key is `<unnamed>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::{Recorder, Sample};
+    ///
+    /// struct Printer;
+    ///
+    /// impl Recorder for Printer {
+    ///     fn record(&self, sample: &Sample<'_>) {
+    ///         println!("{}: {:?}", sample.name(), sample.duration);
+    ///     }
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        match self.route {
+            Some(route) => route.name.as_deref().unwrap_or("<unnamed>"),
+            None => "<unmatched>",
+        }
+    }
+}
+
+/// A [`Fairing`] that times requests and reports them to a [`Recorder`].
+///
+/// See the [module documentation](crate::metrics) for details.
+pub struct Metrics {
+    recorder: Arc<dyn Recorder>,
+    managed: Option<Histograms>,
+}
+
+/// The instant at which the request callback of `Metrics` ran.
+struct Start(Instant);
+
+impl Metrics {
+    /// Returns a `Metrics` fairing that reports every sample to `recorder`.
+    ///
+    /// Unlike [`Metrics::default()`], nothing is placed in managed state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::{Metrics, Histograms};
+    ///
+    /// // Record into histograms that are shared but not managed by Rocket.
+    /// let histograms = Histograms::new();
+    /// let rocket = rocket::build().attach(Metrics::new(histograms.clone()));
+    /// ```
+    pub fn new<R: Recorder>(recorder: R) -> Self {
+        Metrics { recorder: Arc::new(recorder), managed: None }
+    }
+}
+
+impl Default for Metrics {
+    /// Returns a `Metrics` fairing that records to a new [`Histograms`], which
+    /// is placed in managed state at ignition.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::metrics::Metrics;
+    ///
+    /// let rocket = rocket::build().attach(Metrics::default());
+    /// ```
+    fn default() -> Self {
+        let histograms = Histograms::new();
+        Metrics { recorder: Arc::new(histograms.clone()), managed: Some(histograms) }
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Metrics {
+    fn info(&self) -> Info {
+        Info {
+            name: "Metrics",
+            kind: Kind::Ignite | Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        match self.managed {
+            Some(ref histograms) if rocket.state::<Histograms>().is_none() => {
+                Ok(rocket.manage(histograms.clone()))
+            }
+            Some(_) => {
+                error_!("`Metrics` failed to manage its histograms.");
+                info_!("A `Histograms` is already being managed.");
+                Err(rocket)
+            }
+            None => Ok(rocket),
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        req.local_cache(|| Start(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let start = req.local_cache(|| Start(Instant::now()));
+        self.recorder.record(&Sample {
+            route: req.route(),
+            method: req.method(),
+            status: res.status(),
+            duration: start.0.elapsed(),
+        });
+    }
+}
@@ -0,0 +1,67 @@
+//! Request latency metrics with pluggable recorders.
+//!
+//! The [`Metrics`] fairing times every request, from when its request callback
+//! runs until its response callback runs, and reports each timing as a
+//! [`Sample`] to a [`Recorder`]. Samples are keyed by the name of the route
+//! that handled the request. Because fairings run in the order they are
+//! attached, attaching `Metrics` first times the work of all other fairings
+//! but the response callbacks that follow it.
+//!
+//! # Recorders
+//!
+//! A [`Recorder`] decides what to do with samples. The default recorder,
+//! [`Histograms`], keeps a latency histogram per route in memory and can
+//! summarize it as counts and percentiles. A summary is serializable, so it can
+//! be exposed directly as JSON. Other recorders, for instance, one backed by a
+//! Prometheus client, can be plugged in via [`Metrics::new()`].
+//!
+//! # Usage
+//!
+//! Attach [`Metrics::default()`] to record to a [`Histograms`] which is also
+//! placed in managed state. The histograms can then be retrieved via
+//! [`State`](crate::State) and, with the `json` feature enabled, exposed:
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! # #[cfg(feature = "json")] mod example {
+//! use std::collections::BTreeMap;
+//!
+//! use rocket::State;
+//! use rocket::metrics::{Metrics, Histograms, Summary};
+//! use rocket::serde::json::Json;
+//!
+//! #[get("/metrics")]
+//! fn metrics(histograms: &State<Histograms>) -> Json<BTreeMap<String, Summary>> {
+//!     Json(histograms.summaries())
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     rocket::build()
+//!         .mount("/", routes![metrics])
+//!         .attach(Metrics::default())
+//! }
+//! # }
+//! ```
+//!
+//! To record elsewhere, implement [`Recorder`] and attach `Metrics::new()`:
+//!
+//! ```rust
+//! use rocket::metrics::{Metrics, Recorder, Sample};
+//!
+//! struct Logger;
+//!
+//! impl Recorder for Logger {
+//!     fn record(&self, sample: &Sample<'_>) {
+//!         println!("{} {} took {:?}", sample.name(), sample.status, sample.duration);
+//!     }
+//! }
+//!
+//! let rocket = rocket::build().attach(Metrics::new(Logger));
+//! ```
+
+mod metrics;
+mod histograms;
+
+pub use self::metrics::{Metrics, Recorder, Sample};
+pub use self::histograms::{Histograms, Summary};
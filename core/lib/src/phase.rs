@@ -4,6 +4,7 @@ use figment::Figment;
 use crate::{Catcher, Config, Rocket, Route, Shutdown};
 use crate::router::Router;
 use crate::fairing::Fairings;
+use crate::response::ErrorMap;
 
 mod private {
     pub trait Sealed {  }
@@ -84,6 +85,7 @@ phases! {
         pub(crate) fairings: Fairings,
         pub(crate) figment: Figment,
         pub(crate) state: Container![Send + Sync],
+        pub(crate) error_map: ErrorMap,
     }
 
     /// The second launch [`Phase`]: post-build but pre-orbit.
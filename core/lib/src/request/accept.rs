@@ -0,0 +1,214 @@
+use std::convert::Infallible;
+
+use crate::http::{self, MediaType, QMediaType};
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the media ranges in the request's `Accept` header(s).
+///
+/// Unlike [`Request::accept()`], which is `None` unless the entire header
+/// parses, this guard tolerates malformed entries: each comma-separated media
+/// range that fails to parse, or that has an invalid quality value, is skipped
+/// while the rest are retained. The values of all `Accept` headers in the
+/// request are combined. The guard is parsed at most once per request and
+/// always succeeds; a request without any valid media range yields an empty
+/// `Accept`, which, as per [RFC 7231 §5.3.2], accepts every media type.
+///
+/// Media ranges are available in header order, alongside their quality values,
+/// via [`Accept::iter()`]. For server-driven negotiation, [`Accept::quality()`]
+/// computes the quality the client assigns to a given media type, and
+/// [`Accept::preferred()`] picks the most acceptable of several offered ones.
+///
+/// [RFC 7231 §5.3.2]: https://tools.ietf.org/html/rfc7231#section-5.3.2
+///
+/// # Outcomes
+///
+///   * **Success** always.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{ContentType, MediaType};
+/// use rocket::request::Accept;
+///
+/// #[get("/")]
+/// fn index(accept: &Accept) -> (ContentType, &'static str) {
+///     match accept.preferred(&[MediaType::JSON, MediaType::HTML]) {
+///         Some(mt) if *mt == MediaType::HTML => (ContentType::HTML, "<p>hi</p>"),
+///         _ => (ContentType::JSON, r#"{ "message": "hi" }"#),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Accept(Vec<QMediaType>);
+
+impl Accept {
+    fn parse(req: &Request<'_>) -> Accept {
+        let mut ranges = vec![];
+        for value in req.headers().get("Accept") {
+            match value.parse::<http::Accept>() {
+                Ok(accept) => ranges.extend(accept.iter().cloned()),
+                Err(_) => ranges.extend(value.split(',')
+                    .filter_map(|range| range.parse::<http::Accept>().ok())
+                    .flat_map(|accept| accept.iter().cloned().collect::<Vec<_>>())),
+            }
+        }
+
+        ranges.retain(|range| weight(range).is_some());
+        Accept(ranges)
+    }
+
+    /// Returns an iterator over the media ranges, and their quality values, in
+    /// the order in which they appear in the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Accept;
+    ///
+    /// fn handler(accept: &Accept) {
+    ///     for range in accept.iter() {
+    ///         println!("{} (q={})", range.media_type(), range.weight_or(1.0));
+    ///     }
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &QMediaType> + '_ {
+        self.0.iter()
+    }
+
+    /// Returns the number of valid media ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Accept;
+    ///
+    /// fn handler(accept: &Accept) {
+    ///     println!("the client sent {} media range(s)", accept.len());
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if there are no valid media ranges, in which case every
+    /// media type is acceptable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Accept;
+    ///
+    /// fn handler(accept: &Accept) {
+    ///     if accept.is_empty() {
+    ///         println!("the client accepts anything");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the quality value, between `0.0` and `1.0`, the client assigns
+    /// to `media_type`. A quality of `0.0` means `media_type` is not
+    /// acceptable.
+    ///
+    /// As per [RFC 7231 §5.3.2], the quality is that of the most specific
+    /// media range matching `media_type`: `text/plain` takes precedence over
+    /// `text/*`, which takes precedence over `*/*`, and a range with parameters
+    /// takes precedence over one without. A range's parameters only match if
+    /// `media_type` has each of them. A range without a `q` parameter has a
+    /// quality of `1.0`. If there are no media ranges at all, the quality of
+    /// every media type is `1.0`.
+    ///
+    /// [RFC 7231 §5.3.2]: https://tools.ietf.org/html/rfc7231#section-5.3.2
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::MediaType;
+    /// use rocket::request::Accept;
+    ///
+    /// fn handler(accept: &Accept) {
+    ///     if accept.quality(&MediaType::JSON) > 0.0 {
+    ///         println!("the client accepts JSON");
+    ///     }
+    /// }
+    /// ```
+    pub fn quality(&self, media_type: &MediaType) -> f32 {
+        if self.is_empty() {
+            return 1.0;
+        }
+
+        self.iter()
+            .filter(|range| matches(range, media_type))
+            .max_by_key(|range| (range.specificity(), media_params(range).count()))
+            .and_then(weight)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the media type in `offered` with the highest
+    /// [quality](Accept::quality()), or `None` if none are acceptable. Ties are
+    /// broken in favor of the media type that appears first in `offered`, so
+    /// list the types in the server's order of preference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::MediaType;
+    /// use rocket::request::Accept;
+    ///
+    /// fn handler(accept: &Accept) {
+    ///     match accept.preferred(&[MediaType::JSON, MediaType::MsgPack]) {
+    ///         Some(media_type) => println!("responding with {}", media_type),
+    ///         None => println!("nothing acceptable"),
+    ///     }
+    /// }
+    /// ```
+    pub fn preferred<'m>(&self, offered: &'m [MediaType]) -> Option<&'m MediaType> {
+        let mut preferred = None;
+        for media_type in offered {
+            let quality = self.quality(media_type);
+            match preferred {
+                Some((_, q)) if q >= quality => continue,
+                _ if quality > 0.0 => preferred = Some((media_type, quality)),
+                _ => continue,
+            }
+        }
+
+        preferred.map(|(media_type, _)| media_type)
+    }
+}
+
+/// The parameters of `range` that constrain the media type. Those following a
+/// `q` parameter are accept extensions, not media type parameters.
+fn media_params(range: &QMediaType) -> impl Iterator<Item = (&str, &str)> {
+    range.params()
+        .map(|(name, value)| (name.as_str(), value))
+        .take_while(|(name, _)| !name.eq_ignore_ascii_case("q"))
+}
+
+/// The quality value of `range`, or `None` if it is invalid. Unlike
+/// [`QMediaType::weight()`], this considers a `q` parameter in any position.
+fn weight(range: &QMediaType) -> Option<f32> {
+    match range.params().find(|(name, _)| *name == "q") {
+        Some((_, q)) => q.parse::<f32>().ok().filter(|q| (0.0..=1.0).contains(q)),
+        None => Some(1.0),
+    }
+}
+
+/// Whether the media range `range` matches `media_type`.
+fn matches(range: &QMediaType, media_type: &MediaType) -> bool {
+    (range.top() == "*" || range.top() == media_type.top())
+        && (range.sub() == "*" || range.sub() == media_type.sub())
+        && media_params(range).all(|(name, value)| media_type.param(name) == Some(value))
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Accept {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(req.local_cache(|| Accept::parse(req)))
+    }
+}
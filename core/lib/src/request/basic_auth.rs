@@ -0,0 +1,204 @@
+use std::fmt;
+
+use crate::http::{Header, Status, Challenge};
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for credentials sent via HTTP Basic authentication.
+///
+/// The credentials are parsed from an `Authorization` header using the `Basic`
+/// scheme as described in [RFC 7617]: the base64-decoded `user-id:password`
+/// pair is split at the _first_ colon, so passwords may contain colons while
+/// usernames may not. Both must be valid UTF-8.
+///
+/// [RFC 7617]: https://tools.ietf.org/html/rfc7617
+///
+/// # Outcomes
+///
+///   * **Success** if the request contains well-formed `Basic` credentials.
+///   * **Failure** with `401 Unauthorized` if the request contains no
+///     `Authorization` header or one with a scheme other than `Basic`. A
+///     `Basic` challenge for the realm [`BasicAuth::REALM`] is recorded with
+///     [`Request::set_challenge()`].
+///   * **Failure** with `400 Bad Request` if the credentials are not valid
+///     base64, are not valid UTF-8, or do not contain a colon.
+///
+/// A `BasicAuth` guard does not verify the credentials: they should be checked
+/// against a user store by the application, typically in a custom request
+/// guard that wraps `BasicAuth`. To accept requests without credentials, use
+/// `Option<BasicAuth>`.
+///
+/// # Challenges
+///
+/// A `401 Unauthorized` response to a request for a resource protected by
+/// Basic authentication must include a `WWW-Authenticate` header naming the
+/// realm of the resource, which prompts browsers for credentials. When the
+/// guard fails with a `401`, it records a challenge for the request which
+/// Rocket adds to the error response unless the `401` catcher sets its own
+/// `WWW-Authenticate` header. To name a different realm, record a
+/// [`Challenge::basic()`] before the guard runs, for instance, in a request
+/// fairing, or set the header in the catcher via [`BasicAuth::challenge()`].
+/// To offer other schemes as well, respond with a [`Challenge::basic()`]
+/// among the other [`Challenge`]s instead.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::Header;
+/// use rocket::request::BasicAuth;
+///
+/// #[get("/admin")]
+/// fn admin(auth: BasicAuth) -> String {
+///     format!("Hello, {}!", auth.username())
+/// }
+///
+/// #[derive(Responder)]
+/// #[response(status = 401)]
+/// struct Challenge(&'static str, Header<'static>);
+///
+/// #[catch(401)]
+/// fn unauthorized() -> Challenge {
+///     Challenge("Please log in.", BasicAuth::challenge("admin panel"))
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .mount("/", routes![admin])
+///         .register("/", catchers![unauthorized])
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+impl BasicAuth {
+    /// The realm of the challenge recorded when the guard fails with a `401`
+    /// and no other challenge was recorded for the request.
+    pub const REALM: &'static str = "Restricted";
+
+    /// Parses the value of an `Authorization` header. Returns `None` if the
+    /// header doesn't use the `Basic` scheme and `Some(Err(_))` if the scheme
+    /// is `Basic` but the credentials are malformed.
+    fn parse(value: &str) -> Option<Result<BasicAuth, &'static str>> {
+        let value = value.trim();
+        let (scheme, credentials) = match value.find(' ') {
+            Some(i) => (&value[..i], value[i..].trim_start()),
+            None => (value, ""),
+        };
+
+        if !scheme.eq_ignore_ascii_case("basic") {
+            return None;
+        }
+
+        // Some clients omit the padding. Add it back for `binascii`.
+        let mut encoded = credentials.as_bytes().to_vec();
+        match encoded.len() % 4 {
+            0 if !encoded.is_empty() => { /* ok */ },
+            n @ 2 | n @ 3 => encoded.resize(encoded.len() + 4 - n, b'='),
+            _ => return Some(Err("malformed basic credentials")),
+        }
+
+        let mut decoded = vec![0; encoded.len() / 4 * 3];
+        let len = match binascii::b64decode(&encoded, &mut decoded) {
+            Ok(bytes) => bytes.len(),
+            Err(_) => return Some(Err("invalid base64 in basic credentials")),
+        };
+
+        decoded.truncate(len);
+        let string = match String::from_utf8(decoded) {
+            Ok(string) => string,
+            Err(_) => return Some(Err("invalid UTF-8 in basic credentials")),
+        };
+
+        let colon = match string.find(':') {
+            Some(i) => i,
+            None => return Some(Err("basic credentials are missing a colon")),
+        };
+
+        let password = string[colon + 1..].to_string();
+        let mut username = string;
+        username.truncate(colon);
+        Some(Ok(BasicAuth { username, password }))
+    }
+
+    /// Returns the username, the part of the credentials before the first
+    /// colon.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::BasicAuth;
+    ///
+    /// fn handler(auth: BasicAuth) {
+    ///     let username = auth.username();
+    /// }
+    /// ```
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Returns the password, the part of the credentials after the first
+    /// colon. The password may itself contain colons.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::BasicAuth;
+    ///
+    /// fn handler(auth: BasicAuth) {
+    ///     let password = auth.password();
+    /// }
+    /// ```
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    /// Returns a `WWW-Authenticate` header challenging the client to
+    /// authenticate via Basic authentication for the realm `realm`. The
+    /// challenge indicates that credentials are to be encoded as UTF-8.
+    ///
+    /// Quotes and backslashes in `realm` are escaped. This is the header for
+    /// [`Challenge::basic(realm)`](Challenge::basic()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::BasicAuth;
+    ///
+    /// let header = BasicAuth::challenge("admin panel");
+    /// assert_eq!(header.name(), "WWW-Authenticate");
+    /// assert_eq!(header.value(), r#"Basic realm="admin panel", charset="UTF-8""#);
+    /// ```
+    pub fn challenge(realm: &str) -> Header<'static> {
+        Challenge::basic(realm).into()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for BasicAuth {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let auth = request.headers().get("Authorization").filter_map(BasicAuth::parse).next();
+        match auth {
+            Some(Ok(auth)) => Outcome::Success(auth),
+            Some(Err(e)) => Outcome::Failure((Status::BadRequest, e)),
+            None => {
+                request.set_challenge(Challenge::basic(BasicAuth::REALM));
+                Outcome::Failure((Status::Unauthorized, "missing basic credentials"))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BasicAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
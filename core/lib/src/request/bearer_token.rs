@@ -0,0 +1,124 @@
+use std::fmt;
+
+use crate::http::{Status, Challenge, BearerError};
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for an access token sent via the `Bearer` authentication
+/// scheme.
+///
+/// The token is parsed from an `Authorization` header using the `Bearer`
+/// scheme as described in [RFC 6750 § 2.1]. The token must match the
+/// `b68token` syntax: one or more ASCII letters, digits, or any of `-._~+/`,
+/// followed by any number of `=`.
+///
+/// [RFC 6750 § 2.1]: https://tools.ietf.org/html/rfc6750#section-2.1
+///
+/// # Outcomes
+///
+///   * **Success** if the request contains a well-formed `Bearer` token.
+///   * **Failure** with `401 Unauthorized` if the request contains no
+///     `Authorization` header or one with a scheme other than `Bearer`. A
+///     `Bearer` challenge without an error code is recorded with
+///     [`Request::set_challenge()`], as the RFC recommends for requests
+///     lacking authentication.
+///   * **Failure** with `400 Bad Request` if the token is malformed. A
+///     `Bearer` challenge with the error code `invalid_request` is recorded.
+///
+/// Like [`BasicAuth`](crate::request::BasicAuth), a `BearerToken` guard does
+/// not verify the token: that is left to the application, typically in a
+/// custom request guard that wraps `BearerToken`. To accept requests without a
+/// token, use `Option<BearerToken>`.
+///
+/// # Challenges
+///
+/// When the guard fails, Rocket adds the recorded challenge as a
+/// `WWW-Authenticate` header to the error response unless the catcher sets
+/// its own. To name a realm or scope, record a [`Challenge::bearer()`]
+/// before the guard runs, for instance, in a request fairing. A guard that
+/// rejects a token should record a challenge with the appropriate
+/// [`BearerError`] itself.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::BearerToken;
+///
+/// #[get("/api")]
+/// fn api(token: BearerToken) -> String {
+///     format!("Your token is {} bytes long.", token.token().len())
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct BearerToken {
+    token: String,
+}
+
+impl BearerToken {
+    /// Parses the value of an `Authorization` header. Returns `None` if the
+    /// header doesn't use the `Bearer` scheme and `Some(Err(_))` if the scheme
+    /// is `Bearer` but the token is malformed.
+    fn parse(value: &str) -> Option<Result<BearerToken, &'static str>> {
+        let value = value.trim();
+        let (scheme, token) = match value.find(' ') {
+            Some(i) => (&value[..i], value[i..].trim_start()),
+            None => (value, ""),
+        };
+
+        if !scheme.eq_ignore_ascii_case("bearer") {
+            return None;
+        }
+
+        let b68 = token.trim_end_matches('=');
+        let valid = !b68.is_empty() && b68.bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-._~+/".contains(&b));
+
+        match valid {
+            true => Some(Ok(BearerToken { token: token.to_string() })),
+            false => Some(Err("malformed bearer token")),
+        }
+    }
+
+    /// Returns the token.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::BearerToken;
+    ///
+    /// fn handler(token: BearerToken) {
+    ///     let token = token.token();
+    /// }
+    /// ```
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for BearerToken {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let token = request.headers().get("Authorization").filter_map(BearerToken::parse).next();
+        match token {
+            Some(Ok(token)) => Outcome::Success(token),
+            Some(Err(e)) => {
+                request.set_challenge(Challenge::bearer().error(BearerError::InvalidRequest));
+                Outcome::Failure((Status::BadRequest, e))
+            }
+            None => {
+                request.set_challenge(Challenge::bearer());
+                Outcome::Failure((Status::Unauthorized, "missing bearer token"))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BearerToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BearerToken")
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
@@ -0,0 +1,169 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+
+use crate::{Rocket, Orbit};
+use crate::http::{hyper, Version, uncased::UncasedStr};
+use crate::request::{Request, FromRequest, Outcome, ConnectionMeta};
+
+/// Request guard for information about the connection a request arrived on.
+///
+/// A single connection may carry many requests, one after the other when the
+/// connection is kept alive or concurrently over HTTP/2. `ConnectionInfo`
+/// identifies the connection by its local and remote addresses and exposes
+/// the request's position on the connection, making it possible to tell
+/// whether a client, or a load balancer in front of Rocket, is reusing
+/// connections.
+///
+/// # Outcomes
+///
+///   * **Success** if the request was received over a connection.
+///   * **Forward** otherwise, as for local requests dispatched via a
+///     [`local`](crate::local) client.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::ConnectionInfo;
+///
+/// #[get("/")]
+/// fn index(conn: ConnectionInfo) -> String {
+///     format!("request #{} from {:?}, keep-alive: {}",
+///         conn.ordinal(), conn.remote(), conn.keep_alive())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    local: Option<SocketAddr>,
+    remote: SocketAddr,
+    keep_alive: bool,
+    ordinal: usize,
+}
+
+impl ConnectionInfo {
+    /// Records the arrival of the request `hyper` on `conn`.
+    pub(crate) fn new(
+        rocket: &Rocket<Orbit>,
+        hyper: &hyper::RequestParts,
+        conn: &ConnectionMeta,
+    ) -> ConnectionInfo {
+        let ordinal = conn.requests.fetch_add(1, Ordering::AcqRel) + 1;
+        let has_option = |option: &str| hyper.headers.get_all("Connection")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .any(|token| UncasedStr::new(token.trim()) == option);
+
+        let version = Version::from_hyp(hyper.version).unwrap_or(Version::Http11);
+        let keep_alive = rocket.config.keep_alive != 0 && match version {
+            Version::Http09 => false,
+            Version::Http10 => has_option("keep-alive"),
+            Version::Http11 => !has_option("close"),
+            Version::Http2 | Version::Http3 => true,
+        };
+
+        ConnectionInfo { local: conn.local, remote: conn.remote, keep_alive, ordinal }
+    }
+
+    /// The local address of the connection, i.e, the address of the server's
+    /// end of it, if it is known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn handler(conn: ConnectionInfo) {
+    ///     if let Some(local) = conn.local() {
+    ///         println!("accepted on port {}", local.port());
+    ///     }
+    /// }
+    /// ```
+    pub fn local(&self) -> Option<SocketAddr> {
+        self.local
+    }
+
+    /// The remote address of the connection, i.e, the address of the client or
+    /// proxy at the other end of it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn handler(conn: ConnectionInfo) {
+    ///     println!("connected from {}", conn.remote());
+    /// }
+    /// ```
+    pub fn remote(&self) -> SocketAddr {
+        self.remote
+    }
+
+    /// Whether the connection may be reused for further requests.
+    ///
+    /// This is `true` when keep-alive is enabled via the `keep_alive`
+    /// configuration parameter and the request didn't ask for the connection
+    /// to be closed: over HTTP/1.1, the request didn't send `Connection:
+    /// close`; over HTTP/1.0, the request sent `Connection: keep-alive`. HTTP/2
+    /// connections are always kept alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn handler(conn: ConnectionInfo) {
+    ///     if !conn.keep_alive() {
+    ///         println!("this is the last request on the connection");
+    ///     }
+    /// }
+    /// ```
+    pub fn keep_alive(&self) -> bool {
+        self.keep_alive
+    }
+
+    /// The position of the request among all requests received on the
+    /// connection, starting at `1` for the first request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn handler(conn: ConnectionInfo) {
+    ///     println!("request #{} on this connection", conn.ordinal());
+    /// }
+    /// ```
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// Whether the connection carried a request before this one. Equivalent
+    /// to `self.ordinal() > 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ConnectionInfo;
+    ///
+    /// fn handler(conn: ConnectionInfo) {
+    ///     assert_eq!(conn.is_reused(), conn.ordinal() > 1);
+    /// }
+    /// ```
+    pub fn is_reused(&self) -> bool {
+        self.ordinal > 1
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ConnectionInfo {
+    type Error = Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.connection() {
+            Some(info) => Outcome::Success(*info),
+            None => Outcome::Forward(()),
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::future::Future;
+use std::task::{Context, Poll};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::FutureExt;
+
+use crate::request::{FromRequest, Outcome, Request};
+use crate::trip_wire::TripWire;
+
+/// A request guard and future that resolves when a request is finished.
+///
+/// A `Disconnect` resolves once Rocket is done with the request: either its
+/// response has been completely written or the client disconnected first.
+/// [`Disconnect::is_disconnected()`] tells the two apart. Rocket detects that
+/// the client went away when writing the response fails and, while the
+/// handler is running, when the connection, or HTTP/2 stream, is closed as
+/// observed by the HTTP server. A client that closes only its half of the
+/// connection may go unnoticed until the response is written.
+///
+/// When a client disconnects while a streaming response is being written,
+/// Rocket immediately stops polling the response body and drops it, which in
+/// turn drops any generator producing it: code following the last `yield` in a
+/// [`stream!`](crate::response::stream::stream) never runs, but the values it
+/// owns, like a database cursor, are dropped as usual.
+///
+/// A `Disconnect` is useful for work that outlives the response body, such as
+/// a task spawned to fill a channel the body reads from, or to observe a
+/// disconnect for logging. Because it always resolves, such work can wait on it
+/// without outliving the request. Like [`Shutdown`](crate::Shutdown), it is
+/// `Clone`, and every clone resolves when the request is finished.
+///
+/// For requests dispatched via a [`local`](crate::local) client, a
+/// `Disconnect` resolves, without reporting a disconnect, when the
+/// `LocalResponse` is dropped.
+///
+/// # Outcomes
+///
+///   * **Success** always.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::Disconnect;
+/// use rocket::response::stream::TextStream;
+/// use rocket::tokio::{self, sync::mpsc};
+///
+/// #[get("/rows")]
+/// fn rows(disconnect: Disconnect) -> TextStream![String] {
+///     let (tx, mut rx) = mpsc::channel(16);
+///     tokio::spawn(async move {
+///         let produce = async {
+///             for row in 0.. {
+///                 if tx.send(row).await.is_err() {
+///                     break;
+///                 }
+///             }
+///         };
+///
+///         tokio::select! {
+///             _ = produce => (),
+///             _ = disconnect.clone() => if disconnect.is_disconnected() {
+///                 println!("client went away; stopping");
+///             },
+///         }
+///     });
+///
+///     TextStream! {
+///         while let Some(row) = rx.recv().await {
+///             yield format!("row {}\n", row);
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "`Disconnect` does nothing unless polled"]
+pub struct Disconnect {
+    finished: TripWire,
+    disconnected: Arc<AtomicBool>,
+}
+
+impl Disconnect {
+    pub(crate) fn new() -> Self {
+        Disconnect { finished: TripWire::new(), disconnected: Arc::default() }
+    }
+
+    /// Marks the request as finished, resolving every clone. `disconnected`
+    /// is only recorded if the request wasn't already finished.
+    pub(crate) fn finish(&self, disconnected: bool) {
+        if disconnected && !self.finished.tripped() {
+            self.disconnected.store(true, Ordering::Release);
+        }
+
+        self.finished.trip();
+    }
+
+    /// Returns `true` if the request is finished: its response was completely
+    /// written or the client disconnected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Disconnect;
+    ///
+    /// fn handler(disconnect: Disconnect) {
+    ///     // A handler's request can't be finished yet.
+    ///     assert!(!disconnect.is_finished());
+    /// }
+    /// ```
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished.tripped()
+    }
+
+    /// Returns `true` if the client disconnected before the response was
+    /// completely written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Disconnect;
+    ///
+    /// fn handler(disconnect: Disconnect) {
+    ///     if disconnect.is_disconnected() {
+    ///         println!("nobody is listening anymore");
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn is_disconnected(&self) -> bool {
+        self.disconnected.load(Ordering::Acquire)
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for Disconnect {
+    type Error = std::convert::Infallible;
+
+    #[inline]
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(request.state.disconnect.clone())
+    }
+}
+
+impl Future for Disconnect {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.finished.poll_unpin(cx)
+    }
+}
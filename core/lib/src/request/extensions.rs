@@ -0,0 +1,208 @@
+use std::fmt;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::request::{FromRequest, Outcome, Request};
+
+type AnyValue = Arc<dyn Any + Send + Sync>;
+
+/// A per-request map from types to values, shared by fairings, guards, and
+/// handlers.
+///
+/// Every request has its own `Extensions`, retrieved via
+/// [`Request::extensions()`] or as a request guard. Any component that sees
+/// the request can store a value of any `Send + Sync + 'static` type, and any
+/// component that runs later can retrieve it by type. A typical use is a
+/// fairing which resolves something once per request, like a tenant, for
+/// handlers to use without a custom request guard.
+///
+/// Unlike the request-local cache, [`Request::local_cache()`], whose value for
+/// a type is computed once and never changes, the value stored for a type in
+/// `Extensions` can be replaced and removed:
+///
+///   * [`insert()`](Extensions::insert()) stores a value, overwriting and
+///     returning any existing value of the same type.
+///   * [`try_insert()`](Extensions::try_insert()) stores a value only if there
+///     is no value of the same type yet, so the first store wins.
+///   * [`get()`](Extensions::get()) retrieves the current value.
+///   * [`remove()`](Extensions::remove()) removes and returns the value.
+///
+/// Values are retrieved as an `Arc<T>`. A value that has been retrieved thus
+/// remains valid, unchanged, even if it is later overwritten or removed.
+///
+/// # Thread-Safety
+///
+/// All methods take `&self` and may be called from any task or thread
+/// handling the request, including concurrently. Each call is atomic: a
+/// `get()` observes either the value before or after a concurrent `insert()`,
+/// never a mix. Sequences of calls are not atomic; use `try_insert()` instead
+/// of `get()` followed by `insert()` when only the first value should be kept.
+/// Values are not shared between requests: every request starts with empty
+/// `Extensions`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::fairing::AdHoc;
+/// use rocket::request::{Request, Extensions};
+///
+/// #[derive(Debug)]
+/// struct Tenant(String);
+///
+/// #[get("/")]
+/// fn index(extensions: &Extensions) -> String {
+///     match extensions.get::<Tenant>() {
+///         Some(tenant) => format!("Hello, {}!", tenant.0),
+///         None => "Hello, stranger!".into(),
+///     }
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .attach(AdHoc::on_request("Tenant", |req, _| Box::pin(async move {
+///             if let Some(tenant) = req.headers().get_one("X-Tenant") {
+///                 req.extensions().insert(Tenant(tenant.to_string()));
+///             }
+///         })))
+///         .mount("/", routes![index])
+/// }
+/// ```
+#[derive(Default)]
+pub struct Extensions {
+    map: Mutex<HashMap<TypeId, AnyValue>>,
+}
+
+impl Extensions {
+    /// Returns an empty map.
+    pub(crate) fn new() -> Self {
+        Extensions::default()
+    }
+
+    /// Stores `value`, replacing and returning the existing value of type
+    /// `T`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let extensions = request.extensions();
+    /// assert!(extensions.insert(1u8).is_none());
+    /// assert_eq!(extensions.insert(2u8).as_deref(), Some(&1));
+    /// assert_eq!(extensions.get::<u8>().as_deref(), Some(&2));
+    /// ```
+    pub fn insert<T: Send + Sync + 'static>(&self, value: T) -> Option<Arc<T>> {
+        let previous = self.map.lock().insert(TypeId::of::<T>(), Arc::new(value));
+        previous.map(downcast)
+    }
+
+    /// Stores `value` if there is no value of type `T` yet. Otherwise, leaves
+    /// the existing value in place and returns `value` in `Err`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let extensions = request.extensions();
+    /// assert_eq!(extensions.try_insert("first"), Ok(()));
+    /// assert_eq!(extensions.try_insert("second"), Err("second"));
+    /// assert_eq!(extensions.get::<&str>().as_deref(), Some(&"first"));
+    /// ```
+    pub fn try_insert<T: Send + Sync + 'static>(&self, value: T) -> Result<(), T> {
+        let mut map = self.map.lock();
+        if map.contains_key(&TypeId::of::<T>()) {
+            return Err(value);
+        }
+
+        map.insert(TypeId::of::<T>(), Arc::new(value));
+        Ok(())
+    }
+
+    /// Returns the value of type `T`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let extensions = request.extensions();
+    /// assert!(extensions.get::<String>().is_none());
+    ///
+    /// extensions.insert(String::from("hi"));
+    /// assert_eq!(extensions.get::<String>().unwrap().as_str(), "hi");
+    /// ```
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.map.lock().get(&TypeId::of::<T>()).cloned().map(downcast)
+    }
+
+    /// Returns `true` if there is a value of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let extensions = request.extensions();
+    /// assert!(!extensions.contains::<usize>());
+    ///
+    /// extensions.insert(10usize);
+    /// assert!(extensions.contains::<usize>());
+    /// ```
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.map.lock().contains_key(&TypeId::of::<T>())
+    }
+
+    /// Removes and returns the value of type `T`, if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// let extensions = request.extensions();
+    /// extensions.insert(10usize);
+    /// assert_eq!(extensions.remove::<usize>().as_deref(), Some(&10));
+    /// assert!(extensions.remove::<usize>().is_none());
+    /// ```
+    pub fn remove<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.map.lock().remove(&TypeId::of::<T>()).map(downcast)
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.map.lock().len()
+    }
+
+    /// Returns `true` if there are no stored values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Downcasts a value stored under the `TypeId` of `T` to `T`.
+fn downcast<T: Send + Sync + 'static>(value: AnyValue) -> Arc<T> {
+    value.downcast().expect("extension is stored under its own type id")
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r Extensions {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(request.extensions())
+    }
+}
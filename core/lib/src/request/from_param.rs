@@ -1,6 +1,8 @@
+use std::fmt;
 use std::str::FromStr;
 use std::path::PathBuf;
 
+use crate::Request;
 use crate::http::uri::{Segments, error::PathError, fmt::Path};
 
 /// Trait to convert a dynamic path segment string to a concrete value.
@@ -62,6 +64,51 @@ use crate::http::uri::{Segments, error::PathError, fmt::Path};
 /// # fn main() {  }
 /// ```
 ///
+/// # Reporting Errors
+///
+/// The error of a failed `from_param` call is logged and, for the first
+/// parameter of a request that fails to parse, recorded as a [`ParamError`]
+/// along with the parameter's name and the segment that failed. Catchers can
+/// retrieve it via [`ParamError::of()`]. The recorded reason is the `Debug`
+/// representation of the error, so implementations that want to give a useful
+/// reason should return a descriptive error, such as a `ParamError` itself.
+///
+/// By default, a request whose routes all forward is answered with a `404`,
+/// even if one forwarded because a parameter was invalid. An application can
+/// opt in to a different status, such as `400 Bad Request`, by registering a
+/// mapping for `ParamError` with [`Rocket::map_error()`]. The status is then
+/// used when no route succeeds or fails and a parameter failed to parse:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::Request;
+/// use rocket::http::Status;
+/// use rocket::request::ParamError;
+///
+/// #[get("/users/<id>")]
+/// fn user(id: u32) -> String {
+///     format!("user #{}", id)
+/// }
+///
+/// #[catch(400)]
+/// fn bad_request(req: &Request<'_>) -> String {
+///     match ParamError::of(req) {
+///         Some(error) => format!("Bad request: {}.", error),
+///         None => "Bad request.".into(),
+///     }
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .map_error(|_: &ParamError| Status::BadRequest)
+///         .mount("/", routes![user])
+///         .register("/", catchers![bad_request])
+/// }
+/// ```
+///
+/// [`Rocket::map_error()`]: crate::Rocket::map_error()
+///
 /// # Provided Implementations
 ///
 /// Rocket implements `FromParam` for several standard library types. Their
@@ -264,6 +311,197 @@ impl<'a, T: FromParam<'a>> FromParam<'a> for Option<T> {
     }
 }
 
+/// A descriptive error for a dynamic path parameter that failed to parse.
+///
+/// A `ParamError` pairs the path segment that failed to parse with the reason
+/// it failed. It can be returned as the [`FromParam::Error`] of custom types,
+/// and [`ParamError::parse()`] converts the errors of any [`FromStr`]
+/// implementation into one. Rocket also records a `ParamError` for the first
+/// parameter of a request that fails to parse, retrievable via
+/// [`ParamError::of()`]. See [Reporting Errors](FromParam#reporting-errors)
+/// for how the error can be surfaced to clients.
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::request::{FromParam, ParamError};
+///
+/// #[derive(Debug)]
+/// struct Id(u32);
+///
+/// impl<'a> FromParam<'a> for Id {
+///     type Error = ParamError;
+///
+///     fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+///         let digits = param.strip_prefix("id-")
+///             .ok_or_else(|| ParamError::new(param, "missing `id-` prefix"))?;
+///
+///         // Reuse the segment, not just the digits, in the error.
+///         digits.parse().map(Id).map_err(|e| ParamError::new(param, e))
+///     }
+/// }
+///
+/// assert_eq!(Id::from_param("id-12").unwrap().0, 12);
+///
+/// let error = Id::from_param("12").unwrap_err();
+/// assert_eq!(error.segment(), "12");
+/// assert_eq!(error.reason(), "missing `id-` prefix");
+///
+/// let error = Id::from_param("id-x").unwrap_err();
+/// assert_eq!(error.reason(), "invalid digit found in string");
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParamError {
+    name: Option<String>,
+    segment: String,
+    reason: String,
+}
+
+/// The parameter error that failed a request, kept in the request-local cache.
+pub(crate) struct FailedParam(pub ParamError);
+
+impl ParamError {
+    /// Creates an error for `segment` which failed to parse because of
+    /// `reason`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ParamError;
+    ///
+    /// let error = ParamError::new("abc", "expected a number");
+    /// assert_eq!(error.segment(), "abc");
+    /// assert_eq!(error.reason(), "expected a number");
+    /// assert_eq!(error.to_string(), "invalid segment \"abc\": expected a number");
+    /// ```
+    pub fn new<S: Into<String>, R: fmt::Display>(segment: S, reason: R) -> Self {
+        ParamError { name: None, segment: segment.into(), reason: reason.to_string() }
+    }
+
+    /// Parses `segment` into a `T` via [`FromStr`], converting the parse
+    /// error, if any, into a `ParamError` whose reason is the error's
+    /// `Display` representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{FromParam, ParamError};
+    ///
+    /// #[derive(Debug)]
+    /// struct Port(u16);
+    ///
+    /// impl<'a> FromParam<'a> for Port {
+    ///     type Error = ParamError;
+    ///
+    ///     fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+    ///         ParamError::parse(param).map(Port)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Port::from_param("8000").unwrap().0, 8000);
+    ///
+    /// let error = Port::from_param("80000").unwrap_err();
+    /// assert_eq!(error.segment(), "80000");
+    /// assert_eq!(error.reason(), "number too large to fit in target type");
+    /// ```
+    pub fn parse<T>(segment: &str) -> Result<T, ParamError>
+        where T: FromStr, T::Err: fmt::Display
+    {
+        segment.parse().map_err(|e| ParamError::new(segment, e))
+    }
+
+    /// Returns the error recorded for the first parameter of `req` that failed
+    /// to parse, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::Request;
+    /// use rocket::request::ParamError;
+    ///
+    /// #[catch(404)]
+    /// fn not_found(req: &Request<'_>) -> String {
+    ///     match ParamError::of(req) {
+    ///         Some(e) => format!("Invalid {}: {}", e.name().unwrap(), e.reason()),
+    ///         None => "Not found.".into(),
+    ///     }
+    /// }
+    /// ```
+    pub fn of<'r>(req: &'r Request<'_>) -> Option<&'r ParamError> {
+        req.state.cache.try_get::<FailedParam>().map(|failed| &failed.0)
+    }
+
+    /// Returns the name of the parameter that failed to parse. This is only
+    /// known, and thus `Some`, for errors recorded by Rocket.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ParamError;
+    ///
+    /// let error = ParamError::new("abc", "expected a number");
+    /// assert!(error.name().is_none());
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the path segment, or segments for a trailing parameter, that
+    /// failed to parse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ParamError;
+    ///
+    /// let error = ParamError::new("abc", "expected a number");
+    /// assert_eq!(error.segment(), "abc");
+    /// ```
+    pub fn segment(&self) -> &str {
+        &self.segment
+    }
+
+    /// Returns the reason the segment failed to parse.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ParamError;
+    ///
+    /// let error = ParamError::new("abc", "expected a number");
+    /// assert_eq!(error.reason(), "expected a number");
+    /// ```
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub(crate) fn named<E: fmt::Debug>(name: &str, segment: String, error: &E) -> Self {
+        let reason = format!("{:?}", error);
+        ParamError { name: Some(name.into()), segment, reason }
+    }
+}
+
+/// Writes only the reason so that, when a `ParamError` returned by
+/// `from_param` is recorded, the recorded reason is the original reason.
+impl fmt::Debug for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reason)
+    }
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref name) = self.name {
+            write!(f, "parameter `{}`: ", name)?;
+        }
+
+        write!(f, "invalid segment {:?}: {}", self.segment, self.reason)
+    }
+}
+
+impl std::error::Error for ParamError { }
+
 /// Trait to convert _many_ dynamic path segment strings to a concrete value.
 ///
 /// This is the `..` analog to [`FromParam`], and its functionality is identical
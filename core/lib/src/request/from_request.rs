@@ -2,10 +2,13 @@ use std::fmt::Debug;
 use std::net::{IpAddr, SocketAddr};
 
 use crate::{Request, Route};
+use crate::request::TlsInfo;
 use crate::outcome::{self, IntoOutcome};
 use crate::outcome::Outcome::*;
 
-use crate::http::{Status, ContentType, Accept, Method, CookieJar, uri::Origin};
+use crate::http::{Status, ContentType, Accept, Method, Version, CookieJar, RetryAfter, LinkSet};
+use crate::http::ContentRange;
+use crate::http::uri::Origin;
 
 /// Type alias for the `Outcome` of a `FromRequest` conversion.
 pub type Outcome<S, E> = outcome::Outcome<S, (Status, E), ()>;
@@ -123,6 +126,12 @@ impl<S, E> IntoOutcome<S, (Status, E), ()> for Result<S, E> {
 ///
 ///     _This implementation always returns successfully._
 ///
+///   * **Version**
+///
+///     Extracts the HTTP [`Version`] of the incoming request.
+///
+///     _This implementation always returns successfully._
+///
 ///   * **&Origin**
 ///
 ///     Extracts the [`Origin`] URI from the incoming request.
@@ -145,17 +154,90 @@ impl<S, E> IntoOutcome<S, (Status, E), ()> for Result<S, E> {
 ///
 ///     _This implementation always returns successfully._
 ///
+///   * **[`RawCookies`](crate::request::RawCookies)**
+///
+///     Extracts the raw, undecoded values of the request's `Cookie` headers,
+///     including any cookies that the `CookieJar` dropped as malformed.
+///
+///     _This implementation always returns successfully._
+///
 ///   * **&[`Config`]**
 ///
 ///     Extracts the application [`Config`].
 ///
 ///     _This implementation always returns successfully._
 ///
+///   * **&[`Figment`]**
+///
+///     Extracts the application's active [`Figment`], the one returned by
+///     [`Rocket::figment()`](crate::Rocket::figment()), allowing values without
+///     a typed counterpart to be extracted at request time.
+///
+///     _This implementation always returns successfully._
+///
 ///   * **ContentType**
 ///
 ///     Extracts the [`ContentType`] from the incoming request. If the request
 ///     didn't specify a Content-Type, the request is forwarded.
 ///
+///   * **&[`request::Accept`](crate::request::Accept)**
+///
+///     Extracts the media ranges, and their quality values, of the incoming
+///     request's `Accept` headers. Malformed ranges are skipped. For the
+///     strictly parsed [`http::Accept`](crate::http::Accept), which forwards
+///     if the request has no valid `Accept` header, use `&http::Accept`.
+///
+///     _This implementation always returns successfully._
+///
+///   * **RetryAfter**
+///
+///     Parses the `Retry-After` header of the incoming request as a
+///     [`RetryAfter`]. If the request didn't specify a `Retry-After`, the
+///     request is forwarded. If the header's value is malformed, fails with a
+///     status of **400 Bad Request**.
+///
+///   * **LinkSet**
+///
+///     Parses all `Link` headers of the incoming request into a single
+///     [`LinkSet`]. If the request didn't specify a `Link`, the request is
+///     forwarded. If any header's value is malformed, fails with a status of
+///     **400 Bad Request**.
+///
+///   * **ContentRange**
+///
+///     Parses the `Content-Range` header of the incoming request as a
+///     [`ContentRange`], typically sent with chunked or resumable uploads. If
+///     the request didn't specify a `Content-Range`, the request is forwarded.
+///     If the header's value is malformed or the range is invalid, that is,
+///     `start > end` or `end >= total`, fails with a status of **400 Bad
+///     Request**.
+///
+///   * **&TlsInfo**
+///
+///     Extracts the [`TlsInfo`] of the TLS session the request was received
+///     over, which includes the negotiated TLS version and ALPN protocol. If the
+///     request was not received over TLS, the request is forwarded.
+///
+///   * **ConnectionInfo**
+///
+///     Extracts the [`ConnectionInfo`] of the connection the request was
+///     received over, which includes the connection's addresses and the
+///     request's position on it. If the request was not received over a
+///     connection, as for local requests, the request is forwarded.
+///
+///   * **[`Disconnect`](crate::request::Disconnect)**
+///
+///     Returns a future that resolves when the request is finished: its
+///     response has been completely written or the client disconnected.
+///
+///     _This implementation always returns successfully._
+///
+///   * **&[`Extensions`](crate::request::Extensions)**
+///
+///     Returns the request's extensions.
+///
+///     _This implementation always returns successfully._
+///
 ///   * **IpAddr**
 ///
 ///     Extracts the client ip address of the incoming request as an [`IpAddr`].
@@ -185,6 +267,8 @@ impl<S, E> IntoOutcome<S, (Status, E), ()> for Result<S, E> {
 ///     forwarded.
 ///
 /// [`Config`]: crate::config::Config
+/// [`ConnectionInfo`]: crate::request::ConnectionInfo
+/// [`Figment`]: crate::figment::Figment
 ///
 /// # Example
 ///
@@ -392,6 +476,15 @@ impl<'r> FromRequest<'r> for Method {
     }
 }
 
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for Version {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Success(request.version())
+    }
+}
+
 #[crate::async_trait]
 impl<'r> FromRequest<'r> for &'r Origin<'r> {
     type Error = std::convert::Infallible;
@@ -446,6 +539,62 @@ impl<'r> FromRequest<'r> for &'r ContentType {
     }
 }
 
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RetryAfter {
+    type Error = String;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.headers().get_one("Retry-After") {
+            Some(value) => value.parse().into_outcome(Status::BadRequest),
+            None => Forward(())
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for LinkSet {
+    type Error = String;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let mut links = LinkSet::new();
+        for value in request.headers().get("Link") {
+            match value.parse::<LinkSet>() {
+                Ok(parsed) => links.extend(parsed),
+                Err(e) => return Failure((Status::BadRequest, e)),
+            }
+        }
+
+        match links.is_empty() && !request.headers().contains("Link") {
+            true => Forward(()),
+            false => Success(links),
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ContentRange {
+    type Error = String;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.headers().get_one("Content-Range") {
+            Some(value) => value.parse().into_outcome(Status::BadRequest),
+            None => Forward(())
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r TlsInfo {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.tls() {
+            Some(tls) => Success(tls),
+            None => Forward(())
+        }
+    }
+}
+
 #[crate::async_trait]
 impl<'r> FromRequest<'r> for IpAddr {
     type Error = std::convert::Infallible;
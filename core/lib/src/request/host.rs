@@ -0,0 +1,174 @@
+use std::fmt;
+
+use crate::http::{Status, Version};
+use crate::http::uri::Authority;
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the validated host and port the request was sent to.
+///
+/// The host is read from the authority of the request target when the target
+/// is in absolute form, as it is for HTTP/2 requests, which carry it in the
+/// `:authority` pseudo-header, and otherwise from the `Host` header. As RFC
+/// 7230 §5.4 requires, the authority takes precedence over any `Host` header
+/// sent alongside it. It is validated to be of the form
+/// `host[:port]`, where `host` is a registered name, an IPv4 address, or an
+/// IPv6 address enclosed in brackets, and against the
+/// [`allowed_hosts`](crate::Config::allowed_hosts) allowlist, if one is
+/// configured.
+///
+/// # Allowlist
+///
+/// When `allowed_hosts` is non-empty, the host must match one of its entries:
+///
+///   * An entry of `example.com` matches the host `example.com`, on any port.
+///   * An entry of `example.com:8000` matches `example.com` on port `8000`
+///     only. A `Host` without an explicit port never matches such an entry.
+///   * An entry starting with `*.`, such as `*.example.com`, matches any
+///     subdomain of `example.com`, but not `example.com` itself.
+///
+/// Host names are compared case-insensitively. By default, `allowed_hosts` is
+/// empty, and all hosts are allowed.
+///
+/// # Outcomes
+///
+///   * **Success** if the host is valid and allowed.
+///   * **Forward** if the request is an HTTP/1.0, or earlier, request without
+///     a host. Before HTTP/1.1, the `Host` header is optional.
+///   * **Failure** with `400 Bad Request` if the host is missing from a
+///     request of any other version or is malformed.
+///   * **Failure** with `421 Misdirected Request` if the host is valid but not
+///     allowed by `allowed_hosts`.
+///
+/// As such, `Option<Host>` can be used to accept HTTP/1.0 requests without a
+/// host, while other invalid hosts are still rejected.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::Host;
+///
+/// #[get("/")]
+/// fn index(host: Host<'_>) -> String {
+///     match host.port() {
+///         Some(port) => format!("Hello, {} on port {}!", host.host(), port),
+///         None => format!("Hello, {}!", host.host()),
+///     }
+/// }
+/// ```
+///
+/// With the following configuration, requests with a host other than
+/// `example.com` or one of its subdomains fail with a `421` when routed to
+/// `index`:
+///
+/// ```toml
+/// [default]
+/// allowed_hosts = ["example.com", "*.example.com"]
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Host<'r>(Authority<'r>);
+
+impl<'r> Host<'r> {
+    pub(crate) fn parse(value: &'r str) -> Option<Host<'r>> {
+        match Authority::parse(value) {
+            Ok(authority) if authority.user_info().is_none() => Some(Host(authority)),
+            _ => None,
+        }
+    }
+
+    /// Returns the host part, without the port. IPv6 addresses are returned
+    /// with their enclosing brackets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Host;
+    ///
+    /// fn handler(host: Host<'_>) {
+    ///     let name = host.host();
+    /// }
+    /// ```
+    pub fn host(&self) -> &str {
+        self.0.host()
+    }
+
+    /// Returns the port, if one was explicitly specified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::Host;
+    ///
+    /// fn handler(host: Host<'_>) {
+    ///     let port = host.port().unwrap_or(80);
+    /// }
+    /// ```
+    pub fn port(&self) -> Option<u16> {
+        self.0.port()
+    }
+
+    /// Returns `true` if `self` matches the allowlist entry `entry`. Malformed
+    /// entries match no hosts.
+    pub(crate) fn matches(&self, entry: &str) -> bool {
+        let entry = match Authority::parse(entry) {
+            Ok(entry) if entry.user_info().is_none() => entry,
+            _ => return false,
+        };
+
+        if entry.port().is_some() && entry.port() != self.port() {
+            return false;
+        }
+
+        let (host, pattern) = (self.host(), entry.host());
+        match pattern.strip_prefix("*.") {
+            Some(suffix) if host.len() > suffix.len() + 1 => {
+                let (sub, rest) = host.split_at(host.len() - suffix.len());
+                sub.ends_with('.') && rest.eq_ignore_ascii_case(suffix)
+            }
+            Some(_) => false,
+            None => host.eq_ignore_ascii_case(pattern),
+        }
+    }
+
+    /// Returns `true` if `self` is allowed by `allowed`, the configured
+    /// allowlist. An empty allowlist allows every host.
+    pub(crate) fn is_allowed(&self, allowed: &[String]) -> bool {
+        allowed.is_empty() || allowed.iter().any(|entry| self.matches(entry))
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for Host<'r> {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let value = match request.raw_host() {
+            Some(value) => value,
+            None if request.version() <= Version::Http10 => return Outcome::Forward(()),
+            None => return Outcome::Failure((Status::BadRequest, "missing host")),
+        };
+
+        let host = match Host::parse(value) {
+            Some(host) => host,
+            None => return Outcome::Failure((Status::BadRequest, "malformed host")),
+        };
+
+        if !host.is_allowed(&request.rocket().config().allowed_hosts) {
+            warn_!("Host {:?} is not in the `allowed_hosts` allowlist.", value);
+            return Outcome::Failure((Status::MisdirectedRequest, "disallowed host"));
+        }
+
+        Outcome::Success(host)
+    }
+}
+
+impl fmt::Display for Host<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.host().fmt(f)?;
+        if let Some(port) = self.port() {
+            write!(f, ":{}", port)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use std::ops::RangeInclusive;
+
+use crate::http::Status;
+use crate::request::{Request, FromRequest, Outcome, RangeHeader, RangeSpec};
+
+/// Request guard for `Range: items=..` pagination.
+///
+/// Some APIs paginate collections with a `Range` header in the `items` unit,
+/// such as `Range: items=0-24`, answering with the requested slice of the
+/// collection in a `206 Partial Content` response and a `Content-Range:
+/// items 0-24/100` header. An `ItemRange` is the parsed request half of such
+/// an exchange; [`Paginated`](crate::response::Paginated) is the response half.
+///
+/// An `ItemRange` is a single range of item indices, counting from `0`,
+/// that is either closed, `items=first-last`, or open-ended, `items=first-`,
+/// requesting every item from `first` onwards. Suffix ranges (`items=-10`)
+/// and lists of ranges are not supported.
+///
+/// # Outcomes
+///
+///   * **Success** if the request contains a well-formed `items` range.
+///   * **Forward** if the request contains no `Range` header or one whose unit
+///     isn't `items`.
+///   * **Failure** with `400 Bad Request` if the `items` range is malformed,
+///     is a suffix range, or is a list of ranges, or if its first index is
+///     after its last.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::ItemRange;
+/// use rocket::response::Paginated;
+///
+/// const ITEMS: &[&str] = &["a", "b", "c", "d", "e"];
+///
+/// #[get("/items")]
+/// fn items(range: Option<ItemRange>) -> Option<Paginated<String>> {
+///     // Serve at most 2 items at a time, starting at the first by default.
+///     let range = range.unwrap_or_default().limit(2);
+///     let total = ITEMS.len() as u64;
+///     let indices = range.resolve(total)?;
+///
+///     let (start, end) = (*indices.start() as usize, *indices.end() as usize);
+///     Paginated::new(ITEMS[start..=end].join(","), indices, Some(total))
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ItemRange {
+    start: u64,
+    end: Option<u64>,
+}
+
+impl ItemRange {
+    /// Returns a new `ItemRange` for the items `start` through `end`,
+    /// inclusive, or from `start` onwards if `end` is `None`. Returns `None`
+    /// if `start > end`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// assert!(ItemRange::new(0, Some(24)).is_some());
+    /// assert!(ItemRange::new(25, None).is_some());
+    /// assert!(ItemRange::new(24, Some(0)).is_none());
+    /// ```
+    pub fn new(start: u64, end: Option<u64>) -> Option<ItemRange> {
+        match end {
+            Some(end) if start > end => None,
+            _ => Some(ItemRange { start, end }),
+        }
+    }
+
+    /// Parses the value of a `Range` header. Returns `None` if the value is
+    /// malformed, isn't in the `items` unit, or isn't a single closed or
+    /// open-ended range. The unit is matched case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// let range = ItemRange::parse("items=0-24").unwrap();
+    /// assert_eq!((range.start(), range.end()), (0, Some(24)));
+    ///
+    /// let range = ItemRange::parse("Items=25-").unwrap();
+    /// assert_eq!((range.start(), range.end()), (25, None));
+    ///
+    /// assert!(ItemRange::parse("items=-10").is_none());
+    /// assert!(ItemRange::parse("items=0-9, 20-29").is_none());
+    /// assert!(ItemRange::parse("bytes=0-24").is_none());
+    /// ```
+    pub fn parse(value: &str) -> Option<ItemRange> {
+        let range = RangeHeader::parse(value)?;
+        if !range.unit().eq_ignore_ascii_case("items") {
+            return None;
+        }
+
+        match *range.ranges() {
+            [RangeSpec::FromTo(start, end)] => ItemRange::new(start, Some(end)),
+            [RangeSpec::From(start)] => ItemRange::new(start, None),
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the first requested item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// assert_eq!(ItemRange::parse("items=10-19").unwrap().start(), 10);
+    /// ```
+    pub fn start(&self) -> u64 {
+        self.start
+    }
+
+    /// Returns the index of the last requested item, or `None` if the range
+    /// is open-ended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// assert_eq!(ItemRange::parse("items=10-19").unwrap().end(), Some(19));
+    /// assert_eq!(ItemRange::parse("items=10-").unwrap().end(), None);
+    /// ```
+    pub fn end(&self) -> Option<u64> {
+        self.end
+    }
+
+    /// Limits the range to at most `max` items, closing an open-ended range.
+    /// A range of fewer items is returned as is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// let range = ItemRange::parse("items=10-").unwrap().limit(25);
+    /// assert_eq!(range.end(), Some(34));
+    ///
+    /// let range = ItemRange::parse("items=10-19").unwrap().limit(25);
+    /// assert_eq!(range.end(), Some(19));
+    /// ```
+    pub fn limit(self, max: u64) -> ItemRange {
+        assert!(max > 0, "an item range must be limited to at least one item");
+        let last = self.start.saturating_add(max - 1);
+        ItemRange { end: Some(self.end.map_or(last, |end| end.min(last))), ..self }
+    }
+
+    /// Resolves the range against a collection of `total` items, returning
+    /// the inclusive range of indices it selects with the last index clamped
+    /// to `total - 1`. Returns `None` if the range is unsatisfiable, that is,
+    /// if it starts at or after `total`, warranting a
+    /// `416 Range Not Satisfiable` response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::ItemRange;
+    ///
+    /// let range = ItemRange::parse("items=10-19").unwrap();
+    /// assert_eq!(range.resolve(100), Some(10..=19));
+    /// assert_eq!(range.resolve(15), Some(10..=14));
+    /// assert_eq!(range.resolve(10), None);
+    ///
+    /// let range = ItemRange::parse("items=10-").unwrap();
+    /// assert_eq!(range.resolve(100), Some(10..=99));
+    /// ```
+    pub fn resolve(&self, total: u64) -> Option<RangeInclusive<u64>> {
+        if self.start >= total {
+            return None;
+        }
+
+        Some(self.start..=self.end.map_or(total - 1, |end| end.min(total - 1)))
+    }
+}
+
+/// The range of all items: `items=0-`.
+impl Default for ItemRange {
+    fn default() -> Self {
+        ItemRange { start: 0, end: None }
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ItemRange {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let value = match request.headers().get_one("Range") {
+            Some(value) => value,
+            None => return Outcome::Forward(()),
+        };
+
+        let unit = value.split_once('=').map(|(unit, _)| unit.trim());
+        if !matches!(unit, Some(unit) if unit.eq_ignore_ascii_case("items")) {
+            return Outcome::Forward(());
+        }
+
+        match ItemRange::parse(value) {
+            Some(range) => Outcome::Success(range),
+            None => Outcome::Failure((Status::BadRequest, "malformed item range")),
+        }
+    }
+}
@@ -3,13 +3,46 @@
 mod request;
 mod from_param;
 mod from_request;
+mod host;
+mod basic_auth;
+mod bearer_token;
+mod origin_header;
+mod range_header;
+mod item_range;
+mod timing;
+mod connection;
+mod accept;
+mod raw_cookies;
+mod disconnect;
+mod extensions;
 
 #[cfg(test)]
 mod tests;
 
 pub use self::request::Request;
 pub use self::from_request::{FromRequest, Outcome};
-pub use self::from_param::{FromParam, FromSegments};
+pub use self::from_param::{FromParam, FromSegments, ParamError};
+pub(crate) use self::from_param::FailedParam;
+pub use self::host::Host;
+pub use self::basic_auth::BasicAuth;
+pub use self::bearer_token::BearerToken;
+pub use self::origin_header::OriginHeader;
+pub use self::range_header::{RangeHeader, RangeSpec};
+pub use self::item_range::ItemRange;
+pub use self::timing::RequestTiming;
+pub use self::connection::ConnectionInfo;
+pub use self::accept::Accept;
+pub use self::raw_cookies::RawCookies;
+pub use self::disconnect::Disconnect;
+pub use self::extensions::Extensions;
+
+#[doc(inline)]
+pub use crate::http::private::TlsInfo;
+
+#[doc(inline)]
+pub use crate::http::ContentRange;
+
+pub(crate) use self::request::ConnectionMeta;
 
 #[doc(inline)]
 pub use crate::response::flash::FlashMessage;
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::http::Status;
+use crate::http::uri::Absolute;
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the `Origin` request header, as sent by browsers with
+/// cross-origin (CORS) requests.
+///
+/// The `Origin` _header_ names the origin that caused a request to be made: a
+/// scheme, a host, and an optional port, such as `https://example.com:8443`. It
+/// should not be confused with [`uri::Origin`](crate::http::uri::Origin), the
+/// URI type for paths with optional queries like `/foo?bar`, which is what a
+/// [`Request`] is targeted at.
+///
+/// As described in [RFC 6454], the header value is either serialized as
+/// `scheme://host[:port]` or is the literal `null`, which browsers send when
+/// the origin is opaque, for instance, for sandboxed documents. A `null` origin
+/// is parsed successfully and can be identified via
+/// [`OriginHeader::is_null()`]; its scheme and host are `None`.
+///
+/// [RFC 6454]: https://tools.ietf.org/html/rfc6454#section-7
+///
+/// # Outcomes
+///
+///   * **Success** if the request contains a well-formed `Origin` header.
+///   * **Forward** if the request contains no `Origin` header, as is the case
+///     for most same-origin and non-browser requests.
+///   * **Failure** with `400 Bad Request` if the header is malformed, including
+///     if it contains a path, query, or user information.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::OriginHeader;
+///
+/// #[get("/")]
+/// fn index(origin: Option<OriginHeader<'_>>) -> String {
+///     match origin.as_ref().and_then(|o| o.host()) {
+///         Some(host) => format!("Hello, visitor from {}!", host),
+///         None => "Hello, visitor!".into(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OriginHeader<'r>(Option<Absolute<'r>>);
+
+impl<'r> OriginHeader<'r> {
+    fn parse(value: &'r str) -> Option<OriginHeader<'r>> {
+        let value = value.trim();
+        if value == "null" {
+            return Some(OriginHeader(None));
+        }
+
+        let uri = Absolute::parse(value).ok()?;
+        let authority = uri.authority()?;
+        if authority.user_info().is_some() || authority.host().is_empty() {
+            return None;
+        }
+
+        if !uri.path().as_str().is_empty() || uri.query().is_some() {
+            return None;
+        }
+
+        Some(OriginHeader(Some(uri)))
+    }
+
+    /// Returns `true` if the origin is the opaque `null` origin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::OriginHeader;
+    ///
+    /// fn handler(origin: OriginHeader<'_>) {
+    ///     if origin.is_null() {
+    ///         /* reject the request */
+    ///     }
+    /// }
+    /// ```
+    pub fn is_null(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the scheme, such as `https`, or `None` for a `null` origin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::OriginHeader;
+    ///
+    /// fn handler(origin: OriginHeader<'_>) {
+    ///     let is_secure = origin.scheme() == Some("https");
+    /// }
+    /// ```
+    pub fn scheme(&self) -> Option<&str> {
+        self.0.as_ref().map(|uri| uri.scheme())
+    }
+
+    /// Returns the host, or `None` for a `null` origin. IPv6 addresses are
+    /// returned with their enclosing brackets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::OriginHeader;
+    ///
+    /// fn handler(origin: OriginHeader<'_>) {
+    ///     let host = origin.host();
+    /// }
+    /// ```
+    pub fn host(&self) -> Option<&str> {
+        self.0.as_ref().and_then(|uri| uri.authority()).map(|a| a.host())
+    }
+
+    /// Returns the port, if one was explicitly specified. Browsers omit the
+    /// port when it is the default port for the scheme.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::OriginHeader;
+    ///
+    /// fn handler(origin: OriginHeader<'_>) {
+    ///     let port = origin.port();
+    /// }
+    /// ```
+    pub fn port(&self) -> Option<u16> {
+        self.0.as_ref().and_then(|uri| uri.authority()).and_then(|a| a.port())
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for OriginHeader<'r> {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let value = match request.headers().get_one("Origin") {
+            Some(value) => value,
+            None => return Outcome::Forward(()),
+        };
+
+        match OriginHeader::parse(value) {
+            Some(origin) => Outcome::Success(origin),
+            None => Outcome::Failure((Status::BadRequest, "malformed origin header")),
+        }
+    }
+}
+
+/// Formats the origin as it is serialized in an `Origin` header. The result is
+/// suitable for echoing in an `Access-Control-Allow-Origin` header.
+impl fmt::Display for OriginHeader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(ref uri) => uri.fmt(f),
+            None => "null".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::ops::RangeInclusive;
+
+use crate::http::Status;
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the parsed `Range` request header.
+///
+/// A `Range` header, as described in [RFC 7233], names a unit, almost always
+/// `bytes`, and one or more ranges of the representation the client wants to
+/// receive, such as `bytes=0-99, 500-`. A `RangeHeader` exposes both without
+/// deciding how to respond. [`FileServer`](crate::fs::FileServer) handles
+/// single byte ranges on its own; `RangeHeader` is for handlers that implement
+/// their own partial-content behavior.
+///
+/// Each range is a [`RangeSpec`]: `first-last`, `first-`, or a _suffix_ range
+/// `-len` of the last `len` units. Ranges are kept in the order they were sent. Use
+/// [`RangeHeader::normalize()`] to resolve them against the length of a
+/// representation.
+///
+/// [RFC 7233]: https://tools.ietf.org/html/rfc7233#section-3.1
+///
+/// # Outcomes
+///
+///   * **Success** if the request contains a well-formed `Range` header.
+///   * **Forward** if the request contains no `Range` header.
+///   * **Failure** with `400 Bad Request` if the header is malformed, including
+///     if a range's first position is after its last position.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::RangeHeader;
+///
+/// const DATA: &[u8] = b"Hello, world!";
+///
+/// #[get("/data", rank = 1)]
+/// fn partial(range: RangeHeader<'_>) -> Option<Vec<u8>> {
+///     let ranges = match range.is_bytes() {
+///         true => range.normalize(DATA.len() as u64),
+///         false => return None,
+///     };
+///
+///     // Serve the first satisfiable range, if there is one.
+///     let range = ranges.first()?;
+///     let (start, end) = (*range.start() as usize, *range.end() as usize);
+///     Some(DATA[start..=end].to_vec())
+/// }
+///
+/// #[get("/data", rank = 2)]
+/// fn full() -> &'static [u8] {
+///     DATA
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeHeader<'r> {
+    unit: &'r str,
+    ranges: Vec<RangeSpec>,
+}
+
+/// A single range in a [`RangeHeader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeSpec {
+    /// `first-last`: the positions from `first` to `last`, inclusive.
+    FromTo(u64, u64),
+    /// `first-`: the positions from `first` to the end.
+    From(u64),
+    /// `-len`: the last `len` units.
+    Suffix(u64),
+}
+
+impl<'r> RangeHeader<'r> {
+    /// Parses the value of a `Range` header. Returns `None` if the value is
+    /// malformed. Ranges of units other than `bytes` are expected to use the
+    /// same syntax as byte ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::{RangeHeader, RangeSpec};
+    ///
+    /// let range = RangeHeader::parse("bytes=0-99, 500-, -20").unwrap();
+    /// assert_eq!(range.unit(), "bytes");
+    /// assert_eq!(range.ranges(), &[
+    ///     RangeSpec::FromTo(0, 99),
+    ///     RangeSpec::From(500),
+    ///     RangeSpec::Suffix(20),
+    /// ]);
+    ///
+    /// assert!(RangeHeader::parse("bytes=99-0").is_none());
+    /// assert!(RangeHeader::parse("bytes=").is_none());
+    /// assert!(RangeHeader::parse("0-99").is_none());
+    /// ```
+    pub fn parse(value: &'r str) -> Option<RangeHeader<'r>> {
+        let (unit, spec) = value.trim().split_once('=')?;
+        let unit = unit.trim();
+        if unit.is_empty() || unit.contains(|c: char| c.is_whitespace()) {
+            return None;
+        }
+
+        let ranges = spec.split(',')
+            .map(|range| range.trim())
+            .filter(|range| !range.is_empty())
+            .map(parse_range)
+            .collect::<Option<Vec<_>>>()?;
+
+        match ranges.is_empty() {
+            true => None,
+            false => Some(RangeHeader { unit, ranges }),
+        }
+    }
+
+    /// Returns the range unit, such as `bytes`, as it was sent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RangeHeader;
+    ///
+    /// let range = RangeHeader::parse("items=0-9").unwrap();
+    /// assert_eq!(range.unit(), "items");
+    /// ```
+    pub fn unit(&self) -> &'r str {
+        self.unit
+    }
+
+    /// Returns `true` if the unit is, case-insensitively, `bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RangeHeader;
+    ///
+    /// assert!(RangeHeader::parse("Bytes=0-9").unwrap().is_bytes());
+    /// assert!(!RangeHeader::parse("items=0-9").unwrap().is_bytes());
+    /// ```
+    pub fn is_bytes(&self) -> bool {
+        self.unit.eq_ignore_ascii_case("bytes")
+    }
+
+    /// Returns the requested ranges in the order they were sent.
+    pub fn ranges(&self) -> &[RangeSpec] {
+        &self.ranges
+    }
+
+    /// Resolves the ranges against a representation of length `len`.
+    ///
+    /// Each range is converted to the inclusive range of positions it selects,
+    /// with its last position clamped to `len - 1`. Unsatisfiable ranges, those
+    /// starting at or after `len` and empty suffix ranges, are removed. If the
+    /// returned vector is empty, none of the ranges are satisfiable, warranting
+    /// a `416 Range Not Satisfiable` response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RangeHeader;
+    ///
+    /// let range = RangeHeader::parse("bytes=0-99, 500-, -20, 10-2000").unwrap();
+    /// assert_eq!(range.normalize(1000), vec![0..=99, 500..=999, 980..=999, 10..=999]);
+    /// assert_eq!(range.normalize(100), vec![0..=99, 80..=99, 10..=99]);
+    /// assert!(range.normalize(0).is_empty());
+    /// ```
+    pub fn normalize(&self, len: u64) -> Vec<RangeInclusive<u64>> {
+        self.ranges.iter()
+            .filter_map(|&range| resolve(range, len))
+            .collect()
+    }
+}
+
+fn parse_range(range: &str) -> Option<RangeSpec> {
+    let (first, last) = range.split_once('-')?;
+    let (first, last) = (first.trim(), last.trim());
+    match (first.is_empty(), last.is_empty()) {
+        (true, false) => Some(RangeSpec::Suffix(parse_pos(last)?)),
+        (false, true) => Some(RangeSpec::From(parse_pos(first)?)),
+        (false, false) => {
+            let (first, last) = (parse_pos(first)?, parse_pos(last)?);
+            if first > last {
+                return None;
+            }
+
+            Some(RangeSpec::FromTo(first, last))
+        }
+        (true, true) => None,
+    }
+}
+
+/// Parses a position, which must be all ASCII digits: `u64::from_str` would
+/// also accept a leading `+`.
+fn parse_pos(pos: &str) -> Option<u64> {
+    match pos.bytes().all(|b| b.is_ascii_digit()) {
+        true => pos.parse().ok(),
+        false => None,
+    }
+}
+
+fn resolve(range: RangeSpec, len: u64) -> Option<RangeInclusive<u64>> {
+    match range {
+        RangeSpec::FromTo(first, _) | RangeSpec::From(first) if first >= len => None,
+        RangeSpec::FromTo(first, last) => Some(first..=last.min(len - 1)),
+        RangeSpec::From(first) => Some(first..=len - 1),
+        RangeSpec::Suffix(0) => None,
+        RangeSpec::Suffix(_) if len == 0 => None,
+        RangeSpec::Suffix(n) => Some(len.saturating_sub(n)..=len - 1),
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RangeHeader<'r> {
+    type Error = &'static str;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let value = match request.headers().get_one("Range") {
+            Some(value) => value,
+            None => return Outcome::Forward(()),
+        };
+
+        match RangeHeader::parse(value) {
+            Some(range) => Outcome::Success(range),
+            None => Outcome::Failure((Status::BadRequest, "malformed range header")),
+        }
+    }
+}
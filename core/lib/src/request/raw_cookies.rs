@@ -0,0 +1,154 @@
+use std::fmt;
+use std::convert::Infallible;
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the raw, undecoded value of the request's `Cookie`
+/// header(s).
+///
+/// Rocket parses the `Cookie` header into the request's
+/// [`CookieJar`](crate::http::CookieJar), dropping any cookie that fails to
+/// parse. `RawCookies` is an escape hatch for clients that send such malformed
+/// or otherwise non-standard cookies: it exposes the header values exactly as
+/// they were received so that they can be parsed with custom logic. The
+/// `CookieJar` remains the recommended way to read cookies; it decodes values
+/// and transparently handles private cookies, neither of which `RawCookies`
+/// does.
+///
+/// A request may carry more than one `Cookie` header, as is common with
+/// HTTP/2. [`RawCookies::headers()`] returns each value individually, while
+/// the `Display` implementation joins them with `; `, as would be done to
+/// combine them into a single header.
+///
+/// # Outcomes
+///
+///   * **Success** always. If the request has no `Cookie` header, the guard
+///     [is empty](RawCookies::is_empty()).
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::RawCookies;
+///
+/// #[get("/")]
+/// fn index(raw: RawCookies<'_>) -> String {
+///     // A legacy client sends `session` with a malformed value.
+///     match raw.get("session") {
+///         Some(value) => format!("raw session: {}", value),
+///         None => "no session".into(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawCookies<'r>(Vec<&'r str>);
+
+impl<'r> RawCookies<'r> {
+    /// Returns an iterator over the values of every `Cookie` header in the
+    /// request, in the order they were received.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RawCookies;
+    ///
+    /// fn handler(raw: RawCookies<'_>) {
+    ///     for header in raw.headers() {
+    ///         println!("Cookie: {}", header);
+    ///     }
+    /// }
+    /// ```
+    pub fn headers(&self) -> impl Iterator<Item = &'r str> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns an iterator over the `(name, value)` pairs in every `Cookie`
+    /// header, in the order they were received.
+    ///
+    /// Pairs are separated by `;` and are split at the first `=`, with
+    /// surrounding whitespace trimmed from both halves. Nothing else is done:
+    /// values are neither percent-decoded nor unquoted, and no pair is
+    /// rejected. A pair without an `=` has an empty value, and empty pairs are
+    /// skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RawCookies;
+    ///
+    /// fn handler(raw: RawCookies<'_>) {
+    ///     for (name, value) in raw.pairs() {
+    ///         println!("{} = {}", name, value);
+    ///     }
+    /// }
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (&'r str, &'r str)> + '_ {
+        self.headers()
+            .flat_map(|header| header.split(';'))
+            .map(str::trim)
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair.find('=') {
+                Some(i) => (pair[..i].trim(), pair[(i + 1)..].trim()),
+                None => (pair, ""),
+            })
+    }
+
+    /// Returns the raw value of the first cookie named exactly `name`, as
+    /// split by [`RawCookies::pairs()`], if there is one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RawCookies;
+    ///
+    /// fn handler(raw: RawCookies<'_>) {
+    ///     if let Some(value) = raw.get("theme") {
+    ///         println!("raw theme: {}", value);
+    ///     }
+    /// }
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&'r str> {
+        self.pairs().find(|(n, _)| *n == name).map(|(_, v)| v)
+    }
+
+    /// Returns `true` if the request has no `Cookie` header or only empty
+    /// ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RawCookies;
+    ///
+    /// fn handler(raw: RawCookies<'_>) {
+    ///     if raw.is_empty() {
+    ///         println!("the client sent no cookies");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.headers().all(|header| header.trim().is_empty())
+    }
+}
+
+impl fmt::Display for RawCookies<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, header) in self.headers().enumerate() {
+            if i != 0 {
+                f.write_str("; ")?;
+            }
+
+            f.write_str(header)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RawCookies<'r> {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(RawCookies(req.headers().get("Cookie").collect()))
+    }
+}
@@ -1,20 +1,25 @@
 use std::fmt;
 use std::ops::RangeFrom;
-use std::{future::Future, borrow::Cow, sync::Arc};
+use std::{future::Future, borrow::Cow, sync::{Arc, atomic::AtomicUsize}};
 use std::net::{IpAddr, SocketAddr};
 
 use yansi::Paint;
 use state::{Container, Storage};
 use futures::future::BoxFuture;
 use atomic::{Atomic, Ordering};
+use either::Either;
+use parking_lot::Mutex;
 
 // use crate::request::{FromParam, FromSegments, FromRequest, Outcome};
-use crate::request::{FromParam, FromSegments, FromRequest, Outcome};
+use crate::request::{FromParam, FromSegments, FromRequest, Outcome, local_cache};
+use crate::request::{ParamError, FailedParam};
+use crate::request::{ConnectionInfo, Disconnect, Extensions};
 use crate::form::{self, ValueField, FromForm};
 
-use crate::{Rocket, Route, Orbit};
+use crate::{Rocket, Route, Orbit, Response};
 use crate::http::{hyper, uri::{Origin, Segments, fmt::Path}, uncased::UncasedStr};
-use crate::http::{Method, Header, HeaderMap};
+use crate::http::{Method, Version, Header, HeaderMap, Status, Challenge};
+use crate::http::private::{Connection, TlsInfo};
 use crate::http::{ContentType, Accept, MediaType, CookieJar, Cookie};
 use crate::data::Limits;
 
@@ -27,11 +32,63 @@ use crate::data::Limits;
 pub struct Request<'r> {
     method: Atomic<Method>,
     uri: Origin<'r>,
+    raw_uri: Option<Cow<'r, str>>,
     headers: HeaderMap<'r>,
     remote: Option<SocketAddr>,
+    version: Version,
+    tls: Option<TlsInfo>,
+    connection: Option<ConnectionInfo>,
     pub(crate) state: RequestState<'r>,
 }
 
+/// Metadata about the connection a request arrived on.
+#[derive(Debug, Clone)]
+pub(crate) struct ConnectionMeta {
+    pub remote: SocketAddr,
+    pub local: Option<SocketAddr>,
+    pub tls: Option<TlsInfo>,
+    /// The number of requests received on the connection so far, shared by
+    /// every clone of the metadata for the connection.
+    pub requests: Arc<AtomicUsize>,
+}
+
+/// The authentication challenge recorded for a request, kept in the
+/// request-local cache.
+struct RecordedChallenge(Challenge);
+
+/// The missing `#[field(required)]` query fields that failed a request, kept in
+/// the request-local cache.
+struct MissingQueryFields(Vec<String>);
+
+/// The formats expected by the `strict_format` routes that failed a request,
+/// kept in the request-local cache.
+struct ExpectedFormats(Vec<MediaType>);
+
+/// How a request fairing halted a request: with an error status, to be handled
+/// by a catcher, or with a response.
+pub(crate) struct Halt {
+    pub status: Status,
+    response: Mutex<Option<Response<'static>>>,
+}
+
+impl Halt {
+    /// Takes the response the request was halted with, if any.
+    pub fn take_response(&self) -> Option<Response<'static>> {
+        self.response.lock().take()
+    }
+}
+
+impl<C: Connection> From<&C> for ConnectionMeta {
+    fn from(conn: &C) -> Self {
+        ConnectionMeta {
+            remote: conn.remote_addr().unwrap_or_else(|| ([0, 0, 0, 0], 0).into()),
+            local: conn.local_addr(),
+            tls: conn.tls_info(),
+            requests: Arc::default(),
+        }
+    }
+}
+
 pub(crate) struct RequestState<'r> {
     pub rocket: &'r Rocket<Orbit>,
     pub route: Atomic<Option<&'r Route>>,
@@ -39,6 +96,10 @@ pub(crate) struct RequestState<'r> {
     pub accept: Storage<Option<Accept>>,
     pub content_type: Storage<Option<ContentType>>,
     pub cache: Arc<Container![Send + Sync]>,
+    pub extensions: Arc<Extensions>,
+    pub halted: Option<Arc<Halt>>,
+    pub skip_fairings: bool,
+    pub disconnect: Disconnect,
 }
 
 impl Request<'_> {
@@ -46,8 +107,12 @@ impl Request<'_> {
         Request {
             method: Atomic::new(self.method()),
             uri: self.uri.clone(),
+            raw_uri: self.raw_uri.clone(),
             headers: self.headers.clone(),
             remote: self.remote.clone(),
+            version: self.version,
+            tls: self.tls.clone(),
+            connection: self.connection,
             state: self.state.clone(),
         }
     }
@@ -62,6 +127,10 @@ impl RequestState<'_> {
             accept: self.accept.clone(),
             content_type: self.content_type.clone(),
             cache: self.cache.clone(),
+            extensions: self.extensions.clone(),
+            halted: self.halted.clone(),
+            skip_fairings: self.skip_fairings,
+            disconnect: self.disconnect.clone(),
         }
     }
 }
@@ -76,9 +145,13 @@ impl<'r> Request<'r> {
     ) -> Request<'r> {
         Request {
             uri,
+            raw_uri: None,
             method: Atomic::new(method),
             headers: HeaderMap::new(),
             remote: None,
+            version: Version::Http11,
+            tls: None,
+            connection: None,
             state: RequestState {
                 rocket,
                 route: Atomic::new(None),
@@ -86,6 +159,10 @@ impl<'r> Request<'r> {
                 accept: Storage::new(),
                 content_type: Storage::new(),
                 cache: Arc::new(<Container![Send + Sync]>::new()),
+                extensions: Arc::new(Extensions::new()),
+                halted: None,
+                skip_fairings: false,
+                disconnect: Disconnect::new(),
             }
         }
     }
@@ -168,6 +245,66 @@ impl<'r> Request<'r> {
         self.uri = uri;
     }
 
+    /// Returns the request target exactly as it was received, including the
+    /// raw query string, with its original percent-encoding and parameter
+    /// order intact. This is useful for forwarding a request verbatim, as a
+    /// proxy would.
+    ///
+    /// Unlike [`Request::uri()`], the raw URI is unaffected by changes made via
+    /// [`Request::set_uri()`], for instance by fairings. If the request target
+    /// was sent in absolute form, as in `GET http://example.com/ HTTP/1.1`, the
+    /// scheme and authority are included. For requests not received over the
+    /// network and not created by a local client, this is the rendering of
+    /// the request's URI.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::uri::Origin;
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let mut req = c.get("/search?q=a%20b&z=1&a=2");
+    /// # let request = req.inner_mut();
+    ///
+    /// assert_eq!(request.raw_uri(), "/search?q=a%20b&z=1&a=2");
+    ///
+    /// request.set_uri(Origin::parse("/rewritten").unwrap());
+    /// assert_eq!(request.raw_uri(), "/search?q=a%20b&z=1&a=2");
+    /// ```
+    pub fn raw_uri(&self) -> Cow<'_, str> {
+        match self.raw_uri {
+            Some(ref raw) => Cow::Borrowed(raw),
+            None => Cow::Owned(self.uri.to_string()),
+        }
+    }
+
+    /// Returns the authority of the raw request target, without any userinfo,
+    /// if the target was sent in absolute form. HTTP/2 requests always are:
+    /// hyper exposes their `:authority` pseudo-header this way.
+    pub(crate) fn target_authority(&self) -> Option<&str> {
+        let raw = self.raw_uri.as_deref()?;
+        let (_, rest) = raw.split_once("://")?;
+        let authority = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+        let host = match authority.rfind('@') {
+            Some(i) => &authority[(i + 1)..],
+            None => authority,
+        };
+
+        Some(host).filter(|host| !host.is_empty())
+    }
+
+    /// Returns the host the request was sent to, as it was sent: the authority
+    /// of the request target if it was sent in absolute form, which takes
+    /// precedence as RFC 7230 §5.4 requires, and otherwise the `Host` header.
+    pub(crate) fn raw_host(&self) -> Option<&str> {
+        self.target_authority().or_else(|| self.headers().get_one("Host"))
+    }
+
+    /// Sets the raw request target returned by [`Request::raw_uri()`].
+    #[inline(always)]
+    pub(crate) fn set_raw_uri<U: Into<Cow<'r, str>>>(&mut self, raw: U) {
+        self.raw_uri = Some(raw.into());
+    }
+
     /// Returns the raw address of the remote connection that initiated this
     /// request if the address is known. If the address is not known, `None` is
     /// returned.
@@ -223,6 +360,202 @@ impl<'r> Request<'r> {
         self.remote = Some(address);
     }
 
+    /// Halts processing of `self` with the error status `status`.
+    ///
+    /// This method is intended to be called from a [request fairing] to reject
+    /// a request before it is routed. Once a request has been halted, no
+    /// further request fairings are run, routing is skipped entirely, and the
+    /// response is generated by the [catcher](crate::Catcher) for `status`.
+    /// Response fairings are run on that response as usual. To respond with
+    /// anything other than an error, use [`Request::halt_with()`] instead.
+    ///
+    /// Calling this method more than once replaces the previous halt.
+    ///
+    /// [request fairing]: crate::fairing::Fairing::on_request()
+    ///
+    /// # Panics
+    ///
+    /// Panics if `status` is not an error status, that is, a `4xx` or `5xx`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Status;
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let mut req = c.get("/");
+    /// # let request = req.inner_mut();
+    ///
+    /// assert_eq!(request.halted(), None);
+    ///
+    /// request.halt(Status::ServiceUnavailable);
+    /// assert_eq!(request.halted(), Some(Status::ServiceUnavailable));
+    /// ```
+    pub fn halt(&mut self, status: Status) {
+        let class = status.class();
+        if !class.is_client_error() && !class.is_server_error() {
+            panic!("requests can only be halted with an error status, not {}", status);
+        }
+
+        let response = Mutex::new(None);
+        self.state.halted = Some(Arc::new(Halt { status, response }));
+    }
+
+    /// Halts processing of `self`, responding with `response`.
+    ///
+    /// Like [`Request::halt()`], this method is intended to be called from a
+    /// [request fairing]. Once a request has been halted, no further request
+    /// fairings are run and routing is skipped entirely. Instead, `response`
+    /// is used as the response to the request, as if returned by a route.
+    /// Response fairings are run on it as usual.
+    ///
+    /// Calling this method more than once replaces the previous halt.
+    ///
+    /// [request fairing]: crate::fairing::Fairing::on_request()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::http::{Header, Status};
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let mut req = c.get("/");
+    /// # let request = req.inner_mut();
+    ///
+    /// let response = Response::build()
+    ///     .status(Status::Accepted)
+    ///     .header(Header::new("X-Replayed", "true"))
+    ///     .finalize();
+    ///
+    /// request.halt_with(response);
+    /// assert_eq!(request.halted(), Some(Status::Accepted));
+    /// ```
+    pub fn halt_with(&mut self, response: Response<'static>) {
+        let status = response.status();
+        let response = Mutex::new(Some(response));
+        self.state.halted = Some(Arc::new(Halt { status, response }));
+    }
+
+    /// Returns the status `self` was halted with via [`Request::halt()`], or
+    /// the status of the response it was halted with via
+    /// [`Request::halt_with()`], if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let req = c.get("/");
+    /// # let request = req.inner();
+    ///
+    /// assert_eq!(request.halted(), None);
+    /// ```
+    #[inline(always)]
+    pub fn halted(&self) -> Option<Status> {
+        self.state.halted.as_ref().map(|halt| halt.status)
+    }
+
+    /// Skips the request fairings that have yet to run on `self`.
+    ///
+    /// This method is intended to be called from a [request fairing] that
+    /// fully handles a request's preprocessing, such that fairings ordered
+    /// after it need not see the request. Unlike [`Request::halt()`], the
+    /// request is routed as usual. Response fairings are unaffected.
+    ///
+    /// [request fairing]: crate::fairing::Fairing::on_request()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let mut req = c.get("/");
+    /// # let request = req.inner_mut();
+    ///
+    /// assert!(!request.request_fairings_skipped());
+    ///
+    /// request.skip_request_fairings();
+    /// assert!(request.request_fairings_skipped());
+    /// ```
+    #[inline(always)]
+    pub fn skip_request_fairings(&mut self) {
+        self.state.skip_fairings = true;
+    }
+
+    /// Returns `true` if the remaining request fairings were skipped via
+    /// [`Request::skip_request_fairings()`].
+    #[inline(always)]
+    pub fn request_fairings_skipped(&self) -> bool {
+        self.state.skip_fairings
+    }
+
+    /// Returns the HTTP version of the request.
+    ///
+    /// For requests dispatched via a [`local`](crate::local) client, this is
+    /// always [`Version::Http11`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Version;
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let req = c.get("/");
+    /// # let request = req.inner();
+    ///
+    /// if request.version().is_multiplexed() {
+    ///     /* HTTP/2 or later: chunked transfer encoding doesn't apply. */
+    /// }
+    ///
+    /// assert_eq!(request.version(), Version::Http11);
+    /// ```
+    #[inline(always)]
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Returns information about the TLS session the request was received
+    /// over, including the negotiated TLS version and ALPN protocol, if the
+    /// request was received over TLS. Otherwise, returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let req = c.get("/");
+    /// # let request = req.inner();
+    ///
+    /// if let Some(tls) = request.tls() {
+    ///     println!("TLS version: {:?}", tls.version());
+    ///     println!("ALPN protocol: {:?}", tls.alpn_protocol());
+    /// }
+    ///
+    /// assert!(request.tls().is_none());
+    /// ```
+    #[inline(always)]
+    pub fn tls(&self) -> Option<&TlsInfo> {
+        self.tls.as_ref()
+    }
+
+    /// Returns information about the connection the request was received
+    /// over, such as whether it is kept alive and how many requests it has
+    /// carried, if the request was received over a connection. Local requests
+    /// dispatched via [`local`](crate::local) clients have no such information.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let req = c.get("/");
+    /// # let request = req.inner();
+    ///
+    /// if let Some(connection) = request.connection() {
+    ///     println!("request #{} on the connection", connection.ordinal());
+    /// }
+    ///
+    /// assert!(request.connection().is_none());
+    /// ```
+    #[inline(always)]
+    pub fn connection(&self) -> Option<&ConnectionInfo> {
+        self.connection.as_ref()
+    }
+
     /// Returns the IP address in the "X-Real-IP" header of the request if such
     /// a header exists and contains a valid IP address.
     ///
@@ -634,6 +967,130 @@ impl<'r> Request<'r> {
         }
     }
 
+    /// Returns the request's extensions: a map from types to values which
+    /// fairings, guards, and handlers can use to pass data to one another.
+    /// See [`Extensions`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// struct Tenant(&'static str);
+    ///
+    /// request.extensions().insert(Tenant("acme"));
+    /// assert_eq!(request.extensions().get::<Tenant>().unwrap().0, "acme");
+    /// ```
+    #[inline(always)]
+    pub fn extensions(&self) -> &Extensions {
+        &self.state.extensions
+    }
+
+    /// Returns the authentication challenge recorded for this request via
+    /// [`Request::set_challenge()`], if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Challenge;
+    ///
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// assert!(request.challenge().is_none());
+    ///
+    /// request.set_challenge(Challenge::basic("admin"));
+    /// assert_eq!(request.challenge().unwrap().scheme(), "Basic");
+    /// ```
+    #[inline(always)]
+    pub fn challenge(&self) -> Option<&Challenge> {
+        self.state.cache.try_get::<RecordedChallenge>().map(|c| &c.0)
+    }
+
+    /// Records `challenge` as the authentication challenge for this request.
+    /// Only the first challenge is kept: subsequent calls have no effect.
+    ///
+    /// If the request fails with `401 Unauthorized`, or with any error status
+    /// if the challenge carries an `error` parameter as `Bearer` challenges
+    /// may, Rocket adds the challenge as a `WWW-Authenticate` header to the
+    /// error response unless the catcher already set one. The built-in
+    /// [`BasicAuth`] and [`BearerToken`] guards record a challenge when they
+    /// fail; to change it, for instance to name a realm, record a challenge
+    /// before the guards run, such as in a request fairing.
+    ///
+    /// [`BasicAuth`]: crate::request::BasicAuth
+    /// [`BearerToken`]: crate::request::BearerToken
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Challenge;
+    ///
+    /// # let c = rocket::local::blocking::Client::debug_with(vec![]).unwrap();
+    /// # let request = c.get("/");
+    /// request.set_challenge(Challenge::basic("admin"));
+    /// request.set_challenge(Challenge::bearer());
+    /// assert_eq!(request.challenge().unwrap().get("realm"), Some("admin"));
+    /// ```
+    #[inline]
+    pub fn set_challenge(&self, challenge: Challenge) {
+        self.state.cache.set(RecordedChallenge(challenge));
+    }
+
+    /// Returns the names of the `#[field(required)]` query fields whose
+    /// absence caused this request to fail with `400 Bad Request`. The slice
+    /// is empty if the request didn't fail for that reason.
+    ///
+    /// Rocket's default catcher includes these names in its response body.
+    /// Custom catchers can use them to do the same.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::Request;
+    ///
+    /// #[catch(400)]
+    /// fn bad_request(req: &Request<'_>) -> String {
+    ///     match req.missing_query_fields() {
+    ///         [] => "Bad request.".into(),
+    ///         fields => format!("Missing query parameters: {}.", fields.join(", ")),
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn missing_query_fields(&self) -> &[String] {
+        self.state.cache.try_get::<MissingQueryFields>().map_or(&[], |v| v.0.as_slice())
+    }
+
+    /// Returns the formats expected by the `strict_format` routes whose format
+    /// mismatch caused this request to fail with `415 Unsupported Media Type`
+    /// or `406 Not Acceptable`, sorted and without duplicates. The slice is
+    /// empty if the request didn't fail for that reason.
+    ///
+    /// Rocket lists these formats in the `Accept` header of `415` responses
+    /// unless the catcher sets its own. Custom catchers can use them to
+    /// describe the acceptable representations in a `406` response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::Request;
+    ///
+    /// #[catch(406)]
+    /// fn not_acceptable(req: &Request<'_>) -> String {
+    ///     let formats: Vec<_> = req.expected_formats().iter()
+    ///         .map(|format| format.to_string())
+    ///         .collect();
+    ///
+    ///     format!("Available formats: {}.", formats.join(", "))
+    /// }
+    /// ```
+    #[inline]
+    pub fn expected_formats(&self) -> &[MediaType] {
+        self.state.cache.try_get::<ExpectedFormats>().map_or(&[], |v| v.0.as_slice())
+    }
+
     /// Retrieves and parses into `T` the 0-indexed `n`th non-empty segment from
     /// the _routed_ request, that is, the `n`th segment _after_ the mount
     /// point. If the request has not been routed, then this is simply the `n`th
@@ -753,13 +1210,15 @@ impl<'r> Request<'r> {
     pub fn query_value<'a, T>(&'a self, name: &str) -> Option<form::Result<'a, T>>
         where T: FromForm<'a>
     {
-        if self.query_fields().find(|f| f.name == name).is_none() {
-            return None;
-        }
+        let fields = || match T::PLUS_AS_SPACE {
+            true => Either::Left(self.query_fields()),
+            false => Either::Right(self.rfc3986_query_fields()),
+        };
 
+        fields().find(|f| f.name == name)?;
         let mut ctxt = T::init(form::Options::Lenient);
 
-        self.query_fields()
+        fields()
             .filter(|f| f.name == name)
             .for_each(|f| T::push_value(&mut ctxt, f.shift()));
 
@@ -802,6 +1261,38 @@ impl<'r> Request<'r> {
         self.uri().path().segments().skip(mount_segments + n.start)
     }
 
+    /// Records that the dynamic parameter `name`, beginning at the `n`th
+    /// routed segment and spanning the rest of the path if `trailing`, failed
+    /// to parse with `error`. Only the first failure is kept. Used by codegen.
+    pub fn set_param_error<E: fmt::Debug>(&self, name: &str, n: usize, trailing: bool, error: &E) {
+        let segment = match trailing {
+            true => self.routed_segments(n..).collect::<Vec<_>>().join("/"),
+            false => self.routed_segment(n).unwrap_or_default().to_string(),
+        };
+
+        self.state.cache.set(FailedParam(ParamError::named(name, segment, error)));
+    }
+
+    /// Returns a response to this request from the matched route's cache, if
+    /// it has one and a fresh response is stored. Used by codegen once all of
+    /// the route's guards have succeeded.
+    pub fn cached_response<'a>(&self) -> Option<Response<'a>> {
+        self.route()?.cache.as_ref()?.get(self)
+    }
+
+    /// Records the names of the missing `#[field(required)]` query fields that
+    /// caused the request to fail. Only the first record is kept. Used by
+    /// codegen.
+    pub fn set_missing_query_fields(&self, names: Vec<String>) {
+        self.state.cache.set(MissingQueryFields(names));
+    }
+
+    /// Records `formats` as the formats expected by the `strict_format` routes
+    /// whose format mismatch failed the request.
+    pub(crate) fn set_expected_formats(&self, formats: Vec<MediaType>) {
+        self.state.cache.set(ExpectedFormats(formats));
+    }
+
     // Retrieves the pre-parsed query items. Used by matching and codegen.
     #[inline]
     pub fn query_fields(&self) -> impl Iterator<Item = ValueField<'_>> {
@@ -809,6 +1300,18 @@ impl<'r> Request<'r> {
             .map(|q| q.segments().map(ValueField::from))
             .into_iter()
             .flatten()
+            .map(|f| f.with_source(form::Source::Query))
+    }
+
+    // Retrieves the query items decoded per RFC 3986, where `+` is literal.
+    // Used by codegen for query guards that aren't `FromForm::PLUS_AS_SPACE`.
+    pub fn rfc3986_query_fields(&self) -> impl Iterator<Item = ValueField<'_>> {
+        let buffer = local_cache!(self, form::parser::Buffer::new());
+        self.uri().query()
+            .map(|q| form::parser::RawStrParser::new(buffer, q.raw(), false))
+            .into_iter()
+            .flatten()
+            .map(|f| f.with_source(form::Source::Query))
     }
 
     /// Set `self`'s parameters given that the route used to reach this request
@@ -833,7 +1336,7 @@ impl<'r> Request<'r> {
     pub(crate) fn from_hyp(
         rocket: &'r Rocket<Orbit>,
         hyper: &'r hyper::RequestParts,
-        addr: SocketAddr
+        connection: ConnectionMeta,
     ) -> Result<Request<'r>, Error<'r>> {
         // Ensure that the method is known. TODO: Allow made-up methods?
         let method = Method::from_hyp(&hyper.method)
@@ -844,11 +1347,40 @@ impl<'r> Request<'r> {
         // TODO: Keep around not just the path/query, but the rest, if there?
         let uri = hyper.uri.path_and_query().ok_or_else(|| Error::InvalidUri(&hyper.uri))?;
         debug_assert!(Origin::parse(uri.as_str()).is_ok());
+        let raw_uri = match hyper.uri.scheme().is_some() {
+            true => Cow::Owned(hyper.uri.to_string()),
+            false => Cow::Borrowed(uri.as_str()),
+        };
+
         let uri = Origin::new(uri.path(), uri.query().map(Cow::Borrowed));
 
+        // Bound pathological URIs before any routing or fairings see them.
+        // hyper has parsed the target by now, and `Origin::new()` only wraps
+        // its pieces, so this limits the work done from here on, not before.
+        let limits = &rocket.config.limits;
+        if let Some(limit) = limits.get("uri") {
+            if raw_uri.len() as u64 > limit.as_u64() {
+                return Err(Error::UriTooLong(raw_uri.len()));
+            }
+        }
+
+        if let Some(limit) = limits.get("path_segments") {
+            let segments = uri.path().raw_segments().filter(|s| !s.is_empty()).count();
+            if segments as u64 > limit.as_u64() {
+                return Err(Error::TooManySegments(segments));
+            }
+        }
+
+        // Reject requests whose body framing is ambiguous before routing.
+        check_framing(&hyper.headers)?;
+
         // Construct the request object.
         let mut request = Request::new(rocket, method, uri);
-        request.set_remote(addr);
+        request.set_raw_uri(raw_uri);
+        request.set_remote(connection.remote);
+        request.version = Version::from_hyp(hyper.version).unwrap_or(Version::Http11);
+        request.connection = Some(ConnectionInfo::new(rocket, hyper, &connection));
+        request.tls = connection.tls;
 
         // Set the request cookies, if they exist.
         for header in hyper.headers.get_all("Cookie") {
@@ -888,6 +1420,82 @@ pub(crate) enum Error<'r> {
     InvalidUri(&'r hyper::Uri),
     UriParse(crate::http::uri::Error<'r>),
     BadMethod(&'r hyper::Method),
+    UriTooLong(usize),
+    TooManySegments(usize),
+    AmbiguousFraming(&'static str),
+}
+
+impl Error<'_> {
+    /// The status of the response to a request that failed to parse.
+    pub(crate) fn status(&self) -> Status {
+        match self {
+            Error::UriTooLong(_) | Error::TooManySegments(_) => Status::UriTooLong,
+            _ => Status::BadRequest,
+        }
+    }
+
+    /// Whether the connection must be closed after responding. This is the
+    /// case when the request's body framing is ambiguous, as the end of the
+    /// request, and thus the start of the next, cannot be trusted.
+    pub(crate) fn closes_connection(&self) -> bool {
+        matches!(self, Error::AmbiguousFraming(_))
+    }
+}
+
+/// Checks that the framing of a request's body is unambiguous, per [RFC 7230
+/// §3.3.3], to defend against request smuggling. A request is rejected if:
+///
+///   * it has both `Transfer-Encoding` and `Content-Length` headers,
+///   * its `Content-Length` values aren't all the same valid length, or
+///   * `chunked` is applied more than once or isn't the final encoding.
+///
+/// The HTTP/1 parser discards a `Content-Length` that _follows_ a
+/// `Transfer-Encoding`, framing the body by the latter alone, so such
+/// requests can't be detected here; the framing Rocket uses for them is
+/// nevertheless unambiguous.
+///
+/// [RFC 7230 §3.3.3]: https://tools.ietf.org/html/rfc7230#section-3.3.3
+fn check_framing(headers: &hyper::HeaderMap) -> Result<(), Error<'static>> {
+    use crate::http::hyper::header::{CONTENT_LENGTH, TRANSFER_ENCODING};
+
+    let has_te = headers.contains_key(TRANSFER_ENCODING);
+    if has_te && headers.contains_key(CONTENT_LENGTH) {
+        return Err(Error::AmbiguousFraming("both `Transfer-Encoding` and `Content-Length`"));
+    }
+
+    let mut length = None;
+    for value in headers.get_all(CONTENT_LENGTH) {
+        for item in value.to_str().unwrap_or(",").split(',').map(|item| item.trim()) {
+            let n = match item.bytes().all(|b| b.is_ascii_digit()) {
+                true => item.parse::<u64>().ok(),
+                false => None,
+            };
+
+            match (n, length) {
+                (None, _) => return Err(Error::AmbiguousFraming("invalid `Content-Length`")),
+                (Some(n), Some(m)) if n != m => {
+                    return Err(Error::AmbiguousFraming("conflicting `Content-Length`s"));
+                }
+                (Some(n), _) => length = Some(n),
+            }
+        }
+    }
+
+    if has_te {
+        let codings = headers.get_all(TRANSFER_ENCODING).iter()
+            .flat_map(|value| value.to_str().unwrap_or("").split(','))
+            .map(|coding| coding.trim())
+            .filter(|coding| !coding.is_empty())
+            .collect::<Vec<_>>();
+
+        let chunked = codings.iter().filter(|c| c.eq_ignore_ascii_case("chunked")).count();
+        let last_is_chunked = matches!(codings.last(), Some(c) if c.eq_ignore_ascii_case("chunked"));
+        if chunked != 1 || !last_is_chunked {
+            return Err(Error::AmbiguousFraming("`chunked` isn't the sole final encoding"));
+        }
+    }
+
+    Ok(())
 }
 
 impl fmt::Display for Error<'_> {
@@ -898,6 +1506,11 @@ impl fmt::Display for Error<'_> {
             Error::InvalidUri(u) => write!(f, "invalid origin URI: {}", u),
             Error::UriParse(u) => write!(f, "URI `{}` failed to parse as origin", u),
             Error::BadMethod(m) => write!(f, "invalid or unrecognized method: {}", m),
+            Error::UriTooLong(n) => write!(f, "URI of {} bytes exceeds the `uri` limit", n),
+            Error::TooManySegments(n) => {
+                write!(f, "path of {} segments exceeds the `path_segments` limit", n)
+            }
+            Error::AmbiguousFraming(reason) => write!(f, "ambiguous body framing: {}", reason),
         }
     }
 }
@@ -2,6 +2,7 @@ use std::net::{Ipv4Addr, SocketAddrV4};
 use std::collections::HashMap;
 
 use crate::Request;
+use crate::request::ConnectionMeta;
 use crate::local::blocking::Client;
 use crate::http::hyper;
 
@@ -17,9 +18,10 @@ macro_rules! assert_headers {
 
         // Create a valid `Rocket` and convert the hyper req to a Rocket one.
         let client = Client::debug_with(vec![]).unwrap();
-        let addr = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8000).into();
+        let remote = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8000).into();
         let hyper = req.into_parts().0;
-        let req = Request::from_hyp(client.rocket(), &hyper, addr).unwrap();
+        let meta = ConnectionMeta { remote, local: None, tls: None, requests: Default::default() };
+        let req = Request::from_hyp(client.rocket(), &hyper, meta).unwrap();
 
         // Dispatch the request and check that the headers match.
         let actual_headers = req.headers();
@@ -46,3 +48,15 @@ fn test_multiple_headers_merge_into_one_from_hyp() {
     assert_headers!("friend" => ["alice"], "friend" => ["bob"], "friend" => ["carol"]);
     assert_headers!("friend" => ["alice"], "friend" => ["bob"], "enemy" => ["carol"]);
 }
+
+#[test]
+fn test_raw_uri_from_hyp() {
+    let client = Client::debug_with(vec![]).unwrap();
+    let remote = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8000).into();
+    for raw in &["/a%2Fb/c?z=1&a=%3d+x&z=2", "http://example.com:8080/p?q=%20"] {
+        let hyper = hyper::Request::get(*raw).body(()).unwrap().into_parts().0;
+        let meta = ConnectionMeta { remote, local: None, tls: None, requests: Default::default() };
+        let req = Request::from_hyp(client.rocket(), &hyper, meta).unwrap();
+        assert_eq!(req.raw_uri(), *raw);
+    }
+}
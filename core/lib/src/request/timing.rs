@@ -0,0 +1,93 @@
+use std::convert::Infallible;
+use std::time::{Duration, Instant};
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the time at which Rocket began processing the request.
+///
+/// The clock is started once the request has been received, before any
+/// request fairings run, so fairings and handlers observe the same start time.
+/// This makes `RequestTiming` suitable for measuring the latency of a request
+/// from within fairings and handlers alike, for instance in response fairings
+/// that log slow requests.
+///
+/// # Outcomes
+///
+///   * **Success** always.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::request::RequestTiming;
+///
+/// #[get("/")]
+/// fn index(timing: RequestTiming) -> String {
+///     format!("Processing for {:?} so far.", timing.elapsed())
+/// }
+/// ```
+///
+/// The same timing is available to fairings via [`Request::guard()`]:
+///
+/// ```rust
+/// use rocket::fairing::AdHoc;
+/// use rocket::request::RequestTiming;
+///
+/// let fairing = AdHoc::on_response("Slow Requests", |req, _| Box::pin(async move {
+///     let timing = req.guard::<RequestTiming>().await.unwrap();
+///     if timing.elapsed().as_secs() >= 1 {
+///         println!("slow request: {}", req);
+///     }
+/// }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTiming {
+    started_at: Instant,
+}
+
+impl RequestTiming {
+    /// Starts the clock for `req` if it has not yet been started and returns
+    /// the request's timing.
+    pub(crate) fn start(req: &Request<'_>) -> RequestTiming {
+        *req.local_cache(|| RequestTiming { started_at: Instant::now() })
+    }
+
+    /// Returns the instant at which Rocket began processing the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RequestTiming;
+    ///
+    /// fn handler(timing: RequestTiming) {
+    ///     let started_at = timing.started_at();
+    /// }
+    /// ```
+    pub fn started_at(&self) -> Instant {
+        self.started_at
+    }
+
+    /// Returns the time elapsed since Rocket began processing the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::request::RequestTiming;
+    ///
+    /// fn handler(timing: RequestTiming) {
+    ///     let elapsed = timing.elapsed();
+    /// }
+    /// ```
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for RequestTiming {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(RequestTiming::start(req))
+    }
+}
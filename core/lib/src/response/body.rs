@@ -37,7 +37,9 @@ use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, ReadBuf};
 /// its size computed on the fly by seeking ([`Body::size()`]). As such, sized
 /// bodies must implement [`AsyncSeek`]. If a body does not have a preset size
 /// and the fails to be computed dynamically, a sized body is treated as an
-/// unsized body when written out to the network.
+/// unsized body when written out to the network. A reader that can't seek but
+/// whose length is known can be sent with a `Content-Length` via
+/// [`SizedStream`](crate::response::SizedStream).
 ///
 /// ## Unsized
 ///
@@ -127,6 +129,14 @@ impl<'r> Body<'r> {
         }
     }
 
+    /// An unsized body whose size is nonetheless known to be `size`. The body
+    /// must yield exactly `size` bytes.
+    pub(crate) fn with_unsized_preset<T>(body: T, size: usize) -> Self
+        where T: AsyncRead + Send + 'r
+    {
+        Body { size: Some(size), ..Body::with_unsized(body) }
+    }
+
     pub(crate) fn set_max_chunk_size(&mut self, max_chunk: usize) {
         self.max_chunk = max_chunk;
     }
@@ -139,6 +149,11 @@ impl<'r> Body<'r> {
                 inner: Inner::Phantom(b),
                 max_chunk: body.max_chunk,
             },
+            Inner::Unsized(_) if body.size.is_some() => Body {
+                size: body.size,
+                inner: Inner::None,
+                max_chunk: body.max_chunk,
+            },
             Inner::Unsized(_) | Inner::None => Body::default()
         };
     }
@@ -0,0 +1,117 @@
+use std::fmt;
+
+use crate::request::Request;
+use crate::response::{self, Responder};
+
+/// A type-erased, boxed [`Responder`].
+///
+/// Because [`Responder::respond_to()`] consumes `self`, `Responder` can't be
+/// called through a `Box<dyn Responder>`. A `BoxResponder` stands in for such
+/// a box: it wraps a responder of any type, or a closure producing a response,
+/// behind dynamic dispatch. This allows a handler to return responders whose
+/// type isn't known statically, such as those produced by plugins.
+///
+/// The boxed responder must be able to respond to any request, so it must be
+/// `'static` and produce a `'static` response, borrowing neither from the
+/// request nor from other data. Most responders that own their data, like
+/// `String`, `Redirect`, or `NamedFile`, qualify.
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::Status;
+/// use rocket::response::{BoxResponder, Redirect};
+///
+/// #[get("/<page>")]
+/// fn page(page: &str) -> BoxResponder {
+///     match page {
+///         "home" => BoxResponder::new("Welcome home!"),
+///         "old" => BoxResponder::new(Redirect::to("/home")),
+///         _ => BoxResponder::new(Status::NotFound),
+///     }
+/// }
+/// ```
+pub struct BoxResponder {
+    responder: Box<dyn ErasedResponder + Send>,
+}
+
+/// Object-safe counterpart of `Responder` for responders that can respond to
+/// any request.
+trait ErasedResponder {
+    fn respond_boxed(self: Box<Self>, request: &Request<'_>) -> response::Result<'static>;
+}
+
+impl<R> ErasedResponder for R where R: for<'r> Responder<'r, 'static> {
+    fn respond_boxed(self: Box<Self>, request: &Request<'_>) -> response::Result<'static> {
+        (*self).respond_to(request)
+    }
+}
+
+/// A responder which calls a closure to produce the response.
+struct FnResponder<F>(F);
+
+impl<'r, 'o: 'r, F> Responder<'r, 'o> for FnResponder<F>
+    where F: FnOnce(&'r Request<'_>) -> response::Result<'o>
+{
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'o> {
+        (self.0)(request)
+    }
+}
+
+impl BoxResponder {
+    /// Boxes `responder`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::{BoxResponder, Redirect};
+    ///
+    /// let responders: Vec<BoxResponder> = vec![
+    ///     BoxResponder::new("Hello, world!"),
+    ///     BoxResponder::new(Redirect::to("/hello")),
+    /// ];
+    /// ```
+    pub fn new<R>(responder: R) -> Self
+        where R: for<'r> Responder<'r, 'static> + Send + 'static
+    {
+        BoxResponder { responder: Box::new(responder) }
+    }
+
+    /// Boxes a closure which produces the response when called with the
+    /// request being responded to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::get;
+    /// use rocket::response::{BoxResponder, Responder};
+    ///
+    /// #[get("/greet")]
+    /// fn greet() -> BoxResponder {
+    ///     let greeting = String::from("Hello");
+    ///     BoxResponder::from_fn(move |req| {
+    ///         let name = req.query_value::<&str>("name").and_then(|r| r.ok());
+    ///         format!("{}, {}!", greeting, name.unwrap_or("stranger")).respond_to(req)
+    ///     })
+    /// }
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+        where F: for<'r> FnOnce(&'r Request<'_>) -> response::Result<'static> + Send + 'static
+    {
+        BoxResponder::new(FnResponder(f))
+    }
+}
+
+/// Responds with the boxed responder.
+impl<'r> Responder<'r, 'static> for BoxResponder {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        self.responder.respond_boxed(request)
+    }
+}
+
+impl fmt::Debug for BoxResponder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxResponder").finish()
+    }
+}
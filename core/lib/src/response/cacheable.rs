@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+use std::time::Duration;
+
+use crate::request::Request;
+use crate::response::{self, Response, Responder};
+use crate::http::{Header, Method, Status};
+
+/// A responder that makes another responder cacheable and conditionally
+/// retrievable via its ETag.
+///
+/// A `Cacheable` wraps a responder alongside an [entity tag] that identifies
+/// the current representation of the resource and a `max-age` for which the
+/// response may be cached. It packages the common HTTP caching pattern:
+///
+///   * The `ETag` header is set to the supplied entity tag.
+///   * The `Cache-Control` header is set to `max-age=N` where `N` is the
+///     `max-age` in whole seconds.
+///   * The `Vary` header lists every request header the response was
+///     negotiated on, as declared via [`Cacheable::vary()`].
+///
+/// The entity tag is the `opaque-tag` without surrounding double quotes,
+/// which are added by `Cacheable`. It must not itself contain a `"`,
+/// whitespace, or ASCII control characters. Entity tags are strong by default;
+/// use [`Cacheable::weak()`] for a weak tag.
+///
+/// [entity tag]: https://tools.ietf.org/html/rfc7232#section-2.3
+///
+/// # Conditional Requests
+///
+/// When a `GET` or `HEAD` request carries an `If-None-Match` header that
+/// matches the entity tag, by weak comparison, or that is `*`, the client's
+/// cached copy is current. `Cacheable` then responds with `304 Not Modified`
+/// and the caching headers, without a body. As RFC 7232 §4.1 requires, the
+/// `304` also carries the `Vary`, `Content-Location`, `Date`, and `Expires`
+/// headers set by the wrapped responder, which is thus invoked in this case as
+/// well. To avoid producing a response that the client already has, use
+/// [`Conditional`](crate::response::Conditional).
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use std::time::Duration;
+/// use rocket::response::Cacheable;
+///
+/// const LOGO: &[u8] = b"...";
+///
+/// #[get("/logo")]
+/// fn logo() -> Cacheable<&'static [u8]> {
+///     Cacheable::new(LOGO, "logo-v2", Duration::from_secs(3600))
+///         .vary("Accept-Encoding")
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Cacheable<R> {
+    responder: R,
+    etag: Cow<'static, str>,
+    weak: bool,
+    max_age: Duration,
+    vary: Vec<Cow<'static, str>>,
+}
+
+impl<R> Cacheable<R> {
+    /// Wraps `responder` with the strong entity tag `etag`, cacheable for
+    /// `max_age`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `etag` contains a `"`, whitespace, or an ASCII control
+    /// character, none of which may appear in an entity tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::response::Cacheable;
+    ///
+    /// let response = Cacheable::new("Hello, world!", "hello-v1", Duration::from_secs(60));
+    /// ```
+    pub fn new<E>(responder: R, etag: E, max_age: Duration) -> Self
+        where E: Into<Cow<'static, str>>
+    {
+        let etag = etag.into();
+        assert!(is_valid_etag(&etag), "invalid entity tag: {:?}", etag);
+        Cacheable { responder, etag, weak: false, max_age, vary: vec![] }
+    }
+
+    /// Marks the entity tag as weak, indicating that the representation is
+    /// semantically, but not necessarily byte-for-byte, equivalent to any other
+    /// with the same tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::response::Cacheable;
+    ///
+    /// let response = Cacheable::new("Hello, world!", "hello", Duration::from_secs(60))
+    ///     .weak();
+    /// ```
+    pub fn weak(mut self) -> Self {
+        self.weak = true;
+        self
+    }
+
+    /// Adds `header` to the list of request headers the response varies on,
+    /// emitted in the `Vary` header. May be called multiple times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::response::Cacheable;
+    ///
+    /// let response = Cacheable::new("Hello, world!", "hello", Duration::from_secs(60))
+    ///     .vary("Accept")
+    ///     .vary("Accept-Language");
+    /// ```
+    pub fn vary<H: Into<Cow<'static, str>>>(mut self, header: H) -> Self {
+        self.vary.push(header.into());
+        self
+    }
+
+    fn headers(&self) -> Vec<Header<'static>> {
+        let etag = match self.weak {
+            true => format!("W/\"{}\"", self.etag),
+            false => format!("\"{}\"", self.etag),
+        };
+
+        let cache_control = format!("max-age={}", self.max_age.as_secs());
+        let mut headers = vec![
+            Header::new("ETag", etag),
+            Header::new("Cache-Control", cache_control),
+        ];
+
+        headers.extend(self.vary.iter().map(|h| Header::new("Vary", h.clone())));
+        headers
+    }
+}
+
+/// Returns `true` if `tag` consists only of characters allowed in an
+/// `opaque-tag` by RFC 7232 §2.3: `!`, `#` through `~`, and non-ASCII.
+fn is_valid_etag(tag: &str) -> bool {
+    tag.chars().all(|c| c == '!' || ('#'..='~').contains(&c) || !c.is_ascii())
+}
+
+/// Returns `true` if `req` is a `GET` or `HEAD` request whose `If-None-Match`
+/// header matches the opaque tag `etag` by weak comparison or is `*`.
+pub(crate) fn is_not_modified(req: &Request<'_>, etag: &str) -> bool {
+    if !matches!(req.method(), Method::Get | Method::Head) {
+        return false;
+    }
+
+    req.headers()
+        .get("If-None-Match")
+        .any(|value| value.trim() == "*" || entity_tags(value).any(|t| t == etag))
+}
+
+/// Returns an iterator over the opaque tags in a comma-separated list of
+/// entity tags, with weakness indicators and quotes removed.
+fn entity_tags(list: &str) -> impl Iterator<Item = &str> {
+    list.split('"')
+        .enumerate()
+        .filter(|(i, _)| i % 2 == 1)
+        .map(|(_, tag)| tag)
+}
+
+/// Responds with `304 Not Modified`, with the wrapped responder's `Vary`,
+/// `Content-Location`, `Date`, and `Expires` headers, if the request's
+/// `If-None-Match` matches, and with the wrapped responder's response
+/// otherwise. In either case, the `ETag`, `Cache-Control`, and `Vary` headers
+/// are set. `Cache-Control` and `ETag` replace any set by the wrapped
+/// responder; `Vary` is adjoined.
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Cacheable<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let headers = self.headers();
+        let not_modified = is_not_modified(req, &self.etag);
+        let mut response = self.responder.respond_to(req)?;
+        if not_modified {
+            let mut not_modified = Response::build().status(Status::NotModified).finalize();
+            for name in &["Vary", "Content-Location", "Date", "Expires"] {
+                for value in response.headers().get(name) {
+                    not_modified.adjoin_header(Header::new(*name, value.to_string()));
+                }
+            }
+
+            response = not_modified;
+        }
+
+        for header in headers {
+            match header.name.as_str() {
+                "Vary" => response.adjoin_header(header),
+                _ => { response.set_header(header); },
+            }
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::io::{self, Cursor};
+use std::sync::Arc;
+
+use crate::response::Response;
+use crate::data::ByteUnit;
+use crate::http::{Header, Status};
+
+/// A complete response, with its body buffered in memory, that can be sent any
+/// number of times.
+///
+/// A `CapturedResponse` records the status, headers, and body of a
+/// [`Response`] so that it can be replayed later, as the response cache of a
+/// route and the [`Idempotency`] and [`Collapse`] fairings do. Cloning a
+/// `CapturedResponse` does not copy its body.
+///
+/// [`Idempotency`]: ../idempotency/struct.Idempotency.html
+/// [`Collapse`]: crate::collapse::Collapse
+///
+/// # Example
+///
+/// ```rust
+/// use rocket::http::{Header, Status};
+/// use rocket::response::CapturedResponse;
+///
+/// let headers = vec![Header::new("Content-Type", "text/plain")];
+/// let response = CapturedResponse::new(Status::Created, headers, b"created".to_vec());
+/// assert_eq!(response.status(), Status::Created);
+/// assert_eq!(response.body(), b"created");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedResponse {
+    status: Status,
+    headers: Vec<Header<'static>>,
+    body: Arc<[u8]>,
+}
+
+/// The reason a response could not be captured.
+#[derive(Debug)]
+pub(crate) enum CaptureError {
+    /// The body is unsized and may thus be arbitrarily large or never end.
+    Unsized,
+    /// The body is larger than the limit.
+    TooLarge(ByteUnit),
+    /// The body could not be read.
+    Io(io::Error),
+}
+
+impl CapturedResponse {
+    /// Returns a captured response with status `status`, headers `headers`,
+    /// and body `body`. Stores that persist responses elsewhere use this to
+    /// reconstruct them.
+    pub fn new(status: Status, headers: Vec<Header<'static>>, body: Vec<u8>) -> Self {
+        CapturedResponse { status, headers, body: body.into() }
+    }
+
+    /// Captures `response` if its body is sized and no larger than `limit`.
+    /// The body of `response` is read into memory and replaced with the copy
+    /// that is captured, so `response` can still be sent.
+    pub(crate) async fn capture(
+        response: &mut Response<'_>,
+        limit: ByteUnit,
+    ) -> Result<Self, CaptureError> {
+        // A streamed body may be arbitrarily large or never end: don't buffer.
+        match response.body_mut().size().await {
+            Some(size) if limit < size as u64 => return Err(CaptureError::TooLarge(limit)),
+            Some(_) => {},
+            None => return Err(CaptureError::Unsized),
+        }
+
+        let body: Arc<[u8]> = response.body_mut().to_bytes().await
+            .map_err(CaptureError::Io)?
+            .into();
+
+        response.set_sized_body(body.len(), Cursor::new(body.clone()));
+        let headers = response.headers().iter()
+            .map(|h| Header::new(h.name.as_str().to_string(), h.value.to_string()))
+            .collect();
+
+        Ok(CapturedResponse { status: response.status(), headers, body })
+    }
+
+    /// The status of the response.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// The headers of the response, in the order they were set.
+    pub fn headers(&self) -> &[Header<'static>] {
+        &self.headers
+    }
+
+    /// The body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Returns a new `Response` with the status, headers, and body of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{Header, Status};
+    /// use rocket::response::CapturedResponse;
+    ///
+    /// # rocket::async_test(async {
+    /// let headers = vec![Header::new("X-Custom", "value")];
+    /// let captured = CapturedResponse::new(Status::Accepted, headers, b"hi".to_vec());
+    ///
+    /// let mut response = captured.to_response();
+    /// assert_eq!(response.status(), Status::Accepted);
+    /// assert_eq!(response.headers().get_one("X-Custom"), Some("value"));
+    /// assert_eq!(response.body_mut().to_string().await.unwrap(), "hi");
+    /// # });
+    /// ```
+    pub fn to_response<'r>(&self) -> Response<'r> {
+        let mut response = Response::new();
+        response.set_status(self.status);
+        for header in &self.headers {
+            response.adjoin_header(header.clone());
+        }
+
+        response.set_sized_body(self.body.len(), Cursor::new(self.body.clone()));
+        response
+    }
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureError::Unsized => write!(f, "body is unsized"),
+            CaptureError::TooLarge(limit) => write!(f, "body is larger than {}", limit),
+            CaptureError::Io(e) => write!(f, "failed to read body: {}", e),
+        }
+    }
+}
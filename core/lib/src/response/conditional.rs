@@ -0,0 +1,106 @@
+use std::borrow::Cow;
+
+use crate::request::Request;
+use crate::response::{self, Response, Responder};
+use crate::response::cacheable::is_not_modified;
+use crate::http::{Header, Status};
+
+/// A responder that answers conditional `GET`s with `304 Not Modified`
+/// without producing the inner response.
+///
+/// A `Conditional` pairs an [entity tag] identifying the current
+/// representation of the resource with a function producing the inner
+/// responder. When responding, if the request is a `GET` or `HEAD` whose
+/// `If-None-Match` header matches the entity tag, by weak comparison, or is
+/// `*`, the function is never called, and the response is a bodiless `304 Not
+/// Modified` with the `ETag` header set. Otherwise, the function is called,
+/// and the response is that of the inner responder with the `ETag` header set.
+///
+/// Unlike [`Cacheable`](crate::response::Cacheable), which wraps an existing
+/// responder, `Conditional` avoids the work of rendering or loading a body
+/// that the client already has. It sets no `Cache-Control` header.
+///
+/// As with `Cacheable`, the entity tag is the `opaque-tag` without surrounding
+/// double quotes, which must not itself contain a `"`. Entity tags are strong
+/// by default; use [`Conditional::weak()`] for a weak tag.
+///
+/// [entity tag]: https://tools.ietf.org/html/rfc7232#section-2.3
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::response::Conditional;
+///
+/// # fn expensive_render(_: usize) -> String { String::new() }
+/// # fn version_of(_: usize) -> usize { 1 }
+/// #[get("/report/<id>")]
+/// fn report(id: usize) -> Conditional<impl FnOnce() -> String> {
+///     let etag = format!("report-{}-v{}", id, version_of(id));
+///     Conditional::new(etag, move || expensive_render(id))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conditional<F> {
+    inner: F,
+    etag: Cow<'static, str>,
+    weak: bool,
+}
+
+impl<F> Conditional<F> {
+    /// Creates a `Conditional` with the strong entity tag `etag` that calls
+    /// `inner` to produce the inner responder only if the client's copy, if
+    /// any, is not current.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Conditional;
+    ///
+    /// let response = Conditional::new("hello-v1", || "Hello, world!".to_string());
+    /// ```
+    pub fn new<E>(etag: E, inner: F) -> Self
+        where E: Into<Cow<'static, str>>
+    {
+        Conditional { inner, etag: etag.into(), weak: false }
+    }
+
+    /// Marks the entity tag as weak, indicating that the representation is
+    /// semantically, but not necessarily byte-for-byte, equivalent to any other
+    /// with the same tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Conditional;
+    ///
+    /// let response = Conditional::new("hello", || "Hello, world!").weak();
+    /// ```
+    pub fn weak(mut self) -> Self {
+        self.weak = true;
+        self
+    }
+}
+
+/// Responds with `304 Not Modified` if the request's `If-None-Match` matches,
+/// without calling the inner function, and with the response of the responder
+/// it returns otherwise. In either case, the `ETag` header is set, replacing
+/// any set by the inner responder.
+impl<'r, 'o: 'r, F, R> Responder<'r, 'o> for Conditional<F>
+    where F: FnOnce() -> R, R: Responder<'r, 'o>
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = match is_not_modified(req, &self.etag) {
+            true => Response::build().status(Status::NotModified).finalize(),
+            false => (self.inner)().respond_to(req)?,
+        };
+
+        let etag = match self.weak {
+            true => format!("W/\"{}\"", self.etag),
+            false => format!("\"{}\"", self.etag),
+        };
+
+        response.set_header(Header::new("ETag", etag));
+        Ok(response)
+    }
+}
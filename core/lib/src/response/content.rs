@@ -99,6 +99,7 @@ ctrs! {
     Json: JSON, "JSON", "application/json",
     Xml: XML, "XML", "text/xml",
     MsgPack: MsgPack, "MessagePack", "application/msgpack",
+    Cbor: CBOR, "CBOR", "application/cbor",
     Html: HTML, "HTML", "text/html",
     Plain: Plain, "plain text", "text/plain",
     Css: CSS, "CSS", "text/css",
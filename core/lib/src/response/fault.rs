@@ -0,0 +1,215 @@
+use std::fmt;
+use std::error::Error;
+
+use yansi::Paint;
+
+use crate::request::Request;
+use crate::response::{self, Responder};
+use crate::http::Status;
+
+type BoxedError = Box<dyn Error + Send + Sync + 'static>;
+
+type Mapper = Box<dyn Fn(&(dyn Error + 'static)) -> Option<Status> + Send + Sync>;
+
+/// An error responder whose status is determined by the error-to-status
+/// mappings registered via [`Rocket::map_error()`].
+///
+/// A `Fault` wraps any value that implements [`std::error::Error`] and can be
+/// created from one with `?`, allowing handlers that return `Result<T, Fault>`
+/// to propagate domain errors without wrapping each return. When a `Fault`
+/// responds, Rocket finds the mapping registered for the type of the wrapped
+/// error and forwards, as an error, the [`Status`] it returns to the matching
+/// catcher. If no mapping was registered for the type, the status is `500
+/// Internal Server Error`. In either case, the error is logged.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::Status;
+/// use rocket::response::Fault;
+///
+/// #[derive(Debug)]
+/// enum AppError { NotFound, Forbidden }
+///
+/// impl std::fmt::Display for AppError {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// impl std::error::Error for AppError { }
+///
+/// fn lookup(id: usize) -> Result<String, AppError> {
+///     match id {
+///         0 => Err(AppError::Forbidden),
+///         1 => Ok("one".into()),
+///         _ => Err(AppError::NotFound),
+///     }
+/// }
+///
+/// #[get("/<id>")]
+/// fn item(id: usize) -> Result<String, Fault> {
+///     Ok(lookup(id)?)
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build()
+///         .map_error(|e: &AppError| match e {
+///             AppError::NotFound => Status::NotFound,
+///             AppError::Forbidden => Status::Forbidden,
+///         })
+///         .mount("/", routes![item])
+/// }
+/// ```
+///
+/// # Interaction with `Responder for Result`
+///
+/// The [`Responder`] implementation for `Result<T, E>` is unchanged: it
+/// requires `E: Responder` and calls the responder of `E` on `Err`. Mappings
+/// thus only apply to errors that respond via a `Fault`; an error type with its
+/// own `Responder` implementation ignores any mapping registered for it. To
+/// return `Result<T, MyError>` directly while still using the registered
+/// mapping, implement `Responder` for `MyError` by delegating to `Fault`:
+///
+/// ```rust
+/// use rocket::request::Request;
+/// use rocket::response::{self, Responder, Fault};
+///
+/// #[derive(Debug)]
+/// struct MyError;
+///
+/// # impl std::fmt::Display for MyError {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         f.write_str("my error")
+/// #     }
+/// # }
+/// #
+/// # impl std::error::Error for MyError { }
+/// #
+/// impl<'r> Responder<'r, 'static> for MyError {
+///     fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+///         Fault::from(self).respond_to(req)
+///     }
+/// }
+/// ```
+///
+/// [`Rocket::map_error()`]: crate::Rocket::map_error()
+pub struct Fault(BoxedError);
+
+impl Fault {
+    /// Wraps `error` in a `Fault`. Equivalent to `Fault::from(error)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Fault;
+    ///
+    /// let error = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    /// let fault = Fault::new(error);
+    /// ```
+    pub fn new<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        Fault(Box::new(error))
+    }
+
+    /// Returns a reference to the wrapped error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Fault;
+    ///
+    /// let error = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    /// let fault = Fault::new(error);
+    /// assert_eq!(fault.error().to_string(), "oh no!");
+    /// ```
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.0
+    }
+
+    /// Returns a reference to the wrapped error if it is of type `E`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Fault;
+    ///
+    /// let error = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    /// let fault = Fault::new(error);
+    /// assert!(fault.downcast_ref::<std::io::Error>().is_some());
+    /// assert!(fault.downcast_ref::<std::fmt::Error>().is_none());
+    /// ```
+    pub fn downcast_ref<E: Error + 'static>(&self) -> Option<&E> {
+        self.0.downcast_ref()
+    }
+
+    /// Consumes `self` and returns the wrapped error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Fault;
+    ///
+    /// let error = std::io::Error::new(std::io::ErrorKind::Other, "oh no!");
+    /// let error = Fault::new(error).into_inner();
+    /// ```
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync + 'static> {
+        self.0
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for Fault {
+    #[inline(always)]
+    fn from(error: E) -> Self {
+        Fault::new(error)
+    }
+}
+
+impl fmt::Debug for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<'r> Responder<'r, 'static> for Fault {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let status = req.rocket().state::<ErrorMap>()
+            .and_then(|map| map.status_of(self.error()))
+            .unwrap_or(Status::InternalServerError);
+
+        warn_!("Fault: {}", Paint::default(&self.0));
+        warn_!("Fault is mapped to {}.", status);
+        Err(status)
+    }
+}
+
+/// The error-to-status mappings registered via `Rocket::map_error()`.
+#[derive(Default)]
+pub(crate) struct ErrorMap(Vec<Mapper>);
+
+impl ErrorMap {
+    pub(crate) fn add<E, F>(&mut self, f: F)
+        where E: Error + Send + Sync + 'static,
+              F: Fn(&E) -> Status + Send + Sync + 'static
+    {
+        self.0.push(Box::new(move |error| error.downcast_ref::<E>().map(&f)));
+    }
+
+    /// The status of the most recently registered mapping for `error`, if any.
+    pub(crate) fn status_of(&self, error: &(dyn Error + 'static)) -> Option<Status> {
+        self.0.iter().rev().find_map(|map| map(error))
+    }
+}
+
+impl fmt::Debug for ErrorMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ErrorMap").field(&self.0.len()).finish()
+    }
+}
@@ -18,7 +18,16 @@ mod responder;
 mod redirect;
 mod response;
 mod debug;
+mod fault;
 mod body;
+mod cacheable;
+mod conditional;
+mod with_language;
+mod paginated;
+mod sized_stream;
+mod box_responder;
+mod multipart;
+mod captured;
 
 pub(crate) mod flash;
 
@@ -30,11 +39,26 @@ pub mod stream;
 pub use rocket_codegen::Responder;
 
 pub use self::response::{Response, Builder};
+pub(crate) use self::response::Upgrade;
 pub use self::body::Body;
 pub use self::responder::Responder;
 pub use self::redirect::Redirect;
 pub use self::flash::Flash;
 pub use self::debug::Debug;
+pub use self::fault::Fault;
+pub(crate) use self::fault::ErrorMap;
+pub use self::cacheable::Cacheable;
+pub use self::conditional::Conditional;
+pub use self::with_language::WithLanguage;
+pub use self::paginated::Paginated;
+pub use self::sized_stream::SizedStream;
+pub use self::box_responder::BoxResponder;
+pub use self::multipart::{Multipart, Part};
+pub use self::captured::CapturedResponse;
+pub(crate) use self::captured::CaptureError;
+
+#[doc(inline)]
+pub use either::Either;
 
 /// Type alias for the `Result` of a [`Responder::respond_to()`] call.
 pub type Result<'r> = std::result::Result<Response<'r>, crate::http::Status>;
@@ -0,0 +1,261 @@
+use std::fmt;
+use std::io::Cursor;
+use std::pin::Pin;
+
+use futures::stream::{self, Stream, StreamExt};
+use rand::{Rng, distributions::Alphanumeric};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::request::Request;
+use crate::response::{self, Responder, Response};
+use crate::response::stream::ReaderStream;
+use crate::http::{ContentType, Header, HeaderMap};
+
+/// The length, in characters, of a generated boundary.
+const BOUNDARY_LEN: usize = 32;
+
+type BoxedReader<'r> = Pin<Box<dyn AsyncRead + Send + 'r>>;
+
+/// A streaming `multipart/mixed` responder.
+///
+/// A `Multipart` wraps a [`Stream`] of [`Part`]s, each with its own headers
+/// and body. Parts are serialized as they are yielded by the stream, as per
+/// [RFC 2046 §5.1], and the body of each part is streamed as it is read: no
+/// part, nor the response as a whole, is buffered. Parts are separated by a
+/// randomly generated boundary.
+///
+/// [`Stream`]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
+/// [RFC 2046 §5.1]: https://tools.ietf.org/html/rfc2046#section-5.1
+///
+/// # Responder
+///
+/// The response `Content-Type` is set to `multipart/mixed` with a `boundary`
+/// parameter of [`Multipart::boundary()`]. The response body is
+/// [unsized](crate::response::Body#unsized).
+///
+/// # Example
+///
+/// Respond with a JSON part followed by a plain-text part:
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::{ContentType, Header};
+/// use rocket::response::{Multipart, Part};
+/// use rocket::futures::stream::{self, Stream};
+///
+/// #[get("/batch")]
+/// fn batch() -> Multipart<impl Stream<Item = Part<'static>>> {
+///     Multipart::new(stream::iter(vec![
+///         Part::new(ContentType::JSON, r#"{ "id": 1 }"#)
+///             .header(Header::new("Content-ID", "<1>")),
+///         Part::new(ContentType::Text, "done"),
+///     ]))
+/// }
+/// ```
+///
+/// Parts can also be produced asynchronously using
+/// [`stream!`](crate::response::stream::stream):
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::ContentType;
+/// use rocket::response::{Multipart, Part};
+/// use rocket::response::stream::stream;
+/// use rocket::futures::stream::Stream;
+/// use rocket::tokio::fs::File;
+///
+/// #[get("/files")]
+/// fn files() -> Multipart<impl Stream<Item = Part<'static>>> {
+///     Multipart::new(stream! {
+///         for path in &["a.txt", "b.txt"] {
+///             if let Ok(file) = File::open(path).await {
+///                 yield Part::reader(ContentType::Plain, file);
+///             }
+///         }
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Multipart<S> {
+    stream: S,
+    boundary: String,
+}
+
+/// A single part of a [`Multipart`] response.
+///
+/// A part consists of headers, which must include a `Content-Type`, and a
+/// body, which is either a sequence of bytes, created with [`Part::new()`], or
+/// an [`AsyncRead`], created with [`Part::reader()`]. Further headers are added
+/// with [`Part::header()`].
+pub struct Part<'r> {
+    headers: HeaderMap<'r>,
+    body: BoxedReader<'r>,
+}
+
+impl<S> Multipart<S> {
+    /// Creates a new `Multipart` response that streams the parts in `stream`
+    /// separated by a randomly generated boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::ContentType;
+    /// use rocket::response::{Multipart, Part};
+    /// use rocket::futures::stream;
+    ///
+    /// let part = Part::new(ContentType::Text, "hello");
+    /// let multipart = Multipart::new(stream::iter(vec![part]));
+    /// ```
+    pub fn new(stream: S) -> Self {
+        let boundary = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(BOUNDARY_LEN)
+            .map(char::from)
+            .collect();
+
+        Multipart { stream, boundary }
+    }
+
+    /// Returns the boundary separating the parts of this response.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::ContentType;
+    /// use rocket::response::{Multipart, Part};
+    /// use rocket::futures::stream;
+    ///
+    /// let part = Part::new(ContentType::Text, "hello");
+    /// let multipart = Multipart::new(stream::iter(vec![part]));
+    /// assert_eq!(multipart.boundary().len(), 32);
+    /// ```
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+}
+
+impl<S> From<S> for Multipart<S> {
+    /// Creates a `Multipart` from any `S: Stream`. Equivalent to
+    /// `Multipart::new(stream)`.
+    fn from(stream: S) -> Self {
+        Multipart::new(stream)
+    }
+}
+
+impl<'r> Part<'r> {
+    /// Creates a new part of type `content_type` with the body `body`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::ContentType;
+    /// use rocket::response::Part;
+    ///
+    /// let text = Part::new(ContentType::Text, "hello");
+    /// let bytes = Part::new(ContentType::Binary, vec![1, 2, 3]);
+    /// ```
+    pub fn new<B>(content_type: ContentType, body: B) -> Self
+        where B: AsRef<[u8]> + Send + Unpin + 'r
+    {
+        Part::reader(content_type, Cursor::new(body))
+    }
+
+    /// Creates a new part of type `content_type` whose body is streamed from
+    /// `reader`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::ContentType;
+    /// use rocket::response::Part;
+    /// use rocket::tokio::fs::File;
+    ///
+    /// # async fn f() -> std::io::Result<()> {
+    /// let file = File::open("report.csv").await?;
+    /// let part = Part::reader(ContentType::CSV, file);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader<R>(content_type: ContentType, reader: R) -> Self
+        where R: AsyncRead + Send + 'r
+    {
+        let mut headers = HeaderMap::new();
+        headers.add(content_type);
+        Part { headers, body: Box::pin(reader) }
+    }
+
+    /// Sets the header `header` in this part, replacing any existing headers
+    /// of the same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::{ContentType, Header};
+    /// use rocket::response::Part;
+    ///
+    /// let part = Part::new(ContentType::JSON, "{}")
+    ///     .header(Header::new("Content-ID", "<item-1>"))
+    ///     .header(ContentType::new("application", "problem+json"));
+    ///
+    /// assert_eq!(part.headers().get_one("Content-ID"), Some("<item-1>"));
+    /// assert_eq!(part.headers().get_one("Content-Type"), Some("application/problem+json"));
+    /// ```
+    pub fn header<'h: 'r, H: Into<Header<'h>>>(mut self, header: H) -> Self {
+        self.headers.replace(header);
+        self
+    }
+
+    /// Returns the headers of this part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::ContentType;
+    /// use rocket::response::Part;
+    ///
+    /// let part = Part::new(ContentType::Text, "hello");
+    /// assert_eq!(part.headers().get_one("Content-Type"), Some("text/plain; charset=utf-8"));
+    /// ```
+    pub fn headers(&self) -> &HeaderMap<'r> {
+        &self.headers
+    }
+
+    /// The delimiter, headers, and body of this part, in that order.
+    fn into_reader(self, boundary: &str) -> BoxedReader<'r> {
+        let mut head = format!("--{}\r\n", boundary);
+        for header in self.headers.iter() {
+            head.push_str(&format!("{}\r\n", header));
+        }
+
+        head.push_str("\r\n");
+        Box::pin(Cursor::new(head).chain(self.body).chain(Cursor::new("\r\n")))
+    }
+}
+
+impl fmt::Debug for Part<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Part")
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'r, 'o: 'r, S> Responder<'r, 'o> for Multipart<S>
+    where S: Stream<Item = Part<'o>> + Send + 'o
+{
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+        let boundary = self.boundary;
+        let content_type = ContentType::with_params("multipart", "mixed",
+            ("boundary", boundary.clone()));
+
+        let close: BoxedReader<'o> = Box::pin(Cursor::new(format!("--{}--\r\n", boundary)));
+        let parts = self.stream
+            .map(move |part| part.into_reader(&boundary))
+            .chain(stream::once(async move { close }));
+
+        Response::build()
+            .header(content_type)
+            .streamed_body(ReaderStream::from(parts))
+            .ok()
+    }
+}
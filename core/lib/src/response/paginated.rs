@@ -0,0 +1,132 @@
+use std::ops::RangeInclusive;
+
+use crate::request::Request;
+use crate::response::{self, Responder};
+use crate::http::{Header, Status};
+
+/// A responder for a page of items from a paginated collection.
+///
+/// A `Paginated` wraps a responder for the items at some range of indices
+/// within a collection. It sets the `Content-Range` header to the range in
+/// the `items` unit, as in `Content-Range: items 0-24/100`, where the total
+/// number of items may be unknown and serialized as `*`. It also sets
+/// `Accept-Ranges: items`, advertising that the resource may be requested
+/// in pages.
+///
+/// The status of the response is set to `206 Partial Content` unless the
+/// range is known to span the complete collection, in which case the wrapped
+/// responder's status is kept. A response without any items, as for an empty
+/// collection, is created with [`Paginated::empty()`].
+///
+/// See [`ItemRange`](crate::request::ItemRange) for parsing the request half
+/// of `items` pagination.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::response::Paginated;
+///
+/// #[get("/numbers")]
+/// fn numbers() -> Option<Paginated<&'static str>> {
+///     // Responds `206` with `Content-Range: items 0-2/10`.
+///     Paginated::new("0, 1, 2", 0..=2, Some(10))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Paginated<R> {
+    responder: R,
+    range: Option<RangeInclusive<u64>>,
+    total: Option<u64>,
+}
+
+impl<R> Paginated<R> {
+    /// Wraps `responder`, the items with indices in `range`, of a collection
+    /// of `total` items, or of unknown size if `total` is `None`. Returns
+    /// `None` if `range` is empty or, when `total` is known, ends at or after
+    /// `total`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::Paginated;
+    ///
+    /// assert!(Paginated::new("a, b", 0..=1, Some(10)).is_some());
+    /// assert!(Paginated::new("k, l", 10..=11, None).is_some());
+    ///
+    /// assert!(Paginated::new("a, b", 1..=0, Some(10)).is_none());
+    /// assert!(Paginated::new("a, b", 9..=10, Some(10)).is_none());
+    /// ```
+    pub fn new(responder: R, range: RangeInclusive<u64>, total: Option<u64>) -> Option<Self> {
+        if range.is_empty() || matches!(total, Some(total) if *range.end() >= total) {
+            return None;
+        }
+
+        Some(Paginated { responder, range: Some(range), total })
+    }
+
+    /// Wraps `responder`, containing no items of a collection of `total`
+    /// items. The `Content-Range` is `items */total`.
+    ///
+    /// This is the response for an empty collection. With a status of `416
+    /// Range Not Satisfiable`, it is also the response for a range starting
+    /// after the last item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Status;
+    /// use rocket::request::ItemRange;
+    /// use rocket::response::{Paginated, status};
+    ///
+    /// #[get("/empty")]
+    /// fn empty() -> Paginated<&'static str> {
+    ///     Paginated::empty("", 0)
+    /// }
+    ///
+    /// #[get("/unsatisfiable")]
+    /// fn unsatisfiable(_range: ItemRange) -> status::Custom<Paginated<()>> {
+    ///     status::Custom(Status::RangeNotSatisfiable, Paginated::empty((), 10))
+    /// }
+    /// ```
+    pub fn empty(responder: R, total: u64) -> Self {
+        Paginated { responder, range: None, total: Some(total) }
+    }
+
+    /// Returns `true` if the range is known to span the complete collection.
+    fn is_complete(&self) -> bool {
+        match (&self.range, self.total) {
+            (Some(range), Some(total)) => *range.start() == 0 && *range.end() + 1 == total,
+            (None, _) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Sets the `Content-Range` and `Accept-Ranges` headers, overriding any set
+/// by the wrapped responder, and sets the status to `206 Partial Content`
+/// unless the range spans the complete collection.
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Paginated<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let is_complete = self.is_complete();
+        let range = match &self.range {
+            Some(range) => format!("{}-{}", range.start(), range.end()),
+            None => "*".into(),
+        };
+
+        let total = match self.total {
+            Some(total) => total.to_string(),
+            None => "*".into(),
+        };
+
+        let mut response = self.responder.respond_to(req)?;
+        response.set_header(Header::new("Content-Range", format!("items {}/{}", range, total)));
+        response.set_header(Header::new("Accept-Ranges", "items"));
+        if !is_complete {
+            response.set_status(Status::PartialContent);
+        }
+
+        Ok(response)
+    }
+}
@@ -69,7 +69,8 @@ use crate::request::Request;
 ///
 ///     Responds with a streamed body containing the data in the `File`. No
 ///     `Content-Type` is set. To automatically have a `Content-Type` set based
-///     on the file's extension, use [`NamedFile`](crate::fs::NamedFile).
+///     on the file's extension, use [`NamedFile`](crate::fs::NamedFile) or,
+///     for a file that's already open, [`OpenFile`](crate::fs::OpenFile).
 ///
 ///   * **()**
 ///
@@ -87,6 +88,26 @@ use crate::request::Request;
 ///     to the client. If the `Result` is `Err`, the wrapped `Err` responder is
 ///     used to respond to the client.
 ///
+///   * **Either&lt;T, E>**
+///
+///     If the [`Either`](crate::response::Either) is `Left`, the wrapped `Left`
+///     responder is used to respond to the client. If it is `Right`, the
+///     wrapped `Right` responder is used to respond to the client. This is
+///     useful for handlers that return one of two unrelated responder types:
+///
+///     ```rust
+///     # use rocket::get;
+///     use rocket::response::{Either, Redirect};
+///
+///     #[get("/<id>")]
+///     fn item(id: usize) -> Either<String, Redirect> {
+///         match id {
+///             0 => Either::Right(Redirect::to("/1")),
+///             _ => Either::Left(format!("item {}", id)),
+///         }
+///     }
+///     ```
+///
 /// # Implementation Tips
 ///
 /// This section describes a few best practices to take into account when
@@ -215,28 +236,40 @@ impl<'r> Responder<'r, 'static> for String {
     }
 }
 
-/// Returns a response with Content-Type `application/octet-stream` and a
-/// fixed-size body containing the data in `self`. Always returns `Ok`.
+/// Returns a response with Content-Type `application/octet-stream`, or the
+/// [`content_type`](crate::Route::content_type) of the route handling the
+/// request if it has one, and a fixed-size body containing the data in `self`.
+/// Always returns `Ok`.
 impl<'r, 'o: 'r> Responder<'r, 'o> for &'o [u8] {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
         Response::build()
-            .header(ContentType::Binary)
+            .header(binary_content_type(req))
             .sized_body(self.len(), Cursor::new(self))
             .ok()
     }
 }
 
-/// Returns a response with Content-Type `application/octet-stream` and a
-/// fixed-size body containing the data in `self`. Always returns `Ok`.
+/// Returns a response with Content-Type `application/octet-stream`, or the
+/// [`content_type`](crate::Route::content_type) of the route handling the
+/// request if it has one, and a fixed-size body containing the data in `self`.
+/// Always returns `Ok`.
 impl<'r> Responder<'r, 'static> for Vec<u8> {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
         Response::build()
-            .header(ContentType::Binary)
+            .header(binary_content_type(req))
             .sized_body(self.len(), Cursor::new(self))
             .ok()
     }
 }
 
+/// The `Content-Type` of raw bytes: that of the route handling `req`, if it
+/// has one, and `application/octet-stream` otherwise.
+fn binary_content_type(req: &Request<'_>) -> ContentType {
+    req.route()
+        .and_then(|route| route.content_type.clone())
+        .unwrap_or(ContentType::Binary)
+}
+
 /// Returns a response with a sized body for the file. Always returns `Ok`.
 impl<'r> Responder<'r, 'static> for File {
     fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
@@ -297,15 +330,15 @@ impl<'r, 'o: 'r, 't: 'o, 'e: 'o, T, E> Responder<'r, 'o> for Result<T, E>
     }
 }
 
-// Responds with the wrapped `Responder` in `self`, whether it is `Left` or
+/// Responds with the wrapped `Responder` in `self`, whether it is `Left` or
 /// `Right`.
-impl<'r, 'o: 'r, 't: 'o, 'e: 'o, T, E> Responder<'r, 'o> for crate::Either<T, E>
+impl<'r, 'o: 'r, 't: 'o, 'e: 'o, T, E> Responder<'r, 'o> for crate::response::Either<T, E>
     where T: Responder<'r, 't>, E: Responder<'r, 'e>
 {
     fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
         match self {
-            crate::Either::Left(r) => r.respond_to(req),
-            crate::Either::Right(r) => r.respond_to(req),
+            crate::response::Either::Left(r) => r.respond_to(req),
+            crate::response::Either::Right(r) => r.respond_to(req),
         }
     }
 }
@@ -1,10 +1,19 @@
 use std::{fmt, str};
 use std::borrow::Cow;
+use std::pin::Pin;
 
 use tokio::io::{AsyncRead, AsyncSeek};
 
 use crate::http::{Header, HeaderMap, Status, ContentType, Cookie};
+use crate::http::uncased::{Uncased, UncasedStr};
 use crate::response::Body;
+use crate::data::IoHandler;
+
+/// A callback that computes a response's trailers once its body is complete.
+type Trailers<'r> = Box<dyn FnOnce() -> HeaderMap<'static> + Send + 'r>;
+
+/// A protocol and the handler for a connection upgraded to it.
+pub(crate) type Upgrade<'r> = (Uncased<'r>, Pin<Box<dyn IoHandler + 'r>>);
 
 /// Builder for the [`Response`] type.
 ///
@@ -282,6 +291,74 @@ impl<'r> Builder<'r> {
         self
     }
 
+    /// Sets the trailers of the response to those returned by `f`, declaring
+    /// the trailer field `names` in a `Trailer` header.
+    ///
+    /// See [`Response::set_trailers()`] for notes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::Response;
+    /// use rocket::http::{Header, HeaderMap};
+    ///
+    /// let response = Response::build()
+    ///     .streamed_body(Cursor::new("Hello, world!"))
+    ///     .trailers(&["X-Checksum"], || {
+    ///         let mut trailers = HeaderMap::new();
+    ///         trailers.add(Header::new("X-Checksum", "abc123"));
+    ///         trailers
+    ///     })
+    ///     .finalize();
+    ///
+    /// assert_eq!(response.headers().get_one("Trailer"), Some("X-Checksum"));
+    /// ```
+    #[inline(always)]
+    pub fn trailers<F>(&mut self, names: &[&str], f: F) -> &mut Builder<'r>
+        where F: FnOnce() -> HeaderMap<'static> + Send + 'r
+    {
+        self.response.set_trailers(names, f);
+        self
+    }
+
+    /// Registers `handler` to handle the connection if it is upgraded to
+    /// `protocol`.
+    ///
+    /// See [`Response::set_upgrade()`] for notes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::pin::Pin;
+    ///
+    /// use rocket::Response;
+    /// use rocket::data::{IoHandler, IoStream};
+    ///
+    /// struct Handler;
+    ///
+    /// #[rocket::async_trait]
+    /// impl IoHandler for Handler {
+    ///     async fn io(self: Pin<Box<Self>>, io: IoStream) -> std::io::Result<()> {
+    ///         /* .. */
+    /// #       Ok(())
+    ///     }
+    /// }
+    ///
+    /// let response = Response::build()
+    ///     .upgrade("echo", Handler)
+    ///     .finalize();
+    ///
+    /// assert_eq!(response.upgrade_protocol(), Some("echo"));
+    /// ```
+    #[inline(always)]
+    pub fn upgrade<P, H>(&mut self, protocol: P, handler: H) -> &mut Builder<'r>
+        where P: Into<Uncased<'r>>, H: IoHandler + 'r
+    {
+        self.response.set_upgrade(protocol, handler);
+        self
+    }
+
     /// Merges the `other` `Response` into `self` by setting any fields in
     /// `self` to the corresponding value in `other` if they are set in `other`.
     /// Fields in `self` are unchanged if they are not set in `other`. If a
@@ -413,6 +490,8 @@ pub struct Response<'r> {
     status: Option<Status>,
     headers: HeaderMap<'r>,
     body: Body<'r>,
+    trailers: Option<Trailers<'r>>,
+    upgrade: Option<Upgrade<'r>>,
 }
 
 impl<'r> Response<'r> {
@@ -837,9 +916,152 @@ impl<'r> Response<'r> {
         self.body_mut().set_max_chunk_size(size);
     }
 
-    /// Replaces this response's status and body with that of `other`, if they
-    /// exist in `other`. Any headers that exist in `other` replace the ones in
-    /// `self`. Any in `self` that aren't in `other` remain in `self`.
+    /// Sets the trailers of `self` to those returned by `f`, replacing any
+    /// previously set trailers, and sets the `Trailer` header to the
+    /// comma-separated list of `names`.
+    ///
+    /// Trailers are header fields sent after the body. Because `f` is only
+    /// called once the body has been written in its entirety, trailers may
+    /// carry values computed from the body itself, such as checksums. The
+    /// `Trailer` header is sent before the body, so `names` must list the
+    /// fields `f` is expected to return.
+    ///
+    /// Trailers are only sent over `HTTP/2` connections, in a final `HEADERS`
+    /// frame; Rocket does not write `HTTP/1.1` chunked trailers. Over
+    /// `HTTP/1.x`, the trailers are discarded without `f` being called, and
+    /// the `Trailer` header is removed before the response is sent so that
+    /// clients don't wait for fields that never arrive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::Response;
+    /// use rocket::http::{Header, HeaderMap};
+    ///
+    /// # rocket::async_test(async {
+    /// let mut response = Response::new();
+    /// response.set_streamed_body(Cursor::new("Hello, world!"));
+    /// response.set_trailers(&["X-Length"], || {
+    ///     let mut trailers = HeaderMap::new();
+    ///     trailers.add(Header::new("X-Length", "13"));
+    ///     trailers
+    /// });
+    ///
+    /// assert_eq!(response.headers().get_one("Trailer"), Some("X-Length"));
+    ///
+    /// let body = response.body_mut().to_string().await.unwrap();
+    /// let trailers = response.take_trailers().unwrap();
+    /// assert_eq!(body, "Hello, world!");
+    /// assert_eq!(trailers.get_one("X-Length"), Some("13"));
+    /// # })
+    /// ```
+    pub fn set_trailers<F>(&mut self, names: &[&str], f: F)
+        where F: FnOnce() -> HeaderMap<'static> + Send + 'r
+    {
+        self.set_header(Header::new("Trailer", names.join(", ")));
+        self.trailers = Some(Box::new(f));
+    }
+
+    /// Computes and returns the trailers of `self`, if any were set via
+    /// [`Response::set_trailers()`]. Subsequent calls return `None`.
+    ///
+    /// The trailers callback is invoked by this method. It should thus only be
+    /// called after the body has been read in its entirety.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Response;
+    /// use rocket::http::HeaderMap;
+    ///
+    /// let mut response = Response::new();
+    /// assert!(response.take_trailers().is_none());
+    ///
+    /// response.set_trailers(&[], HeaderMap::new);
+    /// assert!(response.take_trailers().is_some());
+    /// assert!(response.take_trailers().is_none());
+    /// ```
+    pub fn take_trailers(&mut self) -> Option<HeaderMap<'static>> {
+        self.trailers.take().map(|f| f())
+    }
+
+    /// Removes the trailers of `self`, if any, without computing them, and
+    /// the `Trailer` header declaring them. Returns `true` if there were any.
+    pub(crate) fn discard_trailers(&mut self) -> bool {
+        let discarded = self.trailers.take().is_some();
+        if discarded {
+            self.remove_header("Trailer");
+        }
+
+        discarded
+    }
+
+    /// Registers `handler` to handle the connection if it is upgraded to
+    /// `protocol`, replacing any previously registered handler.
+    ///
+    /// A connection is upgraded when the request's `Connection` header
+    /// contains `upgrade` and its `Upgrade` header lists `protocol`, compared
+    /// case-insensitively. Rocket then responds with `101 Switching Protocols`,
+    /// sets the `Connection` and `Upgrade` headers, and discards the body. Once
+    /// the response has been sent, `handler` is invoked with an
+    /// [`IoStream`](crate::data::IoStream) to the client. Otherwise, the
+    /// response is sent as is and `handler` is dropped. Only `HTTP/1.1`
+    /// connections can be upgraded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::pin::Pin;
+    ///
+    /// use rocket::Response;
+    /// use rocket::data::{IoHandler, IoStream};
+    ///
+    /// struct Handler;
+    ///
+    /// #[rocket::async_trait]
+    /// impl IoHandler for Handler {
+    ///     async fn io(self: Pin<Box<Self>>, io: IoStream) -> std::io::Result<()> {
+    ///         /* .. */
+    /// #       Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut response = Response::new();
+    /// assert_eq!(response.upgrade_protocol(), None);
+    ///
+    /// response.set_upgrade("echo", Handler);
+    /// assert_eq!(response.upgrade_protocol(), Some("echo"));
+    /// ```
+    pub fn set_upgrade<P, H>(&mut self, protocol: P, handler: H)
+        where P: Into<Uncased<'r>>, H: IoHandler + 'r
+    {
+        self.upgrade = Some((protocol.into(), Box::pin(handler)));
+    }
+
+    /// Returns the protocol a handler was registered for via
+    /// [`Response::set_upgrade()`], if any.
+    pub fn upgrade_protocol(&self) -> Option<&str> {
+        self.upgrade.as_ref().map(|(protocol, _)| protocol.as_str())
+    }
+
+    /// Takes the upgrade handler if it was registered for one of `protocols`,
+    /// returning the protocol along with the handler.
+    pub(crate) fn take_upgrade<'a, I>(&mut self, protocols: I) -> Option<Upgrade<'r>>
+        where I: IntoIterator<Item = &'a str>
+    {
+        let registered = self.upgrade.as_ref().map(|(protocol, _)| protocol.as_uncased_str())?;
+        if protocols.into_iter().any(|p| UncasedStr::new(p) == registered) {
+            return self.upgrade.take();
+        }
+
+        None
+    }
+
+    /// Replaces this response's status, body, trailers, and upgrade handler
+    /// with those of `other`, if they exist in `other`. Any headers that exist
+    /// in `other` replace the ones in `self`. Any in `self` that aren't in
+    /// `other` remain in `self`.
     ///
     /// # Example
     ///
@@ -877,14 +1099,22 @@ impl<'r> Response<'r> {
             self.body = other.body;
         }
 
+        if other.trailers.is_some() {
+            self.trailers = other.trailers;
+        }
+
+        if other.upgrade.is_some() {
+            self.upgrade = other.upgrade;
+        }
+
         for (name, values) in other.headers.into_iter_raw() {
             self.headers.replace_all(name.into_cow(), values);
         }
     }
 
-    /// Sets `self`'s status and body to that of `other` if they are not already
-    /// set in `self`. Any headers present in both `other` and `self` are
-    /// adjoined.
+    /// Sets `self`'s status, body, trailers, and upgrade handler to those of
+    /// `other` if they are not already set in `self`. Any headers present in
+    /// both `other` and `self` are adjoined.
     ///
     /// # Example
     ///
@@ -922,6 +1152,14 @@ impl<'r> Response<'r> {
             self.body = other.body;
         }
 
+        if self.trailers.is_none() {
+            self.trailers = other.trailers;
+        }
+
+        if self.upgrade.is_none() {
+            self.upgrade = other.upgrade;
+        }
+
         for (name, mut values) in other.headers.into_iter_raw() {
             self.headers.add_all(name.into_cow(), &mut values);
         }
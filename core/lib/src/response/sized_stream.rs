@@ -0,0 +1,90 @@
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::request::Request;
+use crate::response::{self, Body, Responder, Response};
+
+/// A responder that streams an [`AsyncRead`] with a known length.
+///
+/// A sized body must ordinarily implement [`AsyncSeek`] so that its size can
+/// be computed. A `SizedStream` instead takes the length of a reader that
+/// can't seek, such as a network stream, up front. The response is sent with a
+/// `Content-Length` of that length and the reader is streamed as its bytes
+/// arrive, without buffering. If no length is given, the reader is streamed
+/// with chunked encoding, as with
+/// [`ReaderStream`](crate::response::stream::ReaderStream).
+///
+/// At most `length` bytes are read from the reader. The reader must not yield
+/// fewer: the connection is closed if it ends early, as the client would
+/// otherwise wait for the missing bytes.
+///
+/// [`AsyncSeek`]: tokio::io::AsyncSeek
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::response::SizedStream;
+/// use rocket::tokio::net::TcpStream;
+///
+/// #[get("/upstream")]
+/// async fn upstream() -> std::io::Result<SizedStream<TcpStream>> {
+///     let (stream, length) = connect_upstream().await?;
+///     Ok(SizedStream::new(stream, length))
+/// }
+///
+/// # async fn connect_upstream() -> std::io::Result<(TcpStream, usize)> {
+/// #     Ok((TcpStream::connect("127.0.0.1:9999").await?, 1024))
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizedStream<R> {
+    reader: R,
+    size: Option<usize>,
+}
+
+impl<R: AsyncRead> SizedStream<R> {
+    /// Streams `reader`, of `size` bytes if `size` is `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::SizedStream;
+    /// use rocket::tokio::io::repeat;
+    ///
+    /// let sized = SizedStream::new(repeat(b'a'), 1024);
+    /// assert_eq!(sized.size(), Some(1024));
+    ///
+    /// let chunked = SizedStream::new(repeat(b'a'), None);
+    /// assert_eq!(chunked.size(), None);
+    /// ```
+    pub fn new<S: Into<Option<usize>>>(reader: R, size: S) -> Self {
+        SizedStream { reader, size: size.into() }
+    }
+
+    /// Returns the length of the stream, if it is known.
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// Returns the wrapped reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// Streams the reader with a `Content-Length` if its length is known and with
+/// chunked encoding otherwise.
+impl<'r, 'o: 'r, R: AsyncRead + Send + 'o> Responder<'r, 'o> for SizedStream<R> {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = Response::new();
+        match self.size {
+            Some(size) => {
+                let reader = self.reader.take(size as u64);
+                *response.body_mut() = Body::with_unsized_preset(reader, size);
+            }
+            None => response.set_streamed_body(self.reader),
+        }
+
+        Ok(response)
+    }
+}
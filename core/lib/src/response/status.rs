@@ -31,7 +31,8 @@ use std::borrow::Cow;
 
 use crate::request::Request;
 use crate::response::{self, Responder, Response};
-use crate::http::Status;
+use crate::http::{Status, RetryAfter, LinkSet, Csp, Challenge, Header};
+use crate::shield::Policy;
 
 /// Sets the status of the response to 201 (Created).
 ///
@@ -463,5 +464,146 @@ impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (Status, R) {
     }
 }
 
+/// Sets the `Retry-After` header of the response and then delegates the
+/// remainder of the response to the wrapped responder.
+///
+/// Typically paired with a status of **503 Service Unavailable** or **429 Too
+/// Many Requests**:
+///
+/// ```rust
+/// # use rocket::get;
+/// use std::time::Duration;
+/// use rocket::http::{Status, RetryAfter};
+///
+/// #[get("/")]
+/// fn busy() -> (Status, (RetryAfter, &'static str)) {
+///     let retry = RetryAfter::Delay(Duration::from_secs(30));
+///     (Status::ServiceUnavailable, (retry, "Come back in a bit."))
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (RetryAfter, R) {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        Response::build_from(self.1.respond_to(req)?)
+            .header(self.0)
+            .ok()
+    }
+}
+
+/// Sets the `Link` header of the response to the links in the [`LinkSet`] and
+/// then delegates the remainder of the response to the wrapped responder. Any
+/// existing `Link` header set by the wrapped responder is replaced.
+///
+/// Typically used to advertise pagination links:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::LinkSet;
+///
+/// #[get("/items?<page>")]
+/// fn items(page: usize) -> (LinkSet, String) {
+///     let links = vec![
+///         (uri!(items(page = page + 1)), "next"),
+///         (uri!(items(page = page.saturating_sub(1))), "prev"),
+///     ];
+///
+///     (links.into_iter().collect(), format!("page {}", page))
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (LinkSet, R) {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        Response::build_from(self.1.respond_to(req)?)
+            .header(self.0)
+            .ok()
+    }
+}
+
+/// Sets the `Content-Security-Policy` header of the response to the [`Csp`]
+/// policy and then delegates the remainder of the response to the wrapped
+/// responder. Any existing `Content-Security-Policy` header set by the wrapped
+/// responder is replaced. Because [`Shield`](crate::shield::Shield) doesn't
+/// overwrite existing headers, this policy takes precedence over a policy
+/// enabled in `Shield`.
+///
+/// A [`CspSource::RequestNonce`](crate::http::CspSource::RequestNonce) in the
+/// policy is rendered with the request's [`CspNonce`](crate::shield::CspNonce).
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{Csp, CspSource};
+/// use rocket::response::content::Html;
+/// use rocket::shield::CspNonce;
+///
+/// #[get("/")]
+/// fn index(nonce: &CspNonce) -> (Csp, Html<String>) {
+///     let csp = Csp::new().script_src(CspSource::RequestNonce);
+///     let html = format!(r#"<script nonce="{}">alert("hi!")</script>"#, nonce);
+///     (csp, Html(html))
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (Csp, R) {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let header: Header<'static> = match self.0.header_for(req) {
+            Some(header) => header,
+            None => self.0.into(),
+        };
+
+        Response::build_from(self.1.respond_to(req)?)
+            .header(header)
+            .ok()
+    }
+}
+
+/// Adds a `WWW-Authenticate` header with the [`Challenge`] to the response and
+/// then delegates the remainder of the response to the wrapped responder.
+///
+/// Typically paired with a status of **401 Unauthorized** or, for errors in a
+/// `Bearer` challenge, the status of the error:
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::{Challenge, BearerError};
+/// use rocket::response::status;
+///
+/// #[get("/")]
+/// fn expired() -> status::Custom<(Challenge, &'static str)> {
+///     let error = BearerError::InvalidToken;
+///     let challenge = Challenge::bearer().realm("api").error(error);
+///     status::Custom(error.status(), (challenge, "Token expired."))
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (Challenge, R) {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        Response::build_from(self.1.respond_to(req)?)
+            .header_adjoin(self.0)
+            .ok()
+    }
+}
+
+/// Adds a `WWW-Authenticate` header for each [`Challenge`] to the response, in
+/// order, and then delegates the remainder of the response to the wrapped
+/// responder. Clients pick the first challenge whose scheme they support.
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::http::Challenge;
+/// use rocket::response::status;
+///
+/// #[get("/")]
+/// fn login() -> status::Unauthorized<(Vec<Challenge>, &'static str)> {
+///     let challenges = vec![Challenge::bearer().realm("api"), Challenge::basic("api")];
+///     status::Unauthorized(Some((challenges, "Authentication required.")))
+/// }
+/// ```
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for (Vec<Challenge>, R) {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.1.respond_to(req)?;
+        for challenge in self.0 {
+            response.adjoin_header(challenge);
+        }
+
+        Ok(response)
+    }
+}
+
 // The following are unimplemented.
 // 206 Partial Content (variant), 203 Non-Authoritative Information (headers).
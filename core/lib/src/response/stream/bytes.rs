@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use futures::stream::{Stream, StreamExt};
 
 use crate::request::Request;
 use crate::response::{self, Response, Responder};
-use crate::http::ContentType;
-use crate::response::stream::ReaderStream;
+use crate::http::{ContentType, HeaderMap};
+use crate::response::stream::{ReaderStream, WithTrailers, Coalesced};
 
 /// A potentially infinite stream of bytes: any `T: AsRef<[u8]>`.
 ///
@@ -54,6 +56,50 @@ use crate::response::stream::ReaderStream;
 #[derive(Debug, Clone)]
 pub struct ByteStream<S>(pub S);
 
+impl<S> ByteStream<S> {
+    /// Sends the trailers returned by `f`, declared as `names`, after the
+    /// body of this stream over `HTTP/2` connections. See [`WithTrailers`]
+    /// for details and an example.
+    pub fn with_trailers<F>(self, names: &[&str], f: F) -> WithTrailers<Self, F>
+        where F: FnOnce() -> HeaderMap<'static>
+    {
+        WithTrailers::new(self, names, f)
+    }
+
+    /// Coalesces the items of this stream into writes of up to `bytes` bytes.
+    /// See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::ByteStream;
+    /// use rocket::futures::stream;
+    ///
+    /// let stream = ByteStream::from(stream::iter(vec![&[1u8, 2, 3][..]]));
+    /// let stream = stream.flush_after_bytes(1024);
+    /// ```
+    pub fn flush_after_bytes(self, bytes: usize) -> Coalesced<Self> {
+        Coalesced::new(self).flush_after_bytes(bytes)
+    }
+
+    /// Coalesces the items of this stream, holding them back for at most
+    /// `interval` while the stream is idle. See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::ByteStream;
+    /// use rocket::futures::stream;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let stream = ByteStream::from(stream::iter(vec![&[1u8, 2, 3][..]]));
+    /// let stream = stream.flush_interval(Duration::from_millis(10));
+    /// ```
+    pub fn flush_interval<I: Into<Option<Duration>>>(self, interval: I) -> Coalesced<Self> {
+        Coalesced::new(self).flush_interval(interval)
+    }
+}
+
 impl<S> From<S> for ByteStream<S> {
     /// Creates a `ByteStream` from any `S: Stream`.
     fn from(stream: S) -> Self {
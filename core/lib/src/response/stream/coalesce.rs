@@ -0,0 +1,251 @@
+use std::io;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::time::{sleep, Sleep};
+use pin_project_lite::pin_project;
+
+use crate::request::Request;
+use crate::response::{self, Response, Responder};
+
+/// A responder that coalesces the body of another responder into fewer,
+/// larger writes.
+///
+/// A `Coalesced` is typically created via the `flush_interval()` or
+/// `flush_after_bytes()` methods of one of the typed streams,
+/// [`ReaderStream`], [`ByteStream`], [`TextStream`], or [`EventStream`],
+/// though it can wrap any responder. Streams send every item as soon as it is
+/// yielded; for streams of many small items, this results in one write to the
+/// connection per item. A `Coalesced` instead buffers items and writes them
+/// out together, trading latency for throughput:
+///
+///   * Once [`flush_after_bytes()`](Coalesced::flush_after_bytes()) bytes are
+///     buffered, they are written immediately. The default is the response's
+///     [maximum chunk size](crate::response::Body::max_chunk_size()).
+///   * When the stream has no items ready, buffered bytes are written out
+///     [`flush_interval()`](Coalesced::flush_interval()) after the first of
+///     them was buffered. If no interval is set, the default, they are written
+///     out right away, so only items that are yielded back-to-back coalesce.
+///   * When the stream ends, all buffered bytes are written out.
+///
+/// The flush interval thus bounds the latency added to any item, no matter
+/// how long the stream remains idle.
+///
+/// [`ReaderStream`]: struct@crate::response::stream::ReaderStream
+/// [`ByteStream`]: struct@crate::response::stream::ByteStream
+/// [`TextStream`]: struct@crate::response::stream::TextStream
+/// [`EventStream`]: struct@crate::response::stream::EventStream
+///
+/// # Responder
+///
+/// The response from the wrapped responder is used with its body replaced by
+/// the coalescing body. Responses with a [sized](crate::response::Body#sized)
+/// body are passed through unchanged.
+///
+/// # Example
+///
+/// Send numbers in batches of up to `1KiB`, at most `50ms` late:
+///
+/// ```rust
+/// # use rocket::*;
+/// use rocket::tokio::time::Duration;
+/// use rocket::response::stream::{Coalesced, TextStream};
+///
+/// #[get("/numbers")]
+/// fn numbers() -> Coalesced<TextStream![String]> {
+///     let stream = TextStream! {
+///         for i in 0..10_000 {
+///             yield format!("{}\n", i);
+///         }
+///     };
+///
+///     stream.flush_after_bytes(1024).flush_interval(Duration::from_millis(50))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Coalesced<R> {
+    responder: R,
+    interval: Option<Duration>,
+    max_bytes: Option<usize>,
+}
+
+impl<R> Coalesced<R> {
+    /// Wraps `responder` so that its body is coalesced with the default
+    /// settings: items yielded back-to-back are written out together, up to
+    /// the maximum chunk size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::{Coalesced, TextStream};
+    /// use rocket::futures::stream;
+    ///
+    /// let response = Coalesced::new(TextStream::from(stream::iter(vec!["a", "b"])));
+    /// ```
+    pub fn new(responder: R) -> Self {
+        Coalesced { responder, interval: None, max_bytes: None }
+    }
+
+    /// Sets how long buffered bytes may be held back while the stream is
+    /// idle. A `None` interval, the default, writes out buffered bytes as soon
+    /// as the stream is idle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tokio::time::Duration;
+    /// use rocket::response::stream::{Coalesced, TextStream};
+    /// use rocket::futures::stream;
+    ///
+    /// let response = Coalesced::new(TextStream::from(stream::iter(vec!["a", "b"])))
+    ///     .flush_interval(Duration::from_millis(10));
+    /// ```
+    pub fn flush_interval<I: Into<Option<Duration>>>(mut self, interval: I) -> Self {
+        self.interval = interval.into();
+        self
+    }
+
+    /// Sets the number of buffered bytes that are written out immediately.
+    /// The default is the response's maximum chunk size. A value of `0` is
+    /// treated as `1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::{Coalesced, TextStream};
+    /// use rocket::futures::stream;
+    ///
+    /// let response = Coalesced::new(TextStream::from(stream::iter(vec!["a", "b"])))
+    ///     .flush_after_bytes(16 * 1024);
+    /// ```
+    pub fn flush_after_bytes(mut self, bytes: usize) -> Self {
+        self.max_bytes = Some(bytes);
+        self
+    }
+}
+
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for Coalesced<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.responder.respond_to(req)?;
+        if response.body().is_none() || response.body().preset_size().is_some() {
+            return Ok(response);
+        }
+
+        let max_chunk = response.body().max_chunk_size();
+        let max_bytes = self.max_bytes.unwrap_or(max_chunk).max(1);
+        let body = response.body_mut().take();
+        Response::build_from(response)
+            .streamed_body(Coalescing::new(body, self.interval, max_bytes))
+            .max_chunk_size(max_chunk.max(max_bytes))
+            .ok()
+    }
+}
+
+/// Returns `true` if `deadline` is `None` or has elapsed.
+fn elapsed(deadline: &mut Option<Pin<Box<Sleep>>>, cx: &mut Context<'_>) -> bool {
+    match deadline {
+        Some(deadline) => deadline.as_mut().poll(cx).is_ready(),
+        None => true,
+    }
+}
+
+/// The number of bytes read from the inner reader at a time.
+const READ_SIZE: usize = 4096;
+
+pin_project! {
+    /// An `AsyncRead` that buffers the output of an inner reader.
+    struct Coalescing<R> {
+        #[pin]
+        reader: R,
+        buffer: Vec<u8>,
+        flushed: usize,
+        flushing: bool,
+        done: bool,
+        max_bytes: usize,
+        interval: Option<Duration>,
+        deadline: Option<Pin<Box<Sleep>>>,
+    }
+}
+
+impl<R> Coalescing<R> {
+    fn new(reader: R, interval: Option<Duration>, max_bytes: usize) -> Self {
+        Coalescing {
+            reader,
+            buffer: Vec::new(),
+            flushed: 0,
+            flushing: false,
+            done: false,
+            max_bytes,
+            interval,
+            deadline: None,
+        }
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for Coalescing<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>
+    ) -> Poll<io::Result<()>> {
+        let mut me = self.project();
+        loop {
+            if *me.flushing {
+                let pending = &me.buffer[*me.flushed..];
+                let n = pending.len().min(buf.remaining());
+                buf.put_slice(&pending[..n]);
+                *me.flushed += n;
+                if *me.flushed == me.buffer.len() {
+                    me.buffer.clear();
+                    *me.flushed = 0;
+                    *me.flushing = false;
+                }
+
+                return Poll::Ready(Ok(()));
+            }
+
+            if *me.done {
+                return Poll::Ready(Ok(()));
+            }
+
+            let len = me.buffer.len();
+            let want = (*me.max_bytes - len).min(READ_SIZE);
+            me.buffer.resize(len + want, 0);
+            let mut read_buf = ReadBuf::new(&mut me.buffer[len..]);
+            let result = me.reader.as_mut().poll_read(cx, &mut read_buf);
+            let read = read_buf.filled().len();
+            me.buffer.truncate(len + read);
+
+            match result {
+                Poll::Ready(Ok(())) if read == 0 => {
+                    *me.done = true;
+                    *me.flushing = !me.buffer.is_empty();
+                }
+                Poll::Ready(Ok(())) => {
+                    if len == 0 {
+                        *me.deadline = me.interval.map(|interval| Box::pin(sleep(interval)));
+                    }
+
+                    if me.buffer.len() >= *me.max_bytes
+                        || (me.deadline.is_some() && elapsed(me.deadline, cx))
+                    {
+                        *me.flushing = true;
+                    }
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending if me.buffer.is_empty() => return Poll::Pending,
+                Poll::Pending => match elapsed(me.deadline, cx) {
+                    true => *me.flushing = true,
+                    false => return Poll::Pending,
+                }
+            }
+
+            if *me.flushing {
+                *me.deadline = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::future::Future;
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use futures::stream::Stream;
+use parking_lot::Mutex;
+
+use crate::response::stream::ByteStream;
+
+/// The slot through which a [`Sink`] hands a chunk to its [`Generator`].
+type Slot = Arc<Mutex<Option<Vec<u8>>>>;
+
+/// Returns a [`struct@ByteStream`] whose chunks are sent by the future `f`
+/// returns.
+///
+/// `f` is called with a [`Sink`] and returns a future which sends each chunk of
+/// the body via [`Sink::send()`]. The body ends when the future completes. This
+/// is an alternative to writing a [`Stream`] or using [`ByteStream!`] for
+/// ad-hoc streaming, particularly when chunks are produced by a loop with
+/// early returns or inside nested async calls.
+///
+/// The future is only polled while the body is being written: `send()` does
+/// not complete until the chunk has been taken to be written, so a producer
+/// never gets ahead of the client. When the client disconnects, the future is
+/// dropped, cancelling it at its current `.await`.
+///
+/// [`Stream`]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::response::stream::{generator, ByteStream, Generator};
+/// use rocket::tokio::time::{self, Duration};
+///
+/// #[get("/report/<rows>")]
+/// fn report(rows: usize) -> ByteStream<Generator<'static>> {
+///     generator(move |mut sink| async move {
+///         sink.send("id,value\n").await;
+///         for i in 0..rows {
+///             sink.send(format!("{},{}\n", i, i * i)).await;
+///             time::sleep(Duration::from_millis(10)).await;
+///         }
+///     })
+/// }
+/// ```
+pub fn generator<'a, F, Fut>(f: F) -> ByteStream<Generator<'a>>
+    where F: FnOnce(Sink) -> Fut, Fut: Future<Output = ()> + Send + 'a
+{
+    let slot = Slot::default();
+    let future = Box::pin(f(Sink { slot: slot.clone() }));
+    ByteStream(Generator { future: Some(future), slot })
+}
+
+/// A stream of the chunks sent by a [`generator()`] future.
+///
+/// A `Generator` is constructed via [`generator()`], which wraps it in a
+/// [`struct@ByteStream`], and is not typically used directly. Each chunk sent
+/// via [`Sink::send()`] is yielded as soon as it is sent. The stream ends when
+/// the future completes.
+pub struct Generator<'a> {
+    future: Option<BoxFuture<'a, ()>>,
+    slot: Slot,
+}
+
+impl Stream for Generator<'_> {
+    type Item = Vec<u8>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Vec<u8>>> {
+        let future = match self.future.as_mut() {
+            Some(future) => future,
+            None => return Poll::Ready(None),
+        };
+
+        let done = future.as_mut().poll(cx).is_ready();
+        if done {
+            self.future = None;
+        }
+
+        match self.slot.lock().take() {
+            Some(chunk) => Poll::Ready(Some(chunk)),
+            None if done => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl fmt::Debug for Generator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Generator")
+            .field("done", &self.future.is_none())
+            .finish()
+    }
+}
+
+/// The sending half of a [`generator()`].
+///
+/// A `Sink` is passed to the closure given to [`generator()`]. Chunks are sent
+/// to the client via [`Sink::send()`].
+///
+/// A `Sink` must only be used by the future returned from that closure, not by
+/// tasks it spawns: chunks are only taken while that future is being polled,
+/// so a `send()` from any other task never completes.
+#[derive(Debug)]
+pub struct Sink {
+    slot: Slot,
+}
+
+impl Sink {
+    /// Sends `chunk` as the next chunk of the body. Completes once the chunk
+    /// has been taken to be written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::generator;
+    /// use rocket::futures::stream::StreamExt;
+    ///
+    /// # rocket::async_test(async {
+    /// let stream = generator(|mut sink| async move {
+    ///     sink.send("hello, ").await;
+    ///     sink.send(String::from("world")).await;
+    /// });
+    ///
+    /// let chunks: Vec<_> = stream.0.collect().await;
+    /// assert_eq!(chunks, [&b"hello, "[..], b"world"]);
+    /// # });
+    /// ```
+    pub fn send<T: Into<Vec<u8>>>(&mut self, chunk: T) -> impl Future<Output = ()> + '_ {
+        SendChunk { slot: &self.slot, chunk: Some(chunk.into()) }
+    }
+}
+
+/// The future returned by [`Sink::send()`].
+struct SendChunk<'s> {
+    slot: &'s Slot,
+    chunk: Option<Vec<u8>>,
+}
+
+impl Future for SendChunk<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        let slot = self.slot;
+        let mut slot = slot.lock();
+        if slot.is_some() {
+            return Poll::Pending;
+        }
+
+        // Place the chunk in the empty slot, then wait to be polled again,
+        // which happens only once the generator has taken it.
+        match self.chunk.take() {
+            Some(chunk) => {
+                *slot = Some(chunk);
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use tokio_stream::wrappers::IntervalStream;
+
+use crate::{Request, Data};
+use crate::http::Method;
+use crate::route::{Route, Handler, Outcome};
+use crate::response::stream::{Event, EventStream};
+
+/// Custom handler for an SSE liveness endpoint.
+///
+/// When mounted, a `Heartbeat` generates a single `GET` route at the mount
+/// point which responds with an [`EventStream`] that sends a `ping` at a fixed
+/// interval and nothing else. Clients, such as dashboards, use the stream to
+/// detect that the server is alive: an [`EventSource`] reconnects when the
+/// connection drops, and a client that stops receiving pings can presume the
+/// server gone.
+///
+/// The stream begins with a `retry` field instructing clients to wait the
+/// [retry period](Heartbeat::retry()), by default the interval, before
+/// reconnecting. Each ping is flushed to the client as soon as it is produced.
+/// Pings are sent as comments, `:ping`, unless [events](Heartbeat::events())
+/// are enabled, in which case they are sent as `ping` events with `ping` data.
+/// The stream ends when the server initiates a [graceful
+/// shutdown](crate::Shutdown), so heartbeat streams never prolong shutdown.
+///
+/// [`EventSource`]: https://developer.mozilla.org/en-US/docs/Web/API/EventSource
+///
+/// # Example
+///
+/// Mount a heartbeat that pings every 5 seconds at `/__heartbeat`:
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate rocket;
+/// use rocket::response::stream::Heartbeat;
+/// use rocket::tokio::time::Duration;
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().mount("/__heartbeat", Heartbeat::new(Duration::from_secs(5)))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Heartbeat {
+    interval: Duration,
+    retry: Option<Duration>,
+    events: bool,
+}
+
+impl Heartbeat {
+    /// Creates a new `Heartbeat` which sends a ping every `interval`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::Heartbeat;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let heartbeat = Heartbeat::new(Duration::from_secs(15));
+    /// ```
+    pub fn new(interval: Duration) -> Self {
+        assert!(interval > Duration::ZERO, "heartbeat interval must be non-zero");
+        Heartbeat { interval, retry: Some(interval), events: false }
+    }
+
+    /// Sets the reconnection period sent to clients in the stream's initial
+    /// `retry` field. When `None`, no `retry` field is sent and clients use
+    /// their own default. The default is the ping interval.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::Heartbeat;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let heartbeat = Heartbeat::new(Duration::from_secs(15))
+    ///     .retry(Duration::from_secs(1));
+    ///
+    /// let heartbeat = Heartbeat::new(Duration::from_secs(15)).retry(None);
+    /// ```
+    pub fn retry<R: Into<Option<Duration>>>(mut self, retry: R) -> Self {
+        self.retry = retry.into();
+        self
+    }
+
+    /// Sets whether pings are sent as `ping` events, which an `EventSource`
+    /// dispatches to listeners, instead of comments, which it discards. The
+    /// default is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::Heartbeat;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let heartbeat = Heartbeat::new(Duration::from_secs(15)).events(true);
+    /// ```
+    pub fn events(mut self, enable: bool) -> Self {
+        self.events = enable;
+        self
+    }
+
+    fn ping(&self) -> Event {
+        match self.events {
+            true => Event::data("ping").event("ping"),
+            false => Event::comment("ping"),
+        }
+    }
+}
+
+impl From<Heartbeat> for Vec<Route> {
+    fn from(heartbeat: Heartbeat) -> Self {
+        let mut route = Route::new(Method::Get, "/", heartbeat);
+        route.name = Some("Heartbeat".into());
+        vec![route]
+    }
+}
+
+#[crate::async_trait]
+impl Handler for Heartbeat {
+    async fn handle<'r>(&self, req: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
+        let ping = self.ping();
+        let retry = stream::iter(self.retry.map(Event::retry));
+        let pings = IntervalStream::new(tokio::time::interval(self.interval))
+            .map(move |_| ping.clone())
+            .take_until(req.rocket().shutdown());
+
+        // The pings _are_ the heartbeat; don't interleave a second one.
+        let stream = EventStream::from(retry.chain(pings)).heartbeat(None);
+        Outcome::from(req, stream)
+    }
+}
@@ -92,6 +92,51 @@
 //! The expansions are identical for `ReaderStream` and `ByteStream`, with
 //! `TextStream` replaced with `ReaderStream` and `ByteStream`, respectively.
 //!
+//! # Generator Closures
+//!
+//! For one-off streaming, [`generator()`] produces a [`struct@ByteStream`] from
+//! an async closure which sends chunks via a [`Sink`] instead of yielding
+//! them. Each `send()` completes only once its chunk has been taken to be
+//! written:
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket::response::stream::{generator, ByteStream, Generator};
+//!
+//! #[get("/countdown")]
+//! fn countdown() -> ByteStream<Generator<'static>> {
+//!     generator(|mut sink| async move {
+//!         for i in (1..=3).rev() {
+//!             sink.send(format!("{}...\n", i)).await;
+//!         }
+//!
+//!         sink.send("liftoff!").await;
+//!     })
+//! }
+//! ```
+//!
+//! # Coalescing
+//!
+//! Typed streams send each item as soon as it is yielded. Chatty streams of
+//! many small items can instead coalesce items into fewer, larger writes via
+//! the `flush_after_bytes()` and `flush_interval()` methods of each typed
+//! stream, which return a [`Coalesced`] responder:
+//!
+//! ```rust
+//! # use rocket::get;
+//! use rocket::tokio::time::Duration;
+//! use rocket::response::stream::{Coalesced, TextStream};
+//!
+//! #[get("/ticks")]
+//! fn ticks() -> Coalesced<TextStream![String]> {
+//!     TextStream! {
+//!         for i in 0..1000 {
+//!             yield format!("tick {}\n", i);
+//!         }
+//!     }.flush_after_bytes(1024).flush_interval(Duration::from_millis(25))
+//! }
+//! ```
+//!
 //! # Graceful Shutdown
 //!
 //! Infinite responders, like the one defined in `hello` above, will prolong
@@ -128,6 +173,16 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Client Disconnects
+//!
+//! When a client disconnects while a streaming response is being written,
+//! Rocket stops polling the stream and drops it, even if the stream is waiting
+//! to produce its next item. Dropping a generator drops the values it holds, so
+//! resources like database cursors are released without any additional code.
+//! Work that isn't owned by the stream, like a task spawned to feed it, can
+//! observe the disconnect via the [`Disconnect`](crate::request::Disconnect)
+//! request guard.
 
 mod reader;
 mod bytes;
@@ -135,6 +190,10 @@ mod text;
 mod one;
 mod sse;
 mod raw_sse;
+mod trailers;
+mod coalesce;
+mod heartbeat;
+mod generator;
 
 pub(crate) use self::raw_sse::*;
 
@@ -143,6 +202,10 @@ pub use self::text::TextStream;
 pub use self::bytes::ByteStream;
 pub use self::reader::ReaderStream;
 pub use self::sse::{Event, EventStream};
+pub use self::trailers::WithTrailers;
+pub use self::coalesce::Coalesced;
+pub use self::heartbeat::Heartbeat;
+pub use self::generator::{generator, Generator, Sink};
 
 crate::export! {
     /// Retrofitted support for [`Stream`]s with `yield`, `for await` syntax.
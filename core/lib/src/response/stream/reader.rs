@@ -1,6 +1,7 @@
 use std::{fmt, io};
 use std::task::{Context, Poll};
 use std::pin::Pin;
+use std::time::Duration;
 
 use futures::stream::Stream;
 use tokio::io::{AsyncRead, ReadBuf};
@@ -8,7 +9,8 @@ use pin_project_lite::pin_project;
 
 use crate::request::Request;
 use crate::response::{self, Response, Responder};
-use crate::response::stream::One;
+use crate::response::stream::{One, WithTrailers, Coalesced};
+use crate::http::HeaderMap;
 
 pin_project! {
     /// An async reader that reads from a stream of async readers.
@@ -22,7 +24,9 @@ pin_project! {
     ///
     /// `ReaderStream` is designed to be used as a building-block for
     /// stream-based responders by acting as the `streamed_body` of a
-    /// `Response`, though it may also be used as a responder itself.
+    /// `Response`, though it may also be used as a responder itself. Its
+    /// responses are always chunked; to stream a single reader of known length
+    /// with a `Content-Length`, use [`SizedStream`](crate::response::SizedStream).
     ///
     /// [`Stream`]: https://docs.rs/futures/0.3/futures/stream/trait.Stream.html
     ///
@@ -133,6 +137,49 @@ impl<R: Unpin> ReaderStream<One<R>> {
     }
 }
 
+impl<S: Stream> ReaderStream<S> {
+    /// Sends the trailers returned by `f`, declared as `names`, after the
+    /// body of this stream over `HTTP/2` connections. See [`WithTrailers`]
+    /// for details and an example.
+    pub fn with_trailers<F>(self, names: &[&str], f: F) -> WithTrailers<Self, F>
+        where F: FnOnce() -> HeaderMap<'static>
+    {
+        WithTrailers::new(self, names, f)
+    }
+
+    /// Coalesces the items of this stream into writes of up to `bytes` bytes.
+    /// See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::response::stream::ReaderStream;
+    ///
+    /// let stream = ReaderStream::one(Cursor::new("hello")).flush_after_bytes(1024);
+    /// ```
+    pub fn flush_after_bytes(self, bytes: usize) -> Coalesced<Self> {
+        Coalesced::new(self).flush_after_bytes(bytes)
+    }
+
+    /// Coalesces the items of this stream, holding them back for at most
+    /// `interval` while the stream is idle. See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use rocket::response::stream::ReaderStream;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let stream = ReaderStream::one(Cursor::new("hello"));
+    /// let stream = stream.flush_interval(Duration::from_millis(10));
+    /// ```
+    pub fn flush_interval<I: Into<Option<Duration>>>(self, interval: I) -> Coalesced<Self> {
+        Coalesced::new(self).flush_interval(interval)
+    }
+}
+
 impl<S: Stream> From<S> for ReaderStream<S> {
     fn from(stream: S) -> Self {
         ReaderStream { stream, state: State::Pending }
@@ -7,8 +7,9 @@ use futures::stream::{self, Stream, StreamExt};
 use futures::future::ready;
 
 use crate::request::Request;
-use crate::response::{self, Response, Responder, stream::{ReaderStream, RawLinedEvent}};
-use crate::http::ContentType;
+use crate::response::{self, Response, Responder};
+use crate::response::stream::{ReaderStream, RawLinedEvent, WithTrailers, Coalesced};
+use crate::http::{ContentType, HeaderMap};
 
 /// A Server-Sent `Event` (SSE) in a Server-Sent [`struct@EventStream`].
 ///
@@ -348,7 +349,9 @@ impl Event {
 /// interval. The comment is discarded by clients and serves only to keep the
 /// connection alive; it does not interfere with application data. The interval
 /// defaults to 30 seconds but can be adjusted with
-/// [`EventStream::heartbeat()`].
+/// [`EventStream::heartbeat()`]. For an endpoint that sends _only_ heartbeats,
+/// say, to let a client monitor the server's liveness, mount a
+/// [`Heartbeat`](crate::response::stream::Heartbeat).
 ///
 /// # Examples
 ///
@@ -481,6 +484,48 @@ impl<S: Stream<Item = Event>> EventStream<S> {
         self
     }
 
+    /// Sends the trailers returned by `f`, declared as `names`, after the
+    /// body of this stream over `HTTP/2` connections. See [`WithTrailers`]
+    /// for details and an example.
+    pub fn with_trailers<F>(self, names: &[&str], f: F) -> WithTrailers<Self, F>
+        where F: FnOnce() -> HeaderMap<'static>
+    {
+        WithTrailers::new(self, names, f)
+    }
+
+    /// Coalesces the items of this stream into writes of up to `bytes` bytes.
+    /// See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::{Event, EventStream};
+    /// use rocket::futures::stream;
+    ///
+    /// let stream = EventStream::from(stream::iter(vec![Event::data("a")]));
+    /// let stream = stream.flush_after_bytes(1024);
+    /// ```
+    pub fn flush_after_bytes(self, bytes: usize) -> Coalesced<Self> {
+        Coalesced::new(self).flush_after_bytes(bytes)
+    }
+
+    /// Coalesces the items of this stream, holding them back for at most
+    /// `interval` while the stream is idle. See [`Coalesced`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::stream::{Event, EventStream};
+    /// use rocket::futures::stream;
+    /// use rocket::tokio::time::Duration;
+    ///
+    /// let stream = EventStream::from(stream::iter(vec![Event::data("a")]));
+    /// let stream = stream.flush_interval(Duration::from_millis(10));
+    /// ```
+    pub fn flush_interval<I: Into<Option<Duration>>>(self, interval: I) -> Coalesced<Self> {
+        Coalesced::new(self).flush_interval(interval)
+    }
+
     fn heartbeat_stream(&self) -> Option<impl Stream<Item = RawLinedEvent>> {
         use tokio::time::interval;
         use tokio_stream::wrappers::IntervalStream;
@@ -0,0 +1,96 @@
+use crate::request::Request;
+use crate::response::{self, Response, Responder};
+use crate::http::HeaderMap;
+
+/// A responder that sends trailers computed after the body of another
+/// responder.
+///
+/// A `WithTrailers` is typically created via the `with_trailers()` method of
+/// one of the typed streams, [`ReaderStream`], [`ByteStream`], [`TextStream`],
+/// or [`EventStream`], though it can wrap any responder. The callback is
+/// invoked once the body has been written in its entirety, so it may compute
+/// values from the streamed data, such as a checksum, by sharing state with
+/// the stream.
+///
+/// [`ReaderStream`]: struct@crate::response::stream::ReaderStream
+/// [`ByteStream`]: struct@crate::response::stream::ByteStream
+/// [`TextStream`]: struct@crate::response::stream::TextStream
+/// [`EventStream`]: struct@crate::response::stream::EventStream
+///
+/// # Responder
+///
+/// The response from the wrapped responder is used with the trailers set as
+/// in [`Response::set_trailers()`]: a `Trailer` header listing the declared
+/// names is added, and the trailers are sent after the body. Trailers are only
+/// sent over `HTTP/2` connections; over `HTTP/1.x`, both the trailers and the
+/// `Trailer` header are dropped.
+///
+/// # Example
+///
+/// Send the number of bytes streamed in an `X-Length` trailer:
+///
+/// ```rust
+/// # use rocket::*;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use rocket::http::{Header, HeaderMap};
+/// use rocket::response::stream::{TextStream, WithTrailers};
+///
+/// #[get("/numbers")]
+/// fn numbers() -> WithTrailers<TextStream![String], impl FnOnce() -> HeaderMap<'static> + Send> {
+///     let length = Arc::new(AtomicUsize::new(0));
+///     let counter = length.clone();
+///     let stream = TextStream! {
+///         for i in 0..10 {
+///             let line = format!("{}\n", i);
+///             counter.fetch_add(line.len(), Ordering::SeqCst);
+///             yield line;
+///         }
+///     };
+///
+///     stream.with_trailers(&["X-Length"], move || {
+///         let mut trailers = HeaderMap::new();
+///         let length = length.load(Ordering::SeqCst);
+///         trailers.add(Header::new("X-Length", length.to_string()));
+///         trailers
+///     })
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithTrailers<R, F> {
+    responder: R,
+    names: Vec<String>,
+    trailers: F,
+}
+
+impl<R, F> WithTrailers<R, F>
+    where F: FnOnce() -> HeaderMap<'static>
+{
+    /// Wraps `responder` so that the trailers returned by `f`, declared as
+    /// `names`, are sent after its body.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::HeaderMap;
+    /// use rocket::response::stream::WithTrailers;
+    ///
+    /// let response = WithTrailers::new("Hello, world!", &["X-Empty"], HeaderMap::new);
+    /// ```
+    pub fn new(responder: R, names: &[&str], f: F) -> Self {
+        let names = names.iter().map(|name| name.to_string()).collect();
+        WithTrailers { responder, names, trailers: f }
+    }
+}
+
+impl<'r, 'o: 'r, R, F> Responder<'r, 'o> for WithTrailers<R, F>
+    where R: Responder<'r, 'o>, F: FnOnce() -> HeaderMap<'static> + Send + 'o
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let names: Vec<&str> = self.names.iter().map(|name| name.as_str()).collect();
+        Response::build_from(self.responder.respond_to(req)?)
+            .trailers(&names, self.trailers)
+            .ok()
+    }
+}
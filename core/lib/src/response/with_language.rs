@@ -0,0 +1,214 @@
+use std::borrow::Cow;
+
+use crate::request::Request;
+use crate::response::{self, Responder};
+use crate::http::Header;
+
+/// A responder that sets the `Content-Language` of another responder.
+///
+/// A `WithLanguage` wraps a responder alongside one or more [BCP 47] language
+/// tags, such as `en`, `en-US`, or `zh-Hant-TW`, naming the natural languages
+/// of the intended audience of the response. The `Content-Language` header is
+/// set to the comma-separated list of tags, replacing any set by the wrapped
+/// responder.
+///
+/// [BCP 47]: https://tools.ietf.org/html/rfc5646
+///
+/// # Validation
+///
+/// Tags are checked to be well-formed according to the BCP 47 syntax. Tags
+/// known at compile-time are typically passed to [`WithLanguage::new()`] and
+/// [`WithLanguage::language()`] which, in debug builds, panic if a tag is
+/// malformed. In release builds, malformed tags are logged and omitted from
+/// the header. Tags only known at runtime, such as a language negotiated from
+/// a request's `Accept-Language` header, should instead be passed to
+/// [`WithLanguage::try_new()`], which returns `None` if the tag is malformed.
+///
+/// When the language is negotiated from the request, remember to declare it
+/// in the response's `Vary` header, for instance via
+/// [`Cacheable::vary()`](crate::response::Cacheable::vary()).
+///
+/// # Example
+///
+/// ```rust
+/// # use rocket::get;
+/// use rocket::response::WithLanguage;
+///
+/// #[get("/hallo")]
+/// fn hallo() -> WithLanguage<&'static str> {
+///     WithLanguage::new("Hallo, Welt!", "de")
+/// }
+///
+/// #[get("/hello")]
+/// fn hello() -> WithLanguage<&'static str> {
+///     WithLanguage::new("Hello, world!", "en-US").language("en-GB")
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WithLanguage<R> {
+    responder: R,
+    languages: Vec<Cow<'static, str>>,
+}
+
+impl<R> WithLanguage<R> {
+    /// Wraps `responder` with the language tag `language`.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `language` is not a well-formed BCP 47 tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::WithLanguage;
+    ///
+    /// let response = WithLanguage::new("Bonjour, le monde !", "fr-CA");
+    /// ```
+    pub fn new<L: Into<Cow<'static, str>>>(responder: R, language: L) -> Self {
+        WithLanguage { responder, languages: vec![] }.language(language)
+    }
+
+    /// Wraps `responder` with the language tag `language` if it is a
+    /// well-formed BCP 47 tag. Returns `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::WithLanguage;
+    ///
+    /// assert!(WithLanguage::try_new("Hello!", String::from("en-US")).is_some());
+    /// assert!(WithLanguage::try_new("Hello!", String::from("en_US")).is_none());
+    /// ```
+    pub fn try_new<L: Into<Cow<'static, str>>>(responder: R, language: L) -> Option<Self> {
+        let language = language.into();
+        match is_valid_language_tag(&language) {
+            true => Some(WithLanguage { responder, languages: vec![language] }),
+            false => None,
+        }
+    }
+
+    /// Adds the language tag `language` to the list of languages in the
+    /// `Content-Language` header.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `language` is not a well-formed BCP 47 tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::response::WithLanguage;
+    ///
+    /// let response = WithLanguage::new("Hello!", "en-US").language("en-GB");
+    /// ```
+    pub fn language<L: Into<Cow<'static, str>>>(mut self, language: L) -> Self {
+        let language = language.into();
+        debug_assert!(is_valid_language_tag(&language),
+            "malformed BCP 47 language tag: {:?}", language);
+
+        self.languages.push(language);
+        self
+    }
+}
+
+/// Sets the `Content-Language` header to the well-formed language tags in
+/// `self`, replacing any set by the wrapped responder. Malformed tags are
+/// omitted; if no tags remain, the header is left as is.
+impl<'r, 'o: 'r, R: Responder<'r, 'o>> Responder<'r, 'o> for WithLanguage<R> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        let mut response = self.responder.respond_to(req)?;
+        let languages: Vec<_> = self.languages.iter()
+            .filter(|tag| match is_valid_language_tag(tag) {
+                true => true,
+                false => {
+                    error_!("Omitting malformed language tag {:?}.", tag);
+                    false
+                }
+            })
+            .map(|tag| tag.as_ref())
+            .collect();
+
+        if !languages.is_empty() {
+            response.set_header(Header::new("Content-Language", languages.join(", ")));
+        }
+
+        Ok(response)
+    }
+}
+
+/// Returns `true` if `tag` is a well-formed BCP 47 (RFC 5646) language tag,
+/// disregarding the registry: the subtags need not be registered.
+fn is_valid_language_tag(tag: &str) -> bool {
+    fn alpha(s: &str) -> bool { s.bytes().all(|b| b.is_ascii_alphabetic()) }
+    fn digit(s: &str) -> bool { s.bytes().all(|b| b.is_ascii_digit()) }
+    fn alnum(s: &str) -> bool { s.bytes().all(|b| b.is_ascii_alphanumeric()) }
+
+    // region = 2ALPHA / 3DIGIT
+    fn is_region(s: &str) -> bool {
+        (s.len() == 2 && alpha(s)) || (s.len() == 3 && digit(s))
+    }
+
+    // variant = 5*8alphanum / (DIGIT 3alphanum)
+    fn is_variant(s: &str) -> bool {
+        alnum(s) && match s.len() {
+            5..=8 => true,
+            4 => s.as_bytes()[0].is_ascii_digit(),
+            _ => false,
+        }
+    }
+
+    // privateuse = "x" 1*("-" (1*8alphanum))
+    fn private_use<'a>(mut subtags: impl Iterator<Item = &'a str>) -> bool {
+        let valid = |s: &str| (1..=8).contains(&s.len()) && alnum(s);
+        matches!(subtags.next(), Some(first) if valid(first)) && subtags.all(valid)
+    }
+
+    let mut subtags = tag.split('-').peekable();
+    let language = subtags.next().unwrap_or_default();
+    if language.eq_ignore_ascii_case("x") {
+        return private_use(subtags);
+    }
+
+    if !(2..=8).contains(&language.len()) || !alpha(language) {
+        return false;
+    }
+
+    // 0: extlang or later, 1: script or later, 2: region or later,
+    // 3: variants, 4: extensions.
+    let (mut stage, mut extlangs) = (0, 0);
+    while let Some(subtag) = subtags.next() {
+        let len = subtag.len();
+        if len == 1 && subtag.eq_ignore_ascii_case("x") {
+            return private_use(subtags);
+        } else if len == 1 && alnum(subtag) {
+            // extension = singleton 1*("-" (2*8alphanum))
+            let mut count = 0;
+            while let Some(next) = subtags.peek() {
+                if !((2..=8).contains(&next.len()) && alnum(next)) {
+                    break;
+                }
+
+                subtags.next();
+                count += 1;
+            }
+
+            if count == 0 {
+                return false;
+            }
+
+            stage = 4;
+        } else if stage == 4 {
+            return false;
+        } else if stage == 0 && language.len() <= 3 && len == 3 && alpha(subtag) && extlangs < 3 {
+            extlangs += 1;
+        } else if stage <= 1 && len == 4 && alpha(subtag) {
+            stage = 2;
+        } else if (stage <= 2 && is_region(subtag)) || is_variant(subtag) {
+            stage = 3;
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
@@ -1,21 +1,23 @@
 use std::fmt;
+use std::borrow::Cow;
 use std::ops::{Deref, DerefMut};
 use std::convert::TryInto;
 
-use yansi::Paint;
+use yansi::{Paint, Color};
 use either::Either;
 use figment::{Figment, Provider};
 
 use crate::{Catcher, Config, Route, Shutdown, sentinel, shield::Shield};
 use crate::router::Router;
-use crate::trip_wire::TripWire;
-use crate::fairing::{Fairing, Fairings};
+use crate::route::{ConcurrencyLimits, ResponseCaches};
+use crate::fairing::{Fairing, Fairings, Info};
 use crate::phase::{Phase, Build, Building, Ignite, Igniting, Orbit, Orbiting};
 use crate::phase::{Stateful, StateRef, State};
-use crate::http::uri::{self, Origin};
+use crate::http::{Method, Status};
+use crate::http::uri::{self, Origin, Authority};
 use crate::http::ext::IntoOwned;
 use crate::error::{Error, ErrorKind};
-use crate::log::PaintExt;
+use crate::log::{PaintExt, LogFormat};
 
 /// The application server itself.
 ///
@@ -310,6 +312,78 @@ impl Rocket<Build> {
             |r, route| r.0.routes.push(route))
     }
 
+    /// Mounts all of the routes in the supplied vector at `/`, matching only
+    /// requests whose `Host` matches `host`. This allows one instance to serve
+    /// several sites, each with its own routes.
+    ///
+    /// `host` is matched like an entry in
+    /// [`allowed_hosts`](crate::Config::allowed_hosts): `example.com` matches
+    /// requests to `example.com` on any port, `example.com:8000` only those on
+    /// port `8000`, and `*.example.com` those to any subdomain of
+    /// `example.com`. Host names are compared case-insensitively.
+    ///
+    /// Routes mounted for a host are tried before routes of the same rank
+    /// mounted without one, such as those mounted with
+    /// [`Rocket::mount()`], and do not collide with them. Routes mounted for
+    /// hosts that some host matches both of, such as `example.com` and
+    /// `example.com:8000` or `*.example.com` and `api.example.com`, collide as
+    /// routes mounted for the same host do. Requests to any other
+    /// host are routed only to routes without a host and, absent a matching
+    /// one, fail with a `404`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `host` is not of the form `host[:port]`, optionally prefixed
+    /// with `*.`, or if any route's URI is not a valid origin URI.
+    ///
+    /// # Example
+    ///
+    /// Requests to `api.example.com/` are dispatched to `api`, while requests
+    /// to `/` on any other host are dispatched to `app`:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// #[get("/")]
+    /// fn api() -> &'static str {
+    ///     "{ \"version\": 1 }"
+    /// }
+    ///
+    /// #[get("/")]
+    /// fn app() -> &'static str {
+    ///     "Welcome!"
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .mount_host("api.example.com", routes![api])
+    ///         .mount("/", routes![app])
+    /// }
+    /// ```
+    pub fn mount_host<H, R>(self, host: H, routes: R) -> Self
+        where H: Into<Cow<'static, str>>, R: Into<Vec<Route>>
+    {
+        let host = host.into();
+        let valid = match Authority::parse(host.strip_prefix("*.").unwrap_or(&host)) {
+            Ok(authority) => authority.user_info().is_none(),
+            Err(_) => false,
+        };
+
+        if !valid {
+            error!("invalid route host: {}", Paint::white(&host));
+            panic!("aborting due to route host error");
+        }
+
+        let routes = routes.into().into_iter()
+            .map(|mut route| {
+                route.host = Some(host.clone());
+                route
+            })
+            .collect::<Vec<_>>();
+
+        self.mount("/", routes)
+    }
+
     /// Registers all of the catchers in the supplied vector, scoped to `base`.
     ///
     /// # Panics
@@ -401,6 +475,48 @@ impl Rocket<Build> {
         self
     }
 
+    /// Registers `f` as the mapping from errors of type `E` to statuses.
+    ///
+    /// When a [`Fault`] wrapping an error of type `E` responds, the status
+    /// returned by `f` for the error is used as the response's status. Handlers
+    /// opt in by returning `Result<T, Fault>`; any error type can be converted
+    /// into a `Fault` with `?`. See [`Fault`] for details, including how
+    /// mappings interact with the [`Responder`](crate::response::Responder)
+    /// implementation for `Result`.
+    ///
+    /// This method can be called any number of times. If it is called more
+    /// than once for the same `E`, the last registered mapping is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::http::Status;
+    /// use rocket::response::Fault;
+    ///
+    /// #[get("/<n>")]
+    /// fn parse(n: &str) -> Result<String, Fault> {
+    ///     let n: u8 = n.parse()?;
+    ///     Ok(format!("{} squared is {}", n, n as u16 * n as u16))
+    /// }
+    ///
+    /// #[launch]
+    /// fn rocket() -> _ {
+    ///     rocket::build()
+    ///         .map_error(|_: &std::num::ParseIntError| Status::BadRequest)
+    ///         .mount("/", routes![parse])
+    /// }
+    /// ```
+    ///
+    /// [`Fault`]: crate::response::Fault
+    pub fn map_error<E, F>(mut self, f: F) -> Self
+        where E: std::error::Error + Send + Sync + 'static,
+              F: Fn(&E) -> Status + Send + Sync + 'static
+    {
+        self.error_map.add(f);
+        self
+    }
+
     /// Attaches a fairing to this instance of Rocket. No fairings are eagerly
     /// excuted; fairings are executed at their appropriate time.
     ///
@@ -498,20 +614,34 @@ impl Rocket<Build> {
         self.catchers.clone().into_iter().for_each(|c| router.add_catcher(c));
         router.finalize().map_err(ErrorKind::Collisions)?;
 
-        // Finally, freeze managed state.
+        // Expose the routes' concurrency limits, caches, and the error map;
+        // finally, freeze managed state.
+        if self.routes.iter().any(|r| r.concurrency.is_some()) {
+            self.state.set(ConcurrencyLimits::new(self.routes.iter()));
+        }
+
+        self.state.set(ResponseCaches::new(self.routes.iter()));
+        let error_map = std::mem::take(&mut self.error_map);
+        self.state.set(error_map);
         self.state.freeze();
 
         // Log everything we know: config, routes, catchers, fairings.
         // TODO: Store/print managed state type names?
         config.pretty_print(self.figment());
-        log_items("🛰  ", "Routes", self.routes(), |r| &r.uri.base, |r| &r.uri);
+        match config.log_format {
+            LogFormat::Lines => {
+                log_items("🛰  ", "Routes", self.routes(), |r| &r.uri.base, |r| &r.uri)
+            }
+            LogFormat::Table => log_route_table(self.routes()),
+        }
+
         log_items("👾 ", "Catchers", self.catchers(), |c| &c.base, |c| &c.base);
         self.fairings.pretty_print();
 
         // Ignite the rocket.
         let rocket: Rocket<Ignite> = Rocket(Igniting {
             router, config,
-            shutdown: Shutdown(TripWire::new()),
+            shutdown: Shutdown::new(),
             figment: self.0.figment,
             fairings: self.0.fairings,
             state: self.0.state,
@@ -534,11 +664,73 @@ fn log_items<T, I, B, O>(e: &str, t: &str, items: I, base: B, origin: O)
         launch_info!("{}{}:", Paint::emoji(e), Paint::magenta(t));
     }
 
+    sort_items(&mut items, base, origin);
+    items.iter().for_each(|i| launch_info_!("{}", i));
+}
+
+/// Sorts `items` by the length of their base and then of their origin, first
+/// in segments and then in characters.
+fn sort_items<T, B, O>(items: &mut [T], base: B, origin: O)
+    where B: Fn(&T) -> &Origin<'_>, O: Fn(&T) -> &Origin<'_>
+{
     items.sort_by_key(|i| origin(i).path().as_str().chars().count());
     items.sort_by_key(|i| origin(i).path().segments().len());
     items.sort_by_key(|i| base(i).path().as_str().chars().count());
     items.sort_by_key(|i| base(i).path().segments().len());
-    items.iter().for_each(|i| launch_info_!("{}", i));
+}
+
+/// Logs `routes` as a table with aligned columns for each route's method,
+/// path, rank, and handler name, coloring the method when colors are enabled.
+fn log_route_table<'a, I: Iterator<Item = &'a Route>>(routes: I) {
+    let mut routes: Vec<_> = routes.collect();
+    if routes.is_empty() {
+        return;
+    }
+
+    sort_items(&mut routes, |r| &r.uri.base, |r| &r.uri);
+    let rows: Vec<[String; 4]> = routes.iter()
+        .map(|r| [
+            r.method.to_string(),
+            format!("{}{}", r.host.as_deref().unwrap_or(""), r.uri),
+            r.rank.to_string(),
+            r.name.as_deref().unwrap_or("-").to_string(),
+        ])
+        .collect();
+
+    let header = ["Method", "Path", "Rank", "Handler"];
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let rule = widths.iter().map(|w| "-".repeat(w + 2)).collect::<Vec<_>>().join("+");
+    let (m, p, r, h) = (widths[0], widths[1], widths[2], widths[3]);
+    launch_info!("{}{}:", Paint::emoji("🛰  "), Paint::magenta("Routes"));
+    launch_info!("+{}+", rule);
+    let [method, path, rank, handler] = header;
+    launch_info!("| {:<m$} | {:<p$} | {:>r$} | {:<h$} |", method, path, rank, handler,
+        m = m, p = p, r = r, h = h);
+    launch_info!("+{}+", rule);
+    for (route, row) in routes.iter().zip(rows.iter()) {
+        let method = method_color(route.method).paint(format!("{:<m$}", row[0], m = m));
+        launch_info!("| {} | {:<p$} | {:>r$} | {:<h$} |", method, row[1], row[2], row[3],
+            p = p, r = r, h = h);
+    }
+
+    launch_info!("+{}+", rule);
+}
+
+/// The color of `method` in the route table.
+fn method_color(method: Method) -> Color {
+    match method {
+        Method::Get => Color::Green,
+        Method::Post => Color::Yellow,
+        Method::Put | Method::Patch => Color::Blue,
+        Method::Delete => Color::Red,
+        _ => Color::Cyan,
+    }
 }
 
 impl Rocket<Ignite> {
@@ -747,6 +939,69 @@ impl<P: Phase> Rocket<P> {
         }
     }
 
+    /// Returns an iterator over the [`Info`] of every fairing attached to this
+    /// instance of Rocket, in the order they were attached.
+    ///
+    /// Only fairings that will run are included: of several attached
+    /// [singleton](crate::fairing::Kind::Singleton) fairings of the same type,
+    /// only the last is returned. Fairings attached by ignite fairings appear
+    /// once ignition has run them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::fairing::{AdHoc, Kind};
+    ///
+    /// let rocket = rocket::build()
+    ///     .attach(AdHoc::on_ignite("Ignite", |rocket| async { rocket }))
+    ///     .attach(AdHoc::on_response("Response", |_, _| Box::pin(async {})));
+    ///
+    /// // `Shield` is attached by default.
+    /// let names: Vec<_> = rocket.fairings().map(|info| info.name).collect();
+    /// assert_eq!(names, ["Shield", "Ignite", "Response"]);
+    /// assert!(rocket.fairings().any(|info| info.kind.is(Kind::Response)));
+    /// ```
+    pub fn fairings(&self) -> impl Iterator<Item = Info> + '_ {
+        let fairings = match self.0.as_state_ref() {
+            StateRef::Build(p) => &p.fairings,
+            StateRef::Ignite(p) => &p.fairings,
+            StateRef::Orbit(p) => &p.fairings,
+        };
+
+        fairings.unique_active().map(|f| f.info())
+    }
+
+    /// Returns a structured report of the routing table of `self`: all routes
+    /// and catchers, any collisions between them, routes shadowed by
+    /// lower-ranked routes, and error status codes without a root catcher.
+    ///
+    /// The report contains the same information that is checked and logged at
+    /// ignition but is available in any phase and can be serialized. See
+    /// [`RouteReport`](crate::route::RouteReport) for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// #[get("/hello")]
+    /// fn hello() -> &'static str { "Hello!" }
+    ///
+    /// #[get("/<name>")]
+    /// fn name(name: &str) -> &str { name }
+    ///
+    /// let report = rocket::build()
+    ///     .mount("/", routes![hello, name])
+    ///     .diagnostics();
+    ///
+    /// assert!(report.is_ok());
+    /// assert_eq!(report.shadowed.len(), 1);
+    /// assert_eq!(report.shadowed[0].0.uri, "/hello");
+    /// assert_eq!(report.shadowed[0].1.uri, "/<name>");
+    /// ```
+    pub fn diagnostics(&self) -> crate::route::RouteReport {
+        crate::route::RouteReport::new(self.routes(), self.catchers())
+    }
+
     /// Returns `Some` of the managed state value for the type `T` if it is
     /// being managed by `self`. Otherwise, returns `None`.
     ///
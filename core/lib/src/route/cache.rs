@@ -0,0 +1,622 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::{Request, Response, Route};
+use crate::data::ByteUnit;
+use crate::http::{HeaderMap, Method};
+use crate::response::{CapturedResponse, CaptureError};
+
+/// A cache of the responses of a route's handler.
+///
+/// A route with a cache, set via [`Route::cache`] or the `cache` route
+/// attribute parameter, stores the complete response to a `GET` or `HEAD`
+/// request for a time-to-live (TTL). Until the TTL elapses, requests with the
+/// same method and URI, including the query, are answered with the stored
+/// response without the handler's function being invoked. Once it elapses, the
+/// next such request invokes the function again, and its response replaces the
+/// stored one. This is meant for expensive endpoints whose responses change
+/// slowly.
+///
+/// The cache is consulted only once all of the route's request, parameter,
+/// query, and data guards have succeeded, so a guard that fails or forwards,
+/// such as one requiring authentication, does so whether or not a response is
+/// stored. Routes generated by the route attributes do this automatically. A
+/// custom [`Handler`](crate::route::Handler) can do the same by calling
+/// [`ResponseCache::get()`] once its own checks succeed.
+///
+/// Only successful (`2xx`) responses with a sized body no larger than
+/// [`ResponseCache::max_body_size()`] are stored: streamed bodies, which may
+/// be arbitrarily large or never end, are not buffered.
+/// Responses are also never stored if they set cookies, if their
+/// `Cache-Control` contains `no-store`, `no-cache`, or `private`, or if they
+/// contain `Vary: *`. A response with a `Vary` header is stored once for every
+/// combination of values of the request headers it names, and is only used to
+/// answer requests with the same values.
+///
+/// # Age and Freshness
+///
+/// A response served from the cache carries an `Age` header with the number of
+/// seconds since it was stored. If the handler's response already had an
+/// `Age`, for instance, because it was itself fetched from an upstream cache,
+/// that age is included. A stored response is fresh for the TTL after it is
+/// stored, but no longer than the `s-maxage` or, absent it, `max-age` in its
+/// `Cache-Control` allows given its upstream age.
+///
+/// If enabled via [`ResponseCache::set_request_directives()`], requests can
+/// bypass the cache via their `Cache-Control`. A stored response is then not
+/// used to answer a request with `no-cache`, or with a `max-age` shorter than
+/// the response's age, such as `max-age=0`. Such a request invokes the
+/// handler, and its response replaces the stored one. A request with
+/// `no-store` is neither answered from the cache nor is its response stored.
+/// This is disabled by default as it lets any client force the expensive
+/// handler to run.
+///
+/// **Warning:** Guards only decide _whether_ a stored response is served, not
+/// _which_ one: a stored response is served to any request with the same
+/// method and URI whose guards succeed. Never cache a route whose response
+/// depends on the identity of the client unless its response names the
+/// identifying headers in `Vary`.
+///
+/// Entries are kept in memory until they are replaced, removed by
+/// [`ResponseCache::remove()`] or [`ResponseCache::clear()`], or found to be
+/// expired when a new response is stored. At most
+/// [`ResponseCache::max_entries()`] responses are stored: when a new response
+/// would exceed the bound, the least recently used ones are evicted. Every
+/// clone of a `ResponseCache`
+/// refers to the same entries. The caches of all mounted routes are
+/// available from managed state via [`ResponseCaches`].
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[get("/stats", cache = "60s")]
+/// async fn stats() -> String {
+///     /* an expensive aggregation */
+///     "the stats".into()
+/// }
+///
+/// let route = &routes![stats][0];
+/// let cache = route.cache.as_ref().unwrap();
+/// assert_eq!(cache.ttl().as_secs(), 60);
+/// assert!(cache.is_empty());
+/// ```
+#[derive(Clone)]
+pub struct ResponseCache(Arc<Inner>);
+
+struct Inner {
+    ttl: Duration,
+    request_directives: AtomicBool,
+    max_entries: AtomicUsize,
+    max_body_size: AtomicU64,
+    entries: Mutex<HashMap<(Method, String), Vec<Entry>>>,
+}
+
+/// Marks a request as answered from a cache so its response isn't re-stored.
+struct Hit(bool);
+
+/// A stored response and the request header values it varies on.
+struct Entry {
+    vary: Vec<(String, Option<String>)>,
+    response: CapturedResponse,
+    stored: Instant,
+    /// When the entry was last served or stored.
+    used: Instant,
+    /// The age of the response when it was stored.
+    upstream_age: Duration,
+    /// The age at which the response is no longer fresh.
+    lifetime: Duration,
+}
+
+impl Entry {
+    fn age(&self) -> Duration {
+        self.upstream_age + self.stored.elapsed()
+    }
+
+    fn is_expired(&self) -> bool {
+        self.age() >= self.lifetime
+    }
+
+    fn matches(&self, req: &Request<'_>) -> bool {
+        self.vary.iter().all(|(name, value)| req.headers().get_one(name) == value.as_deref())
+    }
+
+    fn to_response<'r>(&self) -> Response<'r> {
+        let mut response = self.response.to_response();
+        response.set_raw_header("Age", self.age().as_secs().to_string());
+        response
+    }
+}
+
+impl ResponseCache {
+    /// The default maximum number of stored responses: 1024.
+    pub const MAX_ENTRIES: usize = 1024;
+
+    /// The default maximum size of a stored response's body: 1MiB.
+    pub const MAX_BODY_SIZE: ByteUnit = ByteUnit::Mebibyte(1);
+
+    /// Returns an empty cache whose entries live for `ttl`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use rocket::Route;
+    /// use rocket::http::Method;
+    /// use rocket::route::ResponseCache;
+    /// # use rocket::route::dummy_handler as handler;
+    ///
+    /// let mut route = Route::new(Method::Get, "/stats", handler);
+    /// route.cache = Some(ResponseCache::new(Duration::from_secs(60)));
+    /// ```
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache(Arc::new(Inner {
+            ttl,
+            request_directives: AtomicBool::new(false),
+            max_entries: AtomicUsize::new(Self::MAX_ENTRIES),
+            max_body_size: AtomicU64::new(Self::MAX_BODY_SIZE.as_u64()),
+            entries: Mutex::new(HashMap::new()),
+        }))
+    }
+
+    /// The time-to-live of entries.
+    pub fn ttl(&self) -> Duration {
+        self.0.ttl
+    }
+
+    /// Sets whether the `Cache-Control` directives of requests are honored,
+    /// allowing clients to bypass the cache. Disabled by default. Applies to
+    /// every clone of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// #[get("/stats", cache = "60s")]
+    /// async fn stats() -> String {
+    ///     /* an expensive aggregation */
+    ///     "the stats".into()
+    /// }
+    ///
+    /// let routes = routes![stats];
+    /// let cache = routes[0].cache.as_ref().unwrap();
+    /// assert!(!cache.request_directives());
+    ///
+    /// cache.set_request_directives(true);
+    /// assert!(cache.request_directives());
+    /// ```
+    pub fn set_request_directives(&self, enabled: bool) {
+        self.0.request_directives.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether the `Cache-Control` directives of requests are honored. See
+    /// [`ResponseCache::set_request_directives()`].
+    pub fn request_directives(&self) -> bool {
+        self.0.request_directives.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum number of stored responses, counting every `Vary`
+    /// combination separately. When storing a response would exceed it, the
+    /// least recently used responses are evicted. Defaults to
+    /// [`ResponseCache::MAX_ENTRIES`]. Applies to every clone of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::route::ResponseCache;
+    ///
+    /// let cache = ResponseCache::new(Duration::from_secs(60));
+    /// assert_eq!(cache.max_entries(), ResponseCache::MAX_ENTRIES);
+    ///
+    /// cache.set_max_entries(16);
+    /// assert_eq!(cache.max_entries(), 16);
+    /// ```
+    pub fn set_max_entries(&self, max: usize) {
+        self.0.max_entries.store(max, Ordering::Relaxed);
+    }
+
+    /// The maximum number of stored responses. See
+    /// [`ResponseCache::set_max_entries()`].
+    pub fn max_entries(&self) -> usize {
+        self.0.max_entries.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum size of the body of a stored response. Larger
+    /// responses are served but not stored. Defaults to
+    /// [`ResponseCache::MAX_BODY_SIZE`]. Applies to every clone of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::route::ResponseCache;
+    ///
+    /// let cache = ResponseCache::new(Duration::from_secs(60));
+    /// assert_eq!(cache.max_body_size(), ResponseCache::MAX_BODY_SIZE);
+    ///
+    /// cache.set_max_body_size(64.kibibytes());
+    /// assert_eq!(cache.max_body_size(), 64.kibibytes());
+    /// ```
+    pub fn set_max_body_size(&self, max: ByteUnit) {
+        self.0.max_body_size.store(max.as_u64(), Ordering::Relaxed);
+    }
+
+    /// The maximum size of the body of a stored response. See
+    /// [`ResponseCache::set_max_body_size()`].
+    pub fn max_body_size(&self) -> ByteUnit {
+        ByteUnit::from(self.0.max_body_size.load(Ordering::Relaxed))
+    }
+
+    /// The number of stored responses, including expired ones that have yet
+    /// to be evicted.
+    pub fn len(&self) -> usize {
+        self.0.entries.lock().values().map(|variants| variants.len()).sum()
+    }
+
+    /// Returns `true` if there are no stored responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the responses stored for requests to `uri`, the request URI
+    /// including its query, if any, for every method and `Vary` combination.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::route::ResponseCaches;
+    ///
+    /// #[get("/stats?<region>", cache = "5m")]
+    /// async fn stats(region: &str) -> String {
+    ///     format!("the stats for {}", region)
+    /// }
+    ///
+    /// #[post("/stats/refresh?<region>")]
+    /// fn refresh(region: &str, caches: &State<ResponseCaches>) {
+    ///     let uri = uri!(stats(region)).to_string();
+    ///     caches.get("stats").unwrap().remove(&uri);
+    /// }
+    /// ```
+    pub fn remove(&self, uri: &str) {
+        self.0.entries.lock().retain(|(_, entry_uri), _| entry_uri != uri);
+    }
+
+    /// Removes all stored responses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::State;
+    /// use rocket::route::ResponseCaches;
+    ///
+    /// #[get("/stats", cache = "5m")]
+    /// async fn stats() -> &'static str {
+    ///     "the stats"
+    /// }
+    ///
+    /// #[post("/stats/refresh")]
+    /// fn refresh(caches: &State<ResponseCaches>) {
+    ///     caches.get("stats").unwrap().clear();
+    /// }
+    /// ```
+    pub fn clear(&self) {
+        self.0.entries.lock().clear();
+    }
+
+    /// Returns a response to `req` from the cache, if a fresh one is stored
+    /// and the request's `Cache-Control`, if honored, permits it.
+    ///
+    /// Handlers generated by the route attributes call this method once all of
+    /// the route's guards succeed. A custom handler should do the same. A
+    /// response returned by this method isn't stored again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::{Request, Data, Route};
+    /// use rocket::route::{Handler, Outcome};
+    ///
+    /// #[derive(Clone)]
+    /// struct Stats;
+    ///
+    /// #[rocket::async_trait]
+    /// impl Handler for Stats {
+    ///     async fn handle<'r>(&self, req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+    ///         /* check that the request may see the stats */
+    ///
+    ///         let cached = req.route().and_then(|r| r.cache.as_ref()?.get(req));
+    ///         if let Some(response) = cached {
+    ///             return Outcome::Success(response);
+    ///         }
+    ///
+    ///         Outcome::from(req, "the stats")
+    ///     }
+    /// }
+    /// ```
+    pub fn get<'r>(&self, req: &Request<'_>) -> Option<Response<'r>> {
+        if !is_cacheable_method(req.method()) {
+            return None;
+        }
+
+        let mut max_age = None;
+        let request_directives = match self.request_directives() {
+            true => Some(directives(req.headers())),
+            false => None,
+        };
+
+        for (name, value) in request_directives.into_iter().flatten() {
+            if name.eq_ignore_ascii_case("no-cache") || name.eq_ignore_ascii_case("no-store") {
+                info_!("Bypassing cache: request contains `{}`.", name);
+                return None;
+            } else if name.eq_ignore_ascii_case("max-age") {
+                max_age = Some(seconds(value).unwrap_or_default());
+            }
+        }
+
+        let mut entries = self.0.entries.lock();
+        let entry = entries.get_mut(&(req.method(), req.uri().to_string()))?
+            .iter_mut()
+            .find(|entry| entry.matches(req) && !entry.is_expired())?;
+
+        match max_age {
+            Some(max_age) if entry.age() > max_age => {
+                info_!("Bypassing cache: stored response is older than `max-age`.");
+                None
+            }
+            _ => {
+                info_!("Serving cached response.");
+                entry.used = Instant::now();
+                req.local_cache(|| Hit(true));
+                Some(entry.to_response())
+            }
+        }
+    }
+
+    /// Stores `response` as the response to `req` if it is cacheable and its
+    /// body is sized. The body of a stored response is read into memory and
+    /// replaced with the copy that is stored.
+    pub(crate) async fn store(&self, req: &Request<'_>, response: &mut Response<'_>) {
+        if req.local_cache(|| Hit(false)).0 {
+            return;
+        }
+
+        let vary = match vary(req, response) {
+            Some(vary) if is_cacheable(req, response, self.request_directives()) => vary,
+            _ => return,
+        };
+
+        let captured = match CapturedResponse::capture(response, self.max_body_size()).await {
+            Ok(captured) => captured,
+            Err(e @ CaptureError::Io(_)) => {
+                warn_!("Not caching response: {}.", e);
+                return;
+            }
+            Err(e) => {
+                info_!("Not caching response: {}.", e);
+                return;
+            }
+        };
+
+        let ttl = self.0.ttl;
+        let upstream_age = seconds(response.headers().get_one("Age")).unwrap_or_default();
+        let entry = Entry {
+            vary,
+            response: captured,
+            stored: Instant::now(),
+            used: Instant::now(),
+            upstream_age,
+            lifetime: freshness_lifetime(response).map_or(upstream_age + ttl, |lifetime| {
+                lifetime.min(upstream_age + ttl)
+            }),
+        };
+
+        let max_entries = self.max_entries();
+        if entry.is_expired() || max_entries == 0 {
+            return;
+        }
+
+        let key = (req.method(), req.uri().to_string());
+        let mut entries = self.0.entries.lock();
+        entries.values_mut().for_each(|variants| variants.retain(|e| !e.is_expired()));
+        if let Some(variants) = entries.get_mut(&key) {
+            variants.retain(|variant| variant.vary != entry.vary);
+        }
+
+        entries.retain(|_, variants| !variants.is_empty());
+
+        // Evict the least recently used entries to make room for this one.
+        let mut len: usize = entries.values().map(|variants| variants.len()).sum();
+        while len >= max_entries {
+            let lru = entries.iter()
+                .flat_map(|(key, variants)| variants.iter().enumerate().map(move |v| (key, v)))
+                .min_by_key(|(_, (_, entry))| entry.used)
+                .map(|(key, (i, _))| (key.clone(), i));
+
+            if let Some((key, i)) = lru {
+                let variants = entries.get_mut(&key).expect("LRU key is present");
+                variants.remove(i);
+                if variants.is_empty() {
+                    entries.remove(&key);
+                }
+            }
+
+            len -= 1;
+        }
+
+        entries.entry(key).or_default().push(entry);
+    }
+}
+
+fn is_cacheable_method(method: Method) -> bool {
+    matches!(method, Method::Get | Method::Head)
+}
+
+fn is_cacheable(req: &Request<'_>, response: &Response<'_>, request_directives: bool) -> bool {
+    let forbidden = directives(response.headers())
+        .map(|(name, _)| name)
+        .any(|d| ["no-store", "no-cache", "private"].iter().any(|f| d.eq_ignore_ascii_case(f)));
+
+    let no_store = request_directives && directives(req.headers())
+        .any(|(name, _)| name.eq_ignore_ascii_case("no-store"));
+
+    !forbidden
+        && !no_store
+        && is_cacheable_method(req.method())
+        && response.status().class().is_success()
+        && !response.headers().contains("Set-Cookie")
+        && !req.cookies().has_delta()
+}
+
+/// Returns the name and value, if any, of every `Cache-Control` directive in
+/// `headers`.
+fn directives<'h>(headers: &'h HeaderMap<'_>) -> impl Iterator<Item = (&'h str, Option<&'h str>)> {
+    headers.get("Cache-Control")
+        .flat_map(|value| value.split(','))
+        .map(|directive| match directive.split_once('=') {
+            Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+            None => (directive.trim(), None),
+        })
+        .filter(|(name, _)| !name.is_empty())
+}
+
+/// Parses a number of seconds, as in `Age` or `max-age`.
+fn seconds(value: Option<&str>) -> Option<Duration> {
+    value?.parse().ok().map(Duration::from_secs)
+}
+
+/// Returns the freshness lifetime set by the `Cache-Control` of `response`:
+/// its `s-maxage` or, absent it, its `max-age`.
+fn freshness_lifetime(response: &Response<'_>) -> Option<Duration> {
+    let (mut max_age, mut s_maxage) = (None, None);
+    for (name, value) in directives(response.headers()) {
+        if name.eq_ignore_ascii_case("s-maxage") {
+            s_maxage = Some(seconds(value).unwrap_or_default());
+        } else if name.eq_ignore_ascii_case("max-age") {
+            max_age = Some(seconds(value).unwrap_or_default());
+        }
+    }
+
+    s_maxage.or(max_age)
+}
+
+/// Returns the names and request values of the headers named in the `Vary`
+/// header of `response`, or `None` if the response varies on `*`.
+fn vary(req: &Request<'_>, response: &Response<'_>) -> Option<Vec<(String, Option<String>)>> {
+    let mut vary = vec![];
+    for name in response.headers().get("Vary").flat_map(|value| value.split(',')) {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "*" {
+            return None;
+        } else if !name.is_empty() && !vary.iter().any(|(n, _)| n == &name) {
+            let value = req.headers().get_one(&name).map(|v| v.to_string());
+            vary.push((name, value));
+        }
+    }
+
+    vary.sort();
+    Some(vary)
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttl", &self.ttl())
+            .field("request_directives", &self.request_directives())
+            .field("max_entries", &self.max_entries())
+            .field("max_body_size", &self.max_body_size())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// Managed state exposing the [`ResponseCache`]s of all mounted routes.
+///
+/// Rocket places a `ResponseCaches` in managed state at ignition. It can be
+/// retrieved via `&State<ResponseCaches>` to invalidate stored responses when
+/// the data they were computed from changes.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::route::ResponseCaches;
+///
+/// #[get("/stats", cache = "60s")]
+/// async fn stats() -> &'static str {
+///     "the stats"
+/// }
+///
+/// #[post("/data", data = "<data>")]
+/// fn update(data: &str, caches: &State<ResponseCaches>) {
+///     /* store `data` */
+///     caches.clear();
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().mount("/", routes![stats, update])
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ResponseCaches(Vec<CacheEntry>);
+
+#[derive(Debug)]
+struct CacheEntry {
+    name: Option<Cow<'static, str>>,
+    cache: ResponseCache,
+}
+
+impl ResponseCaches {
+    pub(crate) fn new<'a, I: Iterator<Item = &'a Route>>(routes: I) -> Self {
+        let entries = routes.filter_map(|route| {
+            route.cache.clone().map(|cache| CacheEntry {
+                name: route.name.clone(),
+                cache,
+            })
+        });
+
+        ResponseCaches(entries.collect())
+    }
+
+    /// Returns the cache of the first route named `name`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::route::ResponseCaches;
+    ///
+    /// #[get("/stats", cache = "1h")]
+    /// async fn stats() { }
+    ///
+    /// # rocket::async_test(async {
+    /// let rocket = rocket::build().mount("/", routes![stats]).ignite().await.unwrap();
+    /// let caches = rocket.state::<ResponseCaches>().unwrap();
+    /// assert_eq!(caches.get("stats").unwrap().ttl().as_secs(), 3600);
+    /// assert!(caches.get("index").is_none());
+    /// # });
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&ResponseCache> {
+        self.0.iter()
+            .find(|entry| entry.name.as_deref() == Some(name))
+            .map(|entry| &entry.cache)
+    }
+
+    /// Removes all stored responses from every cache.
+    pub fn clear(&self) {
+        self.0.iter().for_each(|entry| entry.cache.clear());
+    }
+}
@@ -0,0 +1,242 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::Route;
+use crate::http::Method;
+use crate::route::RouteUri;
+
+/// A limit on the number of concurrent executions of a route's handler.
+///
+/// A route with a limit, set via [`Route::concurrency`] or the
+/// `max_concurrency` route attribute parameter, executes its handler for at
+/// most [`limit()`](ConcurrencyLimit::limit()) requests at once. A request
+/// routed to the handler while the limit is reached fails immediately with a
+/// **503 Service Unavailable** without the handler being invoked. Such requests
+/// are neither queued until an execution completes nor forwarded to another
+/// route. This isolates the rest of the application from a slow or
+/// resource-hungry route: a _bulkhead_.
+///
+/// An execution lasts from the moment the handler is invoked until it returns
+/// a response or is cancelled, for instance, because the client disconnected.
+/// Streaming the response body happens after the handler returns and is thus
+/// not limited.
+///
+/// Clones of a `ConcurrencyLimit` share the same count of executions.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[get("/report", max_concurrency = 4)]
+/// async fn report() -> &'static str {
+///     /* an expensive computation */
+///     "the report"
+/// }
+///
+/// let route = &routes![report][0];
+/// let limit = route.concurrency.as_ref().unwrap();
+/// assert_eq!(limit.limit(), 4);
+/// assert_eq!(limit.in_flight(), 0);
+/// ```
+#[derive(Clone)]
+pub struct ConcurrencyLimit(Arc<Inner>);
+
+struct Inner {
+    limit: usize,
+    in_flight: AtomicUsize,
+    rejected: AtomicUsize,
+}
+
+/// A permit to execute a handler, released when dropped.
+pub(crate) struct Permit(Arc<Inner>);
+
+impl ConcurrencyLimit {
+    /// Returns a limit of `limit` concurrent executions. A limit of `0` rejects
+    /// every request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::Route;
+    /// use rocket::http::Method;
+    /// use rocket::route::ConcurrencyLimit;
+    /// # use rocket::route::dummy_handler as handler;
+    ///
+    /// let mut route = Route::new(Method::Get, "/report", handler);
+    /// route.concurrency = Some(ConcurrencyLimit::new(4));
+    /// ```
+    pub fn new(limit: usize) -> Self {
+        ConcurrencyLimit(Arc::new(Inner {
+            limit,
+            in_flight: AtomicUsize::new(0),
+            rejected: AtomicUsize::new(0),
+        }))
+    }
+
+    /// The maximum number of concurrent executions.
+    pub fn limit(&self) -> usize {
+        self.0.limit
+    }
+
+    /// The number of executions presently in flight.
+    pub fn in_flight(&self) -> usize {
+        self.0.in_flight.load(Ordering::Acquire)
+    }
+
+    /// The number of requests that were rejected because the limit was
+    /// reached.
+    pub fn rejected(&self) -> usize {
+        self.0.rejected.load(Ordering::Acquire)
+    }
+
+    /// Acquires a permit to execute if the limit has not been reached.
+    pub(crate) fn try_acquire(&self) -> Option<Permit> {
+        let inner = &self.0;
+        let acquired = inner.in_flight.fetch_update(Ordering::AcqRel, Ordering::Acquire, |n| {
+            if n < inner.limit { Some(n + 1) } else { None }
+        });
+
+        match acquired {
+            Ok(_) => Some(Permit(self.0.clone())),
+            Err(_) => {
+                inner.rejected.fetch_add(1, Ordering::AcqRel);
+                None
+            }
+        }
+    }
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl fmt::Debug for ConcurrencyLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrencyLimit")
+            .field("limit", &self.limit())
+            .field("in_flight", &self.in_flight())
+            .field("rejected", &self.rejected())
+            .finish()
+    }
+}
+
+/// Managed state exposing the [`ConcurrencyLimit`]s of all mounted routes.
+///
+/// Rocket places a `ConcurrencyLimits` in managed state at ignition if at least
+/// one mounted route has a limit. It can then be retrieved via
+/// `&State<ConcurrencyLimits>` to report on utilization. Otherwise, there is
+/// nothing to report on, and `Rocket::state::<ConcurrencyLimits>()` returns
+/// `None`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::route::ConcurrencyLimits;
+///
+/// #[get("/report", max_concurrency = 4)]
+/// async fn report() -> &'static str {
+///     "the report"
+/// }
+///
+/// #[get("/utilization")]
+/// fn utilization(limits: &State<ConcurrencyLimits>) -> String {
+///     limits.iter()
+///         .map(|(route, limit)| format!("{}: {}/{}", route, limit.in_flight(), limit.limit()))
+///         .collect::<Vec<_>>()
+///         .join("\n")
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     rocket::build().mount("/", routes![report, utilization])
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ConcurrencyLimits(Vec<Entry>);
+
+#[derive(Debug)]
+struct Entry {
+    name: Option<Cow<'static, str>>,
+    method: Method,
+    uri: RouteUri<'static>,
+    limit: ConcurrencyLimit,
+}
+
+/// A description of a route with a [`ConcurrencyLimit`].
+#[derive(Debug, Clone, Copy)]
+pub struct LimitedRoute<'a> {
+    /// The name of the route, if it has one.
+    pub name: Option<&'a str>,
+    /// The method of the route.
+    pub method: Method,
+    /// The URI of the route.
+    pub uri: &'a RouteUri<'static>,
+}
+
+impl ConcurrencyLimits {
+    pub(crate) fn new<'a, I: Iterator<Item = &'a Route>>(routes: I) -> Self {
+        let entries = routes.filter_map(|route| {
+            route.concurrency.clone().map(|limit| Entry {
+                name: route.name.clone(),
+                method: route.method,
+                uri: route.uri.clone(),
+                limit,
+            })
+        });
+
+        ConcurrencyLimits(entries.collect())
+    }
+
+    /// Returns the limit of the first route named `name`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::route::ConcurrencyLimits;
+    ///
+    /// #[get("/report", max_concurrency = 4)]
+    /// async fn report() { }
+    ///
+    /// # rocket::async_test(async {
+    /// let rocket = rocket::build().mount("/", routes![report]).ignite().await.unwrap();
+    /// let limits = rocket.state::<ConcurrencyLimits>().unwrap();
+    /// assert_eq!(limits.get("report").unwrap().limit(), 4);
+    /// assert!(limits.get("index").is_none());
+    /// # });
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&ConcurrencyLimit> {
+        self.0.iter()
+            .find(|entry| entry.name.as_deref() == Some(name))
+            .map(|entry| &entry.limit)
+    }
+
+    /// Returns an iterator over the routes with a limit and their limits.
+    pub fn iter(&self) -> impl Iterator<Item = (LimitedRoute<'_>, &ConcurrencyLimit)> {
+        self.0.iter().map(|entry| {
+            let route = LimitedRoute {
+                name: entry.name.as_deref(),
+                method: entry.method,
+                uri: &entry.uri,
+            };
+
+            (route, &entry.limit)
+        })
+    }
+}
+
+impl fmt::Display for LimitedRoute<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = self.name {
+            write!(f, "({}) ", name)?;
+        }
+
+        write!(f, "{} {}", self.method, self.uri)
+    }
+}
@@ -4,9 +4,15 @@ mod route;
 mod handler;
 mod uri;
 mod segment;
+mod report;
+mod concurrency;
+mod cache;
 
 pub use route::*;
 pub use handler::*;
 pub use uri::*;
+pub use report::*;
+pub use concurrency::{ConcurrencyLimit, ConcurrencyLimits, LimitedRoute};
+pub use cache::{ResponseCache, ResponseCaches};
 
 pub(crate) use segment::Segment;
@@ -0,0 +1,196 @@
+use serde::Serialize;
+
+use crate::{Route, Catcher};
+use crate::http::Status;
+use crate::router::Collide;
+
+/// A structured, serializable report of an application's routing table.
+///
+/// A `RouteReport` is generated via [`Rocket::diagnostics()`] and contains the
+/// same information Rocket checks and logs at ignition, but in programmatic
+/// form. It can be asserted on in tests or serialized, for instance to JSON, to
+/// catch accidental routing regressions as an application grows.
+///
+/// A report contains:
+///
+///   * **`routes`**: every mounted route, in descending order of precedence.
+///   * **`collisions`**: pairs of routes that collide. Any collision is an
+///     ignition error; see [`Route#collisions`].
+///   * **`shadowed`**: pairs of routes `(a, b)` where `a` can match a request
+///     that `b` also matches but `a` is tried first by virtue of its rank. `b`
+///     is only reached for such requests if `a` forwards. These are not errors.
+///   * **`catchers`**: every registered catcher.
+///   * **`catcher_collisions`**: pairs of colliding catchers. As with routes,
+///     any collision is an ignition error.
+///   * **`uncaught`**: the known error status codes (`4XX` and `5XX`) for
+///     which no catcher is registered at the root base `/`. Errors with these
+///     codes are handled by Rocket's default catcher unless a catcher with a
+///     more specific base applies.
+///
+/// [`Rocket::diagnostics()`]: crate::Rocket::diagnostics()
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// #[get("/")]
+/// fn index() { }
+///
+/// #[get("/<name>")]
+/// fn hello(name: &str) { }
+///
+/// #[catch(404)]
+/// fn not_found() { }
+///
+/// let rocket = rocket::build()
+///     .mount("/", routes![index, hello])
+///     .register("/", catchers![not_found]);
+///
+/// let report = rocket.diagnostics();
+/// assert!(report.is_ok());
+/// assert_eq!(report.routes.len(), 2);
+/// assert!(report.shadowed.is_empty());
+/// assert!(report.uncaught.contains(&500));
+/// assert!(!report.uncaught.contains(&404));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RouteReport {
+    /// All mounted routes, sorted by method and then by ascending rank.
+    pub routes: Vec<RouteInfo>,
+    /// Pairs of colliding routes.
+    pub collisions: Vec<(RouteInfo, RouteInfo)>,
+    /// Pairs `(a, b)` of routes where `a` takes precedence over `b` for some
+    /// requests that both match.
+    pub shadowed: Vec<(RouteInfo, RouteInfo)>,
+    /// All registered catchers.
+    pub catchers: Vec<CatcherInfo>,
+    /// Pairs of colliding catchers.
+    pub catcher_collisions: Vec<(CatcherInfo, CatcherInfo)>,
+    /// Known error status codes without a catcher registered at `/`.
+    pub uncaught: Vec<u16>,
+}
+
+/// Information about a single route in a [`RouteReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteInfo {
+    /// The name of the route, if it has one.
+    pub name: Option<String>,
+    /// The route's method.
+    pub method: String,
+    /// The route's full, mounted URI.
+    pub uri: String,
+    /// The route's rank.
+    pub rank: isize,
+    /// The route's format, if it has one.
+    pub format: Option<String>,
+}
+
+/// Information about a single catcher in a [`RouteReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CatcherInfo {
+    /// The name of the catcher, if it has one.
+    pub name: Option<String>,
+    /// The status code the catcher handles or `None` for a default catcher.
+    pub code: Option<u16>,
+    /// The catcher's base.
+    pub base: String,
+}
+
+impl RouteReport {
+    pub(crate) fn new<'a, R, C>(routes: R, catchers: C) -> RouteReport
+        where R: Iterator<Item = &'a Route>, C: Iterator<Item = &'a Catcher>
+    {
+        let mut routes: Vec<&Route> = routes.collect();
+        routes.sort_by(|a, b| a.method.as_str().cmp(b.method.as_str())
+            .then(a.rank.cmp(&b.rank))
+            .then_with(|| a.uri.to_string().cmp(&b.uri.to_string())));
+
+        let mut catchers: Vec<&Catcher> = catchers.collect();
+        catchers.sort_by(|a, b| a.code.cmp(&b.code)
+            .then_with(|| a.base.path().as_str().cmp(b.base.path().as_str())));
+
+        let mut collisions = vec![];
+        let mut shadowed = vec![];
+        for (i, a) in routes.iter().enumerate() {
+            for b in routes.iter().skip(i + 1) {
+                if a.collides_with(b) {
+                    collisions.push((RouteInfo::from(*a), RouteInfo::from(*b)));
+                } else if a.shadows(b) {
+                    shadowed.push((RouteInfo::from(*a), RouteInfo::from(*b)));
+                } else if b.shadows(a) {
+                    shadowed.push((RouteInfo::from(*b), RouteInfo::from(*a)));
+                }
+            }
+        }
+
+        let mut catcher_collisions = vec![];
+        for (i, a) in catchers.iter().enumerate() {
+            for b in catchers.iter().skip(i + 1) {
+                if a.collides_with(b) {
+                    catcher_collisions.push((CatcherInfo::from(*a), CatcherInfo::from(*b)));
+                }
+            }
+        }
+
+        let root_catchers = catchers.iter().filter(|c| c.base.path() == "/");
+        let has_root_default = root_catchers.clone().any(|c| c.code.is_none());
+        let uncaught = (400..600)
+            .filter_map(Status::from_code)
+            .map(|status| status.code)
+            .filter(|_| !has_root_default)
+            .filter(|code| !root_catchers.clone().any(|c| c.code == Some(*code)))
+            .collect();
+
+        RouteReport {
+            routes: routes.into_iter().map(RouteInfo::from).collect(),
+            catchers: catchers.into_iter().map(CatcherInfo::from).collect(),
+            collisions, shadowed, catcher_collisions, uncaught,
+        }
+    }
+
+    /// Returns `true` if there are no route or catcher collisions, that is, if
+    /// the report contains no conditions that would cause ignition to fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// #[get("/")]
+    /// fn a() { }
+    ///
+    /// #[get("/")]
+    /// fn b() { }
+    ///
+    /// let report = rocket::build().mount("/", routes![a]).diagnostics();
+    /// assert!(report.is_ok());
+    ///
+    /// let report = rocket::build().mount("/", routes![a, b]).diagnostics();
+    /// assert!(!report.is_ok());
+    /// assert_eq!(report.collisions.len(), 1);
+    /// ```
+    pub fn is_ok(&self) -> bool {
+        self.collisions.is_empty() && self.catcher_collisions.is_empty()
+    }
+}
+
+impl From<&Route> for RouteInfo {
+    fn from(route: &Route) -> Self {
+        RouteInfo {
+            name: route.name.as_ref().map(|n| n.to_string()),
+            method: route.method.as_str().to_string(),
+            uri: route.uri.to_string(),
+            rank: route.rank,
+            format: route.format.as_ref().map(|f| f.to_string()),
+        }
+    }
+}
+
+impl From<&Catcher> for CatcherInfo {
+    fn from(catcher: &Catcher) -> Self {
+        CatcherInfo {
+            name: catcher.name.as_ref().map(|n| n.to_string()),
+            code: catcher.code,
+            base: catcher.base.to_string(),
+        }
+    }
+}
@@ -4,8 +4,8 @@ use std::borrow::Cow;
 
 use yansi::Paint;
 
-use crate::http::{uri, Method, MediaType};
-use crate::route::{Handler, RouteUri, BoxFuture};
+use crate::http::{uri, Method, MediaType, ContentType};
+use crate::route::{Handler, RouteUri, BoxFuture, ConcurrencyLimit, ResponseCache};
 use crate::sentinel::Sentry;
 
 /// A request handling route.
@@ -188,6 +188,66 @@ pub struct Route {
     pub rank: isize,
     /// The media type this route matches against, if any.
     pub format: Option<MediaType>,
+    /// Whether a request that matches this route in all but `format` is
+    /// failed, instead of forwarded, when no route handles it.
+    ///
+    /// When `true`, and no route succeeds or fails for a request that matches
+    /// this route except for its format, the request fails with `415
+    /// Unsupported Media Type` if the route's method supports a payload and
+    /// `406 Not Acceptable` otherwise, instead of the usual `404 Not Found`.
+    /// Other matching routes, including those with a different format, are
+    /// still tried first. Has no effect if `format` is `None`.
+    ///
+    /// In a route attribute, this is set via `strict_format`:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// // A `POST /user` with a `Content-Type` other than JSON fails with a `415`.
+    /// #[post("/user", format = "json", strict_format, data = "<user>")]
+    /// fn new_user(user: String) { /* .. */ }
+    /// ```
+    pub strict_format: bool,
+    /// The `Content-Type` of the handler's responses, if any. It is set on
+    /// successful responses that have none and is used instead of
+    /// `application/octet-stream` by the `&[u8]` and `Vec<u8>` responders.
+    ///
+    /// In a route attribute, this is set via `content_type`:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// // Responses to `GET /logo` have a `Content-Type` of `image/png`.
+    /// #[get("/logo", content_type = "image/png")]
+    /// fn logo() -> Vec<u8> { /* .. */ vec![] }
+    /// ```
+    pub content_type: Option<ContentType>,
+    /// The limit on concurrent executions of the handler, if any. See
+    /// [`ConcurrencyLimit`] for details.
+    ///
+    /// In a route attribute, this is set via `max_concurrency`:
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// // At most 4 requests execute `report` at once; others fail with a `503`.
+    /// #[get("/report", max_concurrency = 4)]
+    /// async fn report() { /* .. */ }
+    /// ```
+    pub concurrency: Option<ConcurrencyLimit>,
+    /// The cache of the handler's responses, if any. See [`ResponseCache`] for
+    /// details.
+    ///
+    /// In a route attribute, this is set via `cache`, with the time-to-live as
+    /// a number of seconds (`s`), minutes (`m`), or hours (`h`):
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// // For a minute, `GET /stats` is answered with the first response.
+    /// #[get("/stats", cache = "60s")]
+    /// async fn stats() -> &'static str { /* .. */ "the stats" }
+    /// ```
+    pub cache: Option<ResponseCache>,
+    /// The host this route matches against, if any. See
+    /// [`Rocket::mount_host()`](crate::Rocket::mount_host()) for details.
+    pub host: Option<Cow<'static, str>>,
     /// The discovered sentinels.
     pub(crate) sentinels: Vec<Sentry>,
 }
@@ -250,6 +310,11 @@ impl Route {
         Route {
             name: None,
             format: None,
+            strict_format: false,
+            content_type: None,
+            concurrency: None,
+            cache: None,
+            host: None,
             sentinels: Vec::new(),
             handler: Box::new(handler),
             rank, uri, method,
@@ -297,6 +362,10 @@ impl fmt::Display for Route {
         }
 
         write!(f, "{} ", Paint::green(&self.method))?;
+        if let Some(ref host) = self.host {
+            write!(f, "{}", Paint::magenta(host))?;
+        }
+
         if self.uri.base() != "/" {
             write!(f, "{}", Paint::blue(self.uri.base()).underline())?;
         }
@@ -309,6 +378,17 @@ impl fmt::Display for Route {
 
         if let Some(ref format) = self.format {
             write!(f, " {}", Paint::yellow(format))?;
+            if self.strict_format {
+                write!(f, " {}", Paint::yellow("(strict)"))?;
+            }
+        }
+
+        if let Some(ref limit) = self.concurrency {
+            write!(f, " {}", Paint::cyan(format!("(max {})", limit.limit())))?;
+        }
+
+        if let Some(ref cache) = self.cache {
+            write!(f, " {}", Paint::cyan(format!("(cache {}s)", cache.ttl().as_secs())))?;
         }
 
         Ok(())
@@ -323,6 +403,11 @@ impl fmt::Debug for Route {
             .field("uri", &self.uri)
             .field("rank", &self.rank)
             .field("format", &self.format)
+            .field("strict_format", &self.strict_format)
+            .field("content_type", &self.content_type)
+            .field("concurrency", &self.concurrency)
+            .field("cache", &self.cache)
+            .field("host", &self.host)
             .finish()
     }
 }
@@ -338,10 +423,18 @@ pub struct StaticInfo {
     pub uri: &'static str,
     /// The route's format, if any.
     pub format: Option<MediaType>,
+    /// Whether format mismatches fail rather than forward.
+    pub strict_format: bool,
+    /// The route's response content type, if any.
+    pub content_type: Option<ContentType>,
     /// The route's handler, i.e, the annotated function.
     pub handler: for<'r> fn(&'r crate::Request<'_>, crate::Data<'r>) -> BoxFuture<'r>,
     /// The route's rank, if any.
     pub rank: Option<isize>,
+    /// The route's concurrency limit, if any.
+    pub max_concurrency: Option<usize>,
+    /// The route's cache time-to-live, if any.
+    pub cache: Option<std::time::Duration>,
     /// Route-derived sentinels, if any.
     /// This isn't `&'static [SentryInfo]` because `type_name()` isn't `const`.
     pub sentinels: Vec<Sentry>,
@@ -359,6 +452,11 @@ impl From<StaticInfo> for Route {
             handler: Box::new(info.handler),
             rank: info.rank.unwrap_or_else(|| uri.default_rank()),
             format: info.format,
+            strict_format: info.strict_format,
+            content_type: info.content_type,
+            concurrency: info.max_concurrency.map(ConcurrencyLimit::new),
+            cache: info.cache.map(ResponseCache::new),
+            host: None,
             sentinels: info.sentinels.into_iter().collect(),
             uri,
         }
@@ -25,4 +25,16 @@ impl Segment {
 
         Segment { value: value.to_string(), dynamic, trailing }
     }
+
+    /// Like [`Segment::from()`], but for path segments: the value of a static
+    /// segment is percent-decoded so that it compares equal to the decoded
+    /// segments of an incoming request.
+    pub fn from_path(segment: &RawStr) -> Self {
+        let mut segment_ = Segment::from(segment);
+        if !segment_.dynamic {
+            segment_.value = segment.percent_decode_lossy().into_owned();
+        }
+
+        segment_
+    }
 }
@@ -250,11 +250,11 @@ impl<'a> RouteUri<'a> {
 impl Metadata {
     fn from(base: &Origin<'_>, origin: &Origin<'_>) -> Self {
         let base_segs = base.path().raw_segments()
-            .map(Segment::from)
+            .map(Segment::from_path)
             .collect::<Vec<_>>();
 
         let path_segs = origin.path().raw_segments()
-            .map(Segment::from)
+            .map(Segment::from_path)
             .collect::<Vec<_>>();
 
         let query_segs = origin.query()
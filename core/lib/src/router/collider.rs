@@ -2,7 +2,7 @@ use crate::catcher::Catcher;
 use crate::route::{Route, Color};
 
 use crate::http::{MediaType, Status};
-use crate::request::Request;
+use crate::request::{Request, Host};
 
 pub trait Collide<T = Self> {
     fn collides_with(&self, other: &T) -> bool;
@@ -61,6 +61,52 @@ fn formats_collide(route: &Route, other: &Route) -> bool {
     }
 }
 
+fn hosts_collide(route: &Route, other: &Route) -> bool {
+    // Routes for a host are tried before routes without one, so only routes
+    // for overlapping hosts, or routes without one, can collide.
+    match (route.host.as_ref(), other.host.as_ref()) {
+        (Some(a), Some(b)) => host_patterns_overlap(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if some host matches both `a` and `b`, patterns as accepted
+/// by [`Rocket::mount_host()`](crate::Rocket::mount_host()). A pattern without
+/// a port matches every port, so `example.com` overlaps `example.com:8000`,
+/// and `*.example.com` overlaps `api.example.com` and `*.api.example.com`.
+fn host_patterns_overlap(a: &str, b: &str) -> bool {
+    use crate::http::uri::Authority;
+
+    let (a, b) = match (Authority::parse(a), Authority::parse(b)) {
+        (Ok(a), Ok(b)) => (a, b),
+        _ => return a.eq_ignore_ascii_case(b),
+    };
+
+    if let (Some(p1), Some(p2)) = (a.port(), b.port()) {
+        if p1 != p2 {
+            return false;
+        }
+    }
+
+    // `*.suffix` matches `host` if `host` is a strict subdomain of `suffix`.
+    let is_subdomain = |host: &str, suffix: &str| {
+        let i = host.len().saturating_sub(suffix.len());
+        i > 1 && host.is_char_boundary(i)
+            && host[..i].ends_with('.')
+            && host[i..].eq_ignore_ascii_case(suffix)
+    };
+
+    match (a.host().strip_prefix("*."), b.host().strip_prefix("*.")) {
+        (Some(x), Some(y)) => x.eq_ignore_ascii_case(y)
+            || is_subdomain(x, y)
+            || is_subdomain(y, x),
+        (Some(x), None) => is_subdomain(b.host(), x),
+        (None, Some(y)) => is_subdomain(a.host(), y),
+        (None, None) => a.host().eq_ignore_ascii_case(b.host()),
+    }
+}
+
 impl Collide for Route {
     /// Determines if two routes can match against some request. That is, if two
     /// routes `collide`, there exists a request that can match against both
@@ -73,21 +119,39 @@ impl Collide for Route {
     ///   * If route doesn't specify a format, it gets requests for any format.
     ///
     /// Because query parsing is lenient, and dynamic query parameters can be
-    /// missing, queries do not impact whether two routes collide.
+    /// missing, queries do not impact whether two routes collide. Routes for
+    /// hosts that no single host matches, or for a host and no host, never
+    /// collide: `example.com` and `example.com:8000` collide, as do
+    /// `*.example.com` and `api.example.com`.
     fn collides_with(&self, other: &Route) -> bool {
         self.method == other.method
             && self.rank == other.rank
+            && hosts_collide(self, other)
             && paths_collide(self, other)
             && formats_collide(self, other)
     }
 }
 
 impl Route {
+    /// Determines if `self` takes precedence over `other` for some request
+    /// that both routes can match. That is, `self` and `other` have the same
+    /// method and overlapping paths and formats, but `self` has a lower rank
+    /// and is thus tried first. `other` is only reached for such requests if
+    /// `self` forwards.
+    pub(crate) fn shadows(&self, other: &Route) -> bool {
+        self.method == other.method
+            && self.rank < other.rank
+            && (self.host.is_none() || hosts_collide(self, other))
+            && paths_collide(self, other)
+            && formats_collide(self, other)
+    }
+
     /// Determines if this route matches against the given request.
     ///
     /// This means that:
     ///
     ///   * The route's method matches that of the incoming request.
+    ///   * The route's host (if any) matches the request's `Host`.
     ///   * The route's format (if any) matches that of the incoming request.
     ///     - If route specifies format, it only gets requests for that format.
     ///     - If route doesn't specify format, it gets requests for any format.
@@ -97,14 +161,58 @@ impl Route {
     ///     request query string, though in any position. If there is no query
     ///     in the route, requests with/without queries match.
     pub(crate) fn matches(&self, req: &Request<'_>) -> bool {
+        self.matches_all_but_format(req) && formats_match(self, req)
+    }
+
+    /// Determines if this route matches against the given request in every
+    /// respect, as defined by [`Route::matches()`], except for its format.
+    pub(crate) fn matches_all_but_format(&self, req: &Request<'_>) -> bool {
+        self.method == req.method()
+            && hosts_match(self, req)
+            && paths_match(self, req, false)
+            && queries_match(self, req)
+    }
+
+    /// Determines if this route matches against the given request in its path
+    /// and query, regardless of the request's method and format.
+    pub(crate) fn matches_all_but_method(&self, req: &Request<'_>) -> bool {
+        hosts_match(self, req) && paths_match(self, req, false) && queries_match(self, req)
+    }
+
+    /// Determines if this route matches against the given request, as defined
+    /// by [`Route::matches()`], when static path components are compared
+    /// ASCII case-insensitively.
+    pub(crate) fn matches_ignoring_case(&self, req: &Request<'_>) -> bool {
         self.method == req.method()
-            && paths_match(self, req)
+            && hosts_match(self, req)
+            && paths_match(self, req, true)
             && queries_match(self, req)
             && formats_match(self, req)
     }
+
+    /// Determines if this route has a strict format and matches the given
+    /// request in every respect but its format.
+    pub(crate) fn mismatches_strict_format(&self, req: &Request<'_>) -> bool {
+        self.strict_format
+            && self.format.is_some()
+            && self.matches_all_but_format(req)
+            && !formats_match(self, req)
+    }
 }
 
-fn paths_match(route: &Route, req: &Request<'_>) -> bool {
+fn hosts_match(route: &Route, req: &Request<'_>) -> bool {
+    let pattern = match route.host {
+        Some(ref pattern) => pattern,
+        None => return true,
+    };
+
+    req.raw_host()
+        .and_then(Host::parse)
+        .map(|host| host.matches(pattern))
+        .unwrap_or(false)
+}
+
+fn paths_match(route: &Route, req: &Request<'_>, ignore_case: bool) -> bool {
     let route_segments = &route.uri.metadata.path_segs;
     let req_segments = req.uri().path().segments();
 
@@ -128,7 +236,16 @@ fn paths_match(route: &Route, req: &Request<'_>) -> bool {
             return true;
         }
 
-        if !(route_seg.dynamic || route_seg.value == req_seg) {
+        if route_seg.dynamic {
+            continue;
+        }
+
+        let eq = match ignore_case {
+            true => route_seg.value.eq_ignore_ascii_case(req_seg),
+            false => route_seg.value == req_seg,
+        };
+
+        if !eq {
             return false;
         }
     }
@@ -300,6 +417,17 @@ mod tests {
         assert!(unranked_collide("/a/b", "/a/b?<c>"));
     }
 
+    #[test]
+    fn encoded_collisions() {
+        assert!(unranked_collide("/%41bc", "/Abc"));
+        assert!(unranked_collide("/%41%62c", "/A%62c"));
+        assert!(unranked_collide("/a/%62", "/a/b"));
+        assert!(unranked_collide("/a%20b", "/a b"));
+        assert!(!unranked_collide("/%41bc", "/abc"));
+        assert!(!unranked_collide("/a%2Fb", "/a/b"));
+        assert!(!unranked_collide("/a+b", "/a%20b"));
+    }
+
     #[test]
     fn non_collisions() {
         assert!(!unranked_collide("/<a>", "/"));
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::request::Request;
-use crate::http::{Method, Status};
+use crate::http::{Method, Status, RawStr};
 
 use crate::{Route, Catcher};
 use crate::router::Collide;
@@ -26,7 +26,7 @@ impl Router {
     pub fn add_route(&mut self, route: Route) {
         let routes = self.routes.entry(route.method).or_default();
         routes.push(route);
-        routes.sort_by_key(|r| r.rank);
+        routes.sort_by_key(|r| (r.rank, r.host.is_none()));
     }
 
     pub fn add_catcher(&mut self, catcher: Catcher) {
@@ -49,14 +49,78 @@ impl Router {
         &'a self,
         req: &'r Request<'r>
     ) -> impl Iterator<Item = &'a Route> + 'r {
-        // Note that routes are presorted by ascending rank on each `add`.
+        // Note that routes are presorted by ascending rank, and routes for a
+        // host before routes without one, on each `add`.
         self.routes.get(&req.method())
             .into_iter()
             .flat_map(move |routes| routes.iter().filter(move |r| r.matches(req)))
     }
 
+    /// Returns the routes with a strict format that match `req` in all but
+    /// their format, in ascending rank order.
+    pub fn format_mismatches<'r, 'a: 'r>(
+        &'a self,
+        req: &'r Request<'r>
+    ) -> impl Iterator<Item = &'a Route> + 'r {
+        self.routes.get(&req.method())
+            .into_iter()
+            .flat_map(move |routes| routes.iter().filter(move |r| r.mismatches_strict_format(req)))
+    }
+
+    /// Returns the methods of the routes that match `req` in path and query,
+    /// regardless of method, including `HEAD` if `GET` is among them. The
+    /// methods are deduplicated and sorted by name.
+    pub fn allowed_methods(&self, req: &Request<'_>) -> Vec<Method> {
+        let mut methods = self.routes.iter()
+            .filter(|(_, routes)| routes.iter().any(|r| r.matches_all_but_method(req)))
+            .map(|(method, _)| *method)
+            .collect::<Vec<_>>();
+
+        if methods.contains(&Method::Get) && !methods.contains(&Method::Head) {
+            methods.push(Method::Head);
+        }
+
+        methods.sort_by_key(|method| method.as_str());
+        methods
+    }
+
+    /// Returns the request's URI recased to match the first route that matches
+    /// `req` when static path segments are compared case-insensitively, or
+    /// `None` if there is no such route or the casing is already canonical.
+    /// Dynamic segments and the query are carried over verbatim.
+    pub fn canonical_uri(&self, req: &Request<'_>) -> Option<String> {
+        let route = self.routes.get(&req.method())?
+            .iter()
+            .find(|r| r.matches_ignoring_case(req))?;
+
+        let mut changed = false;
+        let mut uri = String::new();
+        let mut route_segments = route.uri.metadata.path_segs.iter();
+        for raw in req.uri().path().raw_segments().filter(|s| !s.is_empty()) {
+            uri.push('/');
+            match route_segments.next() {
+                Some(seg) if !seg.dynamic && seg.value != raw.percent_decode_lossy() => {
+                    changed = true;
+                    uri.push_str(RawStr::new(&seg.value).percent_encode().as_str());
+                }
+                _ => uri.push_str(raw.as_str()),
+            }
+        }
+
+        if uri.is_empty() {
+            uri.push('/');
+        }
+
+        if let Some(query) = req.uri().query() {
+            uri.push('?');
+            uri.push_str(query.as_str());
+        }
+
+        Some(uri).filter(|_| changed)
+    }
+
     // For many catchers, using aho-corasick or similar should be much faster.
-    pub fn catch<'r>(&self, status: Status, req: &'r Request<'r>) -> Option<&Catcher> {
+    pub fn catch(&self, status: Status, req: &Request<'_>) -> Option<&Catcher> {
         // Note that catchers are presorted by descending base length.
         let explicit = self.catchers.get(&Some(status.code))
             .and_then(|c| c.iter().find(|c| c.matches(status, req)));
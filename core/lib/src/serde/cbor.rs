@@ -0,0 +1,343 @@
+//! Automatic CBOR (de)serialization support.
+//!
+//! See [`Cbor`](crate::serde::cbor::Cbor) for further details.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `cbor` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rocket]
+//! version = "0.5.0-rc.1"
+//! features = ["cbor"]
+//! ```
+//!
+//! # Testing
+//!
+//! The [`LocalRequest`] and [`LocalResponse`] types provide [`cbor()`] and
+//! [`into_cbor()`] methods to create a request with serialized CBOR and
+//! deserialize a response as CBOR, respectively.
+//!
+//! [`LocalRequest`]: crate::local::blocking::LocalRequest
+//! [`LocalResponse`]: crate::local::blocking::LocalResponse
+//! [`cbor()`]: crate::local::blocking::LocalRequest::cbor()
+//! [`into_cbor()`]: crate::local::blocking::LocalResponse::into_cbor()
+
+use std::{io, fmt};
+use std::ops::{Deref, DerefMut};
+
+use crate::request::Request;
+use crate::data::{Limits, Data, FromData, Outcome};
+use crate::response::{self, Responder, content};
+use crate::http::Status;
+use crate::form::prelude as form;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// The CBOR guard: easily consume and return CBOR.
+///
+/// ## Receiving CBOR
+///
+/// `Cbor` is both a data guard and a form guard.
+///
+/// ### Data Guard
+///
+/// To parse request body data as CBOR, add a `data` route argument with a
+/// target type of `Cbor<T>`, where `T` is some type you'd like to parse from
+/// CBOR. `T` must implement [`serde::de::DeserializeOwned`]: unlike `Json` and
+/// `MsgPack`, CBOR values are always deserialized into owned data.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::serde::cbor::Cbor;
+///
+/// #[post("/users", format = "cbor", data = "<user>")]
+/// fn new_user(user: Cbor<User>) {
+///     /* ... */
+/// }
+/// ```
+///
+/// You don't _need_ to use `format = "cbor"`, but it _may_ be what you want.
+/// Using `format = cbor` means that any request that doesn't specify
+/// "application/cbor" as its first `Content-Type:` header parameter will not be
+/// routed to this handler.
+///
+/// If the body fails to decode as CBOR or as a `T`, the guard fails with a
+/// status of `400 Bad Request`. If the body exceeds the configured limit, the
+/// guard fails with `413 Payload Too Large`.
+///
+/// ### Form Guard
+///
+/// `Cbor<T>`, as a form guard, accepts data fields and parses the data as a
+/// `T`. Simple use `Cbor<T>`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type Metadata = usize;
+/// use rocket::form::{Form, FromForm};
+/// use rocket::serde::cbor::Cbor;
+///
+/// #[derive(FromForm)]
+/// struct User<'r> {
+///     name: &'r str,
+///     metadata: Cbor<Metadata>
+/// }
+///
+/// #[post("/users", data = "<form>")]
+/// fn new_user(form: Form<User<'_>>) {
+///     /* ... */
+/// }
+/// ```
+///
+/// ### Incoming Data Limits
+///
+/// The default size limit for incoming CBOR data is 1MiB. Setting a limit
+/// protects your application from denial of service (DOS) attacks and from
+/// resource exhaustion through high memory consumption. The limit can be
+/// increased by setting the `limits.cbor` configuration parameter. For
+/// instance, to increase the CBOR limit to 5MiB for all environments, you may
+/// add the following to your `Rocket.toml`:
+///
+/// ```toml
+/// [global.limits]
+/// cbor = 5242880
+/// ```
+///
+/// ## Sending CBOR
+///
+/// If you're responding with CBOR data, return a `Cbor<T>` type, where `T`
+/// implements [`Serialize`] from [`serde`]. The content type of the response is
+/// set to `application/cbor` automatically.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::serde::cbor::Cbor;
+///
+/// #[get("/users/<id>")]
+/// fn user(id: usize) -> Cbor<User> {
+///     let user_from_id = User::from(id);
+///     /* ... */
+///     Cbor(user_from_id)
+/// }
+/// ```
+///
+/// As with `Json`, `T` may be a reference such as `&[T]` or `&T` that
+/// borrows from managed state, from request guards, or from `'static` data:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::State;
+/// use rocket::serde::cbor::Cbor;
+///
+/// struct Users(Vec<User>);
+///
+/// #[get("/users")]
+/// fn users(users: &State<Users>) -> Cbor<&[User]> {
+///     Cbor(&users.0)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Cbor<T>(pub T);
+
+/// Error returned by the [`Cbor`] guard when CBOR deserialization fails.
+#[derive(Debug)]
+pub enum Error {
+    /// An I/O error occurred while reading the incoming request data.
+    Io(io::Error),
+
+    /// The client's data was received successfully but failed to parse as
+    /// valid CBOR or as the requested type.
+    Parse(ciborium::de::Error<io::Error>),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "i/o error: {}", e),
+            Error::Parse(e) => write!(f, "parse error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl<T> Cbor<T> {
+    /// Consumes the `Cbor` wrapper and returns the wrapped item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::serde::cbor::Cbor;
+    /// let string = "Hello".to_string();
+    /// let my_cbor = Cbor(string);
+    /// assert_eq!(my_cbor.into_inner(), "Hello".to_string());
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: DeserializeOwned> Cbor<T> {
+    fn from_bytes(buf: &[u8]) -> Result<Self, Error> {
+        from_slice(buf).map(Cbor).map_err(Error::Parse)
+    }
+
+    async fn from_data(req: &Request<'_>, data: Data<'_>) -> Result<Self, Error> {
+        let limit = req.limits().get("cbor").unwrap_or(Limits::CBOR);
+        let bytes = match data.open(limit).into_bytes().await {
+            Ok(buf) if buf.is_complete() => buf.into_inner(),
+            Ok(_) => {
+                let eof = io::ErrorKind::UnexpectedEof;
+                return Err(Error::Io(io::Error::new(eof, "data limit exceeded")));
+            },
+            Err(e) => return Err(Error::Io(e)),
+        };
+
+        Self::from_bytes(&bytes)
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for Cbor<T> {
+    type Error = Error;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        match Self::from_data(req, data).await {
+            Ok(value) => Outcome::Success(value),
+            Err(Error::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Outcome::Failure((Status::PayloadTooLarge, Error::Io(e)))
+            },
+            Err(e) => Outcome::Failure((Status::BadRequest, e)),
+        }
+    }
+}
+
+/// Serializes the wrapped value into CBOR. Returns a response with Content-Type
+/// `CBOR` and a fixed-size body with the serialization. If serialization fails,
+/// an `Err` of `Status::InternalServerError` is returned.
+impl<'r, T: Serialize> Responder<'r, 'static> for Cbor<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let buf = to_vec(&self.0)
+            .map_err(|e| {
+                error_!("CBOR failed to serialize: {:?}", e);
+                Status::InternalServerError
+            })?;
+
+        content::Cbor(buf).respond_to(req)
+    }
+}
+
+impl From<Error> for form::Error<'_> {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Io(e) => e.into(),
+            Error::Parse(e) => form::Error::custom(e),
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'v, T: DeserializeOwned + Send> form::FromFormField<'v> for Cbor<T> {
+    async fn from_data(f: form::DataField<'v, '_>) -> Result<Self, form::Errors<'v>> {
+        Ok(Self::from_data(f.request, f.data).await?)
+    }
+}
+
+impl<T> From<T> for Cbor<T> {
+    fn from(value: T) -> Self {
+        Cbor(value)
+    }
+}
+
+impl<T> Deref for Cbor<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Cbor<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Deserialize an instance of type `T` from CBOR encoded bytes.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Deserialize, cbor};
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data {
+///     framework: String,
+///     stars: usize,
+/// }
+///
+/// let bytes = &[
+///     162, 105, 102, 114, 97, 109, 101, 119, 111, 114, 107, 102, 82, 111,
+///     99, 107, 101, 116, 101, 115, 116, 97, 114, 115, 5
+/// ];
+///
+/// let data: Data = cbor::from_slice(bytes).unwrap();
+/// assert_eq!(data, Data { framework: "Rocket".into(), stars: 5, });
+/// ```
+///
+/// # Errors
+///
+/// Deserialization fails if `v` does not represent a valid CBOR encoding of any
+/// instance of `T` or if `T`'s `Deserialize` implementation fails otherwise.
+#[inline(always)]
+pub fn from_slice<T>(v: &[u8]) -> Result<T, ciborium::de::Error<io::Error>>
+    where T: DeserializeOwned,
+{
+    ciborium::de::from_reader(v)
+}
+
+/// Serialize a `T` into a CBOR byte vector.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Serialize, cbor};
+///
+/// #[derive(Serialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data<'r> {
+///     framework: &'r str,
+///     stars: usize,
+/// }
+///
+/// let bytes = cbor::to_vec(&Data { framework: "Rocket", stars: 5 }).unwrap();
+/// assert_eq!(&bytes[..2], &[162, 105]);
+/// ```
+///
+/// # Errors
+///
+/// Serialization fails if `T`'s `Serialize` implementation fails.
+#[inline(always)]
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, ciborium::ser::Error<io::Error>>
+    where T: Serialize + ?Sized,
+{
+    let mut buf = Vec::new();
+    ciborium::ser::into_writer(value, &mut buf)?;
+    Ok(buf)
+}
@@ -1,6 +1,8 @@
 //! Automatic JSON (de)serialization support.
 //!
-//! See [`Json`](Json) for details.
+//! See [`Json`](Json) for details. To parse a body of newline-delimited JSON
+//! as it is received, see [`NdJson`]. For partial updates via `PATCH`, see
+//! [`MergePatch`] and [`JsonPatch`].
 //!
 //! # Enabling
 //!
@@ -24,20 +26,32 @@
 //! [`json()`]: crate::local::blocking::LocalRequest::json()
 //! [`into_json()`]: crate::local::blocking::LocalResponse::into_json()
 
-use std::io;
+use std::{fmt, io};
+use std::pin::Pin;
+use std::convert::Infallible;
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::task::{Context, Poll};
+
+use futures::stream::Stream;
+use futures::ready;
 
 use crate::request::{Request, local_cache};
-use crate::data::{Limits, Data, FromData, Outcome};
+use crate::data::{Limits, Data, DataChunks, FromData, Outcome};
 use crate::response::{self, Responder, content};
 use crate::http::Status;
 use crate::form::prelude as form;
 
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 
 #[doc(hidden)]
 pub use serde_json;
 
+mod patch;
+
+pub use self::patch::{MergePatch, JsonPatch, PatchOperation, JsonPointer, PatchError};
+
 /// The JSON guard: easily consume and return JSON.
 ///
 /// ## Receiving JSON
@@ -66,6 +80,50 @@ pub use serde_json;
 /// "application/json" as its `Content-Type` header value will not be routed to
 /// the handler.
 ///
+/// ### Borrowing Deserialization
+///
+/// `T` need only implement `Deserialize<'r>`, not `DeserializeOwned`, so it may
+/// borrow from the request body, avoiding a copy of every string field. To
+/// make this possible, the body is read into a buffer stored in the request's
+/// [local cache](crate::request::local_cache), which lives, and thus keeps
+/// the buffer alive, as long as the `&'r Request`. A `Json<T>` that borrows can
+/// therefore be used anywhere the request can, including in the response, but
+/// it cannot outlive the request: to move its data into a spawned task or into
+/// managed state, convert it into owned data first.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use std::borrow::Cow;
+///
+/// use rocket::serde::{Deserialize, json::Json};
+///
+/// #[derive(Deserialize)]
+/// # #[serde(crate = "rocket::serde")]
+/// struct Message<'r> {
+///     /// Borrowed from the body. Fails to parse if the string has escapes.
+///     channel: &'r str,
+///     /// Borrowed from the body unless it has escapes; owned otherwise.
+///     #[serde(borrow)]
+///     text: Cow<'r, str>,
+/// }
+///
+/// #[post("/message", data = "<message>")]
+/// fn message(message: Json<Message<'_>>) -> String {
+///     format!("{}: {}", message.channel, message.text)
+/// }
+/// ```
+///
+/// A `&'r str` can only borrow a JSON string as it appears in the body, so a
+/// string containing escape sequences, like `"\n"` or `"\u00e9"`, fails to
+/// deserialize into one. Use `Cow<'r, str>` with `#[serde(borrow)]` to borrow
+/// when possible and allocate only when necessary.
+///
+/// Borrowing requires the entire body to be in memory, so, borrowing or not, a
+/// `Json<T>` buffers the complete body, subject to the [data
+/// limit](#incoming-data-limits), before deserializing any of it. To process
+/// large inputs incrementally, use [`NdJson`], which deserializes each line
+/// into an owned value as it arrives.
+///
 /// ### Form Guard
 ///
 /// `Json<T>`, as a form guard, accepts value and data fields and parses the
@@ -121,6 +179,36 @@ pub use serde_json;
 ///     Json(user_from_id)
 /// }
 /// ```
+///
+/// ### Borrowed Data
+///
+/// Because `&T` implements `Serialize` whenever `T` does, `T` may also be a
+/// reference such as `&[T]` or `&T`, avoiding a clone of data that is already
+/// in memory. The value is serialized before the handler's response is written,
+/// so the borrow need only outlive the request: it may borrow from managed
+/// state, from request guards, or from `'static` data, but not from locals of
+/// the handler itself.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::State;
+/// use rocket::serde::json::Json;
+///
+/// struct Users(Vec<User>);
+///
+/// #[get("/users")]
+/// fn users(users: &State<Users>) -> Json<&[User]> {
+///     Json(&users.0)
+/// }
+///
+/// static ADMIN: User = 0;
+///
+/// #[get("/users/admin")]
+/// fn admin() -> Json<&'static User> {
+///     Json(&ADMIN)
+/// }
+/// ```
 #[derive(Debug)]
 pub struct Json<T>(pub T);
 
@@ -249,6 +337,175 @@ impl<'v, T: Deserialize<'v> + Send> form::FromFormField<'v> for Json<T> {
     }
 }
 
+/// A data guard that parses newline-delimited JSON as it arrives.
+///
+/// An `NdJson<T>` is a [`Stream`] of `Result<T, LineError>`: each line of the
+/// request body, as in `application/x-ndjson`, is deserialized as a `T` and
+/// yielded as soon as the line is complete, without waiting for the rest of the
+/// body. Blank lines are skipped, and the final line needn't end in a newline.
+/// `T` must implement [`serde::de::DeserializeOwned`].
+///
+/// A line that fails to deserialize yields a [`LineError::Parse`] with its
+/// line number, after which the stream continues with the next line. An I/O
+/// error while reading the body yields a [`LineError::Io`] and ends the stream.
+///
+/// The body is read up to the `json/ndjson` limit, falling back to the `json`
+/// limit and then to [`Limits::JSON`]. If the body exceeds the limit, the stream
+/// yields a [`LineError::Io`] of kind [`io::ErrorKind::UnexpectedEof`] in place
+/// of the truncated line and ends.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::futures::StreamExt;
+/// use rocket::serde::{Deserialize, json::NdJson};
+///
+/// #[derive(Deserialize)]
+/// # #[serde(crate = "rocket::serde")]
+/// struct Reading {
+///     sensor: String,
+///     value: f64,
+/// }
+///
+/// #[post("/ingest", format = "application/x-ndjson", data = "<readings>")]
+/// async fn ingest(mut readings: NdJson<'_, Reading>) -> String {
+///     let (mut ok, mut failed) = (0, 0);
+///     while let Some(reading) = readings.next().await {
+///         match reading {
+///             Ok(reading) => ok += 1,
+///             Err(e) => { println!("{}", e); failed += 1; }
+///         }
+///     }
+///
+///     format!("{} ingested, {} failed", ok, failed)
+/// }
+/// ```
+///
+/// [`Stream`]: crate::futures::Stream
+pub struct NdJson<'r, T> {
+    chunks: DataChunks<'r>,
+    buffer: Vec<u8>,
+    scanned: usize,
+    line: usize,
+    done: bool,
+    _item: PhantomData<fn() -> T>,
+}
+
+/// Error yielded by the [`NdJson`] stream.
+#[derive(Debug)]
+pub enum LineError {
+    /// An I/O error occurred while reading the incoming request data, or the
+    /// data limit was exceeded.
+    Io(io::Error),
+
+    /// The line whose 1-based number is in `.0` failed to parse as valid JSON
+    /// or as the requested type.
+    Parse(usize, serde_json::error::Error),
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineError::Io(e) => write!(f, "i/o error: {}", e),
+            LineError::Parse(line, e) => write!(f, "line {}: {}", line, e),
+        }
+    }
+}
+
+impl std::error::Error for LineError {}
+
+impl<T: DeserializeOwned> NdJson<'_, T> {
+    /// Removes the first complete line from the buffer, if there is one.
+    fn next_line(&mut self) -> Option<Vec<u8>> {
+        let i = self.buffer[self.scanned..].iter().position(|&b| b == b'\n');
+        match i {
+            Some(i) => {
+                let mut line: Vec<u8> = self.buffer.drain(..=(self.scanned + i)).collect();
+                line.pop();
+                self.scanned = 0;
+                Some(line)
+            }
+            None => {
+                self.scanned = self.buffer.len();
+                None
+            }
+        }
+    }
+
+    /// Parses `line`, or returns `None` if it is blank.
+    fn parse(&mut self, line: &[u8]) -> Option<Result<T, LineError>> {
+        self.line += 1;
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            return None;
+        }
+
+        Some(serde_json::from_slice(line).map_err(|e| LineError::Parse(self.line, e)))
+    }
+}
+
+impl<T: DeserializeOwned> Stream for NdJson<'_, T> {
+    type Item = Result<T, LineError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            while let Some(line) = self.next_line() {
+                if let Some(item) = self.parse(&line) {
+                    return Poll::Ready(Some(item));
+                }
+            }
+
+            if self.done {
+                self.scanned = 0;
+                let line = std::mem::take(&mut self.buffer);
+                return match line.is_empty() {
+                    true => Poll::Ready(None),
+                    false => match self.parse(&line) {
+                        Some(item) => Poll::Ready(Some(item)),
+                        None => Poll::Ready(None),
+                    }
+                };
+            }
+
+            match ready!(Pin::new(&mut self.chunks).poll_next(cx)) {
+                Some(Ok(chunk)) => self.buffer.extend_from_slice(&chunk),
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.buffer.clear();
+                    self.scanned = 0;
+                    return Poll::Ready(Some(Err(LineError::Io(e))));
+                }
+                None if !self.chunks.n().complete => {
+                    self.done = true;
+                    self.buffer.clear();
+                    self.scanned = 0;
+                    let eof = io::ErrorKind::UnexpectedEof;
+                    let error = io::Error::new(eof, "data limit exceeded");
+                    return Poll::Ready(Some(Err(LineError::Io(error))));
+                }
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for NdJson<'r, T> {
+    type Error = Infallible;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        let limit = req.limits().find(["json", "ndjson"]).unwrap_or(Limits::JSON);
+        Outcome::Success(NdJson {
+            chunks: data.chunks(limit),
+            buffer: vec![],
+            scanned: 0,
+            line: 0,
+            done: false,
+            _item: PhantomData,
+        })
+    }
+}
+
 /// Serializes the value into JSON. Returns a response with Content-Type JSON
 /// and a fixed-size body with the serialized value.
 impl<'r> Responder<'r, 'static> for Value {
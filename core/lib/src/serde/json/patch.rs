@@ -0,0 +1,622 @@
+use std::fmt;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+use crate::request::Request;
+use crate::data::{Data, FromData, Outcome};
+use crate::response::{self, Responder};
+use crate::http::Status;
+use crate::serde::json::{Json, Error, Value};
+
+/// A data guard for [RFC 7386] JSON Merge Patch documents.
+///
+/// A merge patch describes changes to a JSON document using a document of the
+/// same shape: members present in the patch replace those in the target, a
+/// `null` member removes the corresponding member from the target, and nested
+/// objects are merged recursively. Any other value, including an array,
+/// replaces the target wholesale. A `MergePatch<T>` parses the request body as
+/// such a patch and [applies](MergePatch::apply()) it to an existing `T`,
+/// provided by the handler, which is typically read from a database.
+///
+/// The body is parsed exactly as by [`Json`], including its [data
+/// limit](Json#incoming-data-limits), and fails in the same way: with **400 Bad
+/// Request** when the body isn't JSON and **413 Payload Too Large** when the
+/// body exceeds the limit. A patch that can't be applied to a `T`, because the
+/// patched document isn't a valid `T`, results in a [`PatchError`] which
+/// responds with **422 Unprocessable Entity**.
+///
+/// [RFC 7386]: https://tools.ietf.org/html/rfc7386
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::serde::{Serialize, Deserialize};
+/// use rocket::serde::json::{Json, MergePatch, PatchError};
+///
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "rocket::serde")]
+/// struct User {
+///     name: String,
+///     bio: Option<String>,
+/// }
+///
+/// # fn find_user(_: usize) -> User { User { name: "Bob".into(), bio: None } }
+/// #[patch("/users/<id>", format = "application/merge-patch+json", data = "<patch>")]
+/// fn update(id: usize, patch: MergePatch<User>) -> Result<Json<User>, PatchError> {
+///     let mut user = find_user(id);
+///     patch.apply(&mut user)?;
+///     Ok(Json(user))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergePatch<T> {
+    patch: Value,
+    _target: PhantomData<fn() -> T>,
+}
+
+/// A data guard for [RFC 6902] JSON Patch documents.
+///
+/// A JSON Patch is a JSON array of [operations](PatchOperation), each of which
+/// adds, removes, replaces, moves, copies, or tests a value at a location in
+/// the target identified by a [`JsonPointer`]. The operations are available,
+/// in order, via [`JsonPatch::operations()`], and
+/// [`JsonPatch::apply()`] applies all of them to a value provided by the
+/// handler. Application is atomic: if any operation fails, the target is left
+/// unchanged.
+///
+/// The body is parsed exactly as by [`Json`], including its [data
+/// limit](Json#incoming-data-limits). A body that isn't JSON fails with **400
+/// Bad Request**, while one that is JSON but isn't a list of valid operations,
+/// such as one with an unknown `op` or an invalid pointer, fails with **422
+/// Unprocessable Entity**. An operation that can't be applied, like one whose
+/// `path` doesn't exist in the target or a `test` that fails, results in a
+/// [`PatchError`] which also responds with **422 Unprocessable Entity**.
+///
+/// [RFC 6902]: https://tools.ietf.org/html/rfc6902
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::serde::{Serialize, Deserialize};
+/// use rocket::serde::json::{Json, JsonPatch, PatchError};
+///
+/// #[derive(Serialize, Deserialize)]
+/// # #[serde(crate = "rocket::serde")]
+/// struct Document {
+///     title: String,
+///     tags: Vec<String>,
+/// }
+///
+/// # fn find_document(_: usize) -> Document { Document { title: "".into(), tags: vec![] } }
+/// #[patch("/docs/<id>", format = "application/json-patch+json", data = "<patch>")]
+/// fn update(id: usize, patch: JsonPatch) -> Result<Json<Document>, PatchError> {
+///     let mut document = find_document(id);
+///     patch.apply(&mut document)?;
+///     Ok(Json(document))
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPatch(Vec<PatchOperation>);
+
+/// A single [RFC 6902] JSON Patch operation.
+///
+/// Operations deserialize from objects with an `op` member naming the
+/// operation, as in `{ "op": "add", "path": "/a", "value": 1 }`. Unknown
+/// members are ignored.
+///
+/// [RFC 6902]: https://tools.ietf.org/html/rfc6902#section-4
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    /// Adds `value` at `path`, replacing an existing object member or
+    /// inserting into an array. A final `-` token in `path` appends to an
+    /// array.
+    Add {
+        /// The location to add the value at.
+        path: JsonPointer,
+        /// The value to add.
+        value: Value,
+    },
+    /// Removes the value at `path`, which must exist.
+    Remove {
+        /// The location of the value to remove.
+        path: JsonPointer,
+    },
+    /// Replaces the value at `path`, which must exist, with `value`.
+    Replace {
+        /// The location of the value to replace.
+        path: JsonPointer,
+        /// The new value.
+        value: Value,
+    },
+    /// Removes the value at `from` and adds it at `path`.
+    Move {
+        /// The location of the value to move.
+        from: JsonPointer,
+        /// The location to move the value to.
+        path: JsonPointer,
+    },
+    /// Adds a copy of the value at `from` at `path`.
+    Copy {
+        /// The location of the value to copy.
+        from: JsonPointer,
+        /// The location to copy the value to.
+        path: JsonPointer,
+    },
+    /// Succeeds only if the value at `path` is equal to `value`.
+    Test {
+        /// The location of the value to test.
+        path: JsonPointer,
+        /// The expected value.
+        value: Value,
+    },
+}
+
+/// An [RFC 6901] JSON Pointer identifying a location in a JSON document.
+///
+/// A pointer is either empty, pointing to the whole document, or a sequence of
+/// `/`-prefixed reference tokens, each naming an object member or an array
+/// index, in which `~1` and `~0` escape `/` and `~`, respectively.
+///
+/// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+///
+/// # Example
+///
+/// ```rust
+/// use std::convert::TryFrom;
+/// use rocket::serde::json::JsonPointer;
+///
+/// let pointer = JsonPointer::try_from("/a~1b/0").unwrap();
+/// assert_eq!(pointer.tokens().collect::<Vec<_>>(), ["a/b", "0"]);
+///
+/// assert!(JsonPointer::try_from("a").is_err());
+/// assert!(JsonPointer::try_from("/~2").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct JsonPointer(String);
+
+/// An error applying a [`MergePatch`] or [`JsonPatch`].
+///
+/// As a [`Responder`], a `PatchError` logs the error and forwards to the
+/// **422 Unprocessable Entity** catcher.
+#[derive(Debug)]
+pub enum PatchError {
+    /// The operation at index `.0` of a [`JsonPatch`] could not be applied,
+    /// for the reason described by `.1`.
+    Operation(usize, &'static str),
+    /// The target could not be serialized to JSON, or the patched JSON could
+    /// not be deserialized back into the target's type.
+    Target(serde_json::Error),
+}
+
+impl<T> MergePatch<T> {
+    /// Returns the patch document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{MergePatch, Value};
+    ///
+    /// fn handler(patch: MergePatch<Value>) {
+    ///     if patch.patch().get("password").is_some() {
+    ///         println!("changing the password");
+    ///     }
+    /// }
+    /// ```
+    pub fn patch(&self) -> &Value {
+        &self.patch
+    }
+
+    /// Consumes `self` and returns the patch document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{MergePatch, Value};
+    ///
+    /// fn handler(patch: MergePatch<Value>) {
+    ///     let document: Value = patch.into_inner();
+    /// }
+    /// ```
+    pub fn into_inner(self) -> Value {
+        self.patch
+    }
+
+    /// Applies the patch to the JSON value `target`. This cannot fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{json, MergePatch, Value};
+    ///
+    /// fn handler(patch: MergePatch<Value>) {
+    ///     let mut document = json!({ "a": 1, "b": { "c": 2 } });
+    ///     patch.apply_value(&mut document);
+    /// }
+    /// ```
+    pub fn apply_value(&self, target: &mut Value) {
+        merge(target, &self.patch);
+    }
+
+    /// Applies the patch to `target` by converting `target` to JSON, applying
+    /// the patch, and deserializing the result back into `target`. On error,
+    /// `target` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::{Serialize, Deserialize};
+    /// use rocket::serde::json::{MergePatch, PatchError};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// # #[serde(crate = "rocket::serde")]
+    /// struct Settings {
+    ///     theme: String,
+    ///     volume: u8,
+    /// }
+    ///
+    /// fn handler(patch: MergePatch<Settings>, mut settings: Settings) -> Result<(), PatchError> {
+    ///     patch.apply(&mut settings)?;
+    ///     println!("theme is now {}", settings.theme);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn apply(&self, target: &mut T) -> Result<(), PatchError>
+        where T: Serialize + DeserializeOwned
+    {
+        let mut value = serde_json::to_value(&*target).map_err(PatchError::Target)?;
+        self.apply_value(&mut value);
+        *target = serde_json::from_value(value).map_err(PatchError::Target)?;
+        Ok(())
+    }
+}
+
+impl<T> From<Value> for MergePatch<T> {
+    fn from(patch: Value) -> Self {
+        MergePatch { patch, _target: PhantomData }
+    }
+}
+
+/// Merges `patch` into `target` as per RFC 7386 §2.
+fn merge(target: &mut Value, patch: &Value) {
+    let patch = match patch {
+        Value::Object(patch) => patch,
+        _ => {
+            *target = patch.clone();
+            return;
+        }
+    };
+
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+
+    if let Value::Object(target) = target {
+        for (name, value) in patch {
+            if value.is_null() {
+                target.remove(name);
+            } else {
+                merge(target.entry(name.as_str()).or_insert(Value::Null), value);
+            }
+        }
+    }
+}
+
+impl JsonPatch {
+    /// Returns the operations in the patch, in the order in which they are
+    /// applied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{JsonPatch, PatchOperation};
+    ///
+    /// fn handler(patch: JsonPatch) {
+    ///     for operation in patch.operations() {
+    ///         if let PatchOperation::Remove { path } = operation {
+    ///             println!("removing {}", path);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.0
+    }
+
+    /// Consumes `self` and returns the operations in the patch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{JsonPatch, PatchOperation};
+    ///
+    /// fn handler(patch: JsonPatch) {
+    ///     let operations: Vec<PatchOperation> = patch.into_inner();
+    /// }
+    /// ```
+    pub fn into_inner(self) -> Vec<PatchOperation> {
+        self.0
+    }
+
+    /// Applies every operation, in order, to the JSON value `target`. If any
+    /// operation fails, `target` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::json::{json, JsonPatch, PatchError};
+    ///
+    /// fn handler(patch: JsonPatch) -> Result<(), PatchError> {
+    ///     let mut document = json!({ "tags": ["a"] });
+    ///     patch.apply_value(&mut document)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn apply_value(&self, target: &mut Value) -> Result<(), PatchError> {
+        let mut value = target.clone();
+        for (i, operation) in self.0.iter().enumerate() {
+            operation.apply(&mut value).map_err(|reason| PatchError::Operation(i, reason))?;
+        }
+
+        *target = value;
+        Ok(())
+    }
+
+    /// Applies every operation, in order, to `target` by converting `target`
+    /// to JSON, applying the operations, and deserializing the result back
+    /// into `target`. If any operation fails, `target` is left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::serde::{Serialize, Deserialize};
+    /// use rocket::serde::json::{JsonPatch, PatchError};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// # #[serde(crate = "rocket::serde")]
+    /// struct Playlist {
+    ///     songs: Vec<String>,
+    /// }
+    ///
+    /// fn handler(patch: JsonPatch, mut playlist: Playlist) -> Result<(), PatchError> {
+    ///     patch.apply(&mut playlist)?;
+    ///     println!("the playlist has {} songs", playlist.songs.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn apply<T>(&self, target: &mut T) -> Result<(), PatchError>
+        where T: Serialize + DeserializeOwned
+    {
+        let mut value = serde_json::to_value(&*target).map_err(PatchError::Target)?;
+        self.apply_value(&mut value)?;
+        *target = serde_json::from_value(value).map_err(PatchError::Target)?;
+        Ok(())
+    }
+}
+
+impl From<Vec<PatchOperation>> for JsonPatch {
+    fn from(operations: Vec<PatchOperation>) -> Self {
+        JsonPatch(operations)
+    }
+}
+
+impl PatchOperation {
+    /// Applies `self` to `doc` as per RFC 6902 §4.
+    fn apply(&self, doc: &mut Value) -> Result<(), &'static str> {
+        match self {
+            PatchOperation::Add { path, value } => add(doc, path, value.clone()),
+            PatchOperation::Remove { path } => remove(doc, path).map(|_| ()),
+            PatchOperation::Replace { path, value } => {
+                let target = doc.pointer_mut(path.as_str()).ok_or("path does not exist")?;
+                *target = value.clone();
+                Ok(())
+            }
+            PatchOperation::Move { from, path } => {
+                if path.0.starts_with(&from.0) && path.0[from.0.len()..].starts_with('/') {
+                    return Err("cannot move a value into one of its children");
+                }
+
+                let value = remove(doc, from)?;
+                add(doc, path, value)
+            }
+            PatchOperation::Copy { from, path } => {
+                let value = doc.pointer(from.as_str()).ok_or("from does not exist")?.clone();
+                add(doc, path, value)
+            }
+            PatchOperation::Test { path, value } => match doc.pointer(path.as_str()) {
+                Some(actual) if actual == value => Ok(()),
+                Some(_) => Err("test failed"),
+                None => Err("path does not exist"),
+            }
+        }
+    }
+}
+
+/// Parses `token` as an index into an array of length `len`, allowing `-`,
+/// the index past the end, if `allow_end` is `true`.
+fn array_index(token: &str, len: usize, allow_end: bool) -> Result<usize, &'static str> {
+    let index = match token {
+        "-" if allow_end => len,
+        "0" => 0,
+        _ if token.starts_with('0') => return Err("array index has leading zeros"),
+        _ => token.parse::<usize>().map_err(|_| "invalid array index")?,
+    };
+
+    if index < len || (allow_end && index == len) {
+        Ok(index)
+    } else {
+        Err("array index is out of bounds")
+    }
+}
+
+/// Adds `value` at `path` in `doc` as per RFC 6902 §4.1.
+fn add(doc: &mut Value, path: &JsonPointer, value: Value) -> Result<(), &'static str> {
+    let (parent, token) = match path.split_last() {
+        Some(split) => split,
+        None => {
+            *doc = value;
+            return Ok(());
+        }
+    };
+
+    match doc.pointer_mut(parent).ok_or("parent of path does not exist")? {
+        Value::Object(map) => {
+            map.insert(token, value);
+        }
+        Value::Array(vec) => {
+            let index = array_index(&token, vec.len(), true)?;
+            vec.insert(index, value);
+        }
+        _ => return Err("parent of path is not an object or array"),
+    }
+
+    Ok(())
+}
+
+/// Removes the value at `path` in `doc` as per RFC 6902 §4.2.
+fn remove(doc: &mut Value, path: &JsonPointer) -> Result<Value, &'static str> {
+    let (parent, token) = path.split_last().ok_or("cannot remove the whole document")?;
+    match doc.pointer_mut(parent).ok_or("path does not exist")? {
+        Value::Object(map) => map.remove(&token).ok_or("path does not exist"),
+        Value::Array(vec) => Ok(vec.remove(array_index(&token, vec.len(), false)?)),
+        _ => Err("path does not exist"),
+    }
+}
+
+impl JsonPointer {
+    /// Returns the pointer as a string, with tokens escaped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use rocket::serde::json::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::try_from("/a~1b").unwrap();
+    /// assert_eq!(pointer.as_str(), "/a~1b");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns an iterator over the unescaped reference tokens in the pointer.
+    /// The iterator is empty if the pointer refers to the whole document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use rocket::serde::json::JsonPointer;
+    ///
+    /// let pointer = JsonPointer::try_from("/m~0n/").unwrap();
+    /// assert_eq!(pointer.tokens().collect::<Vec<_>>(), ["m~n", ""]);
+    ///
+    /// let pointer = JsonPointer::try_from("").unwrap();
+    /// assert_eq!(pointer.tokens().count(), 0);
+    /// ```
+    pub fn tokens(&self) -> impl Iterator<Item = String> + '_ {
+        self.0.split('/').skip(1).map(unescape)
+    }
+
+    /// Splits the pointer into the escaped pointer to its parent and its last
+    /// unescaped token, or `None` if it points to the whole document.
+    fn split_last(&self) -> Option<(&str, String)> {
+        let i = self.0.rfind('/')?;
+        Some((&self.0[..i], unescape(&self.0[(i + 1)..])))
+    }
+}
+
+/// Unescapes the reference token `token`.
+fn unescape(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+impl TryFrom<String> for JsonPointer {
+    type Error = &'static str;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        if !string.is_empty() && !string.starts_with('/') {
+            return Err("a JSON pointer must be empty or start with `/`");
+        }
+
+        let mut chars = string.chars();
+        while let Some(c) = chars.next() {
+            if c == '~' && !matches!(chars.next(), Some('0') | Some('1')) {
+                return Err("`~` in a JSON pointer must be followed by `0` or `1`");
+            }
+        }
+
+        Ok(JsonPointer(string))
+    }
+}
+
+impl TryFrom<&str> for JsonPointer {
+    type Error = &'static str;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        JsonPointer::try_from(string.to_string())
+    }
+}
+
+impl From<JsonPointer> for String {
+    fn from(pointer: JsonPointer) -> Self {
+        pointer.0
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::Operation(i, reason) => write!(f, "operation {} failed: {}", i, reason),
+            PatchError::Target(e) => write!(f, "invalid patched document: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PatchError::Operation(..) => None,
+            PatchError::Target(e) => Some(e),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for PatchError {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        warn_!("Failed to apply patch: {}.", self);
+        Err(Status::UnprocessableEntity)
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T> FromData<'r> for MergePatch<T> {
+    type Error = Error<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        <Json<Value> as FromData<'r>>::from_data(req, data).await
+            .map(|json| MergePatch::from(json.into_inner()))
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromData<'r> for JsonPatch {
+    type Error = Error<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        <Json<Vec<PatchOperation>> as FromData<'r>>::from_data(req, data).await
+            .map(|json| JsonPatch(json.into_inner()))
+    }
+}
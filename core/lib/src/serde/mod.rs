@@ -17,6 +17,14 @@ pub mod json;
 #[cfg_attr(nightly, doc(cfg(feature = "msgpack")))]
 pub mod msgpack;
 
+#[cfg(feature = "cbor")]
+#[cfg_attr(nightly, doc(cfg(feature = "cbor")))]
+pub mod cbor;
+
+#[cfg(feature = "xml")]
+#[cfg_attr(nightly, doc(cfg(feature = "xml")))]
+pub mod xml;
+
 #[cfg(feature = "uuid")]
 #[cfg_attr(nightly, doc(cfg(feature = "uuid")))]
 pub mod uuid;
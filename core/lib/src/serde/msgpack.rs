@@ -121,6 +121,23 @@ pub use rmp_serde::decode::Error;
 ///     MsgPack(user_from_id)
 /// }
 /// ```
+///
+/// As with `Json`, `T` may be a reference such as `&[T]` or `&T` that
+/// borrows from managed state, from request guards, or from `'static` data:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::State;
+/// use rocket::serde::msgpack::MsgPack;
+///
+/// struct Users(Vec<User>);
+///
+/// #[get("/users")]
+/// fn users(users: &State<Users>) -> MsgPack<&[User]> {
+///     MsgPack(&users.0)
+/// }
+/// ```
 #[derive(Debug)]
 pub struct MsgPack<T>(pub T);
 
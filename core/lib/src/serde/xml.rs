@@ -0,0 +1,500 @@
+//! Automatic XML (de)serialization support.
+//!
+//! See [`Xml`](crate::serde::xml::Xml) for further details.
+//!
+//! # Enabling
+//!
+//! This module is only available when the `xml` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rocket]
+//! version = "0.5.0-rc.1"
+//! features = ["xml"]
+//! ```
+//!
+//! # Testing
+//!
+//! The [`LocalRequest`] and [`LocalResponse`] types provide [`xml()`] and
+//! [`into_xml()`] methods to create a request with serialized XML and
+//! deserialize a response as XML, respectively.
+//!
+//! [`LocalRequest`]: crate::local::blocking::LocalRequest
+//! [`LocalResponse`]: crate::local::blocking::LocalResponse
+//! [`xml()`]: crate::local::blocking::LocalRequest::xml()
+//! [`into_xml()`]: crate::local::blocking::LocalResponse::into_xml()
+
+use std::{io, fmt};
+use std::borrow::Cow;
+use std::ops::{Deref, DerefMut};
+
+use crate::request::{Request, local_cache};
+use crate::data::{Limits, Data, FromData, Outcome};
+use crate::response::{self, Responder};
+use crate::http::{Status, ContentType};
+use crate::form::prelude as form;
+
+use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
+
+#[doc(hidden)]
+pub use quick_xml;
+
+/// The XML guard: easily consume and return XML.
+///
+/// ## Receiving XML
+///
+/// `Xml` is both a data guard and a form guard.
+///
+/// ### Data Guard
+///
+/// To parse request body data as XML, add a `data` route argument with a
+/// target type of `Xml<T>`, where `T` is some type you'd like to parse from
+/// XML. `T` must implement [`serde::Deserialize`]. The name of the document's
+/// root element is not checked.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::serde::xml::Xml;
+///
+/// #[post("/user", format = "application/xml", data = "<user>")]
+/// fn new_user(user: Xml<User>) {
+///     /* ... */
+/// }
+/// ```
+///
+/// You don't _need_ to use `format = "application/xml"`, but it _may_ be what
+/// you want. Note that the `xml` format shorthand refers to `text/xml`, not
+/// `application/xml`.
+///
+/// If the body fails to parse as XML or as a `T`, the guard fails with a status
+/// of `400 Bad Request`. If the body exceeds the configured limit, the guard
+/// fails with `413 Payload Too Large`.
+///
+/// ### Form Guard
+///
+/// `Xml<T>`, as a form guard, accepts value and data fields and parses the
+/// data as a `T`. Simple use `Xml<T>`:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type Metadata = usize;
+/// use rocket::form::{Form, FromForm};
+/// use rocket::serde::xml::Xml;
+///
+/// #[derive(FromForm)]
+/// struct User<'r> {
+///     name: &'r str,
+///     metadata: Xml<Metadata>
+/// }
+///
+/// #[post("/user", data = "<form>")]
+/// fn new_user(form: Form<User<'_>>) {
+///     /* ... */
+/// }
+/// ```
+///
+/// ### Incoming Data Limits
+///
+/// The default size limit for incoming XML data is 1MiB. Setting a limit
+/// protects your application from denial of service (DOS) attacks and from
+/// resource exhaustion through high memory consumption. The limit can be
+/// increased by setting the `limits.xml` configuration parameter. For instance,
+/// to increase the XML limit to 5MiB for all environments, you may add the
+/// following to your `Rocket.toml`:
+///
+/// ```toml
+/// [global.limits]
+/// xml = 5242880
+/// ```
+///
+/// ## Sending XML
+///
+/// If you're responding with XML data, return an `Xml<T>` type, where `T`
+/// implements [`Serialize`] from [`serde`]. The content type of the response is
+/// set to `application/xml` automatically.
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// # type User = usize;
+/// use rocket::serde::xml::Xml;
+///
+/// #[get("/users/<id>")]
+/// fn user(id: usize) -> Xml<User> {
+///     let user_from_id = User::from(id);
+///     /* ... */
+///     Xml(user_from_id)
+/// }
+/// ```
+///
+/// ### Root Element
+///
+/// The root element of the document is named after `T`: a struct or enum is
+/// serialized into an element with its name, which can be changed with
+/// `#[serde(rename = "...")]`. Values without a name, such as maps and
+/// primitives, fail to serialize, while sequences serialize into a list of
+/// elements rather than into a single document. To choose the name of the root
+/// element at runtime, or to serialize a map or primitive, use
+/// [`Xml::with_root()`].
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::serde::Serialize;
+/// use rocket::serde::xml::{Xml, Rooted};
+///
+/// #[derive(Serialize)]
+/// #[serde(crate = "rocket::serde", rename = "user")]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[get("/user")]
+/// fn user() -> Xml<User> {
+///     // <user><name>Bob</name></user>
+///     Xml(User { name: "Bob".into() })
+/// }
+///
+/// #[get("/member")]
+/// fn member() -> Rooted<User> {
+///     // <member><name>Bob</name></member>
+///     Xml(User { name: "Bob".into() }).with_root("member")
+/// }
+/// ```
+///
+/// ### Borrowed Data
+///
+/// As with `Json`, `T` may be a reference such as `&T` that borrows from
+/// managed state, from request guards, or from `'static` data:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::State;
+/// use rocket::serde::Serialize;
+/// use rocket::serde::xml::Xml;
+///
+/// #[derive(Serialize)]
+/// #[serde(crate = "rocket::serde", rename = "catalog")]
+/// struct Catalog {
+///     items: Vec<String>,
+/// }
+///
+/// #[get("/catalog")]
+/// fn catalog(catalog: &State<Catalog>) -> Xml<&Catalog> {
+///     Xml(catalog.inner())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Xml<T>(pub T);
+
+/// A responder that serializes a value into XML with a custom root element.
+///
+/// A `Rooted` is created via [`Xml::with_root()`]. Its
+/// [`Responder`](crate::response::Responder) implementation is identical to
+/// that of [`Xml`] except that the root element is named as requested.
+#[derive(Debug)]
+pub struct Rooted<T> {
+    root: Cow<'static, str>,
+    value: T,
+}
+
+/// Error returned by the [`Xml`] guard when XML deserialization fails.
+#[derive(Debug)]
+pub enum Error<'a> {
+    /// An I/O error occurred while reading the incoming request data.
+    Io(io::Error),
+
+    /// The client's data was received successfully but failed to parse as
+    /// valid XML or as the requested type. The `&str` value in `.0` is the raw
+    /// data received from the user, while the `DeError` in `.1` is the
+    /// deserialization error.
+    Parse(&'a str, quick_xml::DeError),
+}
+
+impl fmt::Display for Error<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "i/o error: {}", e),
+            Error::Parse(_, e) => write!(f, "parse error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(_, e) => Some(e),
+        }
+    }
+}
+
+impl<T> Xml<T> {
+    /// Consumes the `Xml` wrapper and returns the wrapped item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::serde::xml::Xml;
+    /// let string = "Hello".to_string();
+    /// let my_xml = Xml(string);
+    /// assert_eq!(my_xml.into_inner(), "Hello".to_string());
+    /// ```
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns a responder that serializes the wrapped item into an XML
+    /// document with a root element named `root`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use rocket::serde::xml::{Xml, Rooted};
+    ///
+    /// let mut names = BTreeMap::new();
+    /// names.insert("first", "Bob");
+    /// names.insert("last", "Smith");
+    /// let response: Rooted<_> = Xml(names).with_root("name");
+    /// ```
+    pub fn with_root<R: Into<Cow<'static, str>>>(self, root: R) -> Rooted<T> {
+        Rooted { root: root.into(), value: self.0 }
+    }
+}
+
+impl<'r, T: Deserialize<'r>> Xml<T> {
+    fn from_str(s: &'r str) -> Result<Self, Error<'r>> {
+        quick_xml::de::from_str(s).map(Xml).map_err(|e| Error::Parse(s, e))
+    }
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Result<Self, Error<'r>> {
+        let limit = req.limits().get("xml").unwrap_or(Limits::XML);
+        let string = match data.open(limit).into_string().await {
+            Ok(s) if s.is_complete() => s.into_inner(),
+            Ok(_) => {
+                let eof = io::ErrorKind::UnexpectedEof;
+                return Err(Error::Io(io::Error::new(eof, "data limit exceeded")));
+            },
+            Err(e) => return Err(Error::Io(e)),
+        };
+
+        Self::from_str(local_cache!(req, string))
+    }
+}
+
+#[crate::async_trait]
+impl<'r, T: Deserialize<'r>> FromData<'r> for Xml<T> {
+    type Error = Error<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r, Self> {
+        match Self::from_data(req, data).await {
+            Ok(value) => Outcome::Success(value),
+            Err(Error::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Outcome::Failure((Status::PayloadTooLarge, Error::Io(e)))
+            },
+            Err(e) => Outcome::Failure((Status::BadRequest, e)),
+        }
+    }
+}
+
+/// Responds with `xml` and a Content-Type of `application/xml`, or with an
+/// `Err` of `Status::InternalServerError` if serialization failed.
+fn respond(
+    req: &Request<'_>,
+    xml: Result<String, quick_xml::DeError>
+) -> response::Result<'static> {
+    let string = xml.map_err(|e| {
+        error_!("XML failed to serialize: {:?}", e);
+        Status::InternalServerError
+    })?;
+
+    (ContentType::new("application", "xml"), string).respond_to(req)
+}
+
+/// Serializes the wrapped value into XML. Returns a response with Content-Type
+/// `application/xml` and a fixed-size body with the serialized value. If
+/// serialization fails, an `Err` of `Status::InternalServerError` is returned.
+impl<'r, T: Serialize> Responder<'r, 'static> for Xml<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        respond(req, to_string(&self.0))
+    }
+}
+
+/// Serializes the value into XML with the requested root element. Returns a
+/// response with Content-Type `application/xml` and a fixed-size body with the
+/// serialized value. If serialization fails, an `Err` of
+/// `Status::InternalServerError` is returned.
+impl<'r, T: Serialize> Responder<'r, 'static> for Rooted<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        respond(req, to_string_with_root(&self.root, &self.value))
+    }
+}
+
+impl<T> From<T> for Xml<T> {
+    fn from(value: T) -> Self {
+        Xml(value)
+    }
+}
+
+impl<T> Deref for Xml<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Xml<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl From<Error<'_>> for form::Error<'_> {
+    fn from(e: Error<'_>) -> Self {
+        match e {
+            Error::Io(e) => e.into(),
+            Error::Parse(_, e) => form::Error::custom(e)
+        }
+    }
+}
+
+#[crate::async_trait]
+impl<'v, T: Deserialize<'v> + Send> form::FromFormField<'v> for Xml<T> {
+    fn from_value(field: form::ValueField<'v>) -> Result<Self, form::Errors<'v>> {
+        Ok(Self::from_str(field.value)?)
+    }
+
+    async fn from_data(f: form::DataField<'v, '_>) -> Result<Self, form::Errors<'v>> {
+        Ok(Self::from_data(f.request, f.data).await?)
+    }
+}
+
+/// Deserialize an instance of type `T` from a string of XML text.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Deserialize, xml};
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data<'r> {
+///     framework: &'r str,
+///     stars: usize,
+/// }
+///
+/// let string = r#"
+///     <data>
+///         <framework>Rocket</framework>
+///         <stars>5</stars>
+///     </data>
+/// "#;
+///
+/// let data: Data = xml::from_str(string).unwrap();
+/// assert_eq!(data, Data { framework: "Rocket", stars: 5, });
+/// ```
+///
+/// # Errors
+///
+/// Deserialization fails if `v` is not well-formed XML or if `T`'s
+/// `Deserialize` implementation fails otherwise.
+#[inline(always)]
+pub fn from_str<'a, T>(v: &'a str) -> Result<T, quick_xml::DeError>
+    where T: Deserialize<'a>,
+{
+    quick_xml::de::from_str(v)
+}
+
+/// Deserialize an instance of type `T` from XML text read from `reader`.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Deserialize, xml};
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data {
+///     framework: String,
+///     stars: usize,
+/// }
+///
+/// let bytes = b"<data><framework>Rocket</framework><stars>5</stars></data>";
+/// let data: Data = xml::from_reader(&bytes[..]).unwrap();
+/// assert_eq!(data, Data { framework: "Rocket".into(), stars: 5, });
+/// ```
+///
+/// # Errors
+///
+/// Deserialization fails if reading fails, if the text is not well-formed XML,
+/// or if `T`'s `Deserialize` implementation fails otherwise.
+#[inline(always)]
+pub fn from_reader<R, T>(reader: R) -> Result<T, quick_xml::DeError>
+    where R: io::BufRead, T: DeserializeOwned,
+{
+    quick_xml::de::from_reader(reader)
+}
+
+/// Serialize a `T` into an XML string with a root element named after `T`.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Serialize, xml};
+///
+/// #[derive(Serialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data<'r> {
+///     framework: &'r str,
+///     stars: usize,
+/// }
+///
+/// let string = xml::to_string(&Data { framework: "Rocket", stars: 5 }).unwrap();
+/// assert_eq!(string, "<Data><framework>Rocket</framework><stars>5</stars></Data>");
+/// ```
+///
+/// # Errors
+///
+/// Serialization fails if `T` has no name, such as a map or primitive, or if
+/// `T`'s `Serialize` implementation fails.
+#[inline(always)]
+pub fn to_string<T>(value: &T) -> Result<String, quick_xml::DeError>
+    where T: Serialize + ?Sized,
+{
+    quick_xml::se::to_string(value)
+}
+
+/// Serialize a `T` into an XML string with a root element named `root`.
+///
+/// # Example
+///
+/// ```
+/// use rocket::serde::{Serialize, xml};
+///
+/// #[derive(Serialize)]
+/// #[serde(crate = "rocket::serde")]
+/// struct Data<'r> {
+///     framework: &'r str,
+///     stars: usize,
+/// }
+///
+/// let data = Data { framework: "Rocket", stars: 5 };
+/// let string = xml::to_string_with_root("project", &data).unwrap();
+/// assert_eq!(string, "<project><framework>Rocket</framework><stars>5</stars></project>");
+/// ```
+///
+/// # Errors
+///
+/// Serialization fails if `root` is not a valid element name or if `T`'s
+/// `Serialize` implementation fails.
+#[inline(always)]
+pub fn to_string_with_root<T>(root: &str, value: &T) -> Result<String, quick_xml::DeError>
+    where T: Serialize + ?Sized,
+{
+    quick_xml::se::to_string_with_root(root, value)
+}
@@ -1,6 +1,9 @@
 use std::io;
+use std::any::Any;
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
+use std::task::Poll;
 
 use yansi::Paint;
 use tokio::sync::oneshot;
@@ -8,29 +11,37 @@ use futures::stream::StreamExt;
 use futures::future::{self, FutureExt, Future, TryFutureExt, BoxFuture};
 
 use crate::{Rocket, Orbit, Request, Response, Data, route};
+use crate::request::{ConnectionMeta, Disconnect, RequestTiming, ParamError};
+use crate::catcher::Panic;
+use crate::response::{Body, Redirect, ErrorMap};
+use crate::response::Upgrade;
+use crate::data::{StreamReader, IoHandler, IoStream};
 use crate::form::Form;
 use crate::outcome::Outcome;
 use crate::error::{Error, ErrorKind};
 use crate::ext::{AsyncReadExt, CancellableListener, CancellableIo};
 
-use crate::http::{Method, Status, Header, hyper};
-use crate::http::private::{Listener, Connection, Incoming};
+use crate::http::{Method, Status, Header, Version, hyper, uncased::Uncased};
+use crate::http::private::{Listener, Incoming};
 use crate::http::uri::Origin;
 use crate::http::private::bind_tcp;
 
 // A token returned to force the execution of one method before another.
 pub(crate) struct RequestToken;
 
-async fn handle<Fut, T, F>(name: Option<&str>, run: F) -> Option<T>
+// Runs `run` and awaits its future, catching a panic in either. Returns the
+// panic's payload if there was one.
+async fn handle<Fut, T, F>(name: Option<&str>, run: F) -> Result<T, Box<dyn Any + Send>>
     where F: FnOnce() -> Fut, Fut: Future<Output = T>,
 {
     use std::panic::AssertUnwindSafe;
 
     macro_rules! panic_info {
         ($name:expr, $e:expr) => {{
+            let message = crate::catcher::panic::message(&*$e).unwrap_or("<non-string payload>");
             match $name {
-                Some(name) => error_!("Handler {} panicked.", Paint::white(name)),
-                None => error_!("A handler panicked.")
+                Some(name) => error_!("Handler {} panicked: {}", Paint::white(name), message),
+                None => error_!("A handler panicked: {}", message)
             };
 
             info_!("This is an application bug.");
@@ -47,14 +58,12 @@ async fn handle<Fut, T, F>(name: Option<&str>, run: F) -> Option<T>
 
     let run = AssertUnwindSafe(run);
     let fut = std::panic::catch_unwind(move || run())
-        .map_err(|e| panic_info!(name, e))
-        .ok()?;
+        .map_err(|e| panic_info!(name, e))?;
 
     AssertUnwindSafe(fut)
         .catch_unwind()
         .await
         .map_err(|e| panic_info!(name, e))
-        .ok()
 }
 
 // This function tries to hide all of the Hyper-ness from Rocket. It essentially
@@ -63,18 +72,24 @@ async fn handle<Fut, T, F>(name: Option<&str>, run: F) -> Option<T>
 // `HyperResponse` type, this function does the actual response processing.
 async fn hyper_service_fn(
     rocket: Arc<Rocket<Orbit>>,
-    addr: std::net::SocketAddr,
-    hyp_req: hyper::Request<hyper::Body>,
+    conn: ConnectionMeta,
+    mut hyp_req: hyper::Request<hyper::Body>,
 ) -> Result<hyper::Response<hyper::Body>, io::Error> {
     // This future must return a hyper::Response, but the response body might
     // borrow from the request. Instead, write the body in another future that
     // sends the response metadata (and a body channel) prior.
-    let (tx, rx) = oneshot::channel();
+    let (mut tx, rx) = oneshot::channel();
+    let active = rocket.shutdown.1.request();
 
     tokio::spawn(async move {
+        let _active = active;
+
+        // Keep a handle to the connection in case it is upgraded.
+        let on_upgrade = hyper::upgrade::on(&mut hyp_req);
+
         // Convert a Hyper request into a Rocket request.
         let (h_parts, mut h_body) = hyp_req.into_parts();
-        let mut req = match Request::from_hyp(&rocket, &h_parts, addr) {
+        let mut req = match Request::from_hyp(&rocket, &h_parts, conn) {
             Ok(req) => req,
             Err(e) => {
                 error!("Bad incoming request: {}", e);
@@ -83,18 +98,46 @@ async fn hyper_service_fn(
                 // that we failed to parse a request (by invoking some special
                 // handler) instead of doing this.
                 let dummy = Request::new(&rocket, Method::Get, Origin::ROOT);
-                let r = rocket.handle_error(Status::BadRequest, &dummy).await;
-                return rocket.send_response(r, tx).await;
+                let mut r = rocket.handle_error(e.status(), &dummy).await;
+                if e.closes_connection() {
+                    r.set_raw_header("Connection", "close");
+                }
+
+                return rocket.send_response(r, tx, &dummy.state.disconnect).await;
             }
         };
 
-        // Retrieve the data from the hyper body.
-        let mut data = Data::from(&mut h_body);
+        // Retrieve the data from the hyper body, failing stalled reads.
+        let mut stream = StreamReader::from(&mut h_body);
+        if rocket.config.body_read_timeout > 0 {
+            let period = Duration::from_secs(rocket.config.body_read_timeout.into());
+            stream = stream.with_timeout(period);
+        }
+
+        let mut data = Data::from(stream);
 
         // Dispatch the request to get a response, then write that response out.
+        // `hyper` drops the receiving end of `tx` if the client goes away.
         let token = rocket.preprocess_request(&mut req, &mut data).await;
-        let r = rocket.dispatch(token, &mut req, data).await;
-        rocket.send_response(r, tx).await;
+        let mut r = {
+            let dispatch = rocket.dispatch_before_deadline(token, &req, data);
+            tokio::pin!(dispatch);
+            match future::select(dispatch, Box::pin(tx.closed())).await {
+                future::Either::Left((response, _)) => response,
+                future::Either::Right((_, dispatch)) => {
+                    warn_!("Client disconnected while the request was being handled.");
+                    req.state.disconnect.finish(true);
+                    dispatch.await
+                }
+            }
+        };
+
+        let upgrade = rocket.prepare_upgrade(&req, &mut r);
+        rocket.prepare_trailers(&req, &mut r);
+        rocket.send_response(r, tx, &req.state.disconnect).await;
+        if let Some((protocol, handler)) = upgrade {
+            rocket.handle_upgrade(protocol, handler, on_upgrade).await;
+        }
     });
 
     // Receive the response written to `tx` by the task above.
@@ -102,16 +145,52 @@ async fn hyper_service_fn(
 }
 
 impl Rocket<Orbit> {
-    /// Wrapper around `make_response` to log a success or failure.
+    /// Wrapper around `dispatch` that responds with a `503` instead if the
+    /// shutdown deadline elapses before `request` has been handled.
+    async fn dispatch_before_deadline<'s, 'r: 's>(
+        &'s self,
+        token: RequestToken,
+        request: &'r Request<'s>,
+        data: Data<'r>
+    ) -> Response<'r> {
+        let deadline = self.shutdown.1.deadline.clone();
+        let dispatch = self.dispatch(token, request, data);
+        tokio::pin!(dispatch);
+
+        match future::select(dispatch, deadline).await {
+            future::Either::Left((response, _)) => response,
+            future::Either::Right(..) => {
+                warn_!("Shutdown deadline elapsed. Request was cancelled.");
+                let mut response = self.handle_error(Status::ServiceUnavailable, request).await;
+                response.set_raw_header("Connection", "close");
+                response
+            }
+        }
+    }
+
+    /// Wrapper around `make_response` to log a success or failure. Finishes
+    /// `disconnect`, recording if the client disconnected before the response
+    /// completed.
     #[inline]
     async fn send_response(
         &self,
         response: Response<'_>,
         tx: oneshot::Sender<hyper::Response<hyper::Body>>,
+        disconnect: &Disconnect,
     ) {
         match self.make_response(response, tx).await {
-            Ok(()) => info_!("{}", Paint::green("Response succeeded.")),
-            Err(e) => error_!("Failed to write response: {}.", e),
+            Ok(()) => {
+                disconnect.finish(false);
+                info_!("{}", Paint::green("Response succeeded."));
+            }
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => {
+                disconnect.finish(true);
+                warn_!("Client disconnected: {}.", e);
+            }
+            Err(e) => {
+                disconnect.finish(false);
+                error_!("Failed to write response: {}.", e);
+            }
         }
     }
 
@@ -150,17 +229,114 @@ impl Rocket<Orbit> {
         let (mut sender, hyp_body) = hyper::Body::channel();
         send_response(hyp_res, hyp_body)?;
 
+        // Polling the sender registers interest in the body being dropped by
+        // `hyper`, which happens when the connection or stream is closed. Stop
+        // polling the body stream, and drop it, as soon as that happens, even
+        // if the stream is waiting on its next item.
+        let disconnected = || {
+            let msg = "client disconnected before the response completed";
+            io::Error::new(io::ErrorKind::BrokenPipe, msg)
+        };
+
         let mut stream = body.into_bytes_stream(max_chunk_size);
-        while let Some(next) = stream.next().await {
-            sender.send_data(next?).await
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        loop {
+            let next = future::poll_fn(|cx| match sender.poll_ready(cx) {
+                Poll::Ready(Err(_)) => Poll::Ready(Err(disconnected())),
+                _ => stream.poll_next_unpin(cx).map(Ok),
+            });
+
+            match next.await? {
+                Some(bytes) => sender.send_data(bytes?).await.map_err(|_| disconnected())?,
+                None => break,
+            }
+        }
+
+        drop(stream);
+        if let Some(trailers) = response.take_trailers() {
+            let mut hyp_trailers = hyper::HeaderMap::new();
+            for header in trailers.iter() {
+                let name = hyper::HeaderName::from_bytes(header.name.as_str().as_bytes());
+                let value = hyper::HeaderValue::from_bytes(header.value.as_bytes());
+                match (name, value) {
+                    (Ok(name), Ok(value)) => { hyp_trailers.append(name, value); },
+                    _ => warn_!("Dropping invalid trailer: {}.", header),
+                }
+            }
+
+            // The body is complete, so this only fails if the connection was
+            // closed before the trailers could be written.
+            if let Err(e) = sender.send_trailers(hyp_trailers).await {
+                warn_!("Failed to send trailers: {}.", e);
+            }
         }
 
         Ok(())
     }
 
+    /// Drops the trailers of `response`, and the `Trailer` header declaring
+    /// them, unless `req` was received over `HTTP/2`: `hyper` only writes
+    /// trailers in `HTTP/2` frames, not in `HTTP/1.1` chunked bodies.
+    fn prepare_trailers(&self, req: &Request<'_>, response: &mut Response<'_>) {
+        if !req.version().is_multiplexed() && response.discard_trailers() {
+            warn_!("Trailers are only sent over HTTP/2. Dropping trailers.");
+        }
+    }
+
+    /// If `req` asks to upgrade the connection to a protocol `response` has an
+    /// upgrade handler for, turns `response` into a `101 Switching Protocols`
+    /// response and returns the protocol and handler. Only `HTTP/1.1`
+    /// connections can be upgraded: `HTTP/1.0` lacks upgrades, and `HTTP/2`
+    /// forbids `101` responses.
+    fn prepare_upgrade<'r>(
+        &self,
+        req: &Request<'_>,
+        response: &mut Response<'r>,
+    ) -> Option<Upgrade<'r>> {
+        let requested = req.headers().get("Connection")
+            .flat_map(|value| value.split(','))
+            .any(|token| token.trim().eq_ignore_ascii_case("upgrade"));
+
+        if !requested {
+            return None;
+        }
+
+        if req.version() != Version::Http11 {
+            warn_!("Connection upgrades require HTTP/1.1. Not upgrading.");
+            return None;
+        }
+
+        let protocols = req.headers().get("Upgrade")
+            .flat_map(|value| value.split(','))
+            .map(|protocol| protocol.trim());
+
+        let (protocol, handler) = response.take_upgrade(protocols)?;
+        response.set_status(Status::SwitchingProtocols);
+        response.set_raw_header("Connection", "upgrade");
+        response.set_raw_header("Upgrade", protocol.as_str().to_string());
+        *response.body_mut() = Body::default();
+        Some((protocol, handler))
+    }
+
+    /// Awaits the upgrade of the connection, then hands it to `handler`.
+    async fn handle_upgrade<'r>(
+        &self,
+        protocol: Uncased<'r>,
+        handler: Pin<Box<dyn IoHandler + 'r>>,
+        on_upgrade: hyper::OnUpgrade,
+    ) {
+        info_!("Upgrading connection to {}.", Paint::white(&protocol));
+        match on_upgrade.await {
+            Ok(io) => match handler.io(IoStream::upgraded(io)).await {
+                Ok(()) => info_!("{} connection closed.", protocol),
+                Err(e) => error_!("{} connection handler failed: {}.", protocol, e),
+            },
+            Err(e) => error_!("Failed to upgrade connection to {}: {}.", protocol, e),
+        }
+    }
+
     /// Preprocess the request for Rocket things. Currently, this means:
     ///
+    ///   * Starting the request's `RequestTiming` clock.
     ///   * Rewriting the method in the request if _method form field exists.
     ///   * Run the request fairings.
     ///
@@ -170,6 +346,8 @@ impl Rocket<Orbit> {
         req: &mut Request<'_>,
         data: &mut Data<'_>
     ) -> RequestToken {
+        RequestTiming::start(req);
+
         // Check if this is a form and if the form contains the special _method
         // field which we use to reinterpret the request's method.
         let (min_len, max_len) = ("_method=get".len(), "_method=delete".len());
@@ -216,9 +394,17 @@ impl Rocket<Orbit> {
             }
         }
 
+        // Add the configured default headers the response doesn't set.
+        request.rocket().config.default_headers.apply_to(&mut response);
+
         // Run the response fairings.
         self.fairings.handle_response(request, &mut response).await;
 
+        // Set the cookies changed by response fairings, each as its own header.
+        for header in request.cookies().take_delta() {
+            response.adjoin_header(header);
+        }
+
         // Strip the body if this is a `HEAD` request.
         if was_head_request {
             response.strip_body();
@@ -232,7 +418,19 @@ impl Rocket<Orbit> {
         request: &'r Request<'s>,
         data: Data<'r>
     ) -> Response<'r> {
-        let mut response = match self.route(request, data).await {
+        // Skip routing entirely if a request fairing halted the request.
+        let outcome = match request.state.halted.as_ref() {
+            Some(halt) => {
+                info_!("Halted by request fairing: {}.", halt.status);
+                match halt.take_response() {
+                    Some(response) => Outcome::Success(response),
+                    None => Outcome::Failure(halt.status),
+                }
+            }
+            None => self.route(request, data).await,
+        };
+
+        let mut response = match outcome {
             Outcome::Success(response) => response,
             Outcome::Forward(data) if request.method() == Method::Head => {
                 info_!("Autohandling {} request.", Paint::default("HEAD").bold());
@@ -242,18 +440,17 @@ impl Rocket<Orbit> {
                 match self.route(request, data).await {
                     Outcome::Success(response) => response,
                     Outcome::Failure(status) => self.handle_error(status, request).await,
-                    Outcome::Forward(_) => self.handle_error(Status::NotFound, request).await,
+                    Outcome::Forward(_) => self.handle_unmatched(request).await,
                 }
             }
-            Outcome::Forward(_) => self.handle_error(Status::NotFound, request).await,
+            Outcome::Forward(_) => self.handle_unmatched(request).await,
             Outcome::Failure(status) => self.handle_error(status, request).await,
         };
 
         // Set the cookies. Note that error responses will only include cookies
         // set by the error handler. See `handle_error` for more.
-        let delta_jar = request.cookies().take_delta_jar();
-        for cookie in delta_jar.delta() {
-            response.adjoin_header(cookie);
+        for header in request.cookies().take_delta() {
+            response.adjoin_header(header);
         }
 
         response
@@ -271,14 +468,45 @@ impl Rocket<Orbit> {
         mut data: Data<'r>,
     ) -> route::Outcome<'r> {
         // Go through the list of matching routes until we fail or succeed.
+        let mut matched = false;
         for route in self.router.route(request) {
+            matched = true;
+
             // Retrieve and set the requests parameters.
             info_!("Matched: {}", route);
             request.set_route(route);
 
+            // Acquire a permit to execute, held until the handler completes.
+            let permit = match route.concurrency {
+                Some(ref limit) => match limit.try_acquire() {
+                    Some(permit) => Some(permit),
+                    None => {
+                        warn_!("Route concurrency limit of {} reached.", limit.limit());
+                        return Outcome::Failure(Status::ServiceUnavailable);
+                    }
+                },
+                None => None,
+            };
+
             let name = route.name.as_deref();
-            let outcome = handle(name, || route.handler.handle(request, data)).await
-                .unwrap_or_else(|| Outcome::Failure(Status::InternalServerError));
+            let mut outcome = match handle(name, || route.handler.handle(request, data)).await {
+                Ok(outcome) => outcome,
+                Err(payload) => {
+                    info_!("Panicked while handling {} {} in {}.", request.method(),
+                        request.uri(), name.unwrap_or("an unnamed route"));
+
+                    let panic = Panic::new(&*payload, self.config.panic_messages);
+                    request.state.cache.set(panic);
+                    Outcome::Failure(Status::InternalServerError)
+                }
+            };
+
+            drop(permit);
+
+            // Store a successful response in the route's cache, if it has one.
+            if let (Some(cache), Outcome::Success(response)) = (&route.cache, &mut outcome) {
+                cache.store(request, response).await;
+            }
 
             // Check if the request processing completed (Some) or if the
             // request needs to be forwarded. If it does, continue the loop
@@ -290,10 +518,71 @@ impl Rocket<Orbit> {
             }
         }
 
+        let mut expected = self.router.format_mismatches(request)
+            .filter_map(|route| route.format.clone())
+            .collect::<Vec<_>>();
+
+        if !expected.is_empty() {
+            expected.sort_by_cached_key(|format| format.to_string());
+            expected.dedup_by(|a, b| a.to_string() == b.to_string());
+            let (status, header) = match request.method().supports_payload() {
+                true => (Status::UnsupportedMediaType, "Content-Type"),
+                false => (Status::NotAcceptable, "Accept"),
+            };
+
+            let formats = expected.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+            error_!("No matching routes for {}.", request);
+            info_!("Request {} does not match the routes' expected format: {}.",
+                header, formats.join(", "));
+
+            request.set_expected_formats(expected);
+            return Outcome::Failure(status);
+        }
+
+        if !matched && self.config.case_insensitive_fallback {
+            if let Some(uri) = self.router.canonical_uri(request) {
+                info_!("Redirecting to case-insensitive match: {}.", uri);
+                return route::Outcome::from(request, Redirect::permanent(uri));
+            }
+        }
+
+        // If a parameter failed to parse and the application mapped parameter
+        // errors to a status, fail with that status instead of forwarding.
+        if let Some(error) = ParamError::of(request) {
+            let map = self.state::<ErrorMap>();
+            if let Some(status) = map.and_then(|map| map.status_of(error)) {
+                error_!("No matching routes for {}.", request);
+                info_!("Failed to parse {}.", error);
+                return Outcome::Failure(status);
+            }
+        }
+
         error_!("No matching routes for {}.", request);
         Outcome::Forward(data)
     }
 
+    /// Responds to a request that every route forwarded or that no route
+    /// matched. If [`Config::method_not_allowed`](crate::Config) is enabled and
+    /// routes with other methods match the request's path, responds with a
+    /// `405` and an `Allow` header listing those methods. Otherwise, responds
+    /// with a `404`.
+    async fn handle_unmatched<'s, 'r: 's>(&'s self, req: &'r Request<'s>) -> Response<'r> {
+        if !self.config.method_not_allowed || self.router.route(req).next().is_some() {
+            return self.handle_error(Status::NotFound, req).await;
+        }
+
+        let allowed = self.router.allowed_methods(req);
+        if allowed.is_empty() || allowed.contains(&req.method()) {
+            return self.handle_error(Status::NotFound, req).await;
+        }
+
+        let allowed = allowed.iter().map(|m| m.as_str()).collect::<Vec<_>>().join(", ");
+        info_!("Path is routed for other methods: {}.", allowed);
+        let mut response = self.handle_error(Status::MethodNotAllowed, req).await;
+        response.set_raw_header("Allow", allowed);
+        response
+    }
+
     /// Invokes the handler with `req` for catcher with status `status`.
     ///
     /// In order of preference, invoked handler is:
@@ -304,10 +593,10 @@ impl Rocket<Orbit> {
     /// Return `Ok(result)` if the handler succeeded. Returns `Ok(Some(Status))`
     /// if the handler ran to completion but failed. Returns `Ok(None)` if the
     /// handler panicked while executing.
-    async fn invoke_catcher<'s, 'r: 's>(
-        &'s self,
+    async fn invoke_catcher<'r>(
+        &self,
         status: Status,
-        req: &'r Request<'s>
+        req: &'r Request<'_>
     ) -> Result<Response<'r>, Option<Status>> {
         // For now, we reset the delta state to prevent any modifications
         // from earlier, unsuccessful paths from being reflected in error
@@ -319,7 +608,7 @@ impl Rocket<Orbit> {
             let name = catcher.name.as_deref();
             handle(name, || catcher.handler.handle(status, req)).await
                 .map(|result| result.map_err(Some))
-                .unwrap_or_else(|| Err(None))
+                .unwrap_or(Err(None))
         } else {
             let code = Paint::blue(status.code).bold();
             warn_!("No {} catcher registered. Using Rocket default.", code);
@@ -331,13 +620,15 @@ impl Rocket<Orbit> {
     //
     // On catcher failure, the 500 error catcher is attempted. If _that_ fails,
     // the (infallible) default 500 error cather is used.
-    pub(crate) async fn handle_error<'s, 'r: 's>(
-        &'s self,
+    pub(crate) async fn handle_error<'r>(
+        &self,
         mut status: Status,
-        req: &'r Request<'s>
+        req: &'r Request<'_>
     ) -> Response<'r> {
         // Dispatch to the `status` catcher.
-        if let Ok(r) = self.invoke_catcher(status, req).await {
+        if let Ok(mut r) = self.invoke_catcher(status, req).await {
+            Self::add_challenge(req, &mut r);
+            Self::add_accept(req, &mut r);
             return r;
         }
 
@@ -355,6 +646,42 @@ impl Rocket<Orbit> {
         crate::catcher::default_handler(Status::InternalServerError, req)
     }
 
+    /// Responds to `req` with the catcher for `status`, as if its handler had
+    /// failed with `status`. Used by response fairings, which run after the
+    /// catchers, to fail requests whose response is already known.
+    #[doc(hidden)]
+    pub async fn _handle_error<'r>(&self, status: Status, req: &'r Request<'_>) -> Response<'r> {
+        self.handle_error(status, req).await
+    }
+
+    // Adds the challenge recorded for `req`, if any, to the error response
+    // `res` when it is a 401 or the challenge carries an error code, unless
+    // the catcher set its own `WWW-Authenticate` header.
+    fn add_challenge(req: &Request<'_>, res: &mut Response<'_>) {
+        if let Some(challenge) = req.challenge() {
+            let applies = res.status() == Status::Unauthorized
+                || challenge.get("error").is_some();
+
+            if applies && !res.headers().contains("WWW-Authenticate") {
+                res.set_header(challenge.clone());
+            }
+        }
+    }
+
+    // Lists the formats the request's `strict_format` routes expected in the
+    // `Accept` header of a `415` response `res`, as RFC 9110 §15.5.16 suggests,
+    // unless the catcher set its own `Accept` header.
+    fn add_accept(req: &Request<'_>, res: &mut Response<'_>) {
+        let formats = req.expected_formats();
+        if res.status() == Status::UnsupportedMediaType
+            && !formats.is_empty()
+            && !res.headers().contains("Accept")
+        {
+            let formats = formats.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+            res.set_raw_header("Accept", formats.join(", "));
+        }
+    }
+
     pub(crate) async fn default_tcp_http_server<C>(mut self, ready: C) -> Result<(), Error>
         where C: for<'a> Fn(&'a Self) -> BoxFuture<'a, ()>
     {
@@ -371,7 +698,13 @@ impl Rocket<Orbit> {
             use crate::http::private::tls::bind_tls;
 
             let (certs, key) = config.to_readers().map_err(ErrorKind::Io)?;
-            let l = bind_tls(addr, certs, key).await.map_err(ErrorKind::Bind)?;
+            let tickets = if config.session_tickets() {
+                Some(config.ticket_lifetime())
+            } else {
+                None
+            };
+
+            let l = bind_tls(addr, certs, key, tickets).await.map_err(ErrorKind::Bind)?;
             addr = l.local_addr().unwrap_or(addr);
             self.config.address = addr.ip();
             self.config.port = addr.port();
@@ -387,6 +720,49 @@ impl Rocket<Orbit> {
         self.http_server(l).await
     }
 
+    /// Serves the raw HTTP/1 bytes `request` over an in-memory connection as
+    /// if they had been received over the network and returns the raw bytes
+    /// written in response. Like a client waiting for its responses, the
+    /// connection only reports EOF once all of `request` has been read and no
+    /// response is in flight, so the server closes it as it would any idle
+    /// connection.
+    #[cfg(feature = "raw_requests")]
+    pub(crate) async fn serve_raw(self: Arc<Self>, request: &[u8]) -> Vec<u8> {
+        use crate::local::raw_io::RawIo;
+
+        let (io, connection) = RawIo::new(request);
+        let in_flight = connection.in_flight();
+        let remote = ([0, 0, 0, 0], 0).into();
+        let meta = ConnectionMeta { remote, local: None, tls: None, requests: Default::default() };
+        let http1_keepalive = self.config.keep_alive != 0;
+        let rocket = self;
+        let service = hyper::service_fn(move |req| {
+            // Keep the request in flight until its response body is written.
+            let guard = in_flight.start();
+            hyper_service_fn(rocket.clone(), meta.clone(), req).map_ok(move |res| {
+                res.map(|body| hyper::Body::wrap_stream(body.map(move |chunk| {
+                    let _ = &guard;
+                    chunk
+                })))
+            })
+        });
+
+        // Configured as in `http_server()`, save for HTTP/2.
+        let result = hyper::Http::new()
+            .http1_only(true)
+            .http1_keep_alive(http1_keepalive)
+            .http1_preserve_header_case(true)
+            .serve_connection(io, service)
+            .with_upgrades()
+            .await;
+
+        if let Err(e) = result {
+            info_!("Raw connection closed with an error: {}", e);
+        }
+
+        connection.output().await
+    }
+
     // TODO.async: Solidify the Listener APIs and make this function public
     pub(crate) async fn http_server<L>(self, listener: L) -> Result<(), Error>
         where L: Listener + Send, <L as Listener>::Connection: Send + Unpin + 'static
@@ -407,14 +783,17 @@ impl Rocket<Orbit> {
         let force_shutdown = self.config.shutdown.force;
         let grace = self.config.shutdown.grace as u64;
         let mercy = self.config.shutdown.mercy as u64;
+        let deadline = self.config.shutdown.deadline;
 
         let rocket = Arc::new(self);
         let service_fn = move |conn: &CancellableIo<_, L::Connection>| {
             let rocket = rocket.clone();
-            let remote = conn.remote_addr().unwrap_or_else(|| ([0, 0, 0, 0], 0).into());
+            let connection = ConnectionMeta::from(conn);
+            let active = rocket.shutdown.1.connection();
             async move {
                 Ok::<_, std::convert::Infallible>(hyper::service_fn(move |req| {
-                    hyper_service_fn(rocket.clone(), remote, req)
+                    let _active = &active;
+                    hyper_service_fn(rocket.clone(), connection.clone(), req)
                 }))
             }
         };
@@ -447,8 +826,17 @@ impl Rocket<Orbit> {
 
         // Wait for a shutdown notification or for the server to somehow fail.
         tokio::pin!(server);
-        match future::select(shutdown, server).await {
+        match future::select(shutdown.clone(), server).await {
             future::Either::Left((_, server)) => {
+                // Cancel requests that outlive the deadline, if there is one.
+                if let Some(deadline) = deadline {
+                    let activity = shutdown.1.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(Duration::from_secs(deadline.into())).await;
+                        activity.deadline.trip();
+                    });
+                }
+
                 // If a task has some runaway I/O, like an infinite loop, the
                 // runtime will block indefinitely when it is dropped. To
                 // subvert, we start a ticking process-exit time bomb here.
@@ -480,6 +868,9 @@ impl Rocket<Orbit> {
                 }
 
                 info!("Received shutdown request. Waiting for pending I/O...");
+                info_!("Draining {} request(s) on {} connection(s).",
+                    shutdown.in_flight(), shutdown.connections());
+
                 server.await
             }
             future::Either::Right((result, _)) => result,
@@ -20,6 +20,7 @@
 //! | [Referrer-Policy]           | Enables referrer policy.               | [`Referrer`]   | ✗        |
 //! | [X-DNS-Prefetch-Control]    | Controls browser DNS prefetching.      | [`Prefetch`]   | ✗        |
 //! | [Permissions-Policy]        | Allows or block browser features.      | [`Permission`] | ✔        |
+//! | [Content-Security-Policy]   | Restricts where resources load from.   | [`Csp`]        | ✗        |
 //!
 //! <small>? If TLS is enabled in a non-debug profile, HSTS is automatically
 //! enabled with its default policy and a warning is logged at liftoff.</small>
@@ -33,6 +34,7 @@
 //! [X-DNS-Prefetch-Control]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/X-DNS-Prefetch-Control
 //! [clickjacking]: https://en.wikipedia.org/wiki/Clickjacking
 //! [Permissions-Policy]: https://github.com/w3c/webappsec-permissions-policy/blob/a45df7b237e2a85e1909d7f226ca4eb4ce5095ba/permissions-policy-explainer.md
+//! [Content-Security-Policy]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy
 //!
 //! [`XssFilter`]: self::XssFilter
 //! [`NoSniff`]: self::NoSniff
@@ -41,6 +43,7 @@
 //! [`ExpectCt`]: self::ExpectCt
 //! [`Referrer`]: self::Referrer
 //! [`Prefetch`]: self::Prefetch
+//! [`Csp`]: crate::http::Csp
 //!
 //! # Usage
 //!
@@ -99,6 +102,8 @@
 
 mod shield;
 mod policy;
+mod nonce;
 
 pub use self::shield::Shield;
 pub use self::policy::*;
+pub use self::nonce::CspNonce;
@@ -0,0 +1,96 @@
+use std::convert::Infallible;
+use std::fmt;
+
+use rand::{Rng, distributions::Alphanumeric};
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// The length, in characters, of a nonce: just under 131 bits of entropy.
+const NONCE_LEN: usize = 22;
+
+/// Request guard for the request's Content-Security-Policy nonce.
+///
+/// Each request has a unique, randomly generated nonce, created the first time
+/// it is requested and cached in request-local state thereafter. Wherever a
+/// [`Csp`] policy containing [`CspSource::RequestNonce`] is rendered for the
+/// request, by [`Shield`](crate::shield::Shield) or by the `(Csp, R)`
+/// responder, the nonce is substituted for it. Pages allow an inline script or
+/// style by repeating the nonce in its `nonce` attribute, typically by passing
+/// the nonce to a template:
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::http::{Csp, CspSource};
+/// use rocket::response::content::Html;
+/// use rocket::shield::{Shield, CspNonce};
+///
+/// #[get("/")]
+/// fn index(nonce: &CspNonce) -> Html<String> {
+///     Html(format!(r#"<script nonce="{}">alert("hi!")</script>"#, nonce))
+/// }
+///
+/// #[launch]
+/// fn rocket() -> _ {
+///     let csp = Csp::new()
+///         .default_src(CspSource::This)
+///         .script_src([CspSource::RequestNonce, CspSource::StrictDynamic]);
+///
+///     rocket::build()
+///         .mount("/", routes![index])
+///         .attach(Shield::default().enable(csp))
+/// }
+/// ```
+///
+/// # Outcomes
+///
+///   * **Success** always.
+///
+/// [`Csp`]: crate::http::Csp
+/// [`CspSource::RequestNonce`]: crate::http::CspSource::RequestNonce
+#[derive(Debug, PartialEq, Eq)]
+pub struct CspNonce(String);
+
+impl CspNonce {
+    /// Returns the nonce of `req`, generating it if need be.
+    pub(crate) fn of<'r>(req: &'r Request<'_>) -> &'r CspNonce {
+        req.local_cache(|| {
+            let nonce = rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(NONCE_LEN)
+                .map(char::from)
+                .collect();
+
+            CspNonce(nonce)
+        })
+    }
+
+    /// Returns the nonce as a string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::shield::CspNonce;
+    ///
+    /// fn handler(nonce: &CspNonce) {
+    ///     let nonce: &str = nonce.as_str();
+    /// }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CspNonce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r CspNonce {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        Outcome::Success(CspNonce::of(req))
+    }
+}
@@ -7,7 +7,9 @@ use indexmap::IndexMap;
 use rocket_http::{ext::IntoCollection, private::SmallVec};
 use time::Duration;
 
-use crate::http::{Header, uri::Absolute, uncased::{UncasedStr, Uncased}};
+use crate::Request;
+use crate::http::{Header, Csp, uri::Absolute, uncased::{UncasedStr, Uncased}};
+use crate::shield::CspNonce;
 
 /// Trait implemented by security and privacy policy headers.
 ///
@@ -62,12 +64,47 @@ pub trait Policy: Default + Send + Sync + 'static {
     /// }
     /// ```
     fn header(&self) -> Header<'static>;
+
+    /// Returns the header to attach to the response to `request` if it
+    /// differs from the header returned by [`Policy::header()`], or `None`
+    /// otherwise. The default implementation returns `None`.
+    ///
+    /// Policies whose header depends on the request, such as a [`Csp`] policy
+    /// with a per-request nonce, return `Some`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate rocket;
+    /// use rocket::Request;
+    /// use rocket::http::Header;
+    /// use rocket::shield::Policy;
+    ///
+    /// #[derive(Default)]
+    /// struct RequestPath;
+    ///
+    /// impl Policy for RequestPath {
+    ///     const NAME: &'static str = "X-Request-Path";
+    ///
+    ///     fn header(&self) -> Header<'static> {
+    ///         Header::new(Self::NAME, "")
+    ///     }
+    ///
+    ///     fn header_for(&self, request: &Request<'_>) -> Option<Header<'static>> {
+    ///         Some(Header::new(Self::NAME, request.uri().path().to_string()))
+    ///     }
+    /// }
+    /// ```
+    fn header_for(&self, _request: &Request<'_>) -> Option<Header<'static>> {
+        None
+    }
 }
 
 /// Hack to make `Policy` Object-Safe.
 pub(crate) trait SubPolicy: Send + Sync {
     fn name(&self) -> &'static UncasedStr;
     fn header(&self) -> Header<'static>;
+    fn header_for(&self, request: &Request<'_>) -> Option<Header<'static>>;
 }
 
 impl<P: Policy> SubPolicy for P {
@@ -78,6 +115,10 @@ impl<P: Policy> SubPolicy for P {
     fn header(&self) -> Header<'static> {
         Policy::header(self)
     }
+
+    fn header_for(&self, request: &Request<'_>) -> Option<Header<'static>> {
+        Policy::header_for(self, request)
+    }
 }
 
 macro_rules! impl_policy {
@@ -102,6 +143,29 @@ impl_policy!(Referrer, "Referrer-Policy");
 impl_policy!(Prefetch, "X-DNS-Prefetch-Control");
 impl_policy!(Permission, "Permissions-Policy");
 
+/// The [Content-Security-Policy] header: restricts where resources may be
+/// loaded from. See [`Csp`] for details.
+///
+/// A policy containing [`CspSource::RequestNonce`] is rendered for each
+/// request with the request's [`CspNonce`].
+///
+/// [Content-Security-Policy]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Security-Policy
+/// [`CspSource::RequestNonce`]: crate::http::CspSource::RequestNonce
+impl Policy for Csp {
+    const NAME: &'static str = "Content-Security-Policy";
+
+    fn header(&self) -> Header<'static> {
+        self.into()
+    }
+
+    fn header_for(&self, request: &Request<'_>) -> Option<Header<'static>> {
+        match self.uses_request_nonce() {
+            true => Some(self.header_with_nonce(CspNonce::of(request).as_str())),
+            false => None,
+        }
+    }
+}
+
 /// The [Referrer-Policy] header: controls the value set by the browser for the
 /// [Referer] header.
 ///
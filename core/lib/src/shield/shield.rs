@@ -221,7 +221,7 @@ impl Fairing for Shield {
         }
     }
 
-    async fn on_response<'r>(&self, _: &'r Request<'_>, response: &mut Response<'r>) {
+    async fn on_response<'r>(&self, req: &'r Request<'_>, response: &mut Response<'r>) {
         // Set all of the headers in `self.policies` in `response` as long as
         // the header is not already in the response.
         for header in self.headers() {
@@ -231,7 +231,12 @@ impl Fairing for Shield {
                 continue
             }
 
-            response.set_header(header.clone());
+            // Policies that render differently per request take precedence.
+            let header = self.policies.get(header.name())
+                .and_then(|policy| policy.header_for(req))
+                .unwrap_or_else(|| header.clone());
+
+            response.set_header(header);
         }
     }
 }
@@ -1,6 +1,8 @@
 use std::future::Future;
 use std::task::{Context, Poll};
 use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use futures::FutureExt;
 
@@ -38,6 +40,15 @@ use crate::trip_wire::TripWire;
 /// See the [`stream`](crate::response::stream#graceful-shutdown) docs for an
 /// example of detecting shutdown in an infinite responder.
 ///
+/// # Draining
+///
+/// While a shutdown drains, [`Shutdown::in_flight()`] and
+/// [`Shutdown::connections()`] report how many requests and connections are
+/// still being served. [`AdHoc::on_drain()`] registers a callback that Rocket
+/// invokes periodically with these counts until draining completes.
+///
+/// [`AdHoc::on_drain()`]: crate::fairing::AdHoc::on_drain()
+///
 /// Additionally, a completed shutdown request resolves the future returned from
 /// [`Rocket::launch()`](crate::Rocket::launch()):
 ///
@@ -65,9 +76,104 @@ use crate::trip_wire::TripWire;
 /// ```
 #[derive(Debug, Clone)]
 #[must_use = "`Shutdown` does nothing unless polled or `notify`ed"]
-pub struct Shutdown(pub(crate) TripWire);
+pub struct Shutdown(pub(crate) TripWire, pub(crate) Arc<Activity>);
+
+/// The number of connections and requests being served.
+#[derive(Debug)]
+pub(crate) struct Activity {
+    connections: AtomicUsize,
+    requests: AtomicUsize,
+    /// Tripped once the shutdown `deadline`, if any, has elapsed.
+    pub(crate) deadline: TripWire,
+}
+
+/// Counts a connection or request as active until dropped.
+pub(crate) struct Active(Arc<Activity>, fn(&Activity) -> &AtomicUsize);
+
+impl Activity {
+    fn track(self: &Arc<Self>, counter: fn(&Activity) -> &AtomicUsize) -> Active {
+        counter(self).fetch_add(1, Ordering::AcqRel);
+        Active(self.clone(), counter)
+    }
+
+    pub(crate) fn connection(self: &Arc<Self>) -> Active {
+        self.track(|activity| &activity.connections)
+    }
+
+    pub(crate) fn request(self: &Arc<Self>) -> Active {
+        self.track(|activity| &activity.requests)
+    }
+}
+
+impl Drop for Active {
+    fn drop(&mut self) {
+        (self.1)(&self.0).fetch_sub(1, Ordering::AcqRel);
+    }
+}
 
 impl Shutdown {
+    pub(crate) fn new() -> Self {
+        let activity = Activity {
+            connections: AtomicUsize::new(0),
+            requests: AtomicUsize::new(0),
+            deadline: TripWire::new(),
+        };
+
+        Shutdown(TripWire::new(), Arc::new(activity))
+    }
+
+    /// Returns the number of requests currently being handled, including those
+    /// whose response is still being written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::Shutdown;
+    ///
+    /// #[get("/load")]
+    /// fn load(shutdown: Shutdown) -> String {
+    ///     format!("{} request(s) in flight", shutdown.in_flight())
+    /// }
+    /// ```
+    pub fn in_flight(&self) -> usize {
+        self.1.requests.load(Ordering::Acquire)
+    }
+
+    /// Returns the number of connections currently open.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::Shutdown;
+    ///
+    /// #[get("/load")]
+    /// fn load(shutdown: Shutdown) -> String {
+    ///     format!("{} connection(s) open", shutdown.connections())
+    /// }
+    /// ```
+    pub fn connections(&self) -> usize {
+        self.1.connections.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if a shutdown has been requested.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use rocket::*;
+    /// use rocket::Shutdown;
+    ///
+    /// #[get("/health")]
+    /// fn health(shutdown: Shutdown) -> &'static str {
+    ///     if shutdown.is_requested() { "draining" } else { "ok" }
+    /// }
+    /// ```
+    pub fn is_requested(&self) -> bool {
+        self.0.tripped()
+    }
+
     /// Notify the application to shut down gracefully.
     ///
     /// This function returns immediately; pending requests will continue to run
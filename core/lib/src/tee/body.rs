@@ -0,0 +1,120 @@
+use std::convert::Infallible;
+use std::path::Path;
+
+use tempfile::TempPath;
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the copy of a request's body made by [`Tee`].
+///
+/// The guard succeeds only for requests whose body was copied: those with a
+/// non-empty body accepted by the filter of an attached [`Tee`] fairing. The
+/// file it refers to is deleted once the request has been responded to.
+///
+/// # Outcomes
+///
+///   * **Success** if the body of the request was copied.
+///   * **Forward** otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::tee::TeedBody;
+///
+/// #[post("/", data = "<body>")]
+/// fn index(body: String, copy: Option<&TeedBody>) -> String {
+///     match copy {
+///         Some(copy) => format!("{} bytes copied to {:?}", copy.len(), copy.path()),
+///         None => format!("{} bytes not copied", body.len()),
+///     }
+/// }
+/// ```
+///
+/// [`Tee`]: crate::tee::Tee
+#[derive(Debug)]
+pub struct TeedBody {
+    pub(crate) path: TempPath,
+    pub(crate) len: u64,
+    pub(crate) truncated: bool,
+}
+
+impl TeedBody {
+    /// Returns the path to the file containing the copy of the body.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tee::TeedBody;
+    ///
+    /// async fn audit(body: &TeedBody) -> std::io::Result<Vec<u8>> {
+    ///     rocket::tokio::fs::read(body.path()).await
+    /// }
+    /// ```
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the number of bytes copied. This is the length of the body
+    /// unless the body was [truncated](TeedBody::is_truncated()), in which case
+    /// it is the limit of the [`Tee`](crate::tee::Tee).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tee::TeedBody;
+    ///
+    /// fn handler(body: &TeedBody) {
+    ///     println!("copied {} bytes", body.len());
+    /// }
+    /// ```
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if no bytes were copied. Since only non-empty bodies are
+    /// copied, this is always `false`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tee::TeedBody;
+    ///
+    /// fn handler(body: &TeedBody) {
+    ///     assert!(!body.is_empty());
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the body exceeded the limit of the
+    /// [`Tee`](crate::tee::Tee) and only a prefix of it was copied.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tee::TeedBody;
+    ///
+    /// fn handler(body: &TeedBody) {
+    ///     if body.is_truncated() {
+    ///         println!("only the first {} bytes were copied", body.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for &'r TeedBody {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match req.local_cache(|| None::<TeedBody>) {
+            Some(body) => Outcome::Success(body),
+            None => Outcome::Forward(()),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::io;
+
+use tempfile::NamedTempFile;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+use crate::{Request, Data};
+use crate::data::{ByteUnit, ToByteUnit};
+use crate::fairing::{Fairing, Info, Kind};
+use crate::tee::TeedBody;
+
+/// A [`Fairing`] that copies the body of requests to temporary files.
+///
+/// See the [module documentation](crate::tee) for details.
+pub struct Tee {
+    limit: ByteUnit,
+    filter: Box<dyn Fn(&Request<'_>) -> bool + Send + Sync>,
+}
+
+impl Tee {
+    /// Returns a `Tee` fairing with the default settings: the bodies of all
+    /// requests are copied, up to 1MiB each.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::tee::Tee;
+    ///
+    /// let rocket = rocket::build().attach(Tee::new());
+    /// ```
+    pub fn new() -> Self {
+        Tee { limit: 1.mebibytes(), filter: Box::new(|_| true) }
+    }
+
+    /// Sets the maximum number of bytes of a body that are copied. Bodies
+    /// exceeding the limit are copied in part. Defaults to 1MiB.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::data::ToByteUnit;
+    /// use rocket::tee::Tee;
+    ///
+    /// let tee = Tee::new().limit(256.kibibytes());
+    /// ```
+    pub fn limit(mut self, limit: ByteUnit) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets the filter that determines which requests have their body copied:
+    /// only those for which `filter` returns `true`. Replaces any previously
+    /// set filter. By default, all requests are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rocket::http::Method;
+    /// use rocket::tee::Tee;
+    ///
+    /// let tee = Tee::new().filter(|req| {
+    ///     req.method() == Method::Post && req.uri().path().starts_with("/api")
+    /// });
+    /// ```
+    pub fn filter<F>(mut self, filter: F) -> Self
+        where F: Fn(&Request<'_>) -> bool + Send + Sync + 'static
+    {
+        self.filter = Box::new(filter);
+        self
+    }
+
+    /// Copies at most `self.limit` bytes of `data` to a temporary file,
+    /// leaving `data` intact. Returns `None` if `data` is empty.
+    async fn copy(&self, req: &Request<'_>, data: &mut Data<'_>) -> io::Result<Option<TeedBody>> {
+        let limit = self.limit.as_u64() as usize;
+        let body = data.buffer_up_to(limit.saturating_add(1)).await;
+        if body.is_empty() {
+            return Ok(None);
+        }
+
+        let temp_dir = req.rocket().config().temp_dir.clone();
+        let file = tokio::task::spawn_blocking(move || {
+            NamedTempFile::new_in(temp_dir)
+        }).await.map_err(|_| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "spawn_block panic")
+        })??;
+
+        let (file, path) = file.into_parts();
+        let (body, truncated) = match body.len() > limit {
+            true => (&body[..limit], true),
+            false => (body, false),
+        };
+
+        let mut file = File::from_std(file);
+        file.write_all(body).await?;
+        file.flush().await?;
+        Ok(Some(TeedBody { path, len: body.len() as u64, truncated }))
+    }
+}
+
+impl Default for Tee {
+    fn default() -> Self {
+        Tee::new()
+    }
+}
+
+#[crate::async_trait]
+impl Fairing for Tee {
+    fn info(&self) -> Info {
+        Info {
+            name: "Tee",
+            kind: Kind::Request,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, data: &mut Data<'_>) {
+        if !(self.filter)(req) {
+            return;
+        }
+
+        match self.copy(req, data).await {
+            Ok(Some(body)) => { req.local_cache(|| Some(body)); },
+            Ok(None) => { /* nothing to copy */ },
+            Err(e) => error_!("Failed to copy request body: {}", e),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Copying of request bodies to temporary files.
+//!
+//! Auditing, logging, or replaying requests requires access to their bodies
+//! after they have been read by the handler's data guard. The [`Tee`] fairing
+//! copies the body of every matching request to a temporary file before the
+//! request is routed while presenting the complete, original body to data
+//! guards. The copy is available for the remainder of the request as a
+//! [`TeedBody`]:
+//!
+//!   * Only requests accepted by the [filter](Tee::filter()), by default all
+//!     requests, with a non-empty body are copied.
+//!   * At most the [limit](Tee::limit()), by default 1MiB, of the body is
+//!     copied. A body exceeding the limit is copied in part and marked as
+//!     [truncated](TeedBody::is_truncated()).
+//!   * The file is stored in the `temp_dir` configuration directory. It is
+//!     deleted once the request has been responded to.
+//!
+//! # Usage
+//!
+//! A `TeedBody` is retrieved as a request guard or, in a fairing, via
+//! [`Request::guard()`](crate::Request::guard()):
+//!
+//! ```rust
+//! # #[macro_use] extern crate rocket;
+//! use rocket::fairing::AdHoc;
+//! use rocket::data::ToByteUnit;
+//! use rocket::tee::{Tee, TeedBody};
+//!
+//! #[post("/orders", data = "<order>")]
+//! fn order(order: String) -> String {
+//!     format!("ordered {}", order)
+//! }
+//!
+//! #[launch]
+//! fn rocket() -> _ {
+//!     let tee = Tee::new()
+//!         .limit(64.kibibytes())
+//!         .filter(|req| req.uri().path().starts_with("/orders"));
+//!
+//!     rocket::build()
+//!         .mount("/", routes![order])
+//!         .attach(tee)
+//!         .attach(AdHoc::on_response("Auditor", |req, res| Box::pin(async move {
+//!             if let Some(body) = req.guard::<&TeedBody>().await.succeeded() {
+//!                 println!("{} {}: body in {:?}", req, res.status(), body.path());
+//!             }
+//!         })))
+//! }
+//! ```
+//!
+//! # Caveats
+//!
+//! The copied part of the body is buffered in memory until the body is read by
+//! a data guard, so the limit bounds the additional memory used by each
+//! request. Because the copy is made before routing, the filter cannot depend
+//! on the route the request is routed to.
+
+mod fairing;
+mod body;
+
+pub use self::fairing::Tee;
+pub use self::body::TeedBody;
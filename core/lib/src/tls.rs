@@ -0,0 +1,113 @@
+//! TLS-specific request guards.
+//!
+//! This module is only available when the `tls` feature is enabled. Enable it
+//! in `Cargo.toml` as follows:
+//!
+//! ```toml
+//! [dependencies.rocket]
+//! version = "0.5.0-rc.1"
+//! features = ["tls"]
+//! ```
+//!
+//! Information about the TLS session itself, such as the negotiated protocol
+//! version, is available via [`TlsInfo`](crate::request::TlsInfo).
+
+use std::fmt;
+use std::ops::Deref;
+
+use crate::request::{Request, FromRequest, Outcome};
+
+/// Request guard for the server name the client requested via TLS SNI.
+///
+/// During the TLS handshake, clients may send the name of the server they wish
+/// to connect to in the Server Name Indication (SNI) extension. `ServerName`
+/// extracts that name from the TLS session the request was received over.
+///
+/// # Relation to `Host`
+///
+/// The SNI server name is sent _once_ per connection, before any HTTP is
+/// exchanged, while the [`Host`](crate::request::Host) is sent with every
+/// request. The two need not agree: an HTTP/2 client may reuse a connection
+/// for any host the server's certificate is valid for, and a client may send
+/// an arbitrary `Host` over a connection established for another name. As
+/// such, `ServerName` identifies the name the connection was authenticated
+/// for, while `Host` identifies the name the request is addressed to.
+/// Applications resolving tenants from the server name that also accept the
+/// `Host` should verify that both agree.
+///
+/// Unlike `Host`, `ServerName` is _not_ checked against
+/// [`allowed_hosts`](crate::Config::allowed_hosts).
+///
+/// # Outcomes
+///
+///   * **Success** if the request was received over TLS and the client sent an
+///     SNI server name.
+///   * **Forward** otherwise, including for plain HTTP requests and for TLS
+///     clients that did not send SNI, such as those connecting via an IP
+///     address.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate rocket;
+/// use rocket::tls::ServerName;
+///
+/// #[get("/")]
+/// fn tenant(name: ServerName<'_>) -> String {
+///     format!("Welcome to {}!", name)
+/// }
+///
+/// #[get("/", rank = 2)]
+/// fn no_tenant() -> &'static str {
+///     "Unknown tenant."
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ServerName<'r>(&'r str);
+
+impl<'r> ServerName<'r> {
+    /// Returns the requested server name as a string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate rocket;
+    /// use rocket::tls::ServerName;
+    ///
+    /// #[get("/")]
+    /// fn index(name: ServerName<'_>) -> &str {
+    ///     name.as_str()
+    /// }
+    /// ```
+    #[inline(always)]
+    pub fn as_str(&self) -> &'r str {
+        self.0
+    }
+}
+
+impl Deref for ServerName<'_> {
+    type Target = str;
+
+    #[inline(always)]
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+impl fmt::Display for ServerName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[crate::async_trait]
+impl<'r> FromRequest<'r> for ServerName<'r> {
+    type Error = std::convert::Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.tls().and_then(|tls| tls.server_name()) {
+            Some(name) => Outcome::Success(ServerName(name)),
+            None => Outcome::Forward(())
+        }
+    }
+}
@@ -0,0 +1,80 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, MediaType};
+use rocket::request::Accept;
+use rocket::local::blocking::Client;
+
+const OFFERED: [MediaType; 3] = [MediaType::JSON, MediaType::HTML, MediaType::Plain];
+
+#[get("/")]
+fn preferred(accept: &Accept) -> String {
+    let preferred = accept.preferred(&OFFERED).map(|mt| mt.to_string());
+    format!("{}: {}", accept.len(), preferred.unwrap_or_else(|| "none".into()))
+}
+
+#[get("/quality?<mt>")]
+fn quality(accept: &Accept, mt: &str) -> String {
+    accept.quality(&mt.parse::<MediaType>().unwrap()).to_string()
+}
+
+fn get(client: &Client, uri: &str, accept: &[&'static str]) -> String {
+    let mut request = client.get(uri.to_string());
+    for value in accept {
+        request.add_header(Header::new("Accept", *value));
+    }
+
+    request.dispatch().into_string().unwrap()
+}
+
+#[test]
+fn missing_accept_accepts_everything() {
+    let client = Client::debug_with(routes![preferred, quality]).unwrap();
+    assert_eq!(get(&client, "/", &[]), "0: application/json");
+    assert_eq!(get(&client, "/quality?mt=image/png", &[]), "1");
+}
+
+#[test]
+fn preferred_follows_quality_then_offer_order() {
+    let client = Client::debug_with(routes![preferred, quality]).unwrap();
+    let preferred = |accept| get(&client, "/", &[accept]);
+    assert_eq!(preferred("text/html;q=0.5, application/json"), "2: application/json");
+    assert_eq!(preferred("text/html, application/json;q=0.9"), "2: text/html; charset=utf-8");
+    assert_eq!(preferred("text/html, application/json"), "2: application/json");
+    assert_eq!(preferred("application/json;q=0, */*"), "2: text/html; charset=utf-8");
+    assert_eq!(preferred("text/*;q=0.2, text/plain;q=0.8"), "2: text/plain; charset=utf-8");
+    assert_eq!(preferred("image/png"), "1: none");
+}
+
+#[test]
+fn quality_uses_most_specific_range() {
+    let client = Client::debug_with(routes![preferred, quality]).unwrap();
+    let accept = "text/*;q=0.2, text/plain;q=0.8, */*;q=0.1, text/plain;format=flowed;q=0.6";
+    let quality = |mt| get(&client, &format!("/quality?mt={}", mt), &[accept]);
+    assert_eq!(quality("text/plain"), "0.8");
+    assert_eq!(quality("text/html"), "0.2");
+    assert_eq!(quality("application/json"), "0.1");
+    assert_eq!(quality("text/plain;format=flowed"), "0.6");
+
+    let accept = "text/plain;charset=utf-8, text/plain;q=0.3";
+    let quality = |mt| get(&client, &format!("/quality?mt={}", mt), &[accept]);
+    assert_eq!(quality("text/plain;charset=utf-8"), "1");
+    assert_eq!(quality("text/plain"), "0.3");
+    assert_eq!(quality("image/png"), "0");
+}
+
+#[test]
+fn malformed_ranges_are_skipped() {
+    let client = Client::debug_with(routes![preferred, quality]).unwrap();
+    let preferred = |accept| get(&client, "/", &[accept]);
+    let response = preferred("text/html;q=0.5, !!, application/json;q=7");
+    assert_eq!(response, "1: text/html; charset=utf-8");
+    assert_eq!(preferred("nonsense"), "0: application/json");
+    assert_eq!(preferred("text/plain;q=0.1, , text/html;q=0.2"), "2: text/html; charset=utf-8");
+}
+
+#[test]
+fn multiple_headers_are_combined() {
+    let client = Client::debug_with(routes![preferred, quality]).unwrap();
+    let response = get(&client, "/", &["text/html;q=0.1", "garbage", "text/plain;q=0.5"]);
+    assert_eq!(response, "2: text/plain; charset=utf-8");
+}
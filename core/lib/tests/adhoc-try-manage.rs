@@ -0,0 +1,62 @@
+use rocket::fairing::AdHoc;
+use rocket::figment::Figment;
+
+#[derive(Debug, PartialEq)]
+struct ApiUrl(String);
+
+#[derive(Debug, PartialEq)]
+struct Derived(String);
+
+fn manage_url() -> AdHoc {
+    AdHoc::try_manage("API URL", |figment: Figment| async move {
+        figment.extract_inner::<String>("api_url").map(ApiUrl)
+    })
+}
+
+mod try_manage_tests {
+    use super::*;
+    use rocket::error::ErrorKind;
+
+    #[rocket::async_test]
+    async fn manages_state_from_config() {
+        let figment = rocket::Config::figment().merge(("api_url", "http://a.b"));
+        let rocket = rocket::custom(figment)
+            .attach(manage_url())
+            .ignite().await
+            .unwrap();
+
+        assert_eq!(rocket.state::<ApiUrl>(), Some(&ApiUrl("http://a.b".into())));
+    }
+
+    #[rocket::async_test]
+    async fn fails_ignition_on_error() {
+        let error = rocket::build().attach(manage_url()).ignite().await.unwrap_err();
+        match error.kind() {
+            ErrorKind::FailedFairings(fairings) => assert_eq!(fairings[0].name, "API URL"),
+            kind => panic!("unexpected error kind: {:?}", kind),
+        }
+    }
+
+    #[rocket::async_test]
+    async fn observes_earlier_ignite_fairings() {
+        let rocket = rocket::build()
+            .attach(AdHoc::on_ignite("Configure", |rocket| async {
+                let figment = rocket.figment().clone().merge(("api_url", "http://c.d"));
+                rocket.configure(figment)
+            }))
+            .attach(manage_url())
+            .attach(AdHoc::try_on_ignite("Derive", |rocket| async {
+                let derived = match rocket.state::<ApiUrl>() {
+                    Some(url) => Derived(format!("{}/v1", url.0)),
+                    None => return Err(rocket),
+                };
+
+                Ok(rocket.manage(derived))
+            }))
+            .ignite().await
+            .unwrap();
+
+        assert_eq!(rocket.state::<ApiUrl>(), Some(&ApiUrl("http://c.d".into())));
+        assert_eq!(rocket.state::<Derived>(), Some(&Derived("http://c.d/v1".into())));
+    }
+}
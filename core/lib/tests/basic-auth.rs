@@ -0,0 +1,108 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::BasicAuth;
+
+#[get("/")]
+fn index(auth: BasicAuth) -> String {
+    format!("{}|{}", auth.username(), auth.password())
+}
+
+#[get("/optional")]
+fn optional(auth: Option<BasicAuth>) -> String {
+    auth.map(|a| a.username().to_string()).unwrap_or_else(|| "anonymous".into())
+}
+
+#[derive(Responder)]
+#[response(status = 401)]
+struct Challenge(&'static str, rocket::http::Header<'static>);
+
+#[catch(401)]
+fn unauthorized() -> Challenge {
+    Challenge("log in", BasicAuth::challenge("admin \"panel\""))
+}
+
+mod basic_auth_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![index, optional])
+            .register("/", catchers![unauthorized]);
+
+        Client::debug(rocket).unwrap()
+    }
+
+    fn get(client: &Client, auth: Option<&'static str>) -> (Status, String) {
+        let mut request = client.get("/");
+        if let Some(auth) = auth {
+            request.add_header(Header::new("Authorization", auth));
+        }
+
+        let response = request.dispatch();
+        (response.status(), response.into_string().unwrap_or_default())
+    }
+
+    #[test]
+    fn parses_credentials() {
+        let client = client();
+        let ok = |s: &str| (Status::Ok, s.to_string());
+        let aladdin = Some("Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==");
+        assert_eq!(get(&client, aladdin), ok("Aladdin|open sesame"));
+        assert_eq!(get(&client, Some("basic  dXNlcjpwYTpzcw==")), ok("user|pa:ss"));
+        assert_eq!(get(&client, Some("BASIC dXNlcjo=")), ok("user|"));
+        assert_eq!(get(&client, Some("Basic OnB3")), ok("|pw"));
+        assert_eq!(get(&client, Some("Basic YWI6Y2Q")), ok("ab|cd"));
+    }
+
+    #[test]
+    fn rejects_missing_credentials_with_challenge() {
+        let client = client();
+        for auth in &[None, Some("Bearer abc"), Some("Basicabc")] {
+            let mut request = client.get("/");
+            if let Some(auth) = auth {
+                request.add_header(Header::new("Authorization", *auth));
+            }
+
+            let response = request.dispatch();
+            assert_eq!(response.status(), Status::Unauthorized);
+            assert_eq!(response.headers().get_one("WWW-Authenticate"),
+                Some(r#"Basic realm="admin \"panel\"", charset="UTF-8""#));
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_credentials() {
+        let client = client();
+        for auth in &["Basic", "Basic !!!!", "Basic bm9jb2xvbg==", "Basic //46eA==", "Basic a"] {
+            assert_eq!(get(&client, Some(auth)).0, Status::BadRequest, "{:?}", auth);
+        }
+    }
+
+    #[test]
+    fn optional_credentials() {
+        let client = client();
+        let response = client.get("/optional").dispatch();
+        assert_eq!(response.into_string().unwrap(), "anonymous");
+
+        let response = client.get("/optional")
+            .header(Header::new("Authorization", "Basic YWI6Y2Q="))
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "ab");
+    }
+
+    #[test]
+    fn debug_redacts_password() {
+        let client = client();
+        let request = client.get("/").header(Header::new("Authorization", "Basic YWI6Y2Q="));
+        let auth = rocket::async_test(async {
+            use rocket::request::FromRequest;
+            BasicAuth::from_request(request.inner()).await.succeeded()
+        });
+
+        let debug = format!("{:?}", auth.unwrap());
+        assert!(debug.contains("ab") && !debug.contains("cd"), "{}", debug);
+    }
+}
@@ -0,0 +1,103 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::{Config, Rocket, Build, Data};
+use rocket::data::ToByteUnit;
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+#[post("/", data = "<body>")]
+fn echo(body: String) -> String {
+    body
+}
+
+#[post("/lenient", data = "<data>")]
+async fn lenient(data: Data<'_>) -> String {
+    match data.open(1.kibibytes()).into_string().await {
+        Ok(body) => body.into_inner(),
+        Err(e) => format!("read failed: {:?}", e.kind()),
+    }
+}
+
+fn rocket(body_read_timeout: u32) -> Rocket<Build> {
+    let config = Config { port: 0, body_read_timeout, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![echo, lenient])
+}
+
+/// Launches `rocket`, returning a connection to it.
+async fn connect(rocket: Rocket<Build>) -> TcpStream {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket.attach(AdHoc::on_liftoff("Port", move |rocket| Box::pin(async move {
+        tx.send(rocket.config().port).unwrap();
+    })));
+
+    tokio::spawn(rocket.launch());
+    let port = rx.await.unwrap();
+    TcpStream::connect(("127.0.0.1", port)).await.unwrap()
+}
+
+/// Reads a response.
+async fn response(stream: &mut TcpStream) -> String {
+    let mut buf = vec![0; 1024];
+    let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf));
+    let n = read.await.expect("response").unwrap();
+    String::from_utf8_lossy(&buf[..n]).into_owned()
+}
+
+/// Reads the status line of a response.
+async fn status_line(stream: &mut TcpStream) -> String {
+    let response = response(stream).await;
+    response.lines().next().unwrap_or_default().to_string()
+}
+
+const HEAD: &[u8] = b"POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\n";
+
+#[rocket::async_test]
+async fn stalled_body_times_out_with_408() {
+    let mut stream = connect(rocket(1)).await;
+    stream.write_all(HEAD).await.unwrap();
+    stream.write_all(b"hello").await.unwrap();
+    assert_eq!(status_line(&mut stream).await, "HTTP/1.1 408 Request Timeout");
+}
+
+#[rocket::async_test]
+async fn slow_but_steady_body_succeeds() {
+    let mut stream = connect(rocket(1)).await;
+    stream.write_all(HEAD).await.unwrap();
+    for chunk in &[b"hel", b"low", b"orl"] {
+        stream.write_all(*chunk).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(400)).await;
+    }
+
+    stream.write_all(b"d").await.unwrap();
+    assert_eq!(status_line(&mut stream).await, "HTTP/1.1 200 OK");
+}
+
+#[rocket::async_test]
+async fn zero_disables_the_timeout() {
+    let mut stream = connect(rocket(0)).await;
+    stream.write_all(HEAD).await.unwrap();
+    stream.write_all(b"hello").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+    stream.write_all(b"world").await.unwrap();
+    assert_eq!(status_line(&mut stream).await, "HTTP/1.1 200 OK");
+}
+
+#[rocket::async_test]
+async fn timeouts_fail_reads_not_responses() {
+    let mut stream = connect(rocket(1)).await;
+    let head = b"POST /lenient HTTP/1.1\r\nHost: localhost\r\nContent-Length: 10\r\n\r\n";
+    stream.write_all(head).await.unwrap();
+    stream.write_all(b"hello").await.unwrap();
+
+    let response = response(&mut stream).await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.ends_with("read failed: TimedOut"));
+}
+
+#[test]
+fn the_timeout_is_disabled_by_default() {
+    assert_eq!(Config::default().body_read_timeout, 0);
+}
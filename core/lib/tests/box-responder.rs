@@ -0,0 +1,71 @@
+#[macro_use] extern crate rocket;
+
+use std::collections::HashMap;
+
+use rocket::{Request, State};
+use rocket::http::{ContentType, Status};
+use rocket::response::{BoxResponder, Redirect, Responder};
+
+type Plugin = fn() -> BoxResponder;
+
+struct Plugins(HashMap<&'static str, Plugin>);
+
+#[get("/<name>")]
+fn plugin(name: &str, plugins: &State<Plugins>) -> BoxResponder {
+    match plugins.0.get(name) {
+        Some(plugin) => plugin(),
+        None => BoxResponder::new(Status::NotFound),
+    }
+}
+
+#[get("/echo/<value>")]
+fn echo(value: &str) -> BoxResponder {
+    BoxResponder::new((ContentType::Plain, value.to_string()))
+}
+
+#[get("/path")]
+fn path() -> BoxResponder {
+    BoxResponder::from_fn(|req: &Request<'_>| req.uri().path().to_string().respond_to(req))
+}
+
+mod box_responder_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let mut plugins: HashMap<_, Plugin> = HashMap::new();
+        plugins.insert("hello", || BoxResponder::new("Hello, world!"));
+        plugins.insert("moved", || BoxResponder::new(Redirect::to("/hello")));
+
+        let rocket = rocket::build()
+            .mount("/", routes![plugin, echo, path])
+            .manage(Plugins(plugins));
+
+        Client::debug(rocket).unwrap()
+    }
+
+    #[test]
+    fn boxed_responders_respond() {
+        let client = client();
+        let response = client.get("/hello").dispatch();
+        assert_eq!(response.into_string().unwrap(), "Hello, world!");
+
+        let response = client.get("/moved").dispatch();
+        assert_eq!(response.status(), Status::SeeOther);
+        assert_eq!(response.headers().get_one("Location"), Some("/hello"));
+
+        let response = client.get("/missing").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let response = client.get("/echo/hi").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::Plain));
+        assert_eq!(response.into_string().unwrap(), "hi");
+    }
+
+    #[test]
+    fn closures_see_the_request() {
+        let client = client();
+        let response = client.get("/path").dispatch();
+        assert_eq!(response.into_string().unwrap(), "/path");
+    }
+}
@@ -0,0 +1,88 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::{Request, Response};
+use rocket::response::{self, Responder, Cacheable};
+use rocket::http::{Header, Status};
+
+static RESPONDED: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted;
+
+impl<'r> Responder<'r, 'static> for Counted {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        RESPONDED.fetch_add(1, Ordering::SeqCst);
+        Response::build_from("content".respond_to(req)?)
+            .raw_header("Vary", "Accept")
+            .ok()
+    }
+}
+
+#[get("/")]
+fn index() -> Cacheable<Counted> {
+    Cacheable::new(Counted, "abc", Duration::from_secs(60)).vary("Accept-Encoding")
+}
+
+#[post("/")]
+fn post() -> Cacheable<&'static str> {
+    Cacheable::new("posted", "abc", Duration::from_secs(60)).weak()
+}
+
+mod cacheable_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn cacheable_sets_headers_and_responds_not_modified() {
+        let client = Client::debug_with(routes![index, post]).unwrap();
+
+        let response = client.get("/").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("ETag"), Some("\"abc\""));
+        assert_eq!(response.headers().get_one("Cache-Control"), Some("max-age=60"));
+        let vary: Vec<_> = response.headers().get("Vary").collect();
+        assert_eq!(vary, vec!["Accept", "Accept-Encoding"]);
+        assert_eq!(response.into_string().unwrap(), "content");
+        assert_eq!(RESPONDED.load(Ordering::SeqCst), 1);
+
+        for tag in &["\"abc\"", "W/\"abc\"", "\"xyz\", W/\"abc\"", "*"] {
+            let response = client.get("/").header(Header::new("If-None-Match", *tag)).dispatch();
+            assert_eq!(response.status(), Status::NotModified);
+            assert_eq!(response.headers().get_one("ETag"), Some("\"abc\""));
+            let vary: Vec<_> = response.headers().get("Vary").collect();
+            assert_eq!(vary, vec!["Accept", "Accept-Encoding"]);
+            assert!(response.into_string().is_none());
+        }
+
+        assert_eq!(RESPONDED.load(Ordering::SeqCst), 5);
+
+        let response = client.get("/")
+            .header(Header::new("If-None-Match", "\"xyz\""))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(RESPONDED.load(Ordering::SeqCst), 6);
+
+        let response = client.post("/")
+            .header(Header::new("If-None-Match", "\"abc\""))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("ETag"), Some("W/\"abc\""));
+        assert_eq!(response.into_string().unwrap(), "posted");
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_entity_tags_panic() {
+        let _ = Cacheable::new("", "a\"b", Duration::from_secs(60));
+    }
+
+    #[test]
+    #[should_panic]
+    fn entity_tags_with_whitespace_panic() {
+        let _ = Cacheable::new("", "a b", Duration::from_secs(60));
+    }
+}
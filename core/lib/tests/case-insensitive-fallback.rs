@@ -0,0 +1,79 @@
+#[macro_use] extern crate rocket;
+
+#[get("/hello/world")]
+fn hello() -> &'static str {
+    "Hello, world!"
+}
+
+#[get("/users/<name>/Profile?<tab>")]
+fn profile(name: &str, tab: Option<&str>) -> String {
+    format!("{}: {:?}", name, tab)
+}
+
+#[get("/files/<path..>")]
+fn files(path: std::path::PathBuf) -> String {
+    path.display().to_string()
+}
+
+#[get("/forward/<_>")]
+fn forward() -> Option<&'static str> {
+    None
+}
+
+mod case_insensitive_fallback_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+
+    fn client(fallback: bool) -> Client {
+        let figment = rocket::Config::figment().merge(("case_insensitive_fallback", fallback));
+        let routes = routes![hello, profile, files, forward];
+        Client::debug(rocket::custom(figment).mount("/", routes)).unwrap()
+    }
+
+    fn assert_redirect(client: &Client, uri: &str, expected: &str) {
+        let response = client.get(uri).dispatch();
+        assert_eq!(response.status(), Status::PermanentRedirect, "{}", uri);
+        assert_eq!(response.headers().get_one("Location"), Some(expected), "{}", uri);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let default = Client::debug_with(routes![hello]).unwrap();
+        assert_eq!(default.get("/Hello/World").dispatch().status(), Status::NotFound);
+        assert_eq!(default.get("/hello/world").dispatch().status(), Status::Ok);
+
+        let disabled = client(false);
+        assert_eq!(disabled.get("/HELLO/world").dispatch().status(), Status::NotFound);
+    }
+
+    #[test]
+    fn redirects_to_canonical_casing() {
+        let client = client(true);
+        assert_redirect(&client, "/Hello/World", "/hello/world");
+        assert_redirect(&client, "/HELLO/WORLD", "/hello/world");
+        assert_redirect(&client, "/USERS/BoB/profile", "/users/BoB/Profile");
+        assert_redirect(&client, "/Users/Bo%20B/PROFILE?Tab=X&tab=Y",
+            "/users/Bo%20B/Profile?Tab=X&tab=Y");
+        assert_redirect(&client, "/FILES/Some/Path.TXT", "/files/Some/Path.TXT");
+        assert_redirect(&client, "/%48ello/world", "/hello/world");
+
+        let response = client.get("/users/Bo%20B/Profile?Tab=X&tab=Y").dispatch();
+        assert_eq!(response.into_string().unwrap(), "Bo B: Some(\"Y\")");
+    }
+
+    #[test]
+    fn exact_matches_and_misses_are_unaffected() {
+        let client = client(true);
+        let response = client.get("/hello/world").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "Hello, world!");
+
+        let response = client.get("/%68ello/world").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+
+        assert_eq!(client.get("/hello/there").dispatch().status(), Status::NotFound);
+        assert_eq!(client.get("/forward/x").dispatch().status(), Status::NotFound);
+        assert_eq!(client.post("/Hello/World").dispatch().status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "cbor")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::serde::{Serialize, Deserialize};
+use rocket::serde::cbor::Cbor;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Reading {
+    sensor: String,
+    value: f32,
+}
+
+#[post("/", format = "cbor", data = "<reading>")]
+fn echo(reading: Cbor<Reading>) -> Cbor<Reading> {
+    Cbor(Reading { sensor: reading.sensor.to_uppercase(), value: reading.value * 2.0 })
+}
+
+mod cbor_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::data::{Limits, ToByteUnit};
+
+    fn client(limits: Limits) -> Client {
+        let figment = rocket::Config::figment().merge(("limits", limits));
+        Client::debug(rocket::custom(figment).mount("/", routes![echo])).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let client = client(Limits::default());
+        let reading = Reading { sensor: "temp".into(), value: 10.5 };
+        let response = client.post("/").cbor(&reading).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::CBOR));
+
+        let reading: Reading = response.into_cbor().unwrap();
+        assert_eq!(reading, Reading { sensor: "TEMP".into(), value: 21.0 });
+    }
+
+    #[test]
+    fn malformed_body_is_bad_request() {
+        let client = client(Limits::default());
+        let response = client.post("/")
+            .header(ContentType::CBOR)
+            .body(&[0xff, 0x00, 0x13])
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let bytes = rocket::serde::cbor::to_vec(&("temp", 10.5)).unwrap();
+        let response = client.post("/").header(ContentType::CBOR).body(bytes).dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn oversized_body_is_rejected() {
+        let client = client(Limits::default().limit("cbor", 8.bytes()));
+        let reading = Reading { sensor: "humidity".into(), value: 0.5 };
+        let response = client.post("/").cbor(&reading).dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+}
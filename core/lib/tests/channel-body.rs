@@ -0,0 +1,59 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{ChannelBody, Limits, ToByteUnit};
+
+#[post("/", data = "<body>")]
+async fn collect(mut body: ChannelBody<'_>) -> String {
+    let mut bytes = vec![];
+    while let Some(chunk) = body.recv().await {
+        bytes.extend_from_slice(&chunk);
+    }
+
+    let n = body.finish().await.unwrap();
+    format!("{}:{}:{}", String::from_utf8(bytes).unwrap(), n.written, n.complete)
+}
+
+#[post("/parts", data = "<body>")]
+async fn parts(body: ChannelBody<'_>) -> String {
+    let (mut rx, pump) = body.into_parts();
+    let consumer = async move {
+        let mut len = 0;
+        while let Some(chunk) = rx.recv().await {
+            len += chunk.len();
+        }
+
+        len
+    };
+
+    let (n, len) = rocket::tokio::join!(pump, consumer);
+    format!("{}:{}", len, n.unwrap().complete)
+}
+
+mod channel_body_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn channel_body_reads_within_limit() {
+        let client = Client::debug_with(routes![collect, parts]).unwrap();
+        let response = client.post("/").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello, world:12:true");
+
+        let body = vec![b'a'; 64 * 1024];
+        let response = client.post("/parts").body(&body).dispatch();
+        assert_eq!(response.into_string().unwrap(), "65536:true");
+    }
+
+    #[test]
+    fn channel_body_respects_limit() {
+        let limits = Limits::default().limit("channel", 5.bytes());
+        let figment = rocket::Config::figment().merge(("limits", limits));
+        let client = Client::debug(rocket::custom(figment).mount("/", routes![collect])).unwrap();
+
+        let response = client.post("/").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello:5:false");
+
+        let response = client.post("/").body("hi").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hi:2:true");
+    }
+}
@@ -0,0 +1,147 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rocket::{Rocket, Build, State};
+use rocket::collapse::Collapse;
+use rocket::http::{Accept, Cookie, CookieJar, Header};
+use rocket::tokio::time::sleep;
+
+#[derive(Default)]
+struct Calls(AtomicUsize);
+
+#[get("/slow")]
+async fn slow(calls: &State<Calls>) -> String {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    sleep(Duration::from_millis(250)).await;
+    format!("slow:{}", n)
+}
+
+#[get("/cookie")]
+async fn cookie(calls: &State<Calls>, jar: &CookieJar<'_>) -> String {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    jar.add(Cookie::new("n", n.to_string()));
+    sleep(Duration::from_millis(250)).await;
+    format!("cookie:{}", n)
+}
+
+#[get("/tenant")]
+async fn tenant_a(calls: &State<Calls>) -> String {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    sleep(Duration::from_millis(250)).await;
+    format!("a.example.com:{}", n)
+}
+
+#[get("/tenant")]
+async fn tenant_b(calls: &State<Calls>) -> String {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    sleep(Duration::from_millis(250)).await;
+    format!("b.example.com:{}", n)
+}
+
+fn rocket(collapse: Collapse) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![slow, cookie])
+        .mount_host("a.example.com", routes![tenant_a])
+        .mount_host("b.example.com", routes![tenant_b])
+        .manage(Calls::default())
+        .attach(collapse)
+}
+
+mod collapse_tests {
+    use super::*;
+    use rocket::local::asynchronous::Client;
+
+    #[rocket::async_test]
+    async fn collapses_identical_in_flight_requests() {
+        let client = Client::debug(rocket(Collapse::new())).await.unwrap();
+        let (a, b) = rocket::tokio::join!(
+            client.get("/slow").dispatch(),
+            client.get("/slow").dispatch(),
+        );
+
+        assert_eq!(a.content_type(), b.content_type());
+        assert_eq!(a.into_string().await.unwrap(), "slow:0");
+        assert_eq!(b.into_string().await.unwrap(), "slow:0");
+
+        let c = client.get("/slow").dispatch().await;
+        assert_eq!(c.into_string().await.unwrap(), "slow:1");
+    }
+
+    #[rocket::async_test]
+    async fn distinct_requests_are_not_collapsed() {
+        let client = Client::debug(rocket(Collapse::new())).await.unwrap();
+        let (a, b, c, d) = rocket::tokio::join!(
+            client.get("/slow").dispatch(),
+            client.get("/slow?v=1").dispatch(),
+            client.get("/slow").header(Accept::JSON).dispatch(),
+            client.get("/slow").header(Header::new("Authorization", "Bearer x")).dispatch(),
+        );
+
+        let mut bodies = vec![
+            a.into_string().await.unwrap(),
+            b.into_string().await.unwrap(),
+            c.into_string().await.unwrap(),
+            d.into_string().await.unwrap(),
+        ];
+
+        bodies.sort();
+        assert_eq!(bodies, &["slow:0", "slow:1", "slow:2", "slow:3"]);
+    }
+
+    #[rocket::async_test]
+    async fn requests_for_distinct_hosts_are_not_collapsed() {
+        let client = Client::debug(rocket(Collapse::new())).await.unwrap();
+        let (a, b) = rocket::tokio::join!(
+            client.get("/tenant").header(Header::new("Host", "a.example.com")).dispatch(),
+            client.get("/tenant").header(Header::new("Host", "b.example.com")).dispatch(),
+        );
+
+        let a = a.into_string().await.unwrap();
+        let b = b.into_string().await.unwrap();
+        assert!(a.starts_with("a.example.com:"), "{}", a);
+        assert!(b.starts_with("b.example.com:"), "{}", b);
+    }
+
+    #[rocket::async_test]
+    async fn varied_credentials_are_collapsed() {
+        let collapse = Collapse::new().vary("authorization");
+        let client = Client::debug(rocket(collapse)).await.unwrap();
+        let auth = || Header::new("Authorization", "Bearer x");
+        let (a, b) = rocket::tokio::join!(
+            client.get("/slow").header(auth()).dispatch(),
+            client.get("/slow").header(auth()).dispatch(),
+        );
+
+        assert_eq!(a.into_string().await.unwrap(), "slow:0");
+        assert_eq!(b.into_string().await.unwrap(), "slow:0");
+    }
+
+    #[rocket::async_test]
+    async fn followers_stop_waiting_after_timeout() {
+        let collapse = Collapse::new().timeout(Duration::from_millis(50));
+        let client = Client::debug(rocket(collapse)).await.unwrap();
+        let (a, b) = rocket::tokio::join!(
+            client.get("/slow").dispatch(),
+            client.get("/slow").dispatch(),
+        );
+
+        assert_eq!(a.into_string().await.unwrap(), "slow:0");
+        assert_eq!(b.into_string().await.unwrap(), "slow:1");
+    }
+
+    #[rocket::async_test]
+    async fn responses_setting_cookies_are_not_shared() {
+        let client = Client::untracked(rocket(Collapse::new())).await.unwrap();
+        let (a, b) = rocket::tokio::join!(
+            client.get("/cookie").dispatch(),
+            client.get("/cookie").dispatch(),
+        );
+
+        assert_eq!(a.cookies().get("n").map(|c| c.value()), Some("0"));
+        assert_eq!(b.cookies().get("n").map(|c| c.value()), Some("1"));
+        assert_eq!(a.into_string().await.unwrap(), "cookie:0");
+        assert_eq!(b.into_string().await.unwrap(), "cookie:1");
+    }
+}
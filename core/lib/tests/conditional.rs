@@ -0,0 +1,60 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::response::Conditional;
+use rocket::http::{Header, Status};
+
+static RENDERED: AtomicUsize = AtomicUsize::new(0);
+
+fn render() -> String {
+    RENDERED.fetch_add(1, Ordering::SeqCst);
+    "rendered".into()
+}
+
+#[get("/")]
+fn index() -> Conditional<fn() -> String> {
+    Conditional::new("v1", render)
+}
+
+#[post("/")]
+fn post() -> Conditional<impl FnOnce() -> &'static str> {
+    Conditional::new("v1", || "posted").weak()
+}
+
+mod conditional_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn conditional_skips_inner_when_not_modified() {
+        let client = Client::debug_with(routes![index, post]).unwrap();
+
+        let response = client.get("/").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("ETag"), Some("\"v1\""));
+        assert_eq!(response.into_string().unwrap(), "rendered");
+        assert_eq!(RENDERED.load(Ordering::SeqCst), 1);
+
+        for tag in &["\"v1\"", "W/\"v1\"", "\"v0\", \"v1\"", "*"] {
+            let response = client.get("/").header(Header::new("If-None-Match", *tag)).dispatch();
+            assert_eq!(response.status(), Status::NotModified, "{}", tag);
+            assert_eq!(response.headers().get_one("ETag"), Some("\"v1\""));
+            assert!(response.into_string().is_none());
+        }
+
+        let response = client.head("/").header(Header::new("If-None-Match", "\"v1\"")).dispatch();
+        assert_eq!(response.status(), Status::NotModified);
+        assert_eq!(RENDERED.load(Ordering::SeqCst), 1);
+
+        let response = client.get("/").header(Header::new("If-None-Match", "\"v0\"")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "rendered");
+        assert_eq!(RENDERED.load(Ordering::SeqCst), 2);
+
+        let response = client.post("/").header(Header::new("If-None-Match", "\"v1\"")).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("ETag"), Some("W/\"v1\""));
+        assert_eq!(response.into_string().unwrap(), "posted");
+    }
+}
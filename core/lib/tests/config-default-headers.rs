@@ -0,0 +1,53 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Header;
+use rocket::config::DefaultHeaders;
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[derive(Responder)]
+struct Own(&'static str, Header<'static>);
+
+#[get("/own")]
+fn own() -> Own {
+    Own("own", Header::new("X-Served-By", "handler"))
+}
+
+mod config_default_headers_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let headers = DefaultHeaders::new()
+            .header("X-App-Version", "1.2.3")
+            .header("X-Served-By", "a")
+            .header("X-Served-By", "b");
+
+        let figment = rocket::Config::figment().merge(("default_headers", headers));
+        Client::debug(rocket::custom(figment).mount("/", routes![index, own])).unwrap()
+    }
+
+    #[test]
+    fn default_headers_are_set() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.headers().get_one("X-App-Version"), Some("1.2.3"));
+        let served_by: Vec<_> = response.headers().get("X-Served-By").collect();
+        assert_eq!(served_by, ["a", "b"]);
+
+        let response = client.get("/404").dispatch();
+        assert_eq!(response.headers().get_one("X-App-Version"), Some("1.2.3"));
+    }
+
+    #[test]
+    fn default_headers_do_not_override() {
+        let client = client();
+        let response = client.get("/own").dispatch();
+        assert_eq!(response.headers().get_one("X-App-Version"), Some("1.2.3"));
+        let served_by: Vec<_> = response.headers().get("X-Served-By").collect();
+        assert_eq!(served_by, ["handler"]);
+    }
+}
@@ -0,0 +1,66 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::ConnectionInfo;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index(conn: ConnectionInfo) -> String {
+    format!("<#{}:{}:{}:{}>", conn.ordinal(), conn.is_reused(), conn.keep_alive(),
+        conn.remote().port())
+}
+
+#[get("/", rank = 2)]
+fn no_connection() -> &'static str {
+    "<none>"
+}
+
+#[cfg(feature = "raw_requests")]
+fn bodies(client: &Client, raw: &str) -> Vec<String> {
+    let response = client.send_raw(raw);
+    let response = String::from_utf8_lossy(response.as_bytes());
+    response.split('<').skip(1)
+        .map(|s| format!("<{}", &s[..=s.find('>').unwrap()]))
+        .collect()
+}
+
+#[test]
+fn local_requests_have_no_connection_info() {
+    let client = Client::debug_with(routes![index, no_connection]).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "<none>");
+}
+
+#[test]
+#[cfg(feature = "raw_requests")]
+fn requests_are_numbered_per_connection() {
+    let client = Client::debug_with(routes![index, no_connection]).unwrap();
+    let request = "GET / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n";
+    assert_eq!(bodies(&client, request), ["<#1:false:true:0>"]);
+
+    let last = "GET / HTTP/1.1\r\nHost: rocket.rs\r\nConnection: close\r\n\r\n";
+    let pipelined = format!("{}{}{}", request, request, last);
+    assert_eq!(bodies(&client, &pipelined), [
+        "<#1:false:true:0>",
+        "<#2:true:true:0>",
+        "<#3:true:false:0>",
+    ]);
+
+    // Each connection starts counting anew.
+    assert_eq!(bodies(&client, request), ["<#1:false:true:0>"]);
+}
+
+#[test]
+#[cfg(feature = "raw_requests")]
+fn keep_alive_reflects_version_and_config() {
+    let client = Client::debug_with(routes![index, no_connection]).unwrap();
+    let http10 = "GET / HTTP/1.0\r\nHost: rocket.rs\r\n\r\n";
+    assert_eq!(bodies(&client, http10), ["<#1:false:false:0>"]);
+
+    let http10 = "GET / HTTP/1.0\r\nHost: rocket.rs\r\nConnection: Keep-Alive\r\n\r\n";
+    assert_eq!(bodies(&client, http10), ["<#1:false:true:0>"]);
+
+    let figment = rocket::Config::figment().merge(("keep_alive", 0));
+    let rocket = rocket::custom(figment).mount("/", routes![index, no_connection]);
+    let client = Client::debug(rocket).unwrap();
+    let request = "GET / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n";
+    assert_eq!(bodies(&client, request), ["<#1:false:false:0>"]);
+}
@@ -0,0 +1,80 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Header;
+use rocket::response::WithLanguage;
+
+#[derive(Responder)]
+struct French(&'static str, Header<'static>);
+
+#[get("/")]
+fn index() -> WithLanguage<&'static str> {
+    WithLanguage::new("Hello, world!", "en-US").language("en-GB")
+}
+
+#[get("/override")]
+fn replaces() -> WithLanguage<French> {
+    let french = French("Bonjour !", Header::new("Content-Language", "fr"));
+    WithLanguage::new(french, "de")
+}
+
+#[get("/negotiated/<tag>")]
+fn negotiated(tag: String) -> Option<WithLanguage<&'static str>> {
+    WithLanguage::try_new("Hello!", tag)
+}
+
+mod content_language_tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+
+    fn is_valid(tag: &'static str) -> bool {
+        WithLanguage::try_new((), tag).is_some()
+    }
+
+    #[test]
+    fn sets_content_language() {
+        let client = Client::debug_with(routes![index, replaces, negotiated]).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.headers().get_one("Content-Language"), Some("en-US, en-GB"));
+
+        let response = client.get("/override").dispatch();
+        let languages: Vec<_> = response.headers().get("Content-Language").collect();
+        assert_eq!(languages, vec!["de"]);
+
+        let response = client.get("/negotiated/zh-Hant-TW").dispatch();
+        assert_eq!(response.headers().get_one("Content-Language"), Some("zh-Hant-TW"));
+
+        let response = client.get("/negotiated/en_US").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+
+    #[test]
+    fn accepts_well_formed_tags() {
+        for tag in &[
+            "en", "EN-us", "de-CH-1901", "sl-rozaj-biske", "zh-Hant-CN", "es-419",
+            "zh-cmn-Hans-CN", "sr-Latn-RS", "hy-Latn-IT-arevela", "en-US-u-islamcal",
+            "de-DE-u-co-phonebk", "en-a-bbb-x-a-ccc", "x-whatever", "qaa-Qaaa-QM-x-southern",
+            "de-Qaaa", "sgn-ase", "el-1901",
+        ] {
+            assert!(is_valid(tag), "{:?} should be valid", tag);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_tags() {
+        for tag in &[
+            "", "e", "en_US", "en-", "-en", "en--US", "de-419-DE", "a-DE", "ar-a-aaa-b-bbb-a",
+            "en-a", "en-x", "x", "123", "en-US-u", "toolongtag", "en-ü", "ab-abc-abc-abc-abc",
+            "en-US-Latn", "en-Latn-Latn",
+        ] {
+            assert!(!is_valid(tag), "{:?} should be invalid", tag);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "malformed BCP 47 language tag")]
+    fn new_panics_on_malformed_tag_in_debug() {
+        WithLanguage::new((), "en_US");
+    }
+}
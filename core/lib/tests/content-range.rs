@@ -0,0 +1,56 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, Status};
+use rocket::request::ContentRange;
+
+#[put("/upload", data = "<data>")]
+fn upload(range: Result<ContentRange, String>, data: String) -> String {
+    match range {
+        Ok(range) => format!("{}:{}:{:?}:{}", range.start(), range.end(), range.total(), data),
+        Err(e) => e,
+    }
+}
+
+#[put("/strict")]
+fn strict(range: ContentRange) -> String {
+    range.to_string()
+}
+
+mod content_range_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn content_range_guard() {
+        let client = Client::debug_with(routes![upload]).unwrap();
+
+        let response = client.put("/upload").body("hi").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let response = client.put("/upload")
+            .header(Header::new("Content-Range", "bytes 0-1/10"))
+            .body("hi")
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "0:1:Some(10):hi");
+
+        let response = client.put("/upload")
+            .header(Header::new("Content-Range", "bytes 8-9/*"))
+            .body("hi")
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "8:9:None:hi");
+    }
+
+    #[test]
+    fn invalid_content_range_fails() {
+        let client = Client::debug_with(routes![strict]).unwrap();
+        for value in &["bytes 5-1/10", "bytes 0-10/10", "bytes 0-/10", "0-1/10", "bytes */10"] {
+            let response = client.put("/strict").header(Header::new("Content-Range", *value)).dispatch();
+            assert_eq!(response.status(), Status::BadRequest, "{}", value);
+        }
+
+        let response = client.put("/strict")
+            .header(Header::new("Content-Range", "bytes 0-9/10"))
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "bytes 0-9/10");
+    }
+}
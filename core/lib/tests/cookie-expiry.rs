@@ -0,0 +1,85 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::http::{Cookie, CookieJar, CookieExpiry, Expiration, SameSite};
+
+#[get("/")]
+fn index(jar: &CookieJar<'_>) {
+    jar.add(Cookie::build("hour", "a").max_age_secs(3600).finish());
+    jar.add(Cookie::build("minute", "b").expires_in(Duration::from_millis(60_500)).finish());
+    jar.add(Cookie::build("gone", "").expired().finish());
+}
+
+#[cfg(feature = "secrets")]
+#[get("/private")]
+fn private(jar: &CookieJar<'_>) {
+    jar.add_private(Cookie::build("session", "c").session().finish());
+    jar.add_private(Cookie::new("default", "d"));
+}
+
+mod cookie_expiry_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn max_age_and_expires_are_set() {
+        let client = Client::debug_with(routes![index]).unwrap();
+        let response = client.get("/").dispatch();
+        let now = time::OffsetDateTime::now_utc();
+
+        let hour = response.cookies().get("hour").unwrap();
+        assert_eq!(hour.max_age(), Some(time::Duration::hours(1)));
+        let expires = hour.expires_datetime().unwrap();
+        assert!(expires > now + time::Duration::minutes(59));
+        assert!(expires <= now + time::Duration::hours(1));
+
+        let minute = response.cookies().get("minute").unwrap();
+        assert_eq!(minute.max_age(), Some(time::Duration::minutes(1)));
+
+        let header = response.headers().get("Set-Cookie")
+            .find(|h| h.starts_with("gone="))
+            .unwrap();
+
+        assert!(header.contains("Max-Age=0"));
+        let gone = response.cookies().get("gone").unwrap();
+        assert!(gone.expires_datetime().unwrap() < now);
+    }
+
+    #[cfg(feature = "secrets")]
+    #[test]
+    fn session_private_cookies_do_not_expire() {
+        let client = Client::debug_with(routes![private]).unwrap();
+        let response = client.get("/private").dispatch();
+
+        let session = response.cookies().get_private("session").unwrap();
+        assert_eq!(session.expires_datetime(), None);
+        assert_eq!(session.max_age(), None);
+
+        let default = response.cookies().get_private("default").unwrap();
+        assert!(default.expires_datetime().is_some());
+    }
+
+    #[test]
+    fn session_clears_max_age_and_keeps_attributes() {
+        let cookie = Cookie::build("name", "value")
+            .path("/app")
+            .domain("rocket.rs")
+            .secure(true)
+            .http_only(true)
+            .same_site(SameSite::Strict)
+            .max_age_secs(3600)
+            .session()
+            .finish();
+
+        assert_eq!(cookie.max_age(), None);
+        assert_eq!(cookie.expires(), Some(Expiration::Session));
+        assert_eq!(cookie.name_value(), ("name", "value"));
+        assert_eq!(cookie.path(), Some("/app"));
+        assert_eq!(cookie.domain(), Some("rocket.rs"));
+        assert_eq!(cookie.secure(), Some(true));
+        assert_eq!(cookie.http_only(), Some(true));
+        assert_eq!(cookie.same_site(), Some(SameSite::Strict));
+        assert!(!cookie.to_string().contains("Max-Age"));
+    }
+}
@@ -0,0 +1,53 @@
+#[macro_use] extern crate rocket;
+
+use rocket::data::{Data, ToByteUnit};
+
+#[post("/<limit>", data = "<data>")]
+async fn chunks(limit: u64, data: Data<'_>) -> String {
+    let chunks = data.chunks(limit.bytes()).into_vec().await.unwrap();
+    let body: Vec<u8> = chunks.iter().flat_map(|c| c.iter().copied()).collect();
+    format!("{}:{}:{}", String::from_utf8(body).unwrap(), chunks.n.written, chunks.is_complete())
+}
+
+#[post("/peek", data = "<data>")]
+async fn peek(mut data: Data<'_>) -> String {
+    let peeked = data.peek(2).await.to_vec();
+    let chunks = data.chunks(1.kibibytes()).into_vec().await.unwrap();
+    let first = String::from_utf8(chunks[0][..2].to_vec()).unwrap();
+    format!("{}:{}:{}", String::from_utf8(peeked).unwrap(), first, chunks.n.written)
+}
+
+mod data_chunks_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn chunks_contain_entire_body() {
+        let client = Client::debug_with(routes![chunks, peek]).unwrap();
+        let response = client.post("/1024").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello, world:12:true");
+
+        let response = client.post("/12").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello, world:12:true");
+
+        let response = client.post("/1024").dispatch();
+        assert_eq!(response.into_string().unwrap(), ":0:true");
+    }
+
+    #[test]
+    fn chunks_respect_limit() {
+        let client = Client::debug_with(routes![chunks]).unwrap();
+        let response = client.post("/5").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "hello:5:false");
+
+        let response = client.post("/0").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), ":0:false");
+    }
+
+    #[test]
+    fn chunks_include_peeked_data() {
+        let client = Client::debug_with(routes![peek]).unwrap();
+        let response = client.post("/peek").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "he:he:12");
+    }
+}
@@ -0,0 +1,70 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/%41bc")]
+fn encoded() -> &'static str {
+    "encoded"
+}
+
+#[get("/Abc")]
+fn decoded() -> &'static str {
+    "decoded"
+}
+
+#[get("/Abc/<param>")]
+fn param(param: &str) -> String {
+    param.into()
+}
+
+#[get("/raw/<_>")]
+fn raw(uri: &rocket::http::uri::Origin<'_>) -> String {
+    uri.path().raw_segments().last().unwrap().to_string()
+}
+
+#[get("/a%2Fb")]
+fn slash() -> &'static str {
+    "slash"
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![encoded, param, raw, slash]).unwrap()
+}
+
+#[test]
+fn encoded_literals_match_decoded_requests() {
+    let client = client();
+    for uri in &["/Abc", "/%41bc", "/%41%62%63", "/A%62c"] {
+        let response = client.get(*uri).dispatch();
+        assert_eq!(response.into_string().unwrap(), "encoded", "{}", uri);
+    }
+
+    assert_eq!(client.get("/abc").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/%61bc").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn encoded_slash_stays_within_segment() {
+    let client = client();
+    let response = client.get("/a%2Fb").dispatch();
+    assert_eq!(response.into_string().unwrap(), "slash");
+    assert_eq!(client.get("/a/b").dispatch().status(), Status::NotFound);
+}
+
+#[test]
+fn parameters_receive_decoded_segments() {
+    let client = client();
+    let response = client.get("/%41bc/hello%20there").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello there");
+
+    let response = client.get("/raw/hello%20there").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello%20there");
+}
+
+#[test]
+fn encoded_and_decoded_literals_collide() {
+    let rocket = rocket::build().mount("/", routes![encoded, decoded]);
+    let error = Client::debug(rocket).unwrap_err();
+    assert!(matches!(error.kind(), rocket::error::ErrorKind::Collisions(..)));
+}
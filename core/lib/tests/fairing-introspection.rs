@@ -0,0 +1,64 @@
+use rocket::{Rocket, Build, Config};
+use rocket::fairing::{self, AdHoc, Fairing, Info, Kind};
+
+struct Singleton(&'static str);
+
+#[rocket::async_trait]
+impl Fairing for Singleton {
+    fn info(&self) -> Info {
+        Info { name: self.0, kind: Kind::Response | Kind::Singleton }
+    }
+}
+
+struct Attacher;
+
+#[rocket::async_trait]
+impl Fairing for Attacher {
+    fn info(&self) -> Info {
+        Info { name: "Attacher", kind: Kind::Ignite }
+    }
+
+    async fn on_ignite(&self, rocket: Rocket<Build>) -> fairing::Result {
+        Ok(rocket.attach(AdHoc::on_liftoff("Attached", |_| Box::pin(async {}))))
+    }
+}
+
+fn names<P: rocket::Phase>(rocket: &Rocket<P>) -> Vec<&'static str> {
+    rocket.fairings().map(|info| info.name).collect()
+}
+
+#[test]
+fn fairings_are_listed_in_attach_order() {
+    let rocket = rocket::custom(Config::debug_default());
+    assert_eq!(names(&rocket), ["Shield"]);
+
+    let rocket = rocket
+        .attach(AdHoc::on_request("Request", |_, _| Box::pin(async {})))
+        .attach(AdHoc::on_ignite("Ignite", |rocket| async { rocket }));
+
+    assert_eq!(names(&rocket), ["Shield", "Request", "Ignite"]);
+
+    let kinds: Vec<Kind> = rocket.fairings().map(|info| info.kind).collect();
+    assert!(kinds[0].is(Kind::Singleton));
+    assert!(kinds[1].is(Kind::Request) && !kinds[1].is(Kind::Ignite));
+    assert!(kinds[2].is(Kind::Ignite) && !kinds[2].is(Kind::Request));
+}
+
+#[test]
+fn only_last_singleton_is_listed() {
+    let rocket = rocket::custom(Config::debug_default())
+        .attach(Singleton("First"))
+        .attach(AdHoc::on_response("Between", |_, _| Box::pin(async {})))
+        .attach(Singleton("Second"));
+
+    assert_eq!(names(&rocket), ["Shield", "Between", "Second"]);
+}
+
+#[rocket::async_test]
+async fn fairings_attached_on_ignite_are_listed() {
+    let rocket = rocket::custom(Config::debug_default()).attach(Attacher);
+    assert_eq!(names(&rocket), ["Shield", "Attacher"]);
+
+    let rocket = rocket.ignite().await.unwrap();
+    assert_eq!(names(&rocket), ["Shield", "Attacher", "Attached"]);
+}
@@ -0,0 +1,107 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::{Arc, Mutex};
+
+use rocket::{Rocket, Build};
+use rocket::fairing::AdHoc;
+use rocket::http::Header;
+
+#[derive(Default, Clone)]
+struct Log(Arc<Mutex<Vec<&'static str>>>);
+
+impl Log {
+    fn push(&self, name: &'static str) {
+        self.0.lock().unwrap().push(name);
+    }
+
+    fn take(&self) -> Vec<&'static str> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+#[get("/")]
+fn index(log: &rocket::State<Log>) -> &'static str {
+    log.push("route");
+    "index"
+}
+
+fn request(name: &'static str, priority: i32) -> AdHoc {
+    AdHoc::on_request(name, move |req, _| Box::pin(async move {
+        req.rocket().state::<Log>().unwrap().push(name);
+        if req.headers().contains(name) {
+            req.skip_request_fairings();
+        }
+    })).with_priority(priority)
+}
+
+fn response(name: &'static str, priority: i32) -> AdHoc {
+    AdHoc::on_response(name, move |req, _| Box::pin(async move {
+        req.rocket().state::<Log>().unwrap().push(name);
+    })).with_priority(priority)
+}
+
+fn rocket(log: &Log) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index])
+        .manage(log.clone())
+        .attach(request("a", 0))
+        .attach(request("b", 5))
+        .attach(response("x", -1))
+        .attach(request("c", 0))
+        .attach(response("y", 0))
+        .attach(request("d", 5))
+        .attach(request("e", -10))
+}
+
+mod fairing_priority_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn higher_priorities_run_first_and_ties_in_attach_order() {
+        let log = Log::default();
+        let client = Client::debug(rocket(&log)).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "index");
+        assert_eq!(log.take(), ["b", "d", "a", "c", "e", "route", "y", "x"]);
+    }
+
+    #[test]
+    fn skipped_request_fairings_still_route() {
+        let log = Log::default();
+        let client = Client::debug(rocket(&log)).unwrap();
+        let response = client.get("/").header(Header::new("a", "skip")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "index");
+        assert_eq!(log.take(), ["b", "d", "a", "route", "y", "x"]);
+
+        let response = client.get("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "index");
+        assert_eq!(log.take(), ["b", "d", "a", "c", "e", "route", "y", "x"]);
+    }
+
+    #[test]
+    fn ignite_fairings_are_prioritized() {
+        let log = Log::default();
+        let push = |name: &'static str, priority: i32| {
+            let log = log.clone();
+            AdHoc::on_ignite(name, move |rocket| async move {
+                log.push(name);
+                rocket
+            }).with_priority(priority)
+        };
+
+        let nested = push("nested", 100);
+        let attacher = AdHoc::on_ignite("attacher", move |rocket| async move {
+            rocket.attach(nested)
+        });
+
+        let rocket = rocket::build()
+            .attach(push("low", -1))
+            .attach(attacher)
+            .attach(push("high", 1))
+            .attach(push("default", 0));
+
+        rocket::async_test(rocket.ignite()).unwrap();
+        assert_eq!(log.take(), ["high", "default", "low", "nested"]);
+    }
+}
@@ -0,0 +1,66 @@
+#[macro_use] extern crate rocket;
+
+use rocket::fairing::AdHoc;
+use rocket::http::{Cookie, CookieJar};
+
+#[get("/")]
+fn index(jar: &CookieJar<'_>) -> &'static str {
+    jar.add(Cookie::new("session", "abc"));
+    jar.add(Cookie::new("theme", "dark"));
+    "ok"
+}
+
+#[get("/remove")]
+fn remove(jar: &CookieJar<'_>) -> &'static str {
+    jar.remove(Cookie::named("session"));
+    "removed"
+}
+
+fn rocket() -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![index, remove])
+        .attach(AdHoc::on_response("CSRF", |req, _| Box::pin(async move {
+            req.cookies().add(Cookie::new("csrf", "xyz"));
+        })))
+}
+
+mod fairing_set_cookies_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn handler_and_fairing_cookies_are_distinct_headers() {
+        let client = Client::untracked(rocket()).unwrap();
+        let response = client.get("/").dispatch();
+
+        let headers: Vec<_> = response.headers().get("Set-Cookie").collect();
+        assert_eq!(headers.len(), 3, "{:?}", headers);
+        assert!(headers.iter().all(|h| !h.contains(',')), "{:?}", headers);
+
+        let mut names: Vec<_> = response.cookies().iter().map(|c| c.name().to_string()).collect();
+        names.sort();
+        assert_eq!(names, vec!["csrf", "session", "theme"]);
+    }
+
+    #[test]
+    fn fairing_cookies_are_set_on_error_responses() {
+        let client = Client::untracked(rocket()).unwrap();
+        let response = client.get("/not-found").dispatch();
+
+        let headers: Vec<_> = response.headers().get("Set-Cookie").collect();
+        assert_eq!(headers.len(), 1, "{:?}", headers);
+        assert!(response.cookies().get("csrf").is_some());
+    }
+
+    #[test]
+    fn removals_and_additions_are_distinct_headers() {
+        let client = Client::tracked(rocket()).unwrap();
+        client.get("/").dispatch();
+
+        let response = client.get("/remove").dispatch();
+        let headers: Vec<_> = response.headers().get("Set-Cookie").collect();
+        assert_eq!(headers.len(), 2, "{:?}", headers);
+        assert!(headers.iter().any(|h| h.starts_with("session=;")), "{:?}", headers);
+        assert!(headers.iter().any(|h| h.starts_with("csrf=xyz")), "{:?}", headers);
+    }
+}
@@ -0,0 +1,33 @@
+#[macro_use] extern crate rocket;
+
+use rocket::figment::Figment;
+
+#[get("/<key>")]
+fn value(figment: &Figment, key: &str) -> Option<String> {
+    figment.extract_inner::<String>(key).ok()
+}
+
+#[get("/profile")]
+fn profile(figment: &Figment) -> String {
+    figment.profile().to_string()
+}
+
+mod figment_guard_tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn figment_guard_exposes_active_figment() {
+        let figment = rocket::Config::figment()
+            .merge(("greeting", "hi"))
+            .merge(("nested", rocket::figment::value::Map::from([("flag", "on")])));
+
+        let rocket = rocket::custom(figment).mount("/", routes![value, profile]);
+        let client = Client::debug(rocket).unwrap();
+        assert_eq!(client.get("/greeting").dispatch().into_string().unwrap(), "hi");
+        assert_eq!(client.get("/nested.flag").dispatch().into_string().unwrap(), "on");
+        assert_eq!(client.get("/missing").dispatch().status(), Status::NotFound);
+        assert_eq!(client.get("/profile").dispatch().into_string().unwrap(), "debug");
+    }
+}
@@ -20,6 +20,10 @@ fn rocket() -> Rocket<Build> {
         .mount("/both", FileServer::new(&root, Options::DotFiles | Options::Index))
         .mount("/redir", FileServer::new(&root, Options::NormalizeDirs))
         .mount("/redir_index", FileServer::new(&root, Options::NormalizeDirs | Options::Index))
+        .mount("/compressed", FileServer::new(root, Options::Index | Options::Precompressed))
+        .mount("/ranges", FileServer::new(root, Options::Ranges | Options::Precompressed))
+        .mount("/compressed_ranges", FileServer::new(root, Options::CompressedRanges
+            | Options::Precompressed))
 }
 
 static REGULAR_FILES: &[&str] = &[
@@ -186,3 +190,208 @@ fn test_redirection() {
     assert_eq!(response.status(), Status::PermanentRedirect);
     assert_eq!(response.headers().get("Location").next(), Some("/redir_index/"));
 }
+
+#[test]
+fn test_precompressed() {
+    use rocket::http::{Header, ContentType};
+
+    fn read(path: &str) -> Vec<u8> {
+        std::fs::read(static_root().join(path)).expect("read file")
+    }
+
+    let client = Client::debug(rocket()).expect("valid rocket");
+    let get = |path: &str, accept: Option<&str>| {
+        let mut request = client.get(format!("/compressed/{}", path));
+        if let Some(accept) = accept {
+            request.add_header(Header::new("Accept-Encoding", accept.to_string()));
+        }
+
+        request.dispatch()
+    };
+
+    let cases = &[
+        (None, None),
+        (Some("identity"), None),
+        (Some("gzip"), Some(("gzip", ".gz"))),
+        (Some("gzip, br"), Some(("br", ".br"))),
+        (Some("br;q=0.5, gzip"), Some(("gzip", ".gz"))),
+        (Some("br;q=0, *"), Some(("gzip", ".gz"))),
+        (Some("*;q=0, identity"), None),
+        (Some("gzip, identity;q=0"), Some(("gzip", ".gz"))),
+        (Some("br;q=0.5, identity;q=0.8"), None),
+        (Some("gzip;q=0.5, br;q=0.5"), Some(("br", ".br"))),
+        (Some("gzip;q=0.5, identity;q=0.5"), Some(("gzip", ".gz"))),
+        (Some("gzip;Q=0.2, br;q=0.1"), Some(("gzip", ".gz"))),
+        (Some("br;q=2, gzip;q=0.1"), Some(("gzip", ".gz"))),
+        (Some("deflate"), None),
+    ];
+
+    for (accept, expected) in cases {
+        let response = get("other/hello.txt", *accept);
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::Plain));
+        assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+
+        let encoding = response.headers().get_one("Content-Encoding").map(|s| s.to_string());
+        assert_eq!(encoding.as_deref(), expected.map(|(e, _)| e), "{:?}", accept);
+
+        let ext = expected.map(|(_, ext)| ext).unwrap_or("");
+        assert_eq!(response.into_bytes().unwrap(), read(&format!("other/hello.txt{}", ext)));
+    }
+
+    // Files without a precompressed variant fall back to the original.
+    let response = get("inner/goodbye", Some("gzip, br"));
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Content-Encoding"), None);
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+    assert_eq!(response.into_bytes().unwrap(), read("inner/goodbye"));
+
+    // Forbidding the original without an acceptable variant is a 406.
+    let cases = &[
+        ("other/hello.txt", "*;q=0"),
+        ("other/hello.txt", "deflate, identity;q=0"),
+        ("other/hello.txt", "br;q=0, gzip;q=0, identity;q=0"),
+        ("inner/goodbye", "gzip, br, identity;q=0"),
+    ];
+
+    for (path, accept) in cases {
+        let response = get(path, Some(accept));
+        assert_eq!(response.status(), Status::NotAcceptable, "{}", accept);
+    }
+
+    // Without the option, variants aren't considered.
+    let response = client.get("/default/other/hello.txt")
+        .header(Header::new("Accept-Encoding", "gzip, br"))
+        .dispatch();
+
+    assert_eq!(response.headers().get_one("Content-Encoding"), None);
+    assert_eq!(response.headers().get_one("Vary"), None);
+    assert_eq!(response.into_bytes().unwrap(), read("other/hello.txt"));
+}
+
+#[test]
+fn test_ranges() {
+    use rocket::http::Header;
+
+    let client = Client::debug(rocket()).expect("valid rocket");
+    let get = |path: &str, range: &str| {
+        client.get(format!("/ranges/{}", path))
+            .header(Header::new("Range", range.to_string()))
+            .dispatch()
+    };
+
+    // "Thanks for coming!\n", 19 bytes.
+    let cases = &[
+        ("bytes=0-5", "bytes 0-5/19", "Thanks"),
+        ("bytes=7-9", "bytes 7-9/19", "for"),
+        ("bytes=11-", "bytes 11-18/19", "coming!\n"),
+        ("bytes=-8", "bytes 11-18/19", "coming!\n"),
+        ("bytes=-100", "bytes 0-18/19", "Thanks for coming!\n"),
+        ("bytes=11-100", "bytes 11-18/19", "coming!\n"),
+    ];
+
+    for (range, content_range, body) in cases {
+        let response = get("inner/goodbye", range);
+        assert_eq!(response.status(), Status::PartialContent, "{}", range);
+        assert_eq!(response.headers().get_one("Content-Range"), Some(*content_range));
+        assert_eq!(response.headers().get_one("Accept-Ranges"), Some("bytes"));
+        assert_eq!(response.into_string().unwrap(), *body);
+    }
+
+    for range in &["bytes=19-", "bytes=100-200", "bytes=-0"] {
+        let response = get("inner/goodbye", range);
+        assert_eq!(response.status(), Status::RangeNotSatisfiable, "{}", range);
+        assert_eq!(response.headers().get_one("Content-Range"), Some("bytes */19"));
+        assert!(response.into_string().unwrap_or_default().is_empty());
+    }
+
+    // Malformed and multiple ranges are ignored.
+    for range in &["bytes=5-2", "bytes=a-b", "items=0-5", "bytes=0-1, 4-5", "bytes=-"] {
+        let response = get("inner/goodbye", range);
+        assert_eq!(response.status(), Status::Ok, "{}", range);
+        assert_eq!(response.headers().get_one("Content-Range"), None);
+        assert_eq!(response.headers().get_one("Accept-Ranges"), Some("bytes"));
+        assert_eq!(response.into_string().unwrap(), "Thanks for coming!\n");
+    }
+
+    // A range with an `If-Range` is served only if it matches a validator.
+    let response = get("inner/goodbye", "bytes=0-5");
+    let etag = response.headers().get_one("ETag").unwrap().to_string();
+    let date = response.headers().get_one("Last-Modified").unwrap().to_string();
+    let get_if = |if_range: &str| {
+        client.get("/ranges/inner/goodbye")
+            .header(Header::new("Range", "bytes=0-5"))
+            .header(Header::new("If-Range", if_range.to_string()))
+            .dispatch()
+    };
+
+    for if_range in &[&*etag, &*date] {
+        let response = get_if(if_range);
+        assert_eq!(response.status(), Status::PartialContent, "{}", if_range);
+        assert_eq!(response.into_string().unwrap(), "Thanks");
+    }
+
+    let weak = format!("W/{}", etag);
+    for if_range in &["\"abc\"", &*weak, "Sun, 06 Nov 1994 08:49:37 GMT", "abc"] {
+        let response = get_if(if_range);
+        assert_eq!(response.status(), Status::Ok, "{}", if_range);
+        assert_eq!(response.headers().get_one("ETag"), Some(&*etag));
+        assert_eq!(response.into_string().unwrap(), "Thanks for coming!\n");
+    }
+
+    // Without the option, ranges aren't considered.
+    let response = client.get("/default/inner/goodbye")
+        .header(Header::new("Range", "bytes=0-5"))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Accept-Ranges"), None);
+    assert_eq!(response.into_string().unwrap(), "Thanks for coming!\n");
+}
+
+#[test]
+fn test_ranges_of_precompressed() {
+    use rocket::http::{Header, ContentType};
+
+    fn read(path: &str) -> Vec<u8> {
+        std::fs::read(static_root().join(path)).expect("read file")
+    }
+
+    let client = Client::debug(rocket()).expect("valid rocket");
+    let get = |prefix: &str, range: Option<&str>| {
+        let mut request = client.get(format!("/{}/other/hello.txt", prefix))
+            .header(Header::new("Accept-Encoding", "gzip"));
+
+        if let Some(range) = range {
+            request.add_header(Header::new("Range", range.to_string()));
+        }
+
+        request.dispatch()
+    };
+
+    // Without a range, the precompressed variant is served.
+    let response = get("ranges", None);
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.headers().get_one("Content-Encoding"), Some("gzip"));
+    assert_eq!(response.into_bytes().unwrap(), read("other/hello.txt.gz"));
+
+    // With one, the range is served from the original file by default...
+    let response = get("ranges", Some("bytes=1-2"));
+    assert_eq!(response.status(), Status::PartialContent);
+    assert_eq!(response.content_type(), Some(ContentType::Plain));
+    assert_eq!(response.headers().get_one("Content-Encoding"), None);
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+    assert_eq!(response.headers().get_one("Content-Range"), Some("bytes 1-2/4"));
+    assert_eq!(response.into_bytes().unwrap(), &read("other/hello.txt")[1..=2]);
+
+    // ...and from the variant with `CompressedRanges`.
+    let gz = read("other/hello.txt.gz");
+    let response = get("compressed_ranges", Some("bytes=1-2"));
+    assert_eq!(response.status(), Status::PartialContent);
+    assert_eq!(response.content_type(), Some(ContentType::Plain));
+    assert_eq!(response.headers().get_one("Content-Encoding"), Some("gzip"));
+    assert_eq!(response.headers().get_one("Vary"), Some("Accept-Encoding"));
+    let content_range = format!("bytes 1-2/{}", gz.len());
+    assert_eq!(response.headers().get_one("Content-Range"), Some(&*content_range));
+    assert_eq!(response.into_bytes().unwrap(), &gz[1..=2]);
+}
@@ -0,0 +1,93 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::{Form, Rfc3986, Strict};
+
+#[derive(FromForm)]
+struct Search<'r> {
+    term: &'r str,
+}
+
+#[get("/query?<q>&<rest..>")]
+fn query(q: &str, rest: Rfc3986<Search<'_>>) -> String {
+    format!("{}|{}", q, rest.term)
+}
+
+#[get("/query/strict?<q>&<opt>")]
+fn query_strict(q: Rfc3986<&str>, opt: Option<Rfc3986<String>>) -> String {
+    format!("{}|{:?}", q.into_inner(), opt.map(|o| o.into_inner()))
+}
+
+#[post("/form", data = "<form>")]
+fn form(form: Form<Search<'_>>) -> String {
+    form.term.to_string()
+}
+
+#[post("/form/strict", data = "<form>")]
+fn form_strict(form: Form<Strict<Rfc3986<Search<'_>>>>) -> String {
+    form.term.to_string()
+}
+
+mod plus_decoding_tests {
+    use super::*;
+    use rocket::http::ContentType;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        Client::debug_with(routes![query, query_strict, form, form_strict]).unwrap()
+    }
+
+    fn post(client: &Client, uri: &'static str, body: &'static str) -> String {
+        client.post(uri)
+            .header(ContentType::Form)
+            .body(body)
+            .dispatch()
+            .into_string()
+            .unwrap()
+    }
+
+    #[test]
+    fn query_guards_choose_decoding() {
+        let client = client();
+        let response = client.get("/query?q=a+b%20c&term=c++%2B%20d").dispatch();
+        assert_eq!(response.into_string().unwrap(), "a b c|c+++ d");
+
+        let response = client.get("/query/strict?q=c++&opt=x+y").dispatch();
+        assert_eq!(response.into_string().unwrap(), "c++|Some(\"x+y\")");
+
+        let response = client.get("/query/strict?q=a%20b").dispatch();
+        assert_eq!(response.into_string().unwrap(), "a b|None");
+    }
+
+    #[test]
+    fn form_guards_choose_decoding() {
+        let client = client();
+        assert_eq!(post(&client, "/form", "term=c++%2B"), "c  +");
+        assert_eq!(post(&client, "/form/strict", "term=c++%2B%20d"), "c+++ d");
+    }
+
+    #[test]
+    fn parse_encoded_respects_decoding() {
+        use rocket::http::RawStr;
+
+        #[derive(FromForm)]
+        struct Owned {
+            term: String,
+        }
+
+        let parsed: Owned = Form::parse_encoded(RawStr::new("term=a+b")).unwrap();
+        assert_eq!(parsed.term, "a b");
+
+        let parsed: Rfc3986<Owned> = Form::parse_encoded(RawStr::new("term=a+b")).unwrap();
+        assert_eq!(parsed.term, "a+b");
+    }
+
+    #[test]
+    fn query_value_respects_decoding() {
+        let client = client();
+        let request = client.get("/?q=a+b");
+        assert_eq!(request.query_value::<&str>("q").unwrap(), Ok("a b"));
+
+        let value = request.query_value::<Rfc3986<&str>>("q").unwrap().unwrap();
+        assert_eq!(value.into_inner(), "a+b");
+    }
+}
@@ -0,0 +1,102 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::Host;
+
+#[get("/")]
+fn index(host: Host<'_>) -> String {
+    format!("{} {:?}", host.host(), host.port())
+}
+
+#[get("/display")]
+fn display(host: Host<'_>) -> String {
+    host.to_string()
+}
+
+mod host_guard_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+
+    fn client(allowed: &[&str]) -> Client {
+        let allowed: Vec<String> = allowed.iter().map(|s| s.to_string()).collect();
+        let figment = rocket::Config::figment()
+            .merge((rocket::Config::ALLOWED_HOSTS, allowed));
+
+        Client::debug(rocket::custom(figment).mount("/", routes![index, display])).unwrap()
+    }
+
+    fn status(client: &Client, host: Option<&'static str>) -> Status {
+        let mut request = client.get("/");
+        if let Some(host) = host {
+            request.add_header(Header::new("Host", host));
+        }
+
+        request.dispatch().status()
+    }
+
+    #[test]
+    fn parses_host_and_port() {
+        let client = client(&[]);
+        let response = client.get("/").header(Header::new("Host", "Rocket.rs:8000")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "Rocket.rs Some(8000)");
+
+        let response = client.get("/").header(Header::new("Host", "[::1]")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "[::1] None");
+
+        let response = client.get("/display").header(Header::new("Host", "a.b:80")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "a.b:80");
+    }
+
+    #[test]
+    fn rejects_missing_and_malformed_hosts() {
+        let client = client(&[]);
+        assert_eq!(status(&client, None), Status::BadRequest);
+        assert_eq!(status(&client, Some("user@rocket.rs")), Status::BadRequest);
+        assert_eq!(status(&client, Some("rocket.rs:port")), Status::BadRequest);
+        assert_eq!(status(&client, Some("rocket.rs/path")), Status::BadRequest);
+    }
+
+    #[test]
+    fn allows_any_host_by_default() {
+        let client = client(&[]);
+        assert_eq!(status(&client, Some("anything.example")), Status::Ok);
+    }
+
+    #[test]
+    fn enforces_allowlist() {
+        let client = client(&["rocket.rs", "*.example.com", "localhost:8000"]);
+        assert_eq!(status(&client, Some("rocket.rs")), Status::Ok);
+        assert_eq!(status(&client, Some("ROCKET.RS:1234")), Status::Ok);
+        assert_eq!(status(&client, Some("api.example.com")), Status::Ok);
+        assert_eq!(status(&client, Some("a.b.example.com")), Status::Ok);
+        assert_eq!(status(&client, Some("localhost:8000")), Status::Ok);
+
+        assert_eq!(status(&client, Some("example.com")), Status::MisdirectedRequest);
+        assert_eq!(status(&client, Some("evilexample.com")), Status::MisdirectedRequest);
+        assert_eq!(status(&client, Some("rocket.rs.evil")), Status::MisdirectedRequest);
+        assert_eq!(status(&client, Some("localhost")), Status::MisdirectedRequest);
+        assert_eq!(status(&client, Some("localhost:80")), Status::MisdirectedRequest);
+    }
+}
+
+#[cfg(feature = "raw_requests")]
+mod absolute_form_host_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn body(client: &Client, request: &str) -> String {
+        let bytes = client.send_raw(request).into_bytes();
+        let response = String::from_utf8(bytes).unwrap();
+        response.split("\r\n\r\n").nth(1).unwrap().to_string()
+    }
+
+    #[test]
+    fn absolute_form_authority_takes_precedence_over_host() {
+        let client = Client::debug_with(routes![index, display]).unwrap();
+        let request = "GET http://user@rocket.rs:8000/ HTTP/1.1\r\nHost: evil.com\r\n\r\n";
+        assert_eq!(body(&client, request), "rocket.rs Some(8000)");
+
+        let request = "GET /display HTTP/1.1\r\nHost: rocket.rs:80\r\n\r\n";
+        assert_eq!(body(&client, request), "rocket.rs:80");
+    }
+}
@@ -0,0 +1,31 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Version;
+use rocket::request::TlsInfo;
+
+#[get("/")]
+fn version(version: Version) -> String {
+    version.to_string()
+}
+
+#[get("/tls")]
+fn tls(tls: &TlsInfo) -> String {
+    format!("{:?}", tls.version())
+}
+
+#[get("/tls", rank = 2)]
+fn plain() -> &'static str {
+    "plain"
+}
+
+mod http_version_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn local_requests_are_plain_http_1_1() {
+        let client = Client::debug_with(routes![version, tls, plain]).unwrap();
+        assert_eq!(client.get("/").dispatch().into_string().unwrap(), "HTTP/1.1");
+        assert_eq!(client.get("/tls").dispatch().into_string().unwrap(), "plain");
+    }
+}
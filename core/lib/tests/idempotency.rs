@@ -0,0 +1,272 @@
+#![cfg(feature = "idempotency")]
+
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rocket::{Rocket, Build, State};
+use rocket::http::{Header, Status};
+use rocket::idempotency::{Idempotency, MemoryStore, Store, Reservation};
+use rocket::response::CapturedResponse;
+use rocket::response::stream::TextStream;
+
+#[derive(Default)]
+struct Calls(AtomicUsize);
+
+#[post("/", data = "<body>")]
+fn create(body: &str, calls: &State<Calls>) -> (Status, String) {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    (Status::Created, format!("{}:{}", body, n))
+}
+
+#[get("/")]
+fn read(calls: &State<Calls>) -> String {
+    calls.0.fetch_add(1, Ordering::SeqCst).to_string()
+}
+
+#[post("/fail")]
+fn fail(calls: &State<Calls>) -> Status {
+    calls.0.fetch_add(1, Ordering::SeqCst);
+    Status::ServiceUnavailable
+}
+
+#[post("/big?<n>")]
+fn big(n: usize, calls: &State<Calls>) -> String {
+    let i = calls.0.fetch_add(1, Ordering::SeqCst);
+    format!("{}{}", i, "x".repeat(n))
+}
+
+#[post("/stream")]
+fn stream(calls: &State<Calls>) -> TextStream![String] {
+    let n = calls.0.fetch_add(1, Ordering::SeqCst);
+    TextStream! { yield n.to_string(); }
+}
+
+fn rocket(idempotency: Idempotency) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![create, read, fail, big, stream])
+        .manage(Calls::default())
+        .attach(idempotency)
+}
+
+fn key(value: &str) -> Header<'static> {
+    Header::new("Idempotency-Key", value.to_string())
+}
+
+mod idempotency_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn replays_recorded_response() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        let first = client.post("/").header(key("k1")).body("a").dispatch();
+        assert_eq!(first.status(), Status::Created);
+        assert!(first.headers().get_one("Idempotent-Replayed").is_none());
+        assert_eq!(first.into_string().unwrap(), "a:0");
+
+        let second = client.post("/").header(key("k1")).body("a").dispatch();
+        assert_eq!(second.status(), Status::Created);
+        assert_eq!(second.headers().get_one("Idempotent-Replayed"), Some("true"));
+        assert_eq!(second.content_type(), Some(rocket::http::ContentType::Plain));
+        assert_eq!(second.into_string().unwrap(), "a:0");
+
+        let other = client.post("/").header(key("k2")).body("b").dispatch();
+        assert_eq!(other.into_string().unwrap(), "b:1");
+    }
+
+    #[test]
+    fn requests_without_key_or_safe_are_ignored() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        assert_eq!(client.post("/").body("a").dispatch().into_string().unwrap(), "a:0");
+        assert_eq!(client.post("/").body("a").dispatch().into_string().unwrap(), "a:1");
+        assert_eq!(client.get("/").header(key("k")).dispatch().into_string().unwrap(), "2");
+        assert_eq!(client.get("/").header(key("k")).dispatch().into_string().unwrap(), "3");
+    }
+
+    #[test]
+    fn invalid_keys_are_rejected() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        let response = client.post("/").header(key("")).body("a").dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let long = "k".repeat(256);
+        let response = client.post("/").header(key(&long)).body("a").dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let calls = client.rocket().state::<Calls>().unwrap();
+        assert_eq!(calls.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn fingerprint_mismatch_is_rejected() {
+        let idempotency = Idempotency::default().fingerprint(true);
+        let client = Client::debug(rocket(idempotency)).unwrap();
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.into_string().unwrap(), "a:0");
+
+        let response = client.post("/").header(key("k")).body("b").dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+
+        let response = client.post("/?x").header(key("k")).body("a").dispatch();
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+        assert_eq!(response.into_string().unwrap(), "a:0");
+    }
+
+    #[test]
+    fn fingerprint_body_limit() {
+        use rocket::data::ToByteUnit;
+
+        let idempotency = Idempotency::default().fingerprint(true).body_limit(4.bytes());
+        let client = Client::debug(rocket(idempotency)).unwrap();
+        let response = client.post("/").header(key("k")).body("abcd").dispatch();
+        assert_eq!(response.into_string().unwrap(), "abcd:0");
+
+        let response = client.post("/").header(key("j")).body("abcde").dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn server_errors_are_not_recorded() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        for _ in 0..2 {
+            let response = client.post("/fail").header(key("k")).dispatch();
+            assert_eq!(response.status(), Status::ServiceUnavailable);
+            assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+        }
+
+        let calls = client.rocket().state::<Calls>().unwrap();
+        assert_eq!(calls.0.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn in_flight_keys_conflict() {
+        let store = MemoryStore::new();
+        let client = Client::debug(rocket(Idempotency::new(store.clone()))).unwrap();
+        let timeout = Duration::from_secs(60);
+        let token = match rocket::async_test(store.reserve("POST / k", None, timeout)) {
+            Reservation::Reserved(token) => token,
+            reservation => panic!("unexpected reservation: {:?}", reservation),
+        };
+
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.status(), Status::Conflict);
+
+        rocket::async_test(store.release("POST / k", token));
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.into_string().unwrap(), "a:0");
+    }
+
+    #[test]
+    fn recorded_responses_expire() {
+        let idempotency = Idempotency::default().ttl(Duration::from_millis(50));
+        let client = Client::debug(rocket(idempotency)).unwrap();
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.into_string().unwrap(), "a:0");
+
+        std::thread::sleep(Duration::from_millis(100));
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+        assert_eq!(response.into_string().unwrap(), "a:1");
+    }
+
+    #[test]
+    fn default_idempotency_fails_if_store_is_managed() {
+        let rocket = rocket(Idempotency::default()).manage(MemoryStore::new());
+        let error = Client::debug(rocket).unwrap_err();
+        assert!(matches!(error.kind(), rocket::error::ErrorKind::FailedFairings(_)));
+    }
+
+    #[test]
+    fn stale_reservations_cannot_complete_or_release() {
+        let store = MemoryStore::new();
+        let reserve = |timeout| match rocket::async_test(store.reserve("k", None, timeout)) {
+            Reservation::Reserved(token) => token,
+            reservation => panic!("unexpected reservation: {:?}", reservation),
+        };
+
+        // The first reservation times out and the key is reserved anew.
+        let stale = reserve(Duration::from_millis(10));
+        std::thread::sleep(Duration::from_millis(20));
+        let fresh = reserve(Duration::from_secs(60));
+        assert_ne!(stale, fresh);
+
+        let response = |body: &str| CapturedResponse::new(Status::Ok, vec![], body.into());
+        let ttl = Duration::from_secs(60);
+        rocket::async_test(store.complete("k", stale, response("stale"), ttl));
+        rocket::async_test(store.release("k", stale));
+        let timeout = Duration::from_secs(60);
+        let reservation = rocket::async_test(store.reserve("k", None, timeout));
+        assert_eq!(reservation, Reservation::InFlight);
+
+        rocket::async_test(store.complete("k", fresh, response("fresh"), ttl));
+        let reservation = rocket::async_test(store.reserve("k", None, timeout));
+        assert_eq!(reservation, Reservation::Completed(response("fresh")));
+
+        // A completed key can't be released with its reservation's token.
+        rocket::async_test(store.release("k", fresh));
+        assert!(matches!(rocket::async_test(store.reserve("k", None, timeout)),
+            Reservation::Completed(_)));
+    }
+
+    #[test]
+    fn unsized_responses_are_not_recorded() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        let response = client.post("/stream").header(key("k")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "0");
+
+        let response = client.post("/stream").header(key("k")).dispatch();
+        assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+        assert_eq!(response.into_string().unwrap(), "1");
+    }
+
+    #[test]
+    fn keys_are_scoped_to_method_and_path() {
+        let client = Client::debug(rocket(Idempotency::default())).unwrap();
+        let response = client.post("/big?n=1").header(key("k")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "0x");
+
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+        assert_eq!(response.into_string().unwrap(), "a:1");
+
+        let response = client.post("/big?n=2").header(key("k")).dispatch();
+        assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+        assert_eq!(response.into_string().unwrap(), "0x");
+    }
+
+    #[test]
+    fn unscoped_keys_are_shared_across_routes() {
+        let client = Client::debug(rocket(Idempotency::default().scope_keys(false))).unwrap();
+        let response = client.post("/big?n=1").header(key("k")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "0x");
+
+        let response = client.post("/").header(key("k")).body("a").dispatch();
+        assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+        assert_eq!(response.into_string().unwrap(), "0x");
+    }
+
+    #[test]
+    fn responses_over_the_limit_are_not_recorded() {
+        use rocket::data::ToByteUnit;
+
+        let idempotency = Idempotency::default().response_limit(4.bytes());
+        let client = Client::debug(rocket(idempotency)).unwrap();
+        for i in 0..2 {
+            let response = client.post("/big?n=4").header(key("k")).dispatch();
+            assert!(response.headers().get_one("Idempotent-Replayed").is_none());
+            assert_eq!(response.into_string().unwrap(), format!("{}xxxx", i));
+        }
+
+        let response = client.post("/big?n=3").header(key("j")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "2xxx");
+
+        let response = client.post("/big?n=3").header(key("j")).dispatch();
+        assert_eq!(response.headers().get_one("Idempotent-Replayed"), Some("true"));
+        assert_eq!(response.into_string().unwrap(), "2xxx");
+    }
+}
@@ -0,0 +1,107 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::request::ItemRange;
+use rocket::response::{Paginated, status};
+
+const ITEMS: &[u8] = &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+#[get("/?<total>")]
+fn items(
+    range: Option<ItemRange>,
+    total: Option<u64>,
+) -> Result<Paginated<String>, status::Custom<Paginated<()>>> {
+    let len = ITEMS.len() as u64;
+    let range = range.unwrap_or_default().limit(4);
+    let unsatisfiable = || status::Custom(Status::RangeNotSatisfiable, Paginated::empty((), len));
+    let indices = range.resolve(len).ok_or_else(unsatisfiable)?;
+
+    let (start, end) = (*indices.start() as usize, *indices.end() as usize);
+    let body = format!("{:?}", &ITEMS[start..=end]);
+    Ok(Paginated::new(body, indices, total.or(Some(len))).unwrap())
+}
+
+#[get("/guard")]
+fn guard(range: ItemRange) -> String {
+    format!("{:?}-{:?}", range.start(), range.end())
+}
+
+#[get("/empty")]
+fn empty() -> Paginated<&'static str> {
+    Paginated::empty("[]", 0)
+}
+
+mod item_range_tests {
+    use super::*;
+    use rocket::http::Header;
+    use rocket::local::blocking::{Client, LocalResponse};
+
+    fn get<'c>(client: &'c Client, uri: &'static str, range: &'static str) -> LocalResponse<'c> {
+        client.get(uri).header(Header::new("Range", range)).dispatch()
+    }
+
+    #[test]
+    fn item_ranges_are_paginated() {
+        let client = Client::debug_with(routes![items, guard, empty]).unwrap();
+        let cases = &[
+            ("items=0-1", Status::PartialContent, "items 0-1/10", "[0, 1]"),
+            ("items=8-", Status::PartialContent, "items 8-9/10", "[8, 9]"),
+            ("Items=2-", Status::PartialContent, "items 2-5/10", "[2, 3, 4, 5]"),
+            ("items=9-20", Status::PartialContent, "items 9-9/10", "[9]"),
+        ];
+
+        for &(range, status, content_range, body) in cases {
+            let response = get(&client, "/", range);
+            assert_eq!(response.status(), status, "{}", range);
+            assert_eq!(response.headers().get_one("Content-Range"), Some(content_range));
+            assert_eq!(response.headers().get_one("Accept-Ranges"), Some("items"));
+            assert_eq!(response.into_string().unwrap(), body);
+        }
+
+        let response = client.get("/").dispatch();
+        assert_eq!(response.status(), Status::PartialContent);
+        assert_eq!(response.headers().get_one("Content-Range"), Some("items 0-3/10"));
+    }
+
+    #[test]
+    fn complete_and_empty_collections_are_ok() {
+        let client = Client::debug_with(routes![items, guard, empty]).unwrap();
+        let response = get(&client, "/?total=2", "items=0-1");
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("Content-Range"), Some("items 0-1/2"));
+
+        let response = client.get("/empty").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.headers().get_one("Content-Range"), Some("items */0"));
+    }
+
+    #[test]
+    fn unsatisfiable_ranges_are_416() {
+        let client = Client::debug_with(routes![items, guard, empty]).unwrap();
+        let response = get(&client, "/", "items=10-");
+        assert_eq!(response.status(), Status::RangeNotSatisfiable);
+        assert_eq!(response.headers().get_one("Content-Range"), Some("items */10"));
+    }
+
+    #[test]
+    fn malformed_and_foreign_ranges() {
+        let client = Client::debug_with(routes![items, guard, empty]).unwrap();
+        let response = get(&client, "/guard", "items=3-");
+        assert_eq!(response.into_string().unwrap(), "3-None");
+
+        for range in &["items=5-2", "items=-3", "items=0-1,4-5", "items=", "items=a-b"] {
+            let response = get(&client, "/guard", range);
+            assert_eq!(response.status(), Status::BadRequest, "{}", range);
+        }
+
+        // Ranges in other units, malformed or not, forward.
+        for range in &["bytes=0-9", "bytes=5-2", "0-9"] {
+            let response = get(&client, "/guard", range);
+            assert_eq!(response.status(), Status::NotFound, "{}", range);
+        }
+
+        // `Option<ItemRange>` is `None` for any unusable range.
+        let response = get(&client, "/", "items=5-2");
+        assert_eq!(response.headers().get_one("Content-Range"), Some("items 0-3/10"));
+    }
+}
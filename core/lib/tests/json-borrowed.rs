@@ -0,0 +1,60 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use std::borrow::Cow;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket::serde::{Deserialize, json::Json};
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Message<'r> {
+    channel: &'r str,
+    #[serde(borrow)]
+    text: Cow<'r, str>,
+}
+
+#[post("/", data = "<message>")]
+fn message(message: Json<Message<'_>>) -> String {
+    let kind = match message.text {
+        Cow::Borrowed(_) => "borrowed",
+        Cow::Owned(_) => "owned",
+    };
+
+    format!("{}: {} ({})", message.channel, message.text, kind)
+}
+
+fn client() -> Client {
+    let figment = rocket::Config::figment().merge(("limits.json", 64));
+    Client::debug(rocket::custom(figment).mount("/", routes![message])).unwrap()
+}
+
+#[test]
+fn fields_borrow_from_the_body() {
+    let client = client();
+    let response = client.post("/").body(r#"{ "channel": "news", "text": "hi" }"#).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "news: hi (borrowed)");
+
+    let response = client.post("/").body(r#"{ "channel": "news", "text": "h\u00ed" }"#).dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "news: hí (owned)");
+}
+
+#[test]
+fn escaped_strings_cannot_be_borrowed() {
+    let client = client();
+    let body = r#"{ "channel": "n\news", "text": "hi" }"#;
+    let response = client.post("/").body(body).dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn bodies_over_the_limit_are_rejected() {
+    let client = client();
+    let body = format!(r#"{{ "channel": "news", "text": "{}" }}"#, "a".repeat(64));
+    let response = client.post("/").body(body).dispatch();
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+}
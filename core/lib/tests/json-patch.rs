@@ -0,0 +1,164 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::{ContentType, Status};
+use rocket::local::blocking::Client;
+use rocket::serde::{Serialize, Deserialize};
+use rocket::serde::json::{json, serde_json, Json, Value};
+use rocket::serde::json::{MergePatch, JsonPatch, PatchOperation, PatchError};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct User {
+    name: String,
+    bio: Option<String>,
+    tags: Vec<String>,
+}
+
+fn user() -> User {
+    User { name: "Bob".into(), bio: Some("hi".into()), tags: vec!["a".into()] }
+}
+
+#[patch("/merge", data = "<patch>")]
+fn merge(patch: MergePatch<User>) -> Result<Json<User>, PatchError> {
+    let mut user = user();
+    patch.apply(&mut user)?;
+    Ok(Json(user))
+}
+
+#[patch("/json", data = "<patch>")]
+fn patch_user(patch: JsonPatch) -> Result<Json<User>, PatchError> {
+    let mut user = user();
+    patch.apply(&mut user)?;
+    Ok(Json(user))
+}
+
+fn client() -> Client {
+    Client::debug(rocket::build().mount("/", routes![merge, patch_user])).unwrap()
+}
+
+fn merge_patch(target: Value, patch: Value) -> Value {
+    let mut target = target;
+    MergePatch::<Value>::from(patch).apply_value(&mut target);
+    target
+}
+
+fn json_patch(patch: Value) -> JsonPatch {
+    let operations: Vec<PatchOperation> = serde_json::from_value(patch).unwrap();
+    JsonPatch::from(operations)
+}
+
+fn json_patch_value(target: Value, patch: Value) -> Result<Value, PatchError> {
+    let mut target = target;
+    json_patch(patch).apply_value(&mut target).map(|_| target)
+}
+
+#[test]
+fn merge_patch_follows_rfc_7386() {
+    // The test cases of RFC 7386 Appendix A.
+    let cases = vec![
+        (json!({"a":"b"}), json!({"a":"c"}), json!({"a":"c"})),
+        (json!({"a":"b"}), json!({"b":"c"}), json!({"a":"b","b":"c"})),
+        (json!({"a":"b"}), json!({"a":null}), json!({})),
+        (json!({"a":"b","b":"c"}), json!({"a":null}), json!({"b":"c"})),
+        (json!({"a":["b"]}), json!({"a":"c"}), json!({"a":"c"})),
+        (json!({"a":"c"}), json!({"a":["b"]}), json!({"a":["b"]})),
+        (json!({"a":{"b":"c"}}), json!({"a":{"b":"d","c":null}}), json!({"a":{"b":"d"}})),
+        (json!({"a":[{"b":"c"}]}), json!({"a":[1]}), json!({"a":[1]})),
+        (json!(["a","b"]), json!(["c","d"]), json!(["c","d"])),
+        (json!({"a":"b"}), json!(["c"]), json!(["c"])),
+        (json!({"a":"foo"}), json!(null), json!(null)),
+        (json!({"a":"foo"}), json!("bar"), json!("bar")),
+        (json!({"e":null}), json!({"a":1}), json!({"e":null,"a":1})),
+        (json!([1,2]), json!({"a":"b","c":null}), json!({"a":"b"})),
+        (json!({}), json!({"a":{"bb":{"ccc":null}}}), json!({"a":{"bb":{}}})),
+    ];
+
+    for (target, patch, expected) in cases {
+        assert_eq!(merge_patch(target, patch.clone()), expected, "patch: {}", patch);
+    }
+}
+
+#[test]
+fn json_patch_follows_rfc_6902() {
+    let patch = json!([
+        { "op": "test", "path": "/a/b/c", "value": "foo" },
+        { "op": "remove", "path": "/a/b/c" },
+        { "op": "add", "path": "/a/b/c", "value": ["foo", "bar"] },
+        { "op": "replace", "path": "/a/b/c", "value": 42 },
+        { "op": "move", "from": "/a/b/c", "path": "/a/b/d" },
+        { "op": "copy", "from": "/a/b/d", "path": "/a/b/e" },
+        { "op": "add", "path": "/list/1", "value": "x" },
+        { "op": "add", "path": "/list/-", "value": "z" },
+        { "op": "add", "path": "/m~1n", "value": true, "ignored": 1 },
+    ]);
+
+    let target = json!({ "a": { "b": { "c": "foo" } }, "list": ["w", "y"] });
+    let expected = json!({
+        "a": { "b": { "d": 42, "e": 42 } },
+        "list": ["w", "x", "y", "z"],
+        "m/n": true,
+    });
+
+    assert_eq!(json_patch_value(target, patch).unwrap(), expected);
+    assert_eq!(json_patch_value(json!({}), json!([{ "op": "add", "path": "", "value": 1 }]))
+        .unwrap(), json!(1));
+}
+
+#[test]
+fn failed_json_patches_are_atomic() {
+    let failures = vec![
+        json!([{ "op": "remove", "path": "/missing" }]),
+        json!([{ "op": "replace", "path": "/missing", "value": 1 }]),
+        json!([{ "op": "add", "path": "/missing/child", "value": 1 }]),
+        json!([{ "op": "add", "path": "/list/5", "value": 1 }]),
+        json!([{ "op": "add", "path": "/list/01", "value": 1 }]),
+        json!([{ "op": "move", "from": "/a", "path": "/a/b" }]),
+        json!([{ "op": "test", "path": "/a", "value": 2 }]),
+        json!([{ "op": "add", "path": "/b", "value": 1 }, { "op": "remove", "path": "" }]),
+    ];
+
+    for patch in failures {
+        let mut target = json!({ "a": { "b": 1 }, "list": [] });
+        let result = json_patch(patch.clone()).apply_value(&mut target);
+        assert!(result.is_err(), "patch: {}", patch);
+        assert_eq!(target, json!({ "a": { "b": 1 }, "list": [] }), "patch: {}", patch);
+    }
+}
+
+#[test]
+fn patch_guards_map_errors_to_statuses() {
+    let client = client();
+    let response = client.patch("/merge")
+        .header(ContentType::new("application", "merge-patch+json"))
+        .body(r#"{ "bio": null, "tags": ["b"] }"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    let expected = User { name: "Bob".into(), bio: None, tags: vec!["b".into()] };
+    assert_eq!(response.into_json::<User>().unwrap(), expected);
+
+    let response = client.patch("/json")
+        .body(r#"[{ "op": "add", "path": "/tags/0", "value": "z" }]"#)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_json::<User>().unwrap().tags, ["z", "a"]);
+
+    let cases = vec![
+        ("/merge", r#"{ "bio": "#, Status::BadRequest),
+        ("/merge", r#"{ "name": null }"#, Status::UnprocessableEntity),
+        ("/json", r#"[{ "op": "add", "path": "/tags/0""#, Status::BadRequest),
+        ("/json", r#"[{ "op": "frobnicate", "path": "/tags" }]"#, Status::UnprocessableEntity),
+        ("/json", r#"[{ "op": "remove", "path": "tags" }]"#, Status::UnprocessableEntity),
+        ("/json", r#"[{ "op": "remove", "path": "/missing" }]"#, Status::UnprocessableEntity),
+        ("/json", r#"[{ "op": "replace", "path": "/name", "value": 1 }]"#,
+            Status::UnprocessableEntity),
+    ];
+
+    for (uri, body, status) in cases {
+        let response = client.patch(uri).body(body).dispatch();
+        assert_eq!(response.status(), status, "{} {}", uri, body);
+    }
+}
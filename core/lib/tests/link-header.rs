@@ -0,0 +1,91 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Link, LinkSet};
+
+#[get("/items?<page>")]
+fn items(page: usize) -> (LinkSet, String) {
+    let mut links: LinkSet = vec![(uri!(items(page = page + 1)), "next")].into_iter().collect();
+    if page > 1 {
+        links.push(Link::new(uri!(items(page = page - 1)), "prev").param("title", "Page \"prev\""));
+    }
+
+    (links, format!("page {}", page))
+}
+
+#[get("/links")]
+fn links(links: LinkSet) -> String {
+    links.iter()
+        .map(|link| format!("{} {}", link.rel().unwrap_or("-"), link.uri()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[get("/links", rank = 2)]
+fn no_links() -> &'static str {
+    "no links"
+}
+
+mod link_header_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::{Header, Status};
+
+    fn client() -> Client {
+        Client::debug_with(routes![items, links, no_links]).unwrap()
+    }
+
+    #[test]
+    fn responder_sets_a_single_link_header() {
+        let client = client();
+        let response = client.get("/items?page=1").dispatch();
+        assert_eq!(response.headers().get_one("Link"), Some(r#"</items?page=2>; rel="next""#));
+
+        let response = client.get("/items?page=2").dispatch();
+        assert_eq!(response.headers().get("Link").count(), 1);
+        let expected = concat!(r#"</items?page=3>; rel="next", "#,
+            r#"</items?page=1>; rel="prev"; title="Page \"prev\"""#);
+        assert_eq!(response.headers().get_one("Link"), Some(expected));
+        assert_eq!(response.into_string().unwrap(), "page 2");
+    }
+
+    #[test]
+    fn round_trips_through_the_guard() {
+        let client = client();
+        let response = client.get("/items?page=5").dispatch();
+        let value = response.headers().get_one("Link").unwrap().to_string();
+
+        let links: LinkSet = value.parse().unwrap();
+        assert_eq!(links.find("prev").unwrap().get_param("title"), Some("Page \"prev\""));
+
+        let response = client.get("/links").header(Header::new("Link", value)).dispatch();
+        assert_eq!(response.into_string().unwrap(), "next /items?page=6, prev /items?page=4");
+    }
+
+    #[test]
+    fn guard_joins_multiple_headers() {
+        let client = client();
+        let response = client.get("/links")
+            .header(Header::new("Link", "</a>; rel=first, <https://rocket.rs/b?x=1>; rel=next"))
+            .header(Header::new("Link", "</c>; anchor=\"#x\""))
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), "first /a, next https://rocket.rs/b?x=1, - /c");
+    }
+
+    #[test]
+    fn guard_forwards_or_fails() {
+        let client = client();
+        assert_eq!(client.get("/links").dispatch().into_string().unwrap(), "no links");
+
+        for bad in &["/a; rel=next", "</a>; rel=\"next", "</a> rel=next", "<a b>; rel=next"] {
+            let response = client.get("/links").header(Header::new("Link", *bad)).dispatch();
+            assert_eq!(response.status(), Status::BadRequest, "{:?}", bad);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid Link parameter name")]
+    fn param_names_must_be_tokens() {
+        let _ = Link::new(uri!("/"), "index").param("a b", "c");
+    }
+}
@@ -0,0 +1,127 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Cookie, CookieJar, Method};
+use rocket::response::Redirect;
+
+#[get("/login")]
+fn login(jar: &CookieJar<'_>) -> Redirect {
+    jar.add(Cookie::new("session", "1234"));
+    Redirect::to(uri!(dashboard))
+}
+
+#[get("/dashboard")]
+fn dashboard(jar: &CookieJar<'_>) -> String {
+    match jar.get("session") {
+        Some(cookie) => format!("session: {}", cookie.value()),
+        None => "no session".into(),
+    }
+}
+
+#[post("/submit", data = "<body>")]
+fn submit(body: String) -> Redirect {
+    match body.as_str() {
+        "temporary" => Redirect::temporary(uri!(echo)),
+        "permanent" => Redirect::permanent(uri!(echo)),
+        _ => Redirect::to(uri!(echo)),
+    }
+}
+
+#[get("/echo")]
+fn echo_get() -> &'static str {
+    "GET"
+}
+
+#[post("/echo", data = "<body>")]
+fn echo(body: String) -> String {
+    format!("POST {}", body)
+}
+
+#[get("/loop")]
+fn loop_forever() -> Redirect {
+    Redirect::to(uri!(loop_forever))
+}
+
+#[get("/external")]
+fn external() -> Redirect {
+    Redirect::to("https://rocket.rs")
+}
+
+mod follow_redirects_tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::blocking::Client;
+
+    fn rocket() -> rocket::Rocket<rocket::Build> {
+        rocket::build().mount("/", routes![
+            login, dashboard, submit, echo_get, echo, loop_forever, external
+        ])
+    }
+
+    #[test]
+    fn redirects_not_followed_by_default() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/login").dispatch();
+        assert_eq!(response.status(), Status::SeeOther);
+        assert_eq!(response.headers().get_one("Location"), Some("/dashboard"));
+    }
+
+    #[test]
+    fn follows_redirects_with_tracked_cookies() {
+        let client = Client::debug(rocket()).unwrap().follow_redirects(5);
+        let response = client.get("/login").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "session: 1234");
+    }
+
+    #[test]
+    fn untracked_client_does_not_propagate_cookies() {
+        let client = Client::untracked(rocket()).unwrap().follow_redirects(5);
+        let response = client.get("/login").dispatch();
+        assert_eq!(response.into_string().unwrap(), "no session");
+    }
+
+    #[test]
+    fn method_and_body_preserved_only_for_307_and_308() {
+        let client = Client::debug(rocket()).unwrap().follow_redirects(5);
+        for (body, expected) in &[
+            ("temporary", "POST temporary"),
+            ("permanent", "POST permanent"),
+            ("other", "GET"),
+        ] {
+            let response = client.req(Method::Post, "/submit").body(body).dispatch();
+            assert_eq!(response.status(), Status::Ok);
+            assert_eq!(response.into_string().unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn stops_after_max_redirects() {
+        let client = Client::debug(rocket()).unwrap().follow_redirects(3);
+        let response = client.get("/loop").dispatch();
+        assert_eq!(response.status(), Status::SeeOther);
+        assert_eq!(response.headers().get_one("Location"), Some("/loop"));
+    }
+
+    #[test]
+    fn does_not_follow_non_origin_redirects() {
+        let client = Client::debug(rocket()).unwrap().follow_redirects(3);
+        let response = client.get("/external").dispatch();
+        assert_eq!(response.status(), Status::SeeOther);
+        assert_eq!(response.headers().get_one("Location"), Some("https://rocket.rs"));
+    }
+}
+
+mod async_follow_redirects_tests {
+    use super::*;
+    use rocket::http::Status;
+    use rocket::local::asynchronous::Client;
+
+    #[rocket::async_test]
+    async fn follows_redirects() {
+        let rocket = rocket::build().mount("/", routes![login, dashboard]);
+        let client = Client::tracked(rocket).await.unwrap().follow_redirects(1);
+        let response = client.get("/login").dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().await.unwrap(), "session: 1234");
+    }
+}
@@ -0,0 +1,81 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::Header;
+
+#[derive(Responder)]
+#[response(content_type = "text")]
+struct Secured {
+    body: &'static str,
+    frame: Header<'static>,
+    nosniff: Header<'static>,
+}
+
+#[get("/")]
+fn index() -> Secured {
+    Secured {
+        body: "secured",
+        frame: Header::new("X-Frame-Options", "SAMEORIGIN"),
+        nosniff: Header::new("X-Content-Type-Options", "nosniff"),
+    }
+}
+
+mod local_response_headers_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        Client::debug_with(routes![index]).unwrap()
+    }
+
+    #[test]
+    fn headers_contains_matches_name_and_value() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        assert!(response.headers_contains("X-Frame-Options", "SAMEORIGIN"));
+        assert!(response.headers_contains("x-frame-options", "SAMEORIGIN"));
+        assert!(response.headers_contains("Content-Type", "text/plain; charset=utf-8"));
+        assert!(!response.headers_contains("X-Frame-Options", "sameorigin"));
+        assert!(!response.headers_contains("X-Frame-Options", "DENY"));
+        assert!(!response.headers_contains("Strict-Transport-Security", "max-age=31536000"));
+    }
+
+    #[test]
+    fn assert_headers_accepts_present_headers() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        response.assert_headers(&[]);
+        response.assert_headers(&[
+            ("x-content-type-options", "nosniff"),
+            ("X-Frame-Options", "SAMEORIGIN"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "X-Frame-Options: expected \"DENY\", found [\"SAMEORIGIN\"]")]
+    fn assert_headers_panics_on_mismatch() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        response.assert_headers(&[("X-Frame-Options", "DENY")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Referrer-Policy: expected \"no-referrer\", header missing")]
+    fn assert_headers_panics_on_missing() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        response.assert_headers(&[
+            ("X-Content-Type-Options", "nosniff"),
+            ("Referrer-Policy", "no-referrer"),
+        ]);
+    }
+
+    #[rocket::async_test]
+    async fn async_assert_headers() {
+        use rocket::local::asynchronous::Client;
+
+        let client = Client::debug_with(routes![index]).await.unwrap();
+        let response = client.get("/").dispatch().await;
+        assert!(response.headers_contains("X-Content-Type-Options", "nosniff"));
+        response.assert_headers(&[("X-Frame-Options", "SAMEORIGIN")]);
+    }
+}
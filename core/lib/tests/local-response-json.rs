@@ -0,0 +1,70 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::ContentType;
+use rocket::serde::json::{json, Value};
+
+#[get("/")]
+fn index() -> (ContentType, &'static str) {
+    (ContentType::JSON, r#"{ "b": [1, 2], "a": { "y": null, "x": "hi" } }"#)
+}
+
+#[get("/value")]
+fn value() -> Value {
+    json!({ "a": { "x": "hi", "y": null }, "b": [1, 2] })
+}
+
+#[get("/text")]
+fn text() -> &'static str {
+    "not json"
+}
+
+mod local_response_json_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        Client::debug_with(routes![index, value, text]).unwrap()
+    }
+
+    #[test]
+    fn into_json_value_ignores_key_order() {
+        let client = client();
+        let expected = json!({ "a": { "x": "hi", "y": null }, "b": [1, 2] });
+        assert_eq!(client.get("/").dispatch().into_json_value(), Some(expected.clone()));
+        assert_eq!(client.get("/value").dispatch().into_json_value(), Some(expected));
+        assert_eq!(client.get("/text").dispatch().into_json_value(), None);
+    }
+
+    #[test]
+    fn assert_json_eq_compares_structurally() {
+        let client = client();
+        let reordered = json!({ "b": [1, 2], "a": { "x": "hi", "y": null } });
+        client.get("/").dispatch().assert_json_eq(&reordered);
+
+        let expected = client.get("/").dispatch().into_json_value().unwrap();
+        client.get("/value").dispatch().assert_json_eq(expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "JSON mismatch")]
+    fn assert_json_eq_panics_on_mismatch() {
+        client().get("/").dispatch().assert_json_eq(json!({ "b": [2, 1], "a": { "x": "hi" } }));
+    }
+
+    #[test]
+    #[should_panic(expected = "body is not JSON")]
+    fn assert_json_eq_panics_on_non_json() {
+        client().get("/text").dispatch().assert_json_eq(json!("not json"));
+    }
+
+    #[rocket::async_test]
+    async fn async_into_json_value() {
+        use rocket::local::asynchronous::Client;
+
+        let client = Client::debug_with(routes![index]).await.unwrap();
+        let response = client.get("/").dispatch().await;
+        response.assert_json_eq(json!({ "a": { "x": "hi", "y": null }, "b": [1, 2] })).await;
+    }
+}
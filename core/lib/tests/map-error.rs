@@ -0,0 +1,128 @@
+#[macro_use] extern crate rocket;
+
+use std::fmt;
+
+use rocket::http::Status;
+use rocket::request::Request;
+use rocket::response::{self, Responder, Fault};
+use rocket::local::blocking::Client;
+
+#[derive(Debug)]
+enum AppError {
+    Missing,
+    Denied,
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl std::error::Error for AppError { }
+
+#[derive(Debug)]
+struct Direct;
+
+impl fmt::Display for Direct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("direct")
+    }
+}
+
+impl std::error::Error for Direct { }
+
+impl<'r> Responder<'r, 'static> for Direct {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        Fault::from(self).respond_to(req)
+    }
+}
+
+fn lookup(id: usize) -> Result<&'static str, AppError> {
+    match id {
+        0 => Ok("zero"),
+        1 => Err(AppError::Missing),
+        _ => Err(AppError::Denied),
+    }
+}
+
+#[get("/app/<id>")]
+fn app(id: usize) -> Result<&'static str, Fault> {
+    Ok(lookup(id)?)
+}
+
+#[get("/parse/<n>")]
+fn parse(n: &str) -> Result<String, Fault> {
+    let n: u8 = n.parse()?;
+    Ok(n.to_string())
+}
+
+#[get("/fmt")]
+fn format() -> Result<&'static str, Fault> {
+    Err(fmt::Error.into())
+}
+
+#[get("/direct")]
+fn direct() -> Result<&'static str, Direct> {
+    Err(Direct)
+}
+
+#[catch(default)]
+fn catcher(status: Status, _: &Request<'_>) -> String {
+    format!("caught {}", status.code)
+}
+
+fn client_for(rocket: rocket::Rocket<rocket::Build>) -> Client {
+    let rocket = rocket
+        .mount("/", routes![app, parse, format, direct])
+        .register("/", catchers![catcher]);
+
+    Client::debug(rocket).unwrap()
+}
+
+#[test]
+fn registered_mappings_determine_status() {
+    let client = client_for(rocket::build()
+        .map_error(|e: &AppError| match e {
+            AppError::Missing => Status::NotFound,
+            AppError::Denied => Status::Forbidden,
+        })
+        .map_error(|_: &std::num::ParseIntError| Status::BadRequest)
+        .map_error(|_: &Direct| Status::ImATeapot));
+
+    let response = client.get("/app/0").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "zero");
+
+    let response = client.get("/app/1").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "caught 404");
+
+    assert_eq!(client.get("/app/2").dispatch().status(), Status::Forbidden);
+    assert_eq!(client.get("/parse/10").dispatch().status(), Status::Ok);
+    assert_eq!(client.get("/parse/1000").dispatch().status(), Status::BadRequest);
+    assert_eq!(client.get("/direct").dispatch().status(), Status::ImATeapot);
+}
+
+#[test]
+fn unmapped_errors_are_internal_errors() {
+    let client = client_for(rocket::build().map_error(|_: &AppError| Status::NotFound));
+    assert_eq!(client.get("/app/1").dispatch().status(), Status::NotFound);
+    assert_eq!(client.get("/parse/x").dispatch().status(), Status::InternalServerError);
+    assert_eq!(client.get("/fmt").dispatch().status(), Status::InternalServerError);
+    assert_eq!(client.get("/direct").dispatch().status(), Status::InternalServerError);
+
+    let unmapped = client_for(rocket::build());
+    assert_eq!(unmapped.get("/app/1").dispatch().status(), Status::InternalServerError);
+}
+
+#[test]
+fn last_registered_mapping_wins() {
+    let client = client_for(rocket::build()
+        .map_error(|_: &AppError| Status::NotFound)
+        .map_error(|_: &fmt::Error| Status::ServiceUnavailable)
+        .map_error(|_: &AppError| Status::Gone));
+
+    assert_eq!(client.get("/app/1").dispatch().status(), Status::Gone);
+    assert_eq!(client.get("/fmt").dispatch().status(), Status::ServiceUnavailable);
+}
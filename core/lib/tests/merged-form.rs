@@ -0,0 +1,147 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::{Form, Merged, Strict};
+
+#[derive(Debug, FromForm)]
+struct Comment<'r> {
+    #[field(source = query)]
+    page: usize,
+    #[field(source = data)]
+    body: &'r str,
+    tags: Vec<&'r str>,
+}
+
+impl Comment<'_> {
+    fn describe(&self) -> String {
+        format!("{} {} {:?}", self.page, self.body, self.tags)
+    }
+}
+
+#[post("/merged", data = "<comment>")]
+fn merged(comment: Merged<Comment<'_>>) -> String {
+    comment.describe()
+}
+
+#[post("/strict", data = "<comment>")]
+fn strict(comment: Merged<Strict<Comment<'_>>>) -> String {
+    comment.describe()
+}
+
+#[post("/form", data = "<comment>")]
+fn form(comment: Form<Comment<'_>>) -> String {
+    comment.describe()
+}
+
+#[get("/query?<comment..>")]
+fn query(comment: Comment<'_>) -> String {
+    comment.describe()
+}
+
+#[derive(FromForm)]
+struct Note<'r> {
+    title: &'r str,
+    text: Option<&'r str>,
+}
+
+#[post("/note", data = "<note>")]
+fn note(note: Merged<Note<'_>>) -> String {
+    format!("{} {:?}", note.title, note.text)
+}
+
+mod merged_form_tests {
+    use super::*;
+    use rocket::http::{ContentType, Status};
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        Client::debug_with(routes![merged, strict, form, query, note]).unwrap()
+    }
+
+    fn post(client: &Client, uri: &str, body: &str) -> (Status, Option<String>) {
+        let response = client.post(uri.to_string())
+            .header(ContentType::Form)
+            .body(body)
+            .dispatch();
+
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn merged_draws_from_query_and_body() {
+        let client = client();
+        let (status, body) = post(&client, "/merged?page=2&tags=q", "body=hi&tags=b");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), r#"2 hi ["b", "q"]"#);
+    }
+
+    #[test]
+    fn merged_ignores_fields_from_other_source() {
+        let client = client();
+        let (status, body) = post(&client, "/merged?page=2&body=no", "body=hi&page=9");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "2 hi []");
+
+        let (status, _) = post(&client, "/merged?body=hi", "page=2");
+        assert_eq!(status, Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn merged_strict_rejects_fields_from_other_source() {
+        let client = client();
+        let (status, body) = post(&client, "/strict?page=2", "body=hi&tags=a");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), r#"2 hi ["a"]"#);
+
+        let (status, _) = post(&client, "/strict?page=2", "body=hi&tags=a&page=2");
+        assert_eq!(status, Status::UnprocessableEntity);
+    }
+
+    #[test]
+    fn body_wins_for_single_valued_fields() {
+        let client = client();
+        let (status, body) = post(&client, "/note?title=query&text=query", "title=body");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), r#"body Some("query")"#);
+    }
+
+    #[test]
+    fn merged_forwards_without_form_body() {
+        let client = client();
+        let response = client.post("/note?title=query").body("title=body").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+
+    #[test]
+    fn form_and_query_guards_respect_source() {
+        let client = client();
+        let (status, _) = post(&client, "/form?page=2", "body=hi&page=2");
+        assert_eq!(status, Status::UnprocessableEntity);
+
+        let response = client.get("/query?page=2&body=hi").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+
+    #[test]
+    fn merged_multipart() {
+        let client = client();
+        let body = &[
+            "--X-BOUNDARY",
+            r#"Content-Disposition: form-data; name="body""#,
+            "",
+            "hi",
+            "--X-BOUNDARY",
+            r#"Content-Disposition: form-data; name="tags""#,
+            "",
+            "b",
+            "--X-BOUNDARY--",
+            "",
+        ].join("\r\n");
+
+        let response = client.post("/merged?page=3&tags=q")
+            .header("multipart/form-data; boundary=X-BOUNDARY".parse::<ContentType>().unwrap())
+            .body(body)
+            .dispatch();
+
+        assert_eq!(response.into_string().unwrap(), r#"3 hi ["b", "q"]"#);
+    }
+}
@@ -0,0 +1,81 @@
+#[macro_use] extern crate rocket;
+
+#[get("/item/<_>")]
+fn get_item() -> &'static str {
+    "item"
+}
+
+#[delete("/item/<_>")]
+fn delete_item() -> &'static str {
+    "deleted"
+}
+
+#[post("/upload")]
+fn upload() -> &'static str {
+    "uploaded"
+}
+
+#[put("/forward/<_>")]
+fn forward() -> Option<&'static str> {
+    None
+}
+
+#[catch(405)]
+fn not_allowed() -> &'static str {
+    "not allowed"
+}
+
+mod method_not_allowed_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::Status;
+
+    fn client(enabled: bool) -> Client {
+        let figment = rocket::Config::figment().merge(("method_not_allowed", enabled));
+        let rocket = rocket::custom(figment)
+            .mount("/", routes![get_item, delete_item, upload, forward])
+            .register("/upload", catchers![not_allowed]);
+
+        Client::debug(rocket).unwrap()
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        let default = Client::debug_with(routes![upload]).unwrap();
+        let response = default.get("/upload").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert!(!response.headers().contains("Allow"));
+
+        let disabled = client(false);
+        assert_eq!(disabled.put("/item/1").dispatch().status(), Status::NotFound);
+    }
+
+    #[test]
+    fn responds_with_allowed_methods() {
+        let client = client(true);
+        let response = client.put("/item/1").dispatch();
+        assert_eq!(response.status(), Status::MethodNotAllowed);
+        assert_eq!(response.headers().get_one("Allow"), Some("DELETE, GET, HEAD"));
+
+        let response = client.get("/upload").dispatch();
+        assert_eq!(response.status(), Status::MethodNotAllowed);
+        assert_eq!(response.headers().get_one("Allow"), Some("POST"));
+        assert_eq!(response.into_string().unwrap(), "not allowed");
+
+        let response = client.head("/upload").dispatch();
+        assert_eq!(response.status(), Status::MethodNotAllowed);
+        assert_eq!(response.headers().get_one("Allow"), Some("POST"));
+    }
+
+    #[test]
+    fn unmatched_paths_and_forwards_are_not_found() {
+        let client = client(true);
+        assert_eq!(client.get("/item/1").dispatch().status(), Status::Ok);
+        assert_eq!(client.get("/nothing").dispatch().status(), Status::NotFound);
+        assert_eq!(client.put("/forward/1").dispatch().status(), Status::NotFound);
+
+        let response = client.get("/item/1/2").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert!(!response.headers().contains("Allow"));
+    }
+}
@@ -0,0 +1,88 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::{Arc, Mutex};
+
+use rocket::http::Status;
+use rocket::metrics::{Metrics, Histograms, Recorder, Sample};
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[get("/slow")]
+async fn slow() -> &'static str {
+    rocket::tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    "slow"
+}
+
+#[derive(Clone, Default)]
+struct Collector(Arc<Mutex<Vec<(String, Status)>>>);
+
+impl Recorder for Collector {
+    fn record(&self, sample: &Sample<'_>) {
+        self.0.lock().unwrap().push((sample.name().to_string(), sample.status));
+    }
+}
+
+mod metrics_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn default_metrics_manage_histograms() {
+        let rocket = rocket::build()
+            .mount("/", routes![index, slow])
+            .attach(Metrics::default());
+
+        let client = Client::debug(rocket).unwrap();
+        for _ in 0..3 {
+            client.get("/").dispatch();
+        }
+
+        client.get("/slow").dispatch();
+        client.get("/missing").dispatch();
+
+        let histograms = client.rocket().state::<Histograms>().unwrap();
+        let summaries = histograms.summaries();
+        let names: Vec<_> = summaries.keys().map(|s| s.as_str()).collect();
+        assert_eq!(names, vec!["<unmatched>", "index", "slow"]);
+        assert_eq!(summaries["index"].count, 3);
+        assert_eq!(summaries["<unmatched>"].count, 1);
+
+        let slow = histograms.summary("slow").unwrap();
+        assert_eq!(slow.count, 1);
+        assert!(slow.min_us >= 20_000, "{:?}", slow);
+        assert_eq!(slow.min_us, slow.p99_us);
+        assert!(histograms.percentile("slow", 0.5).unwrap().as_millis() >= 20);
+    }
+
+    #[test]
+    fn default_metrics_fail_if_histograms_are_managed() {
+        let rocket = rocket::build()
+            .manage(Histograms::new())
+            .attach(Metrics::default());
+
+        let error = Client::debug(rocket).unwrap_err();
+        assert!(matches!(error.kind(), rocket::error::ErrorKind::FailedFairings(_)));
+    }
+
+    #[test]
+    fn custom_recorder_receives_samples() {
+        let collector = Collector::default();
+        let rocket = rocket::build()
+            .mount("/", routes![index])
+            .attach(Metrics::new(collector.clone()));
+
+        let client = Client::debug(rocket).unwrap();
+        client.get("/").dispatch();
+        client.get("/missing").dispatch();
+
+        assert!(client.rocket().state::<Histograms>().is_none());
+        let samples = collector.0.lock().unwrap().clone();
+        assert_eq!(samples, vec![
+            ("index".to_string(), Status::Ok),
+            ("<unmatched>".to_string(), Status::NotFound),
+        ]);
+    }
+}
@@ -0,0 +1,116 @@
+#[macro_use] extern crate rocket;
+
+#[get("/")]
+fn api() -> &'static str {
+    "api"
+}
+
+#[get("/users")]
+fn api_users() -> &'static str {
+    "api users"
+}
+
+#[get("/")]
+fn tenant() -> &'static str {
+    "tenant"
+}
+
+#[get("/")]
+fn app() -> &'static str {
+    "app"
+}
+
+#[post("/")]
+fn app_post() -> &'static str {
+    "app post"
+}
+
+mod mount_host_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .mount_host("api.example.com", routes![api, api_users])
+            .mount_host("*.tenants.example.com", routes![tenant])
+            .mount("/", routes![app, app_post]);
+
+        Client::debug(rocket).unwrap()
+    }
+
+    fn get(client: &Client, host: &'static str, uri: &'static str) -> (Status, Option<String>) {
+        let response = client.get(uri).header(Header::new("Host", host)).dispatch();
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn routes_by_host() {
+        let client = client();
+        let ok = |body: &str| (Status::Ok, Some(body.to_string()));
+
+        assert_eq!(get(&client, "api.example.com", "/"), ok("api"));
+        assert_eq!(get(&client, "API.Example.com:8000", "/"), ok("api"));
+        assert_eq!(get(&client, "api.example.com", "/users"), ok("api users"));
+        assert_eq!(get(&client, "a.tenants.example.com", "/"), ok("tenant"));
+        assert_eq!(get(&client, "app.example.com", "/"), ok("app"));
+        assert_eq!(get(&client, "tenants.example.com", "/"), ok("app"));
+    }
+
+    #[test]
+    fn unmatched_hosts_fall_through() {
+        let client = client();
+        assert_eq!(get(&client, "app.example.com", "/users").0, Status::NotFound);
+
+        // Routes without a host still serve requests to a host with routes.
+        let response = client.post("/").header(Header::new("Host", "api.example.com")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "app post");
+    }
+
+    #[test]
+    fn host_routes_do_not_collide_with_others() {
+        let rocket = rocket::build()
+            .mount_host("api.example.com", routes![api])
+            .mount_host("app.example.com", routes![app])
+            .mount("/", routes![app]);
+
+        assert!(Client::debug(rocket).is_ok());
+
+        let rocket = rocket::build()
+            .mount_host("api.example.com", routes![api])
+            .mount_host("API.example.com", routes![app]);
+
+        let error = Client::debug(rocket).unwrap_err();
+        assert!(matches!(error.kind(), rocket::error::ErrorKind::Collisions(..)));
+    }
+
+    #[test]
+    fn overlapping_host_routes_collide() {
+        let collide = |a: &'static str, b: &'static str| {
+            let rocket = rocket::build()
+                .mount_host(a, routes![api])
+                .mount_host(b, routes![app]);
+
+            match Client::debug(rocket) {
+                Err(e) => matches!(e.kind(), rocket::error::ErrorKind::Collisions(..)),
+                Ok(_) => false,
+            }
+        };
+
+        assert!(collide("example.com", "example.com:8000"));
+        assert!(collide("*.example.com", "api.example.com"));
+        assert!(collide("*.example.com", "*.api.example.com"));
+        assert!(collide("*.example.com:8000", "API.example.com"));
+
+        assert!(!collide("example.com:8000", "example.com:9000"));
+        assert!(!collide("*.example.com", "example.com"));
+        assert!(!collide("*.example.com", "api.example.org"));
+        assert!(!collide("*.api.example.com", "app.example.com"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_hosts_panic() {
+        let _ = rocket::build().mount_host("user@example.com", routes![api]);
+    }
+}
@@ -0,0 +1,100 @@
+#[macro_use] extern crate rocket;
+
+use std::io::Cursor;
+
+use rocket::http::{ContentType, Header};
+use rocket::response::{Multipart, Part};
+use rocket::response::stream::stream;
+use rocket::futures::stream::{self, Stream};
+use rocket::tokio::time::{sleep, Duration};
+
+#[get("/")]
+fn index() -> Multipart<impl Stream<Item = Part<'static>>> {
+    Multipart::new(stream::iter(vec![
+        Part::new(ContentType::JSON, r#"{ "id": 1 }"#)
+            .header(Header::new("Content-ID", "<1>")),
+        Part::reader(ContentType::Binary, Cursor::new(vec![0u8, 1, 2])),
+    ]))
+}
+
+#[get("/empty")]
+fn empty() -> Multipart<impl Stream<Item = Part<'static>>> {
+    Multipart::new(stream::empty())
+}
+
+#[get("/slow")]
+fn slow() -> Multipart<impl Stream<Item = Part<'static>>> {
+    Multipart::new(stream! {
+        for i in 0..3 {
+            sleep(Duration::from_millis(5)).await;
+            yield Part::new(ContentType::Text, format!("part {}", i));
+        }
+    })
+}
+
+mod multipart_response_tests {
+    use super::*;
+    use rocket::local::blocking::{Client, LocalResponse};
+
+    fn client() -> Client {
+        Client::debug_with(routes![index, empty, slow]).unwrap()
+    }
+
+    fn boundary(response: &LocalResponse<'_>) -> String {
+        let content_type = response.content_type().expect("content type");
+        assert_eq!(content_type.top(), "multipart");
+        assert_eq!(content_type.sub(), "mixed");
+        content_type.param("boundary").expect("boundary").to_string()
+    }
+
+    #[test]
+    fn parts_are_delimited_by_boundary() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        let b = boundary(&response);
+        assert_eq!(b.len(), 32);
+        assert!(b.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        let mut expected = format!("--{}\r\n\
+            Content-Type: application/json\r\n\
+            Content-ID: <1>\r\n\
+            \r\n\
+            {{ \"id\": 1 }}\r\n\
+            --{}\r\n\
+            Content-Type: application/octet-stream\r\n\
+            \r\n", b, b).into_bytes();
+
+        expected.extend_from_slice(&[0, 1, 2]);
+        expected.extend_from_slice(format!("\r\n--{}--\r\n", b).as_bytes());
+        assert_eq!(response.into_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn empty_stream_is_closed() {
+        let client = client();
+        let response = client.get("/empty").dispatch();
+        let b = boundary(&response);
+        assert_eq!(response.into_string().unwrap(), format!("--{}--\r\n", b));
+    }
+
+    #[test]
+    fn async_parts_are_streamed_in_order() {
+        let client = client();
+        let response = client.get("/slow").dispatch();
+        let b = boundary(&response);
+        let body = response.into_string().unwrap();
+        let part = |i| {
+            format!("--{}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\npart {}\r\n", b, i)
+        };
+
+        assert_eq!(body, format!("{}{}{}--{}--\r\n", part(0), part(1), part(2), b));
+    }
+
+    #[test]
+    fn boundaries_are_unique() {
+        let client = client();
+        let first = boundary(&client.get("/").dispatch());
+        let second = boundary(&client.get("/").dispatch());
+        assert_ne!(first, second);
+    }
+}
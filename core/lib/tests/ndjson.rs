@@ -0,0 +1,66 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::futures::StreamExt;
+use rocket::serde::{Deserialize, json::{NdJson, LineError}};
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct Record {
+    id: usize,
+}
+
+#[post("/", data = "<records>")]
+async fn ingest(mut records: NdJson<'_, Record>) -> String {
+    let mut results = vec![];
+    while let Some(record) = records.next().await {
+        results.push(match record {
+            Ok(record) => record.id.to_string(),
+            Err(LineError::Parse(line, _)) => format!("error@{}", line),
+            Err(LineError::Io(e)) => format!("io:{}", e),
+        });
+    }
+
+    results.join(",")
+}
+
+mod ndjson_tests {
+    use super::*;
+    use rocket::data::{Limits, ToByteUnit};
+    use rocket::local::blocking::Client;
+
+    fn client(limit: Option<usize>) -> Client {
+        let mut limits = Limits::default();
+        if let Some(limit) = limit {
+            limits = limits.limit("json/ndjson", limit.bytes());
+        }
+
+        let figment = rocket::Config::figment().merge(("limits", limits));
+        Client::debug(rocket::custom(figment).mount("/", routes![ingest])).unwrap()
+    }
+
+    fn ingest(client: &Client, body: &str) -> String {
+        client.post("/").body(body).dispatch().into_string().unwrap()
+    }
+
+    #[test]
+    fn parses_records_line_by_line() {
+        let client = client(None);
+        assert_eq!(ingest(&client, ""), "");
+        assert_eq!(ingest(&client, "{\"id\":1}\n{\"id\":2}\n"), "1,2");
+        assert_eq!(ingest(&client, "{\"id\":1}\r\n\n  \n{\"id\":2}"), "1,2");
+        let body = ingest(&client, "{\"id\":1}\n{\"id\":\n{\"id\":3}\nnope");
+        assert_eq!(body, "1,error@2,3,error@4");
+        assert_eq!(ingest(&client, "\n\n{\"name\":\"x\"}\n"), "error@3");
+    }
+
+    #[test]
+    fn applies_the_data_limit() {
+        let client = client(Some(20));
+        assert_eq!(ingest(&client, "{\"id\":1}\n{\"id\":22}\n"), "1,22");
+
+        let body = ingest(&client, "{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n");
+        assert_eq!(body, "1,2,io:data limit exceeded");
+    }
+}
@@ -0,0 +1,95 @@
+#[macro_use] extern crate rocket;
+
+use rocket::fs::{OpenFile, relative};
+use rocket::tokio::fs::File;
+
+#[get("/named")]
+async fn named() -> std::io::Result<OpenFile> {
+    let file = File::open(relative!("tests/static/other/hello.txt")).await?;
+    Ok(OpenFile::new(file).await?.named("greeting.html"))
+}
+
+#[get("/unnamed")]
+async fn unnamed() -> std::io::Result<OpenFile> {
+    let file = std::fs::File::open(relative!("tests/static/other/hello.txt"))?;
+    OpenFile::new(file).await
+}
+
+mod open_file_tests {
+    use super::*;
+    use rocket::http::{ContentType, Status};
+    use rocket::local::blocking::Client;
+    use rocket::response::Responder;
+
+    #[test]
+    fn streams_file_with_inferred_content_type() {
+        let client = Client::debug_with(routes![named, unnamed]).unwrap();
+        let expected = std::fs::read_to_string(relative!("tests/static/other/hello.txt")).unwrap();
+
+        let response = client.get("/named").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::HTML));
+        assert_eq!(response.into_string().unwrap(), expected);
+
+        let response = client.get("/unnamed").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), None);
+        assert_eq!(response.into_string().unwrap(), expected);
+    }
+
+    #[test]
+    fn regular_file_has_known_size() {
+        let client = Client::debug_with(vec![]).unwrap();
+        let request = client.get("/");
+        let path = relative!("tests/static/other/hello.txt");
+        let file = rocket::async_test(async { OpenFile::new(File::open(path).await?).await });
+        let file = file.unwrap();
+
+        let len = std::fs::metadata(path).unwrap().len();
+        assert_eq!(file.size(), Some(len));
+
+        let response = file.respond_to(request.inner()).unwrap();
+        assert_eq!(response.body().preset_size(), Some(len as usize));
+    }
+
+    #[test]
+    fn seeked_file_is_sent_from_its_position() {
+        use std::io::{Seek, SeekFrom};
+
+        let path = relative!("tests/static/other/hello.txt");
+        let expected = std::fs::read(path).unwrap();
+        let mut file = std::fs::File::open(path).unwrap();
+        file.seek(SeekFrom::Start(3)).unwrap();
+
+        let client = Client::debug_with(vec![]).unwrap();
+        let request = client.get("/");
+        let file = rocket::async_test(OpenFile::new(file)).unwrap();
+        assert_eq!(file.size(), Some(expected.len() as u64 - 3));
+
+        let mut response = file.respond_to(request.inner()).unwrap();
+        assert_eq!(response.body().preset_size(), Some(expected.len() - 3));
+
+        let body = rocket::async_test(response.body_mut().to_bytes()).unwrap();
+        assert_eq!(body, &expected[3..]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pipe_is_streamed_without_size() {
+        use std::process::{Command, Stdio};
+
+        let client = Client::debug_with(vec![]).unwrap();
+        let request = client.get("/");
+        let mut child = Command::new("printf").arg("piped").stdout(Stdio::piped()).spawn().unwrap();
+        let pipe = std::fs::File::from(std::os::fd::OwnedFd::from(child.stdout.take().unwrap()));
+        let file = rocket::async_test(OpenFile::new(pipe)).unwrap();
+        assert_eq!(file.size(), None);
+
+        let mut response = file.respond_to(request.inner()).unwrap();
+        assert_eq!(response.body().preset_size(), None);
+
+        let body = rocket::async_test(response.body_mut().to_string()).unwrap();
+        assert_eq!(body, "piped");
+        child.wait().unwrap();
+    }
+}
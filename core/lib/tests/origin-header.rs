@@ -0,0 +1,67 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::OriginHeader;
+
+#[get("/")]
+fn index(origin: OriginHeader<'_>) -> String {
+    match origin.scheme() {
+        Some(scheme) => format!("{} {:?} {:?} {}", scheme, origin.host(), origin.port(), origin),
+        None => format!("null {}", origin.is_null()),
+    }
+}
+
+#[get("/", rank = 2)]
+fn no_origin() -> &'static str {
+    "no origin"
+}
+
+mod origin_header_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::{Header, Status};
+
+    fn client() -> Client {
+        Client::debug_with(routes![index, no_origin]).unwrap()
+    }
+
+    fn get(client: &Client, origin: &'static str) -> (Status, Option<String>) {
+        let response = client.get("/").header(Header::new("Origin", origin)).dispatch();
+        (response.status(), response.into_string())
+    }
+
+    #[test]
+    fn well_formed_origins_are_parsed() {
+        let client = client();
+        let (status, body) = get(&client, "https://example.com");
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body.unwrap(), "https Some(\"example.com\") None https://example.com");
+
+        let (_, body) = get(&client, "http://localhost:8000");
+        assert_eq!(body.unwrap(), "http Some(\"localhost\") Some(8000) http://localhost:8000");
+
+        let (_, body) = get(&client, "http://[::1]:80");
+        assert_eq!(body.unwrap(), "http Some(\"[::1]\") Some(80) http://[::1]:80");
+
+        let (_, body) = get(&client, "null");
+        assert_eq!(body.unwrap(), "null true");
+    }
+
+    #[test]
+    fn missing_origin_forwards() {
+        let client = client();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "no origin");
+    }
+
+    #[test]
+    fn malformed_origins_fail() {
+        let client = client();
+        for origin in &[
+            "example.com", "https://", "https://example.com/", "https://example.com/path",
+            "https://example.com?q", "https://user@example.com", "/path", "",
+        ] {
+            let (status, _) = get(&client, origin);
+            assert_eq!(status, Status::BadRequest, "{:?}", origin);
+        }
+    }
+}
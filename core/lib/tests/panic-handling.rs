@@ -1,6 +1,7 @@
 #[macro_use] extern crate rocket;
 
 use rocket::{Request, Rocket, Route, Catcher, Build, route, catcher};
+use rocket::catcher::Panic;
 use rocket::data::Data;
 use rocket::http::{Method, Status};
 use rocket::local::blocking::Client;
@@ -86,3 +87,47 @@ fn catches_early_catcher_panic() {
     assert_eq!(response.status(), Status::InternalServerError);
     assert_eq!(response.into_string().unwrap(), "Hey, sorry! :(");
 }
+
+#[catch(500)]
+fn panic_details(req: &Request) -> String {
+    match Panic::of(req) {
+        Some(panic) => format!("{}: {:?}", req.route().unwrap(), panic.message()),
+        None => "no panic".into(),
+    }
+}
+
+#[test]
+fn exposes_panic_to_catcher() {
+    let rocket = rocket().register("/", catchers![panic_details]);
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/panic").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+    let expected = format!("{}: Some(\"Panic in route\")", panic_route {}.into_route());
+    assert_eq!(response.into_string().unwrap(), expected);
+
+    let response = client.get("/pre").dispatch();
+    assert!(response.into_string().unwrap().ends_with("Some(\"hey now...\")"));
+}
+
+#[test]
+fn panic_messages_can_be_withheld() {
+    let figment = rocket::Config::figment().merge(("panic_messages", false));
+    let rocket = rocket().configure(figment).register("/", catchers![panic_details]);
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/panic").dispatch();
+    assert_eq!(response.status(), Status::InternalServerError);
+    assert!(response.into_string().unwrap().ends_with(": None"));
+}
+
+#[get("/fail")]
+fn fail() -> Status {
+    Status::InternalServerError
+}
+
+#[test]
+fn no_panic_without_panic() {
+    let rocket = rocket().mount("/", routes![fail]).register("/", catchers![panic_details]);
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/fail").dispatch();
+    assert_eq!(response.into_string().unwrap(), "no panic");
+}
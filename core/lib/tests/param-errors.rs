@@ -0,0 +1,107 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+
+use rocket::{Request, Rocket, Build};
+use rocket::http::Status;
+use rocket::request::{FromParam, ParamError};
+use rocket::local::blocking::Client;
+
+struct Id(u32);
+
+impl<'a> FromParam<'a> for Id {
+    type Error = ParamError;
+
+    fn from_param(param: &'a str) -> Result<Self, Self::Error> {
+        match param.strip_prefix("id-") {
+            Some(digits) => ParamError::parse(digits).map(Id),
+            None => Err(ParamError::new(param, "missing `id-` prefix")),
+        }
+    }
+}
+
+#[get("/user/<id>")]
+fn user(id: Id) -> String {
+    format!("user {}", id.0)
+}
+
+#[get("/num/<n>")]
+fn num(n: u8) -> String {
+    n.to_string()
+}
+
+#[get("/num/<n>", rank = 2)]
+fn num_fallback(n: &str) -> String {
+    format!("not a u8: {}", n)
+}
+
+#[get("/files/<path..>")]
+fn files(path: PathBuf) -> String {
+    path.display().to_string()
+}
+
+fn describe(req: &Request<'_>) -> String {
+    match ParamError::of(req) {
+        Some(e) => format!("{}|{}|{}", e.name().unwrap_or("?"), e.segment(), e.reason()),
+        None => "none".into(),
+    }
+}
+
+#[catch(400)]
+fn bad_request(req: &Request<'_>) -> String {
+    describe(req)
+}
+
+#[catch(404)]
+fn not_found(req: &Request<'_>) -> String {
+    describe(req)
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![user, num, num_fallback, files])
+        .register("/", catchers![bad_request, not_found])
+}
+
+#[test]
+fn param_errors_are_available_to_catchers() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.get("/user/id-7").dispatch();
+    assert_eq!(response.into_string().unwrap(), "user 7");
+
+    let response = client.get("/user/7").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "id|7|missing `id-` prefix");
+
+    let response = client.get("/user/id-x").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "id|id-x|invalid digit found in string");
+
+    let response = client.get("/files/a/.b/../../c").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert!(response.into_string().unwrap().starts_with("path|a/.b/../../c|"));
+
+    let response = client.get("/nothing").dispatch();
+    assert_eq!(response.into_string().unwrap(), "none");
+}
+
+#[test]
+fn param_errors_can_be_mapped_to_a_status() {
+    let rocket = rocket().map_error(|_: &ParamError| Status::BadRequest);
+    let client = Client::debug(rocket).unwrap();
+
+    let response = client.get("/user/7").dispatch();
+    assert_eq!(response.status(), Status::BadRequest);
+    assert_eq!(response.into_string().unwrap(), "id|7|missing `id-` prefix");
+
+    // A forward to a route that succeeds is unaffected.
+    let response = client.get("/num/300").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "not a u8: 300");
+
+    // So are requests without parameter errors.
+    let response = client.get("/nothing").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    assert_eq!(response.into_string().unwrap(), "none");
+}
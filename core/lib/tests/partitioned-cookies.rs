@@ -0,0 +1,80 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Cookie, CookieJar, SameSite};
+
+#[get("/")]
+fn index(jar: &CookieJar<'_>) -> &'static str {
+    jar.add(Cookie::new("plain", "a"));
+    jar.add_partitioned(Cookie::build("embed", "b").same_site(SameSite::None).finish());
+    jar.add_partitioned(Cookie::build("insecure", "c").secure(false).finish());
+    "index"
+}
+
+#[get("/replace")]
+fn replace(jar: &CookieJar<'_>) -> &'static str {
+    jar.add_partitioned(Cookie::new("first", "a"));
+    jar.add(Cookie::new("first", "b"));
+    jar.add(Cookie::new("second", "a"));
+    jar.add_partitioned(Cookie::new("second", "b"));
+    "replace"
+}
+
+#[cfg(feature = "secrets")]
+#[get("/private")]
+fn private(jar: &CookieJar<'_>) -> &'static str {
+    jar.add_private_partitioned(Cookie::new("secret", "value"));
+    "private"
+}
+
+mod partitioned_cookies_tests {
+    use super::*;
+    use rocket::local::blocking::{Client, LocalResponse};
+
+    fn set_cookie<'a>(response: &'a LocalResponse<'_>, name: &str) -> &'a str {
+        response.headers().get("Set-Cookie")
+            .find(|h| h.starts_with(&format!("{}=", name)))
+            .expect("cookie is set")
+    }
+
+    #[test]
+    fn partitioned_cookies_are_secure_and_partitioned() {
+        let client = Client::debug_with(routes![index]).unwrap();
+        let response = client.get("/").dispatch();
+
+        assert_eq!(set_cookie(&response, "plain"), "plain=a; SameSite=Strict; Path=/");
+        assert_eq!(set_cookie(&response, "embed"),
+            "embed=b; SameSite=None; Secure; Path=/; Partitioned");
+        assert_eq!(set_cookie(&response, "insecure"),
+            "insecure=c; SameSite=Strict; Secure; Path=/; Partitioned");
+
+        let cookie = response.cookies().get("embed").unwrap();
+        assert_eq!(cookie.value(), "b");
+        assert_eq!(cookie.secure(), Some(true));
+    }
+
+    #[test]
+    fn last_addition_determines_partitioning() {
+        let client = Client::debug_with(routes![replace]).unwrap();
+        let response = client.get("/replace").dispatch();
+
+        assert_eq!(response.headers().get("Set-Cookie").count(), 2);
+        assert!(!set_cookie(&response, "first").contains("Partitioned"));
+        assert!(!set_cookie(&response, "first").contains("Secure"));
+        assert!(set_cookie(&response, "second").ends_with("; Secure; Path=/; Partitioned"));
+    }
+
+    #[test]
+    #[cfg(feature = "secrets")]
+    fn private_partitioned_cookies_decrypt() {
+        let client = Client::debug_with(routes![private]).unwrap();
+        let response = client.get("/private").dispatch();
+
+        let header = set_cookie(&response, "secret");
+        assert!(header.ends_with("; Partitioned"), "{}", header);
+        assert!(header.contains("; Secure;"), "{}", header);
+        assert!(!header.contains("value"), "{}", header);
+
+        let cookie = client.cookies().get_private("secret").unwrap();
+        assert_eq!(cookie.value(), "value");
+    }
+}
@@ -0,0 +1,102 @@
+#[macro_use] extern crate rocket;
+
+#[derive(FromForm)]
+struct Search<'r> {
+    #[field(required)]
+    q: &'r str,
+    #[field(default = 1)]
+    page: usize,
+}
+
+#[get("/search?<search..>")]
+fn search(search: Search<'_>) -> String {
+    format!("{} {}", search.q, search.page)
+}
+
+#[derive(FromForm)]
+struct Range {
+    #[field(required)]
+    from: usize,
+    #[field(required, name = "to<")]
+    to: usize,
+}
+
+#[get("/range?<range..>")]
+fn range(range: Range) -> String {
+    format!("{}..{}", range.from, range.to)
+}
+
+#[get("/lenient?<id>")]
+fn lenient(id: usize) -> String {
+    id.to_string()
+}
+
+mod query_required_fields_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::Request;
+    use rocket::http::{Accept, Status};
+
+    #[catch(400)]
+    fn bad_request(req: &Request<'_>) -> String {
+        format!("missing: {}", req.missing_query_fields().join(", "))
+    }
+
+    fn client() -> Client {
+        Client::debug_with(routes![search, range, lenient]).unwrap()
+    }
+
+    #[test]
+    fn present_required_fields_succeed() {
+        let client = client();
+        let response = client.get("/search?q=rocket&page=3").dispatch();
+        assert_eq!(response.into_string().unwrap(), "rocket 3");
+
+        let response = client.get("/search?q=rocket").dispatch();
+        assert_eq!(response.into_string().unwrap(), "rocket 1");
+    }
+
+    #[test]
+    fn missing_required_fields_fail_with_400() {
+        let client = client();
+        assert_eq!(client.get("/search").dispatch().status(), Status::BadRequest);
+        assert_eq!(client.get("/search?page=3").dispatch().status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn other_query_errors_forward() {
+        let client = client();
+        assert_eq!(client.get("/search?q=rocket&page=x").dispatch().status(), Status::NotFound);
+        assert_eq!(client.get("/lenient").dispatch().status(), Status::NotFound);
+    }
+
+    #[test]
+    fn default_catcher_names_missing_fields() {
+        let client = client();
+        let body = client.get("/search?page=3").dispatch().into_string().unwrap();
+        assert!(body.contains("missing the required query parameter `q`."), "{}", body);
+
+        let body = client.get("/range").dispatch().into_string().unwrap();
+        assert!(body.contains("parameters `from`, `to&lt;`."), "{}", body);
+
+        let response = client.get("/range?from=1").header(Accept::JSON).dispatch();
+        let body = response.into_string().unwrap();
+        assert!(body.contains(r#""description": "The request is missing the required query "#));
+        assert!(body.contains("parameter `to<`.\""), "{}", body);
+    }
+
+    #[test]
+    fn catchers_see_missing_fields() {
+        let rocket = rocket::build()
+            .mount("/", routes![search, range])
+            .register("/", catchers![bad_request]);
+
+        let client = Client::debug(rocket).unwrap();
+        let response = client.get("/range").dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.into_string().unwrap(), "missing: from, to<");
+
+        let response = client.get("/search?q=rocket&page=x").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,58 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::RangeHeader;
+
+#[get("/")]
+fn ranges(range: RangeHeader<'_>) -> String {
+    let ranges = range.normalize(100).iter()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect::<Vec<_>>();
+
+    format!("{}: {}", range.unit(), ranges.join(","))
+}
+
+#[get("/", rank = 2)]
+fn no_range() -> &'static str {
+    "none"
+}
+
+mod range_header_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn parsed_ranges_are_normalized() {
+        let client = Client::debug_with(routes![ranges, no_range]).unwrap();
+        let response = client.get("/")
+            .header(Header::new("Range", "bytes=0-9, 90-, -5, 95-200, 100-"))
+            .dispatch();
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "bytes: 0-9,90-99,95-99,95-99");
+
+        let response = client.get("/").header(Header::new("Range", "items=500-")).dispatch();
+        assert_eq!(response.into_string().unwrap(), "items: ");
+    }
+
+    #[test]
+    fn absent_range_forwards() {
+        let client = Client::debug_with(routes![ranges, no_range]).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "none");
+    }
+
+    #[test]
+    fn malformed_range_is_bad_request() {
+        let client = Client::debug_with(routes![ranges, no_range]).unwrap();
+        let values = &[
+            "bytes=9-0", "bytes=", "bytes=one-two", "0-9", "bytes=0-9,-",
+            "bytes=+0-9", "bytes=0-+9", "bytes=+5-", "bytes=-+5",
+        ];
+
+        for value in values {
+            let response = client.get("/").header(Header::new("Range", *value)).dispatch();
+            assert_eq!(response.status(), Status::BadRequest, "{}", value);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#[macro_use] extern crate rocket;
+
+use rocket::request::RawCookies;
+
+#[get("/")]
+fn pairs(raw: RawCookies<'_>) -> String {
+    let pairs: Vec<_> = raw.pairs().map(|(n, v)| format!("{}={}", n, v)).collect();
+    format!("{:?} empty: {}", pairs, raw.is_empty())
+}
+
+#[get("/get?<name>")]
+fn get(raw: RawCookies<'_>, name: &str) -> String {
+    raw.get(name).unwrap_or("<none>").to_string()
+}
+
+#[get("/display")]
+fn display(raw: RawCookies<'_>) -> String {
+    raw.to_string()
+}
+
+mod raw_cookies_tests {
+    use super::*;
+    use rocket::http::Header;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        Client::debug_with(routes![pairs, get, display]).unwrap()
+    }
+
+    fn dispatch(client: &Client, uri: &str, cookies: &[&'static str]) -> String {
+        let mut request = client.get(uri.to_string());
+        for value in cookies {
+            request.add_header(Header::new("Cookie", *value));
+        }
+
+        request.dispatch().into_string().unwrap()
+    }
+
+    #[test]
+    fn no_cookie_header_is_empty() {
+        let client = client();
+        assert_eq!(dispatch(&client, "/", &[]), "[] empty: true");
+        assert_eq!(dispatch(&client, "/", &[" "]), "[] empty: true");
+        assert_eq!(dispatch(&client, "/display", &[]), "");
+    }
+
+    #[test]
+    fn pairs_are_split_without_validation() {
+        let client = client();
+        let response = dispatch(&client, "/", &[r#"a=1; legacy;; b = "q%20v" ;c=x=y"#]);
+        assert_eq!(response, r#"["a=1", "legacy=", "b=\"q%20v\"", "c=x=y"] empty: false"#);
+    }
+
+    #[test]
+    fn multiple_headers_are_combined() {
+        let client = client();
+        let cookies = ["a=1; b=2", "c=3"];
+        assert_eq!(dispatch(&client, "/", &cookies), r#"["a=1", "b=2", "c=3"] empty: false"#);
+        assert_eq!(dispatch(&client, "/display", &cookies), "a=1; b=2; c=3");
+    }
+
+    #[test]
+    fn get_returns_first_raw_value() {
+        let client = client();
+        let cookies = ["session=%E2%9C%93 ; session=second", "Theme=dark"];
+        assert_eq!(dispatch(&client, "/get?name=session", &cookies), "%E2%9C%93");
+        assert_eq!(dispatch(&client, "/get?name=Theme", &cookies), "dark");
+        assert_eq!(dispatch(&client, "/get?name=theme", &cookies), "<none>");
+    }
+}
@@ -0,0 +1,27 @@
+#[macro_use] extern crate rocket;
+
+use rocket::form::RawQuery;
+
+#[get("/?<page>")]
+fn index(page: Option<usize>, query: RawQuery<'_>) -> String {
+    let fields: Vec<String> = query.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    format!("{:?} {}", page, fields.join(","))
+}
+
+mod raw_query_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn raw_query_preserves_order_and_duplicates() {
+        let client = Client::debug_with(routes![index]).unwrap();
+        let body = |uri: &str| client.get(uri.to_string()).dispatch().into_string().unwrap();
+
+        assert_eq!(body("/"), "None ");
+        assert_eq!(body("/?page=2"), "Some(2) page=2");
+        assert_eq!(body("/?b=1&a=2&b=3"), "None b=1,a=2,b=3");
+        assert_eq!(body("/?a=x&page=1&&a=y"), "Some(1) a=x,page=1,a=y");
+        assert_eq!(body("/?q=hello+world&name%21=%F0%9F%9A%80"), "None q=hello world,name!=🚀");
+        assert_eq!(body("/?user.name=bob&tags[]=a&flag"), "None user.name=bob,tags[]=a,flag=");
+    }
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "raw_requests")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+
+#[get("/")]
+fn index() -> &'static str {
+    "Hello, world!"
+}
+
+#[post("/echo", data = "<body>")]
+fn echo(body: String) -> String {
+    body
+}
+
+fn client() -> Client {
+    Client::debug_with(routes![index, echo]).unwrap()
+}
+
+#[test]
+fn well_formed_raw_requests() {
+    let response = client().send_raw("GET / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n");
+    assert_eq!(response.status(), Some(Status::Ok));
+
+    let bytes = String::from_utf8(response.into_bytes()).unwrap();
+    assert!(bytes.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(bytes.contains("\r\ncontent-length: 13\r\n"));
+    assert!(bytes.ends_with("\r\n\r\nHello, world!"));
+
+    let request = "POST /echo HTTP/1.1\r\nHost: rocket.rs\r\nContent-Length: 5\r\n\r\nhello";
+    let response = client().send_raw(request);
+    assert!(response.as_bytes().ends_with(b"\r\n\r\nhello"));
+}
+
+#[test]
+fn malformed_raw_requests() {
+    let requests = [
+        "GET / HTTP/1.1\r\nHost\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost: \x7f\r\n\r\n",
+        "GET /\x01 HTTP/1.1\r\nHost: rocket.rs\r\n\r\n",
+        "GET / HTTP/1.1\r\nContent-Length: 1\r\nContent-Length: 2\r\n\r\n",
+    ];
+
+    let client = client();
+    for request in requests {
+        let response = client.send_raw(request);
+        assert_eq!(response.status(), Some(Status::BadRequest), "{:?}", request);
+    }
+
+    let response = client.send_raw("BREW / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n");
+    assert_eq!(response.status(), Some(Status::BadRequest));
+
+    assert_eq!(client.send_raw("").status(), None);
+}
+
+#[test]
+fn pipelined_raw_requests() {
+    let requests = "GET / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n\
+        GET /missing HTTP/1.1\r\nHost: rocket.rs\r\n\r\n";
+
+    let response = client().send_raw(requests);
+    let bytes = String::from_utf8(response.into_bytes()).unwrap();
+    let ok = bytes.find("HTTP/1.1 200 OK\r\n").unwrap();
+    let missing = bytes.find("HTTP/1.1 404 Not Found\r\n").unwrap();
+    assert!(ok < missing);
+}
+
+#[rocket::async_test]
+async fn async_raw_requests() {
+    let client = rocket::local::asynchronous::Client::debug_with(routes![index]).await.unwrap();
+    let response = client.send_raw(b"GET / HTTP/1.1\r\nHost: rocket.rs\r\n\r\n").await;
+    assert!(response.as_bytes().ends_with(b"Hello, world!"));
+}
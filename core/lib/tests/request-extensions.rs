@@ -0,0 +1,106 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::Arc;
+
+use rocket::{Request, Rocket, Build};
+use rocket::fairing::AdHoc;
+use rocket::http::Header;
+use rocket::request::{self, Extensions, FromRequest};
+use rocket::local::blocking::Client;
+
+#[derive(Debug, PartialEq)]
+struct Tenant(String);
+
+struct Guarded(Arc<Tenant>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Guarded {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+        match req.extensions().get::<Tenant>() {
+            Some(tenant) => request::Outcome::Success(Guarded(tenant)),
+            None => request::Outcome::Forward(()),
+        }
+    }
+}
+
+#[get("/")]
+fn index(guarded: Guarded, extensions: &Extensions) -> String {
+    let tenant = extensions.get::<Tenant>().unwrap();
+    assert!(Arc::ptr_eq(&guarded.0, &tenant));
+    tenant.0.clone()
+}
+
+#[get("/", rank = 2)]
+fn fallback(extensions: &Extensions) -> String {
+    format!("none: {}", extensions.is_empty())
+}
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index, fallback])
+        .attach(AdHoc::on_request("Tenant", |req, _| Box::pin(async move {
+            if let Some(tenant) = req.headers().get_one("X-Tenant") {
+                req.extensions().insert(Tenant(tenant.into()));
+            }
+        })))
+        .attach(AdHoc::on_response("Tenant Echo", |req, res| Box::pin(async move {
+            if let Some(tenant) = req.extensions().get::<Tenant>() {
+                res.set_raw_header("X-Tenant-Seen", tenant.0.clone());
+            }
+        })))
+}
+
+#[test]
+fn extensions_are_shared_by_fairings_guards_and_handlers() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.get("/").header(Header::new("X-Tenant", "acme")).dispatch();
+    assert_eq!(response.headers().get_one("X-Tenant-Seen"), Some("acme"));
+    assert_eq!(response.into_string().unwrap(), "acme");
+
+    let response = client.get("/").dispatch();
+    assert!(response.headers().get_one("X-Tenant-Seen").is_none());
+    assert_eq!(response.into_string().unwrap(), "none: true");
+}
+
+#[test]
+fn extensions_are_per_request() {
+    let client = Client::debug(rocket()).unwrap();
+
+    let response = client.get("/").header(Header::new("X-Tenant", "a")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "a");
+
+    let response = client.get("/").header(Header::new("X-Tenant", "b")).dispatch();
+    assert_eq!(response.into_string().unwrap(), "b");
+
+    let response = client.get("/").dispatch();
+    assert_eq!(response.into_string().unwrap(), "none: true");
+}
+
+#[test]
+fn insert_overwrites_and_try_insert_keeps_first() {
+    let client = Client::debug_with(vec![]).unwrap();
+    let request = client.get("/");
+    let extensions = request.extensions();
+
+    assert!(extensions.insert(Tenant("a".into())).is_none());
+    let held = extensions.get::<Tenant>().unwrap();
+
+    let previous = extensions.insert(Tenant("b".into())).unwrap();
+    assert_eq!(*previous, Tenant("a".into()));
+    assert_eq!(*held, Tenant("a".into()));
+    assert_eq!(*extensions.get::<Tenant>().unwrap(), Tenant("b".into()));
+
+    assert_eq!(extensions.try_insert(Tenant("c".into())), Err(Tenant("c".into())));
+    assert_eq!(*extensions.get::<Tenant>().unwrap(), Tenant("b".into()));
+
+    assert_eq!(extensions.try_insert(7u32), Ok(()));
+    assert_eq!(extensions.len(), 2);
+
+    assert_eq!(*extensions.remove::<Tenant>().unwrap(), Tenant("b".into()));
+    assert!(!extensions.contains::<Tenant>());
+    assert_eq!(extensions.try_insert(Tenant("d".into())), Ok(()));
+    assert_eq!(*extensions.get::<Tenant>().unwrap(), Tenant("d".into()));
+}
@@ -0,0 +1,103 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::{Rocket, Build, Request, Response};
+use rocket::fairing::AdHoc;
+use rocket::http::{Header, Status};
+
+#[get("/")]
+fn index() -> &'static str {
+    "index"
+}
+
+#[catch(503)]
+fn maintenance() -> &'static str {
+    "down for maintenance"
+}
+
+#[derive(Default)]
+struct Counter(AtomicUsize);
+
+fn rocket() -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index])
+        .register("/", catchers![maintenance])
+        .manage(Counter::default())
+        .attach(AdHoc::on_request("Maintenance", |req, _| Box::pin(async move {
+            if req.headers().contains("X-Maintenance") {
+                req.halt(Status::ServiceUnavailable);
+            } else if req.headers().contains("X-Static") {
+                let body = "static";
+                req.halt_with(Response::build()
+                    .status(Status::Accepted)
+                    .sized_body(body.len(), std::io::Cursor::new(body))
+                    .finalize());
+            }
+        })))
+        .attach(AdHoc::on_request("Counter", |req, _| Box::pin(async move {
+            req.rocket().state::<Counter>().unwrap().0.fetch_add(1, Ordering::SeqCst);
+        })))
+        .attach(AdHoc::on_response("Marker", |req: &Request<'_>, res| Box::pin(async move {
+            if let Some(status) = req.halted() {
+                res.set_header(Header::new("X-Halted", status.code.to_string()));
+            }
+        })))
+}
+
+mod request_fairing_halt_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn unhalted_requests_are_routed() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert!(response.headers().get_one("X-Halted").is_none());
+        assert_eq!(response.into_string().unwrap(), "index");
+
+        let counter = client.rocket().state::<Counter>().unwrap();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn halted_requests_skip_routing_and_later_fairings() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/").header(Header::new("X-Maintenance", "1")).dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+        assert_eq!(response.headers().get_one("X-Halted"), Some("503"));
+        assert_eq!(response.into_string().unwrap(), "down for maintenance");
+
+        let counter = client.rocket().state::<Counter>().unwrap();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn halted_head_requests_have_no_body() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.head("/").header(Header::new("X-Maintenance", "1")).dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+        assert!(response.into_string().unwrap_or_default().is_empty());
+    }
+
+    #[test]
+    fn requests_halted_with_a_response_are_answered_with_it() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/").header(Header::new("X-Static", "1")).dispatch();
+        assert_eq!(response.status(), Status::Accepted);
+        assert_eq!(response.headers().get_one("X-Halted"), Some("202"));
+        assert_eq!(response.into_string().unwrap(), "static");
+
+        let counter = client.rocket().state::<Counter>().unwrap();
+        assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "error status")]
+    fn requests_cannot_be_halted_with_a_success_status() {
+        let client = Client::debug(rocket()).unwrap();
+        let mut request = client.get("/");
+        request.inner_mut().halt(Status::Ok);
+    }
+}
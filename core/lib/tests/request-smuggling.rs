@@ -0,0 +1,113 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::Config;
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+#[post("/", data = "<body>")]
+fn echo(body: &str) -> String {
+    format!("body: {}", body)
+}
+
+/// Launches a server on a free port and returns the port.
+async fn launch() -> u16 {
+    let (tx, rx) = oneshot::channel();
+    let config = Config { port: 0, ..Config::debug_default() };
+    let rocket = rocket::custom(config)
+        .mount("/", routes![echo])
+        .attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+            tx.send(rocket.config().port).unwrap();
+        })));
+
+    tokio::spawn(rocket.launch());
+    rx.await.unwrap()
+}
+
+/// Sends a `POST /` with the raw `headers` and `body`, returning everything
+/// read until the server closes the connection.
+async fn send(headers: &str, body: &str) -> String {
+    let port = launch().await;
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let request = format!("POST / HTTP/1.1\r\nHost: a\r\n{}\r\n{}", headers, body);
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = vec![];
+    let read = stream.read_to_end(&mut response);
+    tokio::time::timeout(Duration::from_secs(5), read).await
+        .expect("connection closed")
+        .unwrap();
+
+    String::from_utf8_lossy(&response).into_owned()
+}
+
+fn status_of(response: &str) -> &str {
+    response.lines().next().unwrap_or_default()
+}
+
+#[rocket::async_test]
+async fn content_length_then_transfer_encoding_is_rejected() {
+    let headers = "Content-Length: 4\r\nTransfer-Encoding: chunked\r\n";
+    let response = send(headers, "0\r\n\r\nGET /smuggled HTTP/1.1\r\nHost: a\r\n\r\n").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+    assert!(response.to_ascii_lowercase().contains("connection: close"));
+    assert!(!response.contains("smuggled"));
+    assert_eq!(response.matches("HTTP/1.1").count(), 1);
+}
+
+#[rocket::async_test]
+async fn transfer_encoding_then_content_length_is_framed_by_chunks() {
+    let headers = "Transfer-Encoding: chunked\r\nContent-Length: 3\r\nConnection: close\r\n";
+    let response = send(headers, "5\r\nhello\r\n0\r\n\r\n").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 200 OK");
+    assert!(response.ends_with("body: hello"));
+}
+
+#[rocket::async_test]
+async fn conflicting_content_lengths_are_rejected() {
+    let response = send("Content-Length: 5\r\nContent-Length: 6\r\n", "hello!").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+
+    let response = send("Content-Length: 5, 6\r\n", "hello!").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+}
+
+#[rocket::async_test]
+async fn invalid_content_lengths_are_rejected() {
+    let response = send("Content-Length: +5\r\n", "hello").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+
+    let response = send("Content-Length: 5 5\r\n", "hello").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+}
+
+#[rocket::async_test]
+async fn repeated_or_non_final_chunked_is_rejected() {
+    let body = "5\r\nhello\r\n0\r\n\r\n";
+    let response = send("Transfer-Encoding: chunked, chunked\r\n", body).await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+
+    let response = send("Transfer-Encoding: chunked\r\nTransfer-Encoding: chunked\r\n", body).await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+
+    let response = send("Transfer-Encoding: chunked, gzip\r\n", body).await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+
+    let response = send("Transfer-Encoding: xchunked\r\n", body).await;
+    assert_eq!(status_of(&response), "HTTP/1.1 400 Bad Request");
+}
+
+#[rocket::async_test]
+async fn unambiguous_framing_is_accepted() {
+    let headers = "Content-Length: 5\r\nContent-Length: 5\r\nConnection: close\r\n";
+    let response = send(headers, "hello").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 200 OK");
+    assert!(response.ends_with("body: hello"));
+
+    let headers = "Transfer-Encoding: chunked\r\nConnection: close\r\n";
+    let response = send(headers, "5\r\nhello\r\n0\r\n\r\n").await;
+    assert_eq!(status_of(&response), "HTTP/1.1 200 OK");
+    assert!(response.ends_with("body: hello"));
+}
@@ -0,0 +1,53 @@
+#[macro_use] extern crate rocket;
+
+use std::time::{Duration, Instant};
+
+use rocket::{Rocket, Build, State};
+use rocket::fairing::AdHoc;
+use rocket::request::RequestTiming;
+use rocket::tokio::time::sleep;
+
+struct Before(Instant);
+
+#[get("/")]
+fn index(timing: RequestTiming, before: &State<Before>) -> String {
+    assert!(timing.started_at() >= before.0);
+    assert!(timing.elapsed() >= Duration::from_millis(50));
+    "ok".into()
+}
+
+fn rocket(before: Instant) -> Rocket<Build> {
+    rocket::build()
+        .mount("/", routes![index])
+        .manage(Before(before))
+        .attach(AdHoc::on_request("Slow", |req, _| Box::pin(async move {
+            let timing = req.guard::<RequestTiming>().await.unwrap();
+            assert!(timing.elapsed() < Duration::from_millis(50));
+            sleep(Duration::from_millis(50)).await;
+        })))
+}
+
+mod request_timing_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn clock_starts_before_request_fairings() {
+        let before = Instant::now();
+        let client = Client::debug(rocket(before)).unwrap();
+        let response = client.get("/").dispatch();
+        assert_eq!(response.into_string().unwrap(), "ok");
+    }
+
+    #[test]
+    fn each_request_is_timed_separately() {
+        let client = Client::debug(rocket(Instant::now())).unwrap();
+        let first = client.get("/").dispatch();
+        assert_eq!(first.into_string().unwrap(), "ok");
+
+        // If the clock were shared, the `Slow` fairing's check would fail.
+        std::thread::sleep(Duration::from_millis(100));
+        let second = client.get("/").dispatch();
+        assert_eq!(second.into_string().unwrap(), "ok");
+    }
+}
@@ -0,0 +1,329 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::{Request, State};
+use rocket::data::ToByteUnit;
+use rocket::http::{Header, Status, Cookie, CookieJar};
+use rocket::request::{self, FromRequest};
+use rocket::response::status;
+use rocket::response::stream::TextStream;
+use rocket::route::ResponseCaches;
+
+#[derive(Default)]
+struct Calls(AtomicUsize);
+
+impl Calls {
+    fn next(&self) -> usize {
+        self.0.fetch_add(1, Ordering::SeqCst)
+    }
+}
+
+#[get("/stats?<region>", cache = "1h")]
+fn stats(region: Option<&str>, calls: &State<Calls>) -> String {
+    format!("{}: {}", region.unwrap_or("all"), calls.next())
+}
+
+#[get("/fail", cache = "1h")]
+fn fail(calls: &State<Calls>) -> status::NotFound<String> {
+    status::NotFound(calls.next().to_string())
+}
+
+#[get("/cookie", cache = "1h")]
+fn cookie(jar: &CookieJar<'_>, calls: &State<Calls>) -> String {
+    jar.add(Cookie::new("visited", "yes"));
+    calls.next().to_string()
+}
+
+#[derive(Responder)]
+struct WithHeader(String, Header<'static>);
+
+#[get("/vary", cache = "1h")]
+fn vary(calls: &State<Calls>) -> WithHeader {
+    WithHeader(calls.next().to_string(), Header::new("Vary", "Accept-Language"))
+}
+
+#[get("/stream", cache = "1h")]
+fn stream(calls: &State<Calls>) -> TextStream![String] {
+    let call = calls.next();
+    TextStream! { yield call.to_string(); }
+}
+
+#[get("/short", cache = "1s")]
+fn short(calls: &State<Calls>) -> String {
+    calls.next().to_string()
+}
+
+#[derive(Responder)]
+struct Upstream(String, Header<'static>, Header<'static>);
+
+#[get("/upstream?<age>&<control>", cache = "1h")]
+fn upstream(age: &str, control: &str, calls: &State<Calls>) -> Upstream {
+    let age = Header::new("Age", age.to_string());
+    let control = Header::new("Cache-Control", control.to_string());
+    Upstream(calls.next().to_string(), age, control)
+}
+
+#[get("/fresh?<age>", cache = "1h")]
+fn fresh(age: &str, calls: &State<Calls>) -> WithHeader {
+    WithHeader(calls.next().to_string(), Header::new("Age", age.to_string()))
+}
+
+struct Admin;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, ()> {
+        match req.headers().get_one("X-Admin") {
+            Some("yes") => request::Outcome::Success(Admin),
+            _ => request::Outcome::Failure((Status::Forbidden, ())),
+        }
+    }
+}
+
+#[get("/admin", cache = "1h")]
+fn admin(_admin: Admin, calls: &State<Calls>) -> String {
+    calls.next().to_string()
+}
+
+#[get("/big?<n>", cache = "1h")]
+fn big(n: usize, calls: &State<Calls>) -> String {
+    format!("{}{}", calls.next(), " ".repeat(n))
+}
+
+#[post("/refresh?<all>")]
+fn refresh(all: bool, caches: &State<ResponseCaches>) {
+    if all {
+        caches.clear();
+    } else {
+        caches.get("stats").unwrap().remove("/stats?region=eu");
+    }
+}
+
+mod response_cache_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .manage(Calls::default())
+            .mount("/", routes![stats, fail, cookie, vary, stream, short, upstream, fresh])
+            .mount("/", routes![admin, big])
+            .mount("/", routes![refresh]);
+
+        let client = Client::debug(rocket).unwrap();
+        let caches = client.rocket().state::<ResponseCaches>().unwrap();
+        caches.get("fresh").unwrap().set_request_directives(true);
+        client
+    }
+
+    fn age(response: &rocket::local::blocking::LocalResponse<'_>) -> u64 {
+        response.headers().get_one("Age").unwrap().parse().unwrap()
+    }
+
+    fn get(client: &Client, uri: &'static str) -> String {
+        client.get(uri).dispatch().into_string().unwrap()
+    }
+
+    #[test]
+    fn responses_are_cached_by_uri() {
+        let client = client();
+        assert_eq!(get(&client, "/stats"), "all: 0");
+        assert_eq!(get(&client, "/stats"), "all: 0");
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 1");
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 1");
+        assert_eq!(get(&client, "/stats"), "all: 0");
+
+        let response = client.head("/stats").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert!(response.into_string().unwrap_or_default().is_empty());
+    }
+
+    #[test]
+    fn caches_can_be_busted() {
+        let client = client();
+        assert_eq!(get(&client, "/stats"), "all: 0");
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 1");
+
+        client.post("/refresh").dispatch();
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 2");
+        assert_eq!(get(&client, "/stats"), "all: 0");
+
+        client.post("/refresh?all").dispatch();
+        assert_eq!(get(&client, "/stats"), "all: 3");
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 4");
+    }
+
+    #[test]
+    fn uncacheable_responses_are_not_stored() {
+        let client = client();
+        assert_eq!(get(&client, "/fail"), "0");
+        assert_eq!(get(&client, "/fail"), "1");
+
+        let response = client.get("/cookie").dispatch();
+        assert!(response.cookies().get("visited").is_some());
+        assert_eq!(response.into_string().unwrap(), "2");
+        assert_eq!(get(&client, "/cookie"), "3");
+
+        assert_eq!(get(&client, "/stream"), "4");
+        assert_eq!(get(&client, "/stream"), "5");
+    }
+
+    #[test]
+    fn responses_vary_on_request_headers() {
+        let client = client();
+        let get = |lang: &'static str| client.get("/vary")
+            .header(Header::new("Accept-Language", lang))
+            .dispatch()
+            .into_string()
+            .unwrap();
+
+        assert_eq!(get("en"), "0");
+        assert_eq!(get("de"), "1");
+        assert_eq!(get("en"), "0");
+        assert_eq!(get("de"), "1");
+        assert_eq!(client.get("/vary").dispatch().into_string().unwrap(), "2");
+    }
+
+    #[test]
+    fn entries_expire() {
+        let client = client();
+        assert_eq!(get(&client, "/short"), "0");
+        assert_eq!(get(&client, "/short"), "0");
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+        assert_eq!(get(&client, "/short"), "1");
+        assert_eq!(get(&client, "/short"), "1");
+    }
+
+    fn get_with(client: &Client, uri: &'static str, control: &'static str) -> String {
+        client.get(uri)
+            .header(Header::new("Cache-Control", control))
+            .dispatch()
+            .into_string()
+            .unwrap()
+    }
+
+    #[test]
+    fn cached_responses_have_an_age() {
+        let client = client();
+        let response = client.get("/stats").dispatch();
+        assert!(response.headers().get_one("Age").is_none());
+
+        let response = client.get("/stats").dispatch();
+        assert!(age(&response) <= 1);
+        assert_eq!(response.into_string().unwrap(), "all: 0");
+
+        let response = client.get("/fresh?age=30").dispatch();
+        assert_eq!(age(&response), 30);
+
+        let response = client.get("/fresh?age=30").dispatch();
+        assert!((30..=31).contains(&age(&response)));
+        assert_eq!(response.headers().get("Age").count(), 1);
+        assert_eq!(response.into_string().unwrap(), "1");
+    }
+
+    #[test]
+    fn upstream_age_and_freshness_are_honored() {
+        let client = client();
+        let uri = "/upstream?age=30&control=max-age%3D3600";
+        assert_eq!(get(&client, uri), "0");
+
+        let response = client.get(uri).dispatch();
+        assert!((30..=31).contains(&age(&response)));
+        assert_eq!(response.headers().get("Age").count(), 1);
+        assert_eq!(response.into_string().unwrap(), "0");
+
+        // Already as old as its `max-age` allows: stale once stored.
+        let uri = "/upstream?age=10&control=max-age%3D10";
+        assert_eq!(get(&client, uri), "1");
+        assert_eq!(get(&client, uri), "2");
+
+        // `s-maxage` takes precedence over `max-age`.
+        let uri = "/upstream?age=0&control=max-age%3D0,%20s-maxage%3D60";
+        assert_eq!(get(&client, uri), "3");
+        assert_eq!(get(&client, uri), "3");
+
+        // `no-cache` responses are never stored.
+        let uri = "/upstream?age=0&control=no-cache";
+        assert_eq!(get(&client, uri), "4");
+        assert_eq!(get(&client, uri), "5");
+    }
+
+    #[test]
+    fn request_directives_are_ignored_by_default() {
+        let client = client();
+        assert_eq!(get(&client, "/stats"), "all: 0");
+        assert_eq!(get_with(&client, "/stats", "no-cache"), "all: 0");
+        assert_eq!(get_with(&client, "/stats", "max-age=0"), "all: 0");
+        assert_eq!(get_with(&client, "/stats?region=eu", "no-store"), "eu: 1");
+        assert_eq!(get(&client, "/stats?region=eu"), "eu: 1");
+    }
+
+    #[test]
+    fn request_directives_bypass_the_cache() {
+        // Responses to `/fresh?age=30` are stored 30 seconds old.
+        let client = client();
+        let uri = "/fresh?age=30";
+        assert_eq!(get(&client, uri), "0");
+        assert_eq!(get_with(&client, uri, "max-age=3600"), "0");
+        assert_eq!(get_with(&client, uri, "max-age=60"), "0");
+
+        // A bypassing request's response replaces the stored one.
+        assert_eq!(get_with(&client, uri, "max-age=10"), "1");
+        assert_eq!(get(&client, uri), "1");
+        assert_eq!(get_with(&client, uri, "no-cache"), "2");
+        assert_eq!(get(&client, uri), "2");
+        assert_eq!(get_with(&client, uri, "Max-Age=0"), "3");
+        assert_eq!(get(&client, uri), "3");
+
+        // A `no-store` request's response isn't stored.
+        assert_eq!(get_with(&client, uri, "no-store"), "4");
+        assert_eq!(get(&client, uri), "3");
+    }
+
+    #[test]
+    fn guards_run_before_cached_responses_are_served() {
+        let client = client();
+        let admin = |yes: bool| {
+            let value = if yes { "yes" } else { "no" };
+            client.get("/admin").header(Header::new("X-Admin", value)).dispatch()
+        };
+
+        assert_eq!(admin(true).into_string().unwrap(), "0");
+        assert_eq!(admin(true).into_string().unwrap(), "0");
+        assert_eq!(admin(false).status(), Status::Forbidden);
+        assert_eq!(client.get("/admin").dispatch().status(), Status::Forbidden);
+        assert_eq!(admin(true).into_string().unwrap(), "0");
+    }
+
+    #[test]
+    fn least_recently_used_entries_are_evicted() {
+        let client = client();
+        let caches = client.rocket().state::<ResponseCaches>().unwrap();
+        caches.get("stats").unwrap().set_max_entries(2);
+
+        assert_eq!(get(&client, "/stats?region=a"), "a: 0");
+        assert_eq!(get(&client, "/stats?region=b"), "b: 1");
+        assert_eq!(get(&client, "/stats?region=a"), "a: 0");
+        assert_eq!(get(&client, "/stats?region=c"), "c: 2");
+        assert_eq!(caches.get("stats").unwrap().len(), 2);
+
+        assert_eq!(get(&client, "/stats?region=a"), "a: 0");
+        assert_eq!(get(&client, "/stats?region=c"), "c: 2");
+        assert_eq!(get(&client, "/stats?region=b"), "b: 3");
+    }
+
+    #[test]
+    fn large_bodies_are_not_stored() {
+        let client = client();
+        let caches = client.rocket().state::<ResponseCaches>().unwrap();
+        caches.get("big").unwrap().set_max_body_size(1.kibibytes());
+
+        assert_eq!(get(&client, "/big?n=16").trim(), "0");
+        assert_eq!(get(&client, "/big?n=16").trim(), "0");
+        assert_eq!(get(&client, "/big?n=2048").trim(), "1");
+        assert_eq!(get(&client, "/big?n=2048").trim(), "2");
+    }
+}
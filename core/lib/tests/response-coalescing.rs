@@ -0,0 +1,105 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::tokio::time::sleep;
+use rocket::tokio::io::AsyncReadExt;
+use rocket::local::asynchronous::{Client, LocalResponse};
+use rocket::response::stream::{Coalesced, TextStream};
+
+#[get("/plain")]
+fn plain() -> TextStream![&'static str] {
+    TextStream! {
+        for item in &["a", "b", "c"] {
+            yield *item;
+        }
+    }
+}
+
+#[get("/bytes")]
+fn bytes() -> Coalesced<TextStream![&'static str]> {
+    TextStream! {
+        for _ in 0..10 {
+            yield "x";
+        }
+    }.flush_after_bytes(4)
+}
+
+#[get("/idle")]
+fn idle() -> Coalesced<TextStream![&'static str]> {
+    TextStream! {
+        yield "a";
+        sleep(Duration::from_millis(200)).await;
+        yield "b";
+    }.flush_after_bytes(1024)
+}
+
+#[get("/interval")]
+fn interval() -> Coalesced<TextStream![&'static str]> {
+    TextStream! {
+        yield "a";
+        sleep(Duration::from_millis(5)).await;
+        yield "b";
+        sleep(Duration::from_millis(300)).await;
+        yield "c";
+    }.flush_interval(Duration::from_millis(100))
+}
+
+#[get("/sized")]
+fn sized() -> Coalesced<&'static str> {
+    Coalesced::new("hello").flush_after_bytes(2)
+}
+
+/// Returns the chunks of `response`, one per read.
+async fn chunks(mut response: LocalResponse<'_>) -> Vec<String> {
+    let mut chunks = vec![];
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = response.read(&mut buf).await.unwrap();
+        if n == 0 {
+            return chunks;
+        }
+
+        chunks.push(String::from_utf8(buf[..n].to_vec()).unwrap());
+    }
+}
+
+async fn client() -> Client {
+    Client::debug_with(routes![plain, bytes, idle, interval, sized]).await.unwrap()
+}
+
+#[rocket::async_test]
+async fn uncoalesced_items_are_sent_individually() {
+    let client = client().await;
+    let response = client.get("/plain").dispatch().await;
+    assert_eq!(chunks(response).await, vec!["a", "b", "c"]);
+}
+
+#[rocket::async_test]
+async fn items_coalesce_up_to_byte_limit() {
+    let client = client().await;
+    let response = client.get("/bytes").dispatch().await;
+    assert_eq!(chunks(response).await, vec!["xxxx", "xxxx", "xx"]);
+}
+
+#[rocket::async_test]
+async fn idle_streams_flush_immediately() {
+    let client = client().await;
+    let response = client.get("/idle").dispatch().await;
+    assert_eq!(chunks(response).await, vec!["a", "b"]);
+}
+
+#[rocket::async_test]
+async fn idle_streams_flush_after_interval() {
+    let client = client().await;
+    let response = client.get("/interval").dispatch().await;
+    assert_eq!(chunks(response).await, vec!["ab", "c"]);
+}
+
+#[rocket::async_test]
+async fn sized_bodies_are_unchanged() {
+    let client = client().await;
+    let response = client.get("/sized").dispatch().await;
+    assert_eq!(response.body().preset_size(), Some(5));
+    assert_eq!(response.into_string().await.unwrap(), "hello");
+}
@@ -0,0 +1,125 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Header, HeaderMap};
+use rocket::response::stream::{TextStream, WithTrailers};
+
+#[get("/")]
+fn trailers() -> WithTrailers<TextStream![&'static str], impl FnOnce() -> HeaderMap<'static> + Send> {
+    let stream = TextStream! {
+        yield "hello, ";
+        yield "world";
+    };
+
+    stream.with_trailers(&["X-Checksum", "X-Length"], || {
+        let mut trailers = HeaderMap::new();
+        trailers.add(Header::new("X-Checksum", "abc"));
+        trailers.add(Header::new("X-Length", "12"));
+        trailers
+    })
+}
+
+mod response_trailers_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn trailers_are_declared() {
+        let client = Client::debug_with(routes![trailers]).unwrap();
+        let response = client.get("/").dispatch();
+        let declared: Vec<_> = response.headers().get("Trailer").collect();
+        assert_eq!(declared, vec!["X-Checksum, X-Length"]);
+        assert!(response.headers().get_one("X-Checksum").is_none());
+        assert_eq!(response.into_string().unwrap(), "hello, world");
+    }
+}
+
+mod response_trailers_wire_tests {
+    use super::*;
+    use std::time::Duration;
+
+    use rocket::Config;
+    use rocket::http::hyper;
+    use rocket::fairing::AdHoc;
+    use rocket::futures::channel::oneshot;
+    use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+    /// Launches a server with the `trailers` route, returning its port.
+    async fn launch() -> u16 {
+        let (tx, rx) = oneshot::channel();
+        let config = Config { port: 0, ..Config::debug_default() };
+        let rocket = rocket::custom(config)
+            .mount("/", routes![trailers])
+            .attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+                tx.send(rocket.config().port).unwrap();
+            })));
+
+        tokio::spawn(rocket.launch());
+        rx.await.unwrap()
+    }
+
+    /// Sends `GET /` over a raw HTTP/1.1 connection, returning everything read
+    /// until the server closes the connection.
+    async fn get_raw() -> String {
+        let port = launch().await;
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let request = "GET / HTTP/1.1\r\nHost: a\r\nTE: trailers\r\nConnection: close\r\n\r\n";
+        stream.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = vec![];
+        let read = stream.read_to_end(&mut response);
+        tokio::time::timeout(Duration::from_secs(5), read).await
+            .expect("connection closed")
+            .unwrap();
+
+        String::from_utf8_lossy(&response).into_owned()
+    }
+
+    #[rocket::async_test]
+    async fn http2_responses_send_trailers() {
+        let port = launch().await;
+        let tcp = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let (h2, connection) = h2::client::handshake(tcp).await.unwrap();
+        tokio::spawn(connection);
+
+        let mut h2 = h2.ready().await.unwrap();
+        let request = hyper::Request::get("http://a/").body(()).unwrap();
+        let (response, _) = h2.send_request(request, true).unwrap();
+
+        let exchange = async move {
+            let response = response.await.unwrap();
+            assert_eq!(response.status(), 200);
+
+            let mut body = response.into_body();
+            let mut bytes = vec![];
+            while let Some(chunk) = body.data().await {
+                let chunk = chunk.unwrap();
+                body.flow_control().release_capacity(chunk.len()).unwrap();
+                bytes.extend_from_slice(&chunk);
+            }
+
+            assert_eq!(bytes, b"hello, world");
+            body.trailers().await.unwrap().expect("trailers")
+        };
+
+        let trailers = tokio::time::timeout(Duration::from_secs(5), exchange).await
+            .expect("response with trailers");
+
+        assert_eq!(trailers.get("x-checksum").unwrap(), "abc");
+        assert_eq!(trailers.get("x-length").unwrap(), "12");
+    }
+
+    #[rocket::async_test]
+    async fn http1_responses_do_not_advertise_unsent_trailers() {
+        let response = get_raw().await;
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let head = head.to_ascii_lowercase();
+        assert!(head.starts_with("http/1.1 200 ok"));
+        assert!(head.contains("transfer-encoding: chunked"));
+        assert!(!head.contains("\r\ntrailer:"));
+
+        // The chunked body ends with an empty last chunk and no trailer fields.
+        assert!(body.contains("hello, ") && body.contains("world"));
+        assert!(body.ends_with("\r\n0\r\n\r\n"));
+        assert!(!body.contains("X-Checksum"));
+    }
+}
@@ -0,0 +1,56 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::http::{Header, Status, RetryAfter};
+
+#[get("/busy")]
+fn busy() -> (Status, (RetryAfter, &'static str)) {
+    let retry = RetryAfter::Delay(Duration::from_secs(120));
+    (Status::ServiceUnavailable, (retry, "busy"))
+}
+
+#[get("/echo")]
+fn echo(retry: Result<RetryAfter, String>) -> String {
+    match retry {
+        Ok(retry) => retry.to_string(),
+        Err(e) => e,
+    }
+}
+
+mod retry_after_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn retry_after_responder() {
+        let client = Client::debug_with(routes![busy]).unwrap();
+        let response = client.get("/busy").dispatch();
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+        assert_eq!(response.headers().get_one("Retry-After"), Some("120"));
+        assert_eq!(response.into_string().unwrap(), "busy");
+    }
+
+    #[test]
+    fn retry_after_guard() {
+        let client = Client::debug_with(routes![echo]).unwrap();
+
+        let response = client.get("/echo").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+
+        let response = client.get("/echo")
+            .header(Header::new("Retry-After", "15"))
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "15");
+
+        let response = client.get("/echo")
+            .header(Header::new("Retry-After", "Sunday, 06-Nov-94 08:49:37 GMT"))
+            .dispatch();
+        assert_eq!(response.into_string().unwrap(), "Sun, 06 Nov 1994 08:49:37 GMT");
+
+        let response = client.get("/echo")
+            .header(Header::new("Retry-After", "soon"))
+            .dispatch();
+        assert!(response.into_string().unwrap().contains("invalid"));
+    }
+}
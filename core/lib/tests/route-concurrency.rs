@@ -0,0 +1,96 @@
+#[macro_use] extern crate rocket;
+
+use std::time::Duration;
+
+use rocket::State;
+use rocket::http::Status;
+use rocket::route::ConcurrencyLimits;
+use rocket::tokio::sync::Notify;
+use rocket::local::asynchronous::Client;
+
+#[derive(Default)]
+struct Gate {
+    entered: Notify,
+    release: Notify,
+}
+
+#[get("/slow", max_concurrency = 1)]
+async fn slow(gate: &State<Gate>) -> &'static str {
+    gate.entered.notify_one();
+    gate.release.notified().await;
+    "slow"
+}
+
+#[get("/fast")]
+fn fast() -> &'static str {
+    "fast"
+}
+
+async fn client() -> Client {
+    let rocket = rocket::build()
+        .mount("/", routes![slow, fast])
+        .manage(Gate::default());
+
+    Client::debug(rocket).await.unwrap()
+}
+
+#[rocket::async_test]
+async fn excess_requests_are_rejected() {
+    let client = client().await;
+    let gate = client.rocket().state::<Gate>().unwrap();
+    let limits = client.rocket().state::<ConcurrencyLimits>().unwrap();
+    let limit = limits.get("slow").unwrap();
+    assert!(limits.get("fast").is_none());
+
+    let first = client.get("/slow").dispatch();
+    let others = async {
+        gate.entered.notified().await;
+        assert_eq!(limit.in_flight(), 1);
+
+        let response = client.get("/slow").dispatch().await;
+        assert_eq!(response.status(), Status::ServiceUnavailable);
+        assert_eq!(limit.rejected(), 1);
+
+        let response = client.get("/fast").dispatch().await;
+        assert_eq!(response.into_string().await.unwrap(), "fast");
+
+        gate.release.notify_one();
+    };
+
+    let (response, _) = rocket::tokio::join!(first, others);
+    assert_eq!(response.into_string().await.unwrap(), "slow");
+    assert_eq!(limit.in_flight(), 0);
+
+    let second = client.get("/slow").dispatch();
+    let release = async {
+        gate.entered.notified().await;
+        gate.release.notify_one();
+    };
+
+    let (response, _) = rocket::tokio::join!(second, release);
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[rocket::async_test]
+async fn cancelled_requests_release_permits() {
+    let client = client().await;
+    let limits = client.rocket().state::<ConcurrencyLimits>().unwrap();
+    let limit = limits.get("slow").unwrap();
+
+    let request = client.get("/slow").dispatch();
+    let timeout = rocket::tokio::time::timeout(Duration::from_millis(50), request).await;
+    assert!(timeout.is_err());
+    assert_eq!(limit.in_flight(), 0);
+
+    let (route, limit) = limits.iter().next().unwrap();
+    assert_eq!(route.name, Some("slow"));
+    assert_eq!(route.to_string(), "(slow) GET /slow");
+    assert_eq!(limit.limit(), 1);
+}
+
+#[rocket::async_test]
+async fn limits_are_only_managed_when_a_route_is_limited() {
+    let rocket = rocket::build().mount("/", routes![fast]);
+    let client = Client::debug(rocket).await.unwrap();
+    assert!(client.rocket().state::<ConcurrencyLimits>().is_none());
+}
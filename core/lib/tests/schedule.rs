@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use rocket::fairing::Schedule;
+use rocket::tokio::time::{self, sleep};
+
+#[derive(Default)]
+struct Runs {
+    count: AtomicUsize,
+    running: AtomicBool,
+    overlapped: AtomicBool,
+}
+
+mod schedule_tests {
+    use super::*;
+    use rocket::local::asynchronous::Client;
+
+    #[tokio::test]
+    async fn jobs_run_until_shutdown() {
+        // With the clock paused, sleeping auto-advances it to the next pending
+        // timer, so every job runs exactly when scheduled.
+        time::pause();
+
+        let ticks = Arc::new(AtomicUsize::new(0));
+        let counter = ticks.clone();
+        let schedule = Schedule::new()
+            .every(Duration::from_millis(10), move || {
+                let counter = counter.clone();
+                async move { counter.fetch_add(1, Ordering::SeqCst); }
+            })
+            .every_with(Duration::from_millis(5), |runs: Arc<Runs>| async move {
+                if runs.running.swap(true, Ordering::SeqCst) {
+                    runs.overlapped.store(true, Ordering::SeqCst);
+                }
+
+                sleep(Duration::from_millis(20)).await;
+                runs.count.fetch_add(1, Ordering::SeqCst);
+                runs.running.store(false, Ordering::SeqCst);
+            });
+
+        let runs = Arc::new(Runs::default());
+        let rocket = rocket::build().manage(runs.clone()).attach(schedule);
+        let client = Client::debug(rocket).await.unwrap();
+
+        sleep(Duration::from_millis(95)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), 10);
+        assert_eq!(runs.count.load(Ordering::SeqCst), 4);
+        assert!(!runs.overlapped.load(Ordering::SeqCst));
+
+        client.rocket().shutdown().notify();
+        time::advance(Duration::from_millis(100)).await;
+        assert_eq!(ticks.load(Ordering::SeqCst), 10);
+        assert_eq!(runs.count.load(Ordering::SeqCst), 4);
+    }
+}
@@ -0,0 +1,79 @@
+#[macro_use] extern crate rocket;
+
+use rocket::Request;
+use rocket::http::{ContentType, Status};
+use rocket::response::content::{Html, Json};
+
+#[catch(404)]
+fn api_not_found(req: &Request<'_>) -> Json<String> {
+    Json(format!("{{\"error\": \"{} not found\"}}", req.uri().path()))
+}
+
+#[catch(default)]
+fn admin_default(status: Status, _: &Request<'_>) -> Html<String> {
+    Html(format!("<h1>admin: {}</h1>", status.code))
+}
+
+#[catch(404)]
+fn global_not_found() -> &'static str {
+    "global 404"
+}
+
+#[get("/admin/fail")]
+fn admin_fail() -> Status {
+    Status::Forbidden
+}
+
+#[get("/api/fail")]
+fn api_fail() -> Status {
+    Status::Forbidden
+}
+
+fn rocket() -> rocket::Rocket<rocket::Build> {
+    rocket::build()
+        .mount("/", routes![admin_fail, api_fail])
+        .register("/", catchers![global_not_found])
+        .register("/api", catchers![api_not_found])
+        .register("/admin", catchers![admin_default])
+}
+
+mod scoped_catchers_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    #[test]
+    fn most_specific_base_wins() {
+        let client = Client::debug(rocket()).unwrap();
+
+        let response = client.get("/api/users/1").dispatch();
+        assert_eq!(response.status(), Status::NotFound);
+        assert_eq!(response.content_type(), Some(ContentType::JSON));
+        assert_eq!(response.into_string().unwrap(), r#"{"error": "/api/users/1 not found"}"#);
+
+        let response = client.get("/admin/users").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::HTML));
+        assert_eq!(response.into_string().unwrap(), "<h1>admin: 404</h1>");
+    }
+
+    #[test]
+    fn scoped_catchers_fall_back_to_global_then_built_in() {
+        let client = Client::debug(rocket()).unwrap();
+
+        // Neither `/api` nor `/` have a `403` catcher: the built-in one runs.
+        let response = client.get("/api/fail").header(rocket::http::Accept::JSON).dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+        assert!(response.into_string().unwrap().contains("\"code\": 403"));
+
+        let response = client.get("/elsewhere").dispatch();
+        assert_eq!(response.into_string().unwrap(), "global 404");
+
+        // The `/admin` default catcher handles every status under `/admin`.
+        let response = client.get("/admin/fail").dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+        assert_eq!(response.into_string().unwrap(), "<h1>admin: 403</h1>");
+
+        // A base must match by whole segments: `/apis` is not under `/api`.
+        let response = client.get("/apis").dispatch();
+        assert_eq!(response.into_string().unwrap(), "global 404");
+    }
+}
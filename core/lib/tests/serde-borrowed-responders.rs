@@ -0,0 +1,74 @@
+#![cfg(feature = "json")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::State;
+use rocket::serde::json::Json;
+
+struct Names(Vec<String>);
+
+static FIRST: &str = "Bob";
+
+#[get("/json/all")]
+fn json_all(names: &State<Names>) -> Json<&[String]> {
+    Json(&names.0)
+}
+
+#[get("/json/first")]
+fn json_first(names: &State<Names>) -> Json<&String> {
+    Json(&names.0[0])
+}
+
+#[get("/json/static")]
+fn json_static() -> Json<&'static str> {
+    Json(FIRST)
+}
+
+#[cfg(feature = "msgpack")]
+#[get("/msgpack/all")]
+fn msgpack_all(names: &State<Names>) -> rocket::serde::msgpack::MsgPack<&[String]> {
+    rocket::serde::msgpack::MsgPack(&names.0)
+}
+
+mod borrowed_serde_responder_tests {
+    use super::*;
+    use rocket::http::ContentType;
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .manage(Names(vec!["Bob".into(), "Alice".into()]))
+            .mount("/", routes![json_all, json_first, json_static]);
+
+        #[cfg(feature = "msgpack")]
+        let rocket = rocket.mount("/", routes![msgpack_all]);
+
+        Client::debug(rocket).unwrap()
+    }
+
+    #[test]
+    fn json_borrowed() {
+        let client = client();
+        let response = client.get("/json/all").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::JSON));
+        assert_eq!(response.into_string().unwrap(), r#"["Bob","Alice"]"#);
+
+        let response = client.get("/json/first").dispatch();
+        assert_eq!(response.into_string().unwrap(), r#""Bob""#);
+
+        let response = client.get("/json/static").dispatch();
+        assert_eq!(response.into_string().unwrap(), r#""Bob""#);
+    }
+
+    #[test]
+    #[cfg(feature = "msgpack")]
+    fn msgpack_borrowed() {
+        let client = client();
+        let response = client.get("/msgpack/all").dispatch();
+        assert_eq!(response.content_type(), Some(ContentType::MsgPack));
+
+        let bytes = response.into_bytes().unwrap();
+        let names: Vec<String> = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(names, ["Bob", "Alice"]);
+    }
+}
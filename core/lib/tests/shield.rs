@@ -250,3 +250,57 @@ fn permission_test() {
             "accelerometer=(\"http://rocket.rs\" \"https://rocket.rs\"), usb=()");
     });
 }
+
+#[get("/nonce")]
+fn nonce(nonce: &CspNonce) -> String {
+    nonce.to_string()
+}
+
+#[get("/override")]
+fn csp_override(nonce: &CspNonce) -> (rocket::http::Csp, String) {
+    use rocket::http::{Csp, CspSource};
+
+    let csp = Csp::new().default_src(CspSource::None).style_src(CspSource::RequestNonce);
+    (csp, nonce.to_string())
+}
+
+#[test]
+fn csp_policies() {
+    use rocket::http::{Csp, CspSource};
+
+    let csp = Csp::new()
+        .default_src(CspSource::This)
+        .img_src([CspSource::This, CspSource::scheme("data")]);
+
+    dispatch!(Shield::new().enable(csp), |response: LocalResponse<'_>| {
+        let expected = "default-src 'self'; img-src 'self' data:";
+        assert_header!(response, "Content-Security-Policy", expected);
+    });
+
+    let csp = Csp::new()
+        .script_src([CspSource::RequestNonce, CspSource::StrictDynamic])
+        .object_src(CspSource::None);
+
+    let rocket = rocket::build()
+        .mount("/", routes![nonce, csp_override])
+        .attach(Shield::default().enable(csp));
+
+    let client = Client::debug(rocket).unwrap();
+    let mut nonces = vec![];
+    for _ in 0..2 {
+        let response = client.get("/nonce").dispatch();
+        let policy = response.headers().get_one("Content-Security-Policy").unwrap().to_string();
+        let nonce = response.into_string().unwrap();
+        assert_eq!(nonce.len(), 22);
+        let expected = format!("script-src 'nonce-{}' 'strict-dynamic'; object-src 'none'", nonce);
+        assert_eq!(policy, expected);
+        nonces.push(nonce);
+    }
+
+    assert_ne!(nonces[0], nonces[1]);
+
+    let response = client.get("/override").dispatch();
+    let policy = response.headers().get_one("Content-Security-Policy").unwrap().to_string();
+    let nonce = response.into_string().unwrap();
+    assert_eq!(policy, format!("default-src 'none'; style-src 'nonce-{}'", nonce));
+}
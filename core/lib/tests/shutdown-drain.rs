@@ -0,0 +1,99 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rocket::{Config, Shutdown};
+use rocket::config::Shutdown as ShutdownConfig;
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+#[get("/slow")]
+async fn slow() -> &'static str {
+    rocket::futures::future::pending::<()>().await;
+    "unreachable"
+}
+
+#[get("/activity")]
+fn activity(shutdown: Shutdown) -> String {
+    format!("{} {} {}", shutdown.in_flight(), shutdown.connections(), shutdown.is_requested())
+}
+
+type Reports = Arc<Mutex<Vec<(usize, usize)>>>;
+
+/// Launches a server with the shutdown deadline `deadline`, returning its port,
+/// its `Shutdown` handle, and the drain reports it makes.
+async fn launch(deadline: Option<u32>) -> (u16, Shutdown, Reports) {
+    let shutdown = ShutdownConfig {
+        ctrlc: false, grace: 5, mercy: 1, deadline,
+        ..Default::default()
+    };
+
+    let config = Config { port: 0, shutdown, ..Config::debug_default() };
+
+    let reports = Reports::default();
+    let reported = reports.clone();
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket::custom(config)
+        .mount("/", routes![slow, activity])
+        .attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+            tx.send((rocket.config().port, rocket.shutdown())).unwrap();
+        })))
+        .attach(AdHoc::on_drain("Reports", Duration::from_millis(100), move |drain| {
+            reported.lock().unwrap().push((drain.in_flight(), drain.connections()));
+        }));
+
+    tokio::spawn(rocket.launch());
+    let (port, shutdown) = rx.await.unwrap();
+    (port, shutdown, reports)
+}
+
+async fn request(stream: &mut TcpStream, path: &str) {
+    let request = format!("GET {} HTTP/1.1\r\nHost: a\r\n\r\n", path);
+    stream.write_all(request.as_bytes()).await.unwrap();
+}
+
+async fn read_response(stream: &mut TcpStream) -> String {
+    let mut buf = [0; 1024];
+    let read = stream.read(&mut buf);
+    let n = tokio::time::timeout(Duration::from_secs(5), read).await.expect("response").unwrap();
+    String::from_utf8_lossy(&buf[..n]).into_owned()
+}
+
+#[rocket::async_test]
+async fn activity_is_counted() {
+    let (port, shutdown, _) = launch(None).await;
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    request(&mut stream, "/activity").await;
+    assert!(read_response(&mut stream).await.ends_with("1 1 false"));
+
+    let mut other = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    request(&mut other, "/activity").await;
+    assert!(read_response(&mut other).await.ends_with("1 2 false"));
+    shutdown.notify();
+}
+
+#[rocket::async_test]
+async fn requests_outliving_the_deadline_get_a_503() {
+    let (port, shutdown, reports) = launch(Some(1)).await;
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    request(&mut stream, "/slow").await;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(shutdown.in_flight(), 1);
+
+    shutdown.clone().notify();
+    let response = read_response(&mut stream).await;
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable"), "{}", response);
+    assert!(response.to_lowercase().contains("connection: close"), "{}", response);
+
+    // The connection is closed after the 503 is written.
+    let mut buf = [0; 16];
+    assert_eq!(stream.read(&mut buf).await.unwrap(), 0);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.first(), Some(&(1, 1)));
+    assert_eq!(reports.last(), Some(&(0, 0)));
+    assert_eq!((shutdown.in_flight(), shutdown.connections()), (0, 0));
+}
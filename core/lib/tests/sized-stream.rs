@@ -0,0 +1,80 @@
+#[macro_use] extern crate rocket;
+
+use rocket::response::SizedStream;
+use rocket::tokio::io::{repeat, AsyncRead};
+
+#[get("/sized")]
+fn sized() -> SizedStream<impl AsyncRead> {
+    SizedStream::new(repeat(b'a'), 10)
+}
+
+#[get("/chunked")]
+fn chunked() -> SizedStream<impl AsyncRead> {
+    SizedStream::new(&b"chunked"[..], None)
+}
+
+mod sized_stream_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use rocket::{Config, Rocket, Build};
+    use rocket::fairing::AdHoc;
+    use rocket::futures::channel::oneshot;
+    use rocket::local::blocking::Client;
+    use rocket::response::Responder;
+    use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+    fn rocket() -> Rocket<Build> {
+        let config = Config { port: 0, ..Config::debug_default() };
+        rocket::custom(config).mount("/", routes![sized, chunked])
+    }
+
+    /// Sends a request for `path` to a launched `rocket()`, returning the raw
+    /// response.
+    async fn raw_response(path: &str) -> String {
+        let (tx, rx) = oneshot::channel();
+        let rocket = rocket().attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+            tx.send(rocket.config().port).unwrap();
+        })));
+
+        tokio::spawn(rocket.launch());
+        let mut stream = TcpStream::connect(("127.0.0.1", rx.await.unwrap())).await.unwrap();
+        let request = format!("GET {} HTTP/1.1\r\nHost: a\r\nConnection: close\r\n\r\n", path);
+        stream.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = String::new();
+        let read = stream.read_to_string(&mut response);
+        tokio::time::timeout(Duration::from_secs(5), read).await.expect("response").unwrap();
+        response.to_lowercase()
+    }
+
+    #[test]
+    fn streams_exactly_the_given_length() {
+        let client = Client::debug(rocket()).unwrap();
+        let response = client.get("/sized").dispatch();
+        assert_eq!(response.into_string().unwrap(), "aaaaaaaaaa");
+
+        let request = client.get("/");
+        let response = SizedStream::new(repeat(b'a'), 10).respond_to(request.inner()).unwrap();
+        assert_eq!(response.body().preset_size(), Some(10));
+
+        let response = SizedStream::new(repeat(b'a'), None).respond_to(request.inner()).unwrap();
+        assert_eq!(response.body().preset_size(), None);
+    }
+
+    #[rocket::async_test]
+    async fn known_length_is_not_chunked() {
+        let response = raw_response("/sized").await;
+        assert!(response.contains("\r\ncontent-length: 10\r\n"), "{}", response);
+        assert!(!response.contains("transfer-encoding"), "{}", response);
+        assert!(response.ends_with("\r\n\r\naaaaaaaaaa"), "{}", response);
+    }
+
+    #[rocket::async_test]
+    async fn unknown_length_is_chunked() {
+        let response = raw_response("/chunked").await;
+        assert!(response.contains("\r\ntransfer-encoding: chunked\r\n"), "{}", response);
+        assert!(!response.contains("content-length"), "{}", response);
+        assert!(response.contains("chunked\r\n0\r\n\r\n"), "{}", response);
+    }
+}
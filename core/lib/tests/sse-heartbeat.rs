@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use rocket::http::ContentType;
+use rocket::local::asynchronous::Client;
+use rocket::response::stream::Heartbeat;
+
+async fn heartbeat(heartbeat: Heartbeat, run_for: Duration) -> (Option<ContentType>, String) {
+    let rocket = rocket::build().mount("/__heartbeat", heartbeat);
+    let client = Client::debug(rocket).await.unwrap();
+    let shutdown = client.rocket().shutdown();
+    rocket::tokio::spawn(async move {
+        rocket::tokio::time::sleep(run_for).await;
+        shutdown.notify();
+    });
+
+    let response = client.get("/__heartbeat").dispatch().await;
+    let content_type = response.content_type();
+    (content_type, response.into_string().await.unwrap())
+}
+
+#[rocket::async_test]
+async fn pings_until_shutdown() {
+    let beat = Heartbeat::new(Duration::from_millis(100));
+    let (content_type, body) = heartbeat(beat, Duration::from_millis(350)).await;
+    assert_eq!(content_type, Some(ContentType::EventStream));
+    assert!(body.starts_with("retry:100\n\n"), "{:?}", body);
+
+    let pings = body.matches(":ping\n\n").count();
+    assert!((3..=5).contains(&pings), "got {} ping(s): {:?}", pings, body);
+    assert!(!body.contains("event:"));
+}
+
+#[rocket::async_test]
+async fn pings_as_events_without_retry() {
+    let beat = Heartbeat::new(Duration::from_millis(100)).events(true).retry(None);
+    let (_, body) = heartbeat(beat, Duration::from_millis(250)).await;
+    assert!(!body.contains("retry:"), "{:?}", body);
+    assert!(body.starts_with("event:ping\ndata:ping\n\n"), "{:?}", body);
+    assert!(!body.contains(":ping\n\n:"));
+}
+
+#[rocket::async_test]
+async fn custom_retry() {
+    let beat = Heartbeat::new(Duration::from_millis(100)).retry(Duration::from_secs(2));
+    let (_, body) = heartbeat(beat, Duration::from_millis(50)).await;
+    assert!(body.starts_with("retry:2000\n\n:ping\n\n"), "{:?}", body);
+}
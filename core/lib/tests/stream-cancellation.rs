@@ -0,0 +1,173 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use rocket::{Config, Rocket, Build};
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::request::Disconnect;
+use rocket::response::stream::{TextStream, ByteStream, Generator, generator};
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+static GENERATOR_DROPPED: AtomicBool = AtomicBool::new(false);
+static DISCONNECT_RESOLVED: AtomicBool = AtomicBool::new(false);
+static CLOSURE_DROPPED: AtomicBool = AtomicBool::new(false);
+static HANDLER_SAW_DISCONNECT: AtomicBool = AtomicBool::new(false);
+static FINISHED: AtomicBool = AtomicBool::new(false);
+
+/// Sets its flag when dropped.
+struct Cursor(&'static AtomicBool);
+
+impl Drop for Cursor {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[get("/forever")]
+fn forever() -> TextStream![&'static str] {
+    TextStream! {
+        let _cursor = Cursor(&GENERATOR_DROPPED);
+        yield "first";
+        rocket::futures::future::pending::<()>().await;
+        yield "unreachable";
+    }
+}
+
+#[get("/closure")]
+fn closure() -> ByteStream<Generator<'static>> {
+    generator(|mut sink| async move {
+        let _cursor = Cursor(&CLOSURE_DROPPED);
+        loop {
+            sink.send("tick").await;
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+}
+
+#[get("/watched")]
+fn watched(disconnect: Disconnect) -> TextStream![&'static str] {
+    tokio::spawn(async move {
+        disconnect.clone().await;
+        DISCONNECT_RESOLVED.store(disconnect.is_disconnected(), Ordering::SeqCst);
+    });
+
+    TextStream! {
+        loop {
+            yield "tick";
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+}
+
+#[get("/stalled")]
+async fn stalled(disconnect: Disconnect) -> &'static str {
+    disconnect.clone().await;
+    HANDLER_SAW_DISCONNECT.store(disconnect.is_disconnected(), Ordering::SeqCst);
+    "done"
+}
+
+#[get("/finished")]
+fn finished(disconnect: Disconnect) -> &'static str {
+    tokio::spawn(async move {
+        disconnect.clone().await;
+        FINISHED.store(!disconnect.is_disconnected(), Ordering::SeqCst);
+    });
+
+    "finished"
+}
+
+fn rocket() -> Rocket<Build> {
+    let config = Config { port: 0, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![forever, closure, watched, stalled, finished])
+}
+
+/// Requests `path` from a launched `rocket()`, reads the start of the
+/// response, then drops the connection.
+async fn request_and_disconnect(path: &str) {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket().attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+        tx.send(rocket.config().port).unwrap();
+    })));
+
+    tokio::spawn(rocket.launch());
+    let mut stream = TcpStream::connect(("127.0.0.1", rx.await.unwrap())).await.unwrap();
+    let request = format!("GET {} HTTP/1.1\r\nHost: a\r\n\r\n", path);
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut buf = [0; 256];
+    let read = stream.read(&mut buf);
+    let n = tokio::time::timeout(Duration::from_secs(5), read).await.expect("response").unwrap();
+    assert!(String::from_utf8_lossy(&buf[..n]).contains("200 OK"));
+    drop(stream);
+}
+
+/// Waits up to five seconds for `flag` to be set.
+async fn wait_for(flag: &AtomicBool) -> bool {
+    for _ in 0..100 {
+        if flag.load(Ordering::SeqCst) {
+            return true;
+        }
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    false
+}
+
+#[rocket::async_test]
+async fn generator_is_dropped_when_client_disconnects() {
+    request_and_disconnect("/forever").await;
+    assert!(wait_for(&GENERATOR_DROPPED).await, "generator was never dropped");
+}
+
+#[rocket::async_test]
+async fn generator_closure_is_dropped_when_client_disconnects() {
+    request_and_disconnect("/closure").await;
+    assert!(wait_for(&CLOSURE_DROPPED).await, "closure future was never dropped");
+}
+
+#[rocket::async_test]
+async fn disconnect_resolves_when_client_disconnects() {
+    request_and_disconnect("/watched").await;
+    assert!(wait_for(&DISCONNECT_RESOLVED).await, "disconnect never resolved");
+}
+
+#[rocket::async_test]
+async fn disconnect_resolves_when_client_leaves_mid_handler() {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket().attach(AdHoc::on_liftoff("Port", |rocket| Box::pin(async move {
+        tx.send(rocket.config().port).unwrap();
+    })));
+
+    tokio::spawn(rocket.launch());
+    let mut stream = TcpStream::connect(("127.0.0.1", rx.await.unwrap())).await.unwrap();
+    stream.write_all(b"GET /stalled HTTP/1.1\r\nHost: a\r\n\r\n").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    drop(stream);
+
+    assert!(wait_for(&HANDLER_SAW_DISCONNECT).await, "disconnect never resolved");
+}
+
+#[rocket::async_test]
+async fn disconnect_resolves_when_response_completes() {
+    use rocket::local::asynchronous::Client;
+
+    let client = Client::debug(rocket()).await.unwrap();
+    let response = client.get("/finished").dispatch().await;
+    assert_eq!(response.into_string().await.unwrap(), "finished");
+    assert!(wait_for(&FINISHED).await, "disconnect never resolved");
+}
+
+#[test]
+fn disconnect_is_pending_for_local_requests() {
+    use rocket::local::blocking::Client;
+
+    let client = Client::debug(rocket()).unwrap();
+    let req = client.get("/");
+    let disconnect = req.inner().guard::<Disconnect>();
+    let disconnect = rocket::async_test(disconnect).unwrap();
+    assert!(!disconnect.is_disconnected());
+    assert!(!disconnect.is_finished());
+}
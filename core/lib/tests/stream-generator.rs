@@ -0,0 +1,73 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::State;
+use rocket::http::ContentType;
+use rocket::local::blocking::Client;
+use rocket::futures::stream::StreamExt;
+use rocket::response::stream::{generator, ByteStream, Generator};
+
+#[get("/count/<n>")]
+fn count(n: usize) -> ByteStream<Generator<'static>> {
+    generator(move |mut sink| async move {
+        for i in 0..n {
+            sink.send(i.to_string()).await;
+            if i == 5 {
+                return;
+            }
+        }
+    })
+}
+
+#[get("/borrowed/<name>")]
+fn borrowed<'r>(name: &'r str, prefix: &'r State<String>) -> ByteStream<Generator<'r>> {
+    generator(move |mut sink| async move {
+        sink.send(prefix.as_bytes()).await;
+        sink.send(name).await;
+    })
+}
+
+#[test]
+fn generator_chunks_form_the_body() {
+    let rocket = rocket::build()
+        .manage(String::from("hi, "))
+        .mount("/", routes![count, borrowed]);
+
+    let client = Client::debug(rocket).unwrap();
+    let response = client.get("/count/3").dispatch();
+    assert_eq!(response.content_type(), Some(ContentType::Binary));
+    assert_eq!(response.into_string().unwrap(), "012");
+
+    let response = client.get("/count/100").dispatch();
+    assert_eq!(response.into_string().unwrap(), "012345");
+
+    let response = client.get("/count/0").dispatch();
+    assert_eq!(response.into_string().unwrap(), "");
+
+    let response = client.get("/borrowed/bob").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hi, bob");
+}
+
+#[rocket::async_test]
+async fn send_waits_for_the_chunk_to_be_taken() {
+    let sent = Arc::new(AtomicUsize::new(0));
+    let counter = sent.clone();
+    let mut stream = generator(move |mut sink| async move {
+        for i in 0..3u8 {
+            sink.send(vec![i]).await;
+            counter.fetch_add(1, Ordering::SeqCst);
+        }
+    }).0;
+
+    assert_eq!(sent.load(Ordering::SeqCst), 0);
+    assert_eq!(stream.next().await, Some(vec![0]));
+    assert_eq!(sent.load(Ordering::SeqCst), 0);
+    assert_eq!(stream.next().await, Some(vec![1]));
+    assert_eq!(sent.load(Ordering::SeqCst), 1);
+    assert_eq!(stream.next().await, Some(vec![2]));
+    assert_eq!(stream.next().await, None);
+    assert_eq!(sent.load(Ordering::SeqCst), 3);
+    assert_eq!(stream.next().await, None);
+}
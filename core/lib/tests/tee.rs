@@ -0,0 +1,90 @@
+#[macro_use] extern crate rocket;
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use rocket::fairing::AdHoc;
+use rocket::data::ToByteUnit;
+use rocket::tee::{Tee, TeedBody};
+
+#[post("/", data = "<body>")]
+fn echo(body: String, copy: Option<&TeedBody>) -> String {
+    let copy = copy.map(|copy| {
+        let contents = std::fs::read_to_string(copy.path()).unwrap();
+        format!("{}:{}:{}", contents, copy.len(), copy.is_truncated())
+    });
+
+    format!("{} [{}]", body, copy.unwrap_or_default())
+}
+
+#[post("/skip", data = "<body>")]
+fn skip(body: String, copy: Option<&TeedBody>) -> String {
+    format!("{} [{}]", body, copy.is_some())
+}
+
+fn client(paths: Arc<Mutex<Vec<PathBuf>>>) -> rocket::local::blocking::Client {
+    let tee = Tee::new()
+        .limit(8.bytes())
+        .filter(|req| req.uri().path() != "/skip");
+
+    let rocket = rocket::build()
+        .mount("/", routes![echo, skip])
+        .attach(tee)
+        .attach(AdHoc::on_response("Auditor", move |req, _| {
+            let paths = paths.clone();
+            Box::pin(async move {
+                if let Some(body) = req.guard::<&TeedBody>().await.succeeded() {
+                    assert!(body.path().exists());
+                    paths.lock().unwrap().push(body.path().to_path_buf());
+                }
+            })
+        }));
+
+    rocket::local::blocking::Client::debug(rocket).unwrap()
+}
+
+#[test]
+fn bodies_are_copied_and_presented_intact() {
+    let paths = Arc::new(Mutex::new(vec![]));
+    let client = client(paths.clone());
+
+    let response = client.post("/").body("hello").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello [hello:5:false]");
+
+    let response = client.post("/").body("exactly8").dispatch();
+    assert_eq!(response.into_string().unwrap(), "exactly8 [exactly8:8:false]");
+
+    let response = client.post("/").body("hello, world!").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello, world! [hello, w:8:true]");
+
+    // The files are removed once the requests complete.
+    let paths = paths.lock().unwrap();
+    assert_eq!(paths.len(), 3);
+    assert!(paths.iter().all(|path| !path.exists()));
+}
+
+#[test]
+fn unmatched_and_empty_bodies_are_not_copied() {
+    let paths = Arc::new(Mutex::new(vec![]));
+    let client = client(paths.clone());
+
+    let response = client.post("/skip").body("hello").dispatch();
+    assert_eq!(response.into_string().unwrap(), "hello [false]");
+
+    let response = client.post("/").dispatch();
+    assert_eq!(response.into_string().unwrap(), " []");
+    assert!(paths.lock().unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "raw_requests")]
+fn streamed_bodies_are_copied() {
+    let paths = Arc::new(Mutex::new(vec![]));
+    let client = client(paths);
+    let request = "POST / HTTP/1.1\r\nHost: rocket.rs\r\nTransfer-Encoding: chunked\r\n\r\n\
+        3\r\nabc\r\n4\r\ndefg\r\n4\r\nhijk\r\n0\r\n\r\n";
+
+    let response = client.send_raw(request);
+    let response = String::from_utf8_lossy(response.as_bytes());
+    assert!(response.ends_with("abcdefghijk [abcdefgh:8:true]"), "{}", response);
+}
@@ -0,0 +1,79 @@
+#[macro_use] extern crate rocket;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rocket::data::{Limits, ToByteUnit};
+use rocket::fs::TempFile;
+
+static UPLOADS: AtomicUsize = AtomicUsize::new(0);
+
+#[post("/", data = "<file>")]
+async fn upload(mut file: TempFile<'_>) -> std::io::Result<String> {
+    let spilled = file.path().is_some();
+    assert_eq!(spilled, file.bytes().is_none());
+    let in_memory = file.bytes().map(|bytes| bytes.to_vec());
+    let id = UPLOADS.fetch_add(1, Ordering::SeqCst);
+    let path = std::env::temp_dir().join(format!("rocket-spill-{}.txt", id));
+    file.persist_to(&path).await?;
+    let content = rocket::tokio::fs::read_to_string(&path).await?;
+    rocket::tokio::fs::remove_file(&path).await?;
+    assert!(file.bytes().is_none());
+    if let Some(bytes) = in_memory {
+        assert_eq!(bytes, content.as_bytes());
+    }
+
+    Ok(format!("{}:{}", spilled, content))
+}
+
+mod temp_file_spill_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+
+    fn client(limits: Limits) -> Client {
+        let figment = rocket::Config::figment().merge(("limits", limits));
+        Client::debug(rocket::custom(figment).mount("/", routes![upload])).unwrap()
+    }
+
+    #[test]
+    fn files_spill_to_disk_by_default() {
+        let client = client(Limits::default());
+        let response = client.post("/").body("hi").dispatch();
+        assert_eq!(response.into_string().unwrap(), "true:hi");
+    }
+
+    #[test]
+    fn small_files_stay_in_memory() {
+        let client = client(Limits::default().limit("spill", 8.bytes()));
+        let response = client.post("/").body("hello").dispatch();
+        assert_eq!(response.into_string().unwrap(), "false:hello");
+
+        let response = client.post("/").body("hello, world").dispatch();
+        assert_eq!(response.into_string().unwrap(), "true:hello, world");
+
+        let response = client.post("/").body("12345678").dispatch();
+        assert_eq!(response.into_string().unwrap(), "true:12345678");
+    }
+
+    #[test]
+    fn spill_threshold_per_extension() {
+        use rocket::http::ContentType;
+
+        let limits = Limits::default().limit("spill", 64.bytes()).limit("spill/txt", 2.bytes());
+        let client = client(limits);
+        let response = client.post("/").header(ContentType::Text).body("hello").dispatch();
+        assert_eq!(response.into_string().unwrap(), "true:hello");
+
+        let response = client.post("/").header(ContentType::JSON).body("hello").dispatch();
+        assert_eq!(response.into_string().unwrap(), "false:hello");
+    }
+
+    #[test]
+    fn file_limit_bounds_in_memory_files() {
+        use rocket::http::Status;
+
+        let limits = Limits::default().limit("spill", 1.kibibytes()).limit("file", 4.bytes());
+        let client = client(limits);
+        let response = client.post("/").body("hello, world").dispatch();
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+}
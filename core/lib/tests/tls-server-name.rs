@@ -0,0 +1,80 @@
+#![cfg(feature = "tls")]
+
+#[macro_use] extern crate rocket;
+
+use std::sync::Arc;
+
+use rocket::{Config, Rocket, Build};
+use rocket::config::TlsConfig;
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::tls::ServerName;
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+use tokio_rustls::{TlsConnector, rustls::ClientConfig, webpki::DNSNameRef};
+
+const CA_CERT: &[u8] = include_bytes!("../../../examples/tls/private/ca_cert.pem");
+const CERT: &[u8] = include_bytes!("../../../examples/tls/private/rsa_sha256_cert.pem");
+const KEY: &[u8] = include_bytes!("../../../examples/tls/private/rsa_sha256_key.pem");
+
+#[get("/")]
+fn server_name(name: ServerName<'_>) -> String {
+    format!("sni: {}", name)
+}
+
+#[get("/", rank = 2)]
+fn no_server_name() -> &'static str {
+    "no sni"
+}
+
+fn rocket() -> Rocket<Build> {
+    let tls = Some(TlsConfig::from_bytes(CERT, KEY));
+    let config = Config { port: 0, tls, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![server_name, no_server_name])
+}
+
+/// Launches a TLS-enabled Rocket, issues a `GET /` over a TLS connection, and
+/// returns the response body.
+async fn get(enable_sni: bool) -> String {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket().attach(AdHoc::on_liftoff("Port", move |rocket| Box::pin(async move {
+        tx.send(rocket.config().port).unwrap();
+    })));
+
+    tokio::spawn(rocket.launch());
+    let port = rx.await.unwrap();
+
+    let mut config = ClientConfig::new();
+    config.root_store.add_pem_file(&mut &CA_CERT[..]).unwrap();
+    config.enable_sni = enable_sni;
+
+    let tcp = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let name = DNSNameRef::try_from_ascii_str("localhost").unwrap();
+    let connector = TlsConnector::from(Arc::new(config));
+    let mut stream = connector.connect(name, tcp).await.unwrap();
+
+    stream.write_all(b"GET / HTTP/1.1\r\nHost: example.com\r\nConnection: close\r\n\r\n")
+        .await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response.split("\r\n\r\n").nth(1).unwrap_or_default().to_string()
+}
+
+#[rocket::async_test]
+async fn sni_server_name_is_exposed() {
+    assert_eq!(get(true).await, "sni: localhost");
+}
+
+#[rocket::async_test]
+async fn missing_sni_forwards() {
+    assert_eq!(get(false).await, "no sni");
+}
+
+#[test]
+fn plain_requests_forward() {
+    use rocket::local::blocking::Client;
+
+    let client = Client::debug_with(routes![server_name, no_server_name]).unwrap();
+    assert_eq!(client.get("/").dispatch().into_string().unwrap(), "no sni");
+}
@@ -0,0 +1,111 @@
+#[macro_use] extern crate rocket;
+
+use std::io;
+use std::pin::Pin;
+use std::time::Duration;
+
+use rocket::{Config, Rocket, Build, Request, Response};
+use rocket::data::{IoHandler, IoStream};
+use rocket::fairing::AdHoc;
+use rocket::futures::channel::oneshot;
+use rocket::response::{self, Responder};
+use rocket::tokio::{self, net::TcpStream, io::{AsyncReadExt, AsyncWriteExt}};
+
+struct Echo;
+
+#[rocket::async_trait]
+impl IoHandler for Echo {
+    async fn io(self: Pin<Box<Self>>, io: IoStream) -> io::Result<()> {
+        let (mut reader, mut writer) = tokio::io::split(io);
+        tokio::io::copy(&mut reader, &mut writer).await?;
+        writer.shutdown().await
+    }
+}
+
+impl<'r> Responder<'r, 'static> for Echo {
+    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+        Response::build()
+            .sized_body(None, std::io::Cursor::new("not upgraded"))
+            .upgrade("echo", self)
+            .ok()
+    }
+}
+
+#[get("/")]
+fn echo() -> Echo {
+    Echo
+}
+
+fn rocket() -> Rocket<Build> {
+    let config = Config { port: 0, ..Config::debug_default() };
+    rocket::custom(config).mount("/", routes![echo])
+}
+
+/// Launches `rocket`, returning a connection to it.
+async fn connect(rocket: Rocket<Build>) -> TcpStream {
+    let (tx, rx) = oneshot::channel();
+    let rocket = rocket.attach(AdHoc::on_liftoff("Port", move |rocket| Box::pin(async move {
+        tx.send(rocket.config().port).unwrap();
+    })));
+
+    tokio::spawn(rocket.launch());
+    let port = rx.await.unwrap();
+    TcpStream::connect(("127.0.0.1", port)).await.unwrap()
+}
+
+/// Reads from `stream` until it contains `needle`, returning what was read.
+async fn read_until(stream: &mut TcpStream, needle: &str) -> String {
+    let mut response = Vec::new();
+    let mut buf = vec![0; 1024];
+    while !String::from_utf8_lossy(&response).contains(needle) {
+        let read = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf));
+        let n = read.await.expect("response").unwrap();
+        assert_ne!(n, 0, "connection closed early: {:?}", String::from_utf8_lossy(&response));
+        response.extend_from_slice(&buf[..n]);
+    }
+
+    String::from_utf8(response).unwrap()
+}
+
+#[rocket::async_test]
+async fn upgraded_connection_is_handed_to_handler() {
+    let mut stream = connect(rocket()).await;
+    let request = "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: keep-alive, Upgrade\r\n\
+        Upgrade: foo, ECHO\r\n\r\n";
+
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let head = read_until(&mut stream, "\r\n\r\n").await;
+    assert!(head.starts_with("HTTP/1.1 101 Switching Protocols\r\n"), "{}", head);
+    assert!(head.to_lowercase().contains("\r\nupgrade: echo\r\n"), "{}", head);
+    assert!(!head.contains("not upgraded"));
+
+    stream.write_all(b"ping").await.unwrap();
+    assert_eq!(read_until(&mut stream, "ping").await, "ping");
+}
+
+#[rocket::async_test]
+async fn unrequested_upgrade_sends_response() {
+    let mut stream = connect(rocket()).await;
+    let requests = [
+        "GET / HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        "GET / HTTP/1.1\r\nHost: localhost\r\nConnection: Upgrade\r\nUpgrade: other\r\n\r\n",
+    ];
+
+    for request in &requests {
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let response = read_until(&mut stream, "not upgraded").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    }
+}
+
+#[rocket::async_test]
+async fn only_http11_connections_are_upgraded() {
+    let mut stream = connect(rocket()).await;
+    let request = "GET / HTTP/1.0\r\nHost: localhost\r\nConnection: keep-alive, Upgrade\r\n\
+        Upgrade: echo\r\n\r\n";
+
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let response = read_until(&mut stream, "not upgraded").await;
+    assert!(response.contains(" 200 OK\r\n"), "{}", response);
+    assert!(!response.contains("101"), "{}", response);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "raw_requests")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::http::Status;
+use rocket::data::{Limits, ToByteUnit};
+use rocket::local::blocking::Client;
+
+#[get("/<_path..>")]
+fn any(_path: std::path::PathBuf) -> &'static str {
+    "ok"
+}
+
+fn client_with(limits: Limits) -> Client {
+    let figment = rocket::Config::figment().merge(("limits", limits));
+    Client::debug(rocket::custom(figment).mount("/", routes![any])).unwrap()
+}
+
+fn status(client: &Client, uri: &str) -> Option<Status> {
+    let request = format!("GET {} HTTP/1.1\r\nHost: rocket.rs\r\n\r\n", uri);
+    client.send_raw(request).status()
+}
+
+#[test]
+fn oversized_uris_are_rejected() {
+    let client = client_with(Limits::default());
+    let path = format!("/{}", "a".repeat(8 * 1024 - 1));
+    assert_eq!(status(&client, &path), Some(Status::Ok));
+    assert_eq!(status(&client, &format!("{}a", path)), Some(Status::UriTooLong));
+    assert_eq!(status(&client, &format!("{}?a", path)), Some(Status::UriTooLong));
+
+    let client = client_with(Limits::default().limit("uri", 16.bytes()));
+    assert_eq!(status(&client, "/0123456789abcde"), Some(Status::Ok));
+    assert_eq!(status(&client, "/0123456789?abcde"), Some(Status::UriTooLong));
+}
+
+#[test]
+fn paths_with_too_many_segments_are_rejected() {
+    let client = client_with(Limits::default());
+    let path = "/a".repeat(256);
+    assert_eq!(status(&client, &path), Some(Status::Ok));
+    assert_eq!(status(&client, &format!("{}/a", path)), Some(Status::UriTooLong));
+
+    let client = client_with(Limits::default().limit("path_segments", 2.bytes()));
+    assert_eq!(status(&client, "/a//b/"), Some(Status::Ok));
+    assert_eq!(status(&client, "/a/b/c"), Some(Status::UriTooLong));
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "digest")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::data::{self, Capped, Data, FromData, Verified};
+use rocket::http::{Header, Status};
+use rocket::request::Request;
+use rocket::local::blocking::Client;
+
+const SHA_256: &str = "Ccp+TqpuiunH0mEWcSkYSINkTQffuny/vEyKLgg2DVs=";
+const MD5: &str = "5NfxtO0uQtFYmPSyewGdpA==";
+
+const LARGE_SHA_256: &str = "1p5omIFXgzJyMFqvIfRTyAA0boo2QNtleOJgIVVC5dQ=";
+
+struct Picky;
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for Picky {
+    type Error = ();
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        match req.headers().get_one("X-Outcome") {
+            Some("forward") => data::Outcome::Forward(data),
+            _ => data::Outcome::Failure((Status::UnprocessableEntity, ())),
+        }
+    }
+}
+
+#[post("/", data = "<body>")]
+fn upload(body: Verified<String>) -> String {
+    body.into_inner()
+}
+
+#[post("/bytes", data = "<body>")]
+fn bytes(body: Verified<Capped<Vec<u8>>>) -> String {
+    body.len().to_string()
+}
+
+#[put("/", data = "<_body>")]
+fn picky(_body: Verified<Picky>) -> &'static str {
+    "unreachable"
+}
+
+fn client() -> Client {
+    let figment = rocket::Config::figment().merge(("limits.bytes", "1MiB"));
+    let rocket = rocket::custom(figment).mount("/", routes![upload, bytes, picky]);
+    Client::debug(rocket).unwrap()
+}
+
+fn sha_256(digest: &str) -> Header<'static> {
+    Header::new("Digest", format!("sha-256={}", digest))
+}
+
+fn md5(digest: &str) -> Header<'static> {
+    Header::new("Content-MD5", digest.to_string())
+}
+
+fn post(client: &Client, uri: &str, body: &str, headers: Vec<Header<'static>>) -> Status {
+    let mut request = client.post(uri.to_string()).body(body);
+    headers.into_iter().for_each(|header| request.add_header(header));
+    request.dispatch().status()
+}
+
+#[test]
+fn matching_digests_are_accepted() {
+    let client = client();
+    let both = Header::new("Digest", format!("SHA-256={}, MD5={}", SHA_256, MD5));
+    let unknown = Header::new("Digest", format!("unixsum=30637, sha-256={}", SHA_256));
+    let cases = vec![
+        vec![sha_256(SHA_256)],
+        vec![md5(MD5)],
+        vec![both],
+        vec![sha_256(SHA_256), md5(MD5)],
+        vec![unknown],
+    ];
+
+    for headers in cases {
+        let mut request = client.post("/").body("hello, world");
+        headers.into_iter().for_each(|header| request.add_header(header));
+        let response = request.dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(), "hello, world");
+    }
+}
+
+#[test]
+fn mismatched_digests_are_rejected() {
+    let client = client();
+    let status = post(&client, "/", "hello, world!", vec![sha_256(SHA_256)]);
+    assert_eq!(status, Status::BadRequest);
+
+    let status = post(&client, "/", "Hello, world", vec![md5(MD5)]);
+    assert_eq!(status, Status::BadRequest);
+
+    let empty_md5 = md5("1B2M2Y8AsgTpgAmY7PhCfg==");
+    let status = post(&client, "/", "hello, world", vec![sha_256(SHA_256), empty_md5]);
+    assert_eq!(status, Status::BadRequest);
+}
+
+#[test]
+fn missing_or_malformed_digests_are_rejected() {
+    let client = client();
+    assert_eq!(post(&client, "/", "hello, world", vec![]), Status::BadRequest);
+
+    let bad_headers = vec![
+        Header::new("Digest", "unixsum=30637"),
+        Header::new("Digest", "sha-256"),
+        sha_256("not base64!"),
+        md5(SHA_256),
+    ];
+
+    for header in bad_headers {
+        assert_eq!(post(&client, "/", "hello, world", vec![header]), Status::BadRequest);
+    }
+}
+
+#[test]
+fn large_bodies_are_verified_while_streamed() {
+    let client = client();
+    let body = "x".repeat(100_000);
+    let response = client.post("/bytes")
+        .header(sha_256(LARGE_SHA_256))
+        .body(&body)
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "100000");
+
+    let status = post(&client, "/bytes", &body[1..], vec![sha_256(LARGE_SHA_256)]);
+    assert_eq!(status, Status::BadRequest);
+}
+
+#[test]
+fn inner_guard_outcomes_are_preserved() {
+    let client = client();
+    let response = client.put("/")
+        .header(sha_256(SHA_256))
+        .body("hello, world")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let response = client.put("/")
+        .header(sha_256(SHA_256))
+        .header(Header::new("X-Outcome", "forward"))
+        .body("hello, world")
+        .dispatch();
+
+    assert_eq!(response.status(), Status::NotFound);
+}
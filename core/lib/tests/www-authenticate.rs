@@ -0,0 +1,151 @@
+#[macro_use] extern crate rocket;
+
+use rocket::http::{Challenge, BearerError};
+use rocket::request::{BasicAuth, BearerToken};
+use rocket::response::status;
+
+#[get("/basic")]
+fn basic(auth: BasicAuth) -> String {
+    auth.username().to_string()
+}
+
+#[get("/bearer?<token>")]
+fn bearer(token: &str) -> Result<&'static str, status::Custom<(Challenge, &'static str)>> {
+    let error = match token {
+        "good" => return Ok("welcome"),
+        "reader" => BearerError::InsufficientScope,
+        _ => BearerError::InvalidToken,
+    };
+
+    let challenge = Challenge::bearer().realm("api").error(error).scope(["write"]);
+    Err(status::Custom(error.status(), (challenge, "denied")))
+}
+
+#[get("/token")]
+fn token(token: BearerToken) -> String {
+    token.token().to_string()
+}
+
+#[catch(401)]
+fn unauthorized() -> status::Unauthorized<(Vec<Challenge>, &'static str)> {
+    let challenges = vec![Challenge::bearer().realm("api"), Challenge::basic("api")];
+    status::Unauthorized(Some((challenges, "log in")))
+}
+
+mod www_authenticate_tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::blocking::Client;
+
+    fn client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![basic, bearer, token])
+            .register("/", catchers![unauthorized]);
+
+        Client::debug(rocket).unwrap()
+    }
+
+    #[test]
+    fn guard_failures_are_challenged() {
+        let client = client();
+        let response = client.get("/basic").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        let challenges: Vec<_> = response.headers().get("WWW-Authenticate").collect();
+        assert_eq!(challenges, [
+            r#"Bearer realm="api""#,
+            r#"Basic realm="api", charset="UTF-8""#
+        ]);
+
+        assert_eq!(response.into_string().unwrap(), "log in");
+
+        let credentials = Header::new("Authorization", "Basic Ym9iOnB3");
+        let response = client.get("/basic").header(credentials).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert!(!response.headers().contains("WWW-Authenticate"));
+    }
+
+    #[test]
+    fn bearer_errors_set_status_and_challenge() {
+        let client = client();
+        let response = client.get("/bearer?token=good").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert!(!response.headers().contains("WWW-Authenticate"));
+
+        let response = client.get("/bearer?token=bad").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"),
+            Some(r#"Bearer realm="api", error="invalid_token", scope="write""#));
+
+        let response = client.get("/bearer?token=reader").dispatch();
+        assert_eq!(response.status(), Status::Forbidden);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"),
+            Some(r#"Bearer realm="api", error="insufficient_scope", scope="write""#));
+    }
+
+    fn default_client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", routes![basic, token])
+            .attach(rocket::fairing::AdHoc::on_request("Realm", |req, _| Box::pin(async move {
+                if req.uri().query().map_or(false, |q| q == "realm") {
+                    req.set_challenge(Challenge::basic("admin"));
+                }
+            })));
+
+        Client::debug(rocket).unwrap()
+    }
+
+    #[test]
+    fn default_catcher_emits_guard_challenges() {
+        let client = default_client();
+        let response = client.get("/basic").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"),
+            Some(r#"Basic realm="Restricted", charset="UTF-8""#));
+
+        let response = client.get("/basic?realm").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"),
+            Some(r#"Basic realm="admin", charset="UTF-8""#));
+
+        let response = client.get("/token").dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"), Some("Bearer"));
+    }
+
+    #[test]
+    fn catcher_challenges_take_precedence() {
+        let client = client();
+        let response = client.get("/token").dispatch();
+        let challenges: Vec<_> = response.headers().get("WWW-Authenticate").collect();
+        assert_eq!(challenges, [
+            r#"Bearer realm="api""#,
+            r#"Basic realm="api", charset="UTF-8""#
+        ]);
+    }
+
+    #[test]
+    fn bearer_token_guard() {
+        let client = default_client();
+        for token in &["abc", "a-b.c_d~e+f/g==", "mF_9.B5f-4.1JqM"] {
+            let auth = Header::new("Authorization", format!("Bearer {}", token));
+            let response = client.get("/token").header(auth).dispatch();
+            assert_eq!(response.status(), Status::Ok);
+            assert!(!response.headers().contains("WWW-Authenticate"));
+            assert_eq!(response.into_string().unwrap(), *token);
+        }
+
+        for auth in &["Bearer", "Bearer ===", "Bearer a b", "bearer a=b", "Bearer é"] {
+            let auth = Header::new("Authorization", *auth);
+            let response = client.get("/token").header(auth.clone()).dispatch();
+            assert_eq!(response.status(), Status::BadRequest, "{:?}", auth.value());
+            assert_eq!(response.headers().get_one("WWW-Authenticate"),
+                Some(r#"Bearer error="invalid_request""#));
+        }
+
+        let auth = Header::new("Authorization", "Basic Ym9iOnB3");
+        let response = client.get("/token").header(auth).dispatch();
+        assert_eq!(response.status(), Status::Unauthorized);
+        assert_eq!(response.headers().get_one("WWW-Authenticate"), Some("Bearer"));
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "xml")]
+
+#[macro_use] extern crate rocket;
+
+use rocket::serde::{Serialize, Deserialize};
+use rocket::serde::xml::{Xml, Rooted};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "rocket::serde", rename = "reading")]
+struct Reading {
+    sensor: String,
+    value: f32,
+}
+
+#[post("/", data = "<reading>")]
+fn echo(reading: Xml<Reading>) -> Xml<Reading> {
+    Xml(Reading { sensor: reading.sensor.to_uppercase(), value: reading.value * 2.0 })
+}
+
+#[get("/envelope")]
+fn envelope() -> Rooted<Reading> {
+    Xml(Reading { sensor: "temp".into(), value: 1.5 }).with_root("Envelope")
+}
+
+mod xml_tests {
+    use super::*;
+    use rocket::local::blocking::Client;
+    use rocket::http::{ContentType, Status};
+    use rocket::data::{Limits, ToByteUnit};
+
+    fn client(limits: Limits) -> Client {
+        let figment = rocket::Config::figment().merge(("limits", limits));
+        Client::debug(rocket::custom(figment).mount("/", routes![echo, envelope])).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let client = client(Limits::default());
+        let reading = Reading { sensor: "temp".into(), value: 10.5 };
+        let response = client.post("/").xml(&reading).dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.content_type(), Some(ContentType::new("application", "xml")));
+
+        let reading: Reading = response.into_xml().unwrap();
+        assert_eq!(reading, Reading { sensor: "TEMP".into(), value: 21.0 });
+    }
+
+    #[test]
+    fn custom_root() {
+        let client = client(Limits::default());
+        let response = client.get("/envelope").dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(response.into_string().unwrap(),
+            "<Envelope><sensor>temp</sensor><value>1.5</value></Envelope>");
+    }
+
+    #[test]
+    fn malformed_body_is_bad_request() {
+        let client = client(Limits::default());
+        let response = client.post("/")
+            .header(ContentType::new("application", "xml"))
+            .body("<reading><sensor>temp</value></reading>")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = client.post("/")
+            .header(ContentType::new("application", "xml"))
+            .body("<reading><sensor>temp</sensor><value>hot</value></reading>")
+            .dispatch();
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    #[test]
+    fn oversized_body_is_rejected() {
+        let client = client(Limits::default().limit("xml", 8.bytes()));
+        let reading = Reading { sensor: "humidity".into(), value: 0.5 };
+        let response = client.post("/").xml(&reading).dispatch();
+        assert_eq!(response.status(), Status::PayloadTooLarge);
+    }
+}
@@ -91,6 +91,7 @@ ALL_CRATE_ROOTS=(
     "${CONTRIB_ROOT}/sync_db_pools/codegen"
     "${CONTRIB_ROOT}/sync_db_pools/lib"
     "${CONTRIB_ROOT}/dyn_templates"
+    "${CONTRIB_ROOT}/ws"
 )
 
 function print_environment() {
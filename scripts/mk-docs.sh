@@ -21,7 +21,7 @@ echo ":::: Generating the docs..."
 pushd "${PROJECT_ROOT}" > /dev/null 2>&1
   # Set the crate version and fill in missing doc URLs with docs.rs links.
   RUSTDOCFLAGS="-Zunstable-options --crate-version ${DOC_VERSION}" \
-    cargo doc -p rocket -p rocket_sync_db_pools -p rocket_dyn_templates \
+    cargo doc -p rocket -p rocket_sync_db_pools -p rocket_dyn_templates -p rocket_ws \
     -Zrustdoc-map --no-deps --all-features
 popd > /dev/null 2>&1
 
@@ -96,6 +96,9 @@ function test_contrib() {
     echo ":: Building and testing dyn_templates [$feature]..."
     $CARGO test -p rocket_dyn_templates --no-default-features --features $feature $@
   done
+
+  echo ":: Building and testing ws..."
+  $CARGO test -p rocket_ws $@
 }
 
 function test_core() {
@@ -104,7 +107,13 @@ function test_core() {
     tls
     json
     msgpack
+    cbor
+    xml
     uuid
+    chrono
+    digest
+    idempotency
+    raw_requests
   )
 
   echo ":: Building and testing core [no features]..."